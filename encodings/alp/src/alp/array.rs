@@ -1,16 +1,18 @@
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_array::array::PrimitiveArray;
 use vortex_array::encoding::ids;
 use vortex_array::patches::{Patches, PatchesMetadata};
-use vortex_array::stats::StatisticsVTable;
+use vortex_array::stats::{ArrayStatistics, Stat, StatisticsVTable, StatsSet};
 use vortex_array::validity::{ArrayValidity, LogicalValidity, ValidityVTable};
 use vortex_array::variants::{PrimitiveArrayTrait, VariantsVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
 use vortex_array::{
-    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoArrayData,
-    IntoCanonical,
+    flexbuffers_root, impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical,
+    IntoArrayData, IntoCanonical, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
 };
 use vortex_dtype::{DType, PType};
 use vortex_error::{vortex_bail, vortex_panic, VortexExpect as _, VortexResult};
@@ -19,7 +21,10 @@ use crate::alp::{alp_encode, decompress, Exponents};
 
 impl_encoding!("vortex.alp", ids::ALP, ALP);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the exponents e and f, and the optional
+/// patches.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct ALPMetadata {
     exponents: Exponents,
     patches: Option<PatchesMetadata>,
@@ -31,6 +36,33 @@ impl Display for ALPMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for ALPMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        writer.put_u8(self.exponents.e).put_u8(self.exponents.f);
+        PatchesMetadata::write_option_to(self.patches.as_ref(), &mut writer);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for ALPMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let e = reader.get_u8()?;
+        let f = reader.get_u8()?;
+        let patches = PatchesMetadata::read_option_from(&mut reader)?;
+        reader.finish()?;
+        Ok(Self {
+            exponents: Exponents { e, f },
+            patches,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl ALPArray {
     pub fn try_new(
         encoded: ArrayData,
@@ -149,4 +181,17 @@ impl VisitorVTable<ALPArray> for ALPEncoding {
     }
 }
 
-impl StatisticsVTable<ALPArray> for ALPEncoding {}
+impl StatisticsVTable<ALPArray> for ALPEncoding {
+    fn compute_statistics(&self, array: &ALPArray, stat: Stat) -> VortexResult<StatsSet> {
+        // The encoded values are null wherever the array is, patches only replace valid values.
+        Ok(match stat {
+            Stat::NullCount => array
+                .encoded()
+                .statistics()
+                .compute(stat)
+                .map(|null_count| StatsSet::of(stat, null_count))
+                .unwrap_or_default(),
+            _ => StatsSet::default(),
+        })
+    }
+}
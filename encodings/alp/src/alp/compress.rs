@@ -86,6 +86,7 @@ mod tests {
     use core::f64;
 
     use vortex_array::compute::scalar_at;
+    use vortex_array::stats::ArrayStatistics;
 
     use super::*;
 
@@ -189,4 +190,20 @@ mod tests {
             decompressed.maybe_null_slice::<f32>()
         );
     }
+
+    #[test]
+    #[allow(clippy::approx_constant)] // ALP doesn't like E
+    fn test_nullable_patched_null_count() {
+        let values = vec![
+            Some(1.234f64),
+            None,
+            Some(std::f64::consts::PI),
+            Some(4.0),
+            None,
+        ];
+        let array = PrimitiveArray::from_nullable_vec(values);
+        let encoded = alp_encode(&array).unwrap();
+        assert!(encoded.patches().is_some());
+        assert_eq!(encoded.statistics().compute_null_count(), Some(2));
+    }
 }
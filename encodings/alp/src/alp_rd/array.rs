@@ -1,14 +1,17 @@
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_array::array::PrimitiveArray;
 use vortex_array::encoding::ids;
 use vortex_array::patches::{Patches, PatchesMetadata};
-use vortex_array::stats::{StatisticsVTable, StatsSet};
+use vortex_array::stats::{ArrayStatistics, Stat, StatisticsVTable, StatsSet};
 use vortex_array::validity::{ArrayValidity, LogicalValidity, ValidityVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
 use vortex_array::{
-    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoCanonical,
+    flexbuffers_root, impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical,
+    IntoCanonical, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
 };
 use vortex_dtype::{DType, Nullability, PType};
 use vortex_error::{vortex_bail, VortexExpect, VortexResult};
@@ -17,7 +20,10 @@ use crate::alp_rd::alp_rd_decode;
 
 impl_encoding!("vortex.alprd", ids::ALP_RD, ALPRD);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the bit width of the right parts, the length of
+/// the dictionary, its 8 entries as u16s, the ptype of the left parts and the optional patches.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct ALPRDMetadata {
     right_bit_width: u8,
     dict_len: u8,
@@ -32,6 +38,45 @@ impl Display for ALPRDMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for ALPRDMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        writer.put_u8(self.right_bit_width).put_u8(self.dict_len);
+        for code in self.dict {
+            writer.put_u16(code);
+        }
+        writer.put_ptype(self.left_parts_ptype);
+        PatchesMetadata::write_option_to(self.patches.as_ref(), &mut writer);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for ALPRDMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let right_bit_width = reader.get_u8()?;
+        let dict_len = reader.get_u8()?;
+        let mut dict = [0; 8];
+        for code in dict.iter_mut() {
+            *code = reader.get_u16()?;
+        }
+        let left_parts_ptype = reader.get_ptype()?;
+        let patches = PatchesMetadata::read_option_from(&mut reader)?;
+        reader.finish()?;
+        Ok(Self {
+            right_bit_width,
+            dict_len,
+            dict,
+            left_parts_ptype,
+            patches,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl ALPRDArray {
     pub fn try_new(
         dtype: DType,
@@ -250,7 +295,20 @@ impl VisitorVTable<ALPRDArray> for ALPRDEncoding {
     }
 }
 
-impl StatisticsVTable<ALPRDArray> for ALPRDEncoding {}
+impl StatisticsVTable<ALPRDArray> for ALPRDEncoding {
+    fn compute_statistics(&self, array: &ALPRDArray, stat: Stat) -> VortexResult<StatsSet> {
+        // The left parts hold the validity of the array.
+        Ok(match stat {
+            Stat::NullCount => array
+                .left_parts()
+                .statistics()
+                .compute(stat)
+                .map(|null_count| StatsSet::of(stat, null_count))
+                .unwrap_or_default(),
+            _ => StatsSet::default(),
+        })
+    }
+}
 
 impl ArrayTrait for ALPRDArray {}
 
@@ -258,6 +316,7 @@ impl ArrayTrait for ALPRDArray {}
 mod test {
     use rstest::rstest;
     use vortex_array::array::PrimitiveArray;
+    use vortex_array::stats::ArrayStatistics;
     use vortex_array::{IntoArrayData, IntoCanonical};
 
     use crate::{alp_rd, ALPRDFloat};
@@ -294,4 +353,12 @@ mod test {
         let maybe_null_reals: Vec<T> = reals.into_iter().map(|v| v.unwrap_or_default()).collect();
         assert_eq!(decoded.maybe_null_slice::<T>(), &maybe_null_reals);
     }
+
+    #[test]
+    fn test_null_count() {
+        let reals =
+            PrimitiveArray::from_nullable_vec(vec![Some(0.1f64), None, Some(0.2), None, None]);
+        let rd_array = alp_rd::RDEncoder::new(&[0.1f64, 0.2]).encode(&reals);
+        assert_eq!(rd_array.statistics().compute_null_count(), Some(3));
+    }
 }
@@ -13,6 +13,7 @@ use std::ops::{Shl, Shr};
 
 use itertools::Itertools;
 use num_traits::{Float, One, PrimInt};
+use serde::{Deserialize, Serialize};
 use vortex_array::aliases::hash_map::HashMap;
 use vortex_array::array::PrimitiveArray;
 use vortex_array::{ArrayDType, IntoArrayData};
@@ -126,6 +127,7 @@ impl ALPRDFloat for f32 {
 /// Our implementation draws on the MIT-licensed [C++ implementation] provided by the original authors.
 ///
 /// [C++ implementation]: https://github.com/cwida/ALP/blob/main/include/alp/rd.hpp
+#[derive(Serialize, Deserialize)]
 pub struct RDEncoder {
     right_bit_width: u8,
     codes: Vec<u16>,
@@ -3,21 +3,26 @@ use std::mem::ManuallyDrop;
 use std::sync::Arc;
 
 use arrow_buffer::BooleanBuffer;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_array::array::BoolArray;
 use vortex_array::encoding::ids;
 use vortex_array::stats::StatsSet;
 use vortex_array::validity::{LogicalValidity, Validity, ValidityMetadata, ValidityVTable};
 use vortex_array::variants::{BoolArrayTrait, VariantsVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
-use vortex_array::{impl_encoding, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoCanonical};
+use vortex_array::{
+    flexbuffers_root, impl_encoding, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoCanonical,
+    MetadataReader, MetadataWriter, TryDeserializeArrayMetadata, TrySerializeArrayMetadata,
+};
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_error::{VortexExpect as _, VortexResult};
 
 impl_encoding!("vortex.bytebool", ids::BYTE_BOOL, ByteBool);
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the validity.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(remote = "Self")]
 pub struct ByteBoolMetadata {
     validity: ValidityMetadata,
 }
@@ -28,6 +33,27 @@ impl Display for ByteBoolMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for ByteBoolMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        self.validity.write_to(&mut writer);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for ByteBoolMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let validity = ValidityMetadata::read_from(&mut reader)?;
+        reader.finish()?;
+        Ok(Self { validity })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl ByteBoolArray {
     pub fn validity(&self) -> Validity {
         self.metadata().validity.to_validity(|| {
@@ -129,7 +155,10 @@ impl ValidityVTable<ByteBoolArray> for ByteBoolEncoding {
     }
 
     fn logical_validity(&self, array: &ByteBoolArray) -> LogicalValidity {
-        array.validity().to_logical(array.len())
+        array
+            .metadata()
+            .validity
+            .to_logical(array.len(), || array.validity())
     }
 }
 
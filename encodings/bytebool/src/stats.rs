@@ -6,6 +6,13 @@ use super::{ByteBoolArray, ByteBoolEncoding};
 
 impl StatisticsVTable<ByteBoolArray> for ByteBoolEncoding {
     fn compute_statistics(&self, array: &ByteBoolArray, stat: Stat) -> VortexResult<StatsSet> {
+        if stat == Stat::NullCount {
+            return Ok(StatsSet::of(
+                stat,
+                array.validity().null_count(array.len())?,
+            ));
+        }
+
         if array.is_empty() {
             return Ok(StatsSet::default());
         }
@@ -101,4 +108,13 @@ mod tests {
         assert_eq!(bool_arr.statistics().compute_run_count().unwrap(), 1);
         assert_eq!(bool_arr.statistics().compute_true_count().unwrap(), 0);
     }
+
+    #[test]
+    fn null_count() {
+        let bool_arr = ByteBoolArray::from(vec![Some(true), None, Some(false), None]);
+        assert_eq!(bool_arr.statistics().compute_null_count(), Some(2));
+
+        let bool_arr = ByteBoolArray::from(vec![true, false]);
+        assert_eq!(bool_arr.statistics().compute_null_count(), Some(0));
+    }
 }
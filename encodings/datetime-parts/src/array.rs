@@ -1,6 +1,7 @@
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_array::array::StructArray;
 use vortex_array::compute::try_cast;
 use vortex_array::encoding::ids;
@@ -9,8 +10,9 @@ use vortex_array::validity::{ArrayValidity, LogicalValidity, Validity, ValidityV
 use vortex_array::variants::{ExtensionArrayTrait, VariantsVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
 use vortex_array::{
-    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoArrayData,
-    IntoCanonical,
+    flexbuffers_root, impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical,
+    IntoArrayData, IntoCanonical, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
 };
 use vortex_dtype::{DType, PType};
 use vortex_error::{vortex_bail, VortexExpect as _, VortexResult, VortexUnwrap};
@@ -19,7 +21,9 @@ use crate::compute::decode_to_temporal;
 
 impl_encoding!("vortex.datetimeparts", ids::DATE_TIME_PARTS, DateTimeParts);
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the ptypes of the days, seconds and subseconds.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(remote = "Self")]
 pub struct DateTimePartsMetadata {
     // Validity lives in the days array
     // TODO(ngates): we should actually model this with a Tuple array when we have one.
@@ -34,6 +38,36 @@ impl Display for DateTimePartsMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for DateTimePartsMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        writer
+            .put_ptype(self.days_ptype)
+            .put_ptype(self.seconds_ptype)
+            .put_ptype(self.subseconds_ptype);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for DateTimePartsMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let days_ptype = reader.get_ptype()?;
+        let seconds_ptype = reader.get_ptype()?;
+        let subseconds_ptype = reader.get_ptype()?;
+        reader.finish()?;
+        Ok(Self {
+            days_ptype,
+            seconds_ptype,
+            subseconds_ptype,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl DateTimePartsArray {
     pub fn try_new(
         dtype: DType,
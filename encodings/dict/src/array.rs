@@ -1,27 +1,38 @@
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
 use arrow_buffer::BooleanBuffer;
-use serde::{Deserialize, Serialize};
-use vortex_array::array::BoolArray;
-use vortex_array::compute::{scalar_at, take};
+use num_traits::AsPrimitive;
+use serde::Deserialize;
+use vortex_array::array::{BoolArray, ChunkedArray, PrimitiveArray};
+use vortex_array::compute::{filter, scalar_at, take, try_cast, FilterMask};
 use vortex_array::encoding::ids;
+use vortex_array::patches::{Patches, PatchesMetadata};
 use vortex_array::stats::StatsSet;
 use vortex_array::validity::{ArrayValidity, LogicalValidity, ValidityVTable};
 use vortex_array::variants::PrimitiveArrayTrait;
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
 use vortex_array::{
-    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoArrayData,
-    IntoArrayVariant, IntoCanonical,
+    flexbuffers_root, impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical,
+    IntoArrayData, IntoArrayVariant, IntoCanonical, MetadataReader, MetadataWriter,
+    TryDeserializeArrayMetadata, TrySerializeArrayMetadata,
+};
+use vortex_dtype::{
+    match_each_integer_ptype, match_each_unsigned_integer_ptype, DType, NativePType, Nullability,
+    PType,
 };
-use vortex_dtype::{match_each_integer_ptype, DType, PType};
 use vortex_error::{vortex_bail, vortex_panic, VortexExpect as _, VortexResult};
 
 impl_encoding!("vortex.dict", ids::DICT, Dict);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the ptype of the codes, the number of values
+/// as a u64 and the optional patches.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct DictMetadata {
     codes_ptype: PType,
     values_len: usize,
+    patches: Option<PatchesMetadata>,
 }
 
 impl Display for DictMetadata {
@@ -30,20 +41,78 @@ impl Display for DictMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for DictMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        writer
+            .put_ptype(self.codes_ptype)
+            .put_usize(self.values_len);
+        PatchesMetadata::write_option_to(self.patches.as_ref(), &mut writer);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for DictMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let codes_ptype = reader.get_ptype()?;
+        let values_len = reader.get_usize()?;
+        let patches = PatchesMetadata::read_option_from(&mut reader)?;
+        reader.finish()?;
+        Ok(Self {
+            codes_ptype,
+            values_len,
+            patches,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl DictArray {
     pub fn try_new(codes: ArrayData, values: ArrayData) -> VortexResult<Self> {
+        Self::try_new_with_patches(codes, values, None)
+    }
+
+    /// A dictionary of only some of the values, with the others patched in.
+    ///
+    /// The codes at the patched indices are ignored, but must still point at a valid value.
+    pub fn try_new_with_patches(
+        codes: ArrayData,
+        values: ArrayData,
+        patches: Option<Patches>,
+    ) -> VortexResult<Self> {
         if !codes.dtype().is_unsigned_int() || codes.dtype().is_nullable() {
             vortex_bail!(MismatchedTypes: "non-nullable unsigned int", codes.dtype());
         }
+
+        let length = codes.len();
+        let dtype = values.dtype().clone();
+        let metadata = DictMetadata {
+            codes_ptype: PType::try_from(codes.dtype()).vortex_expect("codes dtype must be uint"),
+            values_len: values.len(),
+            patches: patches
+                .as_ref()
+                .map(|p| p.to_metadata(length, &dtype))
+                .transpose()?,
+        };
+
+        let mut children = Vec::with_capacity(4);
+        children.push(codes);
+        children.push(values);
+        if let Some(patches) = patches {
+            let (_, indices, values) = patches.into_parts();
+            children.push(indices);
+            children.push(values);
+        }
+
         Self::try_from_parts(
-            values.dtype().clone(),
-            codes.len(),
-            DictMetadata {
-                codes_ptype: PType::try_from(codes.dtype())
-                    .vortex_expect("codes dtype must be uint"),
-                values_len: values.len(),
-            },
-            [codes, values].into(),
+            dtype,
+            length,
+            metadata,
+            children.into(),
             StatsSet::default(),
         )
     }
@@ -61,12 +130,116 @@ impl DictArray {
             .child(1, self.dtype(), self.metadata().values_len)
             .vortex_expect("DictArray is missing its values child array")
     }
+
+    /// The values that aren't in the dictionary, if it only holds some of them.
+    pub fn patches(&self) -> Option<Patches> {
+        self.metadata().patches.as_ref().map(|p| {
+            Patches::new(
+                self.len(),
+                self.as_ref()
+                    .child(2, &p.indices_dtype(), p.len())
+                    .vortex_expect("DictArray: patch indices"),
+                self.as_ref()
+                    .child(3, self.dtype(), p.len())
+                    .vortex_expect("DictArray: patch values"),
+            )
+        })
+    }
+}
+
+impl DictArray {
+    /// Drop the values that no code refers to, remapping the codes to the values that remain.
+    ///
+    /// Returns `None`, leaving the dictionary as it is, if no more than `max_unused` of the values
+    /// are unused. Codes at patched indices still count as using their value, and the null at
+    /// code 0 of a nullable dictionary is always kept.
+    pub fn prune_values(&self, max_unused: f64) -> VortexResult<Option<Self>> {
+        let values_len = self.metadata().values_len;
+        if values_len == 0 {
+            return Ok(None);
+        }
+
+        let codes = self.codes().into_primitive()?;
+        let pruned = match_each_unsigned_integer_ptype!(codes.ptype(), |$C| {
+            prune_codes(
+                codes.maybe_null_slice::<$C>(),
+                values_len,
+                self.dtype().is_nullable(),
+                max_unused,
+            )
+                .map(|(codes, kept)| (PrimitiveArray::from(codes), kept))
+        });
+        let Some((codes, kept)) = pruned else {
+            return Ok(None);
+        };
+
+        let values = take(self.values(), PrimitiveArray::from(kept).into_array())?;
+        Self::try_new_with_patches(codes.into_array(), values, self.patches()).map(Some)
+    }
+
+    /// Take the given indices like [take], keeping the result dictionary encoded, and prune its
+    /// dictionary with [prune_values][Self::prune_values] if more than `max_unused` of the values
+    /// are unused.
+    ///
+    /// Pruning keeps a small take from holding every value of a much larger array alive, at the
+    /// cost of a pass over the codes.
+    pub fn take_pruned(&self, indices: &ArrayData, max_unused: f64) -> VortexResult<Self> {
+        Self::try_from(take(self, indices)?)?.pruned(max_unused)
+    }
+
+    /// Filter the array like [filter], keeping the result dictionary encoded, and prune its
+    /// dictionary if more than `max_unused` of the values are unused, like
+    /// [take_pruned][Self::take_pruned].
+    pub fn filter_pruned(&self, mask: FilterMask, max_unused: f64) -> VortexResult<Self> {
+        Self::try_from(filter(self.as_ref(), mask)?)?.pruned(max_unused)
+    }
+
+    fn pruned(self, max_unused: f64) -> VortexResult<Self> {
+        Ok(self.prune_values(max_unused)?.unwrap_or(self))
+    }
+}
+
+/// Remap the codes to the values they use, along with the indices of those values, unless the
+/// fraction of unused values is no more than `max_unused`.
+fn prune_codes<C>(
+    codes: &[C],
+    values_len: usize,
+    keep_first: bool,
+    max_unused: f64,
+) -> Option<(Vec<C>, Vec<u64>)>
+where
+    C: NativePType + AsPrimitive<usize>,
+    usize: AsPrimitive<C>,
+{
+    let mut used = vec![false; values_len];
+    used[0] = keep_first;
+    for code in codes {
+        used[code.as_()] = true;
+    }
+    let used_count = used.iter().filter(|&&u| u).count();
+    if (values_len - used_count) as f64 / values_len as f64 <= max_unused {
+        return None;
+    }
+
+    let mut remapped = vec![C::default(); values_len];
+    let mut kept = Vec::with_capacity(used_count);
+    for (value, &is_used) in used.iter().enumerate() {
+        if is_used {
+            remapped[value] = kept.len().as_();
+            kept.push(value as u64);
+        }
+    }
+    Some((codes.iter().map(|c| remapped[c.as_()]).collect(), kept))
 }
 
 impl ArrayTrait for DictArray {}
 
 impl IntoCanonical for DictArray {
     fn into_canonical(self) -> VortexResult<Canonical> {
+        if let Some(patches) = self.patches() {
+            return canonicalize_patched(&self, patches);
+        }
+
         match self.dtype() {
             // NOTE: Utf8 and Binary will decompress into VarBinViewArray, which requires a full
             // decompression to construct the views child array.
@@ -82,6 +255,30 @@ impl IntoCanonical for DictArray {
     }
 }
 
+/// Append the patch values to the dictionary and point the codes of the patched indices at them,
+/// then take from it like an unpatched dictionary.
+fn canonicalize_patched(array: &DictArray, patches: Patches) -> VortexResult<Canonical> {
+    let values = array.values();
+    let values_len = values.len() as u64;
+    let u64_dtype = DType::Primitive(PType::U64, Nullability::NonNullable);
+
+    let mut codes = try_cast(array.codes(), &u64_dtype)?
+        .into_primitive()?
+        .into_maybe_null_slice::<u64>();
+    let (_, indices, patch_values) = patches.into_parts();
+    let indices = try_cast(indices, &u64_dtype)?.into_primitive()?;
+    for (patch, &index) in indices.maybe_null_slice::<u64>().iter().enumerate() {
+        let index: usize = index.as_();
+        codes[index] = values_len + patch as u64;
+    }
+
+    let values: ArrayData =
+        ChunkedArray::try_new(vec![values, patch_values], array.dtype().clone())?
+            .into_canonical()?
+            .into();
+    take(values, PrimitiveArray::from(codes).into_array())?.into_canonical()
+}
+
 impl ValidityVTable<DictArray> for DictEncoding {
     fn is_valid(&self, array: &DictArray, index: usize) -> bool {
         let values_index = scalar_at(array.codes(), index)
@@ -117,6 +314,10 @@ impl ValidityVTable<DictArray> for DictEncoding {
 impl VisitorVTable<DictArray> for DictEncoding {
     fn accept(&self, array: &DictArray, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
         visitor.visit_child("values", &array.values())?;
-        visitor.visit_child("codes", &array.codes())
+        visitor.visit_child("codes", &array.codes())?;
+        if let Some(patches) = array.patches().as_ref() {
+            visitor.visit_patches(patches)?;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,365 @@
+use std::any::Any;
+use std::hash::BuildHasher;
+
+use hashbrown::HashTable;
+use num_traits::AsPrimitive;
+use vortex_array::accessor::ArrayAccessor;
+use vortex_array::aliases::hash_map::DefaultHashBuilder;
+use vortex_array::array::builder::VarBinBuilder;
+use vortex_array::array::{PrimitiveArray, VarBinArray};
+use vortex_array::builders::ArrayBuilder;
+use vortex_array::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant, IntoCanonical};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexExpect, VortexResult};
+use vortex_scalar::{BinaryScalar, Scalar, Utf8Scalar};
+
+use crate::compress::{dict_values_validity, lookup_bytes};
+use crate::{DictArray, NULL_CODE};
+
+/// Default maximum number of distinct values before the builder stops dictionary encoding.
+pub const DEFAULT_MAX_DICT_VALUES: usize = 1 << 16;
+/// Default maximum ratio of distinct values to rows for the output to be dictionary encoded.
+pub const DEFAULT_MAX_DICT_RATIO: f64 = 0.5;
+
+/// An [ArrayBuilder] for string and binary values that dictionary encodes values as they are
+/// appended.
+///
+/// If the ratio of distinct values to rows is at most `max_ratio`, the builder finishes into a
+/// [DictArray], otherwise into a canonical VarBinView array. Once more than `max_values` distinct
+/// values have been seen, or their bytes no longer fit in u32 offsets, the dictionary is dropped
+/// and values are buffered as-is, so high cardinality columns don't pay for the hash table.
+pub struct DictBuilder {
+    dtype: DType,
+    max_values: usize,
+    max_ratio: f64,
+    hasher: DefaultHashBuilder,
+    lookup: HashTable<u64>,
+    codes: Vec<u64>,
+    bytes: Vec<u8>,
+    offsets: Vec<u32>,
+    plain: Option<VarBinBuilder<u64>>,
+    has_nulls: bool,
+}
+
+impl DictBuilder {
+    pub fn new(dtype: DType) -> VortexResult<Self> {
+        Self::with_limits(dtype, DEFAULT_MAX_DICT_VALUES, DEFAULT_MAX_DICT_RATIO)
+    }
+
+    pub fn with_limits(dtype: DType, max_values: usize, max_ratio: f64) -> VortexResult<Self> {
+        if !matches!(dtype, DType::Utf8(_) | DType::Binary(_)) {
+            vortex_bail!("DictBuilder only supports utf8 and binary, got {}", dtype);
+        }
+
+        let mut builder = Self {
+            dtype,
+            max_values,
+            max_ratio,
+            hasher: DefaultHashBuilder::default(),
+            lookup: HashTable::new(),
+            codes: Vec::new(),
+            bytes: Vec::new(),
+            offsets: Vec::new(),
+            plain: None,
+            has_nulls: false,
+        };
+        builder.reset();
+        Ok(builder)
+    }
+
+    /// Append a value to the builder.
+    ///
+    /// For utf8 builders the bytes must be valid UTF-8.
+    pub fn append_value<S: AsRef<[u8]>>(&mut self, value: S) {
+        let value = value.as_ref();
+        if let Some(plain) = self.plain.as_mut() {
+            plain.push_value(value);
+            return;
+        }
+
+        // Dictionary values are addressed by u32 offsets, so stop dictionary encoding before
+        // they could overflow.
+        if self.bytes.len() + value.len() > u32::MAX as usize {
+            self.fallback_to_plain();
+            self.append_value(value);
+            return;
+        }
+
+        let offsets = &mut self.offsets;
+        let bytes = &mut self.bytes;
+        let hasher = &self.hasher;
+        let code = *self
+            .lookup
+            .entry(
+                hasher.hash_one(value),
+                |idx| value == lookup_bytes(offsets.as_slice(), bytes.as_slice(), idx.as_()),
+                |idx| {
+                    hasher.hash_one(lookup_bytes(
+                        offsets.as_slice(),
+                        bytes.as_slice(),
+                        idx.as_(),
+                    ))
+                },
+            )
+            .or_insert_with(|| {
+                let next_code = offsets.len() as u64 - 1;
+                bytes.extend_from_slice(value);
+                offsets
+                    .push(u32::try_from(bytes.len()).vortex_expect("dictionary bytes fit in u32"));
+                next_code
+            })
+            .get();
+        self.codes.push(code);
+
+        if self.values_count() > self.max_values {
+            self.fallback_to_plain();
+        }
+    }
+
+    pub fn append_option<S: AsRef<[u8]>>(&mut self, value: Option<S>) {
+        match value {
+            Some(value) => self.append_value(value),
+            None => self.append_null(),
+        }
+    }
+
+    /// The number of distinct non-null values in the dictionary, or `None` if the builder is no
+    /// longer dictionary encoding.
+    pub fn distinct_count(&self) -> Option<usize> {
+        self.plain.is_none().then(|| self.values_count())
+    }
+
+    fn values_count(&self) -> usize {
+        self.offsets.len() - 1 - usize::from(self.dtype.is_nullable())
+    }
+
+    fn fallback_to_plain(&mut self) {
+        let mut plain = VarBinBuilder::<u64>::with_capacity(self.codes.len());
+        for &code in &self.codes {
+            if code == NULL_CODE && self.dtype.is_nullable() {
+                plain.push_null();
+            } else {
+                plain.push_value(lookup_bytes(&self.offsets, &self.bytes, code.as_()));
+            }
+        }
+        let has_nulls = self.has_nulls;
+        self.reset();
+        self.plain = Some(plain);
+        self.has_nulls = has_nulls;
+    }
+
+    fn reset(&mut self) {
+        self.lookup.clear();
+        self.codes.clear();
+        self.bytes.clear();
+        self.offsets.clear();
+        self.offsets.push(0);
+        // Nullable dictionaries reserve the first value for null.
+        if self.dtype.is_nullable() {
+            self.offsets.push(0);
+        }
+        self.plain = None;
+        self.has_nulls = false;
+    }
+}
+
+impl ArrayBuilder for DictBuilder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        match &self.plain {
+            Some(plain) => plain.len(),
+            None => self.codes.len(),
+        }
+    }
+
+    fn nbytes(&self) -> usize {
+        match &self.plain {
+            Some(plain) => plain.nbytes(),
+            None => {
+                self.codes.len() * size_of::<u64>()
+                    + self.offsets.len() * size_of::<u32>()
+                    + self.bytes.len()
+            }
+        }
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        match &self.plain {
+            Some(plain) => plain.nbytes(),
+            None => {
+                self.codes.capacity() * size_of::<u64>()
+                    + self.offsets.capacity() * size_of::<u32>()
+                    + self.bytes.capacity()
+                    + self.lookup.capacity() * size_of::<u64>()
+            }
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        if self.plain.is_none() {
+            self.codes.reserve(additional);
+        }
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        for _ in 0..n {
+            self.append_value(b"");
+        }
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.has_nulls = true;
+        match self.plain.as_mut() {
+            Some(plain) => (0..n).for_each(|_| plain.push_null()),
+            None => self.codes.extend(std::iter::repeat(NULL_CODE).take(n)),
+        }
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        if !scalar.dtype().eq_ignore_nullability(&self.dtype) {
+            vortex_bail!(MismatchedTypes: self.dtype, scalar.dtype());
+        }
+        match scalar.dtype() {
+            DType::Utf8(_) => self.append_option(Utf8Scalar::try_from(scalar)?.value()),
+            _ => self.append_option(BinaryScalar::try_from(scalar)?.value()),
+        }
+        Ok(())
+    }
+
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        if !array.dtype().eq_ignore_nullability(&self.dtype) {
+            vortex_bail!(MismatchedTypes: self.dtype, array.dtype());
+        }
+        array.clone().into_varbinview()?.with_iterator(|iter| {
+            for value in iter {
+                self.append_option(value);
+            }
+        })
+    }
+
+    fn finish(&mut self) -> VortexResult<ArrayData> {
+        if self.has_nulls && !self.dtype.is_nullable() {
+            vortex_bail!("Non-nullable builder has null values");
+        }
+
+        if let Some(plain) = self.plain.take() {
+            self.reset();
+            return Ok(plain.finish(self.dtype.clone()).into_canonical()?.into());
+        }
+
+        let len = self.codes.len();
+        let values_count = self.values_count();
+        let values_validity =
+            dict_values_validity(self.dtype.is_nullable(), self.offsets.len() - 1);
+        let values = VarBinArray::try_new(
+            PrimitiveArray::from(std::mem::take(&mut self.offsets)).into_array(),
+            PrimitiveArray::from(std::mem::take(&mut self.bytes)).into_array(),
+            self.dtype.clone(),
+            values_validity,
+        )?;
+        let dict = DictArray::try_new(
+            PrimitiveArray::from(std::mem::take(&mut self.codes)).into_array(),
+            values.into_canonical()?.into(),
+        )?;
+        self.reset();
+
+        if values_count as f64 <= self.max_ratio * len as f64 {
+            Ok(dict.into_array())
+        } else {
+            Ok(dict.into_canonical()?.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::builders::ArrayBuilder;
+    use vortex_array::compute::scalar_at;
+    use vortex_array::encoding::Encoding;
+    use vortex_array::{ArrayDType, IntoArrayVariant};
+    use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
+
+    use crate::{DictArray, DictBuilder, DictEncoding};
+
+    #[test]
+    fn low_cardinality_is_dict() {
+        let mut builder = DictBuilder::new(DType::Utf8(Nullability::Nullable)).unwrap();
+        for value in ["a", "b", "a", "a", "b", "a"] {
+            builder.append_value(value);
+        }
+        builder.append_null();
+        assert_eq!(builder.distinct_count(), Some(2));
+
+        let array = builder.finish().unwrap();
+        assert!(array.is_encoding(DictEncoding::ID));
+        assert_eq!(array.len(), 7);
+        assert_eq!(
+            DictArray::try_from(array.clone()).unwrap().values().len(),
+            3
+        );
+        assert_eq!(
+            scalar_at(&array, 1).unwrap(),
+            Scalar::utf8("b", Nullability::Nullable)
+        );
+        assert!(scalar_at(&array, 6).unwrap().is_null());
+    }
+
+    #[test]
+    fn high_cardinality_is_canonical() {
+        let mut builder = DictBuilder::new(DType::Binary(Nullability::NonNullable)).unwrap();
+        builder.append_value(b"a");
+        builder.append_value(b"b");
+        builder.append_value(b"c");
+
+        let array = builder.finish().unwrap();
+        assert!(!array.is_encoding(DictEncoding::ID));
+        assert_eq!(array.dtype(), &DType::Binary(Nullability::NonNullable));
+        let varbinview = array.into_varbinview().unwrap();
+        assert_eq!(varbinview.bytes_at(2).unwrap(), b"c");
+    }
+
+    #[test]
+    fn exceeding_max_values_falls_back() {
+        let mut builder =
+            DictBuilder::with_limits(DType::Utf8(Nullability::Nullable), 2, 1.0).unwrap();
+        builder.append_value("a");
+        builder.append_null();
+        builder.append_value("b");
+        builder.append_value("c");
+        builder.append_value("a");
+        assert_eq!(builder.distinct_count(), None);
+
+        let array = builder.finish().unwrap();
+        assert!(!array.is_encoding(DictEncoding::ID));
+        assert_eq!(array.len(), 5);
+        assert!(scalar_at(&array, 1).unwrap().is_null());
+        assert_eq!(
+            scalar_at(&array, 4).unwrap(),
+            Scalar::utf8("a", Nullability::Nullable)
+        );
+
+        // The builder is reusable after finishing.
+        builder.append_value("z");
+        assert_eq!(builder.distinct_count(), Some(1));
+    }
+
+    #[test]
+    fn non_nullable_with_nulls_fails() {
+        let mut builder = DictBuilder::new(DType::Utf8(Nullability::NonNullable)).unwrap();
+        builder.append_value("a");
+        builder.append_null();
+        assert!(builder.finish().is_err());
+    }
+}
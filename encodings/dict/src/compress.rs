@@ -1,3 +1,4 @@
+use std::cmp::Reverse;
 use std::hash::{BuildHasher, Hash, Hasher};
 
 use hashbrown::hash_map::Entry;
@@ -8,13 +9,18 @@ use vortex_array::aliases::hash_map::{DefaultHashBuilder, HashMap};
 use vortex_array::array::{
     ConstantArray, PrimitiveArray, SparseArray, VarBinArray, VarBinViewArray,
 };
+use vortex_array::compute::{take, try_cast};
+use vortex_array::patches::Patches;
 use vortex_array::validity::Validity;
 use vortex_array::variants::PrimitiveArrayTrait;
-use vortex_array::{ArrayDType, IntoArrayData, IntoCanonical};
-use vortex_dtype::{match_each_native_ptype, DType, NativePType, ToBytes};
-use vortex_error::{VortexExpect as _, VortexUnwrap};
+use vortex_array::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant, IntoCanonical};
+use vortex_dtype::Nullability::NonNullable;
+use vortex_dtype::{match_each_native_ptype, DType, NativePType, PType, ToBytes};
+use vortex_error::{vortex_bail, VortexExpect as _, VortexResult, VortexUnwrap};
 use vortex_scalar::Scalar;
 
+use crate::DictArray;
+
 /// Statically assigned code for a null value.
 pub const NULL_CODE: u64 = 0;
 
@@ -162,7 +168,78 @@ fn dict_encode_varbin_bytes<'a, I: Iterator<Item = Option<&'a [u8]>>>(
     )
 }
 
-fn dict_values_validity(nullable: bool, len: usize) -> Validity {
+/// Keep only the `max_values` most frequent values of a dictionary encoding, such as one from the
+/// functions above, and patch in the others.
+///
+/// Unlike a full dictionary, this stays small with narrow codes for columns of many distinct values
+/// of which a few are common, e.g. Zipfian strings.
+pub fn dict_top_k(
+    codes: &PrimitiveArray,
+    values: ArrayData,
+    max_values: usize,
+) -> VortexResult<DictArray> {
+    let codes = try_cast(codes, &DType::Primitive(PType::U64, NonNullable))?.into_primitive()?;
+    let codes = codes.maybe_null_slice::<u64>();
+    // The null value of a nullable dictionary is always kept, as code 0.
+    let first = usize::from(values.dtype().is_nullable());
+
+    let mut counts = vec![0usize; values.len()];
+    for &code in codes {
+        counts[AsPrimitive::<usize>::as_(code)] += 1;
+    }
+    let mut kept = (first..values.len()).collect::<Vec<_>>();
+    kept.sort_by_key(|&code| Reverse(counts[code]));
+    if kept.len() <= max_values {
+        return DictArray::try_new(PrimitiveArray::from(codes.to_vec()).into_array(), values);
+    }
+    if max_values == 0 {
+        vortex_bail!("A partial dictionary must keep at least one value");
+    }
+    kept.truncate(max_values);
+    // Keep the values in their original order, which is the order they first appear in.
+    kept.sort_unstable();
+
+    let mut remapped = vec![None; values.len()];
+    if first == 1 {
+        remapped[0] = Some(NULL_CODE);
+    }
+    for (new_code, &code) in kept.iter().enumerate() {
+        remapped[code] = Some((first + new_code) as u64);
+    }
+
+    // The codes of patched values point at the first valid value, so that they're valid.
+    let placeholder = first as u64;
+    let mut new_codes = Vec::with_capacity(codes.len());
+    let mut patch_indices = Vec::new();
+    let mut patch_codes = Vec::new();
+    for (index, &code) in codes.iter().enumerate() {
+        match remapped[AsPrimitive::<usize>::as_(code)] {
+            Some(new_code) => new_codes.push(new_code),
+            None => {
+                new_codes.push(placeholder);
+                patch_indices.push(index as u64);
+                patch_codes.push(code);
+            }
+        }
+    }
+
+    let kept_codes = (0..first)
+        .chain(kept)
+        .map(|code| code as u64)
+        .collect::<Vec<_>>();
+    let patches = Patches::new(
+        codes.len(),
+        PrimitiveArray::from(patch_indices).into_array(),
+        take(&values, PrimitiveArray::from(patch_codes).into_array())?,
+    );
+    DictArray::try_new_with_patches(
+        PrimitiveArray::from(new_codes).into_array(),
+        take(&values, PrimitiveArray::from(kept_codes).into_array())?,
+        Some(patches),
+    )
+}
+
+pub(crate) fn dict_values_validity(nullable: bool, len: usize) -> Validity {
     if nullable {
         Validity::Array(
             SparseArray::try_new(
@@ -179,7 +256,7 @@ fn dict_values_validity(nullable: bool, len: usize) -> Validity {
     }
 }
 
-fn lookup_bytes<'a, T: AsPrimitive<usize>>(
+pub(crate) fn lookup_bytes<'a, T: AsPrimitive<usize>>(
     offsets: &'a [T],
     bytes: &'a [u8],
     idx: usize,
@@ -195,12 +272,13 @@ mod test {
 
     use vortex_array::accessor::ArrayAccessor;
     use vortex_array::array::{PrimitiveArray, VarBinArray};
-    use vortex_array::compute::scalar_at;
+    use vortex_array::compute::{scalar_at, slice, take};
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
     use vortex_dtype::Nullability::Nullable;
     use vortex_dtype::{DType, PType};
     use vortex_scalar::Scalar;
 
-    use crate::compress::{dict_encode_typed_primitive, dict_encode_varbin};
+    use crate::compress::{dict_encode_typed_primitive, dict_encode_varbin, dict_top_k};
 
     #[test]
     fn encode_primitive() {
@@ -302,4 +380,53 @@ mod test {
             &[0u64, 0, 1, 1, 0, 1, 0, 1]
         );
     }
+
+    #[test]
+    fn top_k_values() {
+        let strings = vec![
+            Some("a"),
+            Some("b"),
+            None,
+            Some("a"),
+            Some("c"),
+            Some("a"),
+            Some("b"),
+            Some("d"),
+        ];
+        let arr: VarBinArray = strings.clone().into_iter().collect();
+        let (codes, values) = dict_encode_varbin(&arr);
+        let dict = dict_top_k(&codes, values.into_array(), 2).unwrap();
+
+        // The null value and the two most frequent values are in the dictionary.
+        assert_eq!(dict.values().len(), 3);
+        let patches = dict.patches().unwrap();
+        assert_eq!(
+            patches
+                .indices()
+                .clone()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[4, 7]
+        );
+
+        let dict = dict.into_array();
+        let canonical = dict.clone().into_varbinview().unwrap();
+        canonical
+            .with_iterator(|iter| {
+                assert_eq!(
+                    iter.map(|b| b.map(|v| unsafe { str::from_utf8_unchecked(v) }))
+                        .collect::<Vec<_>>(),
+                    strings
+                );
+            })
+            .unwrap();
+
+        assert_eq!(scalar_at(&dict, 7).unwrap(), Scalar::utf8("d", Nullable));
+        let sliced = slice(&dict, 3, 6).unwrap();
+        assert_eq!(scalar_at(&sliced, 1).unwrap(), Scalar::utf8("c", Nullable));
+        let taken = take(&dict, PrimitiveArray::from(vec![7u32, 0]).into_array()).unwrap();
+        assert_eq!(scalar_at(&taken, 0).unwrap(), Scalar::utf8("d", Nullable));
+        assert_eq!(scalar_at(&taken, 1).unwrap(), Scalar::utf8("a", Nullable));
+    }
 }
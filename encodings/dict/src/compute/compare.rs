@@ -1,6 +1,7 @@
 use vortex_array::array::ConstantArray;
-use vortex_array::compute::{compare, take, CompareFn, Operator};
-use vortex_array::ArrayData;
+use vortex_array::compute::{compare, scalar_at, take, CompareFn, Operator};
+use vortex_array::stats::ArrayStatistics;
+use vortex_array::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
 use vortex_error::VortexResult;
 
 use crate::{DictArray, DictEncoding};
@@ -17,10 +18,36 @@ impl CompareFn<DictArray> for DictEncoding {
             // Ensure the other is the same length as the dictionary
             let compare_result = compare(
                 lhs.values(),
-                ConstantArray::new(const_scalar, lhs.values().len()),
+                ConstantArray::new(const_scalar.clone(), lhs.values().len()),
                 operator,
             )?;
-            return take(compare_result, lhs.codes()).map(Some);
+            let patches = lhs.patches();
+
+            // If every value compares the same, so does every code, and the codes needn't be read.
+            if patches.is_none()
+                && !compare_result.is_empty()
+                && compare_result.statistics().compute_is_constant() == Some(true)
+            {
+                return Ok(Some(
+                    ConstantArray::new(scalar_at(&compare_result, 0)?, lhs.len()).into_array(),
+                ));
+            }
+
+            let compared = take(compare_result, lhs.codes())?;
+            return match patches {
+                None => Ok(Some(compared)),
+                // The patched values are compared on their own and patched into the result.
+                Some(patches) => {
+                    let patches = patches.map_values(|values| {
+                        let len = values.len();
+                        compare(values, ConstantArray::new(const_scalar, len), operator)
+                    })?;
+                    compared
+                        .into_bool()?
+                        .patch(patches)
+                        .map(|a| Some(a.into_array()))
+                }
+            };
         }
 
         // It's a little more complex, but we could perform a comparison against the dictionary
@@ -28,3 +55,42 @@ impl CompareFn<DictArray> for DictEncoding {
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::{ConstantArray, PrimitiveArray};
+    use vortex_array::compute::{compare, Operator};
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+    use vortex_dtype::Nullability;
+    use vortex_scalar::Scalar;
+
+    use crate::DictArray;
+
+    #[test]
+    fn compare_constant_over_values() {
+        let dict = DictArray::try_new(
+            PrimitiveArray::from(vec![0u8, 1, 2, 1, 0]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+        )
+        .unwrap();
+
+        let compared = compare(dict.as_ref(), ConstantArray::new(10i32, 5), Operator::Gt).unwrap();
+        assert_eq!(compared.len(), 5);
+        assert_eq!(
+            compared.as_constant(),
+            Some(Scalar::bool(false, Nullability::NonNullable))
+        );
+
+        let compared = compare(dict.as_ref(), ConstantArray::new(2i32, 5), Operator::Gte).unwrap();
+        assert!(compared.as_constant().is_none());
+        assert_eq!(
+            compared
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![false, true, true, true, false]
+        );
+    }
+}
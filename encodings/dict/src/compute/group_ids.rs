@@ -0,0 +1,79 @@
+use num_traits::AsPrimitive;
+use vortex_array::compute::{group_ids, try_cast, GroupIds, GroupIdsFn};
+use vortex_array::{ArrayLen, IntoArrayVariant};
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::VortexResult;
+
+use crate::{DictArray, DictEncoding};
+
+impl GroupIdsFn<DictArray> for DictEncoding {
+    /// Group the rows by their codes, hashing only the values.
+    ///
+    /// Values are grouped too since they needn't be distinct, e.g. after a computation on them.
+    fn group_ids(&self, array: &DictArray) -> VortexResult<Option<GroupIds>> {
+        if array.patches().is_some() {
+            return Ok(None);
+        }
+
+        let value_groups = group_ids(array.values())?;
+        let codes = try_cast(
+            array.codes(),
+            &DType::Primitive(PType::U64, Nullability::NonNullable),
+        )?
+        .into_primitive()?;
+
+        let mut groups = vec![None; value_groups.ngroups()];
+        let mut ids = Vec::with_capacity(codes.len());
+        let mut first_rows = Vec::new();
+        for (row, &code) in codes.maybe_null_slice::<u64>().iter().enumerate() {
+            let group = &mut groups[value_groups.ids()[AsPrimitive::<usize>::as_(code)]];
+            let id = *group.get_or_insert_with(|| {
+                first_rows.push(row as u64);
+                first_rows.len() - 1
+            });
+            ids.push(id);
+        }
+        Ok(Some(GroupIds::new(ids, first_rows)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::{PrimitiveArray, VarBinViewArray};
+    use vortex_array::compute::{group_ids, groupby, Aggregate, AggregateFunction};
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+
+    use crate::DictArray;
+
+    #[test]
+    fn group_by_codes() {
+        // The values are not distinct, so rows with codes 0 and 2 are in the same group.
+        let dict = DictArray::try_new(
+            PrimitiveArray::from(vec![1u8, 0, 2, 1, 2]).into_array(),
+            VarBinViewArray::from_iter_str(["a", "b", "a"]).into_array(),
+        )
+        .unwrap()
+        .into_array();
+
+        let groups = group_ids(&dict).unwrap();
+        assert_eq!(groups.ids(), [0, 1, 1, 0, 1]);
+        assert_eq!(groups.first_rows(), [0, 1]);
+
+        let grouped = groupby(
+            &[dict],
+            &[Aggregate::new(
+                AggregateFunction::Sum,
+                PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]).into_array(),
+            )],
+        )
+        .unwrap();
+        assert_eq!(
+            grouped.aggregates()[0]
+                .clone()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i64>(),
+            [5, 10]
+        );
+    }
+}
@@ -12,6 +12,10 @@ impl LikeFn<DictArray> for DictEncoding {
         options: LikeOptions,
     ) -> VortexResult<ArrayData> {
         let values = like(&array.values(), pattern, options)?;
-        Ok(DictArray::try_new(array.codes(), values)?.into_array())
+        let patches = array
+            .patches()
+            .map(|p| p.map_values(|values| like(&values, pattern, options)))
+            .transpose()?;
+        Ok(DictArray::try_new_with_patches(array.codes(), values, patches)?.into_array())
     }
 }
@@ -0,0 +1,98 @@
+use arrow_buffer::BooleanBuffer;
+use num_traits::AsPrimitive;
+use vortex_array::compute::{filter, min_max, try_cast, FilterMask, MinMaxFn, MinMaxResult};
+use vortex_array::patches::Patches;
+use vortex_array::variants::PrimitiveArrayTrait;
+use vortex_array::IntoArrayVariant;
+use vortex_dtype::{match_each_unsigned_integer_ptype, DType, Nullability, PType};
+use vortex_error::VortexResult;
+
+use crate::{DictArray, DictEncoding};
+
+impl MinMaxFn<DictArray> for DictEncoding {
+    fn min_max(&self, array: &DictArray) -> VortexResult<Option<MinMaxResult>> {
+        let patches = array.patches();
+        let patched = patches.as_ref().map(patch_indices).transpose()?;
+
+        // Only the values that some unpatched code refers to are in the array.
+        let values = array.values();
+        let codes = array.codes().into_primitive()?;
+        let used = match_each_unsigned_integer_ptype!(codes.ptype(), |$C| {
+            used_values(
+                codes.maybe_null_slice::<$C>(),
+                values.len(),
+                patched.as_deref().unwrap_or_default(),
+            )
+        });
+        let values_min_max = min_max(filter(&values, FilterMask::from(used))?)?;
+
+        let patches_min_max = patches.map(|p| min_max(p.values())).transpose()?;
+        Ok(MinMaxResult::merge_opt(
+            values_min_max,
+            patches_min_max.flatten(),
+        ))
+    }
+}
+
+fn patch_indices(patches: &Patches) -> VortexResult<Vec<u64>> {
+    let u64_dtype = DType::Primitive(PType::U64, Nullability::NonNullable);
+    Ok(try_cast(patches.indices(), &u64_dtype)?
+        .into_primitive()?
+        .into_maybe_null_slice::<u64>())
+}
+
+/// Which of the values the codes refer to, ignoring the codes at the sorted patched indices.
+fn used_values<C: AsPrimitive<usize>>(
+    codes: &[C],
+    values_len: usize,
+    patched: &[u64],
+) -> BooleanBuffer {
+    let mut used = vec![false; values_len];
+    let mut patched = patched.iter().copied().peekable();
+    for (idx, code) in codes.iter().enumerate() {
+        if patched.next_if_eq(&(idx as u64)).is_none() {
+            used[code.as_()] = true;
+        }
+    }
+    BooleanBuffer::from(used)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::{PrimitiveArray, VarBinViewArray};
+    use vortex_array::compute::{min_max, slice};
+    use vortex_array::IntoArrayData;
+    use vortex_scalar::Scalar;
+
+    use crate::{dict_encode_primitive, dict_encode_varbinview, DictArray};
+
+    #[test]
+    fn min_max_of_used_values() {
+        let (codes, values) =
+            dict_encode_primitive(&PrimitiveArray::from(vec![5i32, 1, 9, 5, 3, 1]));
+        let dict = DictArray::try_new(codes.into_array(), values.into_array())
+            .unwrap()
+            .into_array();
+        let result = min_max(&dict).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(1i32));
+        assert_eq!(result.max, Scalar::from(9i32));
+
+        // The sliced codes no longer refer to the smallest and largest values.
+        let sliced = slice(&dict, 3, 5).unwrap();
+        let result = min_max(&sliced).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(3i32));
+        assert_eq!(result.max, Scalar::from(5i32));
+    }
+
+    #[test]
+    fn min_max_strings() {
+        let (codes, values) =
+            dict_encode_varbinview(&VarBinViewArray::from_iter_str(["b", "a", "c", "b"]));
+        let dict = DictArray::try_new(codes.into_array(), values.into_array())
+            .unwrap()
+            .into_array();
+        let result = min_max(slice(&dict, 1, 2).unwrap()).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from("a"));
+        assert_eq!(result.max, Scalar::from("a"));
+    }
+}
@@ -1,9 +1,12 @@
 mod compare;
+mod group_ids;
 mod like;
+mod min_max;
 
+use vortex_array::array::ConstantArray;
 use vortex_array::compute::{
     binary_numeric, filter, scalar_at, slice, take, BinaryNumericFn, CompareFn, ComputeVTable,
-    FilterFn, FilterMask, LikeFn, ScalarAtFn, SliceFn, TakeFn,
+    FilterFn, FilterMask, GroupIdsFn, LikeFn, MinMaxFn, ScalarAtFn, SliceFn, TakeFn,
 };
 use vortex_array::{ArrayData, IntoArrayData};
 use vortex_error::VortexResult;
@@ -24,10 +27,18 @@ impl ComputeVTable for DictEncoding {
         Some(self)
     }
 
+    fn group_ids_fn(&self) -> Option<&dyn GroupIdsFn<ArrayData>> {
+        Some(self)
+    }
+
     fn like_fn(&self) -> Option<&dyn LikeFn<ArrayData>> {
         Some(self)
     }
 
+    fn min_max_fn(&self) -> Option<&dyn MinMaxFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
@@ -48,11 +59,22 @@ impl BinaryNumericFn<DictArray> for DictEncoding {
         rhs: &ArrayData,
         op: BinaryNumericOperator,
     ) -> VortexResult<Option<ArrayData>> {
-        if !rhs.is_constant() {
+        let Some(rhs) = rhs.as_constant() else {
             return Ok(None);
-        }
+        };
+        let apply = |values: &ArrayData| {
+            binary_numeric(
+                values,
+                &ConstantArray::new(rhs.clone(), values.len()).into_array(),
+                op,
+            )
+        };
 
-        DictArray::try_new(array.codes(), binary_numeric(&array.values(), rhs, op)?)
+        let patches = array
+            .patches()
+            .map(|p| p.map_values(|values| apply(&values)))
+            .transpose()?;
+        DictArray::try_new_with_patches(array.codes(), apply(&array.values())?, patches)
             .map(IntoArrayData::into_array)
             .map(Some)
     }
@@ -60,6 +82,12 @@ impl BinaryNumericFn<DictArray> for DictEncoding {
 
 impl ScalarAtFn<DictArray> for DictEncoding {
     fn scalar_at(&self, array: &DictArray, index: usize) -> VortexResult<Scalar> {
+        if let Some(patches) = array.patches() {
+            if let Some(patch) = patches.get_patched(index)? {
+                return Ok(patch);
+            }
+        }
+
         let dict_index: usize = scalar_at(array.codes(), index)?.as_ref().try_into()?;
         scalar_at(array.values(), dict_index)
     }
@@ -71,21 +99,38 @@ impl TakeFn<DictArray> for DictEncoding {
         //   codes: 0 0 1
         //   dict: a b c d e f g h
         let codes = take(array.codes(), indices)?;
-        DictArray::try_new(codes, array.values()).map(|a| a.into_array())
+        let patches = array
+            .patches()
+            .map(|p| p.take(indices))
+            .transpose()?
+            .flatten();
+        DictArray::try_new_with_patches(codes, array.values(), patches)
+            .map(IntoArrayData::into_array)
     }
 }
 
 impl FilterFn<DictArray> for DictEncoding {
     fn filter(&self, array: &DictArray, mask: FilterMask) -> VortexResult<ArrayData> {
+        let patches = array
+            .patches()
+            .map(|p| p.filter(mask.clone()))
+            .transpose()?
+            .flatten();
         let codes = filter(&array.codes(), mask)?;
-        DictArray::try_new(codes, array.values()).map(|a| a.into_array())
+        DictArray::try_new_with_patches(codes, array.values(), patches)
+            .map(IntoArrayData::into_array)
     }
 }
 
 impl SliceFn<DictArray> for DictEncoding {
     // TODO(robert): Add function to trim the dictionary
     fn slice(&self, array: &DictArray, start: usize, stop: usize) -> VortexResult<ArrayData> {
-        DictArray::try_new(slice(array.codes(), start, stop)?, array.values())
+        let patches = array
+            .patches()
+            .map(|p| p.slice(start, stop))
+            .transpose()?
+            .flatten();
+        DictArray::try_new_with_patches(slice(array.codes(), start, stop)?, array.values(), patches)
             .map(|a| a.into_array())
     }
 }
@@ -94,7 +139,9 @@ impl SliceFn<DictArray> for DictEncoding {
 mod test {
     use vortex_array::accessor::ArrayAccessor;
     use vortex_array::array::{ConstantArray, PrimitiveArray, VarBinViewArray};
-    use vortex_array::compute::{compare, scalar_at, slice, Operator};
+    use vortex_array::compute::{
+        array_eq, compare, filter, scalar_at, slice, take, FilterMask, Operator,
+    };
     use vortex_array::{ArrayLen, IntoArrayData, IntoArrayVariant, ToArrayData};
     use vortex_dtype::{DType, Nullability};
     use vortex_scalar::Scalar;
@@ -143,6 +190,76 @@ mod test {
         );
     }
 
+    #[test]
+    fn take_keeps_values() {
+        let reference = PrimitiveArray::from((0..100).map(|i| i % 10).collect::<Vec<i32>>());
+        let (codes, values) = dict_encode_primitive(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+
+        let taken = take(dict.as_ref(), PrimitiveArray::from(vec![3u64, 13, 7])).unwrap();
+        assert_eq!(
+            DictArray::try_from(taken.clone()).unwrap().values().len(),
+            10
+        );
+        assert_eq!(
+            taken.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            [3, 3, 7]
+        );
+    }
+
+    #[test]
+    fn take_prunes_unused_values() {
+        let reference = PrimitiveArray::from((0..100).map(|i| i % 10).collect::<Vec<i32>>());
+        let (codes, values) = dict_encode_primitive(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+
+        let taken = dict
+            .take_pruned(PrimitiveArray::from(vec![3u64, 13, 7]).as_ref(), 0.5)
+            .unwrap();
+        assert_eq!(taken.values().len(), 2);
+        assert_eq!(
+            taken.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            [3, 3, 7]
+        );
+
+        // Half of the values are still used after taking every other element
+        let every_other = (0..100u64).step_by(2).collect::<Vec<_>>();
+        let taken = dict
+            .take_pruned(PrimitiveArray::from(every_other).as_ref(), 0.5)
+            .unwrap();
+        assert_eq!(taken.values().len(), 10);
+    }
+
+    #[test]
+    fn filter_prunes_unused_values() {
+        let reference = PrimitiveArray::from_nullable_vec(
+            (0..20)
+                .map(|i| (i % 5 != 0).then_some(i % 10))
+                .collect::<Vec<Option<i32>>>(),
+        );
+        let (codes, values) = dict_encode_primitive(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let values_len = dict.values().len();
+
+        let mask = FilterMask::from_iter((0..20).map(|i| i < 3));
+        let filtered = filter(dict.as_ref(), mask.clone()).unwrap();
+        assert_eq!(
+            DictArray::try_from(filtered)
+                .unwrap()
+                .values()
+                .len(),
+            values_len
+        );
+
+        let pruned = dict.filter_pruned(mask.clone(), 0.5).unwrap();
+        assert!(pruned.values().len() < values_len);
+        assert!(array_eq(
+            pruned.into_array(),
+            filter(reference.as_ref(), mask).unwrap()
+        )
+        .unwrap());
+    }
+
     #[test]
     fn compare_sliced_dict() {
         let reference = PrimitiveArray::from_nullable_vec(vec![
@@ -1,12 +1,22 @@
+use num_traits::AsPrimitive;
 use vortex_array::stats::{ArrayStatistics, Stat, StatisticsVTable, StatsSet};
+use vortex_array::variants::PrimitiveArrayTrait;
+use vortex_array::{ArrayDType, IntoArrayVariant};
+use vortex_dtype::match_each_integer_ptype;
 use vortex_error::VortexResult;
 
+use crate::compress::NULL_CODE;
 use crate::{DictArray, DictEncoding};
 
 impl StatisticsVTable<DictArray> for DictEncoding {
     fn compute_statistics(&self, array: &DictArray, stat: Stat) -> VortexResult<StatsSet> {
         let mut stats = StatsSet::default();
 
+        // Patched values are always valid, but otherwise neither in the codes nor in the values.
+        if array.patches().is_some() && stat != Stat::NullCount {
+            return Ok(stats);
+        }
+
         match stat {
             Stat::RunCount => {
                 if let Some(rc) = array.codes().statistics().compute(Stat::RunCount) {
@@ -23,15 +33,18 @@ impl StatisticsVTable<DictArray> for DictEncoding {
                     stats.set(Stat::Max, max);
                 }
             }
+            Stat::HasNaN => {
+                if let Some(has_nan) = array.values().statistics().compute(Stat::HasNaN) {
+                    stats.set(Stat::HasNaN, has_nan);
+                }
+            }
             Stat::IsConstant => {
                 if let Some(is_constant) = array.codes().statistics().compute(Stat::IsConstant) {
                     stats.set(Stat::IsConstant, is_constant);
                 }
             }
             Stat::NullCount => {
-                if let Some(null_count) = array.codes().statistics().compute(Stat::NullCount) {
-                    stats.set(Stat::NullCount, null_count);
-                }
+                stats.set(Stat::NullCount, null_count(array)?);
             }
             Stat::IsSorted | Stat::IsStrictSorted => {
                 // if dictionary is sorted
@@ -60,3 +73,47 @@ impl StatisticsVTable<DictArray> for DictEncoding {
         Ok(stats)
     }
 }
+
+/// The number of codes pointing at the null value of a nullable dictionary.
+fn null_count(array: &DictArray) -> VortexResult<usize> {
+    let codes = array.codes();
+    if !array.dtype().is_nullable()
+        || codes
+            .statistics()
+            .compute_as_cast::<u64>(Stat::Min)
+            .is_some_and(|min| min != NULL_CODE)
+    {
+        return Ok(0);
+    }
+
+    let codes = codes.into_primitive()?;
+    Ok(match_each_integer_ptype!(codes.ptype(), |$P| {
+        codes
+            .maybe_null_slice::<$P>()
+            .iter()
+            .filter(|&&code| AsPrimitive::<u64>::as_(code) == NULL_CODE)
+            .count()
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::PrimitiveArray;
+    use vortex_array::stats::ArrayStatistics;
+    use vortex_array::IntoArrayData;
+
+    use crate::DictArray;
+
+    #[test]
+    fn null_count() {
+        let values = PrimitiveArray::from_nullable_vec(vec![None, Some(1i32), Some(3)]);
+        let codes = PrimitiveArray::from(vec![1u32, 0, 2, 0, 2]);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        assert_eq!(dict.statistics().compute_null_count(), Some(2));
+
+        let codes = PrimitiveArray::from(vec![1u32, 2, 2]);
+        let values = PrimitiveArray::from_nullable_vec(vec![None, Some(1i32), Some(3)]);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        assert_eq!(dict.statistics().compute_null_count(), Some(0));
+    }
+}
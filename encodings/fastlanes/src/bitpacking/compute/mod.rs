@@ -1,4 +1,6 @@
-use vortex_array::compute::{ComputeVTable, FilterFn, ScalarAtFn, SearchSortedFn, SliceFn, TakeFn};
+use vortex_array::compute::{
+    ComputeVTable, FilterFn, ScalarAtFn, SearchSortedFn, SliceFn, SumFn, TakeFn,
+};
 use vortex_array::ArrayData;
 
 use crate::BitPackedEncoding;
@@ -7,6 +9,7 @@ mod filter;
 mod scalar_at;
 mod search_sorted;
 mod slice;
+mod sum;
 mod take;
 
 impl ComputeVTable for BitPackedEncoding {
@@ -26,6 +29,10 @@ impl ComputeVTable for BitPackedEncoding {
         Some(self)
     }
 
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        Some(self)
+    }
+
     fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
         Some(self)
     }
@@ -0,0 +1,127 @@
+use fastlanes::BitPacking;
+use num_traits::ToPrimitive;
+use vortex_array::compute::{sum, SumAccumulator, SumFn};
+use vortex_array::patches::Patches;
+use vortex_array::validity::ArrayValidity;
+use vortex_array::variants::PrimitiveArrayTrait;
+use vortex_array::{ArrayLen, IntoArrayVariant};
+use vortex_dtype::{match_each_integer_ptype, match_each_unsigned_integer_ptype, NativePType};
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::{BitPackedArray, BitPackedEncoding};
+
+impl SumFn<BitPackedArray> for BitPackedEncoding {
+    fn sum(&self, array: &BitPackedArray) -> VortexResult<Scalar> {
+        let patches = array.patches();
+        let patch_indices = patches
+            .as_ref()
+            .map(patch_indices)
+            .transpose()?
+            .unwrap_or_default();
+
+        let mut acc = SumAccumulator::new(array.ptype());
+        match_each_unsigned_integer_ptype!(array.ptype().to_unsigned(), |$T| {
+            sum_unpatched::<$T>(array, &patch_indices, &mut acc)?
+        });
+        if let Some(patches) = patches {
+            acc.add_scalar(&sum(patches.values())?, 1)?;
+        }
+        Ok(acc.finish())
+    }
+}
+
+fn patch_indices(patches: &Patches) -> VortexResult<Vec<usize>> {
+    let indices = patches.indices().clone().into_primitive()?;
+    match_each_integer_ptype!(indices.ptype(), |$I| {
+        indices
+            .maybe_null_slice::<$I>()
+            .iter()
+            .map(|&idx| {
+                idx.to_usize()
+                    .ok_or_else(|| vortex_err!("Patch index {idx} does not fit in usize"))
+            })
+            .collect()
+    })
+}
+
+/// Sum the valid values that are not patched, unpacking one FastLanes chunk at a time.
+///
+/// Bit-packed values are never negative, so the unsigned `T` can be summed in place of the
+/// logical type of a signed array.
+fn sum_unpatched<T: NativePType + BitPacking>(
+    array: &BitPackedArray,
+    patch_indices: &[usize],
+    acc: &mut SumAccumulator,
+) -> VortexResult<()> {
+    let validity = array.logical_validity().to_null_buffer()?;
+    let offset = array.offset() as usize;
+    let len = array.len();
+    let bit_width = array.bit_width() as usize;
+    let packed = array.packed_slice::<T>();
+    let elems_per_chunk = 128 * bit_width / size_of::<T>();
+
+    // Patched values are summed separately, so the sorted patch indices are skipped as we go.
+    let mut patch_indices = patch_indices.iter().copied().peekable();
+    let mut unpacked = [T::zero(); 1024];
+    for chunk_idx in 0..(offset + len).div_ceil(1024) {
+        if bit_width > 0 {
+            let chunk = &packed[chunk_idx * elems_per_chunk..][..elems_per_chunk];
+            unsafe { BitPacking::unchecked_unpack(bit_width, chunk, &mut unpacked) };
+        }
+
+        // The logical indices covered by this chunk.
+        let chunk_offset = chunk_idx * 1024;
+        let start = chunk_offset.saturating_sub(offset);
+        let end = (chunk_offset + 1024 - offset).min(len);
+        acc.add_values((start..end).filter_map(|idx| {
+            if patch_indices.next_if_eq(&idx).is_some() {
+                return None;
+            }
+            validity
+                .as_ref()
+                .map_or(true, |v| v.is_valid(idx))
+                .then(|| unpacked[idx + offset - chunk_offset])
+        }))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::PrimitiveArray;
+    use vortex_array::compute::{slice, sum};
+    use vortex_array::IntoArrayVariant;
+    use vortex_scalar::Scalar;
+
+    use crate::BitPackedArray;
+
+    #[test]
+    fn sum_with_patches() {
+        let values = (0u32..3000).map(|i| if i % 1000 == 7 { 1 << 20 } else { i % 64 });
+        let unpacked = PrimitiveArray::from_nullable_vec(
+            values
+                .enumerate()
+                .map(|(i, v)| (i % 5 != 0).then_some(v))
+                .collect(),
+        );
+        let packed = BitPackedArray::encode(unpacked.as_ref(), 6).unwrap();
+        assert!(packed.patches().is_some());
+
+        assert_eq!(sum(&packed).unwrap(), sum(&unpacked).unwrap());
+
+        let sliced = slice(&packed, 1001, 2600).unwrap();
+        let expected = slice(&unpacked, 1001, 2600)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(sum(&sliced).unwrap(), sum(&expected).unwrap());
+    }
+
+    #[test]
+    fn sum_signed() {
+        let unpacked = PrimitiveArray::from((0i16..100).collect::<Vec<_>>());
+        let packed = BitPackedArray::encode(unpacked.as_ref(), 7).unwrap();
+        assert_eq!(sum(&packed).unwrap(), Scalar::from(Some(4950i64)));
+    }
+}
@@ -1,18 +1,20 @@
 use std::fmt::{Debug, Display};
 use std::sync::Arc;
 
-use ::serde::{Deserialize, Serialize};
+use ::serde::Deserialize;
 pub use compress::*;
 use fastlanes::BitPacking;
 use vortex_array::array::PrimitiveArray;
 use vortex_array::encoding::ids;
 use vortex_array::patches::{Patches, PatchesMetadata};
-use vortex_array::stats::{StatisticsVTable, StatsSet};
+use vortex_array::stats::{Stat, StatisticsVTable, StatsSet};
 use vortex_array::validity::{LogicalValidity, Validity, ValidityMetadata, ValidityVTable};
 use vortex_array::variants::{PrimitiveArrayTrait, VariantsVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
 use vortex_array::{
-    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoCanonical,
+    flexbuffers_root, impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical,
+    IntoCanonical, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
 };
 use vortex_buffer::Buffer;
 use vortex_dtype::{DType, NativePType, PType};
@@ -23,7 +25,10 @@ mod compute;
 
 impl_encoding!("fastlanes.bitpacked", ids::FL_BITPACKED, BitPacked);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the validity, the bit width, the offset as a
+/// u16 and the optional patches.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct BitPackedMetadata {
     validity: ValidityMetadata,
     bit_width: u8,
@@ -37,6 +42,37 @@ impl Display for BitPackedMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for BitPackedMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        self.validity.write_to(&mut writer);
+        writer.put_u8(self.bit_width).put_u16(self.offset);
+        PatchesMetadata::write_option_to(self.patches.as_ref(), &mut writer);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for BitPackedMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let validity = ValidityMetadata::read_from(&mut reader)?;
+        let bit_width = reader.get_u8()?;
+        let offset = reader.get_u16()?;
+        let patches = PatchesMetadata::read_option_from(&mut reader)?;
+        reader.finish()?;
+        Ok(Self {
+            validity,
+            bit_width,
+            offset,
+            patches,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 /// NB: All non-null values in the patches array are considered patches
 impl BitPackedArray {
     /// Create a new bitpacked array using a buffer of packed data.
@@ -245,7 +281,10 @@ impl ValidityVTable<BitPackedArray> for BitPackedEncoding {
     }
 
     fn logical_validity(&self, array: &BitPackedArray) -> LogicalValidity {
-        array.validity().to_logical(array.len())
+        array
+            .metadata()
+            .validity
+            .to_logical(array.len(), || array.validity())
     }
 }
 
@@ -259,7 +298,20 @@ impl VisitorVTable<BitPackedArray> for BitPackedEncoding {
     }
 }
 
-impl StatisticsVTable<BitPackedArray> for BitPackedEncoding {}
+impl StatisticsVTable<BitPackedArray> for BitPackedEncoding {
+    fn compute_statistics(&self, array: &BitPackedArray, stat: Stat) -> VortexResult<StatsSet> {
+        Ok(match stat {
+            Stat::NullCount => StatsSet::of(
+                stat,
+                array
+                    .metadata()
+                    .validity
+                    .null_count(array.len(), || array.validity())?,
+            ),
+            _ => StatsSet::default(),
+        })
+    }
+}
 
 impl ArrayTrait for BitPackedArray {}
 
@@ -277,9 +329,34 @@ impl PrimitiveArrayTrait for BitPackedArray {}
 #[cfg(test)]
 mod test {
     use vortex_array::array::PrimitiveArray;
-    use vortex_array::{IntoArrayData, IntoArrayVariant};
+    use vortex_array::stats::ArrayStatistics;
+    use vortex_array::{IntoArrayData, IntoArrayVariant, MetadataFormat, MetadataVTable};
 
-    use crate::BitPackedArray;
+    use crate::{BitPackedArray, BitPackedEncoding};
+
+    #[test]
+    fn metadata_layout() {
+        let packed = BitPackedArray::encode(
+            PrimitiveArray::from(vec![1u32, 0, 1, 0, 1, 0, 7]).as_ref(),
+            1,
+        )
+        .unwrap()
+        .into_array();
+        let bytes = packed.metadata_bytes().unwrap();
+        // The layout is released, so change it by adding a version rather than editing the bytes.
+        assert_eq!(
+            bytes.as_ref(),
+            &[0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 3]
+        );
+
+        let metadata = BitPackedEncoding
+            .load_metadata(Some(&bytes), MetadataFormat::FixedLayout)
+            .unwrap();
+        assert_eq!(
+            metadata.to_string(),
+            "BitPackedMetadata { validity: NonNullable, bit_width: 1, offset: 0, patches: Some(PatchesMetadata { len: 1, indices_ptype: U64 }) }"
+        );
+    }
 
     #[test]
     fn test_encode() {
@@ -305,4 +382,12 @@ mod test {
         let _packed = BitPackedArray::encode(uncompressed.as_ref(), 9)
             .expect_err("Cannot pack value into larger width");
     }
+
+    #[test]
+    fn test_null_count() {
+        let values = vec![Some(1u32), None, Some(1), None, Some(u32::MAX), None];
+        let uncompressed = PrimitiveArray::from_nullable_vec(values);
+        let packed = BitPackedArray::encode(uncompressed.as_ref(), 1).unwrap();
+        assert_eq!(packed.statistics().compute_null_count(), Some(3));
+    }
 }
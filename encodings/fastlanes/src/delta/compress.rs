@@ -165,7 +165,8 @@ where
 
 #[cfg(test)]
 mod test {
-    use vortex_array::ArrayLen;
+    use vortex_array::stats::ArrayStatistics;
+    use vortex_array::{ArrayLen, IntoArrayData};
 
     use super::*;
 
@@ -193,4 +194,19 @@ mod test {
             assert_eq!(actual, &expected);
         }
     }
+
+    #[test]
+    fn test_null_count() {
+        let array = PrimitiveArray::from_nullable_vec(
+            (0u32..2048).map(|i| (i % 3 != 0).then_some(i)).collect(),
+        );
+        let (bases, deltas) = delta_compress(&array).unwrap();
+        let delta = DeltaArray::try_from_delta_compress_parts(
+            bases.into_array(),
+            deltas.into_array(),
+            array.validity(),
+        )
+        .unwrap();
+        assert_eq!(delta.statistics().compute_null_count(), Some(683));
+    }
 }
@@ -1,16 +1,18 @@
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
 pub use compress::*;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_array::array::PrimitiveArray;
 use vortex_array::encoding::ids;
-use vortex_array::stats::{StatisticsVTable, StatsSet};
+use vortex_array::stats::{Stat, StatisticsVTable, StatsSet};
 use vortex_array::validity::{LogicalValidity, Validity, ValidityMetadata, ValidityVTable};
 use vortex_array::variants::{PrimitiveArrayTrait, VariantsVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
 use vortex_array::{
-    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoArrayData,
-    IntoCanonical,
+    flexbuffers_root, impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical,
+    IntoArrayData, IntoCanonical, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
 };
 use vortex_dtype::{match_each_unsigned_integer_ptype, NativePType};
 use vortex_error::{vortex_bail, vortex_panic, VortexExpect as _, VortexResult};
@@ -20,7 +22,10 @@ mod compute;
 
 impl_encoding!("fastlanes.delta", ids::FL_DELTA, Delta);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the validity, the length of the deltas as a u64
+/// and the offset as a u16.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct DeltaMetadata {
     validity: ValidityMetadata,
     deltas_len: u64,
@@ -33,6 +38,34 @@ impl Display for DeltaMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for DeltaMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        self.validity.write_to(&mut writer);
+        writer.put_u64(self.deltas_len).put_u16(self.offset);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for DeltaMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let validity = ValidityMetadata::read_from(&mut reader)?;
+        let deltas_len = reader.get_u64()?;
+        let offset = reader.get_u16()?;
+        reader.finish()?;
+        Ok(Self {
+            validity,
+            deltas_len,
+            offset,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 /// A FastLanes-style delta-encoded array of primitive values.
 ///
 /// A [`DeltaArray`] comprises a sequence of _chunks_ each representing 1,024 delta-encoded values,
@@ -238,7 +271,10 @@ impl ValidityVTable<DeltaArray> for DeltaEncoding {
     }
 
     fn logical_validity(&self, array: &DeltaArray) -> LogicalValidity {
-        array.validity().to_logical(array.len())
+        array
+            .metadata()
+            .validity
+            .to_logical(array.len(), || array.validity())
     }
 }
 
@@ -249,4 +285,17 @@ impl VisitorVTable<DeltaArray> for DeltaEncoding {
     }
 }
 
-impl StatisticsVTable<DeltaArray> for DeltaEncoding {}
+impl StatisticsVTable<DeltaArray> for DeltaEncoding {
+    fn compute_statistics(&self, array: &DeltaArray, stat: Stat) -> VortexResult<StatsSet> {
+        Ok(match stat {
+            Stat::NullCount => StatsSet::of(
+                stat,
+                array
+                    .metadata()
+                    .validity
+                    .null_count(array.len(), || array.validity())?,
+            ),
+            _ => StatsSet::default(),
+        })
+    }
+}
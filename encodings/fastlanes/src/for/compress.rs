@@ -273,4 +273,17 @@ mod test {
                 );
             });
     }
+
+    #[test]
+    fn test_null_count() {
+        let array = PrimitiveArray::from_nullable_vec(vec![
+            Some(1_000_000u32),
+            None,
+            Some(1_000_010),
+            None,
+            None,
+        ]);
+        let compressed = for_compress(&array).unwrap();
+        assert_eq!(compressed.statistics().compute_null_count(), Some(3));
+    }
 }
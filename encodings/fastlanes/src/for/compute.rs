@@ -2,8 +2,9 @@ use std::ops::AddAssign;
 
 use num_traits::{CheckedShl, CheckedShr, WrappingAdd, WrappingSub};
 use vortex_array::compute::{
-    filter, scalar_at, search_sorted, slice, take, ComputeVTable, FilterFn, FilterMask, ScalarAtFn,
-    SearchResult, SearchSortedFn, SearchSortedSide, SliceFn, TakeFn,
+    filter, min_max, scalar_at, search_sorted, slice, take, ComputeVTable, FilterFn, FilterMask,
+    MinMaxFn, MinMaxResult, ScalarAtFn, SearchResult, SearchSortedFn, SearchSortedSide, SliceFn,
+    TakeFn,
 };
 use vortex_array::variants::PrimitiveArrayTrait;
 use vortex_array::{ArrayDType, ArrayData, IntoArrayData};
@@ -18,6 +19,10 @@ impl ComputeVTable for FoREncoding {
         Some(self)
     }
 
+    fn min_max_fn(&self) -> Option<&dyn MinMaxFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
@@ -59,27 +64,44 @@ impl FilterFn<FoRArray> for FoREncoding {
 
 impl ScalarAtFn<FoRArray> for FoREncoding {
     fn scalar_at(&self, array: &FoRArray, index: usize) -> VortexResult<Scalar> {
-        let encoded_pvalue = scalar_at(array.encoded(), index)?.reinterpret_cast(array.ptype());
-        let encoded_pvalue = encoded_pvalue.as_primitive();
-        let reference = array.reference_scalar();
-        let reference = reference.as_primitive();
+        Ok(decode_scalar(array, &scalar_at(array.encoded(), index)?))
+    }
+}
 
-        Ok(match_each_integer_ptype!(array.ptype(), |$P| {
-            encoded_pvalue
-                .typed_value::<$P>()
-                .map(|v|
-                     v.checked_shl(array.shift() as u32)
-                     .unwrap_or_default()
-                     .wrapping_add(
-                         reference
-                             .typed_value::<$P>()
-                             .vortex_expect("FoRArray Reference value cannot be null")))
-                .map(|v| Scalar::primitive::<$P>(v, array.dtype().nullability()))
-                .unwrap_or_else(|| Scalar::null(array.dtype().clone()))
-        }))
+impl MinMaxFn<FoRArray> for FoREncoding {
+    fn min_max(&self, array: &FoRArray) -> VortexResult<Option<MinMaxResult>> {
+        // Decoding preserves the order of the encoded values, which are offsets from the reference.
+        Ok(
+            min_max(array.encoded())?.map(|MinMaxResult { min, max }| MinMaxResult {
+                min: decode_scalar(array, &min),
+                max: decode_scalar(array, &max),
+            }),
+        )
     }
 }
 
+/// Decode a scalar of the encoded child into a value of the array.
+fn decode_scalar(array: &FoRArray, encoded: &Scalar) -> Scalar {
+    let encoded_pvalue = encoded.reinterpret_cast(array.ptype());
+    let encoded_pvalue = encoded_pvalue.as_primitive();
+    let reference = array.reference_scalar();
+    let reference = reference.as_primitive();
+
+    match_each_integer_ptype!(array.ptype(), |$P| {
+        encoded_pvalue
+            .typed_value::<$P>()
+            .map(|v|
+                 v.checked_shl(array.shift() as u32)
+                 .unwrap_or_default()
+                 .wrapping_add(
+                     reference
+                         .typed_value::<$P>()
+                         .vortex_expect("FoRArray Reference value cannot be null")))
+            .map(|v| Scalar::primitive::<$P>(v, array.dtype().nullability()))
+            .unwrap_or_else(|| Scalar::null(array.dtype().clone()))
+    })
+}
+
 impl SliceFn<FoRArray> for FoREncoding {
     fn slice(&self, array: &FoRArray, start: usize, stop: usize) -> VortexResult<ArrayData> {
         FoRArray::try_new(
@@ -172,8 +194,11 @@ where
 #[cfg(test)]
 mod test {
     use vortex_array::array::PrimitiveArray;
-    use vortex_array::compute::{scalar_at, search_sorted, SearchResult, SearchSortedSide};
+    use vortex_array::compute::{
+        min_max, scalar_at, search_sorted, slice, SearchResult, SearchSortedSide,
+    };
     use vortex_array::IntoArrayData;
+    use vortex_scalar::Scalar;
 
     use crate::{for_compress, FoRArray};
 
@@ -186,6 +211,21 @@ mod test {
         assert_eq!(scalar_at(&for_arr, 3).unwrap(), 1900.into());
     }
 
+    #[test]
+    fn for_min_max() {
+        let for_arr = for_compress(&PrimitiveArray::from(vec![-100i32, 1900, 1500, 1100]))
+            .unwrap()
+            .into_array();
+        let result = min_max(&for_arr).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(-100i32));
+        assert_eq!(result.max, Scalar::from(1900i32));
+
+        let sliced = slice(&for_arr, 2, 4).unwrap();
+        let result = min_max(&sliced).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(1100i32));
+        assert_eq!(result.max, Scalar::from(1500i32));
+    }
+
     #[test]
     fn for_search() {
         let for_arr = for_compress(&PrimitiveArray::from(vec![1100, 1500, 1900]))
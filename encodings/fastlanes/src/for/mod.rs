@@ -1,14 +1,17 @@
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
 pub use compress::*;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_array::encoding::ids;
-use vortex_array::stats::{StatisticsVTable, StatsSet};
+use vortex_array::stats::{ArrayStatistics, Stat, StatisticsVTable, StatsSet};
 use vortex_array::validity::{ArrayValidity, LogicalValidity, ValidityVTable};
 use vortex_array::variants::{PrimitiveArrayTrait, VariantsVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
 use vortex_array::{
-    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoCanonical,
+    flexbuffers_root, impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical,
+    IntoCanonical, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
 };
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, VortexExpect as _, VortexResult};
@@ -19,7 +22,9 @@ mod compute;
 
 impl_encoding!("fastlanes.for", ids::FL_FOR, FoR);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the reference value and the shift.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct FoRMetadata {
     reference: ScalarValue,
     shift: u8,
@@ -31,6 +36,28 @@ impl Display for FoRMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for FoRMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        writer.put_scalar_value(&self.reference)?.put_u8(self.shift);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for FoRMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let reference = reader.get_scalar_value()?;
+        let shift = reader.get_u8()?;
+        reader.finish()?;
+        Ok(Self { reference, shift })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl FoRArray {
     pub fn try_new(child: ArrayData, reference: Scalar, shift: u8) -> VortexResult<Self> {
         if reference.is_null() {
@@ -100,7 +127,20 @@ impl VisitorVTable<FoRArray> for FoREncoding {
     }
 }
 
-impl StatisticsVTable<FoRArray> for FoREncoding {}
+impl StatisticsVTable<FoRArray> for FoREncoding {
+    fn compute_statistics(&self, array: &FoRArray, stat: Stat) -> VortexResult<StatsSet> {
+        // The encoded values are null wherever the array is.
+        Ok(match stat {
+            Stat::NullCount => array
+                .encoded()
+                .statistics()
+                .compute(stat)
+                .map(|null_count| StatsSet::of(stat, null_count))
+                .unwrap_or_default(),
+            _ => StatsSet::default(),
+        })
+    }
+}
 
 impl ArrayTrait for FoRArray {}
 
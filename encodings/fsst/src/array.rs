@@ -2,14 +2,17 @@ use std::fmt::{Debug, Display};
 use std::sync::Arc;
 
 use fsst::{Decompressor, Symbol};
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_array::array::{VarBinArray, VarBinEncoding};
 use vortex_array::encoding::{ids, Encoding};
-use vortex_array::stats::{StatisticsVTable, StatsSet};
+use vortex_array::stats::{ArrayStatistics, Stat, StatisticsVTable, StatsSet};
 use vortex_array::validity::{ArrayValidity, LogicalValidity, Validity, ValidityVTable};
 use vortex_array::variants::{BinaryArrayTrait, Utf8ArrayTrait, VariantsVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
-use vortex_array::{impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, IntoCanonical};
+use vortex_array::{
+    flexbuffers_root, impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, IntoCanonical,
+    MetadataReader, MetadataWriter, TryDeserializeArrayMetadata, TrySerializeArrayMetadata,
+};
 use vortex_dtype::{DType, Nullability, PType};
 use vortex_error::{vortex_bail, VortexExpect, VortexResult};
 
@@ -18,7 +21,10 @@ impl_encoding!("vortex.fsst", ids::FSST, FSST);
 static SYMBOLS_DTYPE: DType = DType::Primitive(PType::U64, Nullability::NonNullable);
 static SYMBOL_LENS_DTYPE: DType = DType::Primitive(PType::U8, Nullability::NonNullable);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the number of symbols as a u64, whether the
+/// codes are nullable and the ptype of the uncompressed lengths.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct FSSTMetadata {
     symbols_len: usize,
     codes_nullability: Nullability,
@@ -31,6 +37,36 @@ impl Display for FSSTMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for FSSTMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        writer
+            .put_usize(self.symbols_len)
+            .put_bool(self.codes_nullability.into())
+            .put_ptype(self.uncompressed_lengths_ptype);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for FSSTMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let symbols_len = reader.get_usize()?;
+        let codes_nullability = Nullability::from(reader.get_bool()?);
+        let uncompressed_lengths_ptype = reader.get_ptype()?;
+        reader.finish()?;
+        Ok(Self {
+            symbols_len,
+            codes_nullability,
+            uncompressed_lengths_ptype,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl FSSTArray {
     /// Build an FSST array from a set of `symbols` and `codes`.
     ///
@@ -198,7 +234,20 @@ impl VisitorVTable<FSSTArray> for FSSTEncoding {
     }
 }
 
-impl StatisticsVTable<FSSTArray> for FSSTEncoding {}
+impl StatisticsVTable<FSSTArray> for FSSTEncoding {
+    fn compute_statistics(&self, array: &FSSTArray, stat: Stat) -> VortexResult<StatsSet> {
+        // The codes hold the validity of the array.
+        Ok(match stat {
+            Stat::NullCount => array
+                .codes()
+                .statistics()
+                .compute(stat)
+                .map(|null_count| StatsSet::of(stat, null_count))
+                .unwrap_or_default(),
+            _ => StatsSet::default(),
+        })
+    }
+}
 
 impl ValidityVTable<FSSTArray> for FSSTEncoding {
     fn is_valid(&self, array: &FSSTArray, index: usize) -> bool {
@@ -4,6 +4,7 @@ use vortex_array::array::builder::VarBinBuilder;
 use vortex_array::array::PrimitiveArray;
 use vortex_array::compute::{filter, scalar_at, slice, take, FilterMask};
 use vortex_array::encoding::Encoding;
+use vortex_array::stats::ArrayStatistics;
 use vortex_array::validity::Validity;
 use vortex_array::{ArrayData, IntoArrayData, IntoCanonical};
 use vortex_dtype::{DType, Nullability};
@@ -114,3 +115,19 @@ fn test_fsst_array_ops() {
         );
     }
 }
+
+#[test]
+fn test_fsst_null_count() {
+    let mut input_array = VarBinBuilder::<i32>::with_capacity(4);
+    input_array.push_value(b"The Greeks never said that the limit could not be overstepped");
+    input_array.push_null();
+    input_array.push_value(b"Nothing in present history can contradict them");
+    input_array.push_null();
+    let input_array = input_array
+        .finish(DType::Utf8(Nullability::Nullable))
+        .into_array();
+
+    let compressor = fsst_train_compressor(&input_array).unwrap();
+    let fsst_array = fsst_compress(&input_array, &compressor).unwrap();
+    assert_eq!(fsst_array.statistics().compute_null_count(), Some(2));
+}
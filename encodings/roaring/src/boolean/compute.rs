@@ -1,5 +1,7 @@
 use croaring::Bitmap;
-use vortex_array::compute::{ComputeVTable, InvertFn, ScalarAtFn, SliceFn};
+use vortex_array::compute::{
+    BinaryBooleanFn, BinaryOperator, ComputeVTable, InvertFn, ScalarAtFn, SliceFn,
+};
 use vortex_array::{ArrayData, ArrayLen, IntoArrayData};
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
@@ -7,6 +9,10 @@ use vortex_scalar::Scalar;
 use crate::{RoaringBoolArray, RoaringBoolEncoding};
 
 impl ComputeVTable for RoaringBoolEncoding {
+    fn binary_boolean_fn(&self) -> Option<&dyn BinaryBooleanFn<ArrayData>> {
+        Some(self)
+    }
+
     fn invert_fn(&self) -> Option<&dyn InvertFn<ArrayData>> {
         Some(self)
     }
@@ -20,6 +26,26 @@ impl ComputeVTable for RoaringBoolEncoding {
     }
 }
 
+impl BinaryBooleanFn<RoaringBoolArray> for RoaringBoolEncoding {
+    fn binary_boolean(
+        &self,
+        array: &RoaringBoolArray,
+        other: &ArrayData,
+        op: BinaryOperator,
+    ) -> VortexResult<Option<ArrayData>> {
+        let Ok(other) = RoaringBoolArray::try_from(other.clone()) else {
+            return Ok(None);
+        };
+        // Roaring bitmaps have no nulls, so the kleene operators are the same as the plain ones.
+        let bitmap = match op {
+            BinaryOperator::And | BinaryOperator::AndKleene => array.bitmap().and(&other.bitmap()),
+            BinaryOperator::Or | BinaryOperator::OrKleene => array.bitmap().or(&other.bitmap()),
+            BinaryOperator::Xor => array.bitmap().xor(&other.bitmap()),
+        };
+        RoaringBoolArray::try_new(bitmap, array.len()).map(|a| Some(a.into_array()))
+    }
+}
+
 impl InvertFn<RoaringBoolArray> for RoaringBoolEncoding {
     fn invert(&self, array: &RoaringBoolArray) -> VortexResult<ArrayData> {
         RoaringBoolArray::try_new(array.bitmap().flip(0..(array.len() as u32)), array.len())
@@ -53,7 +79,7 @@ impl SliceFn<RoaringBoolArray> for RoaringBoolEncoding {
 #[cfg(test)]
 mod tests {
     use vortex_array::array::BoolArray;
-    use vortex_array::compute::{scalar_at, slice};
+    use vortex_array::compute::{and, or, scalar_at, slice};
     use vortex_array::{IntoArrayData, IntoArrayVariant};
     use vortex_scalar::Scalar;
 
@@ -91,4 +117,38 @@ mod tests {
             &[false, true]
         );
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn test_and_or() {
+        let lhs =
+            RoaringBoolArray::encode(BoolArray::from_iter([true, false, true, false]).into_array())
+                .unwrap();
+        let rhs =
+            RoaringBoolArray::encode(BoolArray::from_iter([true, true, false, false]).into_array())
+                .unwrap();
+
+        let anded = and(&lhs, &rhs).unwrap();
+        assert!(RoaringBoolArray::try_from(anded.clone()).is_ok());
+        assert_eq!(
+            anded
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[true, false, false, false]
+        );
+
+        let ored = or(&lhs, &rhs).unwrap();
+        assert!(RoaringBoolArray::try_from(ored.clone()).is_ok());
+        assert_eq!(
+            ored.into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[true, true, true, false]
+        );
+    }
 }
@@ -5,7 +5,7 @@ use arrow_buffer::{BooleanBuffer, MutableBuffer};
 pub use compress::*;
 use croaring::Native;
 pub use croaring::{Bitmap, Portable};
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_array::array::BoolArray;
 use vortex_array::encoding::ids;
 use vortex_array::stats::StatsSet;
@@ -13,7 +13,9 @@ use vortex_array::validity::{LogicalValidity, ValidityVTable};
 use vortex_array::variants::{BoolArrayTrait, VariantsVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
 use vortex_array::{
-    impl_encoding, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoArrayData, IntoCanonical,
+    flexbuffers_root, impl_encoding, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoArrayData,
+    IntoCanonical, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
 };
 use vortex_buffer::Buffer;
 use vortex_dtype::{DType, Nullability};
@@ -25,7 +27,9 @@ mod stats;
 
 impl_encoding!("vortex.roaring_bool", ids::ROARING_BOOL, RoaringBool);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 has no fields.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct RoaringBoolMetadata;
 
 impl Display for RoaringBoolMetadata {
@@ -34,6 +38,23 @@ impl Display for RoaringBoolMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for RoaringBoolMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        Ok(MetadataWriter::new(0).finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for RoaringBoolMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        MetadataReader::try_new(metadata, 0)?.finish()?;
+        Ok(Self)
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl RoaringBoolArray {
     pub fn try_new(bitmap: Bitmap, length: usize) -> VortexResult<Self> {
         let max_set = bitmap.maximum().unwrap_or(0) as usize;
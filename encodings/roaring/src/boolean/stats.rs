@@ -11,7 +11,7 @@ impl StatisticsVTable<RoaringBoolArray> for RoaringBoolEncoding {
         let true_count = bitmap.statistics().cardinality;
         if matches!(
             stat,
-            Stat::TrueCount | Stat::Min | Stat::Max | Stat::IsConstant
+            Stat::TrueCount | Stat::Min | Stat::Max | Stat::IsConstant | Stat::NullCount
         ) {
             return Ok(StatsSet::bools_with_true_and_null_count(
                 true_count as usize,
@@ -67,6 +67,7 @@ mod test {
         assert!(!bool_arr.statistics().compute_min::<bool>().unwrap());
         assert!(bool_arr.statistics().compute_max::<bool>().unwrap());
         assert_eq!(bool_arr.statistics().compute_true_count().unwrap(), 4);
+        assert_eq!(bool_arr.statistics().compute_null_count().unwrap(), 0);
     }
 
     #[test]
@@ -57,6 +57,7 @@ impl SliceFn<RoaringIntArray> for RoaringIntEncoding {
 mod tests {
     use vortex_array::array::PrimitiveArray;
     use vortex_array::compute::{scalar_at, slice};
+    use vortex_array::stats::ArrayStatistics;
 
     use super::*;
 
@@ -90,4 +91,12 @@ mod tests {
         assert_eq!(scalar_at(&sliced, 0).unwrap(), 18u32.into());
         assert_eq!(scalar_at(&sliced, 1).unwrap(), 19u32.into());
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_null_count() {
+        let ints = PrimitiveArray::from(vec![2u32, 12, 22, 32]).into_array();
+        let array = RoaringIntArray::encode(ints).unwrap();
+        assert_eq!(array.statistics().compute_null_count(), Some(0));
+    }
 }
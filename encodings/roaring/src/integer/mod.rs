@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 pub use compress::*;
 use croaring::{Bitmap, Portable};
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_array::array::PrimitiveArray;
 use vortex_array::compute::try_cast;
 use vortex_array::encoding::ids;
@@ -12,8 +12,9 @@ use vortex_array::validity::{LogicalValidity, Validity, ValidityVTable};
 use vortex_array::variants::{PrimitiveArrayTrait, VariantsVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
 use vortex_array::{
-    impl_encoding, ArrayDType as _, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoArrayData,
-    IntoCanonical,
+    flexbuffers_root, impl_encoding, ArrayDType as _, ArrayData, ArrayLen, ArrayTrait, Canonical,
+    IntoArrayData, IntoCanonical, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
 };
 use vortex_buffer::Buffer;
 use vortex_dtype::Nullability::NonNullable;
@@ -25,7 +26,9 @@ mod compute;
 
 impl_encoding!("vortex.roaring_int", ids::ROARING_INT, RoaringInt);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the ptype of the values.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct RoaringIntMetadata {
     ptype: PType,
 }
@@ -36,6 +39,27 @@ impl Display for RoaringIntMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for RoaringIntMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        writer.put_ptype(self.ptype);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for RoaringIntMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let ptype = reader.get_ptype()?;
+        reader.finish()?;
+        Ok(Self { ptype })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl RoaringIntArray {
     pub fn try_new(bitmap: Bitmap, ptype: PType) -> VortexResult<Self> {
         if !ptype.is_unsigned_int() {
@@ -152,6 +176,9 @@ impl StatisticsVTable<RoaringIntArray> for RoaringIntEncoding {
                 Stat::BitWidthFreq,
                 Stat::RunCount,
             ])
+        } else if stat == Stat::NullCount {
+            // Roaring bitmaps can't hold nulls.
+            Ok(StatsSet::of(stat, 0u64))
         } else {
             Ok(StatsSet::default())
         }
@@ -1,6 +1,7 @@
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_array::array::{BoolArray, PrimitiveArray};
 use vortex_array::compute::{scalar_at, search_sorted_usize, SearchSortedSide};
 use vortex_array::encoding::ids;
@@ -9,8 +10,9 @@ use vortex_array::validity::{LogicalValidity, Validity, ValidityMetadata, Validi
 use vortex_array::variants::{BoolArrayTrait, PrimitiveArrayTrait, VariantsVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
 use vortex_array::{
-    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoArrayData,
-    IntoArrayVariant, IntoCanonical,
+    flexbuffers_root, impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical,
+    IntoArrayData, IntoArrayVariant, IntoCanonical, MetadataReader, MetadataWriter,
+    TryDeserializeArrayMetadata, TrySerializeArrayMetadata,
 };
 use vortex_dtype::{match_each_integer_ptype, match_each_unsigned_integer_ptype, DType, PType};
 use vortex_error::{vortex_bail, VortexExpect as _, VortexResult};
@@ -20,7 +22,10 @@ use crate::compress::{runend_bool_decode_slice, runend_bool_encode_slice, trimme
 
 impl_encoding!("vortex.runendbool", ids::RUN_END_BOOL, RunEndBool);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the value of the first run, the validity, the
+/// ptype of the ends, the number of runs as a u64 and the offset as a u64.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct RunEndBoolMetadata {
     start: bool,
     validity: ValidityMetadata,
@@ -35,6 +40,42 @@ impl Display for RunEndBoolMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for RunEndBoolMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        writer.put_bool(self.start);
+        self.validity.write_to(&mut writer);
+        writer
+            .put_ptype(self.ends_ptype)
+            .put_usize(self.num_runs)
+            .put_usize(self.offset);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for RunEndBoolMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let start = reader.get_bool()?;
+        let validity = ValidityMetadata::read_from(&mut reader)?;
+        let ends_ptype = reader.get_ptype()?;
+        let num_runs = reader.get_usize()?;
+        let offset = reader.get_usize()?;
+        reader.finish()?;
+        Ok(Self {
+            start,
+            validity,
+            ends_ptype,
+            num_runs,
+            offset,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl RunEndBoolArray {
     pub fn try_new(ends: ArrayData, start: bool, validity: Validity) -> VortexResult<Self> {
         let length: usize = scalar_at(&ends, ends.len() - 1)?.as_ref().try_into()?;
@@ -176,7 +217,20 @@ pub(crate) fn value_at_index(idx: usize, start: bool) -> bool {
     }
 }
 
-impl BoolArrayTrait for RunEndBoolArray {}
+impl BoolArrayTrait for RunEndBoolArray {
+    fn run_ends(&self) -> VortexResult<Option<Vec<(usize, bool)>>> {
+        let ends = self.ends().into_primitive()?;
+        let start = self.start();
+        Ok(Some(
+            match_each_unsigned_integer_ptype!(ends.ptype(), |$E| {
+                trimmed_ends_iter(ends.maybe_null_slice::<$E>(), self.offset(), self.len())
+                    .enumerate()
+                    .map(|(idx, end)| (end, value_at_index(idx, start)))
+                    .collect()
+            }),
+        ))
+    }
+}
 
 impl VariantsVTable<RunEndBoolArray> for RunEndBoolEncoding {
     fn as_bool_array<'a>(&self, array: &'a RunEndBoolArray) -> Option<&'a dyn BoolArrayTrait> {
@@ -192,7 +246,10 @@ impl ValidityVTable<RunEndBoolArray> for RunEndBoolEncoding {
     }
 
     fn logical_validity(&self, array: &RunEndBoolArray) -> LogicalValidity {
-        array.validity().to_logical(array.len())
+        array
+            .metadata()
+            .validity
+            .to_logical(array.len(), || array.validity())
     }
 }
 
@@ -220,7 +277,13 @@ impl VisitorVTable<RunEndBoolArray> for RunEndBoolEncoding {
 impl StatisticsVTable<RunEndBoolArray> for RunEndBoolEncoding {
     fn compute_statistics(&self, array: &RunEndBoolArray, stat: Stat) -> VortexResult<StatsSet> {
         let maybe_scalar: Option<Scalar> = match stat {
-            Stat::NullCount => Some(array.validity().null_count(array.len())?.into()),
+            Stat::NullCount => Some(
+                array
+                    .metadata()
+                    .validity
+                    .null_count(array.len(), || array.validity())?
+                    .into(),
+            ),
             Stat::TrueCount => {
                 let pends = array.ends().into_primitive()?;
                 let mut true_count: usize = 0;
@@ -255,7 +318,7 @@ mod test {
     use itertools::Itertools as _;
     use rstest::rstest;
     use vortex_array::array::{BoolArray, PrimitiveArray};
-    use vortex_array::compute::{scalar_at, slice, take};
+    use vortex_array::compute::{scalar_at, slice, take, FilterMask};
     use vortex_array::stats::ArrayStatistics;
     use vortex_array::validity::Validity;
     use vortex_array::{
@@ -412,4 +475,19 @@ mod test {
         let sliced = slice(&arr, 4, 8).unwrap();
         assert_eq!(sliced.statistics().compute_true_count().unwrap(), 2);
     }
+
+    #[test]
+    fn filter_mask_keeps_runs() {
+        // [t, t, t, t, t, f, f, t, t, t]
+        let arr = RunEndBoolArray::try_new(
+            PrimitiveArray::from(vec![5u32, 7, 10]).into_array(),
+            true,
+            Validity::NonNullable,
+        )
+        .unwrap();
+        let mask = FilterMask::try_from(slice(&arr, 4, 8).unwrap()).unwrap();
+        assert_eq!(mask.len(), 4);
+        assert_eq!(mask.true_count(), 2);
+        assert_eq!(mask.slices().unwrap(), &[(0, 1), (3, 4)]);
+    }
 }
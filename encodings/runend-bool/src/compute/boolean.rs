@@ -0,0 +1,144 @@
+use vortex_array::array::PrimitiveArray;
+use vortex_array::compute::{BinaryBooleanFn, BinaryOperator};
+use vortex_array::validity::Validity;
+use vortex_array::variants::PrimitiveArrayTrait;
+use vortex_array::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
+use vortex_dtype::match_each_unsigned_integer_ptype;
+use vortex_error::VortexResult;
+
+use crate::compress::trimmed_ends_iter;
+use crate::{value_at_index, RunEndBoolArray, RunEndBoolEncoding};
+
+impl BinaryBooleanFn<RunEndBoolArray> for RunEndBoolEncoding {
+    fn binary_boolean(
+        &self,
+        array: &RunEndBoolArray,
+        other: &ArrayData,
+        op: BinaryOperator,
+    ) -> VortexResult<Option<ArrayData>> {
+        let Ok(other) = RunEndBoolArray::try_from(other.clone()) else {
+            return Ok(None);
+        };
+        // Nulls would split the runs, so only arrays without them are combined run by run, in
+        // which case the kleene operators are the same as the plain ones.
+        let all_valid =
+            |validity: &Validity| matches!(validity, Validity::NonNullable | Validity::AllValid);
+        if array.is_empty() || !all_valid(&array.validity()) || !all_valid(&other.validity()) {
+            return Ok(None);
+        }
+
+        let op: fn(bool, bool) -> bool = match op {
+            BinaryOperator::And | BinaryOperator::AndKleene => |l, r| l & r,
+            BinaryOperator::Or | BinaryOperator::OrKleene => |l, r| l | r,
+            BinaryOperator::Xor => |l, r| l ^ r,
+        };
+
+        let lhs_ends = trimmed_ends(array)?;
+        let rhs_ends = trimmed_ends(&other)?;
+        let mut ends: Vec<u64> = Vec::with_capacity(lhs_ends.len().max(rhs_ends.len()));
+        let mut start = None;
+        let mut last_value = false;
+        let (mut l, mut r) = (0, 0);
+        while l < lhs_ends.len() && r < rhs_ends.len() {
+            let end = lhs_ends[l].min(rhs_ends[r]);
+            let value = op(
+                value_at_index(l, array.start()),
+                value_at_index(r, other.start()),
+            );
+            match ends.last_mut() {
+                // Extend the current run while the value doesn't change.
+                Some(last_end) if value == last_value => *last_end = end as u64,
+                _ => {
+                    start.get_or_insert(value);
+                    ends.push(end as u64);
+                }
+            }
+            last_value = value;
+
+            if lhs_ends[l] == end {
+                l += 1;
+            }
+            if rhs_ends[r] == end {
+                r += 1;
+            }
+        }
+
+        RunEndBoolArray::try_new(
+            PrimitiveArray::from(ends).into_array(),
+            start.unwrap_or_default(),
+            array.validity().and(other.validity())?,
+        )
+        .map(|a| Some(a.into_array()))
+    }
+}
+
+/// The ends of the runs relative to the start of the array.
+fn trimmed_ends(array: &RunEndBoolArray) -> VortexResult<Vec<usize>> {
+    let ends = array.ends().into_primitive()?;
+    Ok(match_each_unsigned_integer_ptype!(ends.ptype(), |$E| {
+        trimmed_ends_iter(ends.maybe_null_slice::<$E>(), array.offset(), array.len())
+            .collect()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_array::array::BoolArray;
+    use vortex_array::compute::{and, or};
+    use vortex_array::validity::Validity;
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+
+    use crate::{encode_runend_bool, RunEndBoolArray};
+
+    fn runend(values: &[bool]) -> RunEndBoolArray {
+        encode_runend_bool(&BoolArray::from_iter(values.iter().copied())).unwrap()
+    }
+
+    #[test]
+    fn and_or_runs() {
+        let lhs = runend(&[true, true, true, false, false, true, true, true]);
+        let rhs = runend(&[false, true, true, true, true, true, false, false]);
+
+        let anded = and(&lhs, &rhs).unwrap();
+        let anded = RunEndBoolArray::try_from(anded).unwrap();
+        assert_eq!(
+            anded
+                .into_array()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![false, true, true, false, false, true, false, false]
+        );
+
+        let ored = or(&lhs, &rhs).unwrap();
+        let ored = RunEndBoolArray::try_from(ored).unwrap();
+        // The runs of the result are merged, so there's only one run of trues.
+        assert_eq!(ored.ends().len(), 1);
+        assert!(ored
+            .into_array()
+            .into_bool()
+            .unwrap()
+            .boolean_buffer()
+            .iter()
+            .all(|v| v));
+    }
+
+    #[test]
+    fn validity_stays_run_end_encoded() {
+        let lhs = Validity::Array(runend(&[true, true, false, false, true, true]).into_array());
+        let rhs = Validity::Array(runend(&[false, true, true, false, true, false]).into_array());
+
+        let intersection = lhs.clone().and(rhs.clone()).unwrap();
+        let union = lhs.or(rhs).unwrap();
+        assert!(RunEndBoolArray::try_from(intersection.as_array().unwrap().clone()).is_ok());
+        assert!(RunEndBoolArray::try_from(union.as_array().unwrap().clone()).is_ok());
+        assert!(intersection.is_valid(1) && !intersection.is_valid(2) && intersection.is_valid(4));
+        assert!(union.is_valid(0) && !union.is_valid(3) && union.is_valid(5));
+
+        let negated = union.invert().unwrap();
+        assert!(RunEndBoolArray::try_from(negated.as_array().unwrap().clone()).is_ok());
+        assert!(!negated.is_valid(0));
+    }
+}
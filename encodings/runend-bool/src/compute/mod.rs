@@ -1,8 +1,11 @@
+mod boolean;
 mod invert;
 
 use arrow_buffer::BooleanBuffer;
 use vortex_array::array::BoolArray;
-use vortex_array::compute::{slice, ComputeVTable, InvertFn, ScalarAtFn, SliceFn, TakeFn};
+use vortex_array::compute::{
+    slice, BinaryBooleanFn, ComputeVTable, InvertFn, ScalarAtFn, SliceFn, TakeFn,
+};
 use vortex_array::variants::PrimitiveArrayTrait;
 use vortex_array::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
 use vortex_dtype::match_each_integer_ptype;
@@ -12,6 +15,10 @@ use vortex_scalar::Scalar;
 use crate::{value_at_index, RunEndBoolArray, RunEndBoolEncoding};
 
 impl ComputeVTable for RunEndBoolEncoding {
+    fn binary_boolean_fn(&self) -> Option<&dyn BinaryBooleanFn<ArrayData>> {
+        Some(self)
+    }
+
     fn invert_fn(&self) -> Option<&dyn InvertFn<ArrayData>> {
         Some(self)
     }
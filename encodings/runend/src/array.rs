@@ -1,6 +1,7 @@
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_array::array::PrimitiveArray;
 use vortex_array::compute::{
     scalar_at, search_sorted_usize, search_sorted_usize_many, SearchSortedSide,
@@ -11,18 +12,23 @@ use vortex_array::validity::{ArrayValidity, LogicalValidity, ValidityVTable};
 use vortex_array::variants::{BoolArrayTrait, PrimitiveArrayTrait, VariantsVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
 use vortex_array::{
-    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoArrayData,
-    IntoArrayVariant, IntoCanonical,
+    flexbuffers_root, impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical,
+    IntoArrayData, IntoArrayVariant, IntoCanonical, MetadataReader, MetadataWriter,
+    TryDeserializeArrayMetadata, TrySerializeArrayMetadata,
 };
-use vortex_dtype::{DType, PType};
+use vortex_dtype::{match_each_integer_ptype, DType, PType};
 use vortex_error::{vortex_bail, VortexExpect as _, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::compress::{runend_decode_bools, runend_decode_primitive, runend_encode};
+use crate::iter::trimmed_ends_iter;
 
 impl_encoding!("vortex.runend", ids::RUN_END, RunEnd);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the ptype of the ends, the number of runs as a
+/// u64 and the offset as a u64.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct RunEndMetadata {
     ends_ptype: PType,
     num_runs: usize,
@@ -35,6 +41,36 @@ impl Display for RunEndMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for RunEndMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        writer
+            .put_ptype(self.ends_ptype)
+            .put_usize(self.num_runs)
+            .put_usize(self.offset);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for RunEndMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let ends_ptype = reader.get_ptype()?;
+        let num_runs = reader.get_usize()?;
+        let offset = reader.get_usize()?;
+        reader.finish()?;
+        Ok(Self {
+            ends_ptype,
+            num_runs,
+            offset,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl RunEndArray {
     pub fn try_new(ends: ArrayData, values: ArrayData) -> VortexResult<Self> {
         let length = if ends.is_empty() {
@@ -225,7 +261,8 @@ impl VisitorVTable<RunEndArray> for RunEndEncoding {
 impl StatisticsVTable<RunEndArray> for RunEndEncoding {
     fn compute_statistics(&self, array: &RunEndArray, stat: Stat) -> VortexResult<StatsSet> {
         let maybe_stat = match stat {
-            Stat::Min | Stat::Max => array.values().statistics().compute(stat),
+            Stat::Min | Stat::Max | Stat::HasNaN => array.values().statistics().compute(stat),
+            Stat::NullCount => Some(Scalar::from(null_count(array)?)),
             Stat::IsSorted => Some(Scalar::from(
                 array
                     .values()
@@ -245,9 +282,34 @@ impl StatisticsVTable<RunEndArray> for RunEndEncoding {
     }
 }
 
+/// The total length of the runs of null values.
+fn null_count(array: &RunEndArray) -> VortexResult<usize> {
+    Ok(match array.values().logical_validity() {
+        LogicalValidity::AllValid(_) => 0,
+        LogicalValidity::AllInvalid(_) => array.len(),
+        LogicalValidity::Array(validity) => {
+            let validity = validity.into_bool()?.boolean_buffer();
+            let ends = array.ends().into_primitive()?;
+            let mut prev_end = 0;
+            match_each_integer_ptype!(ends.ptype(), |$E| {
+                trimmed_ends_iter(ends.maybe_null_slice::<$E>(), array.offset(), array.len())
+                    .zip(validity.iter())
+                    .map(|(end, valid)| {
+                        let run_len = end - prev_end;
+                        prev_end = end;
+                        if valid { 0 } else { run_len }
+                    })
+                    .sum()
+            })
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use vortex_array::compute::scalar_at;
+    use vortex_array::array::PrimitiveArray;
+    use vortex_array::compute::{scalar_at, slice};
+    use vortex_array::stats::ArrayStatistics;
     use vortex_array::{ArrayDType, ArrayLen, IntoArrayData};
     use vortex_dtype::{DType, Nullability, PType};
 
@@ -274,4 +336,17 @@ mod tests {
         assert_eq!(scalar_at(arr.as_ref(), 5).unwrap(), 3.into());
         assert_eq!(scalar_at(arr.as_ref(), 9).unwrap(), 3.into());
     }
+
+    #[test]
+    fn null_count() {
+        let arr = RunEndArray::try_new(
+            vec![2u32, 5, 10].into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3)]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(arr.statistics().compute_null_count(), Some(3));
+
+        let sliced = slice(arr.as_ref(), 3, 8).unwrap();
+        assert_eq!(sliced.statistics().compute_null_count(), Some(2));
+    }
 }
@@ -1,6 +1,7 @@
 mod compare;
 mod fill_null;
 mod invert;
+mod sum;
 mod take;
 
 use std::cmp::min;
@@ -10,7 +11,7 @@ use num_traits::AsPrimitive;
 use vortex_array::array::{BooleanBuffer, PrimitiveArray};
 use vortex_array::compute::{
     binary_numeric, filter, scalar_at, slice, BinaryNumericFn, CompareFn, ComputeVTable,
-    FillNullFn, FilterFn, FilterMask, InvertFn, ScalarAtFn, SliceFn, TakeFn,
+    FillNullFn, FilterFn, FilterMask, InvertFn, ScalarAtFn, SliceFn, SumFn, TakeFn,
 };
 use vortex_array::variants::PrimitiveArrayTrait;
 use vortex_array::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
@@ -49,6 +50,10 @@ impl ComputeVTable for RunEndEncoding {
         Some(self)
     }
 
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        Some(self)
+    }
+
     fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
         Some(self)
     }
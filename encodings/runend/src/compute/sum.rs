@@ -0,0 +1,80 @@
+use vortex_array::compute::{SumAccumulator, SumFn};
+use vortex_array::validity::ArrayValidity;
+use vortex_array::variants::PrimitiveArrayTrait;
+use vortex_array::{ArrayLen, IntoArrayVariant};
+use vortex_dtype::{match_each_native_ptype, match_each_unsigned_integer_ptype, NativePType};
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::{RunEndArray, RunEndEncoding};
+
+impl SumFn<RunEndArray> for RunEndEncoding {
+    fn sum(&self, array: &RunEndArray) -> VortexResult<Scalar> {
+        let ends = array.ends().into_primitive()?;
+        let run_lengths = match_each_unsigned_integer_ptype!(ends.ptype(), |$E| {
+            run_lengths(ends.maybe_null_slice::<$E>(), array.offset(), array.len())?
+        });
+
+        let values = array.values().into_primitive()?;
+        let validity = values.logical_validity().to_null_buffer()?;
+        let mut acc = SumAccumulator::new(values.ptype());
+        match_each_native_ptype!(values.ptype(), |$T| {
+            let runs = values.maybe_null_slice::<$T>().iter().zip(run_lengths);
+            for (idx, (&value, run_length)) in runs.enumerate() {
+                if validity.as_ref().map_or(true, |v| v.is_valid(idx)) {
+                    acc.add(value, run_length)?;
+                }
+            }
+        });
+        Ok(acc.finish())
+    }
+}
+
+/// The number of rows of each run that fall within the (possibly sliced) array.
+fn run_lengths<E: NativePType>(ends: &[E], offset: usize, len: usize) -> VortexResult<Vec<usize>> {
+    let mut start = offset;
+    ends.iter()
+        .map(|&end| {
+            let end = end
+                .to_usize()
+                .ok_or_else(|| vortex_err!("Run end {end} does not fit in usize"))?
+                .clamp(offset, offset + len);
+            let run_length = end.saturating_sub(start);
+            start = end;
+            Ok(run_length)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::PrimitiveArray;
+    use vortex_array::compute::{slice, sum};
+    use vortex_array::IntoArrayData;
+    use vortex_scalar::Scalar;
+
+    use crate::RunEndArray;
+
+    #[test]
+    fn sum_runs() {
+        let arr = RunEndArray::try_new(
+            PrimitiveArray::from(vec![2u32, 5, 10]).into_array(),
+            PrimitiveArray::from(vec![1i32, -2, 3]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(sum(&arr).unwrap(), Scalar::from(Some(2 - 6 + 15i64)));
+
+        let sliced = slice(&arr, 1, 7).unwrap();
+        assert_eq!(sum(&sliced).unwrap(), Scalar::from(Some(1 - 6 + 6i64)));
+    }
+
+    #[test]
+    fn sum_nullable_runs() {
+        let arr = RunEndArray::try_new(
+            PrimitiveArray::from(vec![2u32, 5, 10]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(1.5f64), None, Some(0.5)]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(sum(&arr).unwrap(), Scalar::from(Some(5.5f64)));
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::Display;
+use std::sync::Arc;
 
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_array::array::PrimitiveArray;
 use vortex_array::encoding::ids;
 use vortex_array::stats::{ArrayStatistics, Stat, StatisticsVTable, StatsSet};
@@ -8,8 +9,9 @@ use vortex_array::validity::{ArrayValidity, LogicalValidity, ValidityVTable};
 use vortex_array::variants::{PrimitiveArrayTrait, VariantsVTable};
 use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
 use vortex_array::{
-    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoArrayVariant,
-    IntoCanonical,
+    flexbuffers_root, impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical,
+    IntoArrayVariant, IntoCanonical, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
 };
 use vortex_dtype::{DType, PType};
 use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexExpect as _, VortexResult};
@@ -21,7 +23,9 @@ use crate::zigzag_decode;
 
 impl_encoding!("vortex.zigzag", ids::ZIGZAG, ZigZag);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 has no fields.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct ZigZagMetadata;
 
 impl Display for ZigZagMetadata {
@@ -30,6 +34,23 @@ impl Display for ZigZagMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for ZigZagMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        Ok(MetadataWriter::new(0).finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for ZigZagMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        MetadataReader::try_new(metadata, 0)?.finish()?;
+        Ok(Self)
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl ZigZagArray {
     pub fn try_new(encoded: ArrayData) -> VortexResult<Self> {
         let encoded_dtype = encoded.dtype().clone();
@@ -6,7 +6,7 @@ use vortex_array::array::{
     BoolEncoding, PrimitiveEncoding, StructEncoding, VarBinEncoding, VarBinViewEncoding,
 };
 use vortex_array::compute::{
-    filter, scalar_at, search_sorted, slice, take, SearchResult, SearchSortedSide,
+    array_compare_elementwise, filter, search_sorted, slice, take, SearchResult, SearchSortedSide,
 };
 use vortex_array::encoding::EncodingRef;
 use vortex_array::{ArrayData, IntoCanonical};
@@ -91,27 +91,12 @@ fn assert_search_sorted(
     );
 }
 
-// TODO(ngates): this is horrific... we should have an array_equals compute function?
 fn assert_array_eq(lhs: &ArrayData, rhs: &ArrayData, step: usize) {
-    assert_eq!(
-        lhs.len(),
-        rhs.len(),
-        "LHS len {} != RHS len {}, lhs is {} rhs is {} in step {step}",
-        lhs.len(),
-        rhs.len(),
+    let diff = array_compare_elementwise(lhs, rhs, 10).unwrap();
+    assert!(
+        diff.is_empty(),
+        "{diff}, lhs is {} rhs is {} in step {step}",
         lhs.encoding().id(),
         rhs.encoding().id()
     );
-    for idx in 0..lhs.len() {
-        let l = scalar_at(lhs, idx).unwrap();
-        let r = scalar_at(rhs, idx).unwrap();
-
-        assert_eq!(
-            l,
-            r,
-            "{l} != {r} at index {idx}, lhs is {} rhs is {} in step {step}",
-            lhs.encoding().id(),
-            rhs.encoding().id()
-        );
-    }
 }
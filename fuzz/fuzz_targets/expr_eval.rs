@@ -0,0 +1,53 @@
+#![no_main]
+
+use libfuzzer_sys::{fuzz_target, Corpus};
+use vortex_array::array::StructArray;
+use vortex_array::compute::array_compare_elementwise;
+use vortex_array::variants::StructArrayTrait;
+use vortex_array::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant, IntoCanonical};
+use vortex_fuzz::FuzzExprAction;
+
+fuzz_target!(|fuzz_action: FuzzExprAction| -> Corpus {
+    let FuzzExprAction {
+        array,
+        compressor,
+        expr,
+    } = fuzz_action;
+
+    // Not every random expression is valid for the dtypes of its operands.
+    let Ok(expected) = expr.evaluate(&canonical_fields(&array)) else {
+        return Corpus::Reject;
+    };
+
+    let compressed = compressor.compress(&array, None).unwrap().into_array();
+    let actual = expr.evaluate(&compressed).unwrap_or_else(|e| {
+        panic!(
+            "Failed to evaluate {expr} on {}: {e}",
+            compressed.encoding().id()
+        )
+    });
+
+    let diff = array_compare_elementwise(&expected, &actual, 10).unwrap();
+    assert!(
+        diff.is_empty(),
+        "{diff} evaluating {expr} on {}",
+        compressed.encoding().id()
+    );
+    Corpus::Keep
+});
+
+/// The struct array with each of its fields in its canonical encoding.
+fn canonical_fields(array: &ArrayData) -> ArrayData {
+    let struct_array = array.clone().into_struct().unwrap();
+    StructArray::try_new(
+        struct_array.names().clone(),
+        struct_array
+            .children()
+            .map(|child| child.into_canonical().unwrap().into_array())
+            .collect(),
+        struct_array.len(),
+        struct_array.validity(),
+    )
+    .unwrap()
+    .into_array()
+}
@@ -0,0 +1,71 @@
+#![no_main]
+
+use std::sync::{Arc, LazyLock};
+
+use futures_executor::block_on;
+use libfuzzer_sys::{fuzz_target, Corpus};
+use vortex_array::compute::array_compare_elementwise;
+use vortex_array::ArrayData;
+use vortex_buffer::Buffer;
+use vortex_error::VortexResult;
+use vortex_expr::ExprRef;
+use vortex_file::{
+    LayoutContext, LayoutDeserializer, Projection, RowFilter, VortexFileWriter, VortexReadBuilder,
+};
+use vortex_fuzz::FuzzFileAction;
+use vortex_io::IoDispatcher;
+use vortex_sampling_compressor::ALL_ENCODINGS_CONTEXT;
+
+// Share the dispatcher between runs, rather than starting a thread for each one.
+static DISPATCHER: LazyLock<Arc<IoDispatcher>> =
+    LazyLock::new(|| Arc::new(IoDispatcher::default()));
+
+fuzz_target!(|fuzz_action: FuzzFileAction| -> Corpus {
+    let FuzzFileAction {
+        array,
+        compressor,
+        projection,
+        row_filter,
+        expected,
+    } = fuzz_action;
+
+    let compressed = compressor.compress(&array, None).unwrap().into_array();
+    let written = block_on(write(compressed)).unwrap();
+    let actual = block_on(read(written, projection, row_filter)).unwrap();
+
+    let diff = array_compare_elementwise(&expected, &actual, 10).unwrap();
+    assert!(
+        diff.is_empty(),
+        "{diff}, expected is {} actual is {}",
+        expected.encoding().id(),
+        actual.encoding().id()
+    );
+    Corpus::Keep
+});
+
+async fn write(array: ArrayData) -> VortexResult<Buffer> {
+    let writer = VortexFileWriter::new(Vec::new())
+        .write_array_columns(array)
+        .await?;
+    Ok(Buffer::from(writer.finalize().await?))
+}
+
+async fn read(
+    written: Buffer,
+    projection: Vec<usize>,
+    row_filter: Option<ExprRef>,
+) -> VortexResult<ArrayData> {
+    let mut builder = VortexReadBuilder::new(
+        written,
+        LayoutDeserializer::new(
+            ALL_ENCODINGS_CONTEXT.clone(),
+            LayoutContext::default().into(),
+        ),
+    )
+    .with_projection(Projection::new(projection))
+    .with_io_dispatcher(DISPATCHER.clone());
+    if let Some(row_filter) = row_filter {
+        builder = builder.with_row_filter(RowFilter::new(row_filter));
+    }
+    builder.build().await?.read_all().await
+}
@@ -0,0 +1,136 @@
+use libfuzzer_sys::arbitrary::Error::EmptyChoose;
+use libfuzzer_sys::arbitrary::{Arbitrary, Result, Unstructured};
+use vortex_array::compute::{scalar_at, LikeOptions};
+use vortex_array::variants::StructArrayTrait;
+use vortex_array::{ArrayDType, ArrayData, IntoArrayVariant};
+use vortex_dtype::field::Field;
+use vortex_dtype::{DType, FieldName, Nullability};
+use vortex_expr::{BinaryExpr, Column, ExprRef, Like, Literal, Not, Operator};
+use vortex_sampling_compressor::SamplingCompressor;
+use vortex_scalar::arbitrary::random_scalar;
+use vortex_scalar::Scalar;
+
+use crate::random_struct_array;
+
+const COMPARISONS: [Operator; 6] = [
+    Operator::Eq,
+    Operator::NotEq,
+    Operator::Gt,
+    Operator::Gte,
+    Operator::Lt,
+    Operator::Lte,
+];
+
+/// A struct array to compress, and a random predicate over its fields, whose result on the
+/// compressed array must match its result on the canonical array.
+#[derive(Debug)]
+pub struct FuzzExprAction {
+    pub array: ArrayData,
+    pub compressor: SamplingCompressor<'static>,
+    pub expr: ExprRef,
+}
+
+impl<'a> Arbitrary<'a> for FuzzExprAction {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let array = random_struct_array(u)?;
+        let struct_array = array.clone().into_struct().unwrap();
+        let columns = struct_array
+            .names()
+            .iter()
+            .cloned()
+            .zip(struct_array.children())
+            .filter(|(_, column)| {
+                matches!(
+                    column.dtype(),
+                    DType::Bool(_) | DType::Primitive(..) | DType::Utf8(_) | DType::Binary(_)
+                )
+            })
+            .collect::<Vec<_>>();
+        if columns.is_empty() {
+            return Err(EmptyChoose);
+        }
+
+        Ok(Self {
+            expr: random_predicate(u, &columns, 2)?,
+            array,
+            compressor: u.arbitrary()?,
+        })
+    }
+}
+
+/// A random boolean expression over the columns, nested up to `depth` levels of boolean operators.
+fn random_predicate(
+    u: &mut Unstructured<'_>,
+    columns: &[(FieldName, ArrayData)],
+    depth: u8,
+) -> Result<ExprRef> {
+    let max_kind = if depth == 0 { 1 } else { 3 };
+    Ok(match u.int_in_range(0..=max_kind)? {
+        0 => random_comparison(u, columns)?,
+        1 => random_like(u, columns)?,
+        2 => BinaryExpr::new_expr(
+            random_predicate(u, columns, depth - 1)?,
+            *u.choose(&[Operator::And, Operator::Or])?,
+            random_predicate(u, columns, depth - 1)?,
+        ),
+        3 => Not::new_expr(random_predicate(u, columns, depth - 1)?),
+        _ => unreachable!(),
+    })
+}
+
+/// A comparison of a column with another column of the same dtype, a value of the column, or a
+/// random value, which may be null.
+fn random_comparison(
+    u: &mut Unstructured<'_>,
+    columns: &[(FieldName, ArrayData)],
+) -> Result<ExprRef> {
+    let (name, column) = u.choose(columns)?;
+    let rhs = match u.int_in_range(0..=2)? {
+        0 => {
+            let same_dtype = columns
+                .iter()
+                .filter(|(_, other)| other.dtype().eq_ignore_nullability(column.dtype()))
+                .collect::<Vec<_>>();
+            Column::new_expr(Field::from(u.choose(&same_dtype)?.0.as_ref()))
+        }
+        1 => Literal::new_expr(scalar_at(column, u.choose_index(column.len())?).unwrap()),
+        2 => Literal::new_expr(random_scalar(u, column.dtype())?),
+        _ => unreachable!(),
+    };
+    Ok(BinaryExpr::new_expr(
+        Column::new_expr(Field::from(name.as_ref())),
+        *u.choose(&COMPARISONS)?,
+        rhs,
+    ))
+}
+
+/// A LIKE of a string column with a random pattern, or a comparison if there are no string
+/// columns.
+fn random_like(u: &mut Unstructured<'_>, columns: &[(FieldName, ArrayData)]) -> Result<ExprRef> {
+    let strings = columns
+        .iter()
+        .filter(|(_, column)| matches!(column.dtype(), DType::Utf8(_)))
+        .collect::<Vec<_>>();
+    if strings.is_empty() {
+        return random_comparison(u, columns);
+    }
+
+    let (name, _) = u.choose(&strings)?;
+    // Wildcards make random patterns likelier to match some values.
+    let mut pattern = String::arbitrary(u)?;
+    if u.arbitrary()? {
+        pattern.insert(0, '%');
+    }
+    if u.arbitrary()? {
+        pattern.push('%');
+    }
+    Ok(Like::new_expr(
+        Column::new_expr(Field::from(name.as_ref())),
+        Literal::new_expr(Scalar::utf8(pattern, Nullability::NonNullable)),
+        LikeOptions {
+            negated: u.arbitrary()?,
+            case_insensitive: u.arbitrary()?,
+            escape_char: None,
+        },
+    ))
+}
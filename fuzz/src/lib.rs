@@ -1,3 +1,5 @@
+mod expr;
+mod file;
 mod filter;
 mod search_sorted;
 mod slice;
@@ -8,12 +10,17 @@ use std::fmt::Debug;
 use std::iter;
 use std::ops::Range;
 
+pub use expr::FuzzExprAction;
+pub use file::FuzzFileAction;
 use libfuzzer_sys::arbitrary::Error::EmptyChoose;
 use libfuzzer_sys::arbitrary::{Arbitrary, Result, Unstructured};
 pub use sort::sort_canonical_array;
+use vortex_array::aliases::hash_set::HashSet;
+use vortex_array::array::arbitrary::random_array;
 use vortex_array::array::PrimitiveArray;
 use vortex_array::compute::{scalar_at, FilterMask, SearchResult, SearchSortedSide};
 use vortex_array::{ArrayDType, ArrayData, IntoArrayData};
+use vortex_dtype::{DType, Nullability, StructDType};
 use vortex_sampling_compressor::SamplingCompressor;
 use vortex_scalar::arbitrary::random_scalar;
 use vortex_scalar::Scalar;
@@ -53,7 +60,7 @@ pub struct FuzzArrayAction {
 
 #[derive(Debug)]
 pub enum Action {
-    Compress(SamplingCompressor<'static>),
+    Compress(Box<SamplingCompressor<'static>>),
     Slice(Range<usize>),
     Take(ArrayData),
     SearchSorted(Scalar, SearchSortedSide),
@@ -77,7 +84,7 @@ impl<'a> Arbitrary<'a> for FuzzArrayAction {
                         return Err(EmptyChoose);
                     }
                     (
-                        Action::Compress(u.arbitrary()?),
+                        Action::Compress(Box::new(u.arbitrary()?)),
                         ExpectedValue::Array(current_array.clone()),
                     )
                 }
@@ -162,3 +169,22 @@ fn random_vec_in_range(u: &mut Unstructured<'_>, min: usize, max: usize) -> Resu
     })
     .collect::<Result<Vec<_>>>()
 }
+
+/// A random non-empty, non-nullable struct array, with at least one field and unique field names,
+/// so that expressions can refer to its fields by name.
+fn random_struct_array(u: &mut Unstructured<'_>) -> Result<ArrayData> {
+    let struct_dtype = StructDType::arbitrary(u)?;
+    let names = struct_dtype.names();
+    if names.is_empty() || names.iter().collect::<HashSet<_>>().len() != names.len() {
+        return Err(EmptyChoose);
+    }
+    let array = random_array(
+        u,
+        &DType::Struct(struct_dtype, Nullability::NonNullable),
+        None,
+    )?;
+    if array.is_empty() {
+        return Err(EmptyChoose);
+    }
+    Ok(array)
+}
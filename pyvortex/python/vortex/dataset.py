@@ -206,7 +206,8 @@ class VortexDataset(pyarrow.dataset.Dataset):
         Parameters
         ----------
         indices : :class:`.pyarrow.Array`
-            A numeric array of absolute indices into `self` indicating which rows to keep.
+            A numeric array of strictly increasing absolute indices into `self` indicating which
+            rows to keep.
         columns : list of str
             The columns to keep, identified by name.
         filter : :class:`.pyarrow.dataset.Expression`
@@ -515,7 +515,7 @@ impl PyArray {
     ///     >>> arr = vortex.array([1, 2, None, 3])
     ///     >>> print(arr.tree_display())
     ///     root: vortex.primitive(0x03)(i64?, len=4) nbytes=36 B (100.00%)
-    ///       metadata: PrimitiveMetadata { validity: Array }
+    ///       metadata: PrimitiveMetadata { validity: Array { null_count: Some(1) } }
     ///       buffer: 32 B
     ///       validity: vortex.bool(0x02)(bool, len=4) nbytes=3 B (8.33%)
     ///         metadata: BoolMetadata { validity: NonNullable, first_byte_bit_offset: 0 }
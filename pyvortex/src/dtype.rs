@@ -56,6 +56,8 @@ impl PyDType {
             DType::Binary(_) => None,
             DType::Struct(child, _) => Some(child.names().iter().map(|x| x.to_string()).collect()),
             DType::List(..) => None,
+            DType::FixedSizeList(..) => None,
+            DType::Decimal(..) => None,
             DType::Extension(..) => None,
         }
     }
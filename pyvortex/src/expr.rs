@@ -297,6 +297,9 @@ pub fn scalar_helper(dtype: DType, value: &Bound<'_, PyAny>) -> PyResult<Scalar>
         },
         DType::Utf8(_) => Ok(Scalar::from(value.extract::<String>()?)),
         DType::Binary(_) => Ok(Scalar::from(value.extract::<&[u8]>()?)),
+        DType::Decimal(..) => Err(PyValueError::new_err(format!(
+            "Decimal literals are not supported yet, got {dtype}"
+        ))),
         DType::Struct(..) => todo!(),
         DType::List(element_type, _) => {
             let list = value.downcast::<PyList>();
@@ -306,6 +309,18 @@ pub fn scalar_helper(dtype: DType, value: &Bound<'_, PyAny>) -> PyResult<Scalar>
                 .collect::<PyResult<Vec<_>>>()?;
             Ok(Scalar::list(element_type, values, Nullability::Nullable))
         }
+        DType::FixedSizeList(element_type, ..) => {
+            let list = value.downcast::<PyList>();
+            let values = list
+                .iter()
+                .map(|element| scalar_helper(element_type.as_ref().clone(), element))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(Scalar::fixed_size_list(
+                element_type,
+                values,
+                Nullability::Nullable,
+            ))
+        }
         DType::Extension(..) => todo!(),
     }
 }
@@ -45,7 +45,21 @@ impl Display for DTypePythonRepr<'_> {
                     .join(", "),
                 n.python_repr()
             ),
+            DType::Decimal(decimal, n) => write!(
+                f,
+                "decimal({}, {}, {})",
+                decimal.precision(),
+                decimal.scale(),
+                n.python_repr()
+            ),
             DType::List(edt, n) => write!(f, "list({}, {})", edt.python_repr(), n.python_repr()),
+            DType::FixedSizeList(edt, size, n) => write!(
+                f,
+                "fixed_size_list({}, {}, {})",
+                edt.python_repr(),
+                size,
+                n.python_repr()
+            ),
             DType::Extension(ext) => {
                 write!(
                     f,
@@ -57,6 +57,17 @@ pub fn scalar_into_py(py: Python, x: Scalar, copy_into_python: bool) -> PyResult
                 }
             }
         }
+        DType::Decimal(..) => {
+            if x.is_null() {
+                py.None()
+            } else {
+                // Python's decimals parse the formatted value without loss of precision.
+                PyModule::import_bound(py, "decimal")?
+                    .getattr("Decimal")?
+                    .call1((x.to_string(),))?
+                    .into_py(py)
+            }
+        }
         DType::Struct(..) => {
             let struct_scalar = x.as_struct();
             if struct_scalar.is_null() {
@@ -67,7 +78,7 @@ pub fn scalar_into_py(py: Python, x: Scalar, copy_into_python: bool) -> PyResult
                 PyVortexStruct::new_pyobject(py, x)?
             }
         }
-        DType::List(..) => {
+        DType::List(..) | DType::FixedSizeList(..) => {
             let list_scalar = x.as_list();
             if list_scalar.is_null() {
                 py.None()
@@ -0,0 +1,61 @@
+#![allow(clippy::unwrap_used)]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::{thread_rng, Rng};
+use vortex_array::array::BoolArray;
+use vortex_array::compute::{and, and_kleene, or_kleene, slice, xor};
+use vortex_array::IntoArrayData;
+use vortex_error::VortexError;
+
+const LEN: usize = 10_000_000;
+
+fn boolean(c: &mut Criterion) {
+    let mut group = c.benchmark_group("boolean");
+
+    let mut rng = thread_rng();
+    let lhs = BoolArray::from_iter((0..LEN).map(|_| rng.gen_bool(0.5))).into_array();
+    let rhs = BoolArray::from_iter((0..LEN).map(|_| rng.gen_bool(0.5))).into_array();
+    let nullable =
+        BoolArray::from_iter((0..LEN).map(|_| rng.gen_bool(0.9).then(|| rng.gen()))).into_array();
+
+    group.bench_function("and", |b| {
+        b.iter(|| {
+            black_box(and(&lhs, &rhs).unwrap());
+            Ok::<(), VortexError>(())
+        });
+    });
+
+    group.bench_function("xor", |b| {
+        b.iter(|| {
+            black_box(xor(&lhs, &rhs).unwrap());
+            Ok::<(), VortexError>(())
+        });
+    });
+
+    // Slices at different bit offsets can't be combined byte by byte.
+    let lhs_unaligned = slice(&lhs, 3, LEN - 5).unwrap();
+    let rhs_unaligned = slice(&rhs, 6, LEN - 2).unwrap();
+    group.bench_function("and_unaligned", |b| {
+        b.iter(|| {
+            black_box(and(&lhs_unaligned, &rhs_unaligned).unwrap());
+            Ok::<(), VortexError>(())
+        });
+    });
+
+    group.bench_function("and_kleene_nullable", |b| {
+        b.iter(|| {
+            black_box(and_kleene(&lhs, &nullable).unwrap());
+            Ok::<(), VortexError>(())
+        });
+    });
+
+    group.bench_function("or_kleene_nullable", |b| {
+        b.iter(|| {
+            black_box(or_kleene(&nullable, &rhs).unwrap());
+            Ok::<(), VortexError>(())
+        });
+    });
+}
+
+criterion_group!(benches, boolean);
+criterion_main!(benches);
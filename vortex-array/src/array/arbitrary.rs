@@ -2,10 +2,10 @@ use std::iter;
 
 use arbitrary::{Arbitrary, Result, Unstructured};
 use arrow_buffer::BooleanBuffer;
-use vortex_dtype::{DType, NativePType, Nullability, PType};
+use vortex_dtype::{DType, DecimalDType, NativePType, Nullability, PType};
 use vortex_error::{VortexExpect, VortexUnwrap};
 
-use super::{BoolArray, ChunkedArray, NullArray, PrimitiveArray, StructArray};
+use super::{BoolArray, ChunkedArray, DecimalArray, NullArray, PrimitiveArray, StructArray};
 use crate::array::{VarBinArray, VarBinViewArray};
 use crate::validity::Validity;
 use crate::{ArrayDType, ArrayData, IntoArrayData as _, IntoArrayVariant};
@@ -17,7 +17,9 @@ impl<'a> Arbitrary<'a> for ArrayData {
     }
 }
 
-fn random_array(u: &mut Unstructured, dtype: &DType, len: Option<usize>) -> Result<ArrayData> {
+/// A random array of the given dtype, with the given length or else a random one, which may be
+/// split into chunks.
+pub fn random_array(u: &mut Unstructured, dtype: &DType, len: Option<usize>) -> Result<ArrayData> {
     let num_chunks = u.int_in_range(1..=3)?;
     let chunk_lens = len.map(|l| split_number_into_parts(l, num_chunks));
     let mut chunks = (0..num_chunks)
@@ -81,8 +83,11 @@ fn random_array(u: &mut Unstructured, dtype: &DType, len: Option<usize>) -> Resu
                     .vortex_unwrap()
                     .into_array())
                 }
+                DType::Decimal(decimal_dtype, n) => {
+                    random_decimal(u, *decimal_dtype, *n, chunk_len)
+                }
                 // TOOD(joe): add arbitrary list
-                DType::List(..) => {
+                DType::List(..) | DType::FixedSizeList(..) => {
                     todo!("List arrays are not implemented")
                 }
                 DType::Extension(..) => {
@@ -171,6 +176,20 @@ fn random_primitive<'a, T: Arbitrary<'a> + NativePType>(
     Ok(PrimitiveArray::from_vec(v, validity).into_array())
 }
 
+fn random_decimal(
+    u: &mut Unstructured,
+    decimal_dtype: DecimalDType,
+    nullability: Nullability,
+    len: Option<usize>,
+) -> Result<ArrayData> {
+    // Random values ignore the precision, which bounds the number of digits
+    let v = arbitrary_vec_of_len::<i128>(u, len)?;
+    let validity = random_validity(u, nullability, v.len())?;
+    Ok(DecimalArray::from_values(v, decimal_dtype, validity)
+        .vortex_expect("Validity length cannot mismatch")
+        .into_array())
+}
+
 fn random_bool(
     u: &mut Unstructured,
     nullability: Nullability,
@@ -0,0 +1,115 @@
+use arrow_buffer::BooleanBuffer;
+use vortex_error::VortexResult;
+
+use crate::array::{BoolArray, BoolEncoding};
+use crate::compute::{BinaryBooleanFn, BinaryOperator};
+use crate::encoding::Encoding;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
+
+impl BinaryBooleanFn<BoolArray> for BoolEncoding {
+    fn binary_boolean(
+        &self,
+        array: &BoolArray,
+        other: &ArrayData,
+        op: BinaryOperator,
+    ) -> VortexResult<Option<ArrayData>> {
+        // Other encodings may have kernels that don't need to decode them.
+        if !other.is_encoding(BoolEncoding::ID) && !other.is_constant() {
+            return Ok(None);
+        }
+        bool_boolean(array, &other.clone().into_bool()?, op).map(Some)
+    }
+}
+
+/// Apply the operator to the bits of both arrays a word at a time, whatever their bit offsets.
+pub(crate) fn bool_boolean(
+    lhs: &BoolArray,
+    rhs: &BoolArray,
+    op: BinaryOperator,
+) -> VortexResult<ArrayData> {
+    let lhs_bits = lhs.boolean_buffer();
+    let rhs_bits = rhs.boolean_buffer();
+
+    let values = match op {
+        BinaryOperator::And | BinaryOperator::AndKleene => &lhs_bits & &rhs_bits,
+        BinaryOperator::Or | BinaryOperator::OrKleene => &lhs_bits | &rhs_bits,
+        BinaryOperator::Xor => &lhs_bits ^ &rhs_bits,
+    };
+    let validity = match op {
+        BinaryOperator::AndKleene => kleene_validity(lhs, rhs, &!&lhs_bits, &!&rhs_bits)?,
+        BinaryOperator::OrKleene => kleene_validity(lhs, rhs, &lhs_bits, &rhs_bits)?,
+        BinaryOperator::And | BinaryOperator::Or | BinaryOperator::Xor => {
+            lhs.validity().and(rhs.validity())?
+        }
+    };
+
+    Ok(BoolArray::try_new(values, validity)?.into_array())
+}
+
+/// Under Kleene logic, a valid value that decides the result on its own, i.e. false for _and_ and
+/// true for _or_, makes the result valid even where the other side is null.
+fn kleene_validity(
+    lhs: &BoolArray,
+    rhs: &BoolArray,
+    lhs_decides: &BooleanBuffer,
+    rhs_decides: &BooleanBuffer,
+) -> VortexResult<Validity> {
+    let lhs_valid = lhs.logical_validity().to_null_buffer()?;
+    let rhs_valid = rhs.logical_validity().to_null_buffer()?;
+    if lhs_valid.is_none() && rhs_valid.is_none() {
+        return lhs.validity().and(rhs.validity());
+    }
+
+    let lhs_valid = lhs_valid.map_or_else(|| BooleanBuffer::new_set(lhs.len()), |b| b.into_inner());
+    let rhs_valid = rhs_valid.map_or_else(|| BooleanBuffer::new_set(rhs.len()), |b| b.into_inner());
+    let valid =
+        &(&(&lhs_valid & &rhs_valid) | &(&lhs_valid & lhs_decides)) | &(&rhs_valid & rhs_decides);
+    Ok(Validity::from(valid))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_buffer::BooleanBuffer;
+
+    use crate::array::BoolArray;
+    use crate::compute::{and, and_kleene, or_kleene, scalar_at, slice, xor};
+    use crate::{ArrayData, IntoArrayData};
+
+    fn bools(array: &ArrayData) -> Vec<Option<bool>> {
+        (0..array.len())
+            .map(|i| scalar_at(array, i).unwrap().as_bool().value())
+            .collect()
+    }
+
+    #[test]
+    fn kleene() {
+        let lhs = BoolArray::from_iter([Some(true), Some(false), None, None, None]).into_array();
+        let rhs = BoolArray::from_iter([None, None, Some(true), Some(false), None]).into_array();
+
+        assert_eq!(
+            bools(&and_kleene(&lhs, &rhs).unwrap()),
+            vec![None, Some(false), None, Some(false), None]
+        );
+        assert_eq!(
+            bools(&or_kleene(&lhs, &rhs).unwrap()),
+            vec![Some(true), None, Some(true), None, None]
+        );
+        assert_eq!(bools(&and(&lhs, &rhs).unwrap()), vec![None; 5]);
+    }
+
+    #[test]
+    fn unaligned_offsets() {
+        // Slicing at different bit offsets makes the words of each side straddle bytes differently
+        let lhs = BoolArray::from(BooleanBuffer::collect_bool(300, |i| i % 3 == 0)).into_array();
+        let rhs = BoolArray::from(BooleanBuffer::collect_bool(300, |i| i % 5 == 0)).into_array();
+        let lhs = slice(&lhs, 3, 203).unwrap();
+        let rhs = slice(&rhs, 61, 261).unwrap();
+
+        let result = bools(&xor(&lhs, &rhs).unwrap());
+        let expected = (0..200)
+            .map(|i| Some(((i + 3) % 3 == 0) ^ ((i + 61) % 5 == 0)))
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected);
+    }
+}
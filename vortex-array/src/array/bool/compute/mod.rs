@@ -5,6 +5,7 @@ use crate::compute::{
 };
 use crate::ArrayData;
 
+mod boolean;
 mod fill_forward;
 mod fill_null;
 pub mod filter;
@@ -14,13 +15,11 @@ mod scalar_at;
 mod slice;
 mod take;
 
+pub(crate) use boolean::bool_boolean;
+
 impl ComputeVTable for BoolEncoding {
     fn binary_boolean_fn(&self) -> Option<&dyn BinaryBooleanFn<ArrayData>> {
-        // We only implement this when other is a constant value, otherwise we fall back to the
-        // default implementation that canonicalizes to Arrow.
-        // TODO(ngates): implement this for constants.
-        // other.is_constant().then_some(self)
-        None
+        Some(self)
     }
 
     fn fill_forward_fn(&self) -> Option<&dyn FillForwardFn<ArrayData>> {
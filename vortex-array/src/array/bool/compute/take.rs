@@ -5,7 +5,7 @@ use vortex_dtype::match_each_integer_ptype;
 use vortex_error::VortexResult;
 
 use crate::array::{BoolArray, BoolEncoding};
-use crate::compute::TakeFn;
+use crate::compute::{null_on_oob_indices, TakeFn, TakeOptions};
 use crate::variants::PrimitiveArrayTrait;
 use crate::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
 
@@ -55,6 +55,30 @@ impl TakeFn<BoolArray> for BoolEncoding {
         let validity = unsafe { validity.take_unchecked(indices.as_ref())? };
         Ok(BoolArray::try_new(buffer, validity)?.into_array())
     }
+
+    fn has_take_unchecked(&self) -> bool {
+        true
+    }
+
+    fn take_with_options(
+        &self,
+        array: &BoolArray,
+        indices: &ArrayData,
+        options: TakeOptions,
+    ) -> VortexResult<Option<ArrayData>> {
+        if !options.null_on_oob {
+            return self.take(array, indices).map(Some);
+        }
+
+        let (indices, in_bounds) = null_on_oob_indices(indices, array.len())?;
+        let buffer = take_bool(&array.boolean_buffer(), indices.maybe_null_slice::<u64>());
+        let validity = array
+            .validity()
+            .take(indices.as_ref())?
+            .into_nullable()
+            .and(in_bounds)?;
+        Ok(Some(BoolArray::try_new(buffer, validity)?.into_array()))
+    }
 }
 
 fn take_byte_bool<I: AsPrimitive<usize>>(bools: Vec<bool>, indices: &[I]) -> BooleanBuffer {
@@ -93,7 +117,8 @@ fn take_bool_unchecked<I: AsPrimitive<usize>>(
 mod test {
     use crate::array::primitive::PrimitiveArray;
     use crate::array::BoolArray;
-    use crate::compute::take;
+    use crate::compute::{take, take_with_options, TakeOptions};
+    use crate::validity::ArrayValidity;
 
     #[test]
     fn take_nullable() {
@@ -112,4 +137,27 @@ mod test {
             BoolArray::from_iter(vec![Some(false), None, Some(false)]).boolean_buffer()
         );
     }
+
+    #[test]
+    fn take_null_on_oob() {
+        let reference = BoolArray::from_iter([true, false, true]);
+        let indices = PrimitiveArray::from_nullable_vec(vec![Some(1i64), Some(-3), None, Some(2)]);
+        let b = BoolArray::try_from(
+            take_with_options(
+                &reference,
+                &indices,
+                TakeOptions {
+                    null_on_oob: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            (0..4).map(|i| b.is_valid(i)).collect::<Vec<_>>(),
+            [true, false, false, true]
+        );
+        assert!(!b.boolean_buffer().value(0) && b.boolean_buffer().value(3));
+    }
 }
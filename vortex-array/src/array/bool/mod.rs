@@ -3,12 +3,17 @@ use std::sync::Arc;
 
 use arrow_array::BooleanArray;
 use arrow_buffer::{BooleanBufferBuilder, MutableBuffer};
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_buffer::Buffer;
 use vortex_dtype::{DType, Nullability};
 use vortex_error::{VortexExpect as _, VortexResult};
 
+use crate::builders::ArrayBuilder;
 use crate::encoding::ids;
+use crate::metadata::{
+    flexbuffers_root, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
+};
 use crate::stats::StatsSet;
 use crate::validity::{LogicalValidity, Validity, ValidityMetadata, ValidityVTable};
 use crate::variants::{BoolArrayTrait, VariantsVTable};
@@ -23,21 +28,58 @@ mod stats;
 
 // Re-export the BooleanBuffer type on our API surface.
 pub use arrow_buffer::BooleanBuffer;
+pub(crate) use compute::bool_boolean;
 
 impl_encoding!("vortex.bool", ids::BOOL, Bool);
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the validity followed by the bit offset as a
+/// u8.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(remote = "Self")]
 pub struct BoolMetadata {
     validity: ValidityMetadata,
     first_byte_bit_offset: u8,
 }
 
+impl BoolMetadata {
+    /// The offset of the first value within the first byte of the buffer.
+    pub(crate) fn first_byte_bit_offset(&self) -> usize {
+        self.first_byte_bit_offset as usize
+    }
+}
+
 impl Display for BoolMetadata {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         Debug::fmt(self, f)
     }
 }
 
+impl TrySerializeArrayMetadata for BoolMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        self.validity.write_to(&mut writer);
+        writer.put_u8(self.first_byte_bit_offset);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for BoolMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let validity = ValidityMetadata::read_from(&mut reader)?;
+        let first_byte_bit_offset = reader.get_u8()?;
+        reader.finish()?;
+        Ok(Self {
+            validity,
+            first_byte_bit_offset,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl BoolArray {
     /// Access internal array buffer
     pub fn buffer(&self) -> &Buffer {
@@ -185,6 +227,10 @@ impl IntoCanonical for BoolArray {
     fn into_canonical(self) -> VortexResult<Canonical> {
         Ok(Canonical::Bool(self))
     }
+
+    fn canonicalize_into(self, builder: &mut dyn ArrayBuilder) -> VortexResult<()> {
+        builder.extend_from_array(self.as_ref())
+    }
 }
 
 impl ValidityVTable<BoolArray> for BoolEncoding {
@@ -193,7 +239,10 @@ impl ValidityVTable<BoolArray> for BoolEncoding {
     }
 
     fn logical_validity(&self, array: &BoolArray) -> LogicalValidity {
-        array.validity().to_logical(array.len())
+        array
+            .metadata()
+            .validity
+            .to_logical(array.len(), || array.validity())
     }
 }
 
@@ -17,6 +17,16 @@ impl StatisticsVTable<BoolArray> for BoolEncoding {
             return Ok(StatsSet::of(stat, array.nbytes()));
         }
 
+        if stat == Stat::NullCount {
+            return Ok(StatsSet::of(
+                stat,
+                array
+                    .metadata()
+                    .validity
+                    .null_count(array.len(), || array.validity())?,
+            ));
+        }
+
         if array.is_empty() {
             return Ok(StatsSet::new_unchecked(vec![
                 (Stat::TrueCount, 0.into()),
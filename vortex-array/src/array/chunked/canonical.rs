@@ -1,5 +1,5 @@
 use arrow_buffer::{BooleanBufferBuilder, Buffer, MutableBuffer, ScalarBuffer};
-use vortex_dtype::{DType, Nullability, PType, StructDType};
+use vortex_dtype::{DType, DecimalDType, Nullability, PType, StructDType};
 use vortex_error::{vortex_bail, vortex_err, ErrString, VortexExpect, VortexResult};
 
 use crate::array::chunked::ChunkedArray;
@@ -7,7 +7,10 @@ use crate::array::extension::ExtensionArray;
 use crate::array::null::NullArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::struct_::StructArray;
-use crate::array::{BinaryView, BoolArray, ListArray, VarBinViewArray};
+use crate::array::{
+    BinaryView, BoolArray, DecimalArray, FixedSizeListArray, ListArray, VarBinViewArray,
+};
+use crate::builders::{builder_with_capacity, ArrayBuilder};
 use crate::compute::{scalar_at, slice, try_cast};
 use crate::validity::Validity;
 use crate::{
@@ -24,6 +27,37 @@ impl IntoCanonical for ChunkedArray {
         };
         try_canonicalize_chunks(self.chunks().collect(), validity, self.dtype())
     }
+
+    fn canonicalize_into(self, builder: &mut dyn ArrayBuilder) -> VortexResult<()> {
+        for chunk in self.chunks() {
+            chunk.canonicalize_into(builder)?;
+        }
+        Ok(())
+    }
+}
+
+/// Concatenate `chunks` into a single canonical array.
+///
+/// Primitive, bool and string-like chunks are decoded straight into one builder, the rest go
+/// through [`ChunkedArray::into_canonical`].
+pub(crate) fn concat_chunks(chunks: Vec<ArrayData>, dtype: &DType) -> VortexResult<ArrayData> {
+    match dtype {
+        DType::Bool(_) | DType::Primitive(..) | DType::Utf8(_) | DType::Binary(_) => {
+            concat_into_builder(chunks, dtype)
+        }
+        _ => Ok(ChunkedArray::try_new(chunks, dtype.clone())?
+            .into_canonical()?
+            .into()),
+    }
+}
+
+fn concat_into_builder(chunks: Vec<ArrayData>, dtype: &DType) -> VortexResult<ArrayData> {
+    let len = chunks.iter().map(ArrayLen::len).sum();
+    let mut builder = builder_with_capacity(dtype, len);
+    for chunk in chunks {
+        chunk.canonicalize_into(builder.as_mut())?;
+    }
+    builder.finish()
 }
 
 pub(crate) fn try_canonicalize_chunks(
@@ -97,6 +131,11 @@ pub(crate) fn try_canonicalize_chunks(
             Ok(Canonical::List(list))
         }
 
+        DType::FixedSizeList(elem_dtype, list_size, _) => {
+            let list = pack_fixed_size_lists(chunks.as_slice(), validity, elem_dtype, *list_size)?;
+            Ok(Canonical::FixedSizeList(list))
+        }
+
         DType::Bool(_) => {
             let bool_array = pack_bools(chunks.as_slice(), validity)?;
             Ok(Canonical::Bool(bool_array))
@@ -105,6 +144,10 @@ pub(crate) fn try_canonicalize_chunks(
             let prim_array = pack_primitives(chunks.as_slice(), *ptype, validity)?;
             Ok(Canonical::Primitive(prim_array))
         }
+        DType::Decimal(decimal_dtype, _) => {
+            let decimal_array = pack_decimals(chunks.as_slice(), *decimal_dtype, validity)?;
+            Ok(Canonical::Decimal(decimal_array))
+        }
         DType::Utf8(_) => {
             let varbin_array = pack_views(chunks.as_slice(), dtype, validity)?;
             Ok(Canonical::VarBinView(varbin_array))
@@ -165,6 +208,28 @@ fn pack_lists(chunks: &[ArrayData], validity: Validity, dtype: &DType) -> Vortex
     ListArray::try_new(chunked_elements, offsets.into_array(), validity)
 }
 
+/// Fixed size lists need no offsets, so the elements of every chunk can simply be chunked together.
+fn pack_fixed_size_lists(
+    chunks: &[ArrayData],
+    validity: Validity,
+    elem_dtype: &DType,
+    list_size: u32,
+) -> VortexResult<FixedSizeListArray> {
+    let len = chunks.iter().map(|c| c.len()).sum();
+    let elements = chunks
+        .iter()
+        .map(|chunk| {
+            chunk
+                .clone()
+                .into_fixed_size_list()
+                .map(|list| list.elements())
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+    let chunked_elements = ChunkedArray::try_new(elements, elem_dtype.clone())?.into_array();
+
+    FixedSizeListArray::try_with_len(chunked_elements, list_size, len, validity)
+}
+
 /// Swizzle the pointers within a ChunkedArray of StructArrays to instead be a single
 /// StructArray, where the Array for each Field is a ChunkedArray.
 ///
@@ -230,6 +295,21 @@ fn pack_primitives(
     ))
 }
 
+fn pack_decimals(
+    chunks: &[ArrayData],
+    decimal_dtype: DecimalDType,
+    validity: Validity,
+) -> VortexResult<DecimalArray> {
+    let len: usize = chunks.iter().map(|chunk| chunk.len()).sum();
+    let mut buffer = MutableBuffer::with_capacity(len * decimal_dtype.value_type().byte_width());
+    for chunk in chunks {
+        let chunk = chunk.clone().into_decimal()?;
+        buffer.extend_from_slice(chunk.buffer());
+    }
+
+    DecimalArray::try_new(Buffer::from(buffer).into(), decimal_dtype, validity)
+}
+
 /// Builds a new [VarBinViewArray] by repacking the values from the chunks into a single
 /// contiguous array.
 ///
@@ -295,11 +375,16 @@ mod tests {
 
     use crate::accessor::ArrayAccessor;
     use crate::array::chunked::canonical::pack_views;
-    use crate::array::{ChunkedArray, ListArray, StructArray, VarBinViewArray};
+    use crate::array::{
+        ChunkedArray, FixedSizeListArray, ListArray, PrimitiveArray, StructArray, VarBinViewArray,
+    };
+    use crate::builders::builder_with_capacity;
     use crate::compute::{scalar_at, slice};
     use crate::validity::Validity;
     use crate::variants::StructArrayTrait;
-    use crate::{ArrayDType, ArrayLen, IntoArrayData, IntoArrayVariant, ToArrayData};
+    use crate::{
+        ArrayDType, ArrayLen, IntoArrayData, IntoArrayVariant, IntoCanonical, ToArrayData,
+    };
 
     fn stringview_array() -> VarBinViewArray {
         VarBinViewArray::from_iter_str(["foo", "bar", "baz", "quak"])
@@ -394,4 +479,51 @@ mod tests {
             scalar_at(canon_values, 1).unwrap()
         );
     }
+
+    #[test]
+    pub fn pack_fixed_size_lists() {
+        let l1 =
+            FixedSizeListArray::try_new(vec![1, 2, 3, 4].into_array(), 2, Validity::NonNullable)
+                .unwrap();
+        let l2 =
+            FixedSizeListArray::try_new(vec![5, 6].into_array(), 2, Validity::NonNullable).unwrap();
+
+        let canon_values = ChunkedArray::try_new(
+            vec![l1.clone().into_array(), l2.clone().into_array()],
+            l1.dtype().clone(),
+        )
+        .unwrap()
+        .into_fixed_size_list()
+        .unwrap();
+
+        assert_eq!(canon_values.len(), 3);
+        assert_eq!(
+            scalar_at(l1, 1).unwrap(),
+            scalar_at(canon_values.clone(), 1).unwrap()
+        );
+        assert_eq!(
+            scalar_at(l2, 0).unwrap(),
+            scalar_at(canon_values, 2).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn canonicalize_into_builder() {
+        let chunked = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from(vec![1i32, 2]).into_array(),
+                PrimitiveArray::from(vec![3i32]).into_array(),
+            ],
+            Primitive(I32, NonNullable),
+        )
+        .unwrap()
+        .into_array();
+
+        let mut builder = builder_with_capacity(chunked.dtype(), 6);
+        chunked.clone().canonicalize_into(builder.as_mut()).unwrap();
+        chunked.canonicalize_into(builder.as_mut()).unwrap();
+
+        let primitive = builder.finish().unwrap().into_primitive().unwrap();
+        assert_eq!(primitive.maybe_null_slice::<i32>(), &[1, 2, 3, 1, 2, 3]);
+    }
 }
@@ -1,6 +1,7 @@
 use vortex_dtype::DType;
 use vortex_error::VortexResult;
 
+use crate::array::chunked::compute::try_map_chunks;
 use crate::array::{ChunkedArray, ChunkedEncoding};
 use crate::compute::{compare, slice, CompareFn, Operator};
 use crate::{ArrayDType, ArrayData, IntoArrayData};
@@ -13,16 +14,18 @@ impl CompareFn<ChunkedArray> for ChunkedEncoding {
         operator: Operator,
     ) -> VortexResult<Option<ArrayData>> {
         let mut idx = 0;
-        let mut compare_chunks = Vec::with_capacity(lhs.nchunks());
-
+        let mut chunks = Vec::with_capacity(lhs.nchunks());
         for chunk in lhs.chunks() {
-            let sliced = slice(rhs, idx, idx + chunk.len())?;
-            let cmp_result = compare(&chunk, &sliced, operator)?;
-            compare_chunks.push(cmp_result);
-
+            let start = idx;
             idx += chunk.len();
+            chunks.push((chunk, start));
         }
 
+        let compare_chunks = try_map_chunks(chunks, |(chunk, start)| {
+            let sliced = slice(rhs, start, start + chunk.len())?;
+            compare(&chunk, &sliced, operator)
+        })?;
+
         Ok(Some(
             ChunkedArray::try_new(
                 compare_chunks,
@@ -1,12 +1,10 @@
-use arrow_buffer::BooleanBufferBuilder;
-use vortex_error::{VortexExpect, VortexResult, VortexUnwrap};
+use vortex_error::{VortexExpect, VortexResult};
 
+use crate::array::chunked::compute::try_map_chunks;
+use crate::array::chunked::find_chunk_idx;
 use crate::array::{ChunkedArray, ChunkedEncoding, PrimitiveArray};
-use crate::compute::{filter, take, FilterFn, FilterMask, SearchSorted, SearchSortedSide};
-use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoCanonical};
-
-// This is modeled after the constant with the equivalent name in arrow-rs.
-const FILTER_SLICES_SELECTIVITY_THRESHOLD: f64 = 0.8;
+use crate::compute::{filter, take, FilterFn, FilterMask, FILTER_SLICES_SELECTIVITY_THRESHOLD};
+use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData};
 
 impl FilterFn<ChunkedArray> for ChunkedEncoding {
     fn filter(&self, array: &ChunkedArray, mask: FilterMask) -> VortexResult<ArrayData> {
@@ -36,41 +34,13 @@ enum ChunkFilter {
     Slices(Vec<(usize, usize)>),
 }
 
-/// Given a sequence of slices that indicate ranges of set values, returns a boolean array
-/// representing the same thing.
-fn slices_to_mask(slices: &[(usize, usize)], len: usize) -> FilterMask {
-    let mut buffer = BooleanBufferBuilder::new(len);
-
-    let mut pos = 0;
-    for (slice_start, slice_end) in slices.iter().copied() {
-        // write however many trailing `false` between the end of the previous slice and the
-        // start of this one.
-        let n_leading_false = slice_start - pos;
-        buffer.append_n(n_leading_false, false);
-        buffer.append_n(slice_end - slice_start, true);
-        pos = slice_end;
-    }
-
-    // Pad the end of the buffer with false, if necessary.
-    let n_trailing_false = len - pos;
-    buffer.append_n(n_trailing_false, false);
-
-    FilterMask::from(buffer.finish())
-}
-
 /// Filter the chunks using slice ranges.
-#[allow(deprecated)]
 fn filter_slices(array: &ChunkedArray, mask: FilterMask) -> VortexResult<Vec<ArrayData>> {
-    let mut result = Vec::with_capacity(array.nchunks());
-
-    // Pre-materialize the chunk ends for performance.
-    // The chunk ends is nchunks+1, which is expected to be in the hundreds or at most thousands.
-    let chunk_ends = array.chunk_offsets().into_canonical()?.into_primitive()?;
-    let chunk_ends = chunk_ends.maybe_null_slice::<u64>();
+    let chunk_ends = array.chunk_offsets_slice();
 
     let mut chunk_filters = vec![ChunkFilter::None; array.nchunks()];
 
-    for (slice_start, slice_end) in mask.iter_slices()? {
+    for &(slice_start, slice_end) in mask.slices()? {
         let (start_chunk, start_idx) = find_chunk_idx(slice_start, chunk_ends);
         // NOTE: we adjust slice end back by one, in case it ends on a chunk boundary, we do not
         // want to index into the unused chunk.
@@ -117,83 +87,48 @@ fn filter_slices(array: &ChunkedArray, mask: FilterMask) -> VortexResult<Vec<Arr
         }
     }
 
-    // Now, apply the chunk filter to every slice.
-    for (chunk, chunk_filter) in array.chunks().zip(chunk_filters.iter()) {
-        match chunk_filter {
+    // Now, apply the chunk filter to every chunk.
+    let chunks = array
+        .chunks()
+        .zip(chunk_filters)
+        .filter_map(|(chunk, chunk_filter)| match chunk_filter {
             // All => preserve the entire chunk unfiltered.
-            ChunkFilter::All => result.push(chunk),
+            ChunkFilter::All => Some((chunk, None)),
             // None => whole chunk is filtered out, skip
-            ChunkFilter::None => {}
-            // Slices => turn the slices into a boolean buffer.
-            ChunkFilter::Slices(slices) => {
-                result.push(filter(&chunk, slices_to_mask(slices, chunk.len()))?);
-            }
-        }
-    }
-
-    Ok(result)
+            ChunkFilter::None => None,
+            ChunkFilter::Slices(slices) => Some((chunk, Some(slices))),
+        })
+        .collect::<Vec<_>>();
+    try_map_chunks(chunks, |(chunk, slices)| match slices {
+        None => Ok(chunk),
+        // Slices => turn the slices into a boolean buffer.
+        Some(slices) => filter(&chunk, FilterMask::from_slices(chunk.len(), slices)),
+    })
 }
 
 /// Filter the chunks using indices.
-#[allow(deprecated)]
 fn filter_indices(array: &ChunkedArray, mask: FilterMask) -> VortexResult<Vec<ArrayData>> {
-    let mut result = Vec::new();
-    let mut current_chunk_id = 0;
-    let mut chunk_indices = Vec::new();
+    let mut chunk_indices: Vec<(usize, Vec<u64>)> = Vec::new();
 
-    // Avoid find_chunk_idx and use our own to avoid the overhead.
-    // The array should only be some thousands of values in the general case.
-    let chunk_ends = array.chunk_offsets().into_canonical()?.into_primitive()?;
-    let chunk_ends = chunk_ends.maybe_null_slice::<u64>();
+    let chunk_ends = array.chunk_offsets_slice();
 
-    for set_index in mask.iter_indices()? {
+    for &set_index in mask.indices()? {
         let (chunk_id, index) = find_chunk_idx(set_index, chunk_ends);
-        if chunk_id != current_chunk_id {
-            // Push the chunk we've accumulated.
-            if !chunk_indices.is_empty() {
-                let chunk = array
-                    .chunk(current_chunk_id)
-                    .vortex_expect("find_chunk_idx must return valid chunk ID");
-                let filtered_chunk = take(
-                    chunk,
-                    PrimitiveArray::from(chunk_indices.clone()).into_array(),
-                )?;
-                result.push(filtered_chunk);
+        match chunk_indices.last_mut() {
+            Some((current_chunk_id, indices)) if *current_chunk_id == chunk_id => {
+                indices.push(index as u64);
             }
-
-            // Advance the chunk forward, reset the chunk indices buffer.
-            current_chunk_id = chunk_id;
-            chunk_indices.clear();
+            // Start accumulating the indices of the next chunk.
+            _ => chunk_indices.push((chunk_id, vec![index as u64])),
         }
-
-        chunk_indices.push(index as u64);
     }
 
-    if !chunk_indices.is_empty() {
+    try_map_chunks(chunk_indices, |(chunk_id, indices)| {
         let chunk = array
-            .chunk(current_chunk_id)
+            .chunk(chunk_id)
             .vortex_expect("find_chunk_idx must return valid chunk ID");
-        let filtered_chunk = take(
-            &chunk,
-            PrimitiveArray::from(chunk_indices.clone()).into_array(),
-        )?;
-        result.push(filtered_chunk);
-    }
-
-    Ok(result)
-}
-
-// Mirrors the find_chunk_idx method on ChunkedArray, but avoids all of the overhead
-// from scalars, dtypes, and metadata cloning.
-fn find_chunk_idx(idx: usize, chunk_ends: &[u64]) -> (usize, usize) {
-    let chunk_id = chunk_ends
-        .search_sorted(&(idx as u64), SearchSortedSide::Right)
-        .to_ends_index(chunk_ends.len())
-        .saturating_sub(1);
-    let chunk_begin: usize = chunk_ends[chunk_id].try_into().vortex_unwrap();
-    let chunk_offset = idx - chunk_begin;
-
-    (chunk_id, chunk_offset)
+        take(&chunk, PrimitiveArray::from(indices).into_array())
+    })
 }
 
 #[cfg(test)]
@@ -202,23 +137,9 @@ mod test {
     use vortex_dtype::half::f16;
     use vortex_dtype::{DType, Nullability, PType};
 
-    use crate::array::chunked::compute::filter::slices_to_mask;
     use crate::array::{ChunkedArray, PrimitiveArray};
     use crate::compute::{filter, FilterMask};
-    use crate::IntoArrayData;
-
-    #[test]
-    fn test_slices_to_predicate() {
-        let slices = [(2, 4), (6, 8), (9, 10)];
-        let predicate = slices_to_mask(&slices, 11);
-
-        let bools = predicate.to_boolean_buffer().unwrap().iter().collect_vec();
-
-        assert_eq!(
-            bools,
-            vec![false, false, true, true, false, false, true, true, false, true, false],
-        )
-    }
+    use crate::{IntoArrayData, IntoArrayVariant};
 
     #[test]
     fn filter_chunked_floats() {
@@ -252,4 +173,23 @@ mod test {
         let filtered = filter(&chunked, mask).unwrap();
         assert_eq!(filtered.len(), 9);
     }
+
+    #[test]
+    fn filter_chunked_indices() {
+        let chunked = ChunkedArray::try_new(
+            (0..4)
+                .map(|chunk| PrimitiveArray::from((chunk * 10..chunk * 10 + 10).collect_vec()))
+                .map(IntoArrayData::into_array)
+                .collect(),
+            DType::Primitive(PType::I32, Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_array();
+        let mask = FilterMask::from_iter((0..40).map(|i| i % 7 == 0));
+        let filtered = filter(&chunked, mask).unwrap();
+        assert_eq!(
+            filtered.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[0, 7, 14, 21, 28, 35]
+        );
+    }
 }
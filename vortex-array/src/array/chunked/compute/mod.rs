@@ -5,7 +5,7 @@ use crate::array::chunked::ChunkedArray;
 use crate::array::ChunkedEncoding;
 use crate::compute::{
     try_cast, BinaryBooleanFn, BinaryNumericFn, CastFn, CompareFn, ComputeVTable, FillNullFn,
-    FilterFn, InvertFn, ScalarAtFn, SliceFn, TakeFn,
+    FilterFn, InvertFn, SampleFn, ScalarAtFn, SearchSortedFn, SliceFn, TakeFn,
 };
 use crate::{ArrayData, IntoArrayData};
 
@@ -14,7 +14,9 @@ mod compare;
 mod fill_null;
 mod filter;
 mod invert;
+mod sample;
 mod scalar_at;
+mod search_sorted;
 mod slice;
 mod take;
 
@@ -47,10 +49,18 @@ impl ComputeVTable for ChunkedEncoding {
         Some(self)
     }
 
+    fn sample_fn(&self) -> Option<&dyn SampleFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
 
+    fn search_sorted_fn(&self) -> Option<&dyn SearchSortedFn<ArrayData>> {
+        Some(self)
+    }
+
     fn slice_fn(&self) -> Option<&dyn SliceFn<ArrayData>> {
         Some(self)
     }
@@ -60,6 +70,28 @@ impl ComputeVTable for ChunkedEncoding {
     }
 }
 
+/// Apply `f` to the work for each chunk, keeping the results in order.
+///
+/// Chunks are independent of each other, so with the `rayon` feature they are processed on the
+/// rayon thread pool.
+fn try_map_chunks<T, R, F>(items: Vec<T>, f: F) -> VortexResult<Vec<R>>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> VortexResult<R> + Send + Sync,
+{
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        items.into_par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        items.into_iter().map(f).collect()
+    }
+}
+
 impl CastFn<ChunkedArray> for ChunkedEncoding {
     fn cast(&self, array: &ChunkedArray, dtype: &DType) -> VortexResult<ArrayData> {
         let mut cast_chunks = Vec::new();
@@ -0,0 +1,68 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use vortex_error::VortexResult;
+
+use crate::array::chunked::compute::try_map_chunks;
+use crate::array::{ChunkedArray, ChunkedEncoding};
+use crate::compute::{sample, SampleFn, SampleSize};
+use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData};
+
+impl SampleFn<ChunkedArray> for ChunkedEncoding {
+    fn sample(&self, array: &ChunkedArray, size: SampleSize, seed: u64) -> VortexResult<ArrayData> {
+        // Each chunk is sampled on its own, with a seed of its own drawn from the seed of the
+        // array, and a share of the count in proportion to its length.
+        let mut rng = StdRng::seed_from_u64(seed);
+        let total = array.len() as u128;
+        let mut start = 0u128;
+        let mut work = Vec::with_capacity(array.nchunks());
+        for chunk in array.chunks() {
+            let end = start + chunk.len() as u128;
+            let chunk_size = match size {
+                SampleSize::Fraction(fraction) => SampleSize::Fraction(fraction),
+                SampleSize::Count(count) => {
+                    let count = count as u128;
+                    // The shares of consecutive chunks are rounded at their boundaries, so that
+                    // they add up to the count.
+                    SampleSize::Count(usize::try_from(
+                        count * end / total - count * start / total,
+                    )?)
+                }
+            };
+            work.push((chunk, chunk_size, rng.gen::<u64>()));
+            start = end;
+        }
+
+        let chunks = try_map_chunks(work, |(chunk, size, seed)| sample(chunk, size, seed))?;
+        Ok(ChunkedArray::try_new(chunks, array.dtype().clone())?.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::{ChunkedArray, PrimitiveArray};
+    use crate::compute::{sample, SampleSize};
+    use crate::{ArrayLen, IntoArrayData};
+
+    #[test]
+    fn sample_chunks_proportionally() {
+        let chunked = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from((0..300u32).collect::<Vec<_>>()).into_array(),
+                PrimitiveArray::from((300..400u32).collect::<Vec<_>>()).into_array(),
+            ],
+            DType::Primitive(PType::U32, Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_array();
+
+        let sampled =
+            ChunkedArray::try_from(sample(&chunked, SampleSize::Count(40), 3).unwrap()).unwrap();
+        assert_eq!(sampled.len(), 40);
+        assert_eq!(
+            sampled.chunks().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![30, 10]
+        );
+    }
+}
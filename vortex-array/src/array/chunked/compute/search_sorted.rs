@@ -0,0 +1,123 @@
+use std::cmp::Ordering;
+
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::{ChunkedArray, ChunkedEncoding};
+use crate::compute::{search_sorted, SearchResult, SearchSortedFn, SearchSortedSide};
+use crate::stats::{ArrayStatistics, Stat};
+use crate::{ArrayData, ArrayLen};
+
+impl SearchSortedFn<ChunkedArray> for ChunkedEncoding {
+    fn search_sorted(
+        &self,
+        array: &ChunkedArray,
+        value: &Scalar,
+        side: SearchSortedSide,
+    ) -> VortexResult<SearchResult> {
+        let offsets = array.chunk_offsets_slice();
+        let chunks = (0..array.nchunks())
+            .filter(|&idx| offsets[idx + 1] > offsets[idx])
+            .collect::<Vec<_>>();
+
+        // The bounds of the chunks of a sorted array are sorted too, so the chunk to search is
+        // found by binary search over them, computing only the bounds it visits. The values
+        // before the chunk are all less than the value, and the values after it are all greater.
+        let mut error = None;
+        let mut cmp_chunk = |idx: usize, stat: Stat| {
+            array
+                .chunk(idx)
+                .map(|chunk| bound_cmp(&chunk, stat, value))
+                .unwrap_or_else(|e| {
+                    error.get_or_insert(e);
+                    Ordering::Greater
+                })
+        };
+        let position = match side {
+            // The first chunk whose maximum is at least the value.
+            SearchSortedSide::Left => {
+                Some(chunks.partition_point(|&idx| cmp_chunk(idx, Stat::Max) == Ordering::Less))
+            }
+            // The last chunk whose minimum is at most the value.
+            SearchSortedSide::Right => chunks
+                .partition_point(|&idx| cmp_chunk(idx, Stat::Min) != Ordering::Greater)
+                .checked_sub(1),
+        };
+        if let Some(e) = error {
+            return Err(e);
+        }
+
+        let Some(&idx) = position.and_then(|p| chunks.get(p)) else {
+            // The value is beyond the last chunk when searching from the left, or before the
+            // first chunk when searching from the right.
+            return Ok(SearchResult::NotFound(match side {
+                SearchSortedSide::Left => array.len(),
+                SearchSortedSide::Right => 0,
+            }));
+        };
+
+        let chunk_start = usize::try_from(offsets[idx])?;
+        Ok(search_sorted(&array.chunk(idx)?, value.clone(), side)?.map(|i| i + chunk_start))
+    }
+}
+
+/// How the minimum or maximum of the chunk compares to the value.
+///
+/// Nulls sort last, so a chunk without a bound, which holds only nulls, is greater than any value.
+fn bound_cmp(chunk: &ArrayData, stat: Stat, value: &Scalar) -> Ordering {
+    chunk
+        .statistics()
+        .compute(stat)
+        .filter(|bound| !bound.is_null())
+        .and_then(|bound| bound.partial_cmp(value))
+        .unwrap_or(Ordering::Greater)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::{ChunkedArray, PrimitiveArray};
+    use crate::compute::{search_sorted, SearchResult, SearchSortedSide};
+    use crate::{ArrayData, IntoArrayData};
+
+    fn chunked() -> ArrayData {
+        ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+                PrimitiveArray::from(Vec::<i32>::new()).into_array(),
+                PrimitiveArray::from(vec![3i32, 3, 5]).into_array(),
+                PrimitiveArray::from(vec![7i32, 9]).into_array(),
+            ],
+            DType::Primitive(PType::I32, Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    #[test]
+    fn search_across_chunks() {
+        let array = chunked();
+        let cases = [
+            (0, SearchResult::NotFound(0), SearchResult::NotFound(0)),
+            (1, SearchResult::Found(0), SearchResult::Found(1)),
+            (3, SearchResult::Found(2), SearchResult::Found(5)),
+            (4, SearchResult::NotFound(5), SearchResult::NotFound(5)),
+            (6, SearchResult::NotFound(6), SearchResult::NotFound(6)),
+            (9, SearchResult::Found(7), SearchResult::Found(8)),
+            (10, SearchResult::NotFound(8), SearchResult::NotFound(8)),
+        ];
+        for (value, left, right) in cases {
+            assert_eq!(
+                search_sorted(&array, value, SearchSortedSide::Left).unwrap(),
+                left,
+                "left {value}"
+            );
+            assert_eq!(
+                search_sorted(&array, value, SearchSortedSide::Right).unwrap(),
+                right,
+                "right {value}"
+            );
+        }
+    }
+}
@@ -1,15 +1,18 @@
 use itertools::Itertools;
 use vortex_dtype::PType;
-use vortex_error::VortexResult;
+use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
-use crate::array::chunked::ChunkedArray;
+use crate::array::chunked::{find_chunk_idx, ChunkedArray};
 use crate::array::ChunkedEncoding;
 use crate::compute::{
-    scalar_at, search_sorted_usize, slice, sub_scalar, take, try_cast, SearchSortedSide, TakeFn,
+    null_on_oob_indices, scalar_at, search_sorted_usize, slice, sub_scalar, take, try_cast,
+    with_nulls, SearchSortedSide, TakeFn, TakeOptions,
 };
 use crate::stats::ArrayStatistics;
-use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant, ToArrayData};
+use crate::{
+    ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant, IntoCanonical, ToArrayData,
+};
 
 impl TakeFn<ChunkedArray> for ChunkedEncoding {
     fn take(&self, array: &ChunkedArray, indices: &ArrayData) -> VortexResult<ArrayData> {
@@ -27,40 +30,82 @@ impl TakeFn<ChunkedArray> for ChunkedEncoding {
         }
 
         let indices = try_cast(indices, PType::U64.into())?.into_primitive()?;
+        let chunk_offsets = array.chunk_offsets_slice();
 
-        // While the chunk idx remains the same, accumulate a list of chunk indices.
-        let mut chunks = Vec::new();
-        let mut indices_in_chunk = Vec::new();
-        let mut prev_chunk_idx = array
-            .find_chunk_idx(indices.maybe_null_slice::<u64>()[0].try_into()?)
-            .0;
+        // Group the indices by the chunk they fall in, keeping their order within each chunk.
+        let mut indices_by_chunk = vec![Vec::new(); array.nchunks()];
+        let mut chunk_of_index = Vec::with_capacity(indices.len());
         for idx in indices.maybe_null_slice::<u64>() {
             let idx = usize::try_from(*idx)?;
-            let (chunk_idx, idx_in_chunk) = array.find_chunk_idx(idx);
-
-            if chunk_idx != prev_chunk_idx {
-                // Start a new chunk
-                let indices_in_chunk_array = indices_in_chunk.clone().into_array();
-                chunks.push(take(
-                    &array.chunk(prev_chunk_idx)?,
-                    &indices_in_chunk_array,
-                )?);
-                indices_in_chunk = Vec::new();
+            if idx >= array.len() {
+                vortex_bail!(OutOfBounds: idx, 0, array.len());
             }
-
-            indices_in_chunk.push(idx_in_chunk as u64);
-            prev_chunk_idx = chunk_idx;
+            let (chunk_idx, idx_in_chunk) = find_chunk_idx(idx, chunk_offsets);
+            indices_by_chunk[chunk_idx].push(idx_in_chunk as u64);
+            chunk_of_index.push(chunk_idx);
         }
 
-        if !indices_in_chunk.is_empty() {
-            let indices_in_chunk_array = indices_in_chunk.into_array();
+        // Take from each chunk once, remembering where the values of each chunk start.
+        let mut chunks = Vec::new();
+        let mut chunk_starts = vec![0u64; array.nchunks()];
+        let mut taken_len = 0;
+        for (chunk_idx, indices_in_chunk) in indices_by_chunk.into_iter().enumerate() {
+            if indices_in_chunk.is_empty() {
+                continue;
+            }
+            chunk_starts[chunk_idx] = taken_len;
+            taken_len += indices_in_chunk.len() as u64;
             chunks.push(take(
-                &array.chunk(prev_chunk_idx)?,
-                &indices_in_chunk_array,
+                &array.chunk(chunk_idx)?,
+                indices_in_chunk.into_array(),
             )?);
         }
+        let taken = ChunkedArray::try_new(chunks, array.dtype().clone())?;
+
+        // The chunks are visited in order, so sorted indices are already in place.
+        if chunk_of_index.is_sorted() {
+            return Ok(taken.into_array());
+        }
+
+        // Otherwise, put the values back in the order of the indices.
+        let mut next_positions = chunk_starts;
+        let positions = chunk_of_index
+            .into_iter()
+            .map(|chunk_idx| {
+                let position = next_positions[chunk_idx];
+                next_positions[chunk_idx] += 1;
+                position
+            })
+            .collect::<Vec<_>>();
+        let reordered = take(taken.into_canonical()?.into_array(), positions.into_array())?;
+
+        Ok(ChunkedArray::try_new(vec![reordered], array.dtype().clone())?.into_array())
+    }
+
+    fn take_with_options(
+        &self,
+        array: &ChunkedArray,
+        indices: &ArrayData,
+        options: TakeOptions,
+    ) -> VortexResult<Option<ArrayData>> {
+        if !options.null_on_oob {
+            return self.take(array, indices).map(Some);
+        }
 
-        Ok(ChunkedArray::try_new(chunks, array.dtype().clone())?.into_array())
+        // Take in bounds indices, then null out each chunk of the result where they were replaced.
+        let (indices, in_bounds) = null_on_oob_indices(indices, array.len())?;
+        let taken = ChunkedArray::try_from(self.take(array, indices.as_ref())?)?;
+        let mut offset = 0;
+        let chunks = taken
+            .chunks()
+            .map(|chunk| {
+                let start = offset;
+                offset += chunk.len();
+                with_nulls(chunk, in_bounds.slice(start, offset)?)
+            })
+            .try_collect()?;
+
+        ChunkedArray::try_new(chunks, array.dtype().as_nullable()).map(|a| Some(a.into_array()))
     }
 }
 
@@ -76,8 +121,8 @@ fn take_strict_sorted(chunked: &ChunkedArray, indices: &ArrayData) -> VortexResu
         let (chunk_idx, _idx_in_chunk) = chunked.find_chunk_idx(idx);
 
         // Find the end of this chunk, and locate that position in the indices array.
-        let chunk_begin = usize::try_from(&scalar_at(chunked.chunk_offsets(), chunk_idx)?)?;
-        let chunk_end = usize::try_from(&scalar_at(chunked.chunk_offsets(), chunk_idx + 1)?)?;
+        let chunk_begin = usize::try_from(chunked.chunk_offsets_slice()[chunk_idx])?;
+        let chunk_end = usize::try_from(chunked.chunk_offsets_slice()[chunk_idx + 1])?;
         let chunk_end_pos =
             search_sorted_usize(indices, chunk_end, SearchSortedSide::Left)?.to_index();
 
@@ -121,8 +166,12 @@ fn take_strict_sorted(chunked: &ChunkedArray, indices: &ArrayData) -> VortexResu
 
 #[cfg(test)]
 mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
     use crate::array::chunked::ChunkedArray;
-    use crate::compute::take;
+    use crate::array::PrimitiveArray;
+    use crate::compute::{scalar_at, take, take_with_options, TakeOptions};
     use crate::{ArrayDType, ArrayLen, IntoArrayData, IntoArrayVariant};
 
     #[test]
@@ -141,4 +190,58 @@ mod test {
             .unwrap();
         assert_eq!(result.maybe_null_slice::<i32>(), &[1, 1, 1, 2]);
     }
+
+    #[test]
+    fn take_unsorted_across_chunks() {
+        let arr = ChunkedArray::try_new(
+            vec![
+                vec![1i32, 2, 3].into_array(),
+                vec![4i32].into_array(),
+                vec![5i32, 6].into_array(),
+            ],
+            DType::Primitive(PType::I32, Nullability::NonNullable),
+        )
+        .unwrap();
+        let indices = vec![5u64, 0, 3, 1, 5, 2].into_array();
+
+        let result = take(arr.as_ref(), &indices)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(result.maybe_null_slice::<i32>(), &[6, 1, 4, 2, 6, 3]);
+
+        let out_of_bounds = vec![1u64, 6].into_array();
+        assert!(take(arr.as_ref(), &out_of_bounds).is_err());
+    }
+
+    #[test]
+    fn test_take_null_on_oob() {
+        let a = vec![1i32, 2, 3].into_array();
+        let arr = ChunkedArray::try_new(vec![a.clone(), a.clone()], a.dtype().clone()).unwrap();
+        let indices = PrimitiveArray::from_nullable_vec(vec![Some(4u32), None, Some(6), Some(0)]);
+        let taken = take_with_options(
+            &arr,
+            &indices,
+            TakeOptions {
+                null_on_oob: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            taken.dtype(),
+            &DType::Primitive(PType::I32, Nullability::Nullable)
+        );
+        assert_eq!(
+            (0..4)
+                .map(|i| scalar_at(&taken, i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Some(2i32).into(),
+                Scalar::null(taken.dtype().clone()),
+                Scalar::null(taken.dtype().clone()),
+                Some(1i32).into()
+            ]
+        );
+    }
 }
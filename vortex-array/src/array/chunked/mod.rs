@@ -3,27 +3,34 @@
 //! Vortex is a chunked array library that's able to
 
 use std::fmt::{Debug, Display};
+use std::sync::{Arc, OnceLock};
 
 use futures_util::stream;
 use itertools::Itertools;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_dtype::{DType, Nullability, PType};
 use vortex_error::{vortex_bail, vortex_panic, VortexExpect as _, VortexResult, VortexUnwrap};
 use vortex_scalar::BinaryNumericOperator;
 
+pub(crate) use self::canonical::concat_chunks;
 use crate::array::primitive::PrimitiveArray;
+use crate::array::{VarBinArray, VarBinViewArray};
 use crate::compute::{
-    binary_numeric, scalar_at, search_sorted_usize, slice, BinaryNumericFn, SearchSortedSide,
+    binary_numeric, scalar_at, slice, sub_scalar, BinaryNumericFn, SearchSorted, SearchSortedSide,
 };
 use crate::encoding::ids;
 use crate::iter::{ArrayIterator, ArrayIteratorAdapter};
+use crate::metadata::{
+    flexbuffers_root, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
+};
 use crate::stats::StatsSet;
 use crate::stream::{ArrayStream, ArrayStreamAdapter};
 use crate::validity::Validity::NonNullable;
 use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityVTable};
 use crate::visitor::{ArrayVisitor, VisitorVTable};
 use crate::{
-    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, IntoArrayData, IntoCanonical,
+    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, IntoArrayData, IntoArrayVariant,
 };
 
 mod canonical;
@@ -33,14 +40,44 @@ mod variants;
 
 impl_encoding!("vortex.chunked", ids::CHUNKED, Chunked);
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the number of chunks as a u64.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(remote = "Self")]
 pub struct ChunkedMetadata {
     nchunks: usize,
+    /// The chunk offsets, read from the offsets child on first access and shared by the clones of
+    /// the array, so that locating an index is a binary search over a slice.
+    #[serde(skip)]
+    chunk_offsets: OnceLock<Arc<[u64]>>,
 }
 
 impl Display for ChunkedMetadata {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Debug::fmt(self, f)
+        write!(f, "ChunkedMetadata {{ nchunks: {} }}", self.nchunks)
+    }
+}
+
+impl TrySerializeArrayMetadata for ChunkedMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        writer.put_usize(self.nchunks);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for ChunkedMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let nchunks = reader.get_usize()?;
+        reader.finish()?;
+        Ok(Self {
+            nchunks,
+            chunk_offsets: OnceLock::new(),
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
     }
 }
 
@@ -68,6 +105,11 @@ impl ChunkedArray {
             .last()
             .vortex_expect("Chunk ends is guaranteed to have at least one element");
 
+        let metadata = ChunkedMetadata {
+            nchunks,
+            chunk_offsets: OnceLock::from(Arc::from(chunk_offsets.as_slice())),
+        };
+
         let mut children = Vec::with_capacity(chunks.len() + 1);
         children.push(PrimitiveArray::from_vec(chunk_offsets, NonNullable).into_array());
         children.extend(chunks);
@@ -75,7 +117,7 @@ impl ChunkedArray {
         Self::try_from_parts(
             dtype,
             length.try_into().vortex_unwrap(),
-            ChunkedMetadata { nchunks },
+            metadata,
             children.into(),
             StatsSet::default(),
         )
@@ -87,13 +129,12 @@ impl ChunkedArray {
             vortex_bail!("chunk index {} > num chunks ({})", idx, self.nchunks());
         }
 
-        let chunk_offsets = self.chunk_offsets();
-        let chunk_start = usize::try_from(&scalar_at(&chunk_offsets, idx)?)?;
-        let chunk_end = usize::try_from(&scalar_at(&chunk_offsets, idx + 1)?)?;
+        let chunk_offsets = self.chunk_offsets_slice();
+        let chunk_len = usize::try_from(chunk_offsets[idx + 1] - chunk_offsets[idx])?;
 
         // Offset the index since chunk_ends is child 0.
         self.as_ref()
-            .child(idx + 1, self.as_ref().dtype(), chunk_end - chunk_start)
+            .child(idx + 1, self.as_ref().dtype(), chunk_len)
     }
 
     pub fn nchunks(&self) -> usize {
@@ -107,22 +148,23 @@ impl ChunkedArray {
             .vortex_expect("Missing chunk ends in ChunkedArray")
     }
 
+    /// The offsets of the chunks, starting with 0 and ending with the length of the array.
+    ///
+    /// The offsets are decoded once and cached, so that they can be read without dispatching
+    /// compute functions over [`chunk_offsets`](Self::chunk_offsets).
+    pub fn chunk_offsets_slice(&self) -> &[u64] {
+        self.metadata().chunk_offsets.get_or_init(|| {
+            self.chunk_offsets()
+                .into_primitive()
+                .vortex_expect("ChunkedArray: chunk offsets must be primitive")
+                .maybe_null_slice::<u64>()
+                .into()
+        })
+    }
+
     fn find_chunk_idx(&self, index: usize) -> (usize, usize) {
         assert!(index <= self.len(), "Index out of bounds of the array");
-
-        // Since there might be duplicate values in offsets because of empty chunks we want to search from right
-        // and take the last chunk (we subtract 1 since there's a leading 0)
-        let index_chunk =
-            search_sorted_usize(&self.chunk_offsets(), index, SearchSortedSide::Right)
-                .vortex_expect("Search sorted failed in find_chunk_idx")
-                .to_ends_index(self.nchunks() + 1)
-                .saturating_sub(1);
-        let chunk_start = scalar_at(self.chunk_offsets(), index_chunk)
-            .and_then(|s| usize::try_from(&s))
-            .vortex_expect("Failed to find chunk start in find_chunk_idx");
-
-        let index_in_chunk = index - chunk_start;
-        (index_chunk, index_in_chunk)
+        find_chunk_idx(index, self.chunk_offsets_slice())
     }
 
     pub fn chunks(&self) -> impl Iterator<Item = ArrayData> + '_ {
@@ -146,24 +188,36 @@ impl ChunkedArray {
         ArrayStreamAdapter::new(self.dtype().clone(), stream::iter(self.chunks().map(Ok)))
     }
 
+    /// Rechunk the array into chunks of at most `target_bytesize` bytes and `target_rowsize` rows,
+    /// measured by the estimated canonical size of the chunks.
+    ///
+    /// Consecutive small chunks are combined into canonical chunks, and chunks bigger than the
+    /// targets are sliced into pieces, without copying them, the last of which may be combined
+    /// with the chunks after it. A single row bigger than the target bytesize is kept as a chunk
+    /// of its own.
     pub fn rechunk(&self, target_bytesize: usize, target_rowsize: usize) -> VortexResult<Self> {
         let mut new_chunks = Vec::new();
         let mut chunks_to_combine = Vec::new();
         let mut new_chunk_n_bytes = 0;
         let mut new_chunk_n_elements = 0;
         for chunk in self.chunks() {
-            let n_bytes = chunk.nbytes();
+            let (pieces, chunk) = split_chunk(chunk, target_bytesize, target_rowsize)?;
+            if !pieces.is_empty() && !chunks_to_combine.is_empty() {
+                new_chunks.push(self.combine_chunks(chunks_to_combine)?);
+                new_chunk_n_bytes = 0;
+                new_chunk_n_elements = 0;
+                chunks_to_combine = Vec::new();
+            }
+            new_chunks.extend(pieces);
+
+            let n_bytes = chunk.estimated_canonical_size();
             let n_elements = chunk.len();
 
             if (new_chunk_n_bytes + n_bytes > target_bytesize
                 || new_chunk_n_elements + n_elements > target_rowsize)
                 && !chunks_to_combine.is_empty()
             {
-                new_chunks.push(
-                    ChunkedArray::try_new(chunks_to_combine, self.dtype().clone())?
-                        .into_canonical()?
-                        .into(),
-                );
+                new_chunks.push(self.combine_chunks(chunks_to_combine)?);
 
                 new_chunk_n_bytes = 0;
                 new_chunk_n_elements = 0;
@@ -180,15 +234,76 @@ impl ChunkedArray {
         }
 
         if !chunks_to_combine.is_empty() {
-            new_chunks.push(
-                ChunkedArray::try_new(chunks_to_combine, self.dtype().clone())?
-                    .into_canonical()?
-                    .into(),
-            );
+            new_chunks.push(self.combine_chunks(chunks_to_combine)?);
         }
 
         Self::try_new(new_chunks, self.dtype().clone())
     }
+
+    fn combine_chunks(&self, chunks: Vec<ArrayData>) -> VortexResult<ArrayData> {
+        concat_chunks(chunks, self.dtype())
+    }
+}
+
+/// Slice a chunk bigger than the targets into pieces that fit them, with the rows spread evenly
+/// over its estimated canonical size.
+///
+/// Returns the pieces but the last, and the last piece, which is the chunk itself if it fits.
+fn split_chunk(
+    chunk: ArrayData,
+    target_bytesize: usize,
+    target_rowsize: usize,
+) -> VortexResult<(Vec<ArrayData>, ArrayData)> {
+    let n_bytes = chunk.estimated_canonical_size();
+    let n_elements = chunk.len();
+    if n_elements <= 1 || (n_bytes <= target_bytesize && n_elements <= target_rowsize) {
+        return Ok((Vec::new(), chunk));
+    }
+
+    let bytes_per_row = n_bytes.div_ceil(n_elements).max(1);
+    let rows_per_piece = (target_bytesize / bytes_per_row).min(target_rowsize).max(1);
+    let last_start = (n_elements - 1) / rows_per_piece * rows_per_piece;
+    let pieces = (0..last_start)
+        .step_by(rows_per_piece)
+        .map(|start| slice(&chunk, start, start + rows_per_piece).and_then(own_bytes))
+        .collect::<VortexResult<Vec<_>>>()?;
+    let last = own_bytes(slice(&chunk, last_start, n_elements)?)?;
+    Ok((pieces, last))
+}
+
+/// Make a slice of a string or binary chunk hold only the bytes of its own values.
+///
+/// Slices otherwise keep the bytes of the whole chunk, which would be written once per piece.
+fn own_bytes(piece: ArrayData) -> VortexResult<ArrayData> {
+    if let Some(varbin) = VarBinArray::maybe_from(&piece) {
+        let offsets = varbin.offsets();
+        let first_offset = scalar_at(&offsets, 0)?;
+        return VarBinArray::try_new(
+            sub_scalar(&offsets, first_offset)?,
+            varbin.sliced_bytes()?,
+            varbin.dtype().clone(),
+            varbin.validity(),
+        )
+        .map(IntoArrayData::into_array);
+    }
+    if let Some(varbinview) = VarBinViewArray::maybe_from(&piece) {
+        return varbinview.compact().map(IntoArrayData::into_array);
+    }
+    Ok(piece)
+}
+
+/// Locate the chunk of `idx` and its index within that chunk, given the offsets of the chunks.
+fn find_chunk_idx(idx: usize, chunk_offsets: &[u64]) -> (usize, usize) {
+    // Since there might be duplicate values in offsets because of empty chunks we want to search from right
+    // and take the last chunk (we subtract 1 since there's a leading 0)
+    let chunk_id = chunk_offsets
+        .search_sorted(&(idx as u64), SearchSortedSide::Right)
+        .to_ends_index(chunk_offsets.len())
+        .saturating_sub(1);
+    let chunk_begin: usize = chunk_offsets[chunk_id].try_into().vortex_unwrap();
+    let chunk_offset = idx - chunk_begin;
+
+    (chunk_id, chunk_offset)
 }
 
 impl ArrayTrait for ChunkedArray {}
@@ -262,7 +377,9 @@ mod test {
     use vortex_error::VortexResult;
 
     use crate::array::chunked::ChunkedArray;
+    use crate::array::{VarBinArray, VarBinViewArray};
     use crate::compute::{scalar_at, sub_scalar};
+    use crate::stats::{ArrayStatistics, Stat};
     use crate::{assert_arrays_eq, ArrayDType, IntoArrayData, IntoArrayVariant};
 
     fn chunked_array() -> ChunkedArray {
@@ -369,9 +486,114 @@ mod test {
         .unwrap();
 
         let rechunked = chunked.rechunk(1 << 16, 5).unwrap();
-        // greedy so should be: [0, 1, 2] [42, 42, 42, 42, 42, 42] [4, 5, 6, 7] [8, 9]
+        // greedy so should be: [0, 1, 2] [42, 42, 42, 42, 42] [42, 4, 5, 6, 7] [8, 9]
 
         assert_eq!(rechunked.nchunks(), 4);
+        assert!(rechunked.chunks().all(|c| c.len() <= 5));
+        assert_arrays_eq!(chunked, rechunked);
+    }
+
+    #[test]
+    fn test_rechunk_split_strings_own_bytes() {
+        let values = (0..8).map(|i| "x".repeat(i + 1)).collect::<Vec<_>>();
+        let chunked = ChunkedArray::try_new(
+            vec![VarBinArray::from(values).into_array()],
+            DType::Utf8(Nullability::NonNullable),
+        )
+        .unwrap();
+
+        let rechunked = chunked.rechunk(1 << 16, 3).unwrap();
+
+        assert_eq!(rechunked.nchunks(), 3);
+        // The last piece fits the targets, so it is canonicalized like any other small chunk.
+        let bytes_lens = rechunked
+            .chunks()
+            .take(2)
+            .map(|chunk| VarBinArray::try_from(chunk).unwrap().bytes().len())
+            .collect::<Vec<_>>();
+        assert_eq!(bytes_lens, vec![1 + 2 + 3, 4 + 5 + 6]);
         assert_arrays_eq!(chunked, rechunked);
     }
+
+    #[test]
+    fn test_rechunk_shares_string_blocks() {
+        let long = "a string that is too long to inline";
+        let chunks = vec![
+            VarBinViewArray::from_iter_str([long, "short"]),
+            VarBinViewArray::from_iter_str(["tiny", long]),
+        ];
+        let buffer_count = chunks
+            .iter()
+            .map(VarBinViewArray::buffer_count)
+            .sum::<usize>();
+        let chunked = ChunkedArray::try_new(
+            chunks.into_iter().map(IntoArrayData::into_array).collect(),
+            DType::Utf8(Nullability::NonNullable),
+        )
+        .unwrap();
+
+        let rechunked = chunked.rechunk(1 << 16, 1 << 16).unwrap();
+
+        assert_eq!(rechunked.nchunks(), 1);
+        let combined = VarBinViewArray::try_from(rechunked.chunk(0).unwrap()).unwrap();
+        assert_eq!(combined.buffer_count(), buffer_count);
+        assert_arrays_eq!(chunked, rechunked);
+    }
+
+    #[test]
+    fn test_rechunk_splits_by_bytesize() {
+        let chunked = ChunkedArray::try_new(
+            vec![(0u64..100).collect::<Vec<_>>().into_array()],
+            DType::Primitive(PType::U64, Nullability::NonNullable),
+        )
+        .unwrap();
+
+        // 8 bytes per row, so at most 4 rows per chunk.
+        let rechunked = chunked.rechunk(32, 1 << 16).unwrap();
+        assert_eq!(rechunked.nchunks(), 25);
+        assert!(rechunked.chunks().all(|c| c.len() == 4));
+        assert_arrays_eq!(chunked, rechunked);
+    }
+
+    #[test]
+    fn approx_distinct_merges_chunks() {
+        let chunked = ChunkedArray::try_new(
+            vec![
+                (0..600).collect::<Vec<i32>>().into_array(),
+                (400..1000).collect::<Vec<i32>>().into_array(),
+            ],
+            DType::Primitive(PType::I32, Nullability::NonNullable),
+        )
+        .unwrap();
+        let whole = (0..1000).collect::<Vec<i32>>().into_array();
+
+        assert_eq!(
+            chunked.statistics().compute(Stat::ApproxDistinct),
+            whole.statistics().compute(Stat::ApproxDistinct)
+        );
+        // A sketch of 1024 registers estimates within about 3% of the true count
+        let estimate = chunked.statistics().compute_approx_distinct().unwrap();
+        assert!(
+            (900..=1100).contains(&estimate),
+            "estimate {estimate} too far from 1000"
+        );
+    }
+
+    #[test]
+    fn find_chunk_across_empty_chunks() {
+        let chunked = ChunkedArray::try_new(
+            vec![
+                vec![1u64, 2].into_array(),
+                Vec::<u64>::new().into_array(),
+                vec![3u64].into_array(),
+            ],
+            DType::Primitive(PType::U64, Nullability::NonNullable),
+        )
+        .unwrap();
+
+        assert_eq!(chunked.chunk_offsets_slice(), &[0, 2, 2, 3]);
+        assert_eq!(chunked.find_chunk_idx(1), (0, 1));
+        assert_eq!(chunked.find_chunk_idx(2), (2, 0));
+        assert_eq!(u64::try_from(&scalar_at(&chunked, 2).unwrap()).unwrap(), 3);
+    }
 }
@@ -1,14 +1,17 @@
+use std::iter;
+
 use arrow_array::builder::make_view;
 use arrow_buffer::{BooleanBuffer, BufferBuilder};
 use vortex_buffer::Buffer;
 use vortex_dtype::{match_each_native_ptype, DType, Nullability, PType};
 use vortex_error::{vortex_bail, VortexResult};
-use vortex_scalar::{BinaryScalar, BoolScalar, ExtScalar, Utf8Scalar};
+use vortex_scalar::{BinaryScalar, BoolScalar, DecimalScalar, DecimalValue, ExtScalar, Utf8Scalar};
 
 use crate::array::constant::ConstantArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::{
-    BinaryView, BoolArray, ExtensionArray, NullArray, VarBinViewArray, VIEW_SIZE_BYTES,
+    BinaryView, BoolArray, DecimalArray, ExtensionArray, NullArray, VarBinViewArray,
+    VIEW_SIZE_BYTES,
 };
 use crate::validity::Validity;
 use crate::{ArrayDType, ArrayLen, Canonical, IntoArrayData, IntoCanonical};
@@ -53,8 +56,18 @@ impl IntoCanonical for ConstantArray {
                 let const_value = value.as_ref().map(|v| v.as_slice());
                 Canonical::VarBinView(canonical_byte_view(const_value, self.dtype(), self.len())?)
             }
+            DType::Decimal(decimal_dtype, _) => {
+                let value = DecimalScalar::try_from(scalar)?.value();
+                Canonical::Decimal(DecimalArray::from_values(
+                    iter::repeat(value.unwrap_or(DecimalValue::I128(0))).take(self.len()),
+                    *decimal_dtype,
+                    validity,
+                )?)
+            }
             DType::Struct(..) => vortex_bail!("Unsupported scalar type {}", self.dtype()),
-            DType::List(..) => vortex_bail!("Unsupported scalar type {}", self.dtype()),
+            DType::List(..) | DType::FixedSizeList(..) => {
+                vortex_bail!("Unsupported scalar type {}", self.dtype())
+            }
             DType::Extension(ext_dtype) => {
                 let s = ExtScalar::try_from(scalar)?;
 
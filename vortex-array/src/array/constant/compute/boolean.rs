@@ -35,6 +35,7 @@ impl BinaryBooleanFn<ConstantArray> for ConstantEncoding {
             BinaryOperator::AndKleene => kleene_and(lhs, rhs),
             BinaryOperator::Or => or(lhs, rhs),
             BinaryOperator::OrKleene => kleene_or(lhs, rhs),
+            BinaryOperator::Xor => xor(lhs, rhs),
         };
 
         let scalar = result
@@ -63,6 +64,10 @@ fn or(left: Option<bool>, right: Option<bool>) -> Option<bool> {
     left.zip(right).map(|(l, r)| l | r)
 }
 
+fn xor(left: Option<bool>, right: Option<bool>) -> Option<bool> {
+    left.zip(right).map(|(l, r)| l ^ r)
+}
+
 fn kleene_or(left: Option<bool>, right: Option<bool>) -> Option<bool> {
     match (left, right) {
         (Some(true), _) => Some(true),
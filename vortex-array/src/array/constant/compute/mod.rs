@@ -4,6 +4,7 @@ mod compare;
 mod invert;
 mod search_sorted;
 
+use vortex_dtype::PType;
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
@@ -11,9 +12,9 @@ use crate::array::constant::ConstantArray;
 use crate::array::ConstantEncoding;
 use crate::compute::{
     BinaryBooleanFn, BinaryNumericFn, CompareFn, ComputeVTable, FilterFn, FilterMask, InvertFn,
-    ScalarAtFn, SearchSortedFn, SliceFn, TakeFn,
+    ScalarAtFn, SearchSortedFn, SliceFn, SumAccumulator, SumFn, TakeFn,
 };
-use crate::{ArrayData, IntoArrayData};
+use crate::{ArrayData, ArrayLen, IntoArrayData};
 
 impl ComputeVTable for ConstantEncoding {
     fn binary_boolean_fn(&self) -> Option<&dyn BinaryBooleanFn<ArrayData>> {
@@ -48,6 +49,10 @@ impl ComputeVTable for ConstantEncoding {
         Some(self)
     }
 
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        Some(self)
+    }
+
     fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
         Some(self)
     }
@@ -71,6 +76,15 @@ impl SliceFn<ConstantArray> for ConstantEncoding {
     }
 }
 
+impl SumFn<ConstantArray> for ConstantEncoding {
+    fn sum(&self, array: &ConstantArray) -> VortexResult<Scalar> {
+        let scalar = array.scalar();
+        let mut acc = SumAccumulator::new(PType::try_from(scalar.dtype())?);
+        acc.add_scalar(&scalar, array.len())?;
+        Ok(acc.finish())
+    }
+}
+
 impl FilterFn<ConstantArray> for ConstantEncoding {
     fn filter(&self, array: &ConstantArray, mask: FilterMask) -> VortexResult<ArrayData> {
         Ok(ConstantArray::new(array.scalar(), mask.true_count()).into_array())
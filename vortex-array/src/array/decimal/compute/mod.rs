@@ -0,0 +1,155 @@
+use num_traits::AsPrimitive;
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::{DecimalArray, DecimalEncoding};
+use crate::compute::{
+    ComputeVTable, FilterFn, FilterIter, FilterMask, ScalarAtFn, SliceFn, TakeFn,
+};
+use crate::validity::ArrayValidity;
+use crate::variants::PrimitiveArrayTrait;
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
+
+impl ComputeVTable for DecimalEncoding {
+    fn filter_fn(&self) -> Option<&dyn FilterFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn slice_fn(&self) -> Option<&dyn SliceFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn<DecimalArray> for DecimalEncoding {
+    fn scalar_at(&self, array: &DecimalArray, index: usize) -> VortexResult<Scalar> {
+        if !array.is_valid(index) {
+            return Ok(Scalar::null(array.dtype().clone()));
+        }
+        Ok(Scalar::decimal(
+            array.value(index),
+            array.decimal_dtype(),
+            array.dtype().nullability(),
+        ))
+    }
+}
+
+impl SliceFn<DecimalArray> for DecimalEncoding {
+    fn slice(&self, array: &DecimalArray, start: usize, stop: usize) -> VortexResult<ArrayData> {
+        let byte_width = array.value_type().byte_width();
+        DecimalArray::try_new(
+            array.buffer().slice(start * byte_width..stop * byte_width),
+            array.decimal_dtype(),
+            array.validity().slice(start, stop)?,
+        )
+        .map(IntoArrayData::into_array)
+    }
+}
+
+impl TakeFn<DecimalArray> for DecimalEncoding {
+    fn take(&self, array: &DecimalArray, indices: &ArrayData) -> VortexResult<ArrayData> {
+        let indices = indices.clone().into_primitive()?;
+        let validity = array.validity().take(indices.as_ref())?;
+
+        match_each_integer_ptype!(indices.ptype(), |$I| {
+            array
+                .take_values(
+                    indices.maybe_null_slice::<$I>().iter().map(|idx| idx.as_()),
+                    validity,
+                )
+                .map(IntoArrayData::into_array)
+        })
+    }
+}
+
+impl FilterFn<DecimalArray> for DecimalEncoding {
+    fn filter(&self, array: &DecimalArray, mask: FilterMask) -> VortexResult<ArrayData> {
+        let validity = array.validity().filter(&mask)?;
+
+        match mask.iter()? {
+            FilterIter::Indices(indices) => array.take_values(indices.iter().copied(), validity),
+            FilterIter::IndicesIter(iter) => array.take_values(iter, validity),
+            FilterIter::Slices(slices) => {
+                array.take_values(slices.iter().flat_map(|&(start, end)| start..end), validity)
+            }
+            FilterIter::SlicesIter(iter) => {
+                array.take_values(iter.flat_map(|(start, end)| start..end), validity)
+            }
+        }
+        .map(IntoArrayData::into_array)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DecimalDType, Nullability};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{DecimalArray, PrimitiveArray};
+    use crate::compute::{filter, scalar_at, slice, take, FilterMask};
+    use crate::validity::Validity;
+    use crate::{ArrayData, IntoArrayData};
+
+    fn decimals() -> ArrayData {
+        DecimalArray::from_values(
+            [100i128, 250, -75, 0],
+            DecimalDType::new(5, 2),
+            Validity::from_iter([true, true, true, false]),
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    fn values(array: ArrayData) -> Vec<Option<i128>> {
+        (0..array.len())
+            .map(|i| scalar_at(&array, i).unwrap())
+            .map(|s| s.as_decimal().value().and_then(|v| v.as_i128()))
+            .collect()
+    }
+
+    #[test]
+    fn scalar_at_decimal() {
+        let array = decimals();
+        assert_eq!(
+            scalar_at(&array, 2).unwrap(),
+            Scalar::decimal(-75i128, DecimalDType::new(5, 2), Nullability::Nullable)
+        );
+        assert!(scalar_at(&array, 3).unwrap().is_null());
+    }
+
+    #[test]
+    fn slice_decimal() {
+        assert_eq!(
+            values(slice(decimals(), 1, 4).unwrap()),
+            vec![Some(250), Some(-75), None]
+        );
+    }
+
+    #[test]
+    fn take_decimal() {
+        let taken = take(
+            decimals(),
+            PrimitiveArray::from(vec![3u32, 0, 0]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(values(taken), vec![None, Some(100), Some(100)]);
+    }
+
+    #[test]
+    fn filter_decimal() {
+        let filtered = filter(
+            &decimals(),
+            FilterMask::from_iter([false, true, true, false]),
+        )
+        .unwrap();
+        assert_eq!(values(filtered), vec![Some(250), Some(-75)]);
+    }
+}
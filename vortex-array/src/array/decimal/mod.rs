@@ -0,0 +1,267 @@
+mod compute;
+
+use std::fmt::{Debug, Display};
+use std::sync::Arc;
+
+use arrow_buffer::i256;
+use itertools::Itertools;
+use vortex_buffer::Buffer;
+use vortex_dtype::{DType, DecimalDType, DecimalValueType};
+use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult};
+use vortex_scalar::DecimalValue;
+
+use crate::encoding::ids;
+use crate::metadata::{
+    MetadataReader, MetadataWriter, TryDeserializeArrayMetadata, TrySerializeArrayMetadata,
+};
+use crate::stats::{Stat, StatisticsVTable, StatsSet};
+use crate::validity::{LogicalValidity, Validity, ValidityMetadata, ValidityVTable};
+use crate::variants::VariantsVTable;
+use crate::visitor::{ArrayVisitor, VisitorVTable};
+use crate::{impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoCanonical};
+
+impl_encoding!("vortex.decimal", ids::DECIMAL, Decimal);
+
+/// Serialized in a fixed layout, whose version 0 is just the validity.
+///
+/// The precision and scale are part of the dtype, which also decides the width of the values.
+#[derive(Clone, Debug)]
+pub struct DecimalMetadata {
+    validity: ValidityMetadata,
+}
+
+impl Display for DecimalMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl TrySerializeArrayMetadata for DecimalMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        self.validity.write_to(&mut writer);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for DecimalMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let validity = ValidityMetadata::read_from(&mut reader)?;
+        reader.finish()?;
+        Ok(Self { validity })
+    }
+}
+
+// The buffer holds the unscaled values as little-endian i128s for precisions up to 38, and i256s
+// beyond that, which is how Arrow lays out its Decimal128 and Decimal256 arrays.
+impl DecimalArray {
+    pub fn try_new(
+        buffer: Buffer,
+        decimal_dtype: DecimalDType,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        let byte_width = decimal_dtype.value_type().byte_width();
+        if buffer.len() % byte_width != 0 {
+            vortex_bail!(
+                "Buffer of {} bytes is not a multiple of the {} byte values of {}",
+                buffer.len(),
+                byte_width,
+                decimal_dtype
+            );
+        }
+        let len = buffer.len() / byte_width;
+
+        ArrayData::try_new_owned(
+            &DecimalEncoding,
+            DType::Decimal(decimal_dtype, validity.nullability()),
+            len,
+            Arc::new(DecimalMetadata {
+                validity: validity.to_metadata(len)?,
+            }),
+            Some(buffer),
+            validity.into_array().into_iter().collect_vec().into(),
+            StatsSet::default(),
+        )?
+        .try_into()
+    }
+
+    /// Create an array of the given values, converting them to the width of the decimal dtype.
+    pub fn from_values<V: Into<DecimalValue>>(
+        values: impl IntoIterator<Item = V>,
+        decimal_dtype: DecimalDType,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        let values = values.into_iter().map(Into::into);
+        let buffer = match decimal_dtype.value_type() {
+            DecimalValueType::I128 => Buffer::from(
+                values
+                    .map(|v| {
+                        v.as_i128().ok_or_else(|| {
+                            vortex_err!("Decimal value {} does not fit {}", v, decimal_dtype)
+                        })
+                    })
+                    .collect::<VortexResult<Vec<i128>>>()?,
+            ),
+            DecimalValueType::I256 => Buffer::from(values.map(|v| v.as_i256()).collect::<Vec<_>>()),
+        };
+        Self::try_new(buffer, decimal_dtype, validity)
+    }
+
+    pub fn decimal_dtype(&self) -> DecimalDType {
+        *self
+            .dtype()
+            .as_decimal()
+            .vortex_expect("must be decimal dtype")
+    }
+
+    pub fn value_type(&self) -> DecimalValueType {
+        self.decimal_dtype().value_type()
+    }
+
+    pub fn buffer(&self) -> &Buffer {
+        self.as_ref()
+            .buffer()
+            .vortex_expect("Missing buffer in DecimalArray")
+    }
+
+    /// The unscaled value at `index`, ignoring validity.
+    pub fn value(&self, index: usize) -> DecimalValue {
+        let byte_width = self.value_type().byte_width();
+        let bytes = &self.buffer().as_slice()[index * byte_width..(index + 1) * byte_width];
+        match self.value_type() {
+            DecimalValueType::I128 => DecimalValue::I128(i128::from_le_bytes(
+                bytes.try_into().vortex_expect("16 bytes of i128"),
+            )),
+            DecimalValueType::I256 => DecimalValue::I256(i256::from_le_bytes(
+                bytes.try_into().vortex_expect("32 bytes of i256"),
+            )),
+        }
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata().validity.to_validity(|| {
+            self.as_ref()
+                .child(0, &Validity::DTYPE, self.len())
+                .vortex_expect("DecimalArray: validity child")
+        })
+    }
+
+    /// Build an array of the values at each of the `indices` in turn.
+    pub(crate) fn take_values(
+        &self,
+        indices: impl IntoIterator<Item = usize>,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        let byte_width = self.value_type().byte_width();
+        let values = self.buffer().as_slice();
+        let mut taken = Vec::new();
+        for idx in indices {
+            if idx >= self.len() {
+                vortex_bail!(OutOfBounds: idx, 0, self.len());
+            }
+            taken.extend_from_slice(&values[idx * byte_width..(idx + 1) * byte_width]);
+        }
+        Self::try_new(Buffer::from(taken), self.decimal_dtype(), validity)
+    }
+}
+
+impl ArrayTrait for DecimalArray {}
+
+impl VariantsVTable<DecimalArray> for DecimalEncoding {}
+
+impl VisitorVTable<DecimalArray> for DecimalEncoding {
+    fn accept(&self, array: &DecimalArray, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_buffer(array.buffer())?;
+        visitor.visit_validity(&array.validity())
+    }
+}
+
+impl IntoCanonical for DecimalArray {
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        Ok(Canonical::Decimal(self))
+    }
+}
+
+impl StatisticsVTable<DecimalArray> for DecimalEncoding {
+    fn compute_statistics(&self, array: &DecimalArray, stat: Stat) -> VortexResult<StatsSet> {
+        Ok(match stat {
+            Stat::NullCount => StatsSet::of(
+                stat,
+                array
+                    .metadata()
+                    .validity
+                    .null_count(array.len(), || array.validity())?,
+            ),
+            _ => StatsSet::default(),
+        })
+    }
+}
+
+impl ValidityVTable<DecimalArray> for DecimalEncoding {
+    fn is_valid(&self, array: &DecimalArray, index: usize) -> bool {
+        array.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self, array: &DecimalArray) -> LogicalValidity {
+        array
+            .metadata()
+            .validity
+            .to_logical(array.len(), || array.validity())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_buffer::i256;
+    use vortex_dtype::{DType, DecimalDType, Nullability};
+    use vortex_scalar::DecimalValue;
+
+    use crate::array::DecimalArray;
+    use crate::stats::ArrayStatistics;
+    use crate::validity::Validity;
+    use crate::{ArrayDType, ArrayLen};
+
+    #[test]
+    fn decimal128() {
+        let array = DecimalArray::from_values(
+            [12345i128, -1, 0],
+            DecimalDType::new(10, 2),
+            Validity::from_iter([true, true, false]),
+        )
+        .unwrap();
+
+        assert_eq!(array.len(), 3);
+        assert_eq!(
+            array.dtype(),
+            &DType::Decimal(DecimalDType::new(10, 2), Nullability::Nullable)
+        );
+        assert_eq!(array.buffer().len(), 48);
+        assert_eq!(array.value(1), DecimalValue::I128(-1));
+        assert_eq!(array.statistics().compute_null_count(), Some(1));
+    }
+
+    #[test]
+    fn decimal256() {
+        let array = DecimalArray::from_values(
+            [i256::MAX, i256::from_i128(-7)],
+            DecimalDType::new(76, 0),
+            Validity::NonNullable,
+        )
+        .unwrap();
+
+        assert_eq!(array.buffer().len(), 64);
+        assert_eq!(array.value(0), DecimalValue::I256(i256::MAX));
+        assert_eq!(array.value(1), DecimalValue::I128(-7));
+    }
+
+    #[test]
+    fn value_too_wide() {
+        assert!(DecimalArray::from_values(
+            [i256::MAX],
+            DecimalDType::new(38, 0),
+            Validity::NonNullable,
+        )
+        .is_err());
+    }
+}
@@ -1,15 +1,19 @@
 mod compare;
 
-use vortex_error::VortexResult;
+use std::sync::Arc;
+
+use vortex_dtype::StorageTransparentOp;
+use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::array::extension::ExtensionArray;
 use crate::array::ExtensionEncoding;
 use crate::compute::{
-    scalar_at, slice, take, CastFn, CompareFn, ComputeVTable, ScalarAtFn, SliceFn, TakeFn,
+    fill_forward, fill_null, filter, scalar_at, slice, take, CastFn, CompareFn, ComputeVTable,
+    FillForwardFn, FillNullFn, FilterFn, FilterMask, ScalarAtFn, SliceFn, TakeFn,
 };
 use crate::variants::ExtensionArrayTrait;
-use crate::{ArrayData, IntoArrayData};
+use crate::{ArrayDType, ArrayData, IntoArrayData};
 
 impl ComputeVTable for ExtensionEncoding {
     fn cast_fn(&self) -> Option<&dyn CastFn<ArrayData>> {
@@ -23,6 +27,18 @@ impl ComputeVTable for ExtensionEncoding {
         Some(self)
     }
 
+    fn fill_forward_fn(&self) -> Option<&dyn FillForwardFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn fill_null_fn(&self) -> Option<&dyn FillNullFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn filter_fn(&self) -> Option<&dyn FilterFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
@@ -47,19 +63,136 @@ impl ScalarAtFn<ExtensionArray> for ExtensionEncoding {
 
 impl SliceFn<ExtensionArray> for ExtensionEncoding {
     fn slice(&self, array: &ExtensionArray, start: usize, stop: usize) -> VortexResult<ArrayData> {
-        Ok(ExtensionArray::new(
-            array.ext_dtype().clone(),
-            slice(array.storage(), start, stop)?,
-        )
-        .into_array())
+        wrap_storage(array, slice(array.storage(), start, stop)?)
     }
 }
 
 impl TakeFn<ExtensionArray> for ExtensionEncoding {
     fn take(&self, array: &ExtensionArray, indices: &ArrayData) -> VortexResult<ArrayData> {
-        Ok(
-            ExtensionArray::new(array.ext_dtype().clone(), take(array.storage(), indices)?)
-                .into_array(),
+        wrap_storage(array, take(array.storage(), indices)?)
+    }
+}
+
+impl FilterFn<ExtensionArray> for ExtensionEncoding {
+    fn filter(&self, array: &ExtensionArray, mask: FilterMask) -> VortexResult<ArrayData> {
+        wrap_storage(array, filter(&array.storage(), mask)?)
+    }
+}
+
+impl FillForwardFn<ExtensionArray> for ExtensionEncoding {
+    fn fill_forward(&self, array: &ExtensionArray) -> VortexResult<ArrayData> {
+        storage_passthrough(array, StorageTransparentOp::FillForward, fill_forward)
+    }
+}
+
+impl FillNullFn<ExtensionArray> for ExtensionEncoding {
+    fn fill_null(&self, array: &ExtensionArray, fill_value: Scalar) -> VortexResult<ArrayData> {
+        storage_passthrough(array, StorageTransparentOp::FillNull, |storage| {
+            fill_null(storage, fill_value.as_extension().storage())
+        })
+    }
+}
+
+/// Apply a compute function to the storage of an extension array and wrap the result in the
+/// extension type, if the extension type marks the function as transparent to its storage.
+fn storage_passthrough(
+    array: &ExtensionArray,
+    op: StorageTransparentOp,
+    f: impl FnOnce(ArrayData) -> VortexResult<ArrayData>,
+) -> VortexResult<ArrayData> {
+    if !array.ext_dtype().is_storage_transparent(op) {
+        vortex_bail!(
+            "{op} is not transparent to the storage of extension type {}",
+            array.id()
+        );
+    }
+    wrap_storage(array, f(array.storage())?)
+}
+
+/// Wrap the result of a compute function on the storage of an extension array in its extension
+/// type, with the nullability of the result.
+fn wrap_storage(array: &ExtensionArray, storage: ArrayData) -> VortexResult<ArrayData> {
+    let ext_dtype = array
+        .ext_dtype()
+        .with_nullability(storage.dtype().nullability());
+    Ok(ExtensionArray::new(Arc::new(ext_dtype), storage).into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use vortex_dtype::{DType, ExtDType, ExtID, Nullability, PType, StorageTransparentOp};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ExtensionArray, PrimitiveArray};
+    use crate::compute::{fill_null, filter, slice, FilterMask};
+    use crate::variants::ExtensionArrayTrait;
+    use crate::{ArrayDType, IntoArrayData, IntoArrayVariant};
+
+    fn ext_array(ops: impl IntoIterator<Item = StorageTransparentOp>) -> ExtensionArray {
+        let storage = PrimitiveArray::from_nullable_vec(vec![Some(1i64), None, Some(3)]);
+        let ext_dtype = ExtDType::new(
+            ExtID::from("test.ext"),
+            Arc::new(DType::Primitive(PType::I64, Nullability::Nullable)),
+            None,
         )
+        .with_storage_transparent(ops);
+        ExtensionArray::new(Arc::new(ext_dtype), storage.into_array())
+    }
+
+    fn fill_value(array: &ExtensionArray) -> Scalar {
+        Scalar::extension(
+            Arc::new(array.ext_dtype().with_nullability(Nullability::NonNullable)),
+            Scalar::from(0i64),
+        )
+    }
+
+    #[test]
+    fn row_selection_is_transparent_by_default() {
+        let array = ext_array([]);
+        let filtered = filter(array.as_ref(), FilterMask::from_iter([true, false, true])).unwrap();
+        assert_eq!(filtered.dtype(), array.dtype());
+        assert_eq!(
+            ExtensionArray::try_from(filtered)
+                .unwrap()
+                .storage()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i64>(),
+            &[1, 3]
+        );
+
+        assert!(fill_null(&array, fill_value(&array)).is_err());
+    }
+
+    #[test]
+    fn marked_functions_delegate() {
+        let array = ext_array([StorageTransparentOp::FillNull]);
+        let filled =
+            ExtensionArray::try_from(fill_null(&array, fill_value(&array)).unwrap()).unwrap();
+        assert!(!filled.dtype().is_nullable());
+        assert_eq!(
+            filled
+                .storage()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i64>(),
+            &[1, 0, 3]
+        );
+
+        // Marking functions as transparent adds to the row selection functions.
+        let sliced = ExtensionArray::try_from(slice(&array, 0, 1).unwrap()).unwrap();
+        assert!(sliced
+            .ext_dtype()
+            .is_storage_transparent(StorageTransparentOp::FillNull));
+        assert_eq!(
+            sliced
+                .storage()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i64>(),
+            &[1]
+        );
     }
 }
@@ -2,11 +2,15 @@ use std::fmt::{Debug, Display};
 use std::sync::Arc;
 
 use enum_iterator::all;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_dtype::{DType, ExtDType, ExtID};
 use vortex_error::{VortexExpect as _, VortexResult};
 
 use crate::encoding::ids;
+use crate::metadata::{
+    flexbuffers_root, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
+};
 use crate::stats::{ArrayStatistics as _, Stat, StatisticsVTable, StatsSet};
 use crate::validity::{ArrayValidity, LogicalValidity, ValidityVTable};
 use crate::variants::{ExtensionArrayTrait, VariantsVTable};
@@ -17,7 +21,9 @@ mod compute;
 
 impl_encoding!("vortex.ext", ids::EXTENSION, Extension);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 has no fields.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct ExtensionMetadata;
 
 impl Display for ExtensionMetadata {
@@ -26,6 +32,23 @@ impl Display for ExtensionMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for ExtensionMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        Ok(MetadataWriter::new(0).finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for ExtensionMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        MetadataReader::try_new(metadata, 0)?.finish()?;
+        Ok(Self)
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl ExtensionArray {
     pub fn new(ext_dtype: Arc<ExtDType>, storage: ArrayData) -> Self {
         assert_eq!(
@@ -50,7 +73,6 @@ impl ExtensionArray {
             .vortex_expect("Missing storage array for ExtensionArray")
     }
 
-    #[allow(dead_code)]
     #[inline]
     pub fn id(&self) -> &ExtID {
         self.ext_dtype().id()
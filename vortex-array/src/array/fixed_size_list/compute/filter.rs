@@ -0,0 +1,25 @@
+use vortex_error::VortexResult;
+
+use crate::array::fixed_size_list::compute::take::take_rows;
+use crate::array::{FixedSizeListArray, FixedSizeListEncoding};
+use crate::compute::{FilterFn, FilterIter, FilterMask};
+use crate::ArrayData;
+
+impl FilterFn<FixedSizeListArray> for FixedSizeListEncoding {
+    fn filter(&self, array: &FixedSizeListArray, mask: FilterMask) -> VortexResult<ArrayData> {
+        let validity = array.validity().filter(&mask)?;
+
+        match mask.iter()? {
+            FilterIter::Indices(indices) => take_rows(array, indices.iter().copied(), validity),
+            FilterIter::IndicesIter(iter) => take_rows(array, iter, validity),
+            FilterIter::Slices(slices) => take_rows(
+                array,
+                slices.iter().flat_map(|&(start, end)| start..end),
+                validity,
+            ),
+            FilterIter::SlicesIter(iter) => {
+                take_rows(array, iter.flat_map(|(start, end)| start..end), validity)
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use itertools::Itertools;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::{FixedSizeListArray, FixedSizeListEncoding};
+use crate::compute::{scalar_at, slice, ComputeVTable, FilterFn, ScalarAtFn, SliceFn, TakeFn};
+use crate::validity::ArrayValidity;
+use crate::{ArrayDType, ArrayData, IntoArrayData};
+
+mod filter;
+mod take;
+
+impl ComputeVTable for FixedSizeListEncoding {
+    fn filter_fn(&self) -> Option<&dyn FilterFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn slice_fn(&self) -> Option<&dyn SliceFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn<FixedSizeListArray> for FixedSizeListEncoding {
+    fn scalar_at(&self, array: &FixedSizeListArray, index: usize) -> VortexResult<Scalar> {
+        if !array.is_valid(index) {
+            return Ok(Scalar::null(array.dtype().clone()));
+        }
+        let size = array.list_size() as usize;
+        let elements = slice(array.elements(), index * size, (index + 1) * size)?;
+        let scalars: Vec<Scalar> = (0..size).map(|i| scalar_at(&elements, i)).try_collect()?;
+
+        Ok(Scalar::fixed_size_list(
+            Arc::new(elements.dtype().clone()),
+            scalars,
+            array.dtype().nullability(),
+        ))
+    }
+}
+
+impl SliceFn<FixedSizeListArray> for FixedSizeListEncoding {
+    fn slice(
+        &self,
+        array: &FixedSizeListArray,
+        start: usize,
+        stop: usize,
+    ) -> VortexResult<ArrayData> {
+        let size = array.list_size();
+        FixedSizeListArray::try_with_len(
+            slice(
+                array.elements(),
+                start * size as usize,
+                stop * size as usize,
+            )?,
+            size,
+            stop - start,
+            array.validity().slice(start, stop)?,
+        )
+        .map(IntoArrayData::into_array)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use vortex_dtype::{Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{FixedSizeListArray, PrimitiveArray};
+    use crate::compute::{filter, scalar_at, slice, take, FilterMask};
+    use crate::validity::{ArrayValidity, Validity};
+    use crate::{ArrayDType, ArrayData, IntoArrayData};
+
+    fn list() -> ArrayData {
+        FixedSizeListArray::try_new(
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5, 6, 7, 8]).into_array(),
+            2,
+            Validity::from_iter([true, false, true, true]),
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    fn values(array: &ArrayData) -> Vec<Scalar> {
+        (0..array.len())
+            .map(|i| scalar_at(array, i).unwrap())
+            .collect()
+    }
+
+    fn row(values: &[i32]) -> Scalar {
+        Scalar::fixed_size_list(
+            Arc::new(PType::I32.into()),
+            values.iter().map(|&v| v.into()).collect(),
+            Nullability::Nullable,
+        )
+    }
+
+    #[test]
+    fn scalar_at_rows() {
+        let list = list();
+        assert_eq!(scalar_at(&list, 0).unwrap(), row(&[1, 2]));
+        assert!(scalar_at(&list, 1).unwrap().is_null());
+        assert_eq!(scalar_at(&list, 3).unwrap(), row(&[7, 8]));
+    }
+
+    #[test]
+    fn slice_rows() {
+        let sliced = slice(list(), 2, 4).unwrap();
+        assert_eq!(sliced.dtype(), list().dtype());
+        assert_eq!(values(&sliced), vec![row(&[5, 6]), row(&[7, 8])]);
+    }
+
+    #[test]
+    fn take_rows() {
+        let taken = take(
+            list(),
+            PrimitiveArray::from(vec![3u64, 0, 0, 1]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(taken.len(), 4);
+        assert_eq!(scalar_at(&taken, 0).unwrap(), row(&[7, 8]));
+        assert_eq!(scalar_at(&taken, 1).unwrap(), row(&[1, 2]));
+        assert_eq!(scalar_at(&taken, 2).unwrap(), row(&[1, 2]));
+        assert!(!taken.is_valid(3));
+
+        assert!(take(list(), PrimitiveArray::from(vec![4u64]).into_array()).is_err());
+    }
+
+    #[test]
+    fn filter_rows() {
+        let filtered = filter(&list(), FilterMask::from_iter([true, false, false, true])).unwrap();
+        assert_eq!(values(&filtered), vec![row(&[1, 2]), row(&[7, 8])]);
+    }
+}
@@ -0,0 +1,54 @@
+use num_traits::AsPrimitive;
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::{FixedSizeListArray, FixedSizeListEncoding, PrimitiveArray};
+use crate::compute::{take, TakeFn};
+use crate::validity::Validity;
+use crate::variants::PrimitiveArrayTrait;
+use crate::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
+
+impl TakeFn<FixedSizeListArray> for FixedSizeListEncoding {
+    fn take(&self, array: &FixedSizeListArray, indices: &ArrayData) -> VortexResult<ArrayData> {
+        let indices = indices.clone().into_primitive()?;
+        let validity = array.validity().take(indices.as_ref())?;
+
+        match_each_integer_ptype!(indices.ptype(), |$I| {
+            take_rows(
+                array,
+                indices.maybe_null_slice::<$I>().iter().map(|idx| idx.as_()),
+                validity,
+            )
+        })
+    }
+}
+
+/// Build a list of the rows of `array` at each of the `rows` in turn.
+pub(super) fn take_rows(
+    array: &FixedSizeListArray,
+    rows: impl IntoIterator<Item = usize>,
+    validity: Validity,
+) -> VortexResult<ArrayData> {
+    let size = array.list_size() as u64;
+    let mut len = 0;
+    let mut element_indices = Vec::new();
+    for row in rows {
+        if row >= array.len() {
+            vortex_bail!(OutOfBounds: row, 0, array.len());
+        }
+        let start = row as u64 * size;
+        element_indices.extend(start..start + size);
+        len += 1;
+    }
+
+    FixedSizeListArray::try_with_len(
+        take(
+            array.elements(),
+            PrimitiveArray::from(element_indices).into_array(),
+        )?,
+        array.list_size(),
+        len,
+        validity,
+    )
+    .map(IntoArrayData::into_array)
+}
@@ -0,0 +1,257 @@
+mod compute;
+
+use std::fmt::{Debug, Display};
+use std::sync::Arc;
+
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexExpect, VortexResult};
+
+use crate::encoding::ids;
+use crate::metadata::{
+    MetadataReader, MetadataWriter, TryDeserializeArrayMetadata, TrySerializeArrayMetadata,
+};
+use crate::stats::{Stat, StatisticsVTable, StatsSet};
+use crate::validity::{LogicalValidity, Validity, ValidityMetadata, ValidityVTable};
+use crate::variants::{ListArrayTrait, VariantsVTable};
+use crate::visitor::{ArrayVisitor, VisitorVTable};
+use crate::{impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoCanonical};
+
+impl_encoding!(
+    "vortex.fixed_size_list",
+    ids::FIXED_SIZE_LIST,
+    FixedSizeList
+);
+
+/// Serialized in a fixed layout, whose version 0 is just the validity.
+///
+/// The list size is part of the dtype, and the number of elements is the list size times the
+/// length of the array.
+#[derive(Clone, Debug)]
+pub struct FixedSizeListMetadata {
+    validity: ValidityMetadata,
+}
+
+impl Display for FixedSizeListMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl TrySerializeArrayMetadata for FixedSizeListMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        self.validity.write_to(&mut writer);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for FixedSizeListMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let validity = ValidityMetadata::read_from(&mut reader)?;
+        reader.finish()?;
+        Ok(Self { validity })
+    }
+}
+
+// The lists all have `list_size` elements, so list `i` is made of the elements
+// `i * list_size..(i + 1) * list_size`, without any offsets.
+impl FixedSizeListArray {
+    pub fn try_new(elements: ArrayData, list_size: u32, validity: Validity) -> VortexResult<Self> {
+        let size = list_size as usize;
+        let len = if size == 0 {
+            match &validity {
+                Validity::Array(array) => array.len(),
+                _ => 0,
+            }
+        } else {
+            if elements.len() % size != 0 {
+                vortex_bail!(
+                    "Number of elements {} is not a multiple of the list size {}",
+                    elements.len(),
+                    list_size
+                );
+            }
+            elements.len() / size
+        };
+        Self::try_with_len(elements, list_size, len, validity)
+    }
+
+    /// Create an array of `len` lists, which is needed for lists of size zero, whose length
+    /// can't be inferred from the elements.
+    pub fn try_with_len(
+        elements: ArrayData,
+        list_size: u32,
+        len: usize,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        if elements.len() != len * list_size as usize {
+            vortex_bail!(
+                "Expected {} elements for {} lists of size {}, got {}",
+                len * list_size as usize,
+                len,
+                list_size,
+                elements.len()
+            );
+        }
+
+        let validity_metadata = validity.to_metadata(len)?;
+        let dtype = DType::FixedSizeList(
+            Arc::new(elements.dtype().clone()),
+            list_size,
+            validity.nullability(),
+        );
+
+        let mut children = vec![elements];
+        if let Some(val) = validity.into_array() {
+            children.push(val);
+        }
+
+        Self::try_from_parts(
+            dtype,
+            len,
+            FixedSizeListMetadata {
+                validity: validity_metadata,
+            },
+            children.into(),
+            StatsSet::default(),
+        )
+    }
+
+    /// The number of elements in each list.
+    pub fn list_size(&self) -> u32 {
+        self.dtype()
+            .as_fixed_size_list_element()
+            .vortex_expect("must be fixed size list dtype")
+            .1
+    }
+
+    /// The elements of all the lists, ignoring validity.
+    pub fn elements(&self) -> ArrayData {
+        let (dtype, _) = self
+            .dtype()
+            .as_fixed_size_list_element()
+            .vortex_expect("must be fixed size list dtype");
+        self.as_ref()
+            .child(0, dtype, self.len() * self.list_size() as usize)
+            .vortex_expect("FixedSizeListArray: elements child")
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata().validity.to_validity(|| {
+            self.as_ref()
+                .child(1, &Validity::DTYPE, self.len())
+                .vortex_expect("FixedSizeListArray: validity child")
+        })
+    }
+}
+
+impl ArrayTrait for FixedSizeListArray {}
+
+impl VariantsVTable<FixedSizeListArray> for FixedSizeListEncoding {
+    fn as_list_array<'a>(&self, array: &'a FixedSizeListArray) -> Option<&'a dyn ListArrayTrait> {
+        Some(array)
+    }
+}
+
+impl ListArrayTrait for FixedSizeListArray {}
+
+impl VisitorVTable<FixedSizeListArray> for FixedSizeListEncoding {
+    fn accept(
+        &self,
+        array: &FixedSizeListArray,
+        visitor: &mut dyn ArrayVisitor,
+    ) -> VortexResult<()> {
+        visitor.visit_child("elements", &array.elements())?;
+        visitor.visit_validity(&array.validity())
+    }
+}
+
+impl IntoCanonical for FixedSizeListArray {
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        Ok(Canonical::FixedSizeList(self))
+    }
+}
+
+impl StatisticsVTable<FixedSizeListArray> for FixedSizeListEncoding {
+    fn compute_statistics(&self, array: &FixedSizeListArray, stat: Stat) -> VortexResult<StatsSet> {
+        Ok(match stat {
+            Stat::NullCount => StatsSet::of(
+                stat,
+                array
+                    .metadata()
+                    .validity
+                    .null_count(array.len(), || array.validity())?,
+            ),
+            _ => StatsSet::default(),
+        })
+    }
+}
+
+impl ValidityVTable<FixedSizeListArray> for FixedSizeListEncoding {
+    fn is_valid(&self, array: &FixedSizeListArray, index: usize) -> bool {
+        array.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self, array: &FixedSizeListArray) -> LogicalValidity {
+        array
+            .metadata()
+            .validity
+            .to_logical(array.len(), || array.validity())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::{FixedSizeListArray, PrimitiveArray};
+    use crate::stats::ArrayStatistics;
+    use crate::validity::Validity;
+    use crate::{ArrayDType, ArrayLen, IntoArrayData};
+
+    #[test]
+    fn fixed_size_list() {
+        let list = FixedSizeListArray::try_new(
+            PrimitiveArray::from(vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0]).into_array(),
+            3,
+            Validity::from_iter([true, false]),
+        )
+        .unwrap();
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.list_size(), 3);
+        assert_eq!(
+            list.dtype(),
+            &DType::FixedSizeList(
+                DType::Primitive(PType::F32, Nullability::NonNullable).into(),
+                3,
+                Nullability::Nullable
+            )
+        );
+        assert_eq!(list.statistics().compute_null_count(), Some(1));
+    }
+
+    #[test]
+    fn elements_not_a_multiple_of_size() {
+        assert!(FixedSizeListArray::try_new(
+            PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+            2,
+            Validity::NonNullable,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn zero_sized_lists() {
+        let list = FixedSizeListArray::try_with_len(
+            PrimitiveArray::from(Vec::<i32>::new()).into_array(),
+            0,
+            4,
+            Validity::NonNullable,
+        )
+        .unwrap();
+        assert_eq!(list.len(), 4);
+        assert!(list.elements().is_empty());
+    }
+}
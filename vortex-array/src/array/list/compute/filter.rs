@@ -0,0 +1,23 @@
+use vortex_error::VortexResult;
+
+use crate::array::list::compute::take::take_rows;
+use crate::array::{ListArray, ListEncoding};
+use crate::compute::{FilterFn, FilterIter, FilterMask};
+use crate::ArrayData;
+
+impl FilterFn<ListArray> for ListEncoding {
+    fn filter(&self, array: &ListArray, mask: FilterMask) -> VortexResult<ArrayData> {
+        let validity = array.validity().filter(&mask)?;
+
+        match mask.iter()? {
+            FilterIter::Indices(indices) => {
+                take_rows(array, indices.iter().map(|&idx| (idx, idx + 1)), validity)
+            }
+            FilterIter::IndicesIter(iter) => {
+                take_rows(array, iter.map(|idx| (idx, idx + 1)), validity)
+            }
+            FilterIter::Slices(slices) => take_rows(array, slices.iter().copied(), validity),
+            FilterIter::SlicesIter(iter) => take_rows(array, iter, validity),
+        }
+    }
+}
@@ -5,10 +5,17 @@ use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::array::{ListArray, ListEncoding};
-use crate::compute::{scalar_at, slice, ComputeVTable, ScalarAtFn, SliceFn};
+use crate::compute::{scalar_at, slice, ComputeVTable, FilterFn, ScalarAtFn, SliceFn, TakeFn};
 use crate::{ArrayDType, ArrayData, IntoArrayData};
 
+mod filter;
+mod take;
+
 impl ComputeVTable for ListEncoding {
+    fn filter_fn(&self) -> Option<&dyn FilterFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
@@ -16,6 +23,10 @@ impl ComputeVTable for ListEncoding {
     fn slice_fn(&self) -> Option<&dyn SliceFn<ArrayData>> {
         Some(self)
     }
+
+    fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
+        Some(self)
+    }
 }
 
 impl ScalarAtFn<ListArray> for ListEncoding {
@@ -41,3 +52,87 @@ impl SliceFn<ListArray> for ListEncoding {
         .into_array())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ListArray, PrimitiveArray};
+    use crate::compute::{filter, scalar_at, take, FilterMask};
+    use crate::validity::Validity;
+    use crate::{ArrayDType, ArrayData, ArrayValidity, IntoArrayData};
+
+    fn list() -> ArrayData {
+        ListArray::try_new(
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5, 6]).into_array(),
+            PrimitiveArray::from(vec![0u32, 2, 2, 5, 6]).into_array(),
+            Validity::from_iter([true, false, true, true]),
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    fn values(array: &ArrayData) -> Vec<Scalar> {
+        (0..array.len())
+            .map(|i| scalar_at(array, i).unwrap())
+            .collect()
+    }
+
+    fn row(values: &[i32]) -> Scalar {
+        Scalar::list(
+            Arc::new(PType::I32.into()),
+            values.iter().map(|&v| v.into()).collect(),
+            Nullability::Nullable,
+        )
+    }
+
+    #[test]
+    fn take_rows() {
+        let list = list();
+        let taken = take(
+            &list,
+            PrimitiveArray::from(vec![3u64, 0, 0, 2]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(
+            values(&taken),
+            vec![row(&[6]), row(&[1, 2]), row(&[1, 2]), row(&[3, 4, 5])]
+        );
+
+        let taken = take(&list, PrimitiveArray::from(vec![1u64]).into_array()).unwrap();
+        assert!(!taken.is_valid(0));
+    }
+
+    #[test]
+    fn filter_rows() {
+        let list = list();
+        let filtered = filter(&list, FilterMask::from_iter([true, false, true, true])).unwrap();
+        assert_eq!(
+            values(&filtered),
+            vec![row(&[1, 2]), row(&[3, 4, 5]), row(&[6])]
+        );
+
+        let filtered = filter(&list, FilterMask::from_iter([false, true, true, false])).unwrap();
+        assert_eq!(filtered.len(), 2);
+        assert!(!filtered.is_valid(0));
+        assert_eq!(scalar_at(&filtered, 1).unwrap(), row(&[3, 4, 5]));
+    }
+
+    #[test]
+    fn concat_lists() {
+        let list = list();
+        let concat = ListArray::concat(vec![list.clone(), list.clone()], list.dtype().clone())
+            .unwrap()
+            .into_array();
+        assert_eq!(concat.len(), 8);
+        assert_eq!(values(&concat)[4..], values(&list)[..]);
+        assert!(ListArray::concat(
+            vec![list],
+            DType::List(Arc::new(PType::I64.into()), Nullability::Nullable)
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use num_traits::AsPrimitive;
+use vortex_dtype::{match_each_integer_ptype, DType, Nullability, PType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::{ChunkedArray, ListArray, ListEncoding, PrimitiveArray};
+use crate::compute::{slice, take, try_cast, TakeFn};
+use crate::validity::Validity;
+use crate::variants::PrimitiveArrayTrait;
+use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
+
+/// Runs of elements at least this long on average are sliced out of the elements and chunked
+/// together, rather than copied element by element.
+const MIN_AVG_RUN_LEN_TO_SLICE: usize = 64;
+
+impl TakeFn<ListArray> for ListEncoding {
+    fn take(&self, array: &ListArray, indices: &ArrayData) -> VortexResult<ArrayData> {
+        let indices = indices.clone().into_primitive()?;
+        let validity = array.validity().take(indices.as_ref())?;
+
+        match_each_integer_ptype!(indices.ptype(), |$I| {
+            take_rows(
+                array,
+                indices
+                    .maybe_null_slice::<$I>()
+                    .iter()
+                    .map(|idx| {
+                        let idx: usize = idx.as_();
+                        (idx, idx + 1)
+                    }),
+                validity,
+            )
+        })
+    }
+}
+
+/// Build a list of the rows of `array` in each of the `row_ranges` in turn.
+///
+/// The elements of adjacent rows are contiguous, so each range of rows is a single run of
+/// elements, as are ranges that follow on from each other.
+pub(super) fn take_rows(
+    array: &ListArray,
+    row_ranges: impl IntoIterator<Item = (usize, usize)>,
+    validity: Validity,
+) -> VortexResult<ArrayData> {
+    let offsets = try_cast(
+        array.offsets(),
+        &DType::Primitive(PType::I64, Nullability::NonNullable),
+    )?
+    .into_primitive()?;
+    let offsets = offsets.maybe_null_slice::<i64>();
+
+    let mut new_offsets = vec![0i64];
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in row_ranges {
+        if end > array.len() {
+            vortex_bail!(OutOfBounds: end - 1, 0, array.len());
+        }
+        let (run_start, run_end) = (offsets[start], offsets[end]);
+        let shift = new_offsets[new_offsets.len() - 1] - run_start;
+        new_offsets.extend(offsets[start + 1..=end].iter().map(|offset| offset + shift));
+
+        let run = (usize::try_from(run_start)?, usize::try_from(run_end)?);
+        match runs.last_mut() {
+            Some(last) if last.1 == run.0 => last.1 = run.1,
+            _ if run.0 == run.1 => {}
+            _ => runs.push(run),
+        }
+    }
+
+    ListArray::try_new(
+        take_runs(array.elements(), &runs)?,
+        PrimitiveArray::from(new_offsets).into_array(),
+        validity,
+    )
+    .map(IntoArrayData::into_array)
+}
+
+/// The elements in each of the `runs` in turn.
+fn take_runs(elements: ArrayData, runs: &[(usize, usize)]) -> VortexResult<ArrayData> {
+    let len = runs.iter().map(|(start, end)| end - start).sum::<usize>();
+    match runs {
+        [] => slice(&elements, 0, 0),
+        [(start, end)] => slice(&elements, *start, *end),
+        _ if len >= runs.len() * MIN_AVG_RUN_LEN_TO_SLICE => {
+            let chunks = runs
+                .iter()
+                .map(|(start, end)| slice(&elements, *start, *end))
+                .collect::<VortexResult<Vec<_>>>()?;
+            Ok(ChunkedArray::try_new(chunks, elements.dtype().clone())?.into_array())
+        }
+        _ => {
+            let indices = runs
+                .iter()
+                .flat_map(|&(start, end)| start as u64..end as u64)
+                .collect::<Vec<_>>();
+            take(&elements, PrimitiveArray::from(indices).into_array())
+        }
+    }
+}
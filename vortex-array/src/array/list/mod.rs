@@ -4,25 +4,32 @@ use std::fmt::Display;
 use std::sync::Arc;
 
 use num_traits::AsPrimitive;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_dtype::{match_each_native_ptype, DType, PType};
 use vortex_error::{vortex_bail, vortex_panic, VortexExpect, VortexResult};
 
-use crate::array::{NullArray, PrimitiveArray};
+use crate::array::{ChunkedArray, NullArray, PrimitiveArray};
 use crate::compute::{scalar_at, slice};
 use crate::encoding::ids;
+use crate::metadata::{
+    flexbuffers_root, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
+};
 use crate::stats::{Stat, StatisticsVTable, StatsSet};
 use crate::validity::{LogicalValidity, Validity, ValidityMetadata, ValidityVTable};
 use crate::variants::{ListArrayTrait, PrimitiveArrayTrait, VariantsVTable};
 use crate::visitor::{ArrayVisitor, VisitorVTable};
 use crate::{
     impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoArrayData,
-    IntoCanonical,
+    IntoArrayVariant, IntoCanonical,
 };
 
 impl_encoding!("vortex.list", ids::LIST, List);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the validity, the number of elements as a u64
+/// and the ptype of the offsets.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct ListMetadata {
     validity: ValidityMetadata,
     elements_len: usize,
@@ -35,6 +42,36 @@ impl Display for ListMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for ListMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        self.validity.write_to(&mut writer);
+        writer
+            .put_usize(self.elements_len)
+            .put_ptype(self.offset_ptype);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for ListMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let validity = ValidityMetadata::read_from(&mut reader)?;
+        let elements_len = reader.get_usize()?;
+        let offset_ptype = reader.get_ptype()?;
+        reader.finish()?;
+        Ok(Self {
+            validity,
+            elements_len,
+            offset_ptype,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 // A list is valid if the:
 // - offsets start at a value in elements
 // - offsets are sorted
@@ -88,6 +125,12 @@ impl ListArray {
         )
     }
 
+    /// Concatenate arrays of the list `dtype` into a single list, whose elements are the elements
+    /// of each list chunked together rather than copied.
+    pub fn concat(lists: Vec<ArrayData>, dtype: DType) -> VortexResult<Self> {
+        ChunkedArray::try_new(lists, dtype)?.into_list()
+    }
+
     pub fn validity(&self) -> Validity {
         self.metadata().validity.to_validity(|| {
             self.as_ref()
@@ -176,8 +219,17 @@ impl IntoCanonical for ListArray {
 }
 
 impl StatisticsVTable<ListArray> for ListEncoding {
-    fn compute_statistics(&self, _array: &ListArray, _stat: Stat) -> VortexResult<StatsSet> {
-        Ok(StatsSet::default())
+    fn compute_statistics(&self, array: &ListArray, stat: Stat) -> VortexResult<StatsSet> {
+        Ok(match stat {
+            Stat::NullCount => StatsSet::of(
+                stat,
+                array
+                    .metadata()
+                    .validity
+                    .null_count(array.len(), || array.validity())?,
+            ),
+            _ => StatsSet::default(),
+        })
     }
 }
 
@@ -189,7 +241,10 @@ impl ValidityVTable<ListArray> for ListEncoding {
     }
 
     fn logical_validity(&self, array: &ListArray) -> LogicalValidity {
-        array.validity().to_logical(array.len())
+        array
+            .metadata()
+            .validity
+            .to_logical(array.len(), || array.validity())
     }
 }
 
@@ -203,6 +258,7 @@ mod test {
     use crate::array::list::ListArray;
     use crate::array::PrimitiveArray;
     use crate::compute::scalar_at;
+    use crate::stats::ArrayStatistics;
     use crate::validity::Validity;
     use crate::{ArrayLen, IntoArrayData};
 
@@ -252,4 +308,16 @@ mod test {
             scalar_at(&list, 2).unwrap()
         );
     }
+
+    #[test]
+    fn test_null_count() {
+        let elements = PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]);
+        let offsets = PrimitiveArray::from(vec![0, 2, 4, 5]);
+        let validity = Validity::from_iter([true, false, false]);
+
+        let list =
+            ListArray::try_new(elements.into_array(), offsets.into_array(), validity).unwrap();
+
+        assert_eq!(list.statistics().compute_null_count(), Some(2));
+    }
 }
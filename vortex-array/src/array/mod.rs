@@ -7,10 +7,13 @@ mod bool;
 mod chunked;
 mod constant;
 mod datetime;
+mod decimal;
 mod extension;
+mod fixed_size_list;
 mod list;
 mod null;
 mod primitive;
+mod sliced;
 mod sparse;
 mod struct_;
 mod varbin;
@@ -25,10 +28,13 @@ pub use self::bool::*;
 pub use self::chunked::*;
 pub use self::constant::*;
 pub use self::datetime::*;
+pub use self::decimal::*;
 pub use self::extension::*;
+pub use self::fixed_size_list::*;
 pub use self::list::*;
 pub use self::null::*;
 pub use self::primitive::*;
+pub use self::sliced::*;
 pub use self::sparse::*;
 pub use self::struct_::*;
 pub use self::varbin::*;
@@ -73,6 +73,10 @@ impl TakeFn<NullArray> for NullEncoding {
     ) -> VortexResult<ArrayData> {
         Ok(NullArray::new(indices.len()).into_array())
     }
+
+    fn has_take_unchecked(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -1,10 +1,15 @@
 use std::fmt::Display;
+use std::sync::Arc;
 
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_dtype::DType;
 use vortex_error::{VortexExpect as _, VortexResult};
 
 use crate::encoding::ids;
+use crate::metadata::{
+    flexbuffers_root, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
+};
 use crate::nbytes::ArrayNBytes;
 use crate::stats::{Stat, StatisticsVTable, StatsSet};
 use crate::validity::{LogicalValidity, Validity, ValidityVTable};
@@ -16,7 +21,9 @@ mod compute;
 
 impl_encoding!("vortex.null", ids::NULL, Null);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 has no fields.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct NullMetadata;
 
 impl Display for NullMetadata {
@@ -25,6 +32,23 @@ impl Display for NullMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for NullMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        Ok(MetadataWriter::new(0).finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for NullMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        MetadataReader::try_new(metadata, 0)?.finish()?;
+        Ok(Self)
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl NullArray {
     pub fn new(len: usize) -> Self {
         Self::try_from_parts(
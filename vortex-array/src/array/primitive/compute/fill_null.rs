@@ -0,0 +1,77 @@
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::{ConstantArray, PrimitiveArray, PrimitiveEncoding};
+use crate::compute::FillNullFn;
+use crate::validity::Validity;
+use crate::variants::PrimitiveArrayTrait;
+use crate::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
+
+impl FillNullFn<PrimitiveArray> for PrimitiveEncoding {
+    fn fill_null(&self, array: &PrimitiveArray, fill_value: Scalar) -> VortexResult<ArrayData> {
+        let validity = Validity::from(fill_value.dtype().nullability());
+
+        Ok(match array.validity() {
+            Validity::NonNullable | Validity::AllValid => {
+                PrimitiveArray::new(array.buffer().clone(), array.ptype(), validity).into_array()
+            }
+            Validity::AllInvalid => ConstantArray::new(fill_value, array.len()).into_array(),
+            Validity::Array(v) => {
+                let nulls = !&v.into_bool()?.boolean_buffer();
+                match_each_native_ptype!(array.ptype(), |$T| {
+                    let fill = fill_value
+                        .as_primitive()
+                        .typed_value::<$T>()
+                        .ok_or_else(|| vortex_err!("Fill value must be non null"))?;
+                    // Only the null values are overwritten, in place if the buffer isn't shared.
+                    let mut mutable = array.clone().try_into_mutable::<$T>()?;
+                    let values = mutable.values_mut();
+                    for idx in nulls.set_indices() {
+                        values[idx] = fill;
+                    }
+                    mutable.set_validity(validity)?;
+                    mutable.freeze().into_array()
+                })
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::PrimitiveArray;
+    use crate::compute::fill_null;
+    use crate::{ArrayDType, IntoArrayVariant};
+
+    #[test]
+    fn fill_nulls() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3), None]);
+        let filled = fill_null(array, Scalar::from(9i32))
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(filled.maybe_null_slice::<i32>(), &[1, 9, 3, 9]);
+        assert_eq!(
+            filled.dtype(),
+            &DType::Primitive(PType::I32, Nullability::NonNullable)
+        );
+    }
+
+    #[test]
+    fn fill_all_nulls() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Option::<u8>::None, None]);
+        let filled = fill_null(array, Scalar::primitive(4u8, Nullability::Nullable))
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(filled.maybe_null_slice::<u8>(), &[4, 4]);
+        assert_eq!(
+            filled.dtype(),
+            &DType::Primitive(PType::U8, Nullability::Nullable)
+        );
+    }
+}
@@ -1,16 +1,18 @@
 use crate::array::PrimitiveEncoding;
 use crate::compute::{
-    CastFn, ComputeVTable, FillForwardFn, FilterFn, ScalarAtFn, SearchSortedFn,
-    SearchSortedUsizeFn, SliceFn, TakeFn,
+    CastFn, ComputeVTable, FillForwardFn, FillNullFn, FilterFn, ScalarAtFn, SearchSortedFn,
+    SearchSortedUsizeFn, SliceFn, SumFn, TakeFn,
 };
 use crate::ArrayData;
 
 mod cast;
 mod fill;
+mod fill_null;
 mod filter;
 mod scalar_at;
 mod search_sorted;
 mod slice;
+mod sum;
 mod take;
 
 impl ComputeVTable for PrimitiveEncoding {
@@ -22,6 +24,10 @@ impl ComputeVTable for PrimitiveEncoding {
         Some(self)
     }
 
+    fn fill_null_fn(&self) -> Option<&dyn FillNullFn<ArrayData>> {
+        Some(self)
+    }
+
     fn filter_fn(&self) -> Option<&dyn FilterFn<ArrayData>> {
         Some(self)
     }
@@ -42,6 +48,10 @@ impl ComputeVTable for PrimitiveEncoding {
         Some(self)
     }
 
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        Some(self)
+    }
+
     fn take_fn(&self) -> Option<&dyn TakeFn<ArrayData>> {
         Some(self)
     }
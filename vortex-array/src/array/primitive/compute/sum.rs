@@ -0,0 +1,28 @@
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::{PrimitiveArray, PrimitiveEncoding};
+use crate::compute::{SumAccumulator, SumFn};
+use crate::validity::ArrayValidity;
+use crate::variants::PrimitiveArrayTrait;
+
+impl SumFn<PrimitiveArray> for PrimitiveEncoding {
+    fn sum(&self, array: &PrimitiveArray) -> VortexResult<Scalar> {
+        let validity = array.logical_validity().to_null_buffer()?;
+        let mut acc = SumAccumulator::new(array.ptype());
+        match_each_native_ptype!(array.ptype(), |$T| {
+            let values = array.maybe_null_slice::<$T>();
+            match validity {
+                None => acc.add_values(values.iter().copied())?,
+                Some(validity) => acc.add_values(
+                    values
+                        .iter()
+                        .zip(validity.inner().iter())
+                        .filter_map(|(&v, valid)| valid.then_some(v)),
+                )?,
+            }
+        });
+        Ok(acc.finish())
+    }
+}
@@ -4,9 +4,9 @@ use vortex_error::VortexResult;
 
 use crate::array::primitive::PrimitiveArray;
 use crate::array::PrimitiveEncoding;
-use crate::compute::TakeFn;
+use crate::compute::{null_on_oob_indices, TakeFn, TakeOptions};
 use crate::variants::PrimitiveArrayTrait;
-use crate::{ArrayData, IntoArrayData, IntoArrayVariant};
+use crate::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
 
 impl TakeFn<PrimitiveArray> for PrimitiveEncoding {
     #[allow(clippy::cognitive_complexity)]
@@ -37,6 +37,33 @@ impl TakeFn<PrimitiveArray> for PrimitiveEncoding {
             })
         })
     }
+
+    fn has_take_unchecked(&self) -> bool {
+        true
+    }
+
+    fn take_with_options(
+        &self,
+        array: &PrimitiveArray,
+        indices: &ArrayData,
+        options: TakeOptions,
+    ) -> VortexResult<Option<ArrayData>> {
+        if !options.null_on_oob {
+            return self.take(array, indices).map(Some);
+        }
+
+        let (indices, in_bounds) = null_on_oob_indices(indices, array.len())?;
+        let validity = array
+            .validity()
+            .take(indices.as_ref())?
+            .into_nullable()
+            .and(in_bounds)?;
+
+        match_each_native_ptype!(array.ptype(), |$T| {
+            let values = take_primitive(array.maybe_null_slice::<$T>(), indices.maybe_null_slice::<u64>());
+            Ok(Some(PrimitiveArray::from_vec(values, validity).into_array()))
+        })
+    }
 }
 
 // We pass a Vec<I> in case we're T == u64.
@@ -62,7 +89,11 @@ unsafe fn take_primitive_unchecked<T: NativePType, I: NativePType + AsPrimitive<
 
 #[cfg(test)]
 mod test {
+    use vortex_scalar::Scalar;
+
     use crate::array::primitive::compute::take::take_primitive;
+    use crate::array::PrimitiveArray;
+    use crate::compute::{scalar_at, take_with_options, TakeOptions};
 
     #[test]
     fn test_take() {
@@ -70,4 +101,24 @@ mod test {
         let result = take_primitive(&a, &[0, 0, 4, 2]);
         assert_eq!(result, vec![1i32, 1, 5, 3]);
     }
+
+    #[test]
+    fn test_take_null_on_oob() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3)]);
+        let indices = PrimitiveArray::from_nullable_vec(vec![Some(2u8), Some(1), None, Some(9)]);
+        let taken = take_with_options(
+            &array,
+            &indices,
+            TakeOptions {
+                null_on_oob: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(taken.len(), 4);
+        assert_eq!(scalar_at(&taken, 0).unwrap(), Scalar::from(Some(3i32)));
+        for i in 1..4 {
+            assert!(scalar_at(&taken, i).unwrap().is_null());
+        }
+    }
 }
@@ -5,14 +5,19 @@ mod accessor;
 
 use arrow_buffer::{ArrowNativeType, BooleanBufferBuilder, Buffer as ArrowBuffer};
 use itertools::Itertools;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_buffer::Buffer;
 use vortex_dtype::{match_each_native_ptype, DType, NativePType, PType};
 use vortex_error::{VortexExpect as _, VortexResult};
 
 use crate::array::BoolArray;
+use crate::builders::ArrayBuilder;
 use crate::encoding::ids;
 use crate::iter::Accessor;
+use crate::metadata::{
+    flexbuffers_root, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
+};
 use crate::stats::StatsSet;
 use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata, ValidityVTable};
 use crate::variants::{PrimitiveArrayTrait, VariantsVTable};
@@ -22,12 +27,18 @@ use crate::{
 };
 
 mod compute;
+mod mutable;
+mod narrow;
 mod patch;
 mod stats;
 
+pub use mutable::PrimitiveArrayMut;
+
 impl_encoding!("vortex.primitive", ids::PRIMITIVE, Primitive);
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is just the validity.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(remote = "Self")]
 pub struct PrimitiveMetadata {
     validity: ValidityMetadata,
 }
@@ -38,6 +49,27 @@ impl Display for PrimitiveMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for PrimitiveMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        self.validity.write_to(&mut writer);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for PrimitiveMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let validity = ValidityMetadata::read_from(&mut reader)?;
+        reader.finish()?;
+        Ok(Self { validity })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl PrimitiveArray {
     pub fn new(buffer: Buffer, ptype: PType, validity: Validity) -> Self {
         let length = match_each_native_ptype!(ptype, |$P| {
@@ -249,6 +281,10 @@ impl IntoCanonical for PrimitiveArray {
     fn into_canonical(self) -> VortexResult<Canonical> {
         Ok(Canonical::Primitive(self))
     }
+
+    fn canonicalize_into(self, builder: &mut dyn ArrayBuilder) -> VortexResult<()> {
+        builder.extend_from_array(self.as_ref())
+    }
 }
 
 impl ValidityVTable<PrimitiveArray> for PrimitiveEncoding {
@@ -257,7 +293,10 @@ impl ValidityVTable<PrimitiveArray> for PrimitiveEncoding {
     }
 
     fn logical_validity(&self, array: &PrimitiveArray) -> LogicalValidity {
-        array.validity().to_logical(array.len())
+        array
+            .metadata()
+            .validity
+            .to_logical(array.len(), || array.validity())
     }
 }
 
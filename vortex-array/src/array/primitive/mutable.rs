@@ -0,0 +1,112 @@
+use arrow_buffer::ArrowNativeType;
+use vortex_dtype::NativePType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::PrimitiveArray;
+use crate::validity::Validity;
+use crate::variants::PrimitiveArrayTrait;
+
+/// A primitive array that owns its values, so that they can be updated in place, e.g. to patch or
+/// fill them, before converting it back with [PrimitiveArrayMut::freeze].
+#[derive(Debug, Clone)]
+pub struct PrimitiveArrayMut<T> {
+    values: Vec<T>,
+    validity: Validity,
+}
+
+impl PrimitiveArray {
+    /// Take ownership of the values of the array to mutate them.
+    ///
+    /// This is zero-copy when the array is the only owner of its buffer, and the buffer was
+    /// allocated as a `Vec` of the same type, e.g. by [PrimitiveArray::from_vec]. Otherwise the
+    /// values are copied into a new `Vec`.
+    pub fn try_into_mutable<T: NativePType + ArrowNativeType>(
+        self,
+    ) -> VortexResult<PrimitiveArrayMut<T>> {
+        if T::PTYPE != self.ptype() {
+            vortex_bail!(
+                "Attempted to mutate values of type {} in array of type {}",
+                T::PTYPE,
+                self.ptype()
+            );
+        }
+        let validity = self.validity();
+        Ok(PrimitiveArrayMut {
+            values: self.into_maybe_null_slice::<T>(),
+            validity,
+        })
+    }
+}
+
+impl<T: NativePType> PrimitiveArrayMut<T> {
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The values of the array, including those that are null.
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    pub fn validity(&self) -> &Validity {
+        &self.validity
+    }
+
+    /// Replace the validity of the array, which must have the same length as its values.
+    pub fn set_validity(&mut self, validity: Validity) -> VortexResult<()> {
+        validity.to_metadata(self.values.len())?;
+        self.validity = validity;
+        Ok(())
+    }
+
+    /// Convert back into an immutable array, without copying the values.
+    pub fn freeze(self) -> PrimitiveArray {
+        PrimitiveArray::from_vec(self.values, self.validity)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::PrimitiveArray;
+    use crate::validity::Validity;
+    use crate::{IntoArrayData, IntoArrayVariant};
+
+    #[test]
+    fn mutate_unique() {
+        let array = PrimitiveArray::from(vec![1u32, 2, 3]);
+        let ptr = array.buffer().as_ptr();
+
+        let mut mutable = array.try_into_mutable::<u32>().unwrap();
+        mutable.values_mut()[1] = 5;
+        mutable
+            .set_validity(Validity::from_iter([true, true, false]))
+            .unwrap();
+        assert!(mutable
+            .set_validity(Validity::from_iter([true, false]))
+            .is_err());
+
+        let frozen = mutable.freeze();
+        assert_eq!(frozen.buffer().as_ptr(), ptr);
+        assert_eq!(frozen.maybe_null_slice::<u32>(), &[1, 5, 3]);
+        assert!(!frozen.validity().is_valid(2));
+    }
+
+    #[test]
+    fn mutate_shared() {
+        let array = PrimitiveArray::from(vec![1u32, 2, 3]);
+        let shared = array.clone().into_array();
+
+        let mut mutable = array.try_into_mutable::<u32>().unwrap();
+        mutable.values_mut()[0] = 7;
+        assert_eq!(mutable.freeze().maybe_null_slice::<u32>(), &[7, 2, 3]);
+        assert_eq!(shared.len(), 3);
+        assert_eq!(
+            shared.into_primitive().unwrap().maybe_null_slice::<u32>(),
+            &[1, 2, 3]
+        );
+
+        assert!(PrimitiveArray::from(vec![1u32])
+            .try_into_mutable::<i32>()
+            .is_err());
+    }
+}
@@ -0,0 +1,160 @@
+use vortex_dtype::{match_each_integer_ptype, DType, PType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::PrimitiveArray;
+use crate::compute::try_cast;
+use crate::stats::{ArrayStatistics, Stat};
+use crate::variants::PrimitiveArrayTrait;
+use crate::{ArrayDType, IntoArrayVariant};
+
+/// Integer types in the order they're tried when narrowing, smallest first.
+const UNSIGNED: [PType; 4] = [PType::U8, PType::U16, PType::U32, PType::U64];
+const SIGNED: [PType; 4] = [PType::I8, PType::I16, PType::I32, PType::I64];
+
+impl PrimitiveArray {
+    /// The smallest integer PType that can hold every valid value of the array, or `None` for
+    /// floating point arrays.
+    ///
+    /// Non-negative values narrow to unsigned types. The bounds come from the min and max
+    /// statistics, which are only computed if they're not already known.
+    pub fn narrowest_ptype(&self) -> Option<PType> {
+        if !self.ptype().is_int() {
+            return None;
+        }
+
+        let (min, max) = match_each_integer_ptype!(self.ptype(), |$T| {
+            (
+                self.statistics().compute_as_cast::<$T>(Stat::Min).map(i128::from),
+                self.statistics().compute_as_cast::<$T>(Stat::Max).map(i128::from),
+            )
+        });
+        // Empty and all-null arrays have no values to hold.
+        let (min, max) = (min.unwrap_or(0), max.unwrap_or(0));
+
+        let candidates = if min >= 0 { UNSIGNED } else { SIGNED };
+        candidates.into_iter().find(|&ptype| {
+            let (lower, upper) = int_range(ptype);
+            lower <= min && max <= upper
+        })
+    }
+
+    /// Cast an integer array to the smallest PType that can hold every value, see
+    /// [`PrimitiveArray::narrowest_ptype`]. Floating point arrays are returned as they are.
+    pub fn narrow(&self) -> VortexResult<Self> {
+        match self.narrowest_ptype() {
+            Some(ptype) if ptype != self.ptype() => {
+                try_cast(self, &DType::Primitive(ptype, self.dtype().nullability()))?
+                    .into_primitive()
+            }
+            _ => Ok(self.clone()),
+        }
+    }
+
+    /// Cast the array to a PType that can represent every value of its current PType, such as
+    /// one it was narrowed from.
+    ///
+    /// Unlike [`try_cast`], this fails up front for casts that could lose values, even if the
+    /// values of this particular array would survive them.
+    pub fn widen(&self, ptype: PType) -> VortexResult<Self> {
+        if !widens_to(self.ptype(), ptype) {
+            vortex_bail!("Cannot widen {} to {}", self.ptype(), ptype);
+        }
+        if ptype == self.ptype() {
+            return Ok(self.clone());
+        }
+        try_cast(self, &DType::Primitive(ptype, self.dtype().nullability()))?.into_primitive()
+    }
+}
+
+/// Whether every value of `from` can be represented exactly by `to`.
+fn widens_to(from: PType, to: PType) -> bool {
+    if from == to {
+        return true;
+    }
+    match (from.is_int(), to.is_int()) {
+        (true, true) => {
+            let (from_min, from_max) = int_range(from);
+            let (to_min, to_max) = int_range(to);
+            to_min <= from_min && from_max <= to_max
+        }
+        // Integers fit in a float if their magnitude fits in its mantissa.
+        (true, false) => {
+            from.bit_width() - usize::from(from.is_signed_int()) <= mantissa_digits(to)
+        }
+        (false, false) => from.byte_width() <= to.byte_width(),
+        (false, true) => false,
+    }
+}
+
+fn int_range(ptype: PType) -> (i128, i128) {
+    match_each_integer_ptype!(ptype, |$T| (i128::from(<$T>::MIN), i128::from(<$T>::MAX)))
+}
+
+/// The number of significant binary digits of a float type, including the implicit leading bit.
+fn mantissa_digits(ptype: PType) -> usize {
+    match ptype {
+        PType::F16 => 11,
+        PType::F32 => 24,
+        _ => 53,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::PType;
+
+    use crate::array::PrimitiveArray;
+    use crate::variants::PrimitiveArrayTrait;
+
+    #[test]
+    fn narrow_integers() {
+        let array = PrimitiveArray::from(vec![0i64, 200, 17]);
+        let narrowed = array.narrow().unwrap();
+        assert_eq!(narrowed.ptype(), PType::U8);
+        assert_eq!(narrowed.maybe_null_slice::<u8>(), [0, 200, 17]);
+
+        let array = PrimitiveArray::from(vec![-129i64, 5]);
+        assert_eq!(array.narrowest_ptype(), Some(PType::I16));
+
+        let array = PrimitiveArray::from(vec![u64::MAX]);
+        assert_eq!(array.narrowest_ptype(), Some(PType::U64));
+
+        let array = PrimitiveArray::from(vec![1.5f64]);
+        assert_eq!(array.narrowest_ptype(), None);
+        assert_eq!(array.narrow().unwrap().ptype(), PType::F64);
+    }
+
+    #[test]
+    fn narrow_nullable() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(-3i32), None, Some(100)]);
+        let narrowed = array.narrow().unwrap();
+        assert_eq!(narrowed.ptype(), PType::I8);
+        assert_eq!(narrowed.validity(), array.validity());
+
+        let array = PrimitiveArray::from_nullable_vec(vec![None::<i32>, None]);
+        assert_eq!(array.narrowest_ptype(), Some(PType::U8));
+    }
+
+    #[test]
+    fn widen_round_trips() {
+        let array = PrimitiveArray::from(vec![1u32, 300, 60_000]);
+        let narrowed = PrimitiveArray::from(vec![1u8, 255]);
+        let widened = narrowed.widen(PType::I16).unwrap();
+        assert_eq!(widened.maybe_null_slice::<i16>(), [1, 255]);
+        assert_eq!(
+            array
+                .narrow()
+                .unwrap()
+                .widen(PType::U32)
+                .unwrap()
+                .maybe_null_slice::<u32>(),
+            array.maybe_null_slice::<u32>()
+        );
+
+        assert!(narrowed.widen(PType::I8).is_err());
+        assert!(narrowed.widen(PType::F16).is_ok());
+        assert!(array.widen(PType::F32).is_err());
+        assert!(array.widen(PType::F64).is_ok());
+        assert!(PrimitiveArray::from(vec![-1i8]).widen(PType::U64).is_err());
+    }
+}
@@ -20,11 +20,13 @@ impl PrimitiveArray {
 
         match_each_integer_ptype!(indices.ptype(), |$I| {
             match_each_native_ptype!(self.ptype(), |$T| {
-                let mut own_values = self.into_maybe_null_slice::<$T>();
+                let mut mutable = self.try_into_mutable::<$T>()?;
+                let own_values = mutable.values_mut();
                 for (idx, value) in indices.into_maybe_null_slice::<$I>().into_iter().zip_eq(values.into_maybe_null_slice::<$T>().into_iter()) {
                     own_values[idx as usize] = value;
                 }
-                Ok(Self::from_vec(own_values, patched_validity))
+                mutable.set_validity(patched_validity)?;
+                Ok(mutable.freeze())
             })
         })
     }
@@ -1,6 +1,7 @@
 use core::marker::PhantomData;
 use std::cmp::Ordering;
 use std::mem::size_of;
+use std::sync::Arc;
 
 use arrow_buffer::buffer::BooleanBuffer;
 use itertools::{Itertools as _, MinMaxResult};
@@ -13,10 +14,12 @@ use vortex_scalar::Scalar;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::PrimitiveEncoding;
 use crate::nbytes::ArrayNBytes;
-use crate::stats::{Stat, StatisticsVTable, StatsSet};
+use crate::stats::{
+    equi_depth_boundaries, HyperLogLog, Stat, StatisticsVTable, StatsSet, HISTOGRAM_BUCKETS,
+};
 use crate::validity::{ArrayValidity, LogicalValidity};
 use crate::variants::PrimitiveArrayTrait;
-use crate::{ArrayDType, IntoArrayVariant};
+use crate::{ArrayDType, ArrayLen, IntoArrayVariant};
 
 trait PStatsType: NativePType + Into<Scalar> + BitWidth {}
 
@@ -28,6 +31,23 @@ impl StatisticsVTable<PrimitiveArray> for PrimitiveEncoding {
             return Ok(StatsSet::of(stat, array.nbytes()));
         }
 
+        if stat == Stat::NullCount {
+            return Ok(StatsSet::of(
+                stat,
+                array
+                    .metadata()
+                    .validity
+                    .null_count(array.len(), || array.validity())?,
+            ));
+        }
+
+        if stat == Stat::ApproxDistinct {
+            let hll = match_each_native_ptype!(array.ptype(), |$P| {
+                compute_approx_distinct(array.maybe_null_slice::<$P>(), array.logical_validity())
+            })?;
+            return Ok(StatsSet::of(stat, hll));
+        }
+
         let mut stats = match_each_native_ptype!(array.ptype(), |$P| {
             match array.logical_validity() {
                 LogicalValidity::AllValid(_) => self.compute_statistics(array.maybe_null_slice::<$P>(), stat),
@@ -59,14 +79,15 @@ impl<T: PStatsType> StatisticsVTable<[T]> for PrimitiveEncoding {
         }
 
         Ok(match stat {
-            Stat::Min | Stat::Max => {
+            Stat::Min | Stat::Max | Stat::HasNaN => {
                 let mut stats = compute_min_max(array.iter().copied(), true);
+                let has_nan = stats.get_as::<bool>(Stat::HasNaN).unwrap_or(false);
                 stats.set(
                     Stat::IsConstant,
                     stats
                         .get(Stat::Min)
                         .zip(stats.get(Stat::Max))
-                        .map(|(min, max)| min == max)
+                        .map(|(min, max)| min == max && !has_nan)
                         .unwrap_or(false),
                 );
                 stats
@@ -80,12 +101,15 @@ impl<T: PStatsType> StatisticsVTable<[T]> for PrimitiveEncoding {
             Stat::IsSorted => compute_is_sorted(array.iter().copied()),
             Stat::IsStrictSorted => compute_is_strict_sorted(array.iter().copied()),
             Stat::RunCount => compute_run_count(array.iter().copied()),
+            Stat::Histogram => compute_histogram(array.iter().copied()),
             Stat::BitWidthFreq | Stat::TrailingZeroFreq => {
                 let mut stats = BitWidthAccumulator::new(array[0]);
                 array.iter().skip(1).for_each(|next| stats.next(*next));
                 stats.finish()
             }
-            Stat::TrueCount | Stat::UncompressedSizeInBytes => StatsSet::default(),
+            Stat::TrueCount | Stat::UncompressedSizeInBytes | Stat::ApproxDistinct => {
+                StatsSet::default()
+            }
         })
     }
 }
@@ -125,7 +149,7 @@ impl<T: PStatsType> StatisticsVTable<NullableValues<'_, T>> for PrimitiveEncodin
         }
 
         let mut set_indices = nulls.1.set_indices();
-        if matches!(stat, Stat::Min | Stat::Max) {
+        if matches!(stat, Stat::Min | Stat::Max | Stat::HasNaN) {
             stats.extend(compute_min_max(set_indices.map(|next| values[next]), false));
         } else if stat == Stat::IsSorted {
             stats.extend(compute_is_sorted(set_indices.map(|next| values[next])));
@@ -135,6 +159,8 @@ impl<T: PStatsType> StatisticsVTable<NullableValues<'_, T>> for PrimitiveEncodin
             ));
         } else if stat == Stat::RunCount {
             stats.extend(compute_run_count(set_indices.map(|next| values[next])));
+        } else if stat == Stat::Histogram {
+            stats.extend(compute_histogram(set_indices.map(|next| values[next])));
         } else if matches!(stat, Stat::BitWidthFreq | Stat::TrailingZeroFreq) {
             let Some(first_non_null) = set_indices.next() else {
                 vortex_panic!(
@@ -161,12 +187,39 @@ impl<T: PStatsType> StatisticsVTable<NullableValues<'_, T>> for PrimitiveEncodin
     }
 }
 
+fn compute_approx_distinct<T: NativePType>(
+    values: &[T],
+    validity: LogicalValidity,
+) -> VortexResult<HyperLogLog> {
+    let mut hll = HyperLogLog::new();
+    match validity {
+        LogicalValidity::AllValid(_) => values
+            .iter()
+            .for_each(|v| hll.insert(v.to_le_bytes().as_ref())),
+        LogicalValidity::AllInvalid(_) => {}
+        LogicalValidity::Array(a) => a
+            .into_bool()?
+            .boolean_buffer()
+            .set_indices()
+            .for_each(|idx| hll.insert(values[idx].to_le_bytes().as_ref())),
+    }
+    Ok(hll)
+}
+
+/// Compute the min and max of the values, excluding NaNs, along with whether there are NaNs for
+/// floating point values.
 fn compute_min_max<T: PStatsType>(
     iter: impl Iterator<Item = T>,
     could_be_constant: bool,
 ) -> StatsSet {
-    // this `compare` function provides a total ordering (even for NaN values)
-    match iter.minmax_by(|a, b| a.total_compare(*b)) {
+    let mut has_nan = false;
+    let non_nan = iter.filter(|v| {
+        let is_nan = v.is_nan();
+        has_nan |= is_nan;
+        !is_nan
+    });
+    // this `compare` function provides a total ordering
+    let mut stats = match non_nan.minmax_by(|a, b| a.total_compare(*b)) {
         MinMaxResult::NoElements => StatsSet::default(),
         MinMaxResult::OneElement(x) => {
             let scalar: Scalar = x.into();
@@ -184,7 +237,33 @@ fn compute_min_max<T: PStatsType>(
                 (could_be_constant && min.total_compare(max) == Ordering::Equal).into(),
             ),
         ]),
+    };
+
+    if T::PTYPE.is_float() {
+        stats.set(Stat::HasNaN, has_nan);
     }
+    stats
+}
+
+fn compute_histogram<T: PStatsType>(iter: impl Iterator<Item = T>) -> StatsSet {
+    let mut values = iter.filter(|v| !v.is_nan()).collect::<Vec<_>>();
+    if values.is_empty() {
+        return StatsSet::default();
+    }
+    values.sort_unstable_by(|a, b| a.total_compare(*b));
+
+    let boundaries = equi_depth_boundaries(&values, HISTOGRAM_BUCKETS)
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    StatsSet::of(
+        Stat::Histogram,
+        Scalar::list(
+            Arc::new(DType::Primitive(T::PTYPE, Nullability::NonNullable)),
+            boundaries,
+            Nullability::NonNullable,
+        ),
+    )
 }
 
 fn compute_is_sorted<T: PStatsType>(mut iter: impl Iterator<Item = T>) -> StatsSet {
@@ -334,6 +413,7 @@ impl<T: PStatsType> BitWidthAccumulator<T> {
 
 #[cfg(test)]
 mod test {
+    use itertools::Itertools;
     use vortex_dtype::{DType, Nullability, PType};
     use vortex_scalar::Scalar;
 
@@ -406,4 +486,65 @@ mod test {
         assert_eq!(min, Some(null_i32.clone()));
         assert_eq!(max, Some(null_i32));
     }
+
+    #[test]
+    fn min_max_exclude_nan() {
+        let arr = PrimitiveArray::from_nullable_vec(vec![
+            Some(f64::NAN),
+            Some(2.0),
+            None,
+            Some(-1.0),
+            Some(f64::NAN),
+        ]);
+        assert_eq!(arr.statistics().compute_min::<f64>(), Some(-1.0));
+        assert_eq!(arr.statistics().compute_max::<f64>(), Some(2.0));
+        assert_eq!(arr.statistics().compute_has_nan(), Some(true));
+
+        let arr = PrimitiveArray::from(vec![1.0f32, f32::NAN]);
+        assert_eq!(arr.statistics().compute_has_nan(), Some(true));
+        assert_eq!(arr.statistics().compute_is_constant(), Some(false));
+
+        let arr = PrimitiveArray::from(vec![1.0f32, 2.0]);
+        assert_eq!(arr.statistics().compute_has_nan(), Some(false));
+
+        let arr = PrimitiveArray::from(vec![1i32, 2]);
+        assert_eq!(arr.statistics().compute_has_nan(), None);
+    }
+
+    #[test]
+    fn approx_distinct() {
+        let arr =
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), Some(2), None, Some(3), Some(2)]);
+        assert_eq!(arr.statistics().compute_approx_distinct(), Some(3));
+    }
+
+    #[test]
+    fn histogram() {
+        let arr = PrimitiveArray::from_nullable_vec(
+            (0..=640)
+                .rev()
+                .map(|i| (i % 3 != 1).then_some(i))
+                .collect::<Vec<Option<i32>>>(),
+        );
+        let boundaries = arr.statistics().compute_histogram::<i32>().unwrap();
+        assert_eq!(
+            boundaries,
+            vec![
+                0, 20, 39, 59, 80, 99, 119, 140, 159, 179, 200, 219, 239, 260, 279, 299, 320, 339,
+                359, 378, 399, 419, 438, 459, 479, 498, 519, 539, 558, 579, 599, 618, 639
+            ]
+        );
+        // The 427 non-null values are spread evenly over the 32 buckets
+        let values = (0..=640).filter(|i| i % 3 != 1).collect::<Vec<i32>>();
+        for (lo, hi) in boundaries.iter().tuple_windows() {
+            let count = values.iter().filter(|v| (lo..hi).contains(v)).count();
+            assert!((13..=14).contains(&count), "{count} values in {lo}..{hi}");
+        }
+
+        let fraction = arr
+            .statistics()
+            .estimate_fraction_lt(&Scalar::from(320))
+            .unwrap();
+        assert!((fraction - 0.5).abs() < 1.0 / 32.0, "{fraction}");
+    }
 }
@@ -0,0 +1,29 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::sliced::SlicedArray;
+use crate::array::SlicedEncoding;
+use crate::compute::{scalar_at, ComputeVTable, ScalarAtFn, SliceFn};
+use crate::{ArrayData, IntoArrayData};
+
+impl ComputeVTable for SlicedEncoding {
+    fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
+        Some(self)
+    }
+
+    fn slice_fn(&self) -> Option<&dyn SliceFn<ArrayData>> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn<SlicedArray> for SlicedEncoding {
+    fn scalar_at(&self, array: &SlicedArray, index: usize) -> VortexResult<Scalar> {
+        scalar_at(array.child(), array.offset() + index)
+    }
+}
+
+impl SliceFn<SlicedArray> for SlicedEncoding {
+    fn slice(&self, array: &SlicedArray, start: usize, stop: usize) -> VortexResult<ArrayData> {
+        SlicedArray::try_new(array.clone().into_array(), start, stop).map(IntoArrayData::into_array)
+    }
+}
@@ -0,0 +1,284 @@
+//! Slices of arrays whose encodings can't slice themselves cheaply.
+//!
+//! A [`SlicedArray`] holds the whole of the sliced array along with the range of the slice, which
+//! is applied when the array is accessed. Slicing a view of an encoding without a
+//! [`SliceFn`](crate::compute::SliceFn), e.g. one that isn't registered with the context, neither
+//! decodes nor copies its buffers.
+//!
+//! As a slice keeps the whole of its child, small slices are copied out with
+//! [`materialize_small_slices`] before they're serialized.
+
+use std::fmt::{Debug, Display};
+use std::sync::Arc;
+
+use serde::Deserialize;
+use vortex_error::{vortex_bail, VortexExpect as _, VortexResult};
+
+use crate::compute::slice;
+use crate::encoding::ids;
+use crate::metadata::{
+    flexbuffers_root, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
+};
+use crate::nbytes::ArrayNBytes;
+use crate::stats::{Stat, StatisticsVTable, StatsSet};
+use crate::validity::{ArrayValidity, LogicalValidity, ValidityVTable};
+use crate::visitor::{ArrayVisitor, VisitorVTable};
+use crate::{
+    impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoArrayData,
+    IntoCanonical,
+};
+
+mod compute;
+mod variants;
+
+impl_encoding!("vortex.sliced", ids::SLICED, Sliced);
+
+/// Serialized in a fixed layout, whose version 0 is the offset and the length of the child, both as
+/// u64.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(remote = "Self")]
+pub struct SlicedMetadata {
+    offset: usize,
+    child_len: usize,
+}
+
+impl Display for SlicedMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl TrySerializeArrayMetadata for SlicedMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        writer.put_usize(self.offset).put_usize(self.child_len);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for SlicedMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let offset = reader.get_usize()?;
+        let child_len = reader.get_usize()?;
+        reader.finish()?;
+        Ok(Self { offset, child_len })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
+impl SlicedArray {
+    /// Slice the array between `start` (inclusive) and `stop` (exclusive) without touching its
+    /// data.
+    ///
+    /// Slicing a [`SlicedArray`] slices the array it wraps, so that slices never nest.
+    pub fn try_new(array: ArrayData, start: usize, stop: usize) -> VortexResult<Self> {
+        if start > stop || stop > array.len() {
+            vortex_bail!(
+                "Invalid slice {}..{} of array of length {}",
+                start,
+                stop,
+                array.len()
+            );
+        }
+
+        let (child, offset) = match SlicedArray::maybe_from(&array) {
+            Some(sliced) => (sliced.child(), sliced.offset() + start),
+            None => (array, start),
+        };
+
+        Self::try_from_parts(
+            child.dtype().clone(),
+            stop - start,
+            SlicedMetadata {
+                offset,
+                child_len: child.len(),
+            },
+            [child].into(),
+            StatsSet::default(),
+        )
+    }
+
+    /// The array that is sliced.
+    pub fn child(&self) -> ArrayData {
+        self.as_ref()
+            .child(0, self.dtype(), self.metadata().child_len)
+            .vortex_expect("SlicedArray is missing its child")
+    }
+
+    /// The index of the child at which the slice starts.
+    pub fn offset(&self) -> usize {
+        self.metadata().offset
+    }
+
+    /// Apply the slice to an array of the same length as the child.
+    fn slice_of(&self, array: impl AsRef<ArrayData>) -> VortexResult<ArrayData> {
+        slice(array, self.offset(), self.offset() + self.len())
+    }
+}
+
+/// Replace the small slices in the array tree with their canonical form.
+///
+/// A slice is small if its canonical form takes fewer bytes than the whole of the array it slices,
+/// so that writing a small slice of a large array doesn't write the large array.
+///
+/// A slice that can't be decoded, e.g. of an encoding that isn't registered, is kept as it is.
+pub fn materialize_small_slices(array: &ArrayData) -> VortexResult<ArrayData> {
+    Ok(materialize_slices_in(array)?.unwrap_or_else(|| array.clone()))
+}
+
+/// The array with its small slices materialized, or `None` if it has none.
+fn materialize_slices_in(array: &ArrayData) -> VortexResult<Option<ArrayData>> {
+    if let Some(sliced) = SlicedArray::maybe_from(array) {
+        let child_nbytes = sliced.child().nbytes();
+        return Ok(sliced
+            .into_canonical()
+            .ok()
+            .map(Canonical::into_array)
+            .filter(|canonical| canonical.nbytes() < child_nbytes));
+    }
+
+    let children = array.children();
+    let materialized = children
+        .iter()
+        .map(materialize_slices_in)
+        .collect::<VortexResult<Vec<_>>>()?;
+    if materialized.iter().all(Option::is_none) {
+        return Ok(None);
+    }
+    array
+        .with_children(
+            children
+                .into_iter()
+                .zip(materialized)
+                .map(|(child, materialized)| materialized.unwrap_or(child))
+                .collect(),
+        )
+        .map(Some)
+}
+
+impl ArrayTrait for SlicedArray {}
+
+impl IntoCanonical for SlicedArray {
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        // Canonical encodings slice without copying, so the slice of the canonical child is
+        // itself canonical.
+        self.slice_of(self.child().into_canonical()?.into_array())?
+            .into_canonical()
+    }
+}
+
+impl ValidityVTable<SlicedArray> for SlicedEncoding {
+    fn is_valid(&self, array: &SlicedArray, index: usize) -> bool {
+        array.child().is_valid(array.offset() + index)
+    }
+
+    fn logical_validity(&self, array: &SlicedArray) -> LogicalValidity {
+        match array.child().logical_validity() {
+            LogicalValidity::AllValid(_) => LogicalValidity::AllValid(array.len()),
+            LogicalValidity::AllInvalid(_) => LogicalValidity::AllInvalid(array.len()),
+            LogicalValidity::Array(validity) => LogicalValidity::Array(
+                array
+                    .slice_of(validity)
+                    .vortex_expect("SlicedArray: failed to slice validity"),
+            ),
+        }
+    }
+}
+
+impl StatisticsVTable<SlicedArray> for SlicedEncoding {
+    fn compute_statistics(&self, array: &SlicedArray, stat: Stat) -> VortexResult<StatsSet> {
+        if stat == Stat::UncompressedSizeInBytes {
+            return Ok(StatsSet::of(stat, array.nbytes()));
+        }
+
+        Ok(StatsSet::default())
+    }
+}
+
+impl VisitorVTable<SlicedArray> for SlicedEncoding {
+    fn accept(&self, array: &SlicedArray, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("sliced", &array.child())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{
+        materialize_small_slices, ConstantArray, PrimitiveArray, SlicedArray, StructArray,
+    };
+    use crate::compute::{scalar_at, slice};
+    use crate::validity::{ArrayValidity, LogicalValidity};
+    use crate::variants::StructArrayTrait;
+    use crate::{ArrayLen, IntoArrayData, IntoArrayVariant};
+
+    #[test]
+    fn slice_of_slice() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(0i32), None, Some(2), Some(3), Some(4)])
+                .into_array();
+        let sliced = SlicedArray::try_new(array, 1, 5).unwrap();
+        let resliced = SlicedArray::try_from(slice(&sliced, 1, 3).unwrap()).unwrap();
+
+        assert_eq!(resliced.offset(), 2);
+        assert_eq!(resliced.len(), 2);
+        assert_eq!(resliced.child().len(), 5);
+        assert_eq!(i32::try_from(&scalar_at(&resliced, 1).unwrap()).unwrap(), 3);
+        assert_eq!(
+            resliced
+                .into_array()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            &[2, 3]
+        );
+
+        assert!(!sliced.is_valid(0));
+        assert!(matches!(
+            sliced.logical_validity(),
+            LogicalValidity::Array(_)
+        ));
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let array = PrimitiveArray::from(vec![0i32, 1]).into_array();
+        assert!(SlicedArray::try_new(array.clone(), 1, 3).is_err());
+        assert!(SlicedArray::try_new(array, 2, 1).is_err());
+    }
+
+    #[test]
+    fn materialize_small() {
+        let values = PrimitiveArray::from((0..1000).collect::<Vec<i32>>()).into_array();
+        let sliced = SlicedArray::try_new(values, 10, 20).unwrap().into_array();
+        let nested = StructArray::from_fields(&[("values", sliced)])
+            .unwrap()
+            .into_array();
+
+        let materialized = materialize_small_slices(&nested)
+            .unwrap()
+            .into_struct()
+            .unwrap()
+            .field(0)
+            .unwrap();
+        assert!(PrimitiveArray::maybe_from(&materialized).is_some());
+        assert_eq!(
+            materialized
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            (10..20).collect::<Vec<_>>()
+        );
+
+        // Decoding the slice of a constant array takes more bytes than the constant.
+        let constant = ConstantArray::new(1i32, 1000).into_array();
+        let sliced = SlicedArray::try_new(constant, 10, 500)
+            .unwrap()
+            .into_array();
+        assert!(SlicedArray::maybe_from(materialize_small_slices(&sliced).unwrap()).is_some());
+    }
+}
@@ -0,0 +1,94 @@
+use vortex_dtype::field::Field;
+use vortex_error::{vortex_err, VortexExpect as _, VortexResult};
+
+use crate::array::sliced::SlicedArray;
+use crate::array::SlicedEncoding;
+use crate::variants::{
+    BinaryArrayTrait, BoolArrayTrait, ExtensionArrayTrait, ListArrayTrait, NullArrayTrait,
+    PrimitiveArrayTrait, StructArrayTrait, Utf8ArrayTrait, VariantsVTable,
+};
+use crate::ArrayData;
+
+/// Sliced arrays support all DTypes
+impl VariantsVTable<SlicedArray> for SlicedEncoding {
+    fn as_null_array<'a>(&self, array: &'a SlicedArray) -> Option<&'a dyn NullArrayTrait> {
+        Some(array)
+    }
+
+    fn as_bool_array<'a>(&self, array: &'a SlicedArray) -> Option<&'a dyn BoolArrayTrait> {
+        Some(array)
+    }
+
+    fn as_primitive_array<'a>(
+        &self,
+        array: &'a SlicedArray,
+    ) -> Option<&'a dyn PrimitiveArrayTrait> {
+        Some(array)
+    }
+
+    fn as_utf8_array<'a>(&self, array: &'a SlicedArray) -> Option<&'a dyn Utf8ArrayTrait> {
+        Some(array)
+    }
+
+    fn as_binary_array<'a>(&self, array: &'a SlicedArray) -> Option<&'a dyn BinaryArrayTrait> {
+        Some(array)
+    }
+
+    fn as_struct_array<'a>(&self, array: &'a SlicedArray) -> Option<&'a dyn StructArrayTrait> {
+        Some(array)
+    }
+
+    fn as_list_array<'a>(&self, array: &'a SlicedArray) -> Option<&'a dyn ListArrayTrait> {
+        Some(array)
+    }
+
+    fn as_extension_array<'a>(
+        &self,
+        array: &'a SlicedArray,
+    ) -> Option<&'a dyn ExtensionArrayTrait> {
+        Some(array)
+    }
+}
+
+impl NullArrayTrait for SlicedArray {}
+
+impl BoolArrayTrait for SlicedArray {}
+
+impl PrimitiveArrayTrait for SlicedArray {}
+
+impl Utf8ArrayTrait for SlicedArray {}
+
+impl BinaryArrayTrait for SlicedArray {}
+
+impl StructArrayTrait for SlicedArray {
+    fn field(&self, idx: usize) -> Option<ArrayData> {
+        let field = self.child().as_struct_array()?.field(idx)?;
+        Some(
+            self.slice_of(field)
+                .vortex_expect("SlicedArray: failed to slice field"),
+        )
+    }
+
+    fn project(&self, projection: &[Field]) -> VortexResult<ArrayData> {
+        let projected = self
+            .child()
+            .as_struct_array()
+            .ok_or_else(|| vortex_err!("Sliced array was not a StructArray"))?
+            .project(projection)?;
+        self.slice_of(projected)
+    }
+}
+
+impl ListArrayTrait for SlicedArray {}
+
+impl ExtensionArrayTrait for SlicedArray {
+    fn storage_data(&self) -> ArrayData {
+        let storage = self
+            .child()
+            .as_extension_array()
+            .vortex_expect("Expected extension array")
+            .storage_data();
+        self.slice_of(storage)
+            .vortex_expect("SlicedArray: failed to slice extension storage")
+    }
+}
@@ -4,8 +4,9 @@ use vortex_scalar::Scalar;
 use crate::array::sparse::SparseArray;
 use crate::array::{ConstantArray, SparseEncoding};
 use crate::compute::{
-    BinaryNumericFn, ComputeVTable, FilterFn, FilterMask, InvertFn, ScalarAtFn, SearchResult,
-    SearchSortedFn, SearchSortedSide, SearchSortedUsizeFn, SliceFn, TakeFn,
+    min_max, BinaryNumericFn, ComputeVTable, FilterFn, FilterMask, InvertFn, MinMaxFn,
+    MinMaxResult, ScalarAtFn, SearchResult, SearchSortedFn, SearchSortedSide, SearchSortedUsizeFn,
+    SliceFn, TakeFn,
 };
 use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData};
 
@@ -27,6 +28,10 @@ impl ComputeVTable for SparseEncoding {
         Some(self)
     }
 
+    fn min_max_fn(&self) -> Option<&dyn MinMaxFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
@@ -48,6 +53,20 @@ impl ComputeVTable for SparseEncoding {
     }
 }
 
+impl MinMaxFn<SparseArray> for SparseEncoding {
+    fn min_max(&self, array: &SparseArray) -> VortexResult<Option<MinMaxResult>> {
+        let patches = array.patches();
+        // The fill value is only in the array if some row isn't patched.
+        let fill_scalar = array.fill_scalar();
+        let fill =
+            (patches.num_patches() < array.len() && !fill_scalar.is_null()).then(|| MinMaxResult {
+                min: fill_scalar.clone(),
+                max: fill_scalar,
+            });
+        Ok(MinMaxResult::merge_opt(min_max(patches.values())?, fill))
+    }
+}
+
 impl ScalarAtFn<SparseArray> for SparseEncoding {
     fn scalar_at(&self, array: &SparseArray, index: usize) -> VortexResult<Scalar> {
         Ok(array
@@ -109,7 +128,7 @@ mod test {
     use crate::array::primitive::PrimitiveArray;
     use crate::array::sparse::SparseArray;
     use crate::compute::{
-        filter, search_sorted, slice, FilterMask, SearchResult, SearchSortedSide,
+        filter, min_max, search_sorted, slice, FilterMask, SearchResult, SearchSortedSide,
     };
     use crate::validity::Validity;
     use crate::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
@@ -126,6 +145,37 @@ mod test {
         .into_array()
     }
 
+    #[rstest]
+    fn min_max_of_patches(array: ArrayData) {
+        let result = min_max(&array).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(Some(33i32)));
+        assert_eq!(result.max, Scalar::from(Some(55i32)));
+
+        let sliced = slice(&array, 3, 12).unwrap();
+        let result = min_max(&sliced).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(Some(44i32)));
+        assert_eq!(result.max, Scalar::from(Some(44i32)));
+    }
+
+    #[test]
+    fn min_max_with_fill() {
+        let array = SparseArray::try_new(
+            PrimitiveArray::from(vec![1u64, 3]).into_array(),
+            PrimitiveArray::from(vec![-5i32, 8]).into_array(),
+            5,
+            Scalar::from(2i32),
+        )
+        .unwrap();
+        let result = min_max(&array).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(-5i32));
+        assert_eq!(result.max, Scalar::from(8i32));
+
+        let sliced = slice(&array, 1, 2).unwrap();
+        let result = min_max(&sliced).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(-5i32));
+        assert_eq!(result.max, Scalar::from(-5i32));
+    }
+
     #[rstest]
     fn search_larger_than(array: ArrayData) {
         let res = search_sorted(&array, 66, SearchSortedSide::Left).unwrap();
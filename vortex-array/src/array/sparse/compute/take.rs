@@ -1,16 +1,35 @@
+use vortex_dtype::{DType, Nullability, PType};
 use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
 
 use crate::array::sparse::SparseArray;
 use crate::array::{ConstantArray, SparseEncoding};
-use crate::compute::TakeFn;
+use crate::compute::{add_scalar, try_cast, TakeFn};
 use crate::{ArrayData, IntoArrayData};
 
 impl TakeFn<SparseArray> for SparseEncoding {
     fn take(&self, array: &SparseArray, take_indices: &ArrayData) -> VortexResult<ArrayData> {
-        // FIXME(DK): add_scalar to the take_indices if they are shorter
-        let resolved_patches = array.resolved_patches()?;
+        let patches = array.patches();
+        let offset = array.indices_offset();
 
-        let Some(new_patches) = resolved_patches.take(take_indices)? else {
+        // The patch indices are offset into a sliced array, so shift whichever of the patch
+        // indices and the take indices there are fewer of, rather than always all the patches.
+        let new_patches = if offset == 0 {
+            patches.take(take_indices)?
+        } else if take_indices.len() < patches.num_patches() {
+            let take_indices = add_scalar(
+                try_cast(
+                    take_indices,
+                    &DType::Primitive(PType::U64, Nullability::NonNullable),
+                )?,
+                Scalar::from(offset),
+            )?;
+            patches.take(&take_indices)?
+        } else {
+            array.resolved_patches()?.take(take_indices)?
+        };
+
+        let Some(new_patches) = new_patches else {
             return Ok(ConstantArray::new(array.fill_scalar(), take_indices.len()).into_array());
         };
 
@@ -25,7 +44,9 @@ mod test {
 
     use crate::array::primitive::PrimitiveArray;
     use crate::array::sparse::SparseArray;
+    use crate::array::ConstantEncoding;
     use crate::compute::{scalar_at, slice, take};
+    use crate::encoding::Encoding;
     use crate::validity::Validity;
     use crate::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
 
@@ -56,6 +77,30 @@ mod test {
         assert_eq!(scalar_at(&sparse, 2).unwrap(), test_array_fill_value());
     }
 
+    #[test]
+    fn take_few_with_indices_offset() {
+        // The same values as `sparse_array`, with the patch indices offset by 10.
+        let sparse = SparseArray::try_new_with_offset(
+            PrimitiveArray::from(vec![10u64, 47, 57, 109]).into_array(),
+            PrimitiveArray::from_vec(vec![1.23f64, 0.47, 9.99, 3.5], Validity::AllValid)
+                .into_array(),
+            100,
+            10,
+            test_array_fill_value(),
+        )
+        .unwrap()
+        .into_array();
+
+        let taken = take(&sparse, vec![37u8, 40].into_array()).unwrap();
+        assert!(SparseArray::try_from(taken.clone()).is_ok());
+        assert_eq!(scalar_at(&taken, 0).unwrap(), Scalar::from(Some(0.47)));
+        assert_eq!(scalar_at(&taken, 1).unwrap(), test_array_fill_value());
+
+        let taken = take(&sparse, vec![1u8].into_array()).unwrap();
+        assert!(taken.is_encoding(ConstantEncoding::ID));
+        assert_eq!(scalar_at(&taken, 0).unwrap(), test_array_fill_value());
+    }
+
     #[test]
     fn sparse_take() {
         let sparse = sparse_array();
@@ -1,12 +1,17 @@
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
-use ::serde::{Deserialize, Serialize};
+use ::serde::Deserialize;
 use vortex_error::{vortex_bail, vortex_panic, VortexExpect as _, VortexResult};
 use vortex_scalar::{Scalar, ScalarValue};
 
 use crate::array::constant::ConstantArray;
 use crate::compute::{scalar_at, sub_scalar};
 use crate::encoding::ids;
+use crate::metadata::{
+    flexbuffers_root, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
+};
 use crate::patches::{Patches, PatchesMetadata};
 use crate::stats::{ArrayStatistics, Stat, StatisticsVTable, StatsSet};
 use crate::validity::{ArrayValidity, LogicalValidity, ValidityVTable};
@@ -19,7 +24,10 @@ mod variants;
 
 impl_encoding!("vortex.sparse", ids::SPARSE, Sparse);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the offset of the indices as a u64, the
+/// patches and the fill value.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct SparseMetadata {
     // Offset value for patch indices as a result of slicing
     indices_offset: usize,
@@ -27,6 +35,35 @@ pub struct SparseMetadata {
     fill_value: ScalarValue,
 }
 
+impl TrySerializeArrayMetadata for SparseMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        writer.put_usize(self.indices_offset);
+        self.patches.write_to(&mut writer);
+        writer.put_scalar_value(&self.fill_value)?;
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for SparseMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let indices_offset = reader.get_usize()?;
+        let patches = PatchesMetadata::read_from(&mut reader)?;
+        let fill_value = reader.get_scalar_value()?;
+        reader.finish()?;
+        Ok(Self {
+            indices_offset,
+            patches,
+            fill_value,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl Display for SparseMetadata {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         Debug::fmt(self, f)
@@ -1,11 +1,17 @@
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use vortex_dtype::field::Field;
 use vortex_dtype::{DType, FieldName, FieldNames, StructDType};
 use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexExpect as _, VortexResult};
+use vortex_scalar::Scalar;
 
 use crate::encoding::ids;
+use crate::metadata::{
+    flexbuffers_root, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
+};
 use crate::stats::{ArrayStatistics, Stat, StatisticsVTable, StatsSet};
 use crate::validity::{LogicalValidity, Validity, ValidityMetadata, ValidityVTable};
 use crate::variants::{StructArrayTrait, VariantsVTable};
@@ -16,10 +22,15 @@ use crate::{
 };
 
 mod compute;
+mod rows;
+
+pub use rows::RowBuffer;
 
 impl_encoding!("vortex.struct", ids::STRUCT, Struct);
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is just the validity.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(remote = "Self")]
 pub struct StructMetadata {
     validity: ValidityMetadata,
 }
@@ -30,6 +41,27 @@ impl Display for StructMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for StructMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        self.validity.write_to(&mut writer);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for StructMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let validity = ValidityMetadata::read_from(&mut reader)?;
+        reader.finish()?;
+        Ok(Self { validity })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl StructArray {
     pub fn validity(&self) -> Validity {
         self.metadata().validity.to_validity(|| {
@@ -139,6 +171,51 @@ impl StructArray {
             self.validity(),
         )
     }
+
+    /// Return a new StructArray with the field `name` set to `array`, replacing the field of that
+    /// name in place if there is one and appending it otherwise.
+    ///
+    /// The other fields and the validity are reused without copying.
+    pub fn with_field(&self, name: impl Into<FieldName>, array: ArrayData) -> VortexResult<Self> {
+        if array.len() != self.len() {
+            vortex_bail!(
+                "Expected field of length {}, found {}",
+                self.len(),
+                array.len()
+            );
+        }
+
+        let name = name.into();
+        let mut names = self.names().to_vec();
+        let mut children = self.children().collect::<Vec<_>>();
+        match names.iter().position(|n| *n == name) {
+            Some(idx) => children[idx] = array,
+            None => {
+                names.push(name);
+                children.push(array);
+            }
+        }
+
+        StructArray::try_new(names.into(), children, self.len(), self.validity())
+    }
+
+    /// Return a new StructArray without the field `name`.
+    ///
+    /// The other fields and the validity are reused without copying.
+    pub fn drop_field(&self, name: &str) -> VortexResult<Self> {
+        let idx = self
+            .names()
+            .iter()
+            .position(|n| n.as_ref() == name)
+            .ok_or_else(|| vortex_err!("Unknown field {name}"))?;
+
+        let mut names = self.names().to_vec();
+        let mut children = self.children().collect::<Vec<_>>();
+        names.remove(idx);
+        children.remove(idx);
+
+        StructArray::try_new(names.into(), children, self.len(), self.validity())
+    }
 }
 
 impl ArrayTrait for StructArray {}
@@ -176,7 +253,10 @@ impl ValidityVTable<StructArray> for StructEncoding {
     }
 
     fn logical_validity(&self, array: &StructArray) -> LogicalValidity {
-        array.validity().to_logical(array.len())
+        array
+            .metadata()
+            .validity
+            .to_logical(array.len(), || array.validity())
     }
 }
 
@@ -202,12 +282,49 @@ impl StatisticsVTable<StructArray> for StructEncoding {
                 .flatten()
                 .map(|size| StatsSet::of(stat, size))
                 .unwrap_or_default(),
-            Stat::NullCount => StatsSet::of(stat, array.validity().null_count(array.len())?),
+            Stat::NullCount => StatsSet::of(
+                stat,
+                array
+                    .metadata()
+                    .validity
+                    .null_count(array.len(), || array.validity())?,
+            ),
+            Stat::Min | Stat::Max => {
+                if array
+                    .metadata()
+                    .validity
+                    .null_count(array.len(), || array.validity())?
+                    == array.len()
+                {
+                    StatsSet::nulls(array.len(), array.dtype())
+                } else {
+                    field_bounds(array, stat)?
+                        .map(|bound| StatsSet::of(stat, bound))
+                        .unwrap_or_default()
+                }
+            }
             _ => StatsSet::default(),
         })
     }
 }
 
+/// The minimum or maximum of every field, as a struct scalar, or `None` if any field has no such
+/// bound.
+///
+/// These bounds are per field rather than the lexicographic extremes of the struct values, so
+/// they can be used to prune on nested columns. Values hidden by struct-level nulls are included,
+/// which may loosen but never invalidate the bounds.
+fn field_bounds(array: &StructArray, stat: Stat) -> VortexResult<Option<Scalar>> {
+    let mut bounds = Vec::with_capacity(array.nfields());
+    for (field, dtype) in array.children().zip(array.dtypes()) {
+        let Some(bound) = field.statistics().compute(stat) else {
+            return Ok(None);
+        };
+        bounds.push(bound.cast(dtype)?);
+    }
+    Ok(Some(Scalar::struct_(array.dtype().clone(), bounds)))
+}
+
 #[cfg(test)]
 mod test {
     use vortex_dtype::field::Field;
@@ -216,10 +333,12 @@ mod test {
     use crate::array::primitive::PrimitiveArray;
     use crate::array::struct_::StructArray;
     use crate::array::varbin::VarBinArray;
-    use crate::array::BoolArray;
+    use crate::array::{BoolArray, BoolEncoding, ChunkedArray};
+    use crate::encoding::Encoding;
+    use crate::stats::{ArrayStatistics, Stat};
     use crate::validity::Validity;
     use crate::variants::StructArrayTrait;
-    use crate::{ArrayLen, IntoArrayData};
+    use crate::{ArrayDType, ArrayLen, IntoArrayData};
 
     #[test]
     fn test_project() {
@@ -257,4 +376,90 @@ mod test {
         let prims = PrimitiveArray::try_from(struct_b.field(1).unwrap()).unwrap();
         assert_eq!(prims.maybe_null_slice::<i64>(), [0i64, 1, 2, 3, 4]);
     }
+
+    #[test]
+    fn test_with_and_drop_field() {
+        let array = struct_array(vec![Some(1), None, Some(3)], vec!["a", "b", "c"]);
+        let zs = BoolArray::from_iter([true, false, true]).into_array();
+
+        let appended = array.with_field("zs", zs.clone()).unwrap();
+        assert_eq!(
+            appended.names().as_ref(),
+            [
+                FieldName::from("xs"),
+                FieldName::from("ys"),
+                FieldName::from("zs")
+            ],
+        );
+
+        let replaced = appended.with_field("xs", zs).unwrap();
+        assert_eq!(replaced.names().as_ref(), appended.names().as_ref());
+        assert!(replaced
+            .field_by_name("xs")
+            .unwrap()
+            .is_encoding(BoolEncoding::ID));
+        assert!(array
+            .with_field("ws", BoolArray::from_iter([true]).into_array())
+            .is_err());
+
+        let dropped = replaced.drop_field("ys").unwrap();
+        assert_eq!(
+            dropped.names().as_ref(),
+            [FieldName::from("xs"), FieldName::from("zs")],
+        );
+        assert_eq!(dropped.len(), 3);
+        assert!(dropped.drop_field("ys").is_err());
+    }
+
+    fn struct_array(xs: Vec<Option<i64>>, ys: Vec<&str>) -> StructArray {
+        let len = xs.len();
+        StructArray::try_new(
+            FieldNames::from(["xs".into(), "ys".into()]),
+            vec![
+                PrimitiveArray::from_nullable_vec(xs).into_array(),
+                VarBinArray::from_vec(ys, DType::Utf8(Nullability::NonNullable)).into_array(),
+            ],
+            len,
+            Validity::NonNullable,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_field_bounds() {
+        let array = struct_array(vec![Some(3), None, Some(1)], vec!["b", "a", "c"]);
+
+        let min = array.statistics().compute(Stat::Min).unwrap();
+        let min = min.as_struct();
+        assert_eq!(
+            min.field("xs").unwrap().as_primitive().typed_value::<i64>(),
+            Some(1)
+        );
+        assert_eq!(String::try_from(&min.field("ys").unwrap()).unwrap(), "a");
+
+        let max = array.statistics().compute(Stat::Max).unwrap();
+        let max = max.as_struct();
+        assert_eq!(
+            max.field("xs").unwrap().as_primitive().typed_value::<i64>(),
+            Some(3)
+        );
+        assert_eq!(String::try_from(&max.field("ys").unwrap()).unwrap(), "c");
+    }
+
+    #[test]
+    fn test_field_bounds_merge_per_field() {
+        let first = struct_array(vec![Some(1), Some(5)], vec!["x", "y"]);
+        let second = struct_array(vec![Some(2), Some(9)], vec!["a", "b"]);
+        let dtype = first.dtype().clone();
+        let chunked =
+            ChunkedArray::try_new(vec![first.into_array(), second.into_array()], dtype).unwrap();
+
+        let min = chunked.statistics().compute(Stat::Min).unwrap();
+        let min = min.as_struct();
+        assert_eq!(
+            min.field("xs").unwrap().as_primitive().typed_value::<i64>(),
+            Some(1)
+        );
+        assert_eq!(String::try_from(&min.field("ys").unwrap()).unwrap(), "a");
+    }
 }
@@ -0,0 +1,662 @@
+//! A row-oriented form of struct arrays, whose rows compare as bytes in the order of sort keys.
+//!
+//! Each field of a row is a null marker byte followed, for valid values, by an encoding of the
+//! value whose bytes compare like the value itself:
+//!
+//! - booleans are one byte, `0` or `1`
+//! - integers are big-endian, with the sign bit of signed integers flipped
+//! - floats are big-endian bits, with every bit flipped for negative values and only the sign bit
+//!   for positive ones, which orders them like [`f64::total_cmp`]
+//! - strings and binaries are their bytes, with each `0x00` escaped as `0x00 0xFF`, terminated by
+//!   `0x00 0x00`
+//! - structs are the encodings of their fields, which follow the direction and null order of the
+//!   key
+//!
+//! The bytes of a value are inverted for descending keys, and the null marker orders nulls first
+//! or last whatever the direction.
+
+use std::cmp::Ordering;
+
+use arrow_buffer::NullBuffer;
+use vortex_dtype::field::Field;
+use vortex_dtype::half::f16;
+use vortex_dtype::{match_each_native_ptype, DType, FieldName, FieldNames};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::accessor::ArrayAccessor;
+use crate::array::{BoolArray, NullArray, PrimitiveArray, StructArray, VarBinViewArray};
+use crate::compute::SortKey;
+use crate::validity::{ArrayValidity, Validity};
+use crate::variants::{PrimitiveArrayTrait, StructArrayTrait};
+use crate::{ArrayDType, ArrayData, ArrayLen, Canonical, IntoArrayData, IntoCanonical};
+
+const NULL_FIRST: u8 = 0x00;
+const VALID: u8 = 0x01;
+const NULL_LAST: u8 = 0x02;
+
+/// The rows of a struct array, see [`StructArray::to_row_buffer`].
+#[derive(Debug, Clone)]
+pub struct RowBuffer {
+    data: Vec<u8>,
+    offsets: Vec<usize>,
+    fields: Vec<RowField>,
+}
+
+/// A field encoded in each row, in the order of the sort keys.
+#[derive(Debug, Clone)]
+struct RowField {
+    name: FieldName,
+    dtype: DType,
+    descending: bool,
+    nulls_first: bool,
+}
+
+impl RowBuffer {
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The bytes of the row at `index`.
+    pub fn row(&self, index: usize) -> &[u8] {
+        &self.data[self.offsets[index]..self.offsets[index + 1]]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        (0..self.len()).map(|i| self.row(i))
+    }
+
+    /// The size in bytes of all rows.
+    pub fn nbytes(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Compare two rows by the sort keys the buffer was made with.
+    pub fn compare(&self, left: usize, right: usize) -> Ordering {
+        self.row(left).cmp(self.row(right))
+    }
+
+    /// The indices of the rows in sorted order, with equal rows in their original order, as a
+    /// u64 array to [take][crate::compute::take] the sorted rows of the struct array with.
+    pub fn sort_indices(&self) -> ArrayData {
+        let mut indices = (0..self.len()).collect::<Vec<_>>();
+        indices.sort_by(|&l, &r| self.compare(l, r));
+        let indices = indices.into_iter().map(|i| i as u64).collect::<Vec<_>>();
+        PrimitiveArray::from(indices).into_array()
+    }
+}
+
+impl StructArray {
+    /// Encode the fields of the sort keys into rows that compare as bytes in the order of the
+    /// keys, or with no keys, all fields ascending with nulls first.
+    ///
+    /// The validity of the struct itself isn't encoded, only that of its fields.
+    pub fn to_row_buffer(&self, sort_keys: &[SortKey]) -> VortexResult<RowBuffer> {
+        let sort_keys = if sort_keys.is_empty() {
+            (0..self.names().len()).map(SortKey::new).collect()
+        } else {
+            sort_keys.to_vec()
+        };
+
+        let mut rows = vec![Vec::new(); self.len()];
+        let mut fields = Vec::with_capacity(sort_keys.len());
+        for key in &sort_keys {
+            let idx = self
+                .key_index(key)
+                .ok_or_else(|| vortex_err!("Unknown sort key {}", key.field()))?;
+            let column = self
+                .field(idx)
+                .ok_or_else(|| vortex_err!("Missing field {idx}"))?;
+            let field = RowField {
+                name: self.names()[idx].clone(),
+                dtype: column.dtype().clone(),
+                descending: key.descending(),
+                nulls_first: key.nulls_first(),
+            };
+            encode_column(&column, &field, &mut rows)?;
+            fields.push(field);
+        }
+
+        let mut offsets = Vec::with_capacity(rows.len() + 1);
+        offsets.push(0);
+        let mut data = Vec::with_capacity(rows.iter().map(Vec::len).sum());
+        for row in rows {
+            data.extend_from_slice(&row);
+            offsets.push(data.len());
+        }
+        Ok(RowBuffer {
+            data,
+            offsets,
+            fields,
+        })
+    }
+
+    /// Decode rows back into a struct array of the fields of their sort keys, in key order.
+    pub fn from_rows(rows: &RowBuffer) -> VortexResult<Self> {
+        let mut positions = rows.offsets[..rows.len()].to_vec();
+        let fields = rows
+            .fields
+            .iter()
+            .map(|field| decode_column(rows, field, &mut positions))
+            .collect::<VortexResult<Vec<_>>>()?;
+        if positions
+            .iter()
+            .zip(&rows.offsets[1..])
+            .any(|(p, e)| p != e)
+        {
+            vortex_bail!("Rows have trailing bytes after their fields");
+        }
+        let names = rows
+            .fields
+            .iter()
+            .map(|f| f.name.clone())
+            .collect::<Vec<_>>();
+        Self::try_new(
+            FieldNames::from(names),
+            fields,
+            rows.len(),
+            Validity::NonNullable,
+        )
+    }
+
+    fn key_index(&self, key: &SortKey) -> Option<usize> {
+        match key.field() {
+            Field::Name(name) => self
+                .names()
+                .iter()
+                .position(|n| n.as_ref() == name.as_ref()),
+            Field::Index(idx) => (*idx < self.names().len()).then_some(*idx),
+        }
+    }
+}
+
+/// Append the encoding of each value of the column to its row.
+fn encode_column(column: &ArrayData, field: &RowField, rows: &mut [Vec<u8>]) -> VortexResult<()> {
+    let nulls = column.logical_validity().to_null_buffer()?;
+    let nulls = nulls.as_ref();
+    let null_marker = if field.nulls_first {
+        NULL_FIRST
+    } else {
+        NULL_LAST
+    };
+    let starts = rows.iter().map(Vec::len).collect::<Vec<_>>();
+
+    match column.clone().into_canonical()? {
+        Canonical::Null(_) => rows.iter_mut().for_each(|row| row.push(null_marker)),
+        Canonical::Bool(array) => {
+            let values = array.boolean_buffer();
+            encode_rows(rows, nulls, null_marker, |i, row| {
+                row.push(u8::from(values.value(i)))
+            });
+        }
+        Canonical::Primitive(array) => match_each_native_ptype!(array.ptype(), |$T| {
+            let values = array.maybe_null_slice::<$T>();
+            encode_rows(rows, nulls, null_marker, |i, row| values[i].encode(row));
+        }),
+        Canonical::VarBinView(array) => array.with_iterator(|values| {
+            let values = values.collect::<Vec<_>>();
+            encode_rows(rows, nulls, null_marker, |i, row| {
+                encode_bytes(values[i].unwrap_or_default(), row)
+            });
+        })?,
+        Canonical::Struct(array) => {
+            let mut children = vec![Vec::new(); rows.len()];
+            for (idx, name) in array.names().iter().enumerate() {
+                let child = array
+                    .field(idx)
+                    .ok_or_else(|| vortex_err!("Missing field {idx}"))?;
+                let child_field = RowField {
+                    name: name.clone(),
+                    dtype: child.dtype().clone(),
+                    descending: field.descending,
+                    nulls_first: field.nulls_first,
+                };
+                encode_column(&child, &child_field, &mut children)?;
+            }
+            // The fields of null structs are encoded too, so that every struct has all of them.
+            for (i, (row, child)) in rows.iter_mut().zip(children).enumerate() {
+                let valid = nulls.map_or(true, |n| n.is_valid(i));
+                row.push(if valid { VALID } else { null_marker });
+                row.extend(child);
+            }
+        }
+        Canonical::Decimal(_)
+        | Canonical::List(_)
+        | Canonical::FixedSizeList(_)
+        | Canonical::Extension(_) => {
+            vortex_bail!("Cannot encode {} values into rows", column.dtype())
+        }
+    }
+
+    // The fields of structs invert their own values, keeping the order of their nulls.
+    if field.descending && !has_value_when_null(&field.dtype) {
+        for (row, start) in rows.iter_mut().zip(starts) {
+            // Only the value is inverted, the null marker keeps the order of nulls.
+            row[start + 1..].iter_mut().for_each(|b| *b = !*b);
+        }
+    }
+    Ok(())
+}
+
+/// Append a null marker to the row of each null, or a valid marker and the value to the others.
+fn encode_rows(
+    rows: &mut [Vec<u8>],
+    nulls: Option<&NullBuffer>,
+    null_marker: u8,
+    mut encode: impl FnMut(usize, &mut Vec<u8>),
+) {
+    for (i, row) in rows.iter_mut().enumerate() {
+        if nulls.map_or(true, |n| n.is_valid(i)) {
+            row.push(VALID);
+            encode(i, row);
+        } else {
+            row.push(null_marker);
+        }
+    }
+}
+
+fn encode_bytes(bytes: &[u8], row: &mut Vec<u8>) {
+    for &b in bytes {
+        row.push(b);
+        if b == 0 {
+            row.push(0xFF);
+        }
+    }
+    row.extend([0, 0]);
+}
+
+/// Decode the field of each row starting at its position, advancing the positions past it.
+fn decode_column(
+    rows: &RowBuffer,
+    field: &RowField,
+    positions: &mut [usize],
+) -> VortexResult<ArrayData> {
+    let len = positions.len();
+    let mut valid = Vec::with_capacity(len);
+    let mut values = Vec::with_capacity(len);
+    for (i, pos) in positions.iter_mut().enumerate() {
+        let marker = *rows
+            .data
+            .get(*pos)
+            .ok_or_else(|| vortex_err!("Row {i} is truncated"))?;
+        *pos += 1;
+        if marker != VALID && !has_value_when_null(&field.dtype) {
+            valid.push(false);
+            values.push(Vec::new());
+            continue;
+        }
+        let value = take_value(&rows.data, pos, &field.dtype, field.descending)
+            .ok_or_else(|| vortex_err!("Row {i} has an invalid {} value", field.dtype))?;
+        valid.push(marker == VALID);
+        values.push(value);
+    }
+
+    let validity = if field.dtype.is_nullable() {
+        Validity::from_iter(valid.iter().copied())
+    } else if valid.iter().all(|v| *v) {
+        Validity::NonNullable
+    } else {
+        vortex_bail!("Non-nullable field {} has null rows", field.name);
+    };
+
+    Ok(match &field.dtype {
+        DType::Null => NullArray::new(len).into_array(),
+        DType::Bool(_) => {
+            let bools = values.iter().map(|v| v.first() == Some(&1));
+            BoolArray::try_new(bools.collect(), validity)?.into_array()
+        }
+        DType::Primitive(ptype, _) => match_each_native_ptype!(*ptype, |$T| {
+            let decoded = values
+                .iter()
+                .map(|v| if v.is_empty() { <$T>::default() } else { <$T>::decode(v) })
+                .collect::<Vec<_>>();
+            PrimitiveArray::from_vec(decoded, validity).into_array()
+        }),
+        DType::Utf8(_) | DType::Binary(_) => VarBinViewArray::from_iter(
+            values
+                .iter()
+                .zip(&valid)
+                .map(|(v, is_valid)| is_valid.then_some(v.as_slice())),
+            field.dtype.clone(),
+        )
+        .into_array(),
+        DType::Struct(st, _) => {
+            let child_rows = RowBuffer {
+                data: values.concat(),
+                offsets: offsets_of(&values),
+                fields: Vec::new(),
+            };
+            let mut child_positions = child_rows.offsets[..len].to_vec();
+            let children = st
+                .names()
+                .iter()
+                .zip(st.dtypes().iter())
+                .map(|(name, dtype)| {
+                    let child_field = RowField {
+                        name: name.clone(),
+                        dtype: dtype.clone(),
+                        descending: field.descending,
+                        nulls_first: field.nulls_first,
+                    };
+                    decode_column(&child_rows, &child_field, &mut child_positions)
+                })
+                .collect::<VortexResult<Vec<_>>>()?;
+            StructArray::try_new(st.names().clone(), children, len, validity)?.into_array()
+        }
+        DType::Decimal(..) | DType::List(..) | DType::FixedSizeList(..) | DType::Extension(_) => {
+            vortex_bail!("Cannot decode {} values from rows", field.dtype)
+        }
+    })
+}
+
+/// Whether null values of the type are encoded, as structs are.
+fn has_value_when_null(dtype: &DType) -> bool {
+    matches!(dtype, DType::Struct(..))
+}
+
+fn offsets_of(values: &[Vec<u8>]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(values.len() + 1);
+    offsets.push(0);
+    for v in values {
+        offsets.push(offsets[offsets.len() - 1] + v.len());
+    }
+    offsets
+}
+
+/// The bytes of the value at `pos`, un-inverted, advancing `pos` past them. Strings and binaries
+/// are unescaped, while structs keep the encoding of their fields.
+fn take_value(data: &[u8], pos: &mut usize, dtype: &DType, descending: bool) -> Option<Vec<u8>> {
+    let flip = |b: u8| if descending { !b } else { b };
+    let mut value = Vec::new();
+    match dtype {
+        DType::Null => return None,
+        DType::Bool(_) => value.push(flip(*data.get(*pos)?)),
+        DType::Primitive(ptype, _) => {
+            let width = ptype.byte_width();
+            value.extend(data.get(*pos..*pos + width)?.iter().map(|b| flip(*b)));
+        }
+        DType::Utf8(_) | DType::Binary(_) => {
+            let mut i = *pos;
+            loop {
+                let b = flip(*data.get(i)?);
+                i += 1;
+                if b != 0 {
+                    value.push(b);
+                    continue;
+                }
+                match flip(*data.get(i)?) {
+                    0 => break,
+                    0xFF => value.push(0),
+                    _ => return None,
+                }
+                i += 1;
+            }
+            *pos = i + 1;
+            return Some(value);
+        }
+        DType::Struct(st, _) => {
+            // Find the end of the fields, keeping their encoding to decode them afterwards. The
+            // fields invert their own values, so the bytes are kept as they are.
+            let mut end = *pos;
+            for child in st.dtypes().iter() {
+                let marker = *data.get(end)?;
+                end += 1;
+                if marker == VALID || has_value_when_null(child) {
+                    take_value(data, &mut end, child, descending)?;
+                }
+            }
+            value.extend_from_slice(data.get(*pos..end)?);
+            *pos = end;
+            return Some(value);
+        }
+        DType::Decimal(..) | DType::List(..) | DType::FixedSizeList(..) | DType::Extension(_) => {
+            return None
+        }
+    }
+    *pos += value.len();
+    Some(value)
+}
+
+/// Native values encoded as big-endian bytes that compare like the values.
+trait RowValue: Sized {
+    fn encode(&self, out: &mut Vec<u8>);
+
+    /// Decode the value from exactly the bytes it was encoded to.
+    fn decode(bytes: &[u8]) -> Self;
+}
+
+macro_rules! unsigned_row_value {
+    ($($T:ty),*) => {
+        $(impl RowValue for $T {
+            fn encode(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_be_bytes());
+            }
+
+            fn decode(bytes: &[u8]) -> Self {
+                let mut be = [0; size_of::<$T>()];
+                be.copy_from_slice(bytes);
+                <$T>::from_be_bytes(be)
+            }
+        })*
+    };
+}
+
+macro_rules! signed_row_value {
+    ($($T:ty => $U:ty),*) => {
+        $(impl RowValue for $T {
+            fn encode(&self, out: &mut Vec<u8>) {
+                (*self as $U ^ (1 << (<$U>::BITS - 1))).encode(out)
+            }
+
+            fn decode(bytes: &[u8]) -> Self {
+                (<$U>::decode(bytes) ^ (1 << (<$U>::BITS - 1))) as $T
+            }
+        })*
+    };
+}
+
+macro_rules! float_row_value {
+    ($($T:ty => $U:ty),*) => {
+        $(impl RowValue for $T {
+            fn encode(&self, out: &mut Vec<u8>) {
+                let bits = self.to_bits();
+                let sign = 1 << (<$U>::BITS - 1);
+                (if bits & sign != 0 { !bits } else { bits | sign }).encode(out)
+            }
+
+            fn decode(bytes: &[u8]) -> Self {
+                let bits = <$U>::decode(bytes);
+                let sign = 1 << (<$U>::BITS - 1);
+                <$T>::from_bits(if bits & sign != 0 { bits & !sign } else { !bits })
+            }
+        })*
+    };
+}
+
+unsigned_row_value!(u8, u16, u32, u64);
+signed_row_value!(i8 => u8, i16 => u16, i32 => u32, i64 => u64);
+float_row_value!(f16 => u16, f32 => u32, f64 => u64);
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::{PrimitiveArray, StructArray, VarBinViewArray};
+    use crate::compute::{take, SortKey};
+    use crate::validity::{ArrayValidity, Validity};
+    use crate::variants::StructArrayTrait;
+    use crate::{IntoArrayData, IntoArrayVariant};
+
+    fn batch() -> StructArray {
+        StructArray::from_fields(&[
+            (
+                "name",
+                VarBinViewArray::from_iter(
+                    [Some("b"), None, Some("a\0"), Some("a"), Some("b")],
+                    DType::Utf8(Nullability::Nullable),
+                )
+                .into_array(),
+            ),
+            (
+                "score",
+                PrimitiveArray::from_nullable_vec(vec![
+                    Some(-1.5f64),
+                    Some(2.0),
+                    None,
+                    Some(0.0),
+                    Some(-3.0),
+                ])
+                .into_array(),
+            ),
+            (
+                "id",
+                PrimitiveArray::from(vec![-2i32, 7, 0, i32::MIN, i32::MAX]).into_array(),
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn rows_round_trip() {
+        let batch = batch();
+        let rows = batch.to_row_buffer(&[]).unwrap();
+        assert_eq!(rows.len(), 5);
+
+        let decoded = StructArray::from_rows(&rows).unwrap();
+        assert_eq!(decoded.names(), batch.names());
+        let names = decoded.field(0).unwrap();
+        assert!(!names.is_valid(1));
+        assert_eq!(
+            names.into_varbinview().unwrap().bytes_at(2).unwrap(),
+            b"a\0"
+        );
+        assert_eq!(
+            decoded
+                .field(2)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            [-2, 7, 0, i32::MIN, i32::MAX]
+        );
+        let scores = decoded.field(1).unwrap().into_primitive().unwrap();
+        assert!(!scores.is_valid(2));
+        assert_eq!(scores.maybe_null_slice::<f64>()[4], -3.0);
+    }
+
+    #[test]
+    fn rows_nested_structs() {
+        let inner = StructArray::try_new(
+            ["x".into()].into(),
+            vec![PrimitiveArray::from(vec![3u8, 1, 2]).into_array()],
+            3,
+            Validity::from_iter([true, false, true]),
+        )
+        .unwrap();
+        let batch = StructArray::from_fields(&[("inner", inner.into_array())]).unwrap();
+
+        let rows = batch
+            .to_row_buffer(&[SortKey::new(0).with_descending(true)])
+            .unwrap();
+        // Nulls first, then descending.
+        let indices = rows.sort_indices().into_primitive().unwrap();
+        assert_eq!(indices.maybe_null_slice::<u64>(), [1, 0, 2]);
+
+        let decoded = StructArray::from_rows(&rows)
+            .unwrap()
+            .field(0)
+            .unwrap()
+            .into_struct()
+            .unwrap();
+        assert!(!decoded.is_valid(1));
+        assert_eq!(
+            decoded
+                .field(0)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u8>(),
+            [3, 1, 2]
+        );
+    }
+
+    #[test]
+    fn rows_nested_nulls_descending() {
+        let inner = StructArray::try_new(
+            ["x".into()].into(),
+            vec![PrimitiveArray::from_nullable_vec(vec![Some(1u8), None, Some(3)]).into_array()],
+            3,
+            Validity::AllValid,
+        )
+        .unwrap();
+        let batch = StructArray::from_fields(&[("inner", inner.into_array())]).unwrap();
+
+        for (nulls_first, expected) in [(true, [1, 2, 0]), (false, [2, 0, 1])] {
+            let rows = batch
+                .to_row_buffer(&[SortKey::new(0)
+                    .with_descending(true)
+                    .with_nulls_first(nulls_first)])
+                .unwrap();
+            // Null fields of nested structs order like nulls of top-level fields.
+            let indices = rows.sort_indices().into_primitive().unwrap();
+            assert_eq!(indices.maybe_null_slice::<u64>(), expected);
+
+            let x = StructArray::from_rows(&rows)
+                .unwrap()
+                .field(0)
+                .unwrap()
+                .into_struct()
+                .unwrap()
+                .field(0)
+                .unwrap()
+                .into_primitive()
+                .unwrap();
+            assert!(!x.is_valid(1));
+            assert_eq!(x.maybe_null_slice::<u8>()[0], 1);
+            assert_eq!(x.maybe_null_slice::<u8>()[2], 3);
+        }
+    }
+
+    #[test]
+    fn rows_sort_by_keys() {
+        let batch = batch();
+        let rows = batch
+            .to_row_buffer(&[
+                SortKey::new("name").with_nulls_first(false),
+                SortKey::new("score").with_descending(true),
+            ])
+            .unwrap();
+        // "a" < "a\0" < "b", ties on "b" broken by the descending score, and nulls last.
+        let indices = rows.sort_indices().into_primitive().unwrap();
+        assert_eq!(indices.maybe_null_slice::<u64>(), [3, 2, 0, 4, 1]);
+
+        let sorted = take(batch.as_ref(), indices.as_ref())
+            .unwrap()
+            .into_struct()
+            .unwrap();
+        assert_eq!(
+            sorted
+                .field_by_name("id")
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            [i32::MIN, 0, -2, i32::MAX, 7]
+        );
+
+        let decoded = StructArray::from_rows(&rows).unwrap();
+        assert_eq!(decoded.names().len(), 2);
+        assert_eq!(
+            decoded
+                .field(1)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<f64>(),
+            [-1.5, 2.0, 0.0, 0.0, -3.0]
+        );
+    }
+}
@@ -12,7 +12,10 @@ impl ValidityVTable<VarBinArray> for VarBinEncoding {
     }
 
     fn logical_validity(&self, array: &VarBinArray) -> LogicalValidity {
-        array.validity().to_logical(array.len())
+        array
+            .metadata()
+            .validity
+            .to_logical(array.len(), || array.validity())
     }
 }
 
@@ -36,6 +36,19 @@ impl<O: NativePType + PrimInt> VarBinBuilder<O> {
         }
     }
 
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of bytes of offsets and data pushed so far.
+    pub fn nbytes(&self) -> usize {
+        self.offsets.len() * size_of::<O>() + self.data.len()
+    }
+
     #[inline]
     pub fn push(&mut self, value: Option<&[u8]>) {
         match value {
@@ -17,7 +17,8 @@ impl CompareFn<VarBinArray> for VarBinEncoding {
         operator: Operator,
     ) -> VortexResult<Option<ArrayData>> {
         if let Some(rhs_const) = rhs.as_constant() {
-            let nullable = lhs.dtype().is_nullable() || rhs_const.dtype().is_nullable();
+            let nullable = (lhs.dtype().is_nullable() || rhs_const.dtype().is_nullable())
+                && !operator.is_null_safe();
 
             let lhs = Datum::try_from(lhs.clone().into_array())?;
 
@@ -46,6 +47,8 @@ impl CompareFn<VarBinArray> for VarBinEncoding {
                 Operator::Gte => cmp::gt_eq(&lhs, arrow_rhs)?,
                 Operator::Lt => cmp::lt(&lhs, arrow_rhs)?,
                 Operator::Lte => cmp::lt_eq(&lhs, arrow_rhs)?,
+                Operator::EqNullSafe => cmp::not_distinct(&lhs, arrow_rhs)?,
+                Operator::NotEqNullSafe => cmp::distinct(&lhs, arrow_rhs)?,
             };
 
             Ok(Some(ArrayData::from_arrow(&array, nullable)))
@@ -6,8 +6,8 @@ use vortex_error::{vortex_err, vortex_panic, VortexResult};
 use crate::array::varbin::builder::VarBinBuilder;
 use crate::array::varbin::VarBinArray;
 use crate::array::VarBinEncoding;
-use crate::compute::TakeFn;
-use crate::validity::Validity;
+use crate::compute::{TakeFn, TakeOptions};
+use crate::validity::{ArrayValidity, Validity};
 use crate::variants::PrimitiveArrayTrait;
 use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
 
@@ -28,6 +28,35 @@ impl TakeFn<VarBinArray> for VarBinEncoding {
             })
         })
     }
+
+    fn take_with_options(
+        &self,
+        array: &VarBinArray,
+        indices: &ArrayData,
+        options: TakeOptions,
+    ) -> VortexResult<Option<ArrayData>> {
+        if !options.null_on_oob {
+            return self.take(array, indices).map(Some);
+        }
+
+        let offsets = array.offsets().into_primitive()?;
+        let data = array.bytes().into_primitive()?;
+        let indices = indices.clone().into_primitive()?;
+        let index_nulls = indices.logical_validity().to_null_buffer()?;
+        let nulls = array.logical_validity().to_null_buffer()?;
+        match_each_integer_ptype!(offsets.ptype(), |$O| {
+            match_each_integer_ptype!(indices.ptype(), |$I| {
+                Ok(Some(take_null_on_oob(
+                    array.dtype().as_nullable(),
+                    offsets.maybe_null_slice::<$O>(),
+                    data.maybe_null_slice::<u8>(),
+                    indices.maybe_null_slice::<$I>(),
+                    index_nulls.as_ref(),
+                    nulls.as_ref(),
+                )?.into_array()))
+            })
+        })
+    }
 }
 
 fn take<I: NativePType, O: NativePType + PrimInt>(
@@ -84,3 +113,71 @@ fn take_nullable<I: NativePType, O: NativePType + PrimInt>(
     }
     builder.finish(dtype)
 }
+
+/// Take with a null for each null or out of bounds index, or index of a null value.
+fn take_null_on_oob<I: NativePType, O: NativePType + PrimInt>(
+    dtype: DType,
+    offsets: &[O],
+    data: &[u8],
+    indices: &[I],
+    index_nulls: Option<&NullBuffer>,
+    nulls: Option<&NullBuffer>,
+) -> VortexResult<VarBinArray> {
+    let len = offsets.len() - 1;
+    let mut builder = VarBinBuilder::<O>::with_capacity(indices.len());
+    for (i, idx) in indices.iter().enumerate() {
+        let idx = idx.to_usize().filter(|&idx| {
+            idx < len
+                && index_nulls.map_or(true, |n| n.is_valid(i))
+                && nulls.map_or(true, |n| n.is_valid(idx))
+        });
+        let Some(idx) = idx else {
+            builder.push(None);
+            continue;
+        };
+        let start = offsets[idx]
+            .to_usize()
+            .ok_or_else(|| vortex_err!("Failed to convert offset to usize: {}", offsets[idx]))?;
+        let stop = offsets[idx + 1].to_usize().ok_or_else(|| {
+            vortex_err!("Failed to convert offset to usize: {}", offsets[idx + 1])
+        })?;
+        builder.push(Some(&data[start..stop]));
+    }
+    Ok(builder.finish(dtype))
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{PrimitiveArray, VarBinArray};
+    use crate::compute::{scalar_at, take_with_options, TakeOptions};
+    use crate::ArrayDType;
+
+    #[test]
+    fn take_null_on_oob() {
+        let array = VarBinArray::from(vec!["a", "bc", "def"]);
+        let indices = PrimitiveArray::from_nullable_vec(vec![Some(2u16), Some(3), None, Some(0)]);
+        let taken = take_with_options(
+            &array,
+            &indices,
+            TakeOptions {
+                null_on_oob: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(taken.dtype(), &DType::Utf8(Nullability::Nullable));
+        assert_eq!(
+            scalar_at(&taken, 0).unwrap(),
+            Scalar::utf8("def", Nullability::Nullable)
+        );
+        assert!(scalar_at(&taken, 1).unwrap().is_null());
+        assert!(scalar_at(&taken, 2).unwrap().is_null());
+        assert_eq!(
+            scalar_at(&taken, 3).unwrap(),
+            Scalar::utf8("a", Nullability::Nullable)
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
 use num_traits::{AsPrimitive, PrimInt};
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 pub use stats::compute_varbin_statistics;
 use vortex_buffer::Buffer;
 use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability, PType};
@@ -15,6 +16,10 @@ use crate::array::primitive::PrimitiveArray;
 use crate::array::varbin::builder::VarBinBuilder;
 use crate::compute::{scalar_at, slice};
 use crate::encoding::ids;
+use crate::metadata::{
+    flexbuffers_root, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
+};
 use crate::stats::StatsSet;
 use crate::validity::{Validity, ValidityMetadata};
 use crate::variants::PrimitiveArrayTrait;
@@ -31,7 +36,10 @@ mod variants;
 
 impl_encoding!("vortex.varbin", ids::VAR_BIN, VarBin);
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the validity, the ptype of the offsets and the
+/// length of the bytes as a u64.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct VarBinMetadata {
     validity: ValidityMetadata,
     offsets_ptype: PType,
@@ -44,6 +52,36 @@ impl Display for VarBinMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for VarBinMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        self.validity.write_to(&mut writer);
+        writer
+            .put_ptype(self.offsets_ptype)
+            .put_usize(self.bytes_len);
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for VarBinMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let validity = ValidityMetadata::read_from(&mut reader)?;
+        let offsets_ptype = reader.get_ptype()?;
+        let bytes_len = reader.get_usize()?;
+        reader.finish()?;
+        Ok(Self {
+            validity,
+            offsets_ptype,
+            bytes_len,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 impl VarBinArray {
     pub fn try_new(
         offsets: ArrayData,
@@ -9,7 +9,7 @@ use crate::accessor::ArrayAccessor;
 use crate::array::varbin::VarBinArray;
 use crate::array::VarBinEncoding;
 use crate::compute::scalar_at;
-use crate::stats::{Stat, StatisticsVTable, StatsSet};
+use crate::stats::{HyperLogLog, Stat, StatisticsVTable, StatsSet};
 use crate::ArrayTrait;
 
 impl StatisticsVTable<VarBinArray> for VarBinEncoding {
@@ -31,6 +31,8 @@ pub fn compute_varbin_statistics<T: ArrayTrait + ArrayAccessor<[u8]>>(
         || stat == Stat::RunCount
         || stat == Stat::BitWidthFreq
         || stat == Stat::TrailingZeroFreq
+        || stat == Stat::Histogram
+        || stat == Stat::HasNaN
     {
         return Ok(StatsSet::default());
     }
@@ -78,11 +80,21 @@ pub fn compute_varbin_statistics<T: ArrayTrait + ArrayAccessor<[u8]>>(
             }
             stats
         }
+        Stat::ApproxDistinct => {
+            let hll = array.with_iterator(|iter| {
+                let mut hll = HyperLogLog::new();
+                iter.flatten().for_each(|value| hll.insert(value));
+                hll
+            })?;
+            StatsSet::of(Stat::ApproxDistinct, hll)
+        }
         Stat::UncompressedSizeInBytes
         | Stat::TrueCount
         | Stat::RunCount
         | Stat::BitWidthFreq
-        | Stat::TrailingZeroFreq => {
+        | Stat::TrailingZeroFreq
+        | Stat::Histogram
+        | Stat::HasNaN => {
             vortex_panic!(
                 "Unreachable, stat {} should have already been handled",
                 stat
@@ -222,4 +234,13 @@ mod test {
         assert!(array.statistics().get(Stat::Min).is_none());
         assert!(array.statistics().get(Stat::Max).is_none());
     }
+
+    #[test]
+    fn approx_distinct() {
+        let array = VarBinArray::from_iter(
+            vec![Some("a"), None, Some("b"), Some("a"), None, Some("c")],
+            DType::Utf8(Nullability::Nullable),
+        );
+        assert_eq!(array.statistics().compute_approx_distinct(), Some(3));
+    }
 }
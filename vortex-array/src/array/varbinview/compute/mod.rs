@@ -11,12 +11,16 @@ use vortex_scalar::Scalar;
 use crate::array::varbin::varbin_scalar;
 use crate::array::varbinview::{VarBinViewArray, VIEW_SIZE_BYTES};
 use crate::array::{PrimitiveArray, VarBinViewEncoding};
-use crate::compute::{slice, ComputeVTable, ScalarAtFn, SliceFn, TakeFn};
+use crate::compute::{slice, ComputeVTable, FilterFn, FilterMask, ScalarAtFn, SliceFn, TakeFn};
 use crate::validity::Validity;
 use crate::variants::PrimitiveArrayTrait;
 use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
 
 impl ComputeVTable for VarBinViewEncoding {
+    fn filter_fn(&self) -> Option<&dyn FilterFn<ArrayData>> {
+        Some(self)
+    }
+
     fn scalar_at_fn(&self) -> Option<&dyn ScalarAtFn<ArrayData>> {
         Some(self)
     }
@@ -57,6 +61,9 @@ impl SliceFn<VarBinViewArray> for VarBinViewEncoding {
 }
 
 /// Take involves creating a new array that references the old array, just with the given set of views.
+///
+/// The data buffers are shared, unless compacting them is worthwhile, see
+/// [VIEW_COMPACTION_THRESHOLD][crate::array::VIEW_COMPACTION_THRESHOLD].
 impl TakeFn<VarBinViewArray> for VarBinViewEncoding {
     fn take(&self, array: &VarBinViewArray, indices: &ArrayData) -> VortexResult<ArrayData> {
         // Compute the new validity
@@ -72,20 +79,7 @@ impl TakeFn<VarBinViewArray> for VarBinViewEncoding {
             take_views(views_buffer, indices.maybe_null_slice::<$I>())
         });
 
-        // Cast views back to u8
-        let views_array = PrimitiveArray::new(
-            views_buffer.into_inner().into(),
-            PType::U8,
-            Validity::NonNullable,
-        );
-
-        Ok(VarBinViewArray::try_new(
-            views_array.into_array(),
-            array.buffers().collect_vec(),
-            array.dtype().clone(),
-            validity,
-        )?
-        .into_array())
+        with_views(array, views_buffer, validity)
     }
 
     unsafe fn take_unchecked(
@@ -106,23 +100,49 @@ impl TakeFn<VarBinViewArray> for VarBinViewEncoding {
             take_views_unchecked(views_buffer, indices.maybe_null_slice::<$I>())
         });
 
-        // Cast views back to u8
-        let views_array = PrimitiveArray::new(
-            views_buffer.into_inner().into(),
-            PType::U8,
-            Validity::NonNullable,
-        );
+        with_views(array, views_buffer, validity)
+    }
 
-        Ok(VarBinViewArray::try_new(
-            views_array.into_array(),
-            array.buffers().collect_vec(),
-            array.dtype().clone(),
-            validity,
-        )?
-        .into_array())
+    fn has_take_unchecked(&self) -> bool {
+        true
+    }
+}
+
+/// Like take, filtering only copies the views and shares the data buffers.
+impl FilterFn<VarBinViewArray> for VarBinViewEncoding {
+    fn filter(&self, array: &VarBinViewArray, mask: FilterMask) -> VortexResult<ArrayData> {
+        let validity = array.validity().filter(&mask)?;
+
+        let views_buffer =
+            ScalarBuffer::<u128>::from(array.views().into_primitive()?.into_buffer().into_arrow());
+        let views_ref = views_buffer.deref();
+        let views = ScalarBuffer::<u128>::from_iter(mask.indices()?.iter().map(|&i| views_ref[i]));
+
+        with_views(array, views, validity)
     }
 }
 
+/// An array of the given views into the data buffers of `array`, compacted if the views only
+/// reference a small part of them.
+fn with_views(
+    array: &VarBinViewArray,
+    views: ScalarBuffer<u128>,
+    validity: Validity,
+) -> VortexResult<ArrayData> {
+    // Cast views back to u8
+    let views_array =
+        PrimitiveArray::new(views.into_inner().into(), PType::U8, Validity::NonNullable);
+
+    Ok(VarBinViewArray::try_new(
+        views_array.into_array(),
+        array.buffers().collect_vec(),
+        array.dtype().clone(),
+        validity,
+    )?
+    .maybe_compact()?
+    .into_array())
+}
+
 fn take_views<I: AsPrimitive<usize>>(
     views: ScalarBuffer<u128>,
     indices: &[I],
@@ -149,8 +169,65 @@ fn take_views_unchecked<I: AsPrimitive<usize>>(
 mod tests {
     use crate::accessor::ArrayAccessor;
     use crate::array::{PrimitiveArray, VarBinViewArray};
-    use crate::compute::take;
-    use crate::{ArrayDType, IntoArrayData, IntoArrayVariant};
+    use crate::compute::{filter, take, FilterMask};
+    use crate::{ArrayDType, ArrayLen, IntoArrayData, IntoArrayVariant};
+
+    fn long_strings(n: usize) -> VarBinViewArray {
+        VarBinViewArray::from_iter_str((0..n).map(|i| format!("a string longer than 12 bytes {i}")))
+    }
+
+    fn buffer_bytes(array: &VarBinViewArray) -> usize {
+        array.buffers().map(|b| b.len()).sum()
+    }
+
+    #[test]
+    fn take_shares_or_compacts_buffers() {
+        let arr = long_strings(100);
+        let bytes = buffer_bytes(&arr);
+
+        let most = take(
+            arr.as_ref(),
+            PrimitiveArray::from((0..90u32).collect::<Vec<_>>()).into_array(),
+        )
+        .unwrap()
+        .into_varbinview()
+        .unwrap();
+        assert_eq!(buffer_bytes(&most), bytes);
+
+        let few = take(
+            arr.as_ref(),
+            PrimitiveArray::from(vec![3u32, 97]).into_array(),
+        )
+        .unwrap()
+        .into_varbinview()
+        .unwrap();
+        assert_eq!(buffer_bytes(&few), few.referenced_bytes().unwrap());
+        assert_eq!(few.bytes_at(1).unwrap(), arr.bytes_at(97).unwrap());
+    }
+
+    #[test]
+    fn filter_shares_or_compacts_buffers() {
+        let arr = long_strings(100);
+        let bytes = buffer_bytes(&arr);
+
+        let most = filter(
+            arr.as_ref(),
+            FilterMask::from_iter((0..100).map(|i| i % 10 != 0)),
+        )
+        .unwrap()
+        .into_varbinview()
+        .unwrap();
+        assert_eq!(most.len(), 90);
+        assert_eq!(buffer_bytes(&most), bytes);
+        assert_eq!(most.bytes_at(0).unwrap(), arr.bytes_at(1).unwrap());
+
+        let few = filter(arr.as_ref(), FilterMask::from_indices(100, [5usize, 50]))
+            .unwrap()
+            .into_varbinview()
+            .unwrap();
+        assert!(buffer_bytes(&few) < bytes / 10);
+        assert_eq!(few.bytes_at(1).unwrap(), arr.bytes_at(50).unwrap());
+    }
 
     #[test]
     fn take_nullable() {
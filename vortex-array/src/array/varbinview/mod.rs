@@ -2,12 +2,13 @@ use std::fmt::{Debug, Display, Formatter};
 use std::ops::Range;
 use std::sync::Arc;
 
-use ::serde::{Deserialize, Serialize};
 use arrow_array::builder::{BinaryViewBuilder, GenericByteViewBuilder, StringViewBuilder};
+use arrow_array::cast::AsArray;
 use arrow_array::types::{BinaryViewType, ByteViewType, StringViewType};
 use arrow_array::{ArrayRef, BinaryViewArray, GenericByteViewArray, StringViewArray};
 use arrow_buffer::ScalarBuffer;
 use itertools::Itertools;
+use serde::Deserialize;
 use static_assertions::{assert_eq_align, assert_eq_size};
 use vortex_buffer::Buffer;
 use vortex_dtype::{DType, PType};
@@ -16,8 +17,13 @@ use vortex_error::{
 };
 
 use crate::arrow::FromArrowArray;
+use crate::builders::{ArrayBuilder, BinaryBuilder, Utf8Builder};
 use crate::compute::slice;
 use crate::encoding::ids;
+use crate::metadata::{
+    flexbuffers_root, MetadataReader, MetadataWriter, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
+};
 use crate::stats::StatsSet;
 use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata, ValidityVTable};
 use crate::variants::PrimitiveArrayTrait;
@@ -194,7 +200,10 @@ impl Debug for BinaryView {
 // reminder: views are 16 bytes with 8-byte alignment
 pub(crate) const VIEW_SIZE_BYTES: usize = size_of::<BinaryView>();
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized in a fixed layout, whose version 0 is the validity, the number of buffers as a u64
+/// and the length of each buffer as a u32.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(remote = "Self")]
 pub struct VarBinViewMetadata {
     // Validity metadata
     validity: ValidityMetadata,
@@ -210,6 +219,38 @@ impl Display for VarBinViewMetadata {
     }
 }
 
+impl TrySerializeArrayMetadata for VarBinViewMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        let mut writer = MetadataWriter::new(0);
+        self.validity.write_to(&mut writer);
+        writer.put_usize(self.buffer_lens.len());
+        for &len in &self.buffer_lens {
+            writer.put_u32(len);
+        }
+        Ok(writer.finish())
+    }
+}
+
+impl TryDeserializeArrayMetadata<'_> for VarBinViewMetadata {
+    fn try_deserialize_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        let mut reader = MetadataReader::try_new(metadata, 0)?;
+        let validity = ValidityMetadata::read_from(&mut reader)?;
+        let nbuffers = reader.get_usize()?;
+        let buffer_lens = (0..nbuffers)
+            .map(|_| reader.get_u32())
+            .collect::<VortexResult<Vec<_>>>()?;
+        reader.finish()?;
+        Ok(Self {
+            validity,
+            buffer_lens,
+        })
+    }
+
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&[u8]>) -> VortexResult<Self> {
+        Ok(Self::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
 pub struct Buffers<'a> {
     index: u32,
     n_buffers: u32,
@@ -232,6 +273,14 @@ impl Iterator for Buffers<'_> {
 
 impl_encoding!("vortex.varbinview", ids::VAR_BIN_VIEW, VarBinView);
 
+/// The fraction of the data buffer bytes below which views are compacted.
+///
+/// Take and filter share the data buffers of their input, unless the values they keep reference
+/// less than this fraction of the buffer bytes. Then the referenced bytes are copied into new
+/// buffers instead, so that a small selection doesn't hold on to large buffers. Canonicalization
+/// compacts arrays by the same rule.
+pub const VIEW_COMPACTION_THRESHOLD: f64 = 0.25;
+
 impl VarBinViewArray {
     pub fn try_new(
         views: ArrayData,
@@ -382,6 +431,48 @@ impl VarBinViewArray {
         })
     }
 
+    /// The number of bytes of the data buffers referenced by the views that aren't inlined.
+    ///
+    /// Bytes referenced by more than one view are counted once per view.
+    pub fn referenced_bytes(&self) -> VortexResult<usize> {
+        Ok(self
+            .binary_views()?
+            .filter(|view| !view.is_inlined())
+            .map(|view| view.len() as usize)
+            .sum())
+    }
+
+    /// Copy the bytes referenced by the views into new data buffers, dropping any bytes that no
+    /// view references.
+    pub fn compact(&self) -> VortexResult<Self> {
+        let arrow = varbinview_as_arrow(self);
+        let compacted: ArrayRef = match self.dtype() {
+            DType::Binary(_) => Arc::new(arrow.as_binary_view().gc()),
+            _ => Arc::new(arrow.as_string_view().gc()),
+        };
+        VarBinViewArray::try_from(ArrayData::from_arrow(compacted, self.dtype().is_nullable()))
+    }
+
+    /// Compact the array if its views reference less than [VIEW_COMPACTION_THRESHOLD] of the
+    /// bytes of its data buffers.
+    pub(crate) fn maybe_compact(self) -> VortexResult<Self> {
+        let buffer_bytes = self
+            .metadata()
+            .buffer_lens
+            .iter()
+            .map(|&len| len as usize)
+            .sum::<usize>();
+        if buffer_bytes == 0 {
+            return Ok(self);
+        }
+        let referenced = self.referenced_bytes()? as f64 / buffer_bytes as f64;
+        if referenced < VIEW_COMPACTION_THRESHOLD {
+            self.compact()
+        } else {
+            Ok(self)
+        }
+    }
+
     /// Accumulate an iterable set of values into our type here.
     #[allow(clippy::same_name_method)]
     pub fn from_iter<T: AsRef<[u8]>, I: IntoIterator<Item = Option<T>>>(
@@ -537,9 +628,21 @@ impl IntoCanonical for VarBinViewArray {
         let arrow_self = varbinview_as_arrow(&self);
         let vortex_array = ArrayData::from_arrow(arrow_self, nullable);
 
-        Ok(Canonical::VarBinView(VarBinViewArray::try_from(
-            vortex_array,
-        )?))
+        Ok(Canonical::VarBinView(
+            VarBinViewArray::try_from(vortex_array)?.maybe_compact()?,
+        ))
+    }
+
+    fn canonicalize_into(self, builder: &mut dyn ArrayBuilder) -> VortexResult<()> {
+        // The views builders copy just the bytes our views reference, which saves the round trip
+        // through Arrow that canonicalizing first would make.
+        if let Some(utf8) = builder.as_any_mut().downcast_mut::<Utf8Builder>() {
+            return utf8.extend_from_varbinview(&self);
+        }
+        if let Some(binary) = builder.as_any_mut().downcast_mut::<BinaryBuilder>() {
+            return binary.extend_from_varbinview(&self);
+        }
+        builder.extend_from_array(self.as_ref())
     }
 }
 
@@ -570,22 +673,20 @@ pub(crate) fn varbinview_as_arrow(var_bin_view: &VarBinViewArray) -> ArrayRef {
         .map(|p| p.buffer().clone().into_arrow())
         .collect::<Vec<_>>();
 
+    // Arrow requires the views to be aligned to u128, which only copies views that were, e.g.,
+    // read from an unaligned IPC buffer. The data buffers are shared as they are.
+    let views = ScalarBuffer::<u128>::from(
+        views
+            .buffer()
+            .clone()
+            .aligned(align_of::<u128>())
+            .into_arrow(),
+    );
+
     // Switch on Arrow DType.
     match var_bin_view.dtype() {
-        DType::Binary(_) => Arc::new(unsafe {
-            BinaryViewArray::new_unchecked(
-                ScalarBuffer::<u128>::from(views.buffer().clone().into_arrow()),
-                data,
-                nulls,
-            )
-        }),
-        DType::Utf8(_) => Arc::new(unsafe {
-            StringViewArray::new_unchecked(
-                ScalarBuffer::<u128>::from(views.buffer().clone().into_arrow()),
-                data,
-                nulls,
-            )
-        }),
+        DType::Binary(_) => Arc::new(unsafe { BinaryViewArray::new_unchecked(views, data, nulls) }),
+        DType::Utf8(_) => Arc::new(unsafe { StringViewArray::new_unchecked(views, data, nulls) }),
         _ => vortex_panic!("expected utf8 or binary, got {}", var_bin_view.dtype()),
     }
 }
@@ -596,7 +697,10 @@ impl ValidityVTable<VarBinViewArray> for VarBinViewEncoding {
     }
 
     fn logical_validity(&self, array: &VarBinViewArray) -> LogicalValidity {
-        array.validity().to_logical(array.len())
+        array
+            .metadata()
+            .validity
+            .to_logical(array.len(), || array.validity())
     }
 }
 
@@ -684,6 +788,26 @@ mod test {
         assert_eq!(scalar_at(&var_bin, 1).unwrap(), Scalar::from("string2"));
     }
 
+    #[test]
+    pub fn compact_filtered_array() {
+        let arr = VarBinViewArray::from_iter_str(
+            (0..100).map(|i| format!("a string that is too long to be inlined {i}")),
+        );
+        let buffer_bytes = |a: &VarBinViewArray| a.buffers().map(|b| b.len()).sum::<usize>();
+
+        let few = VarBinViewArray::try_from(slice(&arr, 96, 98).unwrap()).unwrap();
+        assert_eq!(buffer_bytes(&few), buffer_bytes(&arr));
+
+        let compacted = few
+            .clone()
+            .into_canonical()
+            .unwrap()
+            .into_varbinview()
+            .unwrap();
+        assert_eq!(buffer_bytes(&compacted), few.referenced_bytes().unwrap());
+        assert_eq!(compacted.bytes_at(1).unwrap(), arr.bytes_at(97).unwrap());
+    }
+
     #[test]
     pub fn binary_view_size_and_alignment() {
         assert_eq!(size_of::<BinaryView>(), VIEW_SIZE_BYTES);
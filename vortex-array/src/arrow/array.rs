@@ -5,25 +5,28 @@ use arrow_array::array::{
 };
 use arrow_array::cast::{as_null_array, AsArray};
 use arrow_array::types::{
-    ByteArrayType, ByteViewType, Date32Type, Date64Type, DurationMicrosecondType,
-    DurationMillisecondType, DurationNanosecondType, DurationSecondType, Float16Type, Float32Type,
-    Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, Time32MillisecondType,
-    Time32SecondType, Time64MicrosecondType, Time64NanosecondType, TimestampMicrosecondType,
-    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type,
-    UInt64Type, UInt8Type,
+    ByteArrayType, ByteViewType, Date32Type, Date64Type, Decimal128Type, Decimal256Type,
+    DecimalType, DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType,
+    DurationSecondType, Float16Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type,
+    Int8Type, Time32MillisecondType, Time32SecondType, Time64MicrosecondType, Time64NanosecondType,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow_array::{
+    BinaryViewArray, FixedSizeListArray as ArrowFixedSizeListArray, GenericByteViewArray,
+    GenericListArray, StringViewArray,
 };
-use arrow_array::{BinaryViewArray, GenericByteViewArray, GenericListArray, StringViewArray};
 use arrow_buffer::buffer::{NullBuffer, OffsetBuffer};
 use arrow_buffer::{ArrowNativeType, BooleanBuffer, Buffer, ScalarBuffer};
 use arrow_schema::{DataType, TimeUnit as ArrowTimeUnit};
 use itertools::Itertools;
 use vortex_datetime_dtype::TimeUnit;
-use vortex_dtype::{DType, NativePType, Nullability, PType};
+use vortex_dtype::{DType, DecimalDType, DecimalValueType, NativePType, Nullability, PType};
 use vortex_error::{vortex_panic, VortexExpect as _};
 
 use crate::array::{
-    BoolArray, ListArray, NullArray, PrimitiveArray, StructArray, TemporalArray, VarBinArray,
-    VarBinViewArray,
+    BoolArray, DecimalArray, FixedSizeListArray, ListArray, NullArray, PrimitiveArray, StructArray,
+    TemporalArray, VarBinArray, VarBinViewArray,
 };
 use crate::arrow::FromArrowArray;
 use crate::stats::{ArrayStatistics, Stat};
@@ -191,6 +194,41 @@ impl<O: OffsetSizeTrait + NativePType> FromArrowArray<&GenericListArray<O>> for
     }
 }
 
+impl FromArrowArray<&ArrowFixedSizeListArray> for ArrayData {
+    fn from_arrow(value: &ArrowFixedSizeListArray, nullable: bool) -> Self {
+        FixedSizeListArray::try_with_len(
+            Self::from_arrow(value.values().clone(), value.values().is_nullable()),
+            u32::try_from(value.value_length())
+                .vortex_expect("Arrow list size must not be negative"),
+            value.len(),
+            nulls(value.nulls(), nullable),
+        )
+        .vortex_expect("Failed to convert Arrow FixedSizeListArray to Vortex FixedSizeListArray")
+        .into_array()
+    }
+}
+
+/// Decimal arrays can't share the generic primitive conversion, as their values aren't native
+/// Vortex primitives. A `Decimal256` array keeps its 256-bit values even if its precision would fit
+/// in 128 bits, so that it converts back to the same Arrow type.
+fn decimal_from_arrow<T: DecimalType>(value: &ArrowPrimitiveArray<T>, nullable: bool) -> ArrayData {
+    let value_type = if T::BYTE_LENGTH == DecimalValueType::I256.byte_width() {
+        DecimalValueType::I256
+    } else {
+        DecimalValueType::I128
+    };
+    let decimal_dtype = DecimalDType::try_new(value.precision(), value.scale())
+        .and_then(|d| d.with_value_type(value_type))
+        .vortex_expect("Invalid Arrow decimal precision and scale");
+    DecimalArray::try_new(
+        value.values().clone().into_inner().into(),
+        decimal_dtype,
+        nulls(value.nulls(), nullable),
+    )
+    .vortex_expect("Failed to convert Arrow decimal array to Vortex DecimalArray")
+    .into_array()
+}
+
 impl FromArrowArray<&ArrowNullArray> for ArrayData {
     fn from_arrow(value: &ArrowNullArray, nullable: bool) -> Self {
         assert!(nullable);
@@ -230,6 +268,12 @@ impl FromArrowArray<ArrowArrayRef> for ArrayData {
             DataType::Float16 => Self::from_arrow(array.as_primitive::<Float16Type>(), nullable),
             DataType::Float32 => Self::from_arrow(array.as_primitive::<Float32Type>(), nullable),
             DataType::Float64 => Self::from_arrow(array.as_primitive::<Float64Type>(), nullable),
+            DataType::Decimal128(..) => {
+                decimal_from_arrow(array.as_primitive::<Decimal128Type>(), nullable)
+            }
+            DataType::Decimal256(..) => {
+                decimal_from_arrow(array.as_primitive::<Decimal256Type>(), nullable)
+            }
             DataType::Utf8 => Self::from_arrow(array.as_string::<i32>(), nullable),
             DataType::LargeUtf8 => Self::from_arrow(array.as_string::<i64>(), nullable),
             DataType::Binary => Self::from_arrow(array.as_binary::<i32>(), nullable),
@@ -251,6 +295,7 @@ impl FromArrowArray<ArrowArrayRef> for ArrayData {
             DataType::Struct(_) => Self::from_arrow(array.as_struct(), nullable),
             DataType::List(_) => Self::from_arrow(array.as_list::<i32>(), nullable),
             DataType::LargeList(_) => Self::from_arrow(array.as_list::<i64>(), nullable),
+            DataType::FixedSizeList(..) => Self::from_arrow(array.as_fixed_size_list(), nullable),
             DataType::Null => Self::from_arrow(as_null_array(&array), nullable),
             DataType::Timestamp(u, _) => match u {
                 ArrowTimeUnit::Second => {
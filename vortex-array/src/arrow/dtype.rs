@@ -16,8 +16,8 @@ use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaBuilder, Sch
 use itertools::Itertools;
 use vortex_datetime_dtype::arrow::{make_arrow_temporal_dtype, make_temporal_ext_dtype};
 use vortex_datetime_dtype::is_temporal_ext_type;
-use vortex_dtype::{DType, Nullability, PType, StructDType};
-use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_dtype::{DType, DecimalDType, DecimalValueType, Nullability, PType, StructDType};
+use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult};
 
 use crate::arrow::{FromArrowType, TryFromArrowType};
 
@@ -86,9 +86,25 @@ impl FromArrowType<&Field> for DType {
             | DataType::Timestamp(..) => Extension(Arc::new(
                 make_temporal_ext_dtype(field.data_type()).with_nullability(nullability),
             )),
+            DataType::Decimal128(precision, scale) => Decimal(
+                DecimalDType::try_new(*precision, *scale)
+                    .vortex_expect("Invalid Arrow decimal precision and scale"),
+                nullability,
+            ),
+            DataType::Decimal256(precision, scale) => Decimal(
+                DecimalDType::try_new(*precision, *scale)
+                    .and_then(|d| d.with_value_type(DecimalValueType::I256))
+                    .vortex_expect("Invalid Arrow decimal precision and scale"),
+                nullability,
+            ),
             DataType::List(e) | DataType::LargeList(e) => {
                 List(Arc::new(Self::from_arrow(e.as_ref())), nullability)
             }
+            DataType::FixedSizeList(e, size) => FixedSizeList(
+                Arc::new(Self::from_arrow(e.as_ref())),
+                u32::try_from(*size).vortex_expect("Arrow list size must not be negative"),
+                nullability,
+            ),
             DataType::Struct(f) => Struct(
                 StructDType::new(
                     f.iter()
@@ -150,6 +166,14 @@ pub fn infer_data_type(dtype: &DType) -> VortexResult<DataType> {
         },
         DType::Utf8(_) => DataType::Utf8View,
         DType::Binary(_) => DataType::BinaryView,
+        DType::Decimal(decimal_dtype, _) => match decimal_dtype.value_type() {
+            DecimalValueType::I128 => {
+                DataType::Decimal128(decimal_dtype.precision(), decimal_dtype.scale())
+            }
+            DecimalValueType::I256 => {
+                DataType::Decimal256(decimal_dtype.precision(), decimal_dtype.scale())
+            }
+        },
         DType::Struct(struct_dtype, _) => {
             let mut fields = Vec::with_capacity(struct_dtype.names().len());
             for (field_name, field_dt) in struct_dtype
@@ -173,6 +197,13 @@ pub fn infer_data_type(dtype: &DType) -> VortexResult<DataType> {
             infer_data_type(l.as_ref())?,
             (*null).into(),
         ))),
+        DType::FixedSizeList(e, size, _) => DataType::FixedSizeList(
+            FieldRef::new(Field::new_list_field(
+                infer_data_type(e.as_ref())?,
+                e.is_nullable(),
+            )),
+            i32::try_from(*size)?,
+        ),
         DType::Extension(ext_dtype) => {
             // Try and match against the known extension DTypes.
             if is_temporal_ext_type(ext_dtype.id()) {
@@ -217,6 +248,24 @@ mod test {
             DataType::BinaryView
         );
 
+        for (decimal, data_type) in [
+            (DecimalDType::new(38, 10), DataType::Decimal128(38, 10)),
+            (DecimalDType::new(39, -2), DataType::Decimal256(39, -2)),
+            (
+                DecimalDType::new(10, 2)
+                    .with_value_type(DecimalValueType::I256)
+                    .unwrap(),
+                DataType::Decimal256(10, 2),
+            ),
+        ] {
+            let dtype = DType::Decimal(decimal, Nullability::Nullable);
+            assert_eq!(infer_data_type(&dtype).unwrap(), data_type);
+            assert_eq!(
+                DType::from_arrow(&Field::new("decimal", data_type, true)),
+                dtype
+            );
+        }
+
         assert_eq!(
             infer_data_type(&DType::Struct(
                 StructDType::new(
@@ -231,6 +280,24 @@ mod test {
                 FieldRef::from(Field::new("field_b", DataType::Utf8View, true)),
             ]))
         );
+
+        let fixed_size_list = DType::FixedSizeList(
+            Arc::new(DType::Primitive(PType::F32, Nullability::NonNullable)),
+            4,
+            Nullability::Nullable,
+        );
+        let data_type = infer_data_type(&fixed_size_list).unwrap();
+        assert_eq!(
+            data_type,
+            DataType::FixedSizeList(
+                FieldRef::new(Field::new_list_field(DataType::Float32, false)),
+                4
+            )
+        );
+        assert_eq!(
+            DType::from_arrow(&Field::new("list", data_type, true)),
+            fixed_size_list
+        );
     }
 
     #[test]
@@ -1,35 +1,58 @@
 use std::any::Any;
 
-use arrow_array::builder::{ArrayBuilder as _, BinaryViewBuilder};
-use arrow_array::Array;
 use vortex_dtype::{DType, Nullability};
-use vortex_error::{vortex_bail, VortexResult};
+use vortex_error::VortexResult;
+use vortex_scalar::{BinaryScalar, Scalar};
 
-use crate::arrow::FromArrowArray;
-use crate::builders::ArrayBuilder;
-use crate::ArrayData;
+use crate::array::VarBinViewArray;
+use crate::builders::views::ViewsBuilder;
+use crate::builders::{check_dtype, ArrayBuilder, GrowthStrategy};
+use crate::{ArrayDType, ArrayData, IntoArrayVariant};
 
 pub struct BinaryBuilder {
-    inner: BinaryViewBuilder,
-    nullability: Nullability,
+    inner: ViewsBuilder,
     dtype: DType,
 }
 
 impl BinaryBuilder {
+    pub fn new(nullability: Nullability) -> Self {
+        Self::with_capacity(nullability, 1024) // Same as Arrow builders
+    }
+
     pub fn with_capacity(nullability: Nullability, capacity: usize) -> Self {
         Self {
-            inner: BinaryViewBuilder::with_capacity(capacity),
-            nullability,
+            inner: ViewsBuilder::with_capacity(capacity),
             dtype: DType::Binary(nullability),
         }
     }
 
+    /// Set how the builder grows its buffers once they run out of capacity.
+    pub fn with_growth_strategy(mut self, growth: GrowthStrategy) -> Self {
+        self.inner.set_growth_strategy(growth);
+        self
+    }
+
     pub fn append_value<S: AsRef<[u8]>>(&mut self, value: S) {
         self.inner.append_value(value.as_ref())
     }
 
     pub fn append_option<S: AsRef<[u8]>>(&mut self, value: Option<S>) {
-        self.inner.append_option(value.as_ref())
+        match value {
+            Some(value) => self.append_value(value),
+            None => self.append_null(),
+        }
+    }
+
+    /// Append the values of a [`VarBinViewArray`], copying only the bytes its views reference.
+    pub(crate) fn extend_from_varbinview(&mut self, array: &VarBinViewArray) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        self.inner.extend_from_varbinview(array)
+    }
+
+    pub fn append_values<S: AsRef<[u8]>, I: IntoIterator<Item = S>>(&mut self, values: I) {
+        for value in values {
+            self.append_value(value)
+        }
     }
 }
 
@@ -50,25 +73,74 @@ impl ArrayBuilder for BinaryBuilder {
         self.inner.len()
     }
 
+    fn nbytes(&self) -> usize {
+        self.inner.nbytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.inner.allocated_bytes()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
     fn append_zeros(&mut self, n: usize) {
-        for _ in 0..n {
-            self.inner.append_value([])
-        }
+        self.inner.append_values(&[], n)
     }
 
     fn append_nulls(&mut self, n: usize) {
-        for _ in 0..n {
-            self.inner.append_null()
-        }
+        self.inner.append_nulls(n)
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        check_dtype(self.dtype(), scalar.dtype())?;
+        self.append_option(BinaryScalar::try_from(scalar)?.value());
+        Ok(())
+    }
+
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        let varbinview = array.clone().into_varbinview()?;
+        self.inner.extend_from_varbinview(&varbinview)
     }
 
     fn finish(&mut self) -> VortexResult<ArrayData> {
-        let arrow = self.inner.finish();
+        self.inner.finish(self.dtype.clone())
+    }
+}
 
-        if !self.dtype().is_nullable() && arrow.null_count() > 0 {
-            vortex_bail!("Non-nullable builder has null values");
-        }
+#[cfg(test)]
+mod tests {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::builders::{ArrayBuilder, BinaryBuilder};
+    use crate::{ArrayDType, IntoArrayVariant};
+
+    #[test]
+    fn test_binary_values() {
+        let mut builder = BinaryBuilder::new(Nullability::Nullable);
+        builder.append_value(b"hello");
+        builder.append_option(None::<&[u8]>);
+        builder.append_value([0xffu8, 0x00, 0xfe]);
+        builder.append_zero();
+
+        let array = builder.finish().unwrap();
+        assert_eq!(array.dtype(), &DType::Binary(Nullability::Nullable));
+        assert_eq!(array.len(), 4);
+
+        let varbinview = array.into_varbinview().unwrap();
+        assert_eq!(varbinview.bytes_at(0).unwrap(), b"hello");
+        assert!(!varbinview.validity().is_valid(1));
+        assert_eq!(varbinview.bytes_at(2).unwrap(), vec![0xffu8, 0x00, 0xfe]);
+        assert!(varbinview.bytes_at(3).unwrap().is_empty());
+    }
 
-        Ok(ArrayData::from_arrow(&arrow, self.nullability.into()))
+    #[test]
+    fn test_non_nullable_with_nulls_fails() {
+        let mut builder = BinaryBuilder::new(Nullability::NonNullable);
+        builder.append_values([b"a".as_slice(), b"b".as_slice()]);
+        builder.append_null();
+        assert!(builder.finish().is_err());
     }
 }
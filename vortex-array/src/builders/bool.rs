@@ -1,18 +1,24 @@
 use std::any::Any;
 
-use arrow_array::builder::{ArrayBuilder as _, BooleanBuilder as ArrowBooleanBuilder};
-use arrow_array::Array;
+use arrow_buffer::{BooleanBufferBuilder, NullBufferBuilder};
 use vortex_dtype::{DType, Nullability};
-use vortex_error::{vortex_bail, VortexResult};
+use vortex_error::VortexResult;
+use vortex_scalar::{BoolScalar, Scalar};
 
-use crate::arrow::FromArrowArray;
-use crate::builders::ArrayBuilder;
-use crate::ArrayData;
+use crate::array::BoolArray;
+use crate::builders::{
+    check_dtype, finish_validity, validity_allocated_bytes, validity_nbytes, ArrayBuilder,
+    GrowthStrategy,
+};
+use crate::validity::{ArrayValidity, LogicalValidity};
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
 
 pub struct BoolBuilder {
-    inner: ArrowBooleanBuilder,
+    values: BooleanBufferBuilder,
+    validity: NullBufferBuilder,
     nullability: Nullability,
     dtype: DType,
+    growth: GrowthStrategy,
 }
 
 impl BoolBuilder {
@@ -22,18 +28,30 @@ impl BoolBuilder {
 
     pub fn with_capacity(nullability: Nullability, capacity: usize) -> Self {
         Self {
-            inner: ArrowBooleanBuilder::with_capacity(capacity),
+            values: BooleanBufferBuilder::new(capacity),
+            validity: NullBufferBuilder::new(capacity),
             nullability,
             dtype: DType::Bool(nullability),
+            growth: GrowthStrategy::default(),
         }
     }
 
+    /// Set how the builder grows its buffers once they run out of capacity.
+    pub fn with_growth_strategy(mut self, growth: GrowthStrategy) -> Self {
+        self.growth = growth;
+        self
+    }
+
     pub fn append_value(&mut self, value: bool) {
-        self.inner.append_value(value);
+        self.grow(1);
+        self.values.append(value);
+        self.validity.append_non_null();
     }
 
     pub fn append_values(&mut self, value: bool, n: usize) {
-        self.inner.append_n(n, value);
+        self.grow(n);
+        self.values.append_n(n, value);
+        self.validity.append_n_non_nulls(n);
     }
 
     pub fn append_option(&mut self, value: Option<bool>) {
@@ -42,6 +60,14 @@ impl BoolBuilder {
             None => self.append_null(),
         }
     }
+
+    fn grow(&mut self, additional: usize) {
+        let len = self.values.len();
+        if self.values.capacity() - len < additional {
+            self.values
+                .reserve(self.growth.reserve_count(len, additional));
+        }
+    }
 }
 
 impl ArrayBuilder for BoolBuilder {
@@ -58,24 +84,96 @@ impl ArrayBuilder for BoolBuilder {
     }
 
     fn len(&self) -> usize {
-        self.inner.len()
+        self.values.len()
+    }
+
+    fn nbytes(&self) -> usize {
+        self.values.len().div_ceil(8) + validity_nbytes(&self.validity)
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.values.capacity().div_ceil(8) + validity_allocated_bytes(&self.validity)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
     }
 
     fn append_zeros(&mut self, n: usize) {
-        self.inner.append_n(n, false);
+        self.append_values(false, n);
     }
 
     fn append_nulls(&mut self, n: usize) {
-        self.inner.append_nulls(n);
+        self.grow(n);
+        self.values.append_n(n, false);
+        self.validity.append_n_nulls(n);
     }
 
-    fn finish(&mut self) -> VortexResult<ArrayData> {
-        let arrow = self.inner.finish();
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        check_dtype(self.dtype(), scalar.dtype())?;
+        self.append_option(BoolScalar::try_from(scalar)?.value());
+        Ok(())
+    }
 
-        if !self.dtype().is_nullable() && arrow.null_count() > 0 {
-            vortex_bail!("Non-nullable builder has null values");
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        let bools = array.clone().into_bool()?;
+        self.grow(array.len());
+        self.values.append_buffer(&bools.boolean_buffer());
+        match bools.logical_validity() {
+            LogicalValidity::AllValid(n) => self.validity.append_n_non_nulls(n),
+            LogicalValidity::AllInvalid(n) => self.validity.append_n_nulls(n),
+            LogicalValidity::Array(validity) => validity
+                .into_bool()?
+                .boolean_buffer()
+                .iter()
+                .for_each(|valid| self.validity.append(valid)),
         }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> VortexResult<ArrayData> {
+        let validity = finish_validity(&mut self.validity, self.nullability)?;
+        BoolArray::try_new(self.values.finish(), validity).map(IntoArrayData::into_array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_dtype::Nullability;
+
+    use crate::builders::{ArrayBuilder, BoolBuilder};
+    use crate::IntoArrayVariant;
+
+    #[test]
+    fn test_bool_builder() {
+        let mut builder = BoolBuilder::new(Nullability::Nullable);
+        builder.append_value(true);
+        builder.append_null();
+        builder.append_values(false, 2);
+
+        let bools = builder.finish().unwrap().into_bool().unwrap();
+        assert_eq!(
+            bools.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, false, false]
+        );
+        assert!(!bools.validity().is_valid(1));
+        assert!(bools.validity().is_valid(2));
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut builder = BoolBuilder::with_capacity(Nullability::NonNullable, 0);
+        builder.reserve(1000);
+        assert!(builder.allocated_bytes() >= 1000 / 8);
+    }
 
-        Ok(ArrayData::from_arrow(&arrow, self.nullability.into()))
+    #[test]
+    fn test_allocated_bytes_counts_validity() {
+        let mut builder = BoolBuilder::with_capacity(Nullability::Nullable, 1024);
+        let values_only = builder.allocated_bytes();
+        builder.append_null();
+        assert!(builder.allocated_bytes() >= values_only + 1024 / 8);
+        assert!(builder.allocated_bytes() >= builder.nbytes());
     }
 }
@@ -0,0 +1,518 @@
+use std::any::Any;
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexExpect as _, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::ChunkedArray;
+use crate::builders::ArrayBuilder;
+use crate::compress::CompressionStrategy;
+use crate::compute::slice;
+use crate::{ArrayData, IntoArrayData};
+
+/// Default maximum number of rows in each chunk of a [ChunkedBuilder].
+pub const DEFAULT_CHUNK_ROWS: usize = 64 * 1024;
+/// Default maximum number of bytes in each chunk of a [ChunkedBuilder].
+pub const DEFAULT_CHUNK_BYTES: usize = 16 * 1024 * 1024;
+/// Default number of threads compressing the chunks of a [ChunkedBuilder] in the background.
+pub const DEFAULT_BACKGROUND_THREADS: usize = 2;
+
+enum Chunk {
+    Ready(ArrayData),
+    Compressing(Receiver<ArrayData>),
+}
+
+impl Chunk {
+    fn into_array(self) -> VortexResult<ArrayData> {
+        match self {
+            Chunk::Ready(array) => Ok(array),
+            Chunk::Compressing(result) => result
+                .recv()
+                .map_err(|_| vortex_err!("Chunk compression thread panicked")),
+        }
+    }
+}
+
+/// Compress a chunk, keeping it uncompressed if that fails rather than losing its rows.
+fn compress_chunk(compressor: &dyn CompressionStrategy, chunk: ArrayData) -> ArrayData {
+    compressor.compress(&chunk).unwrap_or_else(|err| {
+        log::warn!(
+            "Keeping chunk of {} rows uncompressed: {}",
+            chunk.len(),
+            err
+        );
+        chunk
+    })
+}
+
+type CompressionJob = (ArrayData, Sender<ArrayData>);
+
+/// A fixed number of threads compressing chunks, fed through a queue of the same length, so that
+/// at most twice as many chunks as there are threads are waiting for or being compressed.
+struct CompressionPool {
+    jobs: Option<SyncSender<CompressionJob>>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl CompressionPool {
+    fn new(compressor: Arc<dyn CompressionStrategy + Send + Sync>, threads: usize) -> Self {
+        let (jobs, queue) = mpsc::sync_channel::<CompressionJob>(threads);
+        let queue = Arc::new(Mutex::new(queue));
+        let threads = (0..threads)
+            .map(|_| {
+                let queue = queue.clone();
+                let compressor = compressor.clone();
+                std::thread::spawn(move || loop {
+                    // The lock is released before compressing, so the other threads can take jobs.
+                    let Ok(Ok((chunk, result))) = queue.lock().map(|queue| queue.recv()) else {
+                        break;
+                    };
+                    // The builder doesn't wait for the result if it was dropped.
+                    result.send(compress_chunk(compressor.as_ref(), chunk)).ok();
+                })
+            })
+            .collect();
+        Self {
+            jobs: Some(jobs),
+            threads,
+        }
+    }
+
+    /// Queue a chunk to be compressed, blocking while the queue is full.
+    fn compress(&self, chunk: ArrayData) -> VortexResult<Chunk> {
+        let (result, receiver) = mpsc::channel();
+        self.jobs
+            .as_ref()
+            .vortex_expect("Compression pool used after it was joined")
+            .send((chunk, result))
+            .map_err(|_| vortex_err!("Chunk compression threads have stopped"))?;
+        Ok(Chunk::Compressing(receiver))
+    }
+
+    /// Wait for the queued chunks to be compressed and for the threads to exit.
+    fn join(&mut self) -> VortexResult<()> {
+        drop(self.jobs.take());
+        let mut panicked = false;
+        for thread in self.threads.drain(..) {
+            panicked |= thread.join().is_err();
+        }
+        if panicked {
+            vortex_bail!("Chunk compression thread panicked");
+        }
+        Ok(())
+    }
+}
+
+impl Drop for CompressionPool {
+    fn drop(&mut self) {
+        // Don't leave threads running after the builder is gone, e.g. when it failed to finish.
+        self.join().ok();
+    }
+}
+
+/// An [ArrayBuilder] that wraps another builder, rolling over into a new chunk whenever the
+/// current chunk reaches a row or byte threshold, and finishes into a [ChunkedArray].
+///
+/// Completed chunks can optionally be compressed, either inline or on a fixed number of background
+/// threads, so the memory held by the builder stays bounded by the few uncompressed chunks being
+/// built, queued and compressed. Chunks that fail to compress are kept uncompressed.
+pub struct ChunkedBuilder {
+    builder: Box<dyn ArrayBuilder>,
+    dtype: DType,
+    max_rows: usize,
+    max_bytes: usize,
+    compressor: Option<Arc<dyn CompressionStrategy + Send + Sync>>,
+    background: bool,
+    background_threads: usize,
+    pool: Option<CompressionPool>,
+    chunks: Vec<Chunk>,
+    chunks_len: usize,
+    error: Option<VortexError>,
+}
+
+impl ChunkedBuilder {
+    pub fn new(builder: Box<dyn ArrayBuilder>) -> Self {
+        Self::with_limits(builder, DEFAULT_CHUNK_ROWS, DEFAULT_CHUNK_BYTES)
+    }
+
+    pub fn with_limits(builder: Box<dyn ArrayBuilder>, max_rows: usize, max_bytes: usize) -> Self {
+        Self {
+            dtype: builder.dtype().clone(),
+            builder,
+            max_rows: max_rows.max(1),
+            max_bytes,
+            compressor: None,
+            background: false,
+            background_threads: DEFAULT_BACKGROUND_THREADS,
+            pool: None,
+            chunks: Vec::new(),
+            chunks_len: 0,
+            error: None,
+        }
+    }
+
+    /// Compress each chunk once it is complete, on background threads if `background` is set.
+    pub fn with_compressor(
+        mut self,
+        compressor: Arc<dyn CompressionStrategy + Send + Sync>,
+        background: bool,
+    ) -> Self {
+        self.compressor = Some(compressor);
+        self.background = background;
+        self
+    }
+
+    /// The number of threads compressing chunks in the background, which defaults to
+    /// [DEFAULT_BACKGROUND_THREADS].
+    pub fn with_background_threads(mut self, threads: usize) -> Self {
+        self.background_threads = threads.max(1);
+        self
+    }
+
+    /// The number of completed chunks.
+    pub fn nchunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Append to the current chunk with the wrapped builder, rolling over into a new chunk if it
+    /// is full afterwards.
+    ///
+    /// This allows appending native values by downcasting the wrapped builder.
+    pub fn append_with<R>(
+        &mut self,
+        f: impl FnOnce(&mut dyn ArrayBuilder) -> R,
+    ) -> VortexResult<R> {
+        let result = f(self.builder.as_mut());
+        self.roll_if_full()?;
+        Ok(result)
+    }
+
+    fn is_full(&self) -> bool {
+        self.builder.len() >= self.max_rows || self.builder.nbytes() >= self.max_bytes
+    }
+
+    fn roll_if_full(&mut self) -> VortexResult<()> {
+        if self.is_full() {
+            self.roll()?;
+        }
+        Ok(())
+    }
+
+    fn roll(&mut self) -> VortexResult<()> {
+        let chunk = self.builder.finish()?;
+        let len = chunk.len();
+
+        let chunk = match self.compressor.clone() {
+            None => Chunk::Ready(chunk),
+            Some(compressor) if self.background => {
+                let threads = self.background_threads;
+                self.pool
+                    .get_or_insert_with(|| CompressionPool::new(compressor, threads))
+                    .compress(chunk)?
+            }
+            Some(compressor) => Chunk::Ready(compress_chunk(compressor.as_ref(), chunk)),
+        };
+        // Only count the rows once the chunk is kept, so a failed compression doesn't leave them
+        // in the builder's length.
+        self.chunks.push(chunk);
+        self.chunks_len += len;
+        Ok(())
+    }
+
+    /// Roll over the current chunk and collect all the chunks, waiting for any that are still
+    /// being compressed.
+    fn finish_chunks(&mut self) -> VortexResult<Vec<ArrayData>> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        if !self.builder.is_empty() {
+            self.roll()?;
+        }
+
+        let chunks = std::mem::take(&mut self.chunks);
+        if let Some(mut pool) = self.pool.take() {
+            pool.join()?;
+        }
+        chunks.into_iter().map(Chunk::into_array).collect()
+    }
+
+    /// Estimate how many more rows fit into the current chunk, based on the average row size
+    /// so far. Always returns at least one row.
+    fn remaining_rows(&self) -> usize {
+        let len = self.builder.len();
+        let rows = self.max_rows.saturating_sub(len);
+        let bytes_rows = if len == 0 {
+            1
+        } else {
+            let nbytes = self.builder.nbytes();
+            let row_nbytes = nbytes.div_ceil(len).max(1);
+            self.max_bytes.saturating_sub(nbytes).div_ceil(row_nbytes)
+        };
+        rows.min(bytes_rows).max(1)
+    }
+
+    /// The size of the completed chunks, excluding those still being compressed.
+    fn chunks_nbytes(&self) -> usize {
+        self.chunks
+            .iter()
+            .map(|chunk| match chunk {
+                Chunk::Ready(array) => array.nbytes(),
+                Chunk::Compressing(_) => 0,
+            })
+            .sum()
+    }
+
+    /// Append `n` rows using `append`, splitting them across chunks to respect the limits.
+    fn append_split(&mut self, mut n: usize, append: impl Fn(&mut dyn ArrayBuilder, usize)) {
+        while n > 0 {
+            let count = n.min(self.remaining_rows());
+            append(self.builder.as_mut(), count);
+            n -= count;
+            if let Err(err) = self.roll_if_full() {
+                // The ArrayBuilder append methods are infallible, so we surface the error on finish.
+                self.error.get_or_insert(err);
+            }
+        }
+    }
+}
+
+impl ArrayBuilder for ChunkedBuilder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.chunks_len + self.builder.len()
+    }
+
+    fn nbytes(&self) -> usize {
+        self.chunks_nbytes() + self.builder.nbytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.chunks_nbytes() + self.builder.allocated_bytes()
+    }
+
+    /// Reserve capacity in the current chunk, up to the chunk's row limit.
+    fn reserve(&mut self, additional: usize) {
+        self.builder.reserve(additional.min(self.remaining_rows()))
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.append_split(n, |builder, count| builder.append_zeros(count))
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.append_split(n, |builder, count| builder.append_nulls(count))
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        self.append_with(|builder| builder.append_scalar(scalar))?
+    }
+
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        let mut offset = 0;
+        while offset < array.len() {
+            let end = array.len().min(offset + self.remaining_rows());
+            self.append_with(|builder| builder.extend_from_array(&slice(array, offset, end)?))??;
+            offset = end;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> VortexResult<ArrayData> {
+        let chunks = self.finish_chunks();
+
+        // Whether or not finishing succeeded, the builder starts over empty.
+        if chunks.is_err() {
+            self.builder.finish().ok();
+        }
+        self.chunks.clear();
+        self.chunks_len = 0;
+        drop(self.pool.take());
+
+        ChunkedArray::try_new(chunks?, self.dtype.clone()).map(IntoArrayData::into_array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_error::{vortex_bail, VortexResult};
+
+    use crate::aliases::hash_set::HashSet;
+    use crate::array::{ChunkedArray, ConstantArray, PrimitiveArray};
+    use crate::builders::{ArrayBuilder, ChunkedBuilder, PrimitiveBuilder};
+    use crate::compress::CompressionStrategy;
+    use crate::encoding::EncodingRef;
+    use crate::stats::ArrayStatistics;
+    use crate::{ArrayDType, ArrayData, ArrayLen, IntoArrayData};
+
+    #[test]
+    fn rolls_over_on_rows() {
+        let mut builder = ChunkedBuilder::with_limits(
+            Box::new(PrimitiveBuilder::<i32>::new(Nullability::NonNullable)),
+            3,
+            usize::MAX,
+        );
+        for i in 0..7 {
+            builder
+                .append_with(|b| {
+                    b.as_any_mut()
+                        .downcast_mut::<PrimitiveBuilder<i32>>()
+                        .unwrap()
+                        .append_value(i)
+                })
+                .unwrap();
+        }
+        assert_eq!(builder.nchunks(), 2);
+        assert_eq!(builder.len(), 7);
+
+        let chunked = ChunkedArray::try_from(builder.finish().unwrap()).unwrap();
+        assert_eq!(chunked.nchunks(), 3);
+        assert_eq!(chunked.chunk(2).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn rolls_over_on_bytes() {
+        let mut builder = ChunkedBuilder::with_limits(
+            Box::new(PrimitiveBuilder::<u64>::new(Nullability::NonNullable)),
+            usize::MAX,
+            16,
+        );
+        builder.append_zeros(5);
+
+        let chunked = ChunkedArray::try_from(builder.finish().unwrap()).unwrap();
+        assert_eq!(chunked.len(), 5);
+        assert_eq!(chunked.nchunks(), 3);
+    }
+
+    #[test]
+    fn splits_bulk_appends() {
+        let mut builder = ChunkedBuilder::with_limits(
+            Box::new(PrimitiveBuilder::<u8>::new(Nullability::Nullable)),
+            4,
+            usize::MAX,
+        );
+        builder.append_nulls(10);
+
+        let chunked = ChunkedArray::try_from(builder.finish().unwrap()).unwrap();
+        assert_eq!(chunked.nchunks(), 3);
+        assert_eq!(chunked.statistics().compute_null_count(), Some(10));
+    }
+
+    #[test]
+    fn splits_extend_from_array() {
+        let mut builder = ChunkedBuilder::with_limits(
+            Box::new(PrimitiveBuilder::<u32>::new(Nullability::NonNullable)),
+            4,
+            usize::MAX,
+        );
+        builder
+            .extend_from_array(&PrimitiveArray::from((0u32..10).collect::<Vec<_>>()).into_array())
+            .unwrap();
+        assert_eq!(builder.nchunks(), 2);
+
+        let chunked = ChunkedArray::try_from(builder.finish().unwrap()).unwrap();
+        assert_eq!(chunked.nchunks(), 3);
+        assert_eq!(
+            crate::compute::scalar_at(chunked.as_ref(), 9).unwrap(),
+            9u32.into()
+        );
+    }
+
+    struct ConstantCompressor;
+
+    impl CompressionStrategy for ConstantCompressor {
+        fn compress(&self, array: &ArrayData) -> VortexResult<ArrayData> {
+            Ok(ConstantArray::new(crate::compute::scalar_at(array, 0)?, array.len()).into_array())
+        }
+
+        fn used_encodings(&self) -> HashSet<EncodingRef> {
+            HashSet::default()
+        }
+    }
+
+    #[test]
+    fn compresses_in_background() {
+        let mut builder = ChunkedBuilder::with_limits(
+            Box::new(PrimitiveBuilder::<i64>::new(Nullability::NonNullable)),
+            2,
+            usize::MAX,
+        )
+        .with_compressor(Arc::new(ConstantCompressor), true);
+        builder.append_zeros(5);
+
+        let array = builder.finish().unwrap();
+        assert_eq!(
+            array.dtype(),
+            &DType::Primitive(PType::I64, Nullability::NonNullable)
+        );
+        let chunked = ChunkedArray::try_from(array).unwrap();
+        assert_eq!(chunked.nchunks(), 3);
+        assert!(chunked.chunks().all(|chunk| chunk.is_constant()));
+    }
+
+    #[test]
+    fn compresses_more_chunks_than_threads() {
+        let mut builder = ChunkedBuilder::with_limits(
+            Box::new(PrimitiveBuilder::<i64>::new(Nullability::NonNullable)),
+            2,
+            usize::MAX,
+        )
+        .with_compressor(Arc::new(ConstantCompressor), true)
+        .with_background_threads(1);
+        builder.append_zeros(41);
+
+        let chunked = ChunkedArray::try_from(builder.finish().unwrap()).unwrap();
+        assert_eq!(chunked.nchunks(), 21);
+        assert!(chunked.chunks().all(|chunk| chunk.is_constant()));
+    }
+
+    struct FailingCompressor;
+
+    impl CompressionStrategy for FailingCompressor {
+        fn compress(&self, _array: &ArrayData) -> VortexResult<ArrayData> {
+            vortex_bail!("Failed to compress")
+        }
+
+        fn used_encodings(&self) -> HashSet<EncodingRef> {
+            HashSet::default()
+        }
+    }
+
+    #[test]
+    fn compression_error_keeps_chunks() {
+        for background in [false, true] {
+            let mut builder = ChunkedBuilder::with_limits(
+                Box::new(PrimitiveBuilder::<i64>::new(Nullability::NonNullable)),
+                2,
+                usize::MAX,
+            )
+            .with_compressor(Arc::new(FailingCompressor), background);
+            builder.append_zeros(5);
+            assert_eq!(builder.len(), 5);
+            assert_eq!(builder.nchunks(), 2);
+
+            let chunked = ChunkedArray::try_from(builder.finish().unwrap()).unwrap();
+            assert_eq!(chunked.len(), 5);
+            assert_eq!(chunked.nchunks(), 3);
+            assert!(chunked.chunks().all(|chunk| chunk.is_canonical()));
+            assert_eq!(builder.len(), 0);
+        }
+    }
+}
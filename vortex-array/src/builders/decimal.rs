@@ -0,0 +1,451 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow_array::Decimal128Array;
+use arrow_buffer::{NullBufferBuilder, ScalarBuffer};
+use vortex_buffer::Buffer;
+use vortex_dtype::{DType, DecimalDType, DecimalValueType, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult};
+use vortex_scalar::{DecimalScalar, DecimalValue, Scalar};
+
+use crate::array::DecimalArray;
+use crate::arrow::FromArrowArray;
+use crate::builders::{
+    check_dtype, finish_validity, validity_allocated_bytes, validity_nbytes, ArrayBuilder,
+    GrowthStrategy,
+};
+use crate::validity::{ArrayValidity, LogicalValidity};
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
+
+/// A builder for decimals of up to [DecimalDType::MAX_DECIMAL128_PRECISION] digits, which are held
+/// as 128-bit values and finished through an Arrow [Decimal128Array].
+pub struct Decimal128Builder {
+    values: Vec<i128>,
+    validity: NullBufferBuilder,
+    decimal_dtype: DecimalDType,
+    dtype: DType,
+    growth: GrowthStrategy,
+}
+
+impl Decimal128Builder {
+    pub fn try_new(decimal_dtype: DecimalDType, nullability: Nullability) -> VortexResult<Self> {
+        Self::try_with_capacity(decimal_dtype, nullability, 1024)
+    }
+
+    /// Create a builder for `decimal_dtype`, failing if its precision doesn't fit in 128 bits.
+    pub fn try_with_capacity(
+        decimal_dtype: DecimalDType,
+        nullability: Nullability,
+        capacity: usize,
+    ) -> VortexResult<Self> {
+        if decimal_dtype.value_type() != DecimalValueType::I128 {
+            vortex_bail!(
+                "{} exceeds the maximum precision {} of a 128-bit decimal",
+                decimal_dtype,
+                DecimalDType::MAX_DECIMAL128_PRECISION
+            );
+        }
+        Ok(Self {
+            values: Vec::with_capacity(capacity),
+            validity: NullBufferBuilder::new(capacity),
+            decimal_dtype,
+            dtype: DType::Decimal(decimal_dtype, nullability),
+            growth: GrowthStrategy::default(),
+        })
+    }
+
+    /// Set how the builder grows its buffers once they run out of capacity.
+    pub fn with_growth_strategy(mut self, growth: GrowthStrategy) -> Self {
+        self.growth = growth;
+        self
+    }
+
+    /// Append an unscaled value, failing if it has more digits than the precision of the dtype.
+    pub fn append_value(&mut self, value: i128) -> VortexResult<()> {
+        // 10^38 still fits in an i128, so the bound can't overflow for any 128-bit precision.
+        let bound = 10u128.pow(u32::from(self.decimal_dtype.precision()));
+        if value.unsigned_abs() >= bound {
+            vortex_bail!("Decimal value {} does not fit {}", value, self.dtype);
+        }
+        self.growth.grow(&mut self.values, 1);
+        self.values.push(value);
+        self.validity.append(true);
+        Ok(())
+    }
+
+    pub fn append_option(&mut self, value: Option<i128>) -> VortexResult<()> {
+        match value {
+            Some(value) => self.append_value(value),
+            None => {
+                self.append_null();
+                Ok(())
+            }
+        }
+    }
+
+    /// Finish the builder into an Arrow [Decimal128Array], resetting it for reuse.
+    pub fn finish_arrow(&mut self) -> VortexResult<Decimal128Array> {
+        let nulls = self.validity.finish();
+        if !self.dtype.is_nullable() && nulls.is_some() {
+            vortex_bail!("Non-nullable builder has null values");
+        }
+        Ok(
+            Decimal128Array::try_new(ScalarBuffer::from(std::mem::take(&mut self.values)), nulls)?
+                .with_precision_and_scale(
+                    self.decimal_dtype.precision(),
+                    self.decimal_dtype.scale(),
+                )?,
+        )
+    }
+}
+
+impl ArrayBuilder for Decimal128Builder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn nbytes(&self) -> usize {
+        self.values.len() * size_of::<i128>() + validity_nbytes(&self.validity)
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.values.capacity() * size_of::<i128>() + validity_allocated_bytes(&self.validity)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.growth.grow(&mut self.values, n);
+        self.values.resize(self.values.len() + n, 0);
+        self.validity.append_n_non_nulls(n);
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.growth.grow(&mut self.values, n);
+        self.values.resize(self.values.len() + n, 0);
+        self.validity.append_n_nulls(n);
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        check_dtype(self.dtype(), scalar.dtype())?;
+        let value = DecimalScalar::try_from(scalar)?
+            .value()
+            .map(|value| {
+                value.as_i128().ok_or_else(|| {
+                    vortex_err!("Decimal value {} does not fit {}", value, self.dtype)
+                })
+            })
+            .transpose()?;
+        self.append_option(value)
+    }
+
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        let decimal = array.clone().into_decimal()?;
+        self.growth.grow(&mut self.values, array.len());
+        self.values.extend(
+            decimal
+                .buffer()
+                .as_slice()
+                .chunks_exact(size_of::<i128>())
+                .map(|bytes| {
+                    i128::from_le_bytes(
+                        bytes
+                            .try_into()
+                            .ok()
+                            .vortex_expect("Chunks hold exactly one i128"),
+                    )
+                }),
+        );
+        match decimal.logical_validity() {
+            LogicalValidity::AllValid(n) => self.validity.append_n_non_nulls(n),
+            LogicalValidity::AllInvalid(n) => self.validity.append_n_nulls(n),
+            LogicalValidity::Array(validity) => validity
+                .into_bool()?
+                .boolean_buffer()
+                .iter()
+                .for_each(|valid| self.validity.append(valid)),
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> VortexResult<ArrayData> {
+        let array = self.finish_arrow()?;
+        Ok(ArrayData::from_arrow(
+            Arc::new(array) as arrow_array::ArrayRef,
+            self.dtype.is_nullable(),
+        ))
+    }
+}
+
+/// A builder of decimals, holding the little-endian bytes of values as wide as the dtype requires.
+pub struct DecimalBuilder {
+    values: Vec<u8>,
+    validity: NullBufferBuilder,
+    decimal_dtype: DecimalDType,
+    dtype: DType,
+    growth: GrowthStrategy,
+}
+
+impl DecimalBuilder {
+    pub fn new(decimal_dtype: DecimalDType, nullability: Nullability) -> Self {
+        Self::with_capacity(decimal_dtype, nullability, 1024)
+    }
+
+    pub fn with_capacity(
+        decimal_dtype: DecimalDType,
+        nullability: Nullability,
+        capacity: usize,
+    ) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity * decimal_dtype.value_type().byte_width()),
+            validity: NullBufferBuilder::new(capacity),
+            decimal_dtype,
+            dtype: DType::Decimal(decimal_dtype, nullability),
+            growth: GrowthStrategy::default(),
+        }
+    }
+
+    /// Set how the builder grows its buffers once they run out of capacity.
+    pub fn with_growth_strategy(mut self, growth: GrowthStrategy) -> Self {
+        self.growth = growth;
+        self
+    }
+
+    fn byte_width(&self) -> usize {
+        self.decimal_dtype.value_type().byte_width()
+    }
+
+    fn grow(&mut self, additional: usize) {
+        let byte_width = self.byte_width();
+        self.growth.grow(&mut self.values, additional * byte_width);
+    }
+
+    /// Append a value, failing if it doesn't fit the width of the dtype.
+    pub fn append_value(&mut self, value: impl Into<DecimalValue>) -> VortexResult<()> {
+        let value = value.into();
+        self.grow(1);
+        match self.decimal_dtype.value_type() {
+            DecimalValueType::I128 => {
+                let value = value.as_i128().ok_or_else(|| {
+                    vortex_err!("Decimal value {} does not fit {}", value, self.dtype)
+                })?;
+                self.values.extend_from_slice(&value.to_le_bytes());
+            }
+            DecimalValueType::I256 => self
+                .values
+                .extend_from_slice(&value.as_i256().to_le_bytes()),
+        }
+        self.validity.append(true);
+        Ok(())
+    }
+
+    pub fn append_option(&mut self, value: Option<impl Into<DecimalValue>>) -> VortexResult<()> {
+        match value {
+            Some(value) => self.append_value(value),
+            None => {
+                self.append_null();
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ArrayBuilder for DecimalBuilder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.values.len() / self.byte_width()
+    }
+
+    fn nbytes(&self) -> usize {
+        self.values.len() + validity_nbytes(&self.validity)
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.values.capacity() + validity_allocated_bytes(&self.validity)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        let byte_width = self.byte_width();
+        self.values.reserve(additional * byte_width);
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.grow(n);
+        let byte_width = self.byte_width();
+        self.values.resize(self.values.len() + n * byte_width, 0);
+        self.validity.append_n_non_nulls(n);
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.grow(n);
+        let byte_width = self.byte_width();
+        self.values.resize(self.values.len() + n * byte_width, 0);
+        self.validity.append_n_nulls(n);
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        check_dtype(self.dtype(), scalar.dtype())?;
+        self.append_option(DecimalScalar::try_from(scalar)?.value())
+    }
+
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        let decimal = array.clone().into_decimal()?;
+        self.grow(array.len());
+        self.values.extend_from_slice(decimal.buffer().as_slice());
+        match decimal.logical_validity() {
+            LogicalValidity::AllValid(n) => self.validity.append_n_non_nulls(n),
+            LogicalValidity::AllInvalid(n) => self.validity.append_n_nulls(n),
+            LogicalValidity::Array(validity) => validity
+                .into_bool()?
+                .boolean_buffer()
+                .iter()
+                .for_each(|valid| self.validity.append(valid)),
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> VortexResult<ArrayData> {
+        let nullability = self.dtype.nullability();
+        let validity = finish_validity(&mut self.validity, nullability)?;
+        DecimalArray::try_new(
+            Buffer::from(std::mem::take(&mut self.values)),
+            self.decimal_dtype,
+            validity,
+        )
+        .map(IntoArrayData::into_array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Array;
+    use vortex_dtype::{DType, DecimalDType, Nullability};
+    use vortex_scalar::Scalar;
+
+    use crate::array::DecimalArray;
+    use crate::builders::{builder_with_capacity, ArrayBuilder, Decimal128Builder, DecimalBuilder};
+    use crate::compute::scalar_at;
+    use crate::validity::Validity;
+    use crate::{ArrayLen, IntoArrayData, IntoArrayVariant};
+
+    #[test]
+    fn test_decimal_builder() {
+        let decimal_dtype = DecimalDType::new(10, 2);
+        let mut builder = DecimalBuilder::new(decimal_dtype, Nullability::Nullable);
+        builder.append_value(12345i128).unwrap();
+        builder.append_null();
+        builder
+            .append_scalar(&Scalar::decimal(
+                -1i128,
+                decimal_dtype,
+                Nullability::Nullable,
+            ))
+            .unwrap();
+        builder
+            .extend_from_array(
+                &DecimalArray::from_values([7i128], decimal_dtype, Validity::AllValid)
+                    .unwrap()
+                    .into_array(),
+            )
+            .unwrap();
+
+        let array = builder.finish().unwrap().into_decimal().unwrap();
+        assert_eq!(array.len(), 4);
+        assert!(scalar_at(array.as_ref(), 1).unwrap().is_null());
+        assert_eq!(
+            scalar_at(array.as_ref(), 2).unwrap(),
+            Scalar::decimal(-1i128, decimal_dtype, Nullability::Nullable)
+        );
+        assert_eq!(
+            scalar_at(array.as_ref(), 3).unwrap(),
+            Scalar::decimal(7i128, decimal_dtype, Nullability::Nullable)
+        );
+    }
+
+    #[test]
+    fn test_decimal_from_dtype() {
+        let builder = builder_with_capacity(
+            &DType::Decimal(DecimalDType::new(50, 5), Nullability::NonNullable),
+            8,
+        );
+        assert!(builder.as_any().downcast_ref::<DecimalBuilder>().is_some());
+    }
+
+    #[test]
+    fn test_decimal128_builder() {
+        let decimal_dtype = DecimalDType::new(DecimalDType::MAX_DECIMAL128_PRECISION, 0);
+        let max = 10i128.pow(38) - 1;
+        let mut builder =
+            Decimal128Builder::try_with_capacity(decimal_dtype, Nullability::NonNullable, 2)
+                .unwrap();
+        builder.append_value(-max).unwrap();
+        builder.append_value(i128::MAX).unwrap_err();
+        builder.append_zeros(1);
+        assert_eq!(builder.nbytes(), 2 * 16);
+
+        let array = builder.finish().unwrap().into_decimal().unwrap();
+        assert_eq!(array.len(), 2);
+        assert_eq!(
+            scalar_at(array.as_ref(), 0).unwrap(),
+            Scalar::decimal(-max, decimal_dtype, Nullability::NonNullable)
+        );
+    }
+
+    #[test]
+    fn test_decimal128_builder_precision() {
+        let mut builder =
+            Decimal128Builder::try_new(DecimalDType::new(3, 1), Nullability::Nullable).unwrap();
+        builder.append_value(999).unwrap();
+        builder.append_value(1000).unwrap_err();
+        builder.append_null();
+
+        let array = builder.finish_arrow().unwrap();
+        assert_eq!(array.precision(), 3);
+        assert_eq!(array.scale(), 1);
+        assert_eq!(array.value(0), 999);
+        assert!(array.is_null(1));
+
+        assert!(
+            Decimal128Builder::try_new(DecimalDType::new(39, 0), Nullability::Nullable).is_err()
+        );
+    }
+
+    #[test]
+    fn test_decimal128_from_dtype() {
+        let builder = builder_with_capacity(
+            &DType::Decimal(DecimalDType::new(10, 2), Nullability::NonNullable),
+            8,
+        );
+        assert!(builder
+            .as_any()
+            .downcast_ref::<Decimal128Builder>()
+            .is_some());
+    }
+}
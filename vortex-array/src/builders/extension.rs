@@ -2,12 +2,12 @@ use std::any::Any;
 use std::sync::Arc;
 
 use vortex_dtype::{DType, ExtDType};
-use vortex_error::VortexResult;
-use vortex_scalar::ExtScalar;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::{ExtScalar, Scalar};
 
 use crate::array::ExtensionArray;
-use crate::builders::{builder_with_capacity, ArrayBuilder, ArrayBuilderExt};
-use crate::{ArrayData, IntoArrayData};
+use crate::builders::{builder_with_capacity, check_dtype, ArrayBuilder};
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
 
 pub struct ExtensionBuilder {
     storage: Box<dyn ArrayBuilder>,
@@ -26,6 +26,33 @@ impl ExtensionBuilder {
         }
     }
 
+    /// Wrap an existing storage builder, attaching the extension dtype when finished.
+    ///
+    /// Returns an error if the builder's dtype doesn't match the extension's storage dtype.
+    pub fn try_from_storage(
+        ext_dtype: Arc<ExtDType>,
+        storage: Box<dyn ArrayBuilder>,
+    ) -> VortexResult<Self> {
+        if storage.dtype() != ext_dtype.storage_dtype() {
+            vortex_bail!(
+                "Storage builder has dtype {}, extension {} expects {}",
+                storage.dtype(),
+                ext_dtype.id(),
+                ext_dtype.storage_dtype()
+            );
+        }
+
+        Ok(Self {
+            storage,
+            dtype: DType::Extension(ext_dtype),
+        })
+    }
+
+    /// Access the storage builder, e.g. to append native values without going through scalars.
+    pub fn storage_mut(&mut self) -> &mut dyn ArrayBuilder {
+        self.storage.as_mut()
+    }
+
     pub fn append_value(&mut self, value: ExtScalar) -> VortexResult<()> {
         self.storage.append_scalar(&value.storage())
     }
@@ -66,6 +93,18 @@ impl ArrayBuilder for ExtensionBuilder {
         self.storage.len()
     }
 
+    fn nbytes(&self) -> usize {
+        self.storage.nbytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.storage.allocated_bytes()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.storage.reserve(additional)
+    }
+
     fn append_zeros(&mut self, n: usize) {
         self.storage.append_zeros(n)
     }
@@ -74,8 +113,78 @@ impl ArrayBuilder for ExtensionBuilder {
         self.storage.append_nulls(n)
     }
 
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        check_dtype(self.dtype(), scalar.dtype())?;
+        self.append_value(ExtScalar::try_from(scalar)?)
+    }
+
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        self.storage
+            .extend_from_array(&array.clone().into_extension()?.storage())
+    }
+
     fn finish(&mut self) -> VortexResult<ArrayData> {
         let storage = self.storage.finish()?;
         Ok(ExtensionArray::new(self.ext_dtype(), storage).into_array())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vortex_datetime_dtype::{TemporalMetadata, TimeUnit, TIMESTAMP_ID};
+    use vortex_dtype::{DType, ExtDType, Nullability, PType};
+
+    use crate::builders::{ArrayBuilder, ExtensionBuilder, PrimitiveBuilder, Utf8Builder};
+    use crate::{ArrayDType, IntoArrayVariant};
+
+    fn timestamp_dtype() -> Arc<ExtDType> {
+        Arc::new(ExtDType::new(
+            TIMESTAMP_ID.clone(),
+            Arc::new(DType::Primitive(PType::I64, Nullability::Nullable)),
+            Some(TemporalMetadata::Timestamp(TimeUnit::Ms, None).into()),
+        ))
+    }
+
+    #[test]
+    fn test_storage_builder() {
+        let ext_dtype = timestamp_dtype();
+        let mut builder = ExtensionBuilder::try_from_storage(
+            ext_dtype.clone(),
+            Box::new(PrimitiveBuilder::<i64>::new(Nullability::Nullable)),
+        )
+        .unwrap();
+
+        let storage = builder
+            .storage_mut()
+            .as_any_mut()
+            .downcast_mut::<PrimitiveBuilder<i64>>()
+            .unwrap();
+        storage.append_value(1_000);
+        storage.append_option(None);
+        builder.append_zero();
+
+        let array = builder.finish().unwrap();
+        assert_eq!(array.dtype(), &DType::Extension(ext_dtype));
+
+        let storage = array
+            .into_extension()
+            .unwrap()
+            .storage()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(storage.maybe_null_slice::<i64>(), &[1_000, 0, 0]);
+        assert!(!storage.validity().is_valid(1));
+    }
+
+    #[test]
+    fn test_mismatched_storage() {
+        assert!(ExtensionBuilder::try_from_storage(
+            timestamp_dtype(),
+            Box::new(Utf8Builder::with_capacity(Nullability::Nullable, 0)),
+        )
+        .is_err());
+    }
+}
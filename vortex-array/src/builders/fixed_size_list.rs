@@ -0,0 +1,167 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use vortex_dtype::{DType, Nullability};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::{ListScalar, Scalar};
+
+use crate::array::FixedSizeListArray;
+use crate::builders::{builder_with_capacity, check_dtype, ArrayBuilder, BoolBuilder};
+use crate::validity::Validity;
+use crate::{ArrayData, IntoArrayData};
+
+pub struct FixedSizeListBuilder {
+    value_builder: Box<dyn ArrayBuilder>,
+    validity: BoolBuilder,
+    list_size: u32,
+    nullability: Nullability,
+    dtype: DType,
+}
+
+impl FixedSizeListBuilder {
+    pub fn with_capacity(
+        value_dtype: Arc<DType>,
+        list_size: u32,
+        nullability: Nullability,
+        capacity: usize,
+    ) -> Self {
+        let value_builder =
+            builder_with_capacity(value_dtype.as_ref(), list_size as usize * capacity);
+
+        Self {
+            value_builder,
+            validity: BoolBuilder::with_capacity(Nullability::NonNullable, capacity),
+            list_size,
+            nullability,
+            dtype: DType::FixedSizeList(value_dtype, list_size, nullability),
+        }
+    }
+
+    pub fn append_value(&mut self, value: ListScalar) -> VortexResult<()> {
+        if value.is_null() {
+            if self.nullability == Nullability::NonNullable {
+                vortex_bail!("Cannot append null value to non-nullable list");
+            }
+            self.append_null();
+            return Ok(());
+        }
+
+        if value.len() != self.list_size as usize {
+            vortex_bail!(
+                "Cannot append list of {} elements to a list of size {}",
+                value.len(),
+                self.list_size
+            );
+        }
+        for scalar in value.elements() {
+            self.value_builder.append_scalar(&scalar)?;
+        }
+        self.validity.append_value(true);
+        Ok(())
+    }
+}
+
+impl ArrayBuilder for FixedSizeListBuilder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    fn nbytes(&self) -> usize {
+        self.value_builder.nbytes() + self.validity.nbytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.value_builder.allocated_bytes() + self.validity.allocated_bytes()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.value_builder
+            .reserve(self.list_size as usize * additional);
+        self.validity.reserve(additional);
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.value_builder.append_zeros(self.list_size as usize * n);
+        self.validity.append_values(true, n);
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        // Null lists still occupy `list_size` elements, which are left as zeros.
+        self.value_builder.append_zeros(self.list_size as usize * n);
+        self.validity.append_values(false, n);
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        check_dtype(self.dtype(), scalar.dtype())?;
+        self.append_value(ListScalar::try_from(scalar)?)
+    }
+
+    fn finish(&mut self) -> VortexResult<ArrayData> {
+        let len = self.validity.len();
+        let validity = match self.nullability {
+            Nullability::NonNullable => Validity::NonNullable,
+            Nullability::Nullable => Validity::Array(self.validity.finish()?),
+        };
+
+        FixedSizeListArray::try_with_len(
+            self.value_builder.finish()?,
+            self.list_size,
+            len,
+            validity,
+        )
+        .map(FixedSizeListArray::into_array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+    use Nullability::{NonNullable, Nullable};
+
+    use crate::builders::fixed_size_list::FixedSizeListBuilder;
+    use crate::builders::ArrayBuilder;
+    use crate::compute::scalar_at;
+    use crate::validity::ArrayValidity;
+    use crate::{ArrayLen, IntoArrayVariant};
+
+    #[test]
+    fn test_nullable_values() {
+        let dtype: Arc<DType> = Arc::new(PType::I32.into());
+        let mut builder = FixedSizeListBuilder::with_capacity(dtype.clone(), 2, Nullable, 0);
+        let value = Scalar::fixed_size_list(dtype, vec![1i32.into(), 2i32.into()], Nullable);
+
+        builder.append_scalar(&value).unwrap();
+        builder.append_null();
+
+        let list = builder.finish().unwrap().into_fixed_size_list().unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.elements().len(), 4);
+        assert_eq!(scalar_at(&list, 0).unwrap(), value);
+        assert!(!list.is_valid(1));
+    }
+
+    #[test]
+    fn test_wrong_size_fails() {
+        let dtype: Arc<DType> = Arc::new(PType::I32.into());
+        let mut builder = FixedSizeListBuilder::with_capacity(dtype.clone(), 2, NonNullable, 0);
+
+        assert!(builder
+            .append_value(Scalar::list(dtype, vec![1i32.into()], NonNullable).as_list())
+            .is_err());
+    }
+}
@@ -4,11 +4,11 @@ use std::sync::Arc;
 use num_traits::{AsPrimitive, PrimInt};
 use vortex_dtype::{DType, NativePType, Nullability};
 use vortex_error::{vortex_bail, VortexExpect, VortexResult};
-use vortex_scalar::{ListScalar, Scalar};
+use vortex_scalar::{FromPrimitiveOrF16, ListScalar, Scalar};
 
 use crate::array::ListArray;
 use crate::builders::{
-    builder_with_capacity, ArrayBuilder, ArrayBuilderExt, BoolBuilder, PrimitiveBuilder,
+    builder_with_capacity, check_dtype, ArrayBuilder, BoolBuilder, PrimitiveBuilder,
 };
 use crate::validity::Validity;
 use crate::{ArrayData, IntoArrayData};
@@ -23,7 +23,7 @@ pub struct ListBuilder<O: PrimInt + NativePType> {
 
 impl<O> ListBuilder<O>
 where
-    O: PrimInt + NativePType,
+    O: PrimInt + NativePType + FromPrimitiveOrF16,
     Scalar: From<O>,
     usize: AsPrimitive<O>,
 {
@@ -67,13 +67,14 @@ where
     }
 
     fn append_index(&mut self, index: O) -> VortexResult<()> {
-        self.index_builder.append_scalar(&Scalar::from(index))
+        self.index_builder.append_value(index);
+        Ok(())
     }
 }
 
 impl<O> ArrayBuilder for ListBuilder<O>
 where
-    O: PrimInt + NativePType,
+    O: PrimInt + NativePType + FromPrimitiveOrF16,
     Scalar: From<O>,
     usize: AsPrimitive<O>,
 {
@@ -93,6 +94,22 @@ where
         self.validity.len()
     }
 
+    fn nbytes(&self) -> usize {
+        self.value_builder.nbytes() + self.index_builder.nbytes() + self.validity.nbytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.value_builder.allocated_bytes()
+            + self.index_builder.allocated_bytes()
+            + self.validity.allocated_bytes()
+    }
+
+    /// Reserve capacity for `additional` more lists, without knowing how many elements they hold.
+    fn reserve(&mut self, additional: usize) {
+        self.index_builder.reserve(additional);
+        self.validity.reserve(additional);
+    }
+
     fn append_zeros(&mut self, n: usize) {
         let count = self.value_builder.len();
         self.value_builder.append_zeros(n);
@@ -114,6 +131,11 @@ where
         self.validity.append_values(false, n);
     }
 
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        check_dtype(self.dtype(), scalar.dtype())?;
+        self.append_value(ListScalar::try_from(scalar)?)
+    }
+
     fn finish(&mut self) -> VortexResult<ArrayData> {
         let validity = match self.nullability {
             Nullability::NonNullable => Validity::NonNullable,
@@ -1,30 +1,70 @@
 mod binary;
 mod bool;
+mod chunked;
+mod decimal;
 mod extension;
+mod fixed_size_list;
 mod list;
 mod null;
+mod pooled;
 mod primitive;
 mod struct_;
 mod utf8;
+mod views;
 
 use std::any::Any;
 
+use arrow_buffer::NullBufferBuilder;
 pub use binary::*;
 pub use bool::*;
+pub use chunked::*;
+pub use decimal::*;
 pub use extension::*;
 pub use null::*;
+pub use pooled::*;
 pub use primitive::*;
 pub use utf8::*;
-use vortex_dtype::{match_each_native_ptype, DType};
-use vortex_error::{vortex_bail, vortex_err, VortexResult};
-use vortex_scalar::{
-    BinaryScalar, BoolScalar, ExtScalar, ListScalar, PrimitiveScalar, Scalar, StructScalar,
-    Utf8Scalar,
-};
+use vortex_dtype::{match_each_native_ptype, DType, DecimalValueType, Nullability};
+use vortex_error::{vortex_bail, VortexExpect, VortexResult};
+use vortex_scalar::Scalar;
 
+use crate::array::BoolArray;
+use crate::builders::fixed_size_list::FixedSizeListBuilder;
 use crate::builders::list::ListBuilder;
 use crate::builders::struct_::StructBuilder;
-use crate::ArrayData;
+use crate::compute::scalar_at;
+use crate::validity::Validity;
+use crate::{ArrayDType, ArrayData, IntoArrayData};
+
+/// How a builder grows its buffers once they run out of capacity.
+///
+/// Bit-packed buffers are backed by Arrow, which may round allocations up further.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GrowthStrategy {
+    /// Grow buffers geometrically, amortizing the cost of reallocation.
+    #[default]
+    Exponential,
+    /// Grow buffers by a multiple of the given number of values, bounding over-allocation.
+    Linear(usize),
+}
+
+impl GrowthStrategy {
+    /// The number of values to reserve when a buffer holding `len` values needs room for
+    /// `additional` more.
+    fn reserve_count(&self, len: usize, additional: usize) -> usize {
+        match self {
+            GrowthStrategy::Exponential => additional.max(len),
+            GrowthStrategy::Linear(step) => additional.next_multiple_of((*step).max(1)),
+        }
+    }
+
+    /// Make room in `values` for `additional` more values.
+    fn grow<T>(&self, values: &mut Vec<T>, additional: usize) {
+        if values.capacity() - values.len() < additional {
+            values.reserve_exact(self.reserve_count(values.len(), additional));
+        }
+    }
+}
 
 pub trait ArrayBuilder: Send {
     fn as_any(&self) -> &dyn Any;
@@ -39,6 +79,23 @@ pub trait ArrayBuilder: Send {
         self.len() == 0
     }
 
+    /// The approximate number of bytes of data appended to the builder so far.
+    ///
+    /// Builders that don't track their size report zero.
+    fn nbytes(&self) -> usize {
+        0
+    }
+
+    /// The approximate number of bytes allocated by the builder, including unused capacity.
+    fn allocated_bytes(&self) -> usize {
+        self.nbytes()
+    }
+
+    /// Reserve capacity for at least `additional` more values.
+    ///
+    /// This is only a hint, which builders that can't reserve ahead ignore.
+    fn reserve(&mut self, _additional: usize) {}
+
     /// Append a "zero" value to the array.
     fn append_zero(&mut self) {
         self.append_zeros(1)
@@ -55,6 +112,30 @@ pub trait ArrayBuilder: Send {
     /// Appends n "null" values to the array.
     fn append_nulls(&mut self, n: usize);
 
+    /// Append a scalar to the array, returning an error if its dtype doesn't match the builder.
+    ///
+    /// The default implementation only appends nulls, and fails for any other value.
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        check_dtype(self.dtype(), scalar.dtype())?;
+        if !scalar.is_null() {
+            vortex_bail!("Builder of {} can't append scalar {}", self.dtype(), scalar);
+        }
+        self.append_null();
+        Ok(())
+    }
+
+    /// Append all values of `array` to the builder.
+    ///
+    /// The default implementation appends one scalar at a time. Builders override this to copy
+    /// the canonical buffers of the array directly.
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        for idx in 0..array.len() {
+            self.append_scalar(&scalar_at(array, idx)?)?;
+        }
+        Ok(())
+    }
+
     fn finish(&mut self) -> VortexResult<ArrayData>;
 }
 
@@ -67,6 +148,15 @@ pub fn builder_with_capacity(dtype: &DType, capacity: usize) -> Box<dyn ArrayBui
                 Box::new(PrimitiveBuilder::<$P>::with_capacity(*n, capacity))
             })
         }
+        DType::Decimal(decimal_dtype, n) => match decimal_dtype.value_type() {
+            DecimalValueType::I128 => Box::new(
+                Decimal128Builder::try_with_capacity(*decimal_dtype, *n, capacity)
+                    .vortex_expect("128-bit decimal dtype"),
+            ),
+            DecimalValueType::I256 => {
+                Box::new(DecimalBuilder::with_capacity(*decimal_dtype, *n, capacity))
+            }
+        },
         DType::Utf8(n) => Box::new(Utf8Builder::with_capacity(*n, capacity)),
         DType::Binary(n) => Box::new(BinaryBuilder::with_capacity(*n, capacity)),
         DType::Struct(struct_dtype, n) => Box::new(StructBuilder::with_capacity(
@@ -79,74 +169,123 @@ pub fn builder_with_capacity(dtype: &DType, capacity: usize) -> Box<dyn ArrayBui
             *n,
             capacity,
         )),
+        DType::FixedSizeList(dtype, size, n) => Box::new(FixedSizeListBuilder::with_capacity(
+            dtype.clone(),
+            *size,
+            *n,
+            capacity,
+        )),
         DType::Extension(ext_dtype) => {
             Box::new(ExtensionBuilder::with_capacity(ext_dtype.clone(), capacity))
         }
     }
 }
 
-pub trait ArrayBuilderExt: ArrayBuilder {
-    /// A generic function to append a scalar to the builder.
-    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
-        if !scalar.dtype().eq_ignore_nullability(self.dtype()) {
-            vortex_bail!(
-                "Builder has dtype {:?}, scalar has {:?}",
-                self.dtype(),
-                scalar.dtype()
-            )
+/// The bytes of the validity bitmap built so far, which is only allocated once a null is appended.
+fn validity_nbytes(validity: &NullBufferBuilder) -> usize {
+    validity.as_slice().map_or(0, <[u8]>::len)
+}
+
+/// The bytes allocated for the validity bitmap, including its unused capacity.
+fn validity_allocated_bytes(validity: &NullBufferBuilder) -> usize {
+    // Arrow reports the capacity of the bitmap in bits
+    validity.allocated_size() / 8
+}
+
+/// Finish a validity buffer for an array with the given nullability.
+fn finish_validity(
+    validity: &mut NullBufferBuilder,
+    nullability: Nullability,
+) -> VortexResult<Validity> {
+    Ok(match (validity.finish(), nullability) {
+        (None, Nullability::NonNullable) => Validity::NonNullable,
+        (Some(_), Nullability::NonNullable) => {
+            vortex_bail!("Non-nullable builder has null values")
         }
-        match scalar.dtype() {
-            DType::Null => self
-                .as_any_mut()
-                .downcast_mut::<NullBuilder>()
-                .ok_or_else(|| vortex_err!("Cannot append null scalar to non-null builder"))?
-                .append_null(),
-            DType::Bool(_) => self
-                .as_any_mut()
-                .downcast_mut::<BoolBuilder>()
-                .ok_or_else(|| vortex_err!("Cannot append bool scalar to non-bool builder"))?
-                .append_option(BoolScalar::try_from(scalar)?.value()),
-            DType::Primitive(ptype, ..) => {
-                match_each_native_ptype!(ptype, |$P| {
-                    self
-                    .as_any_mut()
-                    .downcast_mut::<PrimitiveBuilder<$P>>()
-                    .ok_or_else(|| {
-                        vortex_err!("Cannot append primitive scalar to non-primitive builder")
-                    })?
-                    .append_option(PrimitiveScalar::try_from(scalar)?.typed_value::<$P>())
-                })
+        (None, Nullability::Nullable) => Validity::AllValid,
+        (Some(nulls), Nullability::Nullable) => {
+            if nulls.null_count() == nulls.len() {
+                Validity::AllInvalid
+            } else {
+                Validity::Array(BoolArray::from(nulls.into_inner()).into_array())
             }
-            DType::Utf8(_) => self
-                .as_any_mut()
-                .downcast_mut::<Utf8Builder>()
-                .ok_or_else(|| vortex_err!("Cannot append utf8 scalar to non-utf8 builder"))?
-                .append_option(Utf8Scalar::try_from(scalar)?.value()),
-            DType::Binary(_) => self
-                .as_any_mut()
-                .downcast_mut::<BinaryBuilder>()
-                .ok_or_else(|| vortex_err!("Cannot append binary scalar to non-binary builder"))?
-                .append_option(BinaryScalar::try_from(scalar)?.value()),
-            DType::Struct(..) => self
-                .as_any_mut()
-                .downcast_mut::<StructBuilder>()
-                .ok_or_else(|| vortex_err!("Cannot append struct scalar to non-struct builder"))?
-                .append_value(StructScalar::try_from(scalar)?)?,
-            DType::List(..) => self
-                .as_any_mut()
-                .downcast_mut::<ListBuilder<u64>>()
-                .ok_or_else(|| vortex_err!("Cannot append list scalar to non-list builder"))?
-                .append_value(ListScalar::try_from(scalar)?)?,
-            DType::Extension(..) => self
-                .as_any_mut()
-                .downcast_mut::<ExtensionBuilder>()
-                .ok_or_else(|| {
-                    vortex_err!("Cannot append extension scalar to non-extension builder")
-                })?
-                .append_value(ExtScalar::try_from(scalar)?)?,
         }
-        Ok(())
+    })
+}
+
+/// Check that a scalar or array with `actual` dtype can be appended to a builder of `expected`.
+fn check_dtype(expected: &DType, actual: &DType) -> VortexResult<()> {
+    if !actual.eq_ignore_nullability(expected) {
+        vortex_bail!(MismatchedTypes: expected, actual);
     }
+    Ok(())
 }
 
-impl<T: ?Sized + ArrayBuilder> ArrayBuilderExt for T {}
+#[cfg(test)]
+mod tests {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{PrimitiveArray, StructArray, VarBinViewArray};
+    use crate::builders::builder_with_capacity;
+    use crate::compute::{scalar_at, slice};
+    use crate::{ArrayDType, IntoArrayData, IntoArrayVariant};
+
+    #[test]
+    fn default_append_scalar_appends_nulls() {
+        let mut builder = builder_with_capacity(&DType::Null, 2);
+        builder.reserve(2);
+        builder.append_scalar(&Scalar::null(DType::Null)).unwrap();
+        assert!(builder
+            .append_scalar(&Scalar::primitive(1i32, Nullability::NonNullable))
+            .is_err());
+        assert_eq!(builder.len(), 1);
+        assert_eq!(builder.nbytes(), 0);
+        assert_eq!(builder.finish().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn append_scalar_without_downcast() {
+        let dtype = DType::Primitive(PType::I32, Nullability::Nullable);
+        let mut builder = builder_with_capacity(&dtype, 2);
+        builder
+            .append_scalar(&Scalar::primitive(1i32, Nullability::NonNullable))
+            .unwrap();
+        builder.append_scalar(&Scalar::null(dtype)).unwrap();
+        assert!(builder.append_scalar(&Scalar::from("a")).is_err());
+
+        let array = builder.finish().unwrap().into_primitive().unwrap();
+        assert_eq!(array.maybe_null_slice::<i32>()[0], 1);
+        assert!(!array.validity().is_valid(1));
+    }
+
+    #[test]
+    fn extend_from_array() {
+        let strings =
+            VarBinViewArray::from_iter_nullable_str([Some("a"), None, Some("c")]).into_array();
+        let numbers =
+            PrimitiveArray::from_nullable_vec(vec![Some(1u8), Some(2), None]).into_array();
+        let array = StructArray::from_fields(&[("s", strings), ("n", numbers)])
+            .unwrap()
+            .into_array();
+
+        let mut builder = builder_with_capacity(array.dtype(), 5);
+        builder.extend_from_array(&array).unwrap();
+        builder
+            .extend_from_array(&slice(&array, 1, 3).unwrap())
+            .unwrap();
+        assert!(builder
+            .extend_from_array(&PrimitiveArray::from(vec![1u8]).into_array())
+            .is_err());
+
+        let built = builder.finish().unwrap();
+        assert_eq!(built.dtype(), array.dtype());
+        assert_eq!(built.len(), 5);
+        for (idx, expected) in [0, 1, 2, 1, 2].into_iter().enumerate() {
+            assert_eq!(
+                scalar_at(&built, idx).unwrap(),
+                scalar_at(&array, expected).unwrap()
+            );
+        }
+    }
+}
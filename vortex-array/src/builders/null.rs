@@ -4,8 +4,8 @@ use vortex_dtype::DType;
 use vortex_error::VortexResult;
 
 use crate::array::NullArray;
-use crate::builders::ArrayBuilder;
-use crate::{ArrayData, IntoArrayData};
+use crate::builders::{check_dtype, ArrayBuilder};
+use crate::{ArrayDType, ArrayData, IntoArrayData};
 
 pub struct NullBuilder {
     length: usize,
@@ -48,6 +48,12 @@ impl ArrayBuilder for NullBuilder {
         self.length += n;
     }
 
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        self.length += array.len();
+        Ok(())
+    }
+
     fn finish(&mut self) -> VortexResult<ArrayData> {
         Ok(NullArray::new(self.length).into_array())
     }
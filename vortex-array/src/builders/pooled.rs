@@ -0,0 +1,166 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::builders::{builder_with_capacity, ArrayBuilder};
+use crate::memory::{MemoryPool, MemoryReservation};
+use crate::ArrayData;
+
+/// A builder that reserves the memory allocated by another builder from a [`MemoryPool`].
+///
+/// Appending scalars and arrays fails once the pool runs out of room. The appends that can't fail
+/// reserve what they allocate even beyond the limit of the pool, so that the next append that can
+/// fail does.
+pub struct PooledBuilder {
+    builder: Box<dyn ArrayBuilder>,
+    reservation: MemoryReservation,
+}
+
+impl PooledBuilder {
+    /// Wrap a builder, reserving what it has already allocated.
+    pub fn try_new(
+        builder: Box<dyn ArrayBuilder>,
+        pool: Arc<dyn MemoryPool>,
+    ) -> VortexResult<Self> {
+        let mut reservation = MemoryReservation::new(pool);
+        reservation.try_resize(builder.allocated_bytes())?;
+        Ok(Self {
+            builder,
+            reservation,
+        })
+    }
+
+    /// A builder for the dtype with room for `capacity` values, reserved from the pool.
+    pub fn with_capacity(
+        dtype: &DType,
+        capacity: usize,
+        pool: Arc<dyn MemoryPool>,
+    ) -> VortexResult<Self> {
+        Self::try_new(builder_with_capacity(dtype, capacity), pool)
+    }
+
+    pub fn reservation(&self) -> &MemoryReservation {
+        &self.reservation
+    }
+
+    /// Finish the array, along with a reservation of the memory it holds, which is moved out of
+    /// the reservation of the builder.
+    pub fn finish_reserved(&mut self) -> VortexResult<(ArrayData, MemoryReservation)> {
+        let array = self.builder.finish()?;
+        let owned = array.allocated_bytes().owned();
+        self.reservation
+            .resize(owned + self.builder.allocated_bytes());
+        let reservation = self.reservation.split(owned)?;
+        Ok((array, reservation))
+    }
+
+    fn track(&mut self) {
+        self.reservation.resize(self.builder.allocated_bytes());
+    }
+
+    fn try_track(&mut self) -> VortexResult<()> {
+        let allocated = self.builder.allocated_bytes();
+        self.reservation.try_resize(allocated).inspect_err(|_| {
+            // The memory is allocated either way, so it must be accounted for.
+            self.reservation.resize(allocated)
+        })
+    }
+}
+
+impl ArrayBuilder for PooledBuilder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn dtype(&self) -> &DType {
+        self.builder.dtype()
+    }
+
+    fn len(&self) -> usize {
+        self.builder.len()
+    }
+
+    fn nbytes(&self) -> usize {
+        self.builder.nbytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.builder.allocated_bytes()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.builder.reserve(additional);
+        self.track();
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.builder.append_zeros(n);
+        self.track();
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.builder.append_nulls(n);
+        self.track();
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        self.builder.append_scalar(scalar)?;
+        self.try_track()
+    }
+
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        self.builder.extend_from_array(array)?;
+        self.try_track()
+    }
+
+    fn finish(&mut self) -> VortexResult<ArrayData> {
+        let array = self.builder.finish()?;
+        self.track();
+        Ok(array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::PrimitiveArray;
+    use crate::builders::{ArrayBuilder, PooledBuilder};
+    use crate::memory::{GreedyMemoryPool, MemoryPool};
+    use crate::IntoArrayData;
+
+    #[test]
+    fn pooled_builder_limit() {
+        let pool = Arc::new(GreedyMemoryPool::new(1024));
+        let dtype = DType::Primitive(PType::U64, Nullability::NonNullable);
+        let mut builder = PooledBuilder::with_capacity(&dtype, 0, pool.clone()).unwrap();
+
+        let values = PrimitiveArray::from(vec![1u64; 100]).into_array();
+        builder.extend_from_array(&values).unwrap();
+        assert_eq!(pool.reserved(), builder.allocated_bytes());
+        assert!(builder.extend_from_array(&values).is_err());
+        // What was allocated stays reserved, even beyond the limit.
+        assert_eq!(pool.reserved(), builder.allocated_bytes());
+
+        let (array, reservation) = builder.finish_reserved().unwrap();
+        assert_eq!(array.len(), 200);
+        assert_eq!(reservation.size(), array.allocated_bytes().owned());
+        assert_eq!(
+            pool.reserved(),
+            reservation.size() + builder.allocated_bytes()
+        );
+
+        drop(reservation);
+        drop(builder);
+        assert_eq!(pool.reserved(), 0);
+    }
+}
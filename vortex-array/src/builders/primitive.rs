@@ -2,21 +2,30 @@ use std::any::Any;
 use std::iter;
 
 use arrow_buffer::NullBufferBuilder;
+use vortex_dtype::half::f16;
 use vortex_dtype::{DType, NativePType, Nullability};
-use vortex_error::{vortex_bail, VortexResult};
+use vortex_error::VortexResult;
+use vortex_scalar::{FromPrimitiveOrF16, PrimitiveScalar, Scalar};
 
-use crate::array::{BoolArray, PrimitiveArray};
-use crate::builders::ArrayBuilder;
-use crate::validity::Validity;
-use crate::{ArrayData, IntoArrayData};
+use crate::array::PrimitiveArray;
+use crate::builders::{
+    check_dtype, finish_validity, validity_allocated_bytes, validity_nbytes, ArrayBuilder,
+    GrowthStrategy,
+};
+use crate::validity::{ArrayValidity, LogicalValidity};
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
+
+/// A builder for half-precision floats.
+pub type F16Builder = PrimitiveBuilder<f16>;
 
 pub struct PrimitiveBuilder<T: NativePType> {
     values: Vec<T>,
     validity: NullBufferBuilder,
     dtype: DType,
+    growth: GrowthStrategy,
 }
 
-impl<T: NativePType + 'static> PrimitiveBuilder<T> {
+impl<T: NativePType + FromPrimitiveOrF16 + 'static> PrimitiveBuilder<T> {
     pub fn new(nullability: Nullability) -> Self {
         Self::with_capacity(nullability, 1024) // Same as Arrow builders
     }
@@ -26,26 +35,31 @@ impl<T: NativePType + 'static> PrimitiveBuilder<T> {
             values: Vec::with_capacity(capacity),
             validity: NullBufferBuilder::new(capacity),
             dtype: DType::Primitive(T::PTYPE, nullability),
+            growth: GrowthStrategy::default(),
         }
     }
 
+    /// Set how the builder grows its buffers once they run out of capacity.
+    pub fn with_growth_strategy(mut self, growth: GrowthStrategy) -> Self {
+        self.growth = growth;
+        self
+    }
+
     pub fn append_value(&mut self, value: T) {
+        self.growth.grow(&mut self.values, 1);
         self.values.push(value);
         self.validity.append(true);
     }
 
     pub fn append_option(&mut self, value: Option<T>) {
         match value {
-            Some(value) => {
-                self.values.push(value);
-                self.validity.append(true);
-            }
+            Some(value) => self.append_value(value),
             None => self.append_null(),
         }
     }
 }
 
-impl<T: NativePType + 'static> ArrayBuilder for PrimitiveBuilder<T> {
+impl<T: NativePType + FromPrimitiveOrF16 + 'static> ArrayBuilder for PrimitiveBuilder<T> {
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -62,32 +76,112 @@ impl<T: NativePType + 'static> ArrayBuilder for PrimitiveBuilder<T> {
         self.values.len()
     }
 
+    fn nbytes(&self) -> usize {
+        self.values.len() * size_of::<T>() + validity_nbytes(&self.validity)
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.values.capacity() * size_of::<T>() + validity_allocated_bytes(&self.validity)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+    }
+
     fn append_zeros(&mut self, n: usize) {
+        self.growth.grow(&mut self.values, n);
         self.values.extend(iter::repeat(T::default()).take(n));
         self.validity.append_n_non_nulls(n);
     }
 
     fn append_nulls(&mut self, n: usize) {
+        self.growth.grow(&mut self.values, n);
         self.values.extend(iter::repeat(T::default()).take(n));
         self.validity.append_n_nulls(n);
     }
 
-    fn finish(&mut self) -> VortexResult<ArrayData> {
-        let validity = match (self.validity.finish(), self.dtype().nullability()) {
-            (None, Nullability::NonNullable) => Validity::NonNullable,
-            (Some(_), Nullability::NonNullable) => {
-                vortex_bail!("Non-nullable builder has null values")
-            }
-            (None, Nullability::Nullable) => Validity::AllValid,
-            (Some(nulls), Nullability::Nullable) => {
-                if nulls.null_count() == nulls.len() {
-                    Validity::AllInvalid
-                } else {
-                    Validity::Array(BoolArray::from(nulls.into_inner()).into_array())
-                }
-            }
-        };
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        check_dtype(self.dtype(), scalar.dtype())?;
+        self.append_option(PrimitiveScalar::try_from(scalar)?.as_::<T>()?);
+        Ok(())
+    }
 
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        let primitive = array.clone().into_primitive()?;
+        self.growth.grow(&mut self.values, array.len());
+        self.values
+            .extend_from_slice(primitive.maybe_null_slice::<T>());
+        match primitive.logical_validity() {
+            LogicalValidity::AllValid(n) => self.validity.append_n_non_nulls(n),
+            LogicalValidity::AllInvalid(n) => self.validity.append_n_nulls(n),
+            LogicalValidity::Array(validity) => validity
+                .into_bool()?
+                .boolean_buffer()
+                .iter()
+                .for_each(|valid| self.validity.append(valid)),
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> VortexResult<ArrayData> {
+        let nullability = self.dtype.nullability();
+        let validity = finish_validity(&mut self.validity, nullability)?;
         Ok(PrimitiveArray::from_vec(std::mem::take(&mut self.values), validity).into_array())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use vortex_dtype::half::f16;
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::builders::{
+        builder_with_capacity, ArrayBuilder, F16Builder, GrowthStrategy, PrimitiveBuilder,
+    };
+    use crate::{ArrayDType, IntoArrayVariant};
+
+    #[test]
+    fn test_f16_builder() {
+        let mut builder = F16Builder::new(Nullability::Nullable);
+        builder.append_value(f16::from_f32(1.5));
+        builder.append_null();
+        builder
+            .append_scalar(&Scalar::from(f16::from_f32(-2.0)))
+            .unwrap();
+
+        let array = builder.finish().unwrap();
+        assert_eq!(
+            array.dtype(),
+            &DType::Primitive(PType::F16, Nullability::Nullable)
+        );
+        let primitive = array.into_primitive().unwrap();
+        assert_eq!(primitive.maybe_null_slice::<f16>()[0], f16::from_f32(1.5));
+        assert!(!primitive.validity().is_valid(1));
+        assert_eq!(primitive.maybe_null_slice::<f16>()[2], f16::from_f32(-2.0));
+    }
+
+    #[test]
+    fn test_f16_from_dtype() {
+        let builder =
+            builder_with_capacity(&DType::Primitive(PType::F16, Nullability::NonNullable), 8);
+        assert!(builder.as_any().downcast_ref::<F16Builder>().is_some());
+    }
+
+    #[test]
+    fn test_linear_growth() {
+        let mut builder = PrimitiveBuilder::<i32>::with_capacity(Nullability::NonNullable, 0)
+            .with_growth_strategy(GrowthStrategy::Linear(100));
+        builder.append_value(1);
+        builder.append_zeros(99);
+        // The first append reserved room for 100 values, so the buffer is exactly full.
+        assert_eq!(builder.allocated_bytes(), builder.nbytes());
+
+        builder.append_value(2);
+        assert_eq!(
+            builder.allocated_bytes() - builder.nbytes(),
+            99 * size_of::<i32>()
+        );
+    }
+}
@@ -3,12 +3,12 @@ use std::any::Any;
 use itertools::Itertools;
 use vortex_dtype::{DType, Nullability, StructDType};
 use vortex_error::{vortex_bail, VortexResult};
-use vortex_scalar::StructScalar;
+use vortex_scalar::{Scalar, StructScalar};
 
 use crate::array::StructArray;
-use crate::builders::{builder_with_capacity, ArrayBuilder, ArrayBuilderExt, BoolBuilder};
-use crate::validity::Validity;
-use crate::{ArrayData, IntoArrayData};
+use crate::builders::{builder_with_capacity, check_dtype, ArrayBuilder, BoolBuilder};
+use crate::validity::{ArrayValidity, LogicalValidity, Validity};
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
 
 pub struct StructBuilder {
     builders: Vec<Box<dyn ArrayBuilder>>,
@@ -78,6 +78,25 @@ impl ArrayBuilder for StructBuilder {
         self.validity.len()
     }
 
+    fn nbytes(&self) -> usize {
+        self.builders.iter().map(|b| b.nbytes()).sum::<usize>() + self.validity.nbytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.builders
+            .iter()
+            .map(|b| b.allocated_bytes())
+            .sum::<usize>()
+            + self.validity.allocated_bytes()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.builders
+            .iter_mut()
+            .for_each(|builder| builder.reserve(additional));
+        self.validity.reserve(additional);
+    }
+
     fn append_zeros(&mut self, n: usize) {
         self.builders
             .iter_mut()
@@ -91,7 +110,30 @@ impl ArrayBuilder for StructBuilder {
             // We push zero values into our children when appending a null in case the children are
             // themselves non-nullable.
             .for_each(|builder| builder.append_zeros(n));
-        self.validity.append_value(false);
+        self.validity.append_values(false, n);
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        check_dtype(self.dtype(), scalar.dtype())?;
+        self.append_value(StructScalar::try_from(scalar)?)
+    }
+
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        let struct_array = array.clone().into_struct()?;
+        for (builder, field) in self.builders.iter_mut().zip_eq(struct_array.children()) {
+            builder.extend_from_array(&field)?;
+        }
+        match struct_array.logical_validity() {
+            LogicalValidity::AllValid(n) => self.validity.append_values(true, n),
+            LogicalValidity::AllInvalid(n) => self.validity.append_values(false, n),
+            LogicalValidity::Array(validity) => validity
+                .into_bool()?
+                .boolean_buffer()
+                .iter()
+                .for_each(|valid| self.validity.append_value(valid)),
+        }
+        Ok(())
     }
 
     fn finish(&mut self) -> VortexResult<ArrayData> {
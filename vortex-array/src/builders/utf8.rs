@@ -1,35 +1,48 @@
 use std::any::Any;
 
-use arrow_array::builder::{ArrayBuilder as _, StringViewBuilder};
-use arrow_array::Array;
 use vortex_dtype::{DType, Nullability};
-use vortex_error::{vortex_bail, VortexResult};
+use vortex_error::VortexResult;
+use vortex_scalar::{Scalar, Utf8Scalar};
 
-use crate::arrow::FromArrowArray;
-use crate::builders::ArrayBuilder;
-use crate::ArrayData;
+use crate::array::VarBinViewArray;
+use crate::builders::views::ViewsBuilder;
+use crate::builders::{check_dtype, ArrayBuilder, GrowthStrategy};
+use crate::{ArrayDType, ArrayData, IntoArrayVariant};
 
 pub struct Utf8Builder {
-    inner: StringViewBuilder,
-    nullability: Nullability,
+    inner: ViewsBuilder,
     dtype: DType,
 }
 
 impl Utf8Builder {
     pub fn with_capacity(nullability: Nullability, capacity: usize) -> Self {
         Self {
-            inner: StringViewBuilder::with_capacity(capacity),
-            nullability,
+            inner: ViewsBuilder::with_capacity(capacity),
             dtype: DType::Utf8(nullability),
         }
     }
 
+    /// Set how the builder grows its buffers once they run out of capacity.
+    pub fn with_growth_strategy(mut self, growth: GrowthStrategy) -> Self {
+        self.inner.set_growth_strategy(growth);
+        self
+    }
+
     pub fn append_value<S: AsRef<str>>(&mut self, value: S) {
-        self.inner.append_value(value.as_ref())
+        self.inner.append_value(value.as_ref().as_bytes())
     }
 
     pub fn append_option<S: AsRef<str>>(&mut self, value: Option<S>) {
-        self.inner.append_option(value.as_ref())
+        match value {
+            Some(value) => self.append_value(value),
+            None => self.append_null(),
+        }
+    }
+
+    /// Append the values of a [`VarBinViewArray`], copying only the bytes its views reference.
+    pub(crate) fn extend_from_varbinview(&mut self, array: &VarBinViewArray) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        self.inner.extend_from_varbinview(array)
     }
 }
 
@@ -50,25 +63,122 @@ impl ArrayBuilder for Utf8Builder {
         self.inner.len()
     }
 
+    fn nbytes(&self) -> usize {
+        self.inner.nbytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.inner.allocated_bytes()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
     fn append_zeros(&mut self, n: usize) {
-        for _ in 0..n {
-            self.inner.append_value("")
-        }
+        self.inner.append_values(&[], n)
     }
 
     fn append_nulls(&mut self, n: usize) {
-        for _ in 0..n {
-            self.inner.append_null()
-        }
+        self.inner.append_nulls(n)
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        check_dtype(self.dtype(), scalar.dtype())?;
+        self.append_option(Utf8Scalar::try_from(scalar)?.value());
+        Ok(())
+    }
+
+    fn extend_from_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        let varbinview = array.clone().into_varbinview()?;
+        self.inner.extend_from_varbinview(&varbinview)
     }
 
     fn finish(&mut self) -> VortexResult<ArrayData> {
-        let arrow = self.inner.finish();
+        self.inner.finish(self.dtype.clone())
+    }
+}
 
-        if !self.dtype().is_nullable() && arrow.null_count() > 0 {
-            vortex_bail!("Non-nullable builder has null values");
-        }
+#[cfg(test)]
+mod tests {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::VarBinViewArray;
+    use crate::builders::{ArrayBuilder, Utf8Builder};
+    use crate::compute::slice;
+    use crate::{ArrayDType, ArrayLen, IntoArrayData, IntoArrayVariant};
+
+    #[test]
+    fn test_utf8_values() {
+        let long = "a string too long to be inlined".repeat(1000);
+        let mut builder = Utf8Builder::with_capacity(Nullability::Nullable, 0);
+        builder.append_value("short");
+        builder.append_null();
+        builder.append_value(&long);
+        builder.append_value(&long);
+        builder.append_zero();
+
+        let varbinview = builder.finish().unwrap().into_varbinview().unwrap();
+        assert_eq!(varbinview.dtype(), &DType::Utf8(Nullability::Nullable));
+        assert_eq!(varbinview.bytes_at(0).unwrap(), b"short");
+        assert!(!varbinview.validity().is_valid(1));
+        assert_eq!(varbinview.bytes_at(2).unwrap(), long.as_bytes());
+        assert_eq!(varbinview.bytes_at(3).unwrap(), long.as_bytes());
+        assert!(varbinview.bytes_at(4).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_extend_from_array() {
+        let array = VarBinViewArray::from_iter_nullable_str([
+            Some("a value that is not inlined"),
+            None,
+            Some("inlined"),
+        ])
+        .into_array();
+
+        let mut builder = Utf8Builder::with_capacity(Nullability::Nullable, 0);
+        builder.append_value("another value that is not inlined");
+        builder
+            .extend_from_array(&slice(&array, 0, 3).unwrap())
+            .unwrap();
+        builder.reserve(100);
+        assert!(builder.allocated_bytes() >= builder.nbytes());
+
+        let varbinview = builder.finish().unwrap().into_varbinview().unwrap();
+        assert_eq!(varbinview.len(), 4);
+        assert_eq!(
+            varbinview.bytes_at(0).unwrap(),
+            b"another value that is not inlined"
+        );
+        assert_eq!(
+            varbinview.bytes_at(1).unwrap(),
+            b"a value that is not inlined"
+        );
+        assert!(!varbinview.validity().is_valid(2));
+        assert_eq!(varbinview.bytes_at(3).unwrap(), b"inlined");
+    }
+
+    #[test]
+    fn test_extend_copies_referenced_bytes() {
+        let long = "a string too long to be inlined";
+        let array = VarBinViewArray::from_iter_str([long; 100]).into_array();
+        let buffer_bytes = |a: &VarBinViewArray| a.buffers().map(|b| b.len()).sum::<usize>();
+
+        let mut builder = Utf8Builder::with_capacity(Nullability::NonNullable, 0);
+        builder
+            .extend_from_array(&slice(&array, 10, 12).unwrap())
+            .unwrap();
+        let sliced = builder.finish().unwrap().into_varbinview().unwrap();
+        assert_eq!(buffer_bytes(&sliced), 2 * long.len());
+        assert_eq!(sliced.bytes_at(1).unwrap(), long.as_bytes());
 
-        Ok(ArrayData::from_arrow(&arrow, self.nullability.into()))
+        let mut builder = Utf8Builder::with_capacity(Nullability::NonNullable, 0);
+        builder.extend_from_array(&array).unwrap();
+        let whole = builder.finish().unwrap().into_varbinview().unwrap();
+        assert_eq!(
+            buffer_bytes(&whole),
+            buffer_bytes(&array.into_varbinview().unwrap())
+        );
     }
 }
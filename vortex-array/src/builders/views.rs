@@ -0,0 +1,200 @@
+use arrow_buffer::NullBufferBuilder;
+use vortex_buffer::Buffer;
+use vortex_dtype::{DType, PType};
+use vortex_error::{VortexExpect, VortexResult};
+
+use crate::array::{BinaryView, PrimitiveArray, VarBinViewArray};
+use crate::builders::{finish_validity, validity_allocated_bytes, validity_nbytes, GrowthStrategy};
+use crate::validity::{ArrayValidity, LogicalValidity, Validity};
+use crate::{ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant};
+
+/// The size of the first data block, doubling for every block after it with exponential growth.
+const MIN_BLOCK_SIZE: usize = 8 * 1024;
+/// The maximum size of a data block, unless a single value is larger.
+const MAX_BLOCK_SIZE: usize = 2 * 1024 * 1024;
+
+/// The views, data blocks and validity shared by the utf8 and binary builders, finishing into a
+/// [VarBinViewArray].
+pub(crate) struct ViewsBuilder {
+    views: Vec<BinaryView>,
+    validity: NullBufferBuilder,
+    completed: Vec<Buffer>,
+    in_progress: Vec<u8>,
+    growth: GrowthStrategy,
+}
+
+impl ViewsBuilder {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            views: Vec::with_capacity(capacity),
+            validity: NullBufferBuilder::new(capacity),
+            completed: Vec::new(),
+            in_progress: Vec::new(),
+            growth: GrowthStrategy::default(),
+        }
+    }
+
+    pub fn set_growth_strategy(&mut self, growth: GrowthStrategy) {
+        self.growth = growth;
+    }
+
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    pub fn nbytes(&self) -> usize {
+        self.views.len() * size_of::<BinaryView>()
+            + self.completed.iter().map(Buffer::len).sum::<usize>()
+            + self.in_progress.len()
+            + validity_nbytes(&self.validity)
+    }
+
+    pub fn allocated_bytes(&self) -> usize {
+        self.views.capacity() * size_of::<BinaryView>()
+            + self.completed.iter().map(Buffer::len).sum::<usize>()
+            + self.in_progress.capacity()
+            + validity_allocated_bytes(&self.validity)
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.views.reserve(additional);
+    }
+
+    pub fn append_value(&mut self, value: &[u8]) {
+        self.growth.grow(&mut self.views, 1);
+        let view = self.copy_value(value);
+        self.views.push(view);
+        self.validity.append_non_null();
+    }
+
+    /// The view of `value`, whose bytes are copied into the block in progress unless inlined.
+    fn copy_value(&mut self, value: &[u8]) -> BinaryView {
+        if value.len() <= BinaryView::MAX_INLINED_SIZE {
+            return BinaryView::new_inlined(value);
+        }
+        if self.in_progress.capacity() - self.in_progress.len() < value.len() {
+            self.flush_in_progress();
+            self.in_progress = Vec::with_capacity(self.next_block_size().max(value.len()));
+        }
+        let view = BinaryView::new_view(
+            u32::try_from(value.len()).vortex_expect("value must be within 32-bit range"),
+            value[..4]
+                .try_into()
+                .vortex_expect("value has at least 4 bytes"),
+            u32::try_from(self.completed.len()).vortex_expect("too many data blocks"),
+            u32::try_from(self.in_progress.len()).vortex_expect("block within 32-bit range"),
+        );
+        self.in_progress.extend_from_slice(value);
+        view
+    }
+
+    pub fn append_values(&mut self, value: &[u8], n: usize) {
+        self.growth.grow(&mut self.views, n);
+        for _ in 0..n {
+            self.append_value(value);
+        }
+    }
+
+    pub fn append_nulls(&mut self, n: usize) {
+        self.growth.grow(&mut self.views, n);
+        self.views
+            .extend(std::iter::repeat(BinaryView::from(0u128)).take(n));
+        self.validity.append_n_nulls(n);
+    }
+
+    /// Append the values of `array`, copying only the bytes that its views reference.
+    ///
+    /// The data blocks of an array whose views reference all of their bytes, e.g. one that isn't
+    /// sliced or filtered, are taken as they are rather than copied value by value.
+    pub fn extend_from_varbinview(&mut self, array: &VarBinViewArray) -> VortexResult<()> {
+        let blocks = array
+            .buffers()
+            .map(|block| Ok(block.into_primitive()?.into_buffer()))
+            .collect::<VortexResult<Vec<Buffer>>>()?;
+        let block_bytes = blocks.iter().map(Buffer::len).sum::<usize>();
+
+        self.growth.grow(&mut self.views, array.len());
+        if array.referenced_bytes()? < block_bytes {
+            for view in array.binary_views()? {
+                let view = if view.is_inlined() {
+                    view
+                } else {
+                    let view_ref = view.as_view();
+                    let start = view_ref.offset() as usize;
+                    let block = &blocks[view_ref.buffer_index() as usize];
+                    self.copy_value(&block[start..start + view.len() as usize])
+                };
+                self.views.push(view);
+            }
+        } else {
+            self.flush_in_progress();
+            let block_offset =
+                u32::try_from(self.completed.len()).vortex_expect("too many data blocks");
+            self.completed.extend(blocks);
+            self.views.extend(array.binary_views()?.map(|view| {
+                if view.is_inlined() {
+                    view
+                } else {
+                    let view_ref = view.as_view();
+                    BinaryView::new_view(
+                        view.len(),
+                        *view_ref.prefix(),
+                        view_ref.buffer_index() + block_offset,
+                        view_ref.offset(),
+                    )
+                }
+            }));
+        }
+
+        match array.logical_validity() {
+            LogicalValidity::AllValid(n) => self.validity.append_n_non_nulls(n),
+            LogicalValidity::AllInvalid(n) => self.validity.append_n_nulls(n),
+            LogicalValidity::Array(validity) => validity
+                .into_bool()?
+                .boolean_buffer()
+                .iter()
+                .for_each(|valid| self.validity.append(valid)),
+        }
+        Ok(())
+    }
+
+    pub fn finish(&mut self, dtype: DType) -> VortexResult<ArrayData> {
+        self.flush_in_progress();
+        let validity = finish_validity(&mut self.validity, dtype.nullability())?;
+
+        let views = std::mem::take(&mut self.views)
+            .into_iter()
+            .flat_map(|view| view.as_u128().to_le_bytes())
+            .collect::<Vec<u8>>();
+        let buffers = std::mem::take(&mut self.completed)
+            .into_iter()
+            .map(|buffer| {
+                PrimitiveArray::new(buffer, PType::U8, Validity::NonNullable).into_array()
+            })
+            .collect();
+
+        VarBinViewArray::try_new(
+            PrimitiveArray::from(views).into_array(),
+            buffers,
+            dtype,
+            validity,
+        )
+        .map(IntoArrayData::into_array)
+    }
+
+    fn flush_in_progress(&mut self) {
+        if !self.in_progress.is_empty() {
+            self.completed
+                .push(Buffer::from(std::mem::take(&mut self.in_progress)));
+        }
+    }
+
+    fn next_block_size(&self) -> usize {
+        match self.growth {
+            GrowthStrategy::Exponential => {
+                (MIN_BLOCK_SIZE << self.completed.len().min(8)).min(MAX_BLOCK_SIZE)
+            }
+            GrowthStrategy::Linear(_) => MIN_BLOCK_SIZE,
+        }
+    }
+}
@@ -13,15 +13,16 @@ use arrow_array::{
 use arrow_buffer::ScalarBuffer;
 use arrow_schema::{Field, FieldRef, Fields};
 use vortex_datetime_dtype::{is_temporal_ext_type, TemporalMetadata, TimeUnit};
-use vortex_dtype::{DType, NativePType, PType};
+use vortex_dtype::{DType, DecimalValueType, NativePType, PType};
 use vortex_error::{vortex_bail, VortexError, VortexResult};
 
 use crate::array::{
-    varbinview_as_arrow, BoolArray, ExtensionArray, ListArray, NullArray, PrimitiveArray,
-    StructArray, TemporalArray, VarBinViewArray,
+    varbinview_as_arrow, BoolArray, DecimalArray, ExtensionArray, FixedSizeListArray, ListArray,
+    NullArray, PrimitiveArray, StructArray, TemporalArray, VarBinViewArray,
 };
 use crate::arrow::wrappers::as_offset_buffer;
 use crate::arrow::{infer_data_type, FromArrowArray};
+use crate::builders::ArrayBuilder;
 use crate::compute::try_cast;
 use crate::encoding::Encoding;
 use crate::stats::ArrayStatistics;
@@ -57,9 +58,11 @@ pub enum Canonical {
     Null(NullArray),
     Bool(BoolArray),
     Primitive(PrimitiveArray),
+    Decimal(DecimalArray),
     Struct(StructArray),
     // TODO(joe): maybe this should be a ListView, however this will be annoying in spiral
     List(ListArray),
+    FixedSizeList(FixedSizeListArray),
     VarBinView(VarBinViewArray),
     Extension(ExtensionArray),
 }
@@ -75,8 +78,10 @@ impl Canonical {
             Canonical::Null(a) => null_to_arrow(a)?,
             Canonical::Bool(a) => bool_to_arrow(a)?,
             Canonical::Primitive(a) => primitive_to_arrow(a)?,
+            Canonical::Decimal(a) => decimal_to_arrow(a)?,
             Canonical::Struct(a) => struct_to_arrow(a)?,
             Canonical::List(a) => list_to_arrow(a)?,
+            Canonical::FixedSizeList(a) => fixed_size_list_to_arrow(a)?,
             Canonical::VarBinView(a) => varbinview_as_arrow(&a),
             Canonical::Extension(a) => {
                 if is_temporal_ext_type(a.id()) {
@@ -127,6 +132,13 @@ impl Canonical {
         }
     }
 
+    pub fn into_decimal(self) -> VortexResult<DecimalArray> {
+        match self {
+            Canonical::Decimal(a) => Ok(a),
+            _ => vortex_bail!("Cannot unwrap DecimalArray from {:?}", &self),
+        }
+    }
+
     pub fn into_struct(self) -> VortexResult<StructArray> {
         match self {
             Canonical::Struct(a) => Ok(a),
@@ -141,6 +153,13 @@ impl Canonical {
         }
     }
 
+    pub fn into_fixed_size_list(self) -> VortexResult<FixedSizeListArray> {
+        match self {
+            Canonical::FixedSizeList(a) => Ok(a),
+            _ => vortex_bail!("Cannot unwrap FixedSizeListArray from {:?}", &self),
+        }
+    }
+
     pub fn into_varbinview(self) -> VortexResult<VarBinViewArray> {
         match self {
             Canonical::VarBinView(a) => Ok(a),
@@ -192,6 +211,25 @@ fn primitive_to_arrow(primitive_array: PrimitiveArray) -> VortexResult<ArrayRef>
     })
 }
 
+fn decimal_to_arrow(decimal_array: DecimalArray) -> VortexResult<ArrayRef> {
+    fn as_arrow_decimal<T: DecimalType>(array: &DecimalArray) -> VortexResult<ArrayRef> {
+        let decimal_dtype = array.decimal_dtype();
+        let buffer = array.buffer().clone().aligned(align_of::<T::Native>());
+        Ok(Arc::new(
+            ArrowPrimitiveArray::<T>::new(
+                ScalarBuffer::<T::Native>::new(buffer.into_arrow(), 0, array.len()),
+                array.logical_validity().to_null_buffer()?,
+            )
+            .with_precision_and_scale(decimal_dtype.precision(), decimal_dtype.scale())?,
+        ))
+    }
+
+    match decimal_array.value_type() {
+        DecimalValueType::I128 => as_arrow_decimal::<Decimal128Type>(&decimal_array),
+        DecimalValueType::I256 => as_arrow_decimal::<Decimal256Type>(&decimal_array),
+    }
+}
+
 fn struct_to_arrow(struct_array: StructArray) -> VortexResult<ArrayRef> {
     let field_arrays = struct_array
         .names()
@@ -278,6 +316,23 @@ fn list_to_arrow(list: ListArray) -> VortexResult<ArrayRef> {
     })
 }
 
+fn fixed_size_list_to_arrow(list: FixedSizeListArray) -> VortexResult<ArrayRef> {
+    let elements = list.elements();
+    let field_ref = FieldRef::new(Field::new_list_field(
+        infer_data_type(elements.dtype())?,
+        elements.dtype().is_nullable(),
+    ));
+    let size = i32::try_from(list.list_size())?;
+    let nulls = list.logical_validity().to_null_buffer()?;
+
+    Ok(Arc::new(arrow_array::FixedSizeListArray::try_new(
+        field_ref,
+        size,
+        elements.into_arrow()?,
+        nulls,
+    )?))
+}
+
 fn temporal_to_arrow(temporal_array: TemporalArray) -> VortexResult<ArrayRef> {
     macro_rules! extract_temporal_values {
         ($values:expr, $prim:ty) => {{
@@ -371,6 +426,17 @@ pub trait IntoCanonical {
     {
         self.into_canonical()?.into_arrow()
     }
+
+    /// Canonicalize the array by appending its values to `builder`.
+    ///
+    /// This allows decoding many arrays into one shared output, without allocating a canonical
+    /// array for each of them first where the encoding supports it.
+    fn canonicalize_into(self, builder: &mut dyn ArrayBuilder) -> VortexResult<()>
+    where
+        Self: Sized,
+    {
+        builder.extend_from_array(&ArrayData::from(self.into_canonical()?))
+    }
 }
 
 /// Encoding VTable for canonicalizing an array.
@@ -379,6 +445,12 @@ pub trait IntoCanonicalVTable {
     fn into_canonical(&self, array: ArrayData) -> VortexResult<Canonical>;
 
     fn into_arrow(&self, array: ArrayData) -> VortexResult<ArrayRef>;
+
+    fn canonicalize_into(
+        &self,
+        array: ArrayData,
+        builder: &mut dyn ArrayBuilder,
+    ) -> VortexResult<()>;
 }
 
 /// Implement the [IntoCanonicalVTable] for all encodings with arrays implementing [IntoCanonical].
@@ -398,6 +470,14 @@ where
     fn into_arrow(&self, array: ArrayData) -> VortexResult<ArrayRef> {
         E::Array::try_from(array)?.into_arrow()
     }
+
+    fn canonicalize_into(
+        &self,
+        array: ArrayData,
+        builder: &mut dyn ArrayBuilder,
+    ) -> VortexResult<()> {
+        E::Array::try_from(array)?.canonicalize_into(builder)
+    }
 }
 
 /// Trait for types that can be converted from an owned type into an owned array variant.
@@ -412,10 +492,14 @@ pub trait IntoArrayVariant {
 
     fn into_primitive(self) -> VortexResult<PrimitiveArray>;
 
+    fn into_decimal(self) -> VortexResult<DecimalArray>;
+
     fn into_struct(self) -> VortexResult<StructArray>;
 
     fn into_list(self) -> VortexResult<ListArray>;
 
+    fn into_fixed_size_list(self) -> VortexResult<FixedSizeListArray>;
+
     fn into_varbinview(self) -> VortexResult<VarBinViewArray>;
 
     fn into_extension(self) -> VortexResult<ExtensionArray>;
@@ -437,6 +521,10 @@ where
         self.into_canonical()?.into_primitive()
     }
 
+    fn into_decimal(self) -> VortexResult<DecimalArray> {
+        self.into_canonical()?.into_decimal()
+    }
+
     fn into_struct(self) -> VortexResult<StructArray> {
         self.into_canonical()?.into_struct()
     }
@@ -445,6 +533,10 @@ where
         self.into_canonical()?.into_list()
     }
 
+    fn into_fixed_size_list(self) -> VortexResult<FixedSizeListArray> {
+        self.into_canonical()?.into_fixed_size_list()
+    }
+
     fn into_varbinview(self) -> VortexResult<VarBinViewArray> {
         self.into_canonical()?.into_varbinview()
     }
@@ -470,6 +562,10 @@ impl IntoCanonical for ArrayData {
     fn into_arrow(self) -> VortexResult<ArrayRef> {
         self.encoding().into_arrow(self)
     }
+
+    fn canonicalize_into(self, builder: &mut dyn ArrayBuilder) -> VortexResult<()> {
+        self.encoding().canonicalize_into(self, builder)
+    }
 }
 
 /// This conversion is always "free" and should not touch underlying data. All it does is create an
@@ -483,8 +579,10 @@ impl From<Canonical> for ArrayData {
             Canonical::Null(a) => a.into_array(),
             Canonical::Bool(a) => a.into_array(),
             Canonical::Primitive(a) => a.into_array(),
+            Canonical::Decimal(a) => a.into_array(),
             Canonical::Struct(a) => a.into_array(),
             Canonical::List(a) => a.into_array(),
+            Canonical::FixedSizeList(a) => a.into_array(),
             Canonical::VarBinView(a) => a.into_array(),
             Canonical::Extension(a) => a.into_array(),
         }
@@ -497,8 +595,10 @@ impl AsRef<ArrayData> for Canonical {
             Canonical::Null(a) => a.as_ref(),
             Canonical::Bool(a) => a.as_ref(),
             Canonical::Primitive(a) => a.as_ref(),
+            Canonical::Decimal(a) => a.as_ref(),
             Canonical::Struct(a) => a.as_ref(),
             Canonical::List(a) => a.as_ref(),
+            Canonical::FixedSizeList(a) => a.as_ref(),
             Canonical::VarBinView(a) => a.as_ref(),
             Canonical::Extension(a) => a.as_ref(),
         }
@@ -511,8 +611,10 @@ impl IntoArrayData for Canonical {
             Canonical::Null(a) => a.into_array(),
             Canonical::Bool(a) => a.into_array(),
             Canonical::Primitive(a) => a.into_array(),
+            Canonical::Decimal(a) => a.into_array(),
             Canonical::Struct(a) => a.into_array(),
             Canonical::List(a) => a.into_array(),
+            Canonical::FixedSizeList(a) => a.into_array(),
             Canonical::VarBinView(a) => a.into_array(),
             Canonical::Extension(a) => a.into_array(),
         }
@@ -524,17 +626,19 @@ mod test {
     use std::sync::Arc;
 
     use arrow_array::cast::AsArray;
-    use arrow_array::types::{Int32Type, Int64Type, UInt64Type};
+    use arrow_array::types::{Decimal128Type, Decimal256Type, Int32Type, Int64Type, UInt64Type};
     use arrow_array::{
+        Array, ArrayRef, Decimal128Array, Decimal256Array, FixedSizeListArray,
         PrimitiveArray as ArrowPrimitiveArray, StringViewArray, StructArray as ArrowStructArray,
     };
-    use arrow_buffer::NullBufferBuilder;
+    use arrow_buffer::{i256, NullBufferBuilder};
     use arrow_schema::{DataType, Field};
+    use vortex_dtype::{DType, DecimalDType, Nullability};
 
     use crate::array::{PrimitiveArray, SparseArray, StructArray};
     use crate::arrow::FromArrowArray;
     use crate::validity::Validity;
-    use crate::{ArrayData, IntoArrayData, IntoCanonical};
+    use crate::{ArrayDType, ArrayData, IntoArrayData, IntoCanonical};
 
     #[test]
     fn test_canonicalize_nested_struct() {
@@ -641,4 +745,103 @@ mod test {
             vortex_struct.into_arrow().unwrap().as_struct()
         );
     }
+
+    #[test]
+    fn roundtrip_string_view_shares_buffers() {
+        let strings = StringViewArray::from_iter(vec![
+            Some("a string that is too long to be inlined"),
+            None,
+            Some("short"),
+            Some("another string that is too long to be inlined"),
+        ]);
+
+        let vortex_array = ArrayData::from_arrow(&strings, true);
+        let arrow_array = vortex_array.into_arrow().unwrap();
+        let roundtrip = arrow_array.as_string_view();
+
+        assert_eq!(roundtrip, &strings);
+        assert_eq!(
+            roundtrip.views().inner().as_ptr(),
+            strings.views().inner().as_ptr()
+        );
+        assert_eq!(roundtrip.data_buffers().len(), strings.data_buffers().len());
+        for (roundtrip, original) in roundtrip.data_buffers().iter().zip(strings.data_buffers()) {
+            assert_eq!(roundtrip.as_ptr(), original.as_ptr());
+        }
+        assert_eq!(
+            roundtrip.nulls().unwrap().buffer().as_ptr(),
+            strings.nulls().unwrap().buffer().as_ptr()
+        );
+    }
+    #[test]
+    fn roundtrip_fixed_size_list() {
+        let mut nulls = NullBufferBuilder::new(3);
+        nulls.append_non_null();
+        nulls.append_null();
+        nulls.append_non_null();
+        let arrow_list = FixedSizeListArray::new(
+            Arc::new(Field::new_list_field(DataType::Int32, true)),
+            2,
+            Arc::new(ArrowPrimitiveArray::<Int32Type>::from(vec![
+                Some(1),
+                None,
+                Some(3),
+                Some(4),
+                Some(5),
+                Some(6),
+            ])),
+            nulls.finish(),
+        );
+
+        let vortex_list = ArrayData::from_arrow(&arrow_list, true);
+
+        assert_eq!(
+            &arrow_list,
+            vortex_list.into_arrow().unwrap().as_fixed_size_list()
+        );
+    }
+
+    #[test]
+    fn roundtrip_decimal() {
+        let arrow_decimals = Decimal128Array::from(vec![Some(12345), None, Some(-1)])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        // Decimals aren't native types, so they convert through the dynamically typed array.
+        let vortex_decimals =
+            ArrayData::from_arrow(Arc::new(arrow_decimals.clone()) as ArrayRef, true);
+        assert_eq!(
+            vortex_decimals.dtype(),
+            &DType::Decimal(DecimalDType::new(10, 2), Nullability::Nullable)
+        );
+        assert_eq!(
+            &arrow_decimals,
+            vortex_decimals
+                .into_arrow()
+                .unwrap()
+                .as_primitive::<Decimal128Type>()
+        );
+
+        let arrow_decimals = Decimal256Array::from(vec![i256::MAX, i256::from_i128(-7)])
+            .with_precision_and_scale(76, 0)
+            .unwrap();
+        assert_eq!(
+            &arrow_decimals,
+            ArrayData::from_arrow(Arc::new(arrow_decimals.clone()) as ArrayRef, false)
+                .into_arrow()
+                .unwrap()
+                .as_primitive::<Decimal256Type>()
+        );
+
+        // A narrow precision stored in 256 bits stays a Decimal256.
+        let arrow_decimals = Decimal256Array::from(vec![i256::from_i128(12345)])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        assert_eq!(
+            &arrow_decimals,
+            ArrayData::from_arrow(Arc::new(arrow_decimals.clone()) as ArrayRef, false)
+                .into_arrow()
+                .unwrap()
+                .as_primitive::<Decimal256Type>()
+        );
+    }
 }
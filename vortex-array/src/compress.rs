@@ -1,8 +1,10 @@
+use std::iter;
+
 use vortex_error::VortexResult;
 
 use crate::aliases::hash_set::HashSet;
 use crate::encoding::EncodingRef;
-use crate::stats::{ArrayStatistics as _, PRUNING_STATS};
+use crate::stats::{ArrayStatistics as _, Stat, StatsPolicy, PRUNING_STATS};
 use crate::ArrayData;
 
 pub trait CompressionStrategy {
@@ -55,8 +57,6 @@ pub fn check_statistics_unchanged(arr: &ArrayData, compressed: &ArrayData) {
     let _ = compressed;
     #[cfg(debug_assertions)]
     {
-        use crate::stats::Stat;
-
         // Run count merge_ordered assumes that the run is "broken" on each chunk, which is a useful estimate but not guaranteed to be correct.
         for (stat, value) in arr
             .statistics()
@@ -79,8 +79,13 @@ pub fn check_statistics_unchanged(arr: &ArrayData, compressed: &ArrayData) {
 }
 
 /// Eagerly compute certain statistics (i.e., pruning stats plus UncompressedSizeInBytes) for an array.
+///
+/// Only the statistics the given [StatsPolicy] allows are computed.
 /// This function is intended to be called in compressors, immediately before compression occurs.
-pub fn compute_precompression_stats(arr: &ArrayData) -> VortexResult<()> {
-    arr.statistics().compute_uncompressed_size_in_bytes();
-    arr.statistics().compute_all(PRUNING_STATS).map(|_| ())
+pub fn compute_precompression_stats(arr: &ArrayData, policy: &StatsPolicy) -> VortexResult<()> {
+    let stats = iter::once(Stat::UncompressedSizeInBytes)
+        .chain(PRUNING_STATS.iter().copied())
+        .collect::<Vec<_>>();
+    policy.compute_all(arr, &stats);
+    Ok(())
 }
@@ -0,0 +1,282 @@
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::ChunkedArray;
+use crate::compute::{
+    compare_with_options, scalar_at, slice, CompareOptions, NullEquality, Operator,
+};
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant, IntoCanonical};
+
+/// A way in which two arrays differ, as found by [array_compare_elementwise].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArrayDifference {
+    /// The arrays have different dtypes, so their values weren't compared.
+    DType { lhs: DType, rhs: DType },
+    /// The arrays have different lengths, so their values weren't compared.
+    Len { lhs: usize, rhs: usize },
+    /// The arrays have different values at the index.
+    Value {
+        index: usize,
+        lhs: Scalar,
+        rhs: Scalar,
+    },
+}
+
+impl Display for ArrayDifference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArrayDifference::DType { lhs, rhs } => write!(f, "dtype {lhs} != {rhs}"),
+            ArrayDifference::Len { lhs, rhs } => write!(f, "len {lhs} != {rhs}"),
+            ArrayDifference::Value { index, lhs, rhs } => {
+                write!(f, "{lhs} != {rhs} at index {index}")
+            }
+        }
+    }
+}
+
+/// The differences between the logical contents of two arrays.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArrayDiff {
+    differences: Vec<ArrayDifference>,
+    truncated: bool,
+}
+
+impl ArrayDiff {
+    /// Whether the arrays are equal.
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// The differences found, ordered by index.
+    pub fn differences(&self) -> &[ArrayDifference] {
+        &self.differences
+    }
+
+    /// Whether the comparison stopped at the maximum number of differences with values left to
+    /// compare, so that there may be more differences than were reported.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Whether the maximum number of differences has been found, in which case the values left
+    /// to compare are skipped.
+    fn stop_at(&mut self, max_differences: usize) -> bool {
+        self.truncated |= self.differences.len() >= max_differences;
+        self.truncated
+    }
+}
+
+impl Display for ArrayDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "arrays are equal");
+        }
+        write!(f, "{}", self.differences.iter().format(", "))?;
+        if self.truncated {
+            write!(f, ", ...")?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether two arrays have the same dtype and logical values, regardless of their encodings.
+///
+/// Nulls are equal to each other, and the comparison stops at the first difference.
+pub fn array_eq(lhs: impl AsRef<ArrayData>, rhs: impl AsRef<ArrayData>) -> VortexResult<bool> {
+    Ok(array_compare_elementwise(lhs, rhs, 1)?.is_empty())
+}
+
+/// Compare the logical values of two arrays of any encodings, reporting up to `max_differences`
+/// of the indices at which they differ.
+///
+/// Chunked arrays are canonicalized one chunk at a time, so that neither array is decoded in full
+/// at once, and the comparison stops once `max_differences` differences are found.
+pub fn array_compare_elementwise(
+    lhs: impl AsRef<ArrayData>,
+    rhs: impl AsRef<ArrayData>,
+    max_differences: usize,
+) -> VortexResult<ArrayDiff> {
+    let lhs = lhs.as_ref();
+    let rhs = rhs.as_ref();
+    let mut diff = ArrayDiff::default();
+
+    if lhs.dtype() != rhs.dtype() {
+        diff.differences.push(ArrayDifference::DType {
+            lhs: lhs.dtype().clone(),
+            rhs: rhs.dtype().clone(),
+        });
+        return Ok(diff);
+    }
+    if lhs.len() != rhs.len() {
+        diff.differences.push(ArrayDifference::Len {
+            lhs: lhs.len(),
+            rhs: rhs.len(),
+        });
+        return Ok(diff);
+    }
+
+    // Compare the ranges between the chunk boundaries of either side, so that each range lies
+    // within a single chunk of both.
+    let ends = chunk_ends(lhs).into_iter().merge(chunk_ends(rhs)).dedup();
+    let mut start = 0;
+    for end in ends {
+        if start == end {
+            continue;
+        }
+        if diff.stop_at(max_differences) {
+            break;
+        }
+        compare_range(lhs, rhs, start, end, max_differences, &mut diff)?;
+        start = end;
+    }
+
+    Ok(diff)
+}
+
+/// The end offsets of the chunks of the array, or just its length if it isn't chunked.
+fn chunk_ends(array: &ArrayData) -> Vec<usize> {
+    match ChunkedArray::try_from(array.clone()) {
+        Ok(chunked) => chunked
+            .chunks()
+            .scan(0, |end, chunk| {
+                *end += chunk.len();
+                Some(*end)
+            })
+            .collect(),
+        Err(_) => vec![array.len()],
+    }
+}
+
+fn compare_range(
+    lhs: &ArrayData,
+    rhs: &ArrayData,
+    start: usize,
+    end: usize,
+    max_differences: usize,
+    diff: &mut ArrayDiff,
+) -> VortexResult<()> {
+    let lhs = canonical_range(lhs, start, end)?;
+    let rhs = canonical_range(rhs, start, end)?;
+
+    let equal = compare_with_options(
+        &lhs,
+        &rhs,
+        Operator::Eq,
+        CompareOptions {
+            null_equality: NullEquality::NullEqualsNull,
+            ..Default::default()
+        },
+    );
+    let unequal: Box<dyn Iterator<Item = usize>> = match equal {
+        Ok(equal) => Box::new(
+            (!&equal.into_bool()?.boolean_buffer())
+                .set_indices()
+                .collect::<Vec<_>>()
+                .into_iter(),
+        ),
+        // Not every dtype can be compared by the kernel, e.g. structs and lists, so fall back to
+        // comparing the scalars.
+        Err(_) => Box::new(0..lhs.len()),
+    };
+
+    for idx in unequal {
+        if diff.stop_at(max_differences) {
+            break;
+        }
+        let l = scalar_at(&lhs, idx)?;
+        let r = scalar_at(&rhs, idx)?;
+        if l != r {
+            diff.differences.push(ArrayDifference::Value {
+                index: start + idx,
+                lhs: l,
+                rhs: r,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn canonical_range(array: &ArrayData, start: usize, end: usize) -> VortexResult<ArrayData> {
+    let range = if start == 0 && end == array.len() {
+        array.clone()
+    } else {
+        slice(array, start, end)?
+    };
+    Ok(range.into_canonical()?.into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::Nullability;
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ChunkedArray, ConstantArray, PrimitiveArray, StructArray};
+    use crate::compute::{array_compare_elementwise, array_eq, ArrayDifference};
+    use crate::{ArrayDType, IntoArrayData};
+
+    #[test]
+    fn equal_across_encodings() {
+        let primitive = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3), Some(4)])
+            .into_array();
+        let chunked = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3)]).into_array(),
+                PrimitiveArray::from_nullable_vec(vec![Some(4i32)]).into_array(),
+            ],
+            primitive.dtype().clone(),
+        )
+        .unwrap()
+        .into_array();
+        assert!(array_eq(&primitive, &chunked).unwrap());
+
+        let constant = ConstantArray::new(Scalar::primitive(1i32, Nullability::Nullable), 4);
+        let diff = array_compare_elementwise(&constant, &chunked, 2).unwrap();
+        assert_eq!(
+            diff.differences()[0],
+            ArrayDifference::Value {
+                index: 1,
+                lhs: Scalar::primitive(1i32, Nullability::Nullable),
+                rhs: Scalar::null(primitive.dtype().clone()),
+            }
+        );
+        assert_eq!(diff.differences().len(), 2);
+        assert!(diff.is_truncated());
+    }
+
+    #[test]
+    fn unequal_shapes() {
+        let lhs = PrimitiveArray::from(vec![1i32, 2]).into_array();
+        let longer = PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        let wider = PrimitiveArray::from(vec![1i64, 2]).into_array();
+        assert!(!array_eq(&lhs, &wider).unwrap());
+        assert_eq!(
+            array_compare_elementwise(&lhs, &longer, 10)
+                .unwrap()
+                .differences(),
+            &[ArrayDifference::Len { lhs: 2, rhs: 3 }]
+        );
+    }
+
+    #[test]
+    fn struct_values() {
+        let lhs =
+            StructArray::from_fields(&[("a", PrimitiveArray::from(vec![1i32, 2, 3]).into_array())])
+                .unwrap()
+                .into_array();
+        let rhs =
+            StructArray::from_fields(&[("a", PrimitiveArray::from(vec![1i32, 5, 3]).into_array())])
+                .unwrap()
+                .into_array();
+        assert!(array_eq(&lhs, &lhs).unwrap());
+        let diff = array_compare_elementwise(&lhs, &rhs, 10).unwrap();
+        assert_eq!(diff.differences().len(), 1);
+        assert!(matches!(
+            diff.differences()[0],
+            ArrayDifference::Value { index: 1, .. }
+        ));
+    }
+}
@@ -1,13 +1,10 @@
-use std::sync::Arc;
-
-use arrow_array::cast::AsArray;
-use arrow_array::ArrayRef;
-use vortex_dtype::DType;
+use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_scalar::Scalar;
 
-use crate::arrow::FromArrowArray;
+use crate::array::{bool_boolean, ConstantArray};
 use crate::encoding::Encoding;
-use crate::{ArrayDType, ArrayData, Canonical, IntoArrayVariant};
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BinaryOperator {
@@ -15,9 +12,9 @@ pub enum BinaryOperator {
     AndKleene,
     Or,
     OrKleene,
+    Xor,
     // AndNot,
     // AndNotKleene,
-    // Xor,
 }
 
 pub trait BinaryBooleanFn<Array> {
@@ -80,6 +77,13 @@ pub fn or_kleene(
     binary_boolean(lhs.as_ref(), rhs.as_ref(), BinaryOperator::OrKleene)
 }
 
+/// Point-wise logical _xor_ between two Boolean arrays.
+///
+/// A null on either side makes the result null, as neither value decides the result on its own.
+pub fn xor(lhs: impl AsRef<ArrayData>, rhs: impl AsRef<ArrayData>) -> VortexResult<ArrayData> {
+    binary_boolean(lhs.as_ref(), rhs.as_ref(), BinaryOperator::Xor)
+}
+
 pub fn binary_boolean(
     lhs: &ArrayData,
     rhs: &ArrayData,
@@ -88,20 +92,33 @@ pub fn binary_boolean(
     if lhs.len() != rhs.len() {
         vortex_bail!("Boolean operations aren't supported on arrays of different lengths")
     }
-    if !lhs.dtype().is_boolean() || !rhs.dtype().is_boolean() {
+    let is_boolean = |dtype: &DType| dtype.is_boolean() || matches!(dtype, DType::Null);
+    if !is_boolean(lhs.dtype()) || !is_boolean(rhs.dtype()) {
         vortex_bail!("Boolean operations are only supported on boolean arrays")
     }
 
+    // Arrays of the null dtype are treated as boolean nulls, so that the kernels only see booleans.
+    if matches!(lhs.dtype(), DType::Null) {
+        return binary_boolean(&null_bools(lhs.len()), rhs, op);
+    }
+    if matches!(rhs.dtype(), DType::Null) {
+        return binary_boolean(lhs, &null_bools(rhs.len()), op);
+    }
+
+    // Without Kleene logic, a null on either side makes the whole result null.
+    if matches!(
+        op,
+        BinaryOperator::And | BinaryOperator::Or | BinaryOperator::Xor
+    ) && (lhs.is_constant_null() || rhs.is_constant_null())
+    {
+        return Ok(null_bools(lhs.len()));
+    }
+
     // If LHS is constant, then we make sure it's on the RHS.
     if lhs.is_constant() && !rhs.is_constant() {
         return binary_boolean(rhs, lhs, op);
     }
 
-    // If the RHS is constant and the LHS is Arrow, we can't do any better than arrow_compare.
-    if lhs.is_arrow() && (rhs.is_arrow() || rhs.is_constant()) {
-        return arrow_boolean(lhs.clone(), rhs.clone(), op);
-    }
-
     // Check if either LHS or RHS supports the operation directly.
     if let Some(result) = lhs
         .encoding()
@@ -152,38 +169,12 @@ pub fn binary_boolean(
         op,
     );
 
-    // If neither side implements the trait, then we delegate to Arrow compute.
-    arrow_boolean(lhs.clone(), rhs.clone(), op)
+    // If neither side implements the trait, then we operate on the canonical bits.
+    bool_boolean(&lhs.clone().into_bool()?, &rhs.clone().into_bool()?, op)
 }
 
-/// Implementation of `BinaryBooleanFn` using the Arrow crate.
-///
-/// Note that other encodings should handle a constant RHS value, so we can assume here that
-/// the RHS is not constant and expand to a full array.
-pub(crate) fn arrow_boolean(
-    lhs: ArrayData,
-    rhs: ArrayData,
-    operator: BinaryOperator,
-) -> VortexResult<ArrayData> {
-    let nullable = lhs.dtype().is_nullable() || rhs.dtype().is_nullable();
-
-    let lhs = Canonical::Bool(lhs.into_bool()?)
-        .into_arrow()?
-        .as_boolean()
-        .clone();
-    let rhs = Canonical::Bool(rhs.into_bool()?)
-        .into_arrow()?
-        .as_boolean()
-        .clone();
-
-    let array = match operator {
-        BinaryOperator::And => arrow_arith::boolean::and(&lhs, &rhs)?,
-        BinaryOperator::AndKleene => arrow_arith::boolean::and_kleene(&lhs, &rhs)?,
-        BinaryOperator::Or => arrow_arith::boolean::or(&lhs, &rhs)?,
-        BinaryOperator::OrKleene => arrow_arith::boolean::or_kleene(&lhs, &rhs)?,
-    };
-
-    Ok(ArrayData::from_arrow(Arc::new(array) as ArrayRef, nullable))
+fn null_bools(len: usize) -> ArrayData {
+    ConstantArray::new(Scalar::null(DType::Bool(Nullability::Nullable)), len).into_array()
 }
 
 #[cfg(test)]
@@ -191,9 +182,8 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
-    use crate::array::BoolArray;
+    use crate::array::{BoolArray, NullArray};
     use crate::compute::scalar_at;
-    use crate::IntoArrayData;
 
     #[rstest]
     #[case(BoolArray::from_iter([Some(true), Some(true), Some(false), Some(false)].into_iter())
@@ -236,4 +226,30 @@ mod tests {
         assert!(!v2.unwrap());
         assert!(!v3.unwrap());
     }
+
+    #[test]
+    fn test_null_constant() {
+        let values = BoolArray::from_iter([Some(true), Some(false), None]).into_array();
+        let null = ConstantArray::new(Scalar::null(DType::Bool(Nullability::Nullable)), 3);
+
+        let r = and(&values, &null).unwrap();
+        assert_eq!(r.dtype(), &DType::Bool(Nullability::Nullable));
+        assert!(r.is_constant_null());
+
+        let r = or_kleene(&null, &values).unwrap();
+        assert_eq!(
+            (0..3)
+                .map(|i| scalar_at(&r, i).unwrap().as_bool().value())
+                .collect::<Vec<_>>(),
+            vec![Some(true), None, None]
+        );
+
+        let r = and_kleene(&values, NullArray::new(3)).unwrap();
+        assert_eq!(
+            (0..3)
+                .map(|i| scalar_at(&r, i).unwrap().as_bool().value())
+                .collect::<Vec<_>>(),
+            vec![None, Some(false), None]
+        );
+    }
 }
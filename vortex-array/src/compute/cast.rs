@@ -2,6 +2,7 @@ use vortex_dtype::DType;
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 
 use crate::encoding::Encoding;
+use crate::stats::derive_cast_stats;
 use crate::{ArrayDType, ArrayData, IntoArrayData, IntoCanonical};
 
 pub trait CastFn<Array> {
@@ -48,6 +49,8 @@ pub fn try_cast(array: impl AsRef<ArrayData>, dtype: &DType) -> VortexResult<Arr
         array.encoding().id()
     );
 
+    derive_cast_stats(array, &casted);
+
     Ok(casted)
 }
 
@@ -1,14 +1,18 @@
 use core::fmt;
 use std::fmt::{Display, Formatter};
 
+use arrow_buffer::BooleanBuffer;
 use arrow_ord::cmp;
 use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_scalar::Scalar;
 
+use crate::array::{BoolArray, ConstantArray};
 use crate::arrow::{Datum, FromArrowArray};
+use crate::compute::NullOrder;
 use crate::encoding::Encoding;
-use crate::{ArrayDType, ArrayData};
+use crate::validity::{ArrayValidity, LogicalValidity};
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd)]
 pub enum Operator {
@@ -18,6 +22,11 @@ pub enum Operator {
     Gte,
     Lt,
     Lte,
+    /// Equality where nulls equal each other and no other value, i.e. SQL `IS NOT DISTINCT FROM`.
+    /// The result is never null.
+    EqNullSafe,
+    /// The negation of [Operator::EqNullSafe], i.e. SQL `IS DISTINCT FROM`.
+    NotEqNullSafe,
 }
 
 impl Display for Operator {
@@ -29,6 +38,8 @@ impl Display for Operator {
             Operator::Gte => ">=",
             Operator::Lt => "<",
             Operator::Lte => "<=",
+            Operator::EqNullSafe => "IS NOT DISTINCT FROM",
+            Operator::NotEqNullSafe => "IS DISTINCT FROM",
         };
         Display::fmt(display, f)
     }
@@ -43,6 +54,8 @@ impl Operator {
             Operator::Gte => Operator::Lt,
             Operator::Lt => Operator::Gte,
             Operator::Lte => Operator::Gt,
+            Operator::EqNullSafe => Operator::NotEqNullSafe,
+            Operator::NotEqNullSafe => Operator::EqNullSafe,
         }
     }
 
@@ -55,13 +68,21 @@ impl Operator {
             Operator::Gte => Operator::Lte,
             Operator::Lt => Operator::Gt,
             Operator::Lte => Operator::Gte,
+            Operator::EqNullSafe => Operator::EqNullSafe,
+            Operator::NotEqNullSafe => Operator::NotEqNullSafe,
         }
     }
 
+    /// Whether the operator compares nulls as values, producing a non-nullable result.
+    pub fn is_null_safe(self) -> bool {
+        matches!(self, Operator::EqNullSafe | Operator::NotEqNullSafe)
+    }
+
+    /// The comparison of two non-null values.
     pub fn to_fn<T: PartialEq + PartialOrd>(&self) -> fn(T, T) -> bool {
         match self {
-            Operator::Eq => |l, r| l == r,
-            Operator::NotEq => |l, r| l != r,
+            Operator::Eq | Operator::EqNullSafe => |l, r| l == r,
+            Operator::NotEq | Operator::NotEqNullSafe => |l, r| l != r,
             Operator::Gt => |l, r| l > r,
             Operator::Gte => |l, r| l >= r,
             Operator::Lt => |l, r| l < r,
@@ -70,6 +91,30 @@ impl Operator {
     }
 }
 
+/// How null values participate in a comparison.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum NullEquality {
+    /// SQL three-valued logic: any comparison involving a null produces a null.
+    #[default]
+    Propagate,
+    /// Nulls compare equal to each other and unequal to every non-null value, producing a
+    /// non-nullable result. This matches SQL `IS NOT DISTINCT FROM`.
+    NullEqualsNull,
+    /// Nulls compare unequal to everything, including other nulls, producing a non-nullable result.
+    NullNotEqualsNull,
+}
+
+/// Options controlling the null semantics of [compare_with_options].
+#[derive(Default, Debug, Clone, Copy)]
+pub struct CompareOptions {
+    /// Whether nulls propagate, or compare equal or unequal to other nulls.
+    pub null_equality: NullEquality,
+    /// Where nulls are ordered relative to non-null values for the ordering operators.
+    ///
+    /// Ignored when `null_equality` is [NullEquality::Propagate].
+    pub null_order: NullOrder,
+}
+
 pub trait CompareFn<Array> {
     /// Compares two arrays and returns a new boolean array with the result of the comparison.
     /// Or, returns None if comparison is not supported for these arrays.
@@ -113,10 +158,41 @@ pub fn compare(
     if left.len() != right.len() {
         vortex_bail!("Compare operations only support arrays of the same length");
     }
-    if !left.dtype().eq_ignore_nullability(right.dtype()) {
+    if !left.dtype().eq_ignore_nullability(right.dtype())
+        && !matches!(left.dtype(), DType::Null)
+        && !matches!(right.dtype(), DType::Null)
+    {
         vortex_bail!("Compare operations only support arrays of the same type");
     }
 
+    // Encodings only implement the null-propagating operators, the null-safe ones are resolved
+    // from their results.
+    if operator.is_null_safe() {
+        let operator = if operator == Operator::EqNullSafe {
+            Operator::Eq
+        } else {
+            Operator::NotEq
+        };
+        return compare_with_options(
+            left,
+            right,
+            operator,
+            CompareOptions {
+                null_equality: NullEquality::NullEqualsNull,
+                ..Default::default()
+            },
+        );
+    }
+
+    // Comparisons to null are null, whatever the values on the other side.
+    if left.is_constant_null() || right.is_constant_null() {
+        return Ok(ConstantArray::new(
+            Scalar::null(DType::Bool(Nullability::Nullable)),
+            left.len(),
+        )
+        .into_array());
+    }
+
     // Always try to put constants on the right-hand side so encodings can optimise themselves.
     if left.is_constant() && !right.is_constant() {
         return compare(right, left, operator.swap());
@@ -179,6 +255,77 @@ pub fn compare(
     arrow_compare(left, right, operator)
 }
 
+/// Compare two arrays, resolving comparisons involving nulls according to the given options.
+///
+/// With the default options this is equivalent to [compare]. Otherwise, the comparison between
+/// non-null values is delegated to [compare], and positions where either side is null are
+/// resolved using the [NullEquality] and [NullOrder] of the options, producing a non-nullable
+/// boolean array.
+///
+/// The null-safe operators always compare nulls equal to each other, whatever the options.
+pub fn compare_with_options(
+    left: impl AsRef<ArrayData>,
+    right: impl AsRef<ArrayData>,
+    operator: Operator,
+    options: CompareOptions,
+) -> VortexResult<ArrayData> {
+    let left = left.as_ref();
+    let right = right.as_ref();
+
+    let result = compare(left, right, operator)?;
+    if options.null_equality == NullEquality::Propagate || operator.is_null_safe() {
+        return Ok(result);
+    }
+
+    let lhs_validity = left.logical_validity();
+    let rhs_validity = right.logical_validity();
+    // Without any nulls there is nothing to resolve, only the nullability of the result changes.
+    if lhs_validity.all_valid() && rhs_validity.all_valid() {
+        return Ok(BoolArray::from(result.into_bool()?.boolean_buffer()).into_array());
+    }
+
+    let lhs_valid = validity_buffer(lhs_validity)?;
+    let rhs_valid = validity_buffer(rhs_validity)?;
+    let values = result.into_bool()?.boolean_buffer();
+
+    let nulls_equal = options.null_equality == NullEquality::NullEqualsNull;
+    let nulls_first = options.null_order == NullOrder::First;
+    // The outcome of the comparison when both sides are null, when only the left side is null,
+    // and when only the right side is null.
+    let (both_null, lhs_null, rhs_null) = match operator {
+        Operator::Eq => (nulls_equal, false, false),
+        Operator::NotEq => (!nulls_equal, true, true),
+        Operator::Gt => (false, !nulls_first, nulls_first),
+        Operator::Gte => (nulls_equal, !nulls_first, nulls_first),
+        Operator::Lt => (false, nulls_first, !nulls_first),
+        Operator::Lte => (nulls_equal, nulls_first, !nulls_first),
+        // Already resolved by compare, listed for completeness.
+        Operator::EqNullSafe => (true, false, false),
+        Operator::NotEqNullSafe => (false, true, true),
+    };
+
+    let mut buffer = &values & &(&lhs_valid & &rhs_valid);
+    if both_null {
+        buffer = &buffer | &(&!&lhs_valid & &!&rhs_valid);
+    }
+    if lhs_null {
+        buffer = &buffer | &(&!&lhs_valid & &rhs_valid);
+    }
+    if rhs_null {
+        buffer = &buffer | &(&lhs_valid & &!&rhs_valid);
+    }
+
+    Ok(BoolArray::from(buffer).into_array())
+}
+
+fn validity_buffer(validity: LogicalValidity) -> VortexResult<BooleanBuffer> {
+    Ok(match validity {
+        LogicalValidity::AllValid(len) => BooleanBuffer::new_set(len),
+        LogicalValidity::AllInvalid(len) => BooleanBuffer::new_unset(len),
+        LogicalValidity::Array(array) => array.into_bool()?.boolean_buffer(),
+    })
+}
+
 /// Implementation of `CompareFn` using the Arrow crate.
 pub(crate) fn arrow_compare(
     lhs: &ArrayData,
@@ -196,18 +343,35 @@ pub(crate) fn arrow_compare(
         Operator::Gte => cmp::gt_eq(&lhs, &rhs)?,
         Operator::Lt => cmp::lt(&lhs, &rhs)?,
         Operator::Lte => cmp::lt_eq(&lhs, &rhs)?,
+        Operator::EqNullSafe => cmp::not_distinct(&lhs, &rhs)?,
+        Operator::NotEqNullSafe => cmp::distinct(&lhs, &rhs)?,
     };
 
-    Ok(ArrayData::from_arrow(&array, nullable))
+    Ok(ArrayData::from_arrow(
+        &array,
+        nullable && !operator.is_null_safe(),
+    ))
 }
 
 pub fn scalar_cmp(lhs: &Scalar, rhs: &Scalar, operator: Operator) -> Scalar {
+    if operator.is_null_safe() {
+        let equal = match (lhs.is_null(), rhs.is_null()) {
+            (true, true) => true,
+            (false, false) => lhs == rhs,
+            _ => false,
+        };
+        return Scalar::bool(
+            equal == (operator == Operator::EqNullSafe),
+            Nullability::NonNullable,
+        );
+    }
+
     if lhs.is_null() | rhs.is_null() {
         Scalar::null(DType::Bool(Nullability::Nullable))
     } else {
         let b = match operator {
-            Operator::Eq => lhs == rhs,
-            Operator::NotEq => lhs != rhs,
+            Operator::Eq | Operator::EqNullSafe => lhs == rhs,
+            Operator::NotEq | Operator::NotEqNullSafe => lhs != rhs,
             Operator::Gt => lhs > rhs,
             Operator::Gte => lhs >= rhs,
             Operator::Lt => lhs < rhs,
@@ -225,9 +389,10 @@ pub fn scalar_cmp(lhs: &Scalar, rhs: &Scalar, operator: Operator) -> Scalar {
 mod tests {
     use arrow_buffer::BooleanBuffer;
     use itertools::Itertools;
+    use vortex_dtype::PType;
 
     use super::*;
-    use crate::array::{BoolArray, ConstantArray};
+    use crate::array::{NullArray, PrimitiveArray};
     use crate::validity::Validity;
     use crate::{ArrayLen, IntoArrayData, IntoArrayVariant};
 
@@ -315,4 +480,162 @@ mod tests {
         assert_eq!(res.as_bool().value(), Some(false));
         assert_eq!(compare.len(), 10);
     }
+
+    #[test]
+    fn compare_null_constant() {
+        let values = PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        let null = ConstantArray::new(
+            Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable)),
+            3,
+        )
+        .into_array();
+
+        for (lhs, rhs) in [(&values, &null), (&null, &values), (&null, &null)] {
+            let result = compare(lhs, rhs, Operator::Lt).unwrap();
+            assert_eq!(result.dtype(), &DType::Bool(Nullability::Nullable));
+            assert_eq!(result.len(), 3);
+            assert!(result.as_constant().unwrap().is_null());
+        }
+
+        let untyped = NullArray::new(3).into_array();
+        assert!(compare(&values, &untyped, Operator::Eq)
+            .unwrap()
+            .is_constant_null());
+
+        let eq = compare_with_options(
+            &null,
+            &untyped,
+            Operator::Eq,
+            CompareOptions {
+                null_equality: NullEquality::NullEqualsNull,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            eq.boolean_buffer().iter().collect_vec(),
+            vec![true, true, true]
+        );
+    }
+
+    #[test]
+    fn compare_nulls_equal() {
+        let lhs = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, None, Some(4)]);
+        let rhs = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3), Some(5)]);
+        let options = CompareOptions {
+            null_equality: NullEquality::NullEqualsNull,
+            ..Default::default()
+        };
+
+        let eq = compare_with_options(&lhs, &rhs, Operator::Eq, options)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(eq.dtype(), &DType::Bool(Nullability::NonNullable));
+        assert_eq!(
+            eq.boolean_buffer().iter().collect_vec(),
+            vec![true, true, false, false]
+        );
+
+        let neq = compare_with_options(&lhs, &rhs, Operator::NotEq, options)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            neq.boolean_buffer().iter().collect_vec(),
+            vec![false, false, true, true]
+        );
+    }
+
+    #[test]
+    fn compare_nulls_ordering() {
+        let lhs = PrimitiveArray::from_nullable_vec(vec![None, Some(2i32), None]);
+        let rhs = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, None]);
+
+        let first = CompareOptions {
+            null_equality: NullEquality::NullNotEqualsNull,
+            null_order: NullOrder::First,
+        };
+        let lt = compare_with_options(&lhs, &rhs, Operator::Lt, first)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            lt.boolean_buffer().iter().collect_vec(),
+            vec![true, false, false]
+        );
+
+        let last = CompareOptions {
+            null_equality: NullEquality::NullEqualsNull,
+            null_order: NullOrder::Last,
+        };
+        let lte = compare_with_options(&lhs, &rhs, Operator::Lte, last)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            lte.boolean_buffer().iter().collect_vec(),
+            vec![false, true, true]
+        );
+    }
+
+    #[test]
+    fn compare_null_safe() {
+        let lhs = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, None, Some(4)]);
+        let rhs = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3), Some(5)]);
+
+        for (operator, expected) in [
+            (Operator::EqNullSafe, vec![true, true, false, false]),
+            (Operator::NotEqNullSafe, vec![false, false, true, true]),
+        ] {
+            let result = compare(&lhs, &rhs, operator).unwrap();
+            assert_eq!(result.dtype(), &DType::Bool(Nullability::NonNullable));
+            assert_eq!(
+                result
+                    .into_bool()
+                    .unwrap()
+                    .boolean_buffer()
+                    .iter()
+                    .collect_vec(),
+                expected
+            );
+
+            let arrow = arrow_compare(lhs.as_ref(), rhs.as_ref(), operator)
+                .unwrap()
+                .into_bool()
+                .unwrap();
+            assert_eq!(arrow.dtype(), &DType::Bool(Nullability::NonNullable));
+            assert_eq!(arrow.boolean_buffer().iter().collect_vec(), expected);
+        }
+
+        let null = ConstantArray::new(Scalar::null(lhs.dtype().clone()), lhs.len());
+        let is_null = compare(&lhs, &null, Operator::EqNullSafe)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            is_null.boolean_buffer().iter().collect_vec(),
+            vec![false, true, true, false]
+        );
+    }
+
+    #[test]
+    fn scalar_cmp_null_safe() {
+        let null = Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable));
+        let one = Scalar::from(1i32);
+        assert_eq!(
+            scalar_cmp(&null, &null, Operator::EqNullSafe),
+            Scalar::bool(true, Nullability::NonNullable)
+        );
+        assert_eq!(
+            scalar_cmp(&null, &one, Operator::NotEqNullSafe),
+            Scalar::bool(true, Nullability::NonNullable)
+        );
+        assert_eq!(
+            scalar_cmp(&one, &one, Operator::NotEqNullSafe),
+            Scalar::bool(false, Nullability::NonNullable)
+        );
+    }
 }
@@ -7,18 +7,22 @@ use num_traits::AsPrimitive;
 use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexExpect, VortexResult};
 
-use crate::array::{BoolArray, ConstantArray};
+use crate::array::{BoolArray, ChunkedArray, ConstantArray};
 use crate::arrow::FromArrowArray;
-use crate::compute::scalar_at;
+use crate::compute::{scalar_at, slice};
 use crate::encoding::Encoding;
-use crate::stats::ArrayStatistics;
+use crate::stats::{derive_subsequence_stats, ArrayStatistics};
 use crate::{ArrayDType, ArrayData, Canonical, IntoArrayData, IntoCanonical};
 
 /// If the filter selects more than this fraction of rows, iterate over slices instead of indices.
 ///
 /// Threshold of 0.8 chosen based on Arrow Rust, which is in turn based on:
 ///   <https://dl.acm.org/doi/abs/10.1145/3465998.3466009>
-const FILTER_SLICES_SELECTIVITY_THRESHOLD: f64 = 0.8;
+pub const FILTER_SLICES_SELECTIVITY_THRESHOLD: f64 = 0.8;
+
+/// If the runs of set values of the filter are at least this long on average, iterate over slices
+/// instead of indices, whatever the selectivity.
+pub const FILTER_SLICES_MIN_RUN_LENGTH: usize = 32;
 
 pub trait FilterFn<Array> {
     /// Filter an array by the provided predicate.
@@ -87,10 +91,32 @@ pub fn filter(array: &ArrayData, mask: FilterMask) -> VortexResult<ArrayData> {
         array.encoding().id()
     );
 
+    derive_subsequence_stats(array, &filtered);
+
     Ok(filtered)
 }
 
 fn filter_impl(array: &ArrayData, mask: FilterMask) -> VortexResult<ArrayData> {
+    if array.encoding().slice_fn().is_some() {
+        if array.encoding().filter_fn().is_none() {
+            // Masks of long runs, as predicates over clustered data produce, are applied by
+            // slicing out each run, so that encodings without a filter kernel only decode the
+            // selected values.
+            if mask.is_run_heavy()? {
+                return match mask.slices()? {
+                    [(start, end)] => slice(array, *start, *end),
+                    slices => filter_slices(array, slices),
+                };
+            }
+        } else if mask.cached_run_count() == Some(1) {
+            // Counting the runs would cost as much as the filter kernel, but a mask known to be
+            // a single run is just a slice.
+            if let [(start, end)] = mask.slices()? {
+                return slice(array, *start, *end);
+            }
+        }
+    }
+
     if let Some(filter_fn) = array.encoding().filter_fn() {
         return filter_fn.filter(array, mask);
     }
@@ -114,13 +140,29 @@ fn filter_impl(array: &ArrayData, mask: FilterMask) -> VortexResult<ArrayData> {
     Ok(ArrayData::from_arrow(filtered, array.dtype().is_nullable()))
 }
 
+/// Concatenate the `[start, end)` slices of the array.
+fn filter_slices(array: &ArrayData, slices: &[(usize, usize)]) -> VortexResult<ArrayData> {
+    let chunks = slices
+        .iter()
+        .map(|&(start, end)| slice(array, start, end))
+        .collect::<VortexResult<Vec<_>>>()?;
+    Ok(ChunkedArray::try_new(chunks, array.dtype().clone())?
+        .into_canonical()?
+        .into())
+}
+
 /// Represents the mask argument to a filter function.
 /// Internally this will cache the canonical representation of the mask if it is ever used.
+///
+/// A mask is backed either by a boolean array, or by the slices of its runs of set values, in
+/// which case its boolean buffer is only built if it's asked for.
 #[derive(Debug)]
 pub struct FilterMask {
-    array: ArrayData,
+    len: usize,
+    array: Option<ArrayData>,
     true_count: usize,
     range_selectivity: f64,
+    run_count: Arc<OnceLock<usize>>,
     indices: Arc<OnceLock<Vec<usize>>>,
     slices: Arc<OnceLock<Vec<(usize, usize)>>>,
     buffer: Arc<OnceLock<BooleanBuffer>>,
@@ -131,18 +173,18 @@ pub struct FilterMask {
 /// in a recursive function, we will cache the slices internally.
 impl Clone for FilterMask {
     fn clone(&self) -> Self {
-        if self.range_selectivity > FILTER_SLICES_SELECTIVITY_THRESHOLD {
-            let _: VortexResult<_> = self
-                .slices
-                .get_or_try_init(|| Ok(self.boolean_buffer()?.set_slices().collect()));
+        if self.prefers_slices().unwrap_or(false) {
+            let _: VortexResult<_> = self.slices();
         } else {
             let _: VortexResult<_> = self.indices();
         }
 
         Self {
+            len: self.len,
             array: self.array.clone(),
             true_count: self.true_count,
             range_selectivity: self.range_selectivity,
+            run_count: self.run_count.clone(),
             indices: self.indices.clone(),
             slices: self.slices.clone(),
             buffer: self.buffer.clone(),
@@ -212,12 +254,50 @@ impl FilterMask {
         Self::from(BooleanBufferBuilder::new_from_buffer(buffer, length).finish())
     }
 
+    /// Create a new FilterMask where the given ranges of indices are set.
+    ///
+    /// The slices must be sorted and disjoint, as returned by [FilterMask::slices].
+    pub fn from_slices<I: IntoIterator<Item = (usize, usize)>>(length: usize, slices: I) -> Self {
+        let slices = slices.into_iter().collect::<Vec<_>>();
+        let true_count = slices.iter().map(|(start, end)| end - start).sum();
+        Self {
+            len: length,
+            array: None,
+            true_count,
+            range_selectivity: true_count as f64 / length as f64,
+            run_count: Arc::new(OnceLock::from(slices.len())),
+            indices: Arc::new(OnceLock::new()),
+            slices: Arc::new(OnceLock::from(slices)),
+            buffer: Arc::new(OnceLock::new()),
+        }
+    }
+
+    /// Create a new FilterMask from the runs of a run-end encoded boolean array, given as the
+    /// exclusive end of each run and whether its values are set.
+    ///
+    /// The run ends must be increasing, and the last one is the length of the mask.
+    pub fn from_run_ends<I: IntoIterator<Item = (usize, bool)>>(runs: I) -> Self {
+        let mut slices: Vec<(usize, usize)> = Vec::new();
+        let mut start = 0;
+        for (end, set) in runs {
+            if set && start < end {
+                match slices.last_mut() {
+                    // Merge adjacent runs of set values
+                    Some(last) if last.1 == start => last.1 = end,
+                    _ => slices.push((start, end)),
+                }
+            }
+            start = end;
+        }
+        Self::from_slices(start, slices)
+    }
+
     pub fn len(&self) -> usize {
-        self.array.len()
+        self.len
     }
 
     pub fn is_empty(&self) -> bool {
-        self.array.is_empty()
+        self.len == 0
     }
 
     /// Get the true count of the mask.
@@ -227,7 +307,7 @@ impl FilterMask {
 
     /// Get the false count of the mask.
     pub fn false_count(&self) -> usize {
-        self.array.len() - self.true_count
+        self.len - self.true_count
     }
 
     /// Return the selectivity of the full mask.
@@ -235,7 +315,8 @@ impl FilterMask {
         self.true_count as f64 / self.len() as f64
     }
 
-    /// Return the selectivity of the range of true values of the mask.
+    /// Return the fraction of the mask's values that are set, the same as
+    /// [FilterMask::selectivity], which decides whether the mask is applied as slices.
     pub fn range_selectivity(&self) -> f64 {
         self.range_selectivity
     }
@@ -252,50 +333,154 @@ impl FilterMask {
     }
 
     fn boolean_buffer(&self) -> VortexResult<&BooleanBuffer> {
-        self.buffer.get_or_try_init(|| {
-            Ok(self
-                .array
+        self.buffer.get_or_try_init(|| match &self.array {
+            Some(array) => Ok(array
                 .clone()
                 .into_canonical()?
                 .into_bool()?
-                .boolean_buffer())
+                .boolean_buffer()),
+            None => {
+                let slices = self
+                    .slices
+                    .get()
+                    .ok_or_else(|| vortex_err!("FilterMask has neither an array nor slices"))?;
+                let mut buffer = BooleanBufferBuilder::new(self.len);
+                let mut pos = 0;
+                for &(start, end) in slices.iter() {
+                    buffer.append_n(start - pos, false);
+                    buffer.append_n(end - start, true);
+                    pos = end;
+                }
+                buffer.append_n(self.len - pos, false);
+                Ok(buffer.finish())
+            }
         })
     }
 
-    fn indices(&self) -> VortexResult<&[usize]> {
+    /// The indices of the set values, which are cached by the mask and its clones.
+    pub fn indices(&self) -> VortexResult<&[usize]> {
         self.indices
             .get_or_try_init(|| {
                 let mut indices = Vec::with_capacity(self.true_count());
-                indices.extend(self.boolean_buffer()?.set_indices());
+                match self.slices.get() {
+                    Some(slices) => {
+                        indices.extend(slices.iter().flat_map(|&(start, end)| start..end))
+                    }
+                    None => indices.extend(self.boolean_buffer()?.set_indices()),
+                }
                 Ok(indices)
             })
             .map(|v| v.as_slice())
     }
 
+    /// The sorted, disjoint `[start, end)` ranges of set values, which are cached by the mask and
+    /// its clones.
+    pub fn slices(&self) -> VortexResult<&[(usize, usize)]> {
+        self.slices
+            .get_or_try_init(|| Ok(self.boolean_buffer()?.set_slices().collect()))
+            .map(|v| v.as_slice())
+    }
+
+    /// The number of runs of set values, counted without collecting the slices if they aren't
+    /// already cached.
+    pub fn run_count(&self) -> VortexResult<usize> {
+        self.run_count
+            .get_or_try_init(|| match self.slices.get() {
+                Some(slices) => Ok(slices.len()),
+                None => {
+                    // A run starts at each set bit whose preceding bit is unset
+                    let mut carry = 0u64;
+                    Ok(self
+                        .boolean_buffer()?
+                        .bit_chunks()
+                        .iter_padded()
+                        .map(|chunk| {
+                            let starts = chunk & !((chunk << 1) | carry);
+                            carry = chunk >> 63;
+                            starts.count_ones() as usize
+                        })
+                        .sum())
+                }
+            })
+            .copied()
+    }
+
+    /// The number of runs of set values if it's known without counting them.
+    fn cached_run_count(&self) -> Option<usize> {
+        self.run_count.get().copied()
+    }
+
+    /// Whether the set values come in runs of at least [FILTER_SLICES_MIN_RUN_LENGTH] on average,
+    /// in which case the mask is better applied by slices.
+    pub fn is_run_heavy(&self) -> VortexResult<bool> {
+        let run_count = self.run_count()?;
+        Ok(run_count > 0 && self.true_count >= run_count * FILTER_SLICES_MIN_RUN_LENGTH)
+    }
+
+    fn prefers_slices(&self) -> VortexResult<bool> {
+        Ok(self.range_selectivity > FILTER_SLICES_SELECTIVITY_THRESHOLD || self.is_run_heavy()?)
+    }
+
+    /// The mask of the values set in both this mask and the other.
+    pub fn and(&self, other: &Self) -> VortexResult<Self> {
+        self.check_same_len(other)?;
+        Ok(Self::from(self.boolean_buffer()? & other.boolean_buffer()?))
+    }
+
+    /// The mask of the values set in either this mask or the other.
+    pub fn or(&self, other: &Self) -> VortexResult<Self> {
+        self.check_same_len(other)?;
+        Ok(Self::from(self.boolean_buffer()? | other.boolean_buffer()?))
+    }
+
+    /// The mask of the values not set in this mask.
+    pub fn not(&self) -> VortexResult<Self> {
+        Ok(Self::from(!self.boolean_buffer()?))
+    }
+
+    /// The `len` values of the mask starting at `offset`.
+    pub fn slice(&self, offset: usize, len: usize) -> VortexResult<Self> {
+        if offset + len > self.len() {
+            vortex_bail!(OutOfBounds: offset + len, 0, self.len());
+        }
+        Ok(Self::from(self.boolean_buffer()?.slice(offset, len)))
+    }
+
+    fn check_same_len(&self, other: &Self) -> VortexResult<()> {
+        if self.len() != other.len() {
+            vortex_bail!(
+                "Cannot combine masks of different lengths {} and {}",
+                self.len(),
+                other.len()
+            );
+        }
+        Ok(())
+    }
+
     /// Returns the best iterator based on a selectivity threshold.
     ///
-    /// Currently, this threshold is fixed at 0.8 based on Arrow Rust.
+    /// Masks selecting more than [FILTER_SLICES_SELECTIVITY_THRESHOLD] of the range between their
+    /// first and last set values, or whose runs are [long](Self::is_run_heavy), are iterated by
+    /// slices, and others by indices, reusing the slices or indices if they have been cached.
     pub fn iter(&self) -> VortexResult<FilterIter> {
-        Ok(
-            if self.range_selectivity > FILTER_SLICES_SELECTIVITY_THRESHOLD {
-                // Iterate over slices
-                if let Some(slices) = self.slices.get() {
-                    FilterIter::Slices(slices.as_slice())
-                } else {
-                    FilterIter::SlicesIter(self.boolean_buffer()?.set_slices())
-                }
+        Ok(if self.prefers_slices()? {
+            // Iterate over slices
+            if let Some(slices) = self.slices.get() {
+                FilterIter::Slices(slices.as_slice())
             } else {
-                // Iterate over indices
-                if let Some(indices) = self.indices.get() {
-                    FilterIter::Indices(indices.as_slice())
-                } else {
-                    FilterIter::IndicesIter(BitIndexIterator::new(
-                        self.boolean_buffer()?.set_indices(),
-                        self.true_count,
-                    ))
-                }
-            },
-        )
+                FilterIter::SlicesIter(self.boolean_buffer()?.set_slices())
+            }
+        } else {
+            // Iterate over indices
+            if let Some(indices) = self.indices.get() {
+                FilterIter::Indices(indices.as_slice())
+            } else {
+                FilterIter::IndicesIter(BitIndexIterator::new(
+                    self.boolean_buffer()?.set_indices(),
+                    self.true_count,
+                ))
+            }
+        })
     }
 
     #[deprecated(note = "Move to using iter() instead")]
@@ -320,6 +505,16 @@ impl TryFrom<ArrayData> for FilterMask {
             );
         }
 
+        // Run-end encoded masks keep their runs as slices rather than being decoded.
+        if let Some(runs) = array
+            .as_bool_array()
+            .map(|bools| bools.run_ends())
+            .transpose()?
+            .flatten()
+        {
+            return Ok(Self::from_run_ends(runs));
+        }
+
         let true_count = array
             .statistics()
             .compute_true_count()
@@ -328,9 +523,11 @@ impl TryFrom<ArrayData> for FilterMask {
         let selectivity = true_count as f64 / array.len() as f64;
 
         Ok(Self {
-            array,
+            len: array.len(),
+            array: Some(array),
             true_count,
             range_selectivity: selectivity,
+            run_count: Arc::new(OnceLock::new()),
             indices: Arc::new(OnceLock::new()),
             slices: Arc::new(OnceLock::new()),
             buffer: Arc::new(OnceLock::new()),
@@ -379,4 +576,98 @@ mod test {
             vec![0i32, 1i32, 2i32]
         );
     }
+
+    #[test]
+    fn test_from_slices() {
+        let mask = FilterMask::from_slices(11, [(2, 4), (6, 8), (9, 10)]);
+        assert_eq!(
+            mask.to_boolean_buffer().unwrap().iter().collect::<Vec<_>>(),
+            vec![false, false, true, true, false, false, true, true, false, true, false],
+        );
+        assert_eq!(mask.true_count(), 5);
+        assert_eq!(mask.slices().unwrap(), &[(2, 4), (6, 8), (9, 10)]);
+        assert_eq!(mask.indices().unwrap(), &[2, 3, 6, 7, 9]);
+    }
+
+    #[test]
+    fn test_mask_algebra() {
+        let lhs = FilterMask::from_iter([true, true, false, false]);
+        let rhs = FilterMask::from_iter([true, false, true, false]);
+        let bools = |mask: FilterMask| mask.to_boolean_buffer().unwrap().iter().collect::<Vec<_>>();
+
+        assert_eq!(
+            bools(lhs.and(&rhs).unwrap()),
+            vec![true, false, false, false]
+        );
+        assert_eq!(bools(lhs.or(&rhs).unwrap()), vec![true, true, true, false]);
+        assert_eq!(bools(lhs.not().unwrap()), vec![false, false, true, true]);
+        assert_eq!(bools(rhs.slice(1, 2).unwrap()), vec![false, true]);
+
+        assert!(lhs.and(&FilterMask::from_iter([true])).is_err());
+        assert!(lhs.slice(3, 2).is_err());
+    }
+
+    #[test]
+    fn test_from_run_ends() {
+        let mask =
+            FilterMask::from_run_ends([(2, false), (4, true), (5, true), (8, false), (9, true)]);
+        assert_eq!(mask.len(), 9);
+        assert_eq!(mask.true_count(), 4);
+        assert_eq!(mask.run_count().unwrap(), 2);
+        assert_eq!(mask.slices().unwrap(), &[(2, 5), (8, 9)]);
+        assert_eq!(mask.indices().unwrap(), &[2, 3, 4, 8]);
+        assert_eq!(
+            mask.to_boolean_buffer().unwrap().iter().collect::<Vec<_>>(),
+            vec![false, false, true, true, true, false, false, false, true],
+        );
+    }
+
+    #[test]
+    fn test_run_count() {
+        let mask = FilterMask::from_iter((0..200).map(|i| (i / 10) % 2 == 1));
+        assert_eq!(mask.run_count().unwrap(), 10);
+        assert!(!mask.is_run_heavy().unwrap());
+
+        let mask = FilterMask::from_iter((0..1000).map(|i| (60..130).contains(&i) || i >= 900));
+        assert_eq!(mask.run_count().unwrap(), 2);
+        assert!(mask.is_run_heavy().unwrap());
+        assert!(matches!(mask.iter().unwrap(), FilterIter::SlicesIter(_)));
+    }
+
+    #[test]
+    fn test_filter_runs() {
+        let items = PrimitiveArray::from((0..1000).collect::<Vec<i32>>()).into_array();
+        let mask = FilterMask::from_slices(1000, [(100, 200), (500, 600)]);
+        assert!(mask.is_run_heavy().unwrap());
+
+        let filtered = filter(&items, mask).unwrap();
+        assert_eq!(
+            filtered
+                .into_canonical()
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            (100..200).chain(500..600).collect::<Vec<_>>()
+        );
+
+        let single = filter(
+            &items,
+            FilterMask::from_run_ends([(300, false), (400, true), (1000, false)]),
+        )
+        .unwrap();
+        assert_eq!(single.len(), 100);
+        assert_eq!(scalar_at(&single, 0).unwrap(), 300.into());
+    }
+
+    #[test]
+    fn test_filter_kernel_skips_run_count() {
+        let items = PrimitiveArray::from((0..1000).collect::<Vec<i32>>()).into_array();
+        let mask = FilterMask::from_iter((0..1000).map(|i| i >= 100));
+
+        // The primitive filter kernel is used without counting the runs of the mask
+        let filtered = filter(&items, mask.clone()).unwrap();
+        assert_eq!(filtered.len(), 900);
+        assert_eq!(mask.cached_run_count(), None);
+    }
 }
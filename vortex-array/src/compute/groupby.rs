@@ -0,0 +1,479 @@
+//! Grouping the rows of arrays by the values of key arrays, and aggregating each group.
+
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+use arrow_buffer::NullBuffer;
+use num_traits::ToPrimitive;
+use vortex_dtype::{match_each_native_ptype, DType, NativePType, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::accessor::ArrayAccessor;
+use crate::aliases::hash_map::HashMap;
+use crate::array::PrimitiveArray;
+use crate::compute::take;
+use crate::encoding::Encoding;
+use crate::validity::ArrayValidity;
+use crate::variants::PrimitiveArrayTrait;
+use crate::{
+    ArrayDType, ArrayData, ArrayLen, Canonical, IntoArrayData, IntoArrayVariant, IntoCanonical,
+};
+
+/// The group of each row of an array, where rows with equal values are in the same group.
+///
+/// Groups are numbered in the order of their first row, and all nulls form a single group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupIds {
+    ids: Vec<usize>,
+    first_rows: Vec<u64>,
+}
+
+impl GroupIds {
+    /// Create the groups from the group of each row and the first row of each group.
+    pub fn new(ids: Vec<usize>, first_rows: Vec<u64>) -> Self {
+        Self { ids, first_rows }
+    }
+
+    /// The group of each row.
+    pub fn ids(&self) -> &[usize] {
+        &self.ids
+    }
+
+    /// The index of the first row of each group, from which the group's key can be taken.
+    pub fn first_rows(&self) -> &[u64] {
+        &self.first_rows
+    }
+
+    /// The number of groups.
+    pub fn ngroups(&self) -> usize {
+        self.first_rows.len()
+    }
+
+    /// Assign rows to groups by hashing a key per row.
+    fn from_keys<K: Hash + Eq>(keys: impl IntoIterator<Item = K>) -> Self {
+        let mut groups: HashMap<K, usize> = HashMap::new();
+        let mut ids = Vec::new();
+        let mut first_rows = Vec::new();
+        for (row, key) in keys.into_iter().enumerate() {
+            let next = first_rows.len();
+            let id = *groups.entry(key).or_insert_with(|| {
+                first_rows.push(row as u64);
+                next
+            });
+            ids.push(id);
+        }
+        Self { ids, first_rows }
+    }
+
+    /// The groups of rows that are in the same group of both `self` and `other`.
+    fn intersect(&self, other: &GroupIds) -> Self {
+        Self::from_keys(self.ids.iter().copied().zip(other.ids.iter().copied()))
+    }
+}
+
+pub trait GroupIdsFn<Array> {
+    /// Assign each row of the array to a group of rows with equal values, see [GroupIds].
+    ///
+    /// Returns `None` if the encoding can't do better than hashing its canonical values.
+    fn group_ids(&self, array: &Array) -> VortexResult<Option<GroupIds>>;
+}
+
+impl<E: Encoding> GroupIdsFn<ArrayData> for E
+where
+    E: GroupIdsFn<E::Array>,
+    for<'a> &'a E::Array: TryFrom<&'a ArrayData, Error = VortexError>,
+{
+    fn group_ids(&self, array: &ArrayData) -> VortexResult<Option<GroupIds>> {
+        let array_ref = <&E::Array>::try_from(array)?;
+        let encoding = array
+            .encoding()
+            .as_any()
+            .downcast_ref::<E>()
+            .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
+        GroupIdsFn::group_ids(encoding, array_ref)
+    }
+}
+
+/// Assign each row of an array to a group of rows with equal values.
+///
+/// Floating point values are grouped by their bit patterns, so that all NaNs with the same
+/// payload form one group, while `0.0` and `-0.0` are in different groups.
+pub fn group_ids(array: impl AsRef<ArrayData>) -> VortexResult<GroupIds> {
+    let array = array.as_ref();
+    if let Some(f) = array.encoding().group_ids_fn() {
+        if let Some(groups) = f.group_ids(array)? {
+            debug_assert_eq!(
+                groups.ids().len(),
+                array.len(),
+                "GroupIds length mismatch {}",
+                array.encoding().id()
+            );
+            return Ok(groups);
+        }
+    }
+
+    hash_group_ids(array.clone().into_canonical()?)
+}
+
+fn hash_group_ids(canonical: Canonical) -> VortexResult<GroupIds> {
+    match canonical {
+        Canonical::Null(a) => Ok(GroupIds::from_keys((0..a.len()).map(|_| ()))),
+        Canonical::Bool(a) => {
+            let validity = a.logical_validity().to_null_buffer()?;
+            let values = a.boolean_buffer();
+            Ok(GroupIds::from_keys((0..a.len()).map(|row| {
+                is_valid(validity.as_ref(), row).then(|| values.value(row))
+            })))
+        }
+        Canonical::Primitive(a) => {
+            let validity = a.logical_validity().to_null_buffer()?;
+            match_each_native_ptype!(a.ptype(), |$T| {
+                Ok(GroupIds::from_keys(
+                    a.maybe_null_slice::<$T>()
+                        .iter()
+                        .enumerate()
+                        .map(|(row, v)| is_valid(validity.as_ref(), row).then(|| v.to_le_bytes())),
+                ))
+            })
+        }
+        Canonical::VarBinView(a) => a.with_iterator(|values| GroupIds::from_keys(values)),
+        Canonical::Struct(a) => {
+            let validity = a.logical_validity().to_null_buffer()?;
+            let mut groups =
+                GroupIds::from_keys((0..a.len()).map(|row| is_valid(validity.as_ref(), row)));
+            for field in a.children() {
+                groups = groups.intersect(&group_ids(&field)?);
+            }
+            // Null structs group together whatever the values of their fields.
+            if let Some(validity) = validity {
+                groups = GroupIds::from_keys(
+                    groups
+                        .ids()
+                        .iter()
+                        .enumerate()
+                        .map(|(row, &id)| validity.is_valid(row).then_some(id)),
+                );
+            }
+            Ok(groups)
+        }
+        Canonical::Extension(a) => group_ids(a.storage()),
+        Canonical::Decimal(a) => vortex_bail!("Cannot group by values of type {}", a.dtype()),
+        Canonical::List(a) => vortex_bail!("Cannot group by values of type {}", a.dtype()),
+        Canonical::FixedSizeList(a) => vortex_bail!("Cannot group by values of type {}", a.dtype()),
+    }
+}
+
+fn is_valid(validity: Option<&NullBuffer>, row: usize) -> bool {
+    validity.map_or(true, |v| v.is_valid(row))
+}
+
+/// A function aggregating the values of each group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AggregateFunction {
+    /// The number of non-null values, as a non-nullable `u64`.
+    Count,
+    /// The sum of the non-null values, as an `i64`, `u64` or `f64` depending on the input type,
+    /// failing on integer overflow.
+    Sum,
+    /// The smallest non-null value, of the input type.
+    Min,
+    /// The largest non-null value, of the input type.
+    Max,
+}
+
+impl Display for AggregateFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Count => write!(f, "count"),
+            Self::Sum => write!(f, "sum"),
+            Self::Min => write!(f, "min"),
+            Self::Max => write!(f, "max"),
+        }
+    }
+}
+
+impl AggregateFunction {
+    /// The PType of the aggregated values of an input of the given PType.
+    pub fn result_ptype(&self, input: PType) -> PType {
+        match self {
+            Self::Count => PType::U64,
+            Self::Sum if input.is_float() => PType::F64,
+            Self::Sum if input.is_signed_int() => PType::I64,
+            Self::Sum => PType::U64,
+            Self::Min | Self::Max => input,
+        }
+    }
+}
+
+/// An aggregate function applied to an array of the same length as the keys.
+#[derive(Debug, Clone)]
+pub struct Aggregate {
+    function: AggregateFunction,
+    input: ArrayData,
+}
+
+impl Aggregate {
+    pub fn new(function: AggregateFunction, input: ArrayData) -> Self {
+        Self { function, input }
+    }
+
+    pub fn function(&self) -> AggregateFunction {
+        self.function
+    }
+
+    pub fn input(&self) -> &ArrayData {
+        &self.input
+    }
+}
+
+/// The result of [groupby], holding one row per group.
+#[derive(Debug, Clone)]
+pub struct Grouped {
+    keys: Vec<ArrayData>,
+    aggregates: Vec<ArrayData>,
+}
+
+impl Grouped {
+    /// The key of each group, one array per key array.
+    pub fn keys(&self) -> &[ArrayData] {
+        &self.keys
+    }
+
+    /// The aggregated values of each group, one array per aggregate.
+    pub fn aggregates(&self) -> &[ArrayData] {
+        &self.aggregates
+    }
+
+    pub fn into_parts(self) -> (Vec<ArrayData>, Vec<ArrayData>) {
+        (self.keys, self.aggregates)
+    }
+}
+
+/// Group the rows of the key arrays by their values, and compute each aggregate over the rows of
+/// each group.
+///
+/// Groups are in the order of their first row. Nulls in the aggregate inputs are ignored, and the
+/// sum, min and max of a group without any non-null values are null.
+pub fn groupby(keys: &[ArrayData], aggregates: &[Aggregate]) -> VortexResult<Grouped> {
+    let Some((first, rest)) = keys.split_first() else {
+        vortex_bail!("Cannot group by no keys");
+    };
+    let len = first.len();
+    for array in rest.iter().chain(aggregates.iter().map(Aggregate::input)) {
+        if array.len() != len {
+            vortex_bail!(
+                "Expected all keys and aggregate inputs to have length {len}, found {}",
+                array.len()
+            );
+        }
+    }
+
+    let mut groups = group_ids(first)?;
+    for key in rest {
+        groups = groups.intersect(&group_ids(key)?);
+    }
+
+    let first_rows = PrimitiveArray::from(groups.first_rows().to_vec()).into_array();
+    let keys = keys
+        .iter()
+        .map(|key| take(key, &first_rows))
+        .collect::<VortexResult<Vec<_>>>()?;
+    let aggregates = aggregates
+        .iter()
+        .map(|aggregate| {
+            aggregate_groups(aggregate, &groups).map_err(|e| {
+                e.with_context(format!(
+                    "Failed to compute {} of {}",
+                    aggregate.function(),
+                    aggregate.input().dtype()
+                ))
+            })
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+    Ok(Grouped { keys, aggregates })
+}
+
+fn aggregate_groups(aggregate: &Aggregate, groups: &GroupIds) -> VortexResult<ArrayData> {
+    let validity = aggregate.input().logical_validity().to_null_buffer()?;
+    let validity = validity.as_ref();
+    if aggregate.function() == AggregateFunction::Count {
+        let mut counts = vec![0u64; groups.ngroups()];
+        for (row, &id) in groups.ids().iter().enumerate() {
+            if is_valid(validity, row) {
+                counts[id] += 1;
+            }
+        }
+        return Ok(PrimitiveArray::from(counts).into_array());
+    }
+
+    if !matches!(aggregate.input().dtype(), DType::Primitive(..)) {
+        vortex_bail!("Only primitive values can be aggregated");
+    }
+    let input = aggregate.input().clone().into_primitive()?;
+    if aggregate.function() == AggregateFunction::Sum {
+        sum_groups(&input, validity, groups)
+    } else {
+        let min = aggregate.function() == AggregateFunction::Min;
+        match_each_native_ptype!(input.ptype(), |$T| {
+            fold_groups(input.maybe_null_slice::<$T>(), validity, groups, |acc: Option<$T>, v| {
+                Ok(acc.map_or(v, |acc| if v.total_compare(acc).is_lt() == min { v } else { acc }))
+            })
+        })
+    }
+}
+
+fn sum_groups(
+    input: &PrimitiveArray,
+    validity: Option<&NullBuffer>,
+    groups: &GroupIds,
+) -> VortexResult<ArrayData> {
+    let ptype = input.ptype();
+    match_each_native_ptype!(ptype, |$T| {
+        let values = input.maybe_null_slice::<$T>();
+        if ptype.is_float() {
+            fold_groups(values, validity, groups, |acc: Option<f64>, v| {
+                let v = ToPrimitive::to_f64(&v).ok_or_else(|| vortex_err!("Cannot sum {v} as f64"))?;
+                Ok(acc.unwrap_or(0.0) + v)
+            })
+        } else if ptype.is_signed_int() {
+            fold_groups(values, validity, groups, |acc: Option<i64>, v| {
+                let v = v.to_i64().ok_or_else(|| vortex_err!("Cannot sum {v} as i64"))?;
+                acc.unwrap_or(0).checked_add(v).ok_or_else(|| vortex_err!("Sum overflows i64"))
+            })
+        } else {
+            fold_groups(values, validity, groups, |acc: Option<u64>, v| {
+                let v = v.to_u64().ok_or_else(|| vortex_err!("Cannot sum {v} as u64"))?;
+                acc.unwrap_or(0).checked_add(v).ok_or_else(|| vortex_err!("Sum overflows u64"))
+            })
+        }
+    })
+}
+
+/// Fold the non-null values of each group into a nullable accumulator, which is null for groups
+/// without any non-null values.
+fn fold_groups<T: Copy, A: NativePType>(
+    values: &[T],
+    validity: Option<&NullBuffer>,
+    groups: &GroupIds,
+    mut f: impl FnMut(Option<A>, T) -> VortexResult<A>,
+) -> VortexResult<ArrayData> {
+    let mut acc: Vec<Option<A>> = vec![None; groups.ngroups()];
+    for (row, (&value, &id)) in values.iter().zip(groups.ids()).enumerate() {
+        if is_valid(validity, row) {
+            acc[id] = Some(f(acc[id], value)?);
+        }
+    }
+    Ok(PrimitiveArray::from_nullable_vec(acc).into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::Nullability;
+
+    use super::*;
+    use crate::array::{BoolArray, VarBinViewArray};
+    use crate::compute::scalar_at;
+
+    #[test]
+    fn group_primitive_and_null_keys() {
+        let keys =
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(2), Some(1), None])
+                .into_array();
+        let groups = group_ids(&keys).unwrap();
+        assert_eq!(groups.ids(), [0, 1, 2, 0, 1]);
+        assert_eq!(groups.first_rows(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn aggregate_groups() {
+        let keys = VarBinViewArray::from_iter_str(["a", "b", "a", "c", "b", "a"]).into_array();
+        let values = PrimitiveArray::from_nullable_vec(vec![
+            Some(5i32),
+            None,
+            Some(-2),
+            Some(7),
+            None,
+            Some(1),
+        ])
+        .into_array();
+        let grouped = groupby(
+            &[keys],
+            &[
+                Aggregate::new(AggregateFunction::Count, values.clone()),
+                Aggregate::new(AggregateFunction::Sum, values.clone()),
+                Aggregate::new(AggregateFunction::Min, values.clone()),
+                Aggregate::new(AggregateFunction::Max, values),
+            ],
+        )
+        .unwrap();
+
+        let keys = grouped.keys()[0]
+            .clone()
+            .into_varbinview()
+            .unwrap()
+            .with_iterator(|iter| {
+                iter.map(|v| String::from_utf8(v.unwrap().to_vec()).unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(keys, ["a", "b", "c"]);
+
+        let [count, sum, min, max] = grouped.aggregates() else {
+            unreachable!()
+        };
+        assert_eq!(
+            count
+                .clone()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            [3, 0, 1]
+        );
+        assert_eq!(
+            sum.dtype(),
+            &DType::Primitive(PType::I64, Nullability::Nullable)
+        );
+        let sums = (0..3)
+            .map(|i| i64::try_from(&scalar_at(sum, i).unwrap()).ok())
+            .collect::<Vec<_>>();
+        assert_eq!(sums, [Some(4), None, Some(7)]);
+        assert_eq!(i32::try_from(&scalar_at(min, 0).unwrap()).unwrap(), -2);
+        assert_eq!(i32::try_from(&scalar_at(max, 0).unwrap()).unwrap(), 5);
+        assert!(!max.is_valid(1));
+    }
+
+    #[test]
+    fn group_multiple_keys() {
+        let bools = BoolArray::from_iter([true, true, false, true]).into_array();
+        let numbers = PrimitiveArray::from(vec![1u8, 2, 1, 1]).into_array();
+        let values = PrimitiveArray::from(vec![1.5f64, 2.0, 3.0, 4.0]).into_array();
+        let grouped = groupby(
+            &[bools, numbers],
+            &[Aggregate::new(AggregateFunction::Sum, values)],
+        )
+        .unwrap();
+        assert_eq!(
+            grouped.keys()[1]
+                .clone()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u8>(),
+            [1, 2, 1]
+        );
+        assert_eq!(
+            grouped.aggregates()[0]
+                .clone()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<f64>(),
+            [5.5, 2.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn mismatched_lengths() {
+        let keys = PrimitiveArray::from(vec![1u8, 2]).into_array();
+        let values = PrimitiveArray::from(vec![1u8]).into_array();
+        assert!(groupby(&[keys], &[Aggregate::new(AggregateFunction::Sum, values)]).is_err());
+    }
+}
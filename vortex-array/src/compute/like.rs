@@ -1,9 +1,12 @@
-use vortex_dtype::DType;
+use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_scalar::{Scalar, Utf8Scalar};
 
+use crate::accessor::ArrayAccessor;
+use crate::array::{ConstantArray, VarBinViewArray};
 use crate::arrow::{Datum, FromArrowArray};
 use crate::encoding::Encoding;
-use crate::{ArrayDType, ArrayData};
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
 
 pub trait LikeFn<Array> {
     fn like(
@@ -36,10 +39,13 @@ where
 }
 
 /// Options for SQL LIKE function
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LikeOptions {
     pub negated: bool,
     pub case_insensitive: bool,
+    /// The character that makes the character after it match literally, as in
+    /// `LIKE '100#%' ESCAPE '#'`, or `None` for the default `\`.
+    pub escape_char: Option<char>,
 }
 
 /// Perform SQL left LIKE right
@@ -47,16 +53,27 @@ pub struct LikeOptions {
 /// There are two wildcards supported with the LIKE operator:
 /// - %: matches zero or more characters
 /// - _: matches exactly one character
+///
+/// A wildcard preceded by the escape character of the options matches itself.
 pub fn like(
     array: &ArrayData,
     pattern: &ArrayData,
     options: LikeOptions,
 ) -> VortexResult<ArrayData> {
-    if !matches!(array.dtype(), DType::Utf8(..)) {
+    if !matches!(array.dtype(), DType::Utf8(..) | DType::Null) {
         vortex_bail!("Expected utf8 array, got {}", array.dtype());
     }
-    if !matches!(pattern.dtype(), DType::Utf8(..)) {
-        vortex_bail!("Expected utf8 pattern, got {}", array.dtype());
+    if !matches!(pattern.dtype(), DType::Utf8(..) | DType::Null) {
+        vortex_bail!("Expected utf8 pattern, got {}", pattern.dtype());
+    }
+
+    // Matching a null value or a null pattern is null.
+    if array.is_constant_null() || pattern.is_constant_null() {
+        return Ok(ConstantArray::new(
+            Scalar::null(DType::Bool(Nullability::Nullable)),
+            array.len(),
+        )
+        .into_array());
     }
 
     if let Some(f) = array.encoding().like_fn() {
@@ -92,9 +109,12 @@ pub(crate) fn arrow_like(
     pattern: &ArrayData,
     options: LikeOptions,
 ) -> VortexResult<ArrayData> {
-    let nullable = child.dtype().is_nullable();
+    let nullable = child.dtype().is_nullable() || pattern.dtype().is_nullable();
     let child = Datum::try_from(child.clone())?;
-    let pattern = Datum::try_from(pattern.clone())?;
+    let pattern = match options.escape_char {
+        Some(escape) if escape != '\\' => Datum::try_from(with_default_escape(pattern, escape)?)?,
+        _ => Datum::try_from(pattern.clone())?,
+    };
 
     let array = match (options.negated, options.case_insensitive) {
         (false, false) => arrow_string::like::like(&child, &pattern)?,
@@ -105,3 +125,118 @@ pub(crate) fn arrow_like(
 
     Ok(ArrayData::from_arrow(&array, nullable))
 }
+
+/// Rewrite patterns escaped with `escape` to the `\` escape character that Arrow expects.
+fn with_default_escape(pattern: &ArrayData, escape: char) -> VortexResult<ArrayData> {
+    if let Some(scalar) = pattern.as_constant() {
+        let value = Utf8Scalar::try_from(&scalar)?
+            .value()
+            .ok_or_else(|| vortex_err!("Null patterns match nothing"))?;
+        return Ok(ConstantArray::new(
+            Scalar::utf8(
+                rewrite_escapes(value.as_str(), escape)?,
+                pattern.dtype().nullability(),
+            ),
+            pattern.len(),
+        )
+        .into_array());
+    }
+
+    let patterns = pattern.clone().into_varbinview()?.with_iterator(|iter| {
+        iter.map(|p| {
+            p.map(|p| rewrite_escapes(std::str::from_utf8(p)?, escape))
+                .transpose()
+        })
+        .collect::<VortexResult<Vec<_>>>()
+    })??;
+    Ok(VarBinViewArray::from_iter_nullable_str(patterns).into_array())
+}
+
+/// Rewrite a pattern escaped with `escape` to one escaped with `\`, in which Arrow reads a
+/// backslash as escaping whatever character follows it.
+fn rewrite_escapes(pattern: &str, escape: char) -> VortexResult<String> {
+    let mut rewritten = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        let (literal, wildcard) = if c == escape {
+            let escaped = chars.next().ok_or_else(|| {
+                vortex_err!("LIKE pattern {pattern:?} ends with its escape character")
+            })?;
+            (escaped, false)
+        } else {
+            (c, matches!(c, '%' | '_'))
+        };
+        if !wildcard && matches!(literal, '%' | '_' | '\\') {
+            rewritten.push('\\');
+        }
+        rewritten.push(literal);
+    }
+    Ok(rewritten)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::VarBinViewArray;
+    use crate::compute::{like, LikeOptions};
+    use crate::{IntoArrayData, IntoArrayVariant};
+
+    fn matches(values: &[&str], pattern: &str, options: LikeOptions) -> Vec<bool> {
+        let values = VarBinViewArray::from_iter_str(values.iter().copied()).into_array();
+        let pattern = VarBinViewArray::from_iter_str(vec![pattern; values.len()]).into_array();
+        like(&values, &pattern, options)
+            .unwrap()
+            .into_bool()
+            .unwrap()
+            .boolean_buffer()
+            .iter()
+            .collect()
+    }
+
+    #[test]
+    fn like_with_escape_char() {
+        let values = ["100%", "1000", "a\\b", "a_b", "axb"];
+        let options = LikeOptions {
+            escape_char: Some('#'),
+            ..Default::default()
+        };
+        assert_eq!(
+            matches(&values, "100#%", options),
+            [true, false, false, false, false]
+        );
+        assert_eq!(
+            matches(&values, "a\\b", options),
+            [false, false, true, false, false]
+        );
+        assert_eq!(
+            matches(&values, "a\\%", options),
+            [false, false, true, false, false]
+        );
+        assert_eq!(
+            matches(&values, "a#_b", options),
+            [false, false, false, true, false]
+        );
+        assert_eq!(
+            matches(&values, "a_b", options),
+            [false, false, true, true, true]
+        );
+        assert_eq!(
+            matches(&values, "100\\%", LikeOptions::default()),
+            [true, false, false, false, false]
+        );
+
+        let values = VarBinViewArray::from_iter_str(["a"]).into_array();
+        let pattern = VarBinViewArray::from_iter_str(["a#"]).into_array();
+        assert!(like(&values, &pattern, options).is_err());
+    }
+
+    #[test]
+    fn like_case_insensitive_negated() {
+        let values = ["Apple", "apricot", "banana"];
+        let options = LikeOptions {
+            negated: true,
+            case_insensitive: true,
+            escape_char: None,
+        };
+        assert_eq!(matches(&values, "AP%", options), [false, false, true]);
+    }
+}
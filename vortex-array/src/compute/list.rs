@@ -0,0 +1,188 @@
+//! Functions on the rows of list arrays, as needed to unnest them.
+
+use arrow_buffer::{BooleanBufferBuilder, NullBuffer};
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::{ListArray, PrimitiveArray, SparseArray};
+use crate::compute::{filter, slice, take, try_cast, FilterMask};
+use crate::{ArrayDType, ArrayData, ArrayLen, ArrayValidity, IntoArrayData, IntoArrayVariant};
+
+/// The number of elements in each row of a list array, as u64s that are null where the list is.
+pub fn list_length(array: impl AsRef<ArrayData>) -> VortexResult<ArrayData> {
+    let list = array.as_ref().clone().into_list()?;
+    let offsets = u64_offsets(&list)?;
+    let lengths = offsets
+        .maybe_null_slice::<u64>()
+        .windows(2)
+        .map(|w| w[1] - w[0])
+        .collect::<Vec<_>>();
+    Ok(PrimitiveArray::from_vec(lengths, list.validity()).into_array())
+}
+
+/// The element at `index` within each row of a list array.
+///
+/// The result has the nullable element dtype, and is null for rows that are null or have no more
+/// than `index` elements.
+pub fn list_element_at(array: impl AsRef<ArrayData>, index: usize) -> VortexResult<ArrayData> {
+    let list = array.as_ref().clone().into_list()?;
+    let offsets = u64_offsets(&list)?;
+    let nulls = list.logical_validity().to_null_buffer()?;
+    let dtype = element_dtype(&list)?.as_nullable();
+
+    let mut rows = Vec::new();
+    let mut element_indices = Vec::new();
+    for (row, w) in offsets.maybe_null_slice::<u64>().windows(2).enumerate() {
+        if is_valid(nulls.as_ref(), row) && w[1] - w[0] > index as u64 {
+            rows.push(row as u64);
+            element_indices.push(w[0] + index as u64);
+        }
+    }
+
+    let values = try_cast(
+        take(
+            list.elements(),
+            PrimitiveArray::from(element_indices).into_array(),
+        )?,
+        &dtype,
+    )?;
+    if rows.len() == list.len() {
+        return Ok(values);
+    }
+    SparseArray::try_new(
+        PrimitiveArray::from(rows).into_array(),
+        values,
+        list.len(),
+        Scalar::null(dtype),
+    )
+    .map(IntoArrayData::into_array)
+}
+
+/// Flatten a list array into the elements of its rows, in order, along with the index of the row
+/// each element came from, as u64s.
+///
+/// Null rows contribute no elements.
+pub fn list_flatten(array: impl AsRef<ArrayData>) -> VortexResult<(ArrayData, ArrayData)> {
+    let list = array.as_ref().clone().into_list()?;
+    let offsets = u64_offsets(&list)?;
+    let offsets = offsets.maybe_null_slice::<u64>();
+    let nulls = list.logical_validity().to_null_buffer()?;
+
+    let first = usize::try_from(offsets[0])?;
+    let last = usize::try_from(offsets[list.len()])?;
+    let elements = slice(list.elements(), first, last)?;
+
+    let mut parent_indices = Vec::with_capacity(elements.len());
+    for (row, w) in offsets.windows(2).enumerate() {
+        if is_valid(nulls.as_ref(), row) {
+            let len = usize::try_from(w[1] - w[0])?;
+            parent_indices.extend(std::iter::repeat(row as u64).take(len));
+        }
+    }
+
+    // Only the elements of null rows need to be filtered out.
+    let elements = if parent_indices.len() == elements.len() {
+        elements
+    } else {
+        let mut mask = BooleanBufferBuilder::new(elements.len());
+        for (row, w) in offsets.windows(2).enumerate() {
+            mask.append_n(usize::try_from(w[1] - w[0])?, is_valid(nulls.as_ref(), row));
+        }
+        filter(&elements, FilterMask::from(mask.finish()))?
+    };
+
+    Ok((elements, PrimitiveArray::from(parent_indices).into_array()))
+}
+
+fn u64_offsets(list: &ListArray) -> VortexResult<PrimitiveArray> {
+    try_cast(
+        list.offsets(),
+        &DType::Primitive(PType::U64, Nullability::NonNullable),
+    )?
+    .into_primitive()
+}
+
+fn element_dtype(list: &ListArray) -> VortexResult<&DType> {
+    list.dtype()
+        .as_list_element()
+        .ok_or_else(|| vortex_err!("Expected list dtype, got {}", list.dtype()))
+}
+
+fn is_valid(nulls: Option<&NullBuffer>, row: usize) -> bool {
+    nulls.map_or(true, |nulls| nulls.is_valid(row))
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ListArray, PrimitiveArray};
+    use crate::compute::{list_element_at, list_flatten, list_length, scalar_at};
+    use crate::validity::Validity;
+    use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
+
+    // [[1, 2], null, [], [3, 4, 5]], where the null row has the elements [9].
+    fn list() -> ArrayData {
+        ListArray::try_new(
+            PrimitiveArray::from(vec![1i32, 2, 9, 3, 4, 5]).into_array(),
+            PrimitiveArray::from(vec![0u32, 2, 3, 3, 6]).into_array(),
+            Validity::from_iter([true, false, true, true]),
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    #[test]
+    fn lengths() {
+        let lengths = list_length(list()).unwrap();
+        assert_eq!(
+            lengths.dtype(),
+            &DType::Primitive(PType::U64, Nullability::Nullable)
+        );
+        assert_eq!(
+            (0..4)
+                .map(|i| scalar_at(&lengths, i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Scalar::primitive(2u64, Nullability::Nullable),
+                Scalar::null(lengths.dtype().clone()),
+                Scalar::primitive(0u64, Nullability::Nullable),
+                Scalar::primitive(3u64, Nullability::Nullable),
+            ]
+        );
+    }
+
+    #[test]
+    fn element_at() {
+        let second = list_element_at(list(), 1).unwrap();
+        assert_eq!(
+            (0..4)
+                .map(|i| scalar_at(&second, i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Scalar::primitive(2i32, Nullability::Nullable),
+                Scalar::null(second.dtype().clone()),
+                Scalar::null(second.dtype().clone()),
+                Scalar::primitive(4i32, Nullability::Nullable),
+            ]
+        );
+    }
+
+    #[test]
+    fn flatten() {
+        let (elements, parent_indices) = list_flatten(list()).unwrap();
+        assert_eq!(
+            elements.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            parent_indices
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[0, 0, 3, 3, 3]
+        );
+    }
+}
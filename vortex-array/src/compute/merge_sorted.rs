@@ -0,0 +1,267 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use arrow_array::Array;
+use arrow_ord::ord::{make_comparator, DynComparator};
+use arrow_schema::SortOptions;
+use arrow_select::concat::concat;
+use vortex_dtype::field::Field;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::array::{ChunkedArray, PrimitiveArray};
+use crate::compute::take;
+use crate::variants::StructArrayTrait;
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant, IntoCanonical};
+
+/// A column, and the order of its values, by which the rows of struct arrays are sorted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortKey {
+    field: Field,
+    descending: bool,
+    nulls_first: bool,
+}
+
+impl SortKey {
+    /// Sort by the field in ascending order, with nulls first.
+    pub fn new(field: impl Into<Field>) -> Self {
+        Self {
+            field: field.into(),
+            descending: false,
+            nulls_first: true,
+        }
+    }
+
+    pub fn with_descending(mut self, descending: bool) -> Self {
+        self.descending = descending;
+        self
+    }
+
+    pub fn with_nulls_first(mut self, nulls_first: bool) -> Self {
+        self.nulls_first = nulls_first;
+        self
+    }
+
+    pub fn field(&self) -> &Field {
+        &self.field
+    }
+
+    pub fn descending(&self) -> bool {
+        self.descending
+    }
+
+    pub fn nulls_first(&self) -> bool {
+        self.nulls_first
+    }
+
+    fn options(&self) -> SortOptions {
+        SortOptions {
+            descending: self.descending,
+            nulls_first: self.nulls_first,
+        }
+    }
+}
+
+/// Merge arrays that are each sorted by the sort keys into one sorted array.
+///
+/// The arrays must be struct arrays containing the fields of the sort keys, or with no sort keys,
+/// arrays whose values are themselves sorted in ascending order with nulls first. Rows that
+/// compare equal keep the order of the arrays they came from, and their order within them.
+pub fn merge_sorted(arrays: &[ArrayData], sort_keys: &[SortKey]) -> VortexResult<ArrayData> {
+    let indices = merge_sorted_indices(arrays, sort_keys)?;
+    let Some(first) = arrays.first() else {
+        vortex_bail!("Cannot merge no arrays");
+    };
+    if let [array] = arrays {
+        return Ok(array.clone());
+    }
+    let chunked = ChunkedArray::try_new(arrays.to_vec(), first.dtype().clone())?.into_array();
+    take(chunked, indices)
+}
+
+/// The order in which [merge_sorted] merges the rows of the arrays, as indices into the arrays
+/// concatenated in order.
+pub fn merge_sorted_indices(
+    arrays: &[ArrayData],
+    sort_keys: &[SortKey],
+) -> VortexResult<ArrayData> {
+    let Some(first) = arrays.first() else {
+        vortex_bail!("Cannot merge no arrays");
+    };
+    if let Some(array) = arrays.iter().find(|a| a.dtype() != first.dtype()) {
+        vortex_bail!(
+            "Cannot merge arrays of different types {} and {}",
+            first.dtype(),
+            array.dtype()
+        );
+    }
+
+    // Each key of all arrays is concatenated into one Arrow array, so that any two rows can be
+    // compared by their index in the concatenation.
+    let comparators = if sort_keys.is_empty() {
+        vec![concat_comparator(arrays, SortOptions::default(), |a| {
+            Ok(a.clone())
+        })?]
+    } else {
+        sort_keys
+            .iter()
+            .map(|key| concat_comparator(arrays, key.options(), |a| sort_column(a, key.field())))
+            .collect::<VortexResult<Vec<_>>>()?
+    };
+    let compare = |left: usize, right: usize| {
+        comparators
+            .iter()
+            .map(|cmp| cmp(left, right))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    };
+
+    let mut offset = 0;
+    let mut heap = BinaryHeap::with_capacity(arrays.len());
+    for (source, array) in arrays.iter().enumerate() {
+        if !array.is_empty() {
+            heap.push(Cursor {
+                row: offset,
+                end: offset + array.len(),
+                source,
+                compare: &compare,
+            });
+        }
+        offset += array.len();
+    }
+
+    let mut indices = Vec::with_capacity(offset);
+    while let Some(mut cursor) = heap.pop() {
+        indices.push(cursor.row as u64);
+        cursor.row += 1;
+        if cursor.row < cursor.end {
+            heap.push(cursor);
+        }
+    }
+    Ok(PrimitiveArray::from(indices).into_array())
+}
+
+/// A comparator of the rows of all arrays, by the column that `column` selects from each.
+fn concat_comparator(
+    arrays: &[ArrayData],
+    options: SortOptions,
+    column: impl Fn(&ArrayData) -> VortexResult<ArrayData>,
+) -> VortexResult<DynComparator> {
+    let columns = arrays
+        .iter()
+        .map(|a| column(a)?.into_canonical()?.into_arrow())
+        .collect::<VortexResult<Vec<_>>>()?;
+    let concatenated = concat(
+        &columns
+            .iter()
+            .map(|c| c.as_ref())
+            .collect::<Vec<&dyn Array>>(),
+    )?;
+    Ok(make_comparator(
+        concatenated.as_ref(),
+        concatenated.as_ref(),
+        options,
+    )?)
+}
+
+fn sort_column(array: &ArrayData, field: &Field) -> VortexResult<ArrayData> {
+    let st = array.clone().into_struct()?;
+    match field {
+        Field::Name(name) => st.field_by_name(name),
+        Field::Index(idx) => st.field(*idx),
+    }
+    .ok_or_else(|| vortex_err!("Unknown sort key {field}"))
+}
+
+/// The next row of one of the merged arrays.
+struct Cursor<'a, F> {
+    row: usize,
+    end: usize,
+    source: usize,
+    compare: &'a F,
+}
+
+impl<F: Fn(usize, usize) -> Ordering> Ord for Cursor<'_, F> {
+    // Reversed, so that the max-heap pops the smallest row, and of equal rows the earliest array.
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.compare)(other.row, self.row).then_with(|| other.source.cmp(&self.source))
+    }
+}
+
+impl<F: Fn(usize, usize) -> Ordering> PartialOrd for Cursor<'_, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: Fn(usize, usize) -> Ordering> PartialEq for Cursor<'_, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<F: Fn(usize, usize) -> Ordering> Eq for Cursor<'_, F> {}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{PrimitiveArray, StructArray};
+    use crate::compute::{merge_sorted, merge_sorted_indices, SortKey};
+    use crate::validity::ArrayValidity;
+    use crate::variants::StructArrayTrait;
+    use crate::{IntoArrayData, IntoArrayVariant};
+
+    #[test]
+    fn merge_primitives() {
+        let arrays = [
+            PrimitiveArray::from_nullable_vec(vec![None, Some(1i32), Some(4), Some(9)])
+                .into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(2i32), Some(4), Some(5)]).into_array(),
+            PrimitiveArray::from_nullable_vec(Vec::<Option<i32>>::new()).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![None, Some(0i32)]).into_array(),
+        ];
+
+        let indices = merge_sorted_indices(&arrays, &[])
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(
+            indices.maybe_null_slice::<u64>(),
+            [0, 7, 8, 1, 4, 2, 5, 6, 3]
+        );
+
+        let merged = merge_sorted(&arrays, &[])
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(merged.maybe_null_slice::<i32>()[3..], [1, 2, 4, 4, 5, 9]);
+        assert_eq!(merged.logical_validity().null_count().unwrap(), 2);
+    }
+
+    #[test]
+    fn merge_by_keys() {
+        let batch = |keys: Vec<u32>, ids: Vec<u8>| {
+            StructArray::from_fields(&[
+                ("key", PrimitiveArray::from(keys).into_array()),
+                ("id", PrimitiveArray::from(ids).into_array()),
+            ])
+            .unwrap()
+            .into_array()
+        };
+        let arrays = [
+            batch(vec![9, 5, 1], vec![0, 1, 2]),
+            batch(vec![7, 5], vec![3, 4]),
+        ];
+
+        let merged = merge_sorted(&arrays, &[SortKey::new("key").with_descending(true)])
+            .unwrap()
+            .into_struct()
+            .unwrap();
+        let ids = merged
+            .field_by_name("id")
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(ids.maybe_null_slice::<u8>(), [0, 3, 1, 4, 2]);
+
+        assert!(merge_sorted(&arrays, &[SortKey::new("missing")]).is_err());
+    }
+}
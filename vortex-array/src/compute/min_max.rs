@@ -0,0 +1,168 @@
+use arrow_array::Array;
+use arrow_ord::ord::make_comparator;
+use arrow_schema::SortOptions;
+use vortex_dtype::DType;
+use vortex_error::{vortex_err, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::compute::scalar_at;
+use crate::encoding::Encoding;
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoCanonical};
+
+/// The smallest and largest valid values of an array, as scalars of the array's dtype.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinMaxResult {
+    pub min: Scalar,
+    pub max: Scalar,
+}
+
+impl MinMaxResult {
+    /// The bounds of the values of both results.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            min: if other.min < self.min {
+                other.min
+            } else {
+                self.min
+            },
+            max: if other.max > self.max {
+                other.max
+            } else {
+                self.max
+            },
+        }
+    }
+
+    /// The bounds of the values of both results, either of which may be `None` for no values.
+    pub fn merge_opt(lhs: Option<Self>, rhs: Option<Self>) -> Option<Self> {
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => Some(lhs.merge(rhs)),
+            (lhs, rhs) => lhs.or(rhs),
+        }
+    }
+}
+
+/// Implementation of min_max for an encoding.
+///
+/// Unlike the [min][crate::stats::Stat::Min] and [max][crate::stats::Stat::Max] statistics, which
+/// may be missing or only bound the values, the result must be the exact bounds of the valid
+/// values of the array.
+pub trait MinMaxFn<Array> {
+    fn min_max(&self, array: &Array) -> VortexResult<Option<MinMaxResult>>;
+}
+
+impl<E: Encoding> MinMaxFn<ArrayData> for E
+where
+    E: MinMaxFn<E::Array>,
+    for<'a> &'a E::Array: TryFrom<&'a ArrayData, Error = VortexError>,
+{
+    fn min_max(&self, array: &ArrayData) -> VortexResult<Option<MinMaxResult>> {
+        let array_ref = <&E::Array>::try_from(array)?;
+        let encoding = array
+            .encoding()
+            .as_any()
+            .downcast_ref::<E>()
+            .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
+        MinMaxFn::min_max(encoding, array_ref)
+    }
+}
+
+/// Compute the smallest and largest valid values of an array, or `None` if it has no valid values.
+///
+/// This always computes the bounds from the values, so unlike the statistics of an array it is
+/// exact after slicing. Floats are ordered by their total order, in which NaN is the largest value.
+pub fn min_max(array: impl AsRef<ArrayData>) -> VortexResult<Option<MinMaxResult>> {
+    let array = array.as_ref();
+    if array.is_empty() || array.dtype() == &DType::Null {
+        return Ok(None);
+    }
+
+    let result = min_max_impl(array)?;
+
+    debug_assert!(
+        result
+            .as_ref()
+            .map_or(true, |r| r.min.dtype() == array.dtype()
+                && r.max.dtype() == array.dtype()),
+        "MinMax dtype mismatch {}",
+        array.encoding().id()
+    );
+
+    Ok(result)
+}
+
+fn min_max_impl(array: &ArrayData) -> VortexResult<Option<MinMaxResult>> {
+    if let Some(min_max_fn) = array.encoding().min_max_fn() {
+        return min_max_fn.min_max(array);
+    }
+
+    // Fallback: find the bounds with an Arrow comparator.
+    log::debug!("MinMaxFn not implemented for {}", array.encoding().id());
+    let canonical = array.clone().into_canonical()?.into_array();
+    let arrow = canonical.clone().into_arrow()?;
+    let compare = make_comparator(arrow.as_ref(), arrow.as_ref(), SortOptions::default())?;
+
+    let mut valid = (0..arrow.len()).filter(|&idx| arrow.is_valid(idx));
+    let Some(first) = valid.next() else {
+        return Ok(None);
+    };
+    let (min, max) = valid.fold((first, first), |(min, max), idx| {
+        (
+            if compare(idx, min).is_lt() { idx } else { min },
+            if compare(idx, max).is_gt() { idx } else { max },
+        )
+    });
+
+    Ok(Some(MinMaxResult {
+        min: scalar_at(&canonical, min)?,
+        max: scalar_at(&canonical, max)?,
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::array::{BoolArray, PrimitiveArray, VarBinViewArray};
+    use crate::compute::{min_max, slice, MinMaxResult};
+    use crate::IntoArrayData;
+
+    #[test]
+    fn min_max_primitive() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(3i32), None, Some(-2), Some(7)]);
+        assert_eq!(
+            min_max(&array).unwrap(),
+            Some(MinMaxResult {
+                min: Scalar::from(Some(-2i32)),
+                max: Scalar::from(Some(7i32)),
+            })
+        );
+    }
+
+    #[test]
+    fn min_max_sliced() {
+        let array = PrimitiveArray::from(vec![9u8, 1, 5, 4, 0]).into_array();
+        let sliced = slice(&array, 1, 4).unwrap();
+        let result = min_max(&sliced).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(1u8));
+        assert_eq!(result.max, Scalar::from(5u8));
+    }
+
+    #[test]
+    fn min_max_other_types() {
+        let strings = VarBinViewArray::from_iter_str(["b", "c", "a"]).into_array();
+        let result = min_max(&strings).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from("a"));
+        assert_eq!(result.max, Scalar::from("c"));
+
+        let bools = BoolArray::from_iter([true, true]).into_array();
+        let result = min_max(&bools).unwrap().unwrap();
+        assert_eq!(result.min, Scalar::from(true));
+    }
+
+    #[test]
+    fn min_max_all_null() {
+        let array = PrimitiveArray::from_nullable_vec(vec![None::<i64>, None]);
+        assert_eq!(min_max(&array).unwrap(), None);
+    }
+}
@@ -7,24 +7,40 @@
 //! implementations of these operators, else we will decode, and perform the equivalent operator
 //! from Arrow.
 
+pub use array_eq::{array_compare_elementwise, array_eq, ArrayDiff, ArrayDifference};
 pub use binary_numeric::*;
 pub use boolean::{
-    and, and_kleene, binary_boolean, or, or_kleene, BinaryBooleanFn, BinaryOperator,
+    and, and_kleene, binary_boolean, or, or_kleene, xor, BinaryBooleanFn, BinaryOperator,
 };
 pub use cast::{try_cast, CastFn};
-pub use compare::{compare, scalar_cmp, CompareFn, Operator};
+pub use compare::{
+    compare, compare_with_options, scalar_cmp, CompareFn, CompareOptions, NullEquality, Operator,
+};
 pub use fill_forward::{fill_forward, FillForwardFn};
 pub use fill_null::{fill_null, FillNullFn};
-pub use filter::{filter, FilterFn, FilterIter, FilterMask};
+pub use filter::{
+    filter, FilterFn, FilterIter, FilterMask, FILTER_SLICES_MIN_RUN_LENGTH,
+    FILTER_SLICES_SELECTIVITY_THRESHOLD,
+};
+pub use groupby::{
+    group_ids, groupby, Aggregate, AggregateFunction, GroupIds, GroupIdsFn, Grouped,
+};
 pub use invert::{invert, InvertFn};
 pub use like::{like, LikeFn, LikeOptions};
+pub use list::{list_element_at, list_flatten, list_length};
+pub use merge_sorted::{merge_sorted, merge_sorted_indices, SortKey};
+pub use min_max::{min_max, MinMaxFn, MinMaxResult};
+pub use sample::{sample, SampleFn, SampleSize};
 pub use scalar_at::{scalar_at, ScalarAtFn};
 pub use search_sorted::*;
 pub use slice::{slice, SliceFn};
-pub use take::{take, TakeFn};
+pub use sum::{sum, sum_ptype, SumAccumulator, SumFn};
+pub(crate) use take::{null_on_oob_indices, with_nulls};
+pub use take::{take, take_unchecked, take_with_options, IndexValidation, TakeFn, TakeOptions};
 
 use crate::ArrayData;
 
+mod array_eq;
 mod binary_numeric;
 mod boolean;
 mod cast;
@@ -32,11 +48,17 @@ mod compare;
 mod fill_forward;
 mod fill_null;
 mod filter;
+mod groupby;
 mod invert;
 mod like;
+mod list;
+mod merge_sorted;
+mod min_max;
+mod sample;
 mod scalar_at;
 mod search_sorted;
 mod slice;
+mod sum;
 mod take;
 
 /// VTable for dispatching compute functions to Vortex encodings.
@@ -91,6 +113,13 @@ pub trait ComputeVTable {
         None
     }
 
+    /// Assign the rows of an array to groups of equal values.
+    ///
+    /// See: [GroupIdsFn].
+    fn group_ids_fn(&self) -> Option<&dyn GroupIdsFn<ArrayData>> {
+        None
+    }
+
     /// Invert a boolean array. Converts true -> false, false -> true, null -> null.
     ///
     /// See [InvertFn]
@@ -105,6 +134,20 @@ pub trait ComputeVTable {
         None
     }
 
+    /// Compute the exact bounds of the valid values of an array.
+    ///
+    /// See: [MinMaxFn].
+    fn min_max_fn(&self) -> Option<&dyn MinMaxFn<ArrayData>> {
+        None
+    }
+
+    /// Select a random subset of the rows of an array.
+    ///
+    /// See: [SampleFn].
+    fn sample_fn(&self) -> Option<&dyn SampleFn<ArrayData>> {
+        None
+    }
+
     /// Single item indexing on Vortex arrays.
     ///
     /// See: [ScalarAtFn].
@@ -133,6 +176,13 @@ pub trait ComputeVTable {
         None
     }
 
+    /// Sum the valid values of a primitive array.
+    ///
+    /// See: [SumFn].
+    fn sum_fn(&self) -> Option<&dyn SumFn<ArrayData>> {
+        None
+    }
+
     /// Take a set of indices from an array. This often forces allocations and decoding of
     /// the receiver.
     ///
@@ -0,0 +1,146 @@
+use arrow_buffer::BooleanBuffer;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::compute::{filter, FilterMask};
+use crate::encoding::Encoding;
+use crate::{ArrayDType, ArrayData};
+
+/// How many rows of an array to [sample].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SampleSize {
+    /// Select each row independently with the given probability, i.e. Bernoulli sampling, so that
+    /// the number of rows selected varies around that fraction of the array.
+    Fraction(f64),
+    /// Select exactly this many rows, uniformly at random without replacement, or every row if
+    /// the array is shorter.
+    Count(usize),
+}
+
+pub trait SampleFn<Array> {
+    /// Select a random subset of the rows of the array, keeping them in order.
+    ///
+    /// The size is validated, and counts of at least the length of the array are handled, before
+    /// this is called.
+    fn sample(&self, array: &Array, size: SampleSize, seed: u64) -> VortexResult<ArrayData>;
+}
+
+impl<E: Encoding> SampleFn<ArrayData> for E
+where
+    E: SampleFn<E::Array>,
+    for<'a> &'a E::Array: TryFrom<&'a ArrayData, Error = VortexError>,
+{
+    fn sample(&self, array: &ArrayData, size: SampleSize, seed: u64) -> VortexResult<ArrayData> {
+        let array_ref = <&E::Array>::try_from(array)?;
+        let encoding = array
+            .encoding()
+            .as_any()
+            .downcast_ref::<E>()
+            .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
+        SampleFn::sample(encoding, array_ref, size, seed)
+    }
+}
+
+/// Select a random subset of the rows of an array, in their original order.
+///
+/// The same seed always selects the same rows of an array, so that samples can be reproduced,
+/// e.g. when compressing or computing approximate statistics of a column.
+pub fn sample(
+    array: impl AsRef<ArrayData>,
+    size: SampleSize,
+    seed: u64,
+) -> VortexResult<ArrayData> {
+    let array = array.as_ref();
+    match size {
+        SampleSize::Fraction(fraction) if !(0.0..=1.0).contains(&fraction) => {
+            vortex_bail!("Sample fraction must be between 0 and 1, got {fraction}")
+        }
+        SampleSize::Count(count) if count >= array.len() => return Ok(array.clone()),
+        _ => {}
+    }
+
+    let sampled = match array.encoding().sample_fn() {
+        Some(f) => f.sample(array, size, seed)?,
+        None => filter(array, sample_mask(array.len(), size, seed))?,
+    };
+
+    debug_assert_eq!(
+        sampled.dtype(),
+        array.dtype(),
+        "Sample dtype mismatch {}",
+        array.encoding().id()
+    );
+    if let SampleSize::Count(count) = size {
+        debug_assert_eq!(
+            sampled.len(),
+            count,
+            "Sample length mismatch {}",
+            array.encoding().id()
+        );
+    }
+
+    Ok(sampled)
+}
+
+/// A mask selecting a random subset of `len` rows.
+fn sample_mask(len: usize, size: SampleSize, seed: u64) -> FilterMask {
+    let mut rng = StdRng::seed_from_u64(seed);
+    match size {
+        SampleSize::Fraction(fraction) => {
+            FilterMask::from(BooleanBuffer::collect_bool(len, |_| rng.gen_bool(fraction)))
+        }
+        SampleSize::Count(count) => {
+            FilterMask::from_indices(len, rand::seq::index::sample(&mut rng, len, count.min(len)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::PrimitiveArray;
+    use crate::compute::{sample, SampleSize};
+    use crate::{IntoArrayData, IntoArrayVariant};
+
+    #[test]
+    fn sample_count() {
+        let array = PrimitiveArray::from((0..1000u32).collect::<Vec<_>>()).into_array();
+
+        let sampled = sample(&array, SampleSize::Count(100), 42)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        let values = sampled.maybe_null_slice::<u32>();
+        assert_eq!(values.len(), 100);
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+
+        let again = sample(&array, SampleSize::Count(100), 42)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(again.maybe_null_slice::<u32>(), values);
+
+        assert_eq!(
+            sample(&array, SampleSize::Count(2000), 42).unwrap().len(),
+            1000
+        );
+    }
+
+    #[test]
+    fn sample_fraction() {
+        let array = PrimitiveArray::from(vec![1u8; 10_000]).into_array();
+        assert_eq!(
+            sample(&array, SampleSize::Fraction(0.0), 7).unwrap().len(),
+            0
+        );
+        assert_eq!(
+            sample(&array, SampleSize::Fraction(1.0), 7).unwrap().len(),
+            10_000
+        );
+        let len = sample(&array, SampleSize::Fraction(0.1), 7).unwrap().len();
+        assert!((800..1200).contains(&len), "sampled {len} rows");
+
+        assert!(sample(&array, SampleSize::Fraction(1.5), 7).is_err());
+        assert!(sample(&array, SampleSize::Fraction(f64::NAN), 7).is_err());
+    }
+}
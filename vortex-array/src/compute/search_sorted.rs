@@ -7,8 +7,9 @@ use itertools::Itertools;
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_scalar::Scalar;
 
-use crate::compute::scalar_at;
+use crate::compute::{scalar_at, slice};
 use crate::encoding::Encoding;
+use crate::stats::ArrayStatistics;
 use crate::{ArrayDType, ArrayData};
 
 #[derive(Debug, Copy, Clone)]
@@ -26,6 +27,25 @@ impl Display for SearchSortedSide {
     }
 }
 
+/// Where null values are placed relative to non-null values in a sorted array.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NullOrder {
+    /// Nulls sort before all non-null values.
+    First,
+    /// Nulls sort after all non-null values.
+    #[default]
+    Last,
+}
+
+impl Display for NullOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NullOrder::First => write!(f, "nulls_first"),
+            NullOrder::Last => write!(f, "nulls_last"),
+        }
+    }
+}
+
 /// Result of performing search_sorted on an Array
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SearchResult {
@@ -255,6 +275,51 @@ pub fn search_sorted<T: Into<Scalar>>(
     )
 }
 
+/// Search for a value in a sorted array whose nulls are placed according to `null_order`.
+///
+/// Unlike [search_sorted], the target may be null, in which case the result locates the run of
+/// nulls in the array. Non-null targets are searched for amongst the non-null values only.
+pub fn search_sorted_with_null_order<T: Into<Scalar>>(
+    array: &ArrayData,
+    target: T,
+    side: SearchSortedSide,
+    null_order: NullOrder,
+) -> VortexResult<SearchResult> {
+    let target = target.into();
+    let null_count = array
+        .statistics()
+        .compute_null_count()
+        .ok_or_else(|| vortex_err!("Failed to compute null count for search_sorted"))?;
+    let len = array.len();
+    let (valid_start, valid_end) = match null_order {
+        NullOrder::First => (null_count, len),
+        NullOrder::Last => (0, len - null_count),
+    };
+
+    if target.is_null() {
+        let (nulls_start, nulls_end) = match null_order {
+            NullOrder::First => (0, null_count),
+            NullOrder::Last => (valid_end, len),
+        };
+        let idx = match side {
+            SearchSortedSide::Left => nulls_start,
+            SearchSortedSide::Right => nulls_end,
+        };
+        return Ok(if null_count > 0 {
+            SearchResult::Found(idx)
+        } else {
+            SearchResult::NotFound(idx)
+        });
+    }
+
+    if null_count == 0 {
+        return search_sorted(array, target, side);
+    }
+
+    let valid = slice(array, valid_start, valid_end)?;
+    Ok(search_sorted(&valid, target, side)?.map(|i| i + valid_start))
+}
+
 pub fn search_sorted_usize(
     array: &ArrayData,
     target: usize,
@@ -524,9 +589,14 @@ impl<T> Len for [T] {
 
 #[cfg(test)]
 mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
     use crate::array::PrimitiveArray;
     use crate::compute::search_sorted::{SearchResult, SearchSorted, SearchSortedSide};
-    use crate::compute::{search_sorted, search_sorted_many};
+    use crate::compute::{
+        search_sorted, search_sorted_many, search_sorted_with_null_order, NullOrder,
+    };
     use crate::validity::Validity;
     use crate::IntoArrayData;
 
@@ -599,4 +669,47 @@ mod test {
         let res = search_sorted_many(&arr, &[256], SearchSortedSide::Left).unwrap();
         assert_eq!(res, vec![SearchResult::NotFound(arr.len())]);
     }
+
+    #[test]
+    fn search_nulls_first() {
+        let arr = PrimitiveArray::from_nullable_vec(vec![None, None, Some(1i32), Some(2), Some(2)])
+            .into_array();
+        let null = Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable));
+
+        let res = search_sorted_with_null_order(
+            &arr,
+            null.clone(),
+            SearchSortedSide::Left,
+            NullOrder::First,
+        )
+        .unwrap();
+        assert_eq!(res, SearchResult::Found(0));
+        let res =
+            search_sorted_with_null_order(&arr, null, SearchSortedSide::Right, NullOrder::First)
+                .unwrap();
+        assert_eq!(res, SearchResult::Found(2));
+        let res =
+            search_sorted_with_null_order(&arr, 2i32, SearchSortedSide::Left, NullOrder::First)
+                .unwrap();
+        assert_eq!(res, SearchResult::Found(3));
+        let res =
+            search_sorted_with_null_order(&arr, 0i32, SearchSortedSide::Left, NullOrder::First)
+                .unwrap();
+        assert_eq!(res, SearchResult::NotFound(2));
+    }
+
+    #[test]
+    fn search_nulls_last() {
+        let arr = PrimitiveArray::from_nullable_vec(vec![Some(1i32), Some(2), None]).into_array();
+        let null = Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable));
+
+        let res =
+            search_sorted_with_null_order(&arr, null, SearchSortedSide::Left, NullOrder::Last)
+                .unwrap();
+        assert_eq!(res, SearchResult::Found(2));
+        let res =
+            search_sorted_with_null_order(&arr, 3i32, SearchSortedSide::Left, NullOrder::Last)
+                .unwrap();
+        assert_eq!(res, SearchResult::NotFound(2));
+    }
 }
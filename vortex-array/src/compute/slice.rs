@@ -1,7 +1,9 @@
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 
+use crate::array::SlicedArray;
 use crate::encoding::Encoding;
-use crate::{ArrayDType, ArrayData};
+use crate::stats::derive_subsequence_stats;
+use crate::{ArrayDType, ArrayData, IntoArrayData};
 
 /// Limit array to start...stop range
 pub trait SliceFn<Array> {
@@ -35,21 +37,17 @@ where
 /// underlying array.
 ///
 /// Slicing returns an error if the underlying codec's [slice](SliceFn::slice()) implementation
-/// returns an error.
+/// returns an error. Arrays whose codec doesn't implement it are wrapped in a [`SlicedArray`].
 pub fn slice(array: impl AsRef<ArrayData>, start: usize, stop: usize) -> VortexResult<ArrayData> {
     let array = array.as_ref();
     check_slice_bounds(array, start, stop)?;
 
-    let sliced = array
-        .encoding()
-        .slice_fn()
-        .map(|f| f.slice(array, start, stop))
-        .unwrap_or_else(|| {
-            Err(vortex_err!(
-                NotImplemented: "slice",
-                array.encoding().id()
-            ))
-        })?;
+    // Encodings that can't slice themselves are sliced lazily, so that their data is neither
+    // decoded nor copied until it is accessed.
+    let sliced = match array.encoding().slice_fn() {
+        Some(f) => f.slice(array, start, stop)?,
+        None => SlicedArray::try_new(array.clone(), start, stop)?.into_array(),
+    };
 
     debug_assert_eq!(
         sliced.len(),
@@ -64,6 +62,8 @@ pub fn slice(array: impl AsRef<ArrayData>, start: usize, stop: usize) -> VortexR
         array.encoding().id()
     );
 
+    derive_subsequence_stats(array, &sliced);
+
     Ok(sliced)
 }
 
@@ -0,0 +1,250 @@
+use num_traits::ToPrimitive;
+use vortex_dtype::{DType, NativePType, Nullability, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::compute::AggregateFunction;
+use crate::encoding::Encoding;
+use crate::{ArrayDType, ArrayData, IntoArrayData, IntoCanonical};
+
+/// Implementation of sum for an encoding.
+///
+/// The result must be a nullable scalar of the [sum type][sum_ptype] of the array, which is null
+/// when the array has no valid values. See [SumAccumulator] for building one.
+pub trait SumFn<Array> {
+    fn sum(&self, array: &Array) -> VortexResult<Scalar>;
+}
+
+impl<E: Encoding> SumFn<ArrayData> for E
+where
+    E: SumFn<E::Array>,
+    for<'a> &'a E::Array: TryFrom<&'a ArrayData, Error = VortexError>,
+{
+    fn sum(&self, array: &ArrayData) -> VortexResult<Scalar> {
+        let array_ref = <&E::Array>::try_from(array)?;
+        let encoding = array
+            .encoding()
+            .as_any()
+            .downcast_ref::<E>()
+            .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
+        SumFn::sum(encoding, array_ref)
+    }
+}
+
+/// The type of the sum of values of the given type: `f64` for floats, and `i64` or `u64` for
+/// signed and unsigned integers.
+pub fn sum_ptype(ptype: PType) -> PType {
+    AggregateFunction::Sum.result_ptype(ptype)
+}
+
+/// Sum the valid values of a primitive array.
+///
+/// The result is a nullable scalar of the [sum type][sum_ptype] of the array, which is null if the
+/// array has no valid values. Integer sums that overflow are an error.
+pub fn sum(array: impl AsRef<ArrayData>) -> VortexResult<Scalar> {
+    let array = array.as_ref();
+    let DType::Primitive(ptype, _) = array.dtype() else {
+        vortex_bail!("Cannot sum array with dtype {}", array.dtype())
+    };
+    let ptype = *ptype;
+
+    if array.is_empty() {
+        return Ok(SumAccumulator::new(ptype).finish());
+    }
+
+    let sum = sum_impl(array)?;
+
+    debug_assert_eq!(
+        sum.dtype(),
+        &DType::Primitive(sum_ptype(ptype), Nullability::Nullable),
+        "Sum dtype mismatch {}",
+        array.encoding().id()
+    );
+
+    Ok(sum)
+}
+
+fn sum_impl(array: &ArrayData) -> VortexResult<Scalar> {
+    if let Some(sum_fn) = array.encoding().sum_fn() {
+        return sum_fn.sum(array);
+    }
+
+    log::debug!("SumFn not implemented for {}", array.encoding().id());
+    let canonical_arr = array.clone().into_canonical()?.into_array();
+    if let Some(sum_fn) = canonical_arr.encoding().sum_fn() {
+        return sum_fn.sum(&canonical_arr);
+    }
+
+    vortex_bail!(
+        "sum not implemented for canonical encoding {}, fallback from {}",
+        canonical_arr.encoding().id(),
+        array.encoding().id()
+    )
+}
+
+/// A running sum of values, widened to the [sum type][sum_ptype] of their type.
+#[derive(Debug, Clone, Copy)]
+pub struct SumAccumulator(Accumulator);
+
+#[derive(Debug, Clone, Copy)]
+enum Accumulator {
+    F64(Option<f64>),
+    I64(Option<i64>),
+    U64(Option<u64>),
+}
+
+impl SumAccumulator {
+    /// Create an empty sum of values of the given type.
+    pub fn new(ptype: PType) -> Self {
+        Self(match sum_ptype(ptype) {
+            PType::F64 => Accumulator::F64(None),
+            PType::I64 => Accumulator::I64(None),
+            _ => Accumulator::U64(None),
+        })
+    }
+
+    /// Add `count` copies of `value` to the sum.
+    pub fn add<T: NativePType>(&mut self, value: T, count: usize) -> VortexResult<()> {
+        if count == 0 {
+            return Ok(());
+        }
+
+        match &mut self.0 {
+            Accumulator::F64(acc) => {
+                let v = value
+                    .to_f64()
+                    .ok_or_else(|| vortex_err!("Cannot sum {value} as f64"))?;
+                *acc = Some(acc.unwrap_or(0.0) + v * count as f64);
+            }
+            Accumulator::I64(acc) => {
+                let v = value
+                    .to_i64()
+                    .ok_or_else(|| vortex_err!("Cannot sum {value} as i64"))?;
+                let total = count
+                    .to_i64()
+                    .and_then(|n| v.checked_mul(n))
+                    .and_then(|v| acc.unwrap_or(0).checked_add(v))
+                    .ok_or_else(|| vortex_err!("Sum overflows i64"))?;
+                *acc = Some(total);
+            }
+            Accumulator::U64(acc) => {
+                let v = value
+                    .to_u64()
+                    .ok_or_else(|| vortex_err!("Cannot sum {value} as u64"))?;
+                let total = count
+                    .to_u64()
+                    .and_then(|n| v.checked_mul(n))
+                    .and_then(|v| acc.unwrap_or(0).checked_add(v))
+                    .ok_or_else(|| vortex_err!("Sum overflows u64"))?;
+                *acc = Some(total);
+            }
+        }
+        Ok(())
+    }
+
+    /// Add each of the values to the sum.
+    pub fn add_values<T: NativePType>(
+        &mut self,
+        values: impl IntoIterator<Item = T>,
+    ) -> VortexResult<()> {
+        match &mut self.0 {
+            Accumulator::F64(acc) => {
+                for value in values {
+                    let v = value
+                        .to_f64()
+                        .ok_or_else(|| vortex_err!("Cannot sum {value} as f64"))?;
+                    *acc = Some(acc.unwrap_or(0.0) + v);
+                }
+            }
+            Accumulator::I64(acc) => {
+                for value in values {
+                    let v = value
+                        .to_i64()
+                        .ok_or_else(|| vortex_err!("Cannot sum {value} as i64"))?;
+                    let total = acc.unwrap_or(0).checked_add(v);
+                    *acc = Some(total.ok_or_else(|| vortex_err!("Sum overflows i64"))?);
+                }
+            }
+            Accumulator::U64(acc) => {
+                for value in values {
+                    let v = value
+                        .to_u64()
+                        .ok_or_else(|| vortex_err!("Cannot sum {value} as u64"))?;
+                    let total = acc.unwrap_or(0).checked_add(v);
+                    *acc = Some(total.ok_or_else(|| vortex_err!("Sum overflows u64"))?);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Add `count` copies of a primitive scalar to the sum, ignoring it if it is null.
+    ///
+    /// This also merges in the result of another [sum] by passing a `count` of one.
+    pub fn add_scalar(&mut self, value: &Scalar, count: usize) -> VortexResult<()> {
+        let value = PrimitiveScalar::try_from(value)?;
+        match self.0 {
+            Accumulator::F64(_) => value.as_::<f64>()?.map(|v| self.add(v, count)),
+            Accumulator::I64(_) => value.as_::<i64>()?.map(|v| self.add(v, count)),
+            Accumulator::U64(_) => value.as_::<u64>()?.map(|v| self.add(v, count)),
+        }
+        .transpose()
+        .map(|_| ())
+    }
+
+    /// The sum as a nullable scalar, which is null if no values were added.
+    pub fn finish(self) -> Scalar {
+        match self.0 {
+            Accumulator::F64(acc) => Scalar::from(acc),
+            Accumulator::I64(acc) => Scalar::from(acc),
+            Accumulator::U64(acc) => Scalar::from(acc),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::array::{BoolArray, ConstantArray, PrimitiveArray};
+    use crate::compute::sum;
+    use crate::IntoArrayData;
+
+    #[test]
+    fn sum_primitive() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(-4), Some(10)]);
+        assert_eq!(sum(array).unwrap(), Scalar::from(Some(7i64)));
+
+        let array = PrimitiveArray::from(vec![1.5f32, 2.5]);
+        assert_eq!(sum(array).unwrap(), Scalar::from(Some(4.0f64)));
+    }
+
+    #[test]
+    fn sum_all_null_is_null() {
+        let array = PrimitiveArray::from_nullable_vec(vec![None::<u8>, None]);
+        assert!(sum(array).unwrap().is_null());
+
+        let array = PrimitiveArray::from(Vec::<u8>::new());
+        assert!(sum(array).unwrap().is_null());
+    }
+
+    #[test]
+    fn sum_overflow() {
+        let array = PrimitiveArray::from(vec![u64::MAX, 1]);
+        assert!(sum(array).is_err());
+    }
+
+    #[test]
+    fn sum_constant() {
+        let array = ConstantArray::new(3u16, 5).into_array();
+        assert_eq!(sum(array).unwrap(), Scalar::from(Some(15u64)));
+
+        let array = ConstantArray::new(Scalar::from(None::<i8>), 5).into_array();
+        assert!(sum(array).unwrap().is_null());
+    }
+
+    #[test]
+    fn sum_non_primitive() {
+        assert!(sum(BoolArray::from_iter([true, false]).into_array()).is_err());
+    }
+}
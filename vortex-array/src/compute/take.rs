@@ -1,21 +1,58 @@
+use std::sync::Arc;
+
+use arrow_buffer::BooleanBufferBuilder;
+use num_traits::ToPrimitive;
+use vortex_dtype::{match_each_integer_ptype, Nullability};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_scalar::Scalar;
 
+use crate::array::{
+    BoolArray, ConstantArray, DecimalArray, ExtensionArray, FixedSizeListArray, ListArray,
+    PrimitiveArray, StructArray, VarBinViewArray,
+};
 use crate::encoding::Encoding;
-use crate::stats::{ArrayStatistics, Stat};
-use crate::{ArrayDType, ArrayData, IntoArrayData, IntoCanonical};
+use crate::stats::{derive_take_stats, ArrayStatistics, Stat};
+use crate::validity::{ArrayValidity, Validity};
+use crate::variants::{ExtensionArrayTrait, PrimitiveArrayTrait, StructArrayTrait};
+use crate::{
+    ArrayDType, ArrayData, ArrayLen, Canonical, IntoArrayData, IntoArrayVariant, IntoCanonical,
+};
+
+/// How [take] treats indices that are out of bounds of the array.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IndexValidation {
+    /// Fail if any index is negative or out of bounds.
+    #[default]
+    Validate,
+    /// Take the first value for negative indices and the last value for those past the end.
+    Clamp,
+}
+
+/// Options for [take_with_options].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TakeOptions {
+    /// Take a null for each null or out of bounds index instead of failing, like Arrow's `take`
+    /// does for null indices. The indices may then be nullable, and the result is always nullable.
+    pub null_on_oob: bool,
+    /// How to treat out of bounds indices, unless they're nulled out by `null_on_oob`.
+    pub index_validation: IndexValidation,
+}
 
 pub trait TakeFn<Array> {
     /// Create a new array by taking the values from the `array` at the
     /// given `indices`.
     ///
-    /// # Panics
-    ///
-    /// Using `indices` that are invalid for the given `array` will cause a panic.
+    /// [take] calls this when it has not validated the indices, which may be out of bounds.
+    /// Implementations must then fail or panic rather than read out of bounds.
     fn take(&self, array: &Array, indices: &ArrayData) -> VortexResult<ArrayData>;
 
     /// Create a new array by taking the values from the `array` at the
     /// given `indices`.
     ///
+    /// [take] calls this once it has validated or clamped the indices, as does [take_unchecked]
+    /// for indices the caller vouches for, if [has_take_unchecked][TakeFn::has_take_unchecked]
+    /// says the encoding implements it.
+    ///
     /// # Safety
     ///
     /// This take variant will not perform bounds checking on indices, so it is the caller's
@@ -24,6 +61,26 @@ pub trait TakeFn<Array> {
     unsafe fn take_unchecked(&self, array: &Array, indices: &ArrayData) -> VortexResult<ArrayData> {
         self.take(array, indices)
     }
+
+    /// Whether [take_unchecked][TakeFn::take_unchecked] skips the bounds checks of
+    /// [take][TakeFn::take] rather than falling back to it.
+    fn has_take_unchecked(&self) -> bool {
+        false
+    }
+
+    /// Create a new array by taking the values from the `array` at the given `indices`, as
+    /// configured by `options`.
+    ///
+    /// Returns `None` if the encoding doesn't implement the options, in which case
+    /// [take_with_options] falls back to a plain [take][TakeFn::take].
+    fn take_with_options(
+        &self,
+        _array: &Array,
+        _indices: &ArrayData,
+        _options: TakeOptions,
+    ) -> VortexResult<Option<ArrayData>> {
+        Ok(None)
+    }
 }
 
 impl<E: Encoding> TakeFn<ArrayData> for E
@@ -40,33 +97,101 @@ where
             .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
         TakeFn::take(encoding, array_ref, indices)
     }
+
+    unsafe fn take_unchecked(
+        &self,
+        array: &ArrayData,
+        indices: &ArrayData,
+    ) -> VortexResult<ArrayData> {
+        let array_ref = <&E::Array>::try_from(array)?;
+        let encoding = array
+            .encoding()
+            .as_any()
+            .downcast_ref::<E>()
+            .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
+        unsafe { TakeFn::take_unchecked(encoding, array_ref, indices) }
+    }
+
+    fn has_take_unchecked(&self) -> bool {
+        TakeFn::<E::Array>::has_take_unchecked(self)
+    }
+
+    fn take_with_options(
+        &self,
+        array: &ArrayData,
+        indices: &ArrayData,
+        options: TakeOptions,
+    ) -> VortexResult<Option<ArrayData>> {
+        let array_ref = <&E::Array>::try_from(array)?;
+        let encoding = array
+            .encoding()
+            .as_any()
+            .downcast_ref::<E>()
+            .ok_or_else(|| vortex_err!("Mismatched encoding"))?;
+        TakeFn::take_with_options(encoding, array_ref, indices, options)
+    }
 }
 
+/// Take the values of an array at the given indices, failing if any of them is out of bounds.
+///
+/// Use [take_with_options] to clamp the indices instead.
 pub fn take(
     array: impl AsRef<ArrayData>,
     indices: impl AsRef<ArrayData>,
 ) -> VortexResult<ArrayData> {
-    // TODO(ngates): if indices are sorted and unique (strict-sorted), then we should delegate to
-    //  the filter function since they're typically optimised for this case.
-    // TODO(ngates): if indices min is quite high, we could slice self and offset the indices
-    //  such that canonicalize does less work.
+    take_validated(array.as_ref(), indices.as_ref(), IndexValidation::Validate)
+}
 
-    let array = array.as_ref();
-    let indices = indices.as_ref();
+/// Take the values of an array at the given indices without checking that they're in bounds,
+/// which is only checked in debug builds.
+///
+/// # Safety
+///
+/// The indices must all be in bounds of the array, since encodings with a
+/// [take_unchecked][TakeFn::take_unchecked] kernel read the values at them without bounds checks.
+pub unsafe fn take_unchecked(
+    array: impl AsRef<ArrayData>,
+    indices: impl AsRef<ArrayData>,
+) -> VortexResult<ArrayData> {
+    let (array, indices) = (array.as_ref(), indices.as_ref());
+    check_indices_dtype(indices)?;
+    debug_assert!(
+        first_out_of_bounds(&indices.clone().into_primitive()?, array.len()).is_none(),
+        "Take indices are out of bounds for array of length {}",
+        array.len()
+    );
+    take_checked(array, indices, true)
+}
+
+fn take_validated(
+    array: &ArrayData,
+    indices: &ArrayData,
+    validation: IndexValidation,
+) -> VortexResult<ArrayData> {
+    check_indices_dtype(indices)?;
+    let (indices, checked_indices) = check_indices(indices, array.len(), validation)?;
+    take_checked(array, &indices, checked_indices)
+}
 
+fn check_indices_dtype(indices: &ArrayData) -> VortexResult<()> {
     if !indices.dtype().is_int() || indices.dtype().is_nullable() {
         vortex_bail!(
             "Take indices must be a non-nullable integer type, got {}",
             indices.dtype()
         );
     }
+    Ok(())
+}
 
-    // If the indices are all within bounds, we can skip bounds checking.
-    let checked_indices = indices
-        .statistics()
-        .get_as::<usize>(Stat::Max)
-        .is_some_and(|max| max < array.len());
-
+fn take_checked(
+    array: &ArrayData,
+    indices: &ArrayData,
+    checked_indices: bool,
+) -> VortexResult<ArrayData> {
+    // TODO(ngates): if indices are sorted and unique (strict-sorted), then we should delegate to
+    //  the filter function since they're typically optimised for this case.
+    // TODO(ngates): if indices min is quite high, we could slice self and offset the indices
+    //  such that canonicalize does less work.
     let taken = take_impl(array, indices, checked_indices)?;
 
     debug_assert_eq!(
@@ -82,20 +207,90 @@ pub fn take(
         array.encoding().id()
     );
 
+    derive_take_stats(array, indices, &taken);
+
     Ok(taken)
 }
 
+/// Apply the validation policy to indices into an array of length `len`, returning the indices to
+/// take along with whether they're known to be in bounds.
+fn check_indices(
+    indices: &ArrayData,
+    len: usize,
+    validation: IndexValidation,
+) -> VortexResult<(ArrayData, bool)> {
+    match validation {
+        // Cached min and max statistics, e.g. of constant indices or of indices read with their
+        // statistics, save canonicalizing and scanning the indices.
+        _ if in_bounds_by_stats(indices, len) => Ok((indices.clone(), true)),
+        IndexValidation::Validate => {
+            if let Some(idx) = first_out_of_bounds(&indices.clone().into_primitive()?, len) {
+                vortex_bail!("Take index {idx} is out of bounds for array of length {len}");
+            }
+            Ok((indices.clone(), true))
+        }
+        IndexValidation::Clamp => Ok((
+            clamp_indices(&indices.clone().into_primitive()?, len)?.into_array(),
+            true,
+        )),
+    }
+}
+
+/// Whether the cached min and max statistics of `indices` show them to be in bounds of length
+/// `len`, without computing statistics that aren't cached.
+fn in_bounds_by_stats(indices: &ArrayData, len: usize) -> bool {
+    if indices.is_empty() {
+        return true;
+    }
+    // Converting to u64 fails for negative values.
+    let as_u64 = |stat: Stat| {
+        indices
+            .statistics()
+            .get(stat)
+            .and_then(|s| s.as_primitive_opt()?.as_::<u64>().ok().flatten())
+    };
+    let min_in_bounds = indices.dtype().is_unsigned_int() || as_u64(Stat::Min).is_some();
+    min_in_bounds && as_u64(Stat::Max).is_some_and(|max| max < len as u64)
+}
+
+/// The first index that's negative or not below `len`.
+fn first_out_of_bounds(indices: &PrimitiveArray, len: usize) -> Option<String> {
+    match_each_integer_ptype!(indices.ptype(), |$I| {
+        indices
+            .maybe_null_slice::<$I>()
+            .iter()
+            .find(|idx| idx.to_usize().map_or(true, |idx| idx >= len))
+            .map(|idx| idx.to_string())
+    })
+}
+
+/// Replace negative indices by zero and those not below `len` by `len - 1`, as `u64`s.
+fn clamp_indices(indices: &PrimitiveArray, len: usize) -> VortexResult<PrimitiveArray> {
+    if len == 0 && !indices.is_empty() {
+        vortex_bail!("Cannot clamp take indices into an empty array");
+    }
+    let last = len.saturating_sub(1) as u64;
+
+    let clamped = match_each_integer_ptype!(indices.ptype(), |$I| {
+        indices
+            .maybe_null_slice::<$I>()
+            .iter()
+            .map(|idx| idx.to_u64().unwrap_or(0).min(last))
+            .collect::<Vec<_>>()
+    });
+    Ok(PrimitiveArray::from(clamped))
+}
+
 fn take_impl(
     array: &ArrayData,
     indices: &ArrayData,
     checked_indices: bool,
 ) -> VortexResult<ArrayData> {
     // If TakeFn defined for the encoding, delegate to TakeFn.
-    // If we know from stats that indices are all valid, we can avoid all bounds checks.
+    // If the indices have been checked to be in bounds, we can avoid all bounds checks.
     if let Some(take_fn) = array.encoding().take_fn() {
-        let result = if checked_indices {
-            // SAFETY: indices are all inbounds per stats.
-            // TODO(aduffy): this means stats must be trusted, can still trigger UB if stats are bad.
+        let result = if checked_indices && take_fn.has_take_unchecked() {
+            // SAFETY: indices were validated or clamped by check_indices.
             unsafe { take_fn.take_unchecked(array, indices) }
         } else {
             take_fn.take(array, indices)
@@ -119,10 +314,287 @@ fn take_impl(
         .take_fn()
         .ok_or_else(|| vortex_err!(NotImplemented: "take", canonical.encoding().id()))?;
 
-    if checked_indices {
-        // SAFETY: indices are known to be in-bound from stats
+    if checked_indices && canonical_take_fn.has_take_unchecked() {
+        // SAFETY: indices were validated or clamped by check_indices.
         unsafe { canonical_take_fn.take_unchecked(&canonical, indices) }
     } else {
         canonical_take_fn.take(&canonical, indices)
     }
 }
+
+/// Take the values of an array at the given indices, as configured by `options`.
+///
+/// With the default options this is the same as [take]. The index validation policy only applies
+/// without `null_on_oob`, which handles out of bounds indices on its own.
+pub fn take_with_options(
+    array: impl AsRef<ArrayData>,
+    indices: impl AsRef<ArrayData>,
+    options: TakeOptions,
+) -> VortexResult<ArrayData> {
+    let array = array.as_ref();
+    let indices = indices.as_ref();
+
+    if !options.null_on_oob {
+        return take_validated(array, indices, options.index_validation);
+    }
+
+    if !indices.dtype().is_int() {
+        vortex_bail!(
+            "Take indices must be an integer type, got {}",
+            indices.dtype()
+        );
+    }
+
+    let dtype = array.dtype().as_nullable();
+    if array.is_empty() {
+        // Every index is out of bounds.
+        return Ok(ConstantArray::new(Scalar::null(dtype), indices.len()).into_array());
+    }
+
+    let taken = take_with_options_impl(array, indices, options)?;
+
+    debug_assert_eq!(
+        taken.len(),
+        indices.len(),
+        "Take length mismatch {}",
+        array.encoding().id()
+    );
+    if taken.dtype() != &dtype {
+        vortex_bail!(
+            "TakeFn {} returned dtype {}, expected {}",
+            array.encoding().id(),
+            taken.dtype(),
+            dtype
+        );
+    }
+
+    Ok(taken)
+}
+
+fn take_with_options_impl(
+    array: &ArrayData,
+    indices: &ArrayData,
+    options: TakeOptions,
+) -> VortexResult<ArrayData> {
+    if let Some(take_fn) = array.encoding().take_fn() {
+        if let Some(taken) = take_fn.take_with_options(array, indices, options)? {
+            return Ok(taken);
+        }
+    }
+
+    // Otherwise, take in bounds indices and null out the others afterwards.
+    log::debug!(
+        "TakeFn::take_with_options not implemented for {}",
+        array.encoding().id()
+    );
+    let (indices, validity) = null_on_oob_indices(indices, array.len())?;
+    with_nulls(take(array, indices)?, validity)
+}
+
+/// Replace each null or out of bounds index into an array of length `len` by zero, returning the
+/// replaced indices as `u64`s along with the validity of the values taken at them.
+pub(crate) fn null_on_oob_indices(
+    indices: &ArrayData,
+    len: usize,
+) -> VortexResult<(PrimitiveArray, Validity)> {
+    let indices = indices.clone().into_primitive()?;
+    let nulls = indices.logical_validity().to_null_buffer()?;
+    let mut in_bounds = BooleanBufferBuilder::new(indices.len());
+
+    let replaced = match_each_integer_ptype!(indices.ptype(), |$I| {
+        indices
+            .maybe_null_slice::<$I>()
+            .iter()
+            .enumerate()
+            .map(|(i, idx)| {
+                let idx = idx.to_usize().filter(|&idx| {
+                    idx < len && nulls.as_ref().map_or(true, |nulls| nulls.is_valid(i))
+                });
+                in_bounds.append(idx.is_some());
+                idx.unwrap_or(0) as u64
+            })
+            .collect::<Vec<_>>()
+    });
+
+    Ok((
+        PrimitiveArray::from(replaced),
+        Validity::from(in_bounds.finish()),
+    ))
+}
+
+/// Make an array nullable, and null wherever `validity` is null as well as where it already was.
+pub(crate) fn with_nulls(array: ArrayData, validity: Validity) -> VortexResult<ArrayData> {
+    let validity_of = |v: Validity| v.into_nullable().and(validity.clone());
+
+    Ok(match array.into_canonical()? {
+        Canonical::Null(a) => a.into_array(),
+        Canonical::Bool(a) => {
+            BoolArray::try_new(a.boolean_buffer(), validity_of(a.validity())?)?.into_array()
+        }
+        Canonical::Primitive(a) => {
+            PrimitiveArray::new(a.buffer().clone(), a.ptype(), validity_of(a.validity())?)
+                .into_array()
+        }
+        Canonical::Decimal(a) => DecimalArray::try_new(
+            a.buffer().clone(),
+            a.decimal_dtype(),
+            validity_of(a.validity())?,
+        )?
+        .into_array(),
+        Canonical::Struct(a) => StructArray::try_new(
+            a.names().clone(),
+            a.children().collect(),
+            a.len(),
+            validity_of(a.validity())?,
+        )?
+        .into_array(),
+        Canonical::List(a) => {
+            ListArray::try_new(a.elements(), a.offsets(), validity_of(a.validity())?)?.into_array()
+        }
+        Canonical::FixedSizeList(a) => FixedSizeListArray::try_with_len(
+            a.elements(),
+            a.list_size(),
+            a.len(),
+            validity_of(a.validity())?,
+        )?
+        .into_array(),
+        Canonical::VarBinView(a) => VarBinViewArray::try_new(
+            a.views(),
+            a.buffers().collect(),
+            a.dtype().as_nullable(),
+            validity_of(a.validity())?,
+        )?
+        .into_array(),
+        Canonical::Extension(a) => ExtensionArray::new(
+            Arc::new(a.ext_dtype().with_nullability(Nullability::Nullable)),
+            with_nulls(a.storage(), validity)?,
+        )
+        .into_array(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ConstantArray, PrimitiveArray, StructArray, VarBinArray, VarBinViewArray};
+    use crate::compute::{
+        scalar_at, take, take_unchecked, take_with_options, IndexValidation, TakeOptions,
+    };
+    use crate::stats::{ArrayStatistics, Stat};
+    use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
+
+    const NULL_ON_OOB: TakeOptions = TakeOptions {
+        null_on_oob: true,
+        index_validation: IndexValidation::Validate,
+    };
+
+    #[test]
+    fn take_validate() {
+        let array = PrimitiveArray::from(vec![10i32, 20, 30]);
+
+        let err = take(&array, PrimitiveArray::from(vec![0i64, 3])).unwrap_err();
+        assert!(err.to_string().contains("Take index 3"), "{err}");
+        let err = take(&array, PrimitiveArray::from(vec![-1i64])).unwrap_err();
+        assert!(err.to_string().contains("Take index -1"), "{err}");
+
+        let taken = take(&array, PrimitiveArray::from(vec![2u8, 0]))
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(taken.maybe_null_slice::<i32>(), &[30, 10]);
+    }
+
+    #[test]
+    fn take_validate_constant_indices() {
+        let array = PrimitiveArray::from(vec![10i32, 20, 30]);
+
+        let taken = take(&array, ConstantArray::new(2u32, 2))
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(taken.maybe_null_slice::<i32>(), &[30, 30]);
+        let err = take(&array, ConstantArray::new(3u32, 2)).unwrap_err();
+        assert!(err.to_string().contains("Take index 3"), "{err}");
+    }
+
+    #[test]
+    fn unchecked_take_kernels() {
+        let has_unchecked = |array: ArrayData| {
+            array
+                .encoding()
+                .take_fn()
+                .is_some_and(|take_fn| take_fn.has_take_unchecked())
+        };
+        assert!(has_unchecked(PrimitiveArray::from(vec![1u8]).into_array()));
+        assert!(!has_unchecked(VarBinArray::from(vec!["a"]).into_array()));
+
+        let array = PrimitiveArray::from(vec![10i32, 20, 30]);
+        // SAFETY: the indices are in bounds.
+        let taken = unsafe { take_unchecked(&array, PrimitiveArray::from(vec![2u8, 0])) }
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(taken.maybe_null_slice::<i32>(), &[30, 10]);
+    }
+
+    #[test]
+    fn take_validate_without_computing_stats() {
+        let array = PrimitiveArray::from(vec![10i32, 20, 30]);
+        let indices = PrimitiveArray::from(vec![2u8, 0]).into_array();
+        take(&array, &indices).unwrap();
+        assert!(indices.statistics().get(Stat::Max).is_none());
+    }
+
+    #[test]
+    fn take_clamp() {
+        let array = PrimitiveArray::from(vec![10i32, 20, 30]);
+        let clamp = TakeOptions {
+            index_validation: IndexValidation::Clamp,
+            ..Default::default()
+        };
+
+        let taken = take_with_options(&array, PrimitiveArray::from(vec![-5i64, 1, 7]), clamp)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(taken.maybe_null_slice::<i32>(), &[10, 20, 30]);
+
+        let empty = PrimitiveArray::from(Vec::<i32>::new());
+        assert!(take_with_options(&empty, PrimitiveArray::from(vec![0u32]), clamp).is_err());
+    }
+
+    #[test]
+    fn take_null_on_oob_struct() {
+        let names = VarBinViewArray::from_iter_str(["a", "b"]).into_array();
+        let array = StructArray::from_fields(&[("names", names)]).unwrap();
+        let indices = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(2), Some(-1)]);
+
+        let taken = take_with_options(&array, &indices, NULL_ON_OOB).unwrap();
+        assert_eq!(taken.dtype(), &array.dtype().as_nullable());
+        assert_eq!(
+            scalar_at(&taken, 0)
+                .unwrap()
+                .as_struct()
+                .field("names")
+                .unwrap(),
+            Scalar::from("b")
+        );
+        for i in 1..4 {
+            assert!(scalar_at(&taken, i).unwrap().is_null());
+        }
+    }
+
+    #[test]
+    fn take_null_on_oob_empty() {
+        let array = PrimitiveArray::from(Vec::<u8>::new());
+        let taken =
+            take_with_options(&array, PrimitiveArray::from(vec![0u32, 3]), NULL_ON_OOB).unwrap();
+        assert_eq!(
+            taken.dtype(),
+            &DType::Primitive(PType::U8, Nullability::Nullable)
+        );
+        assert!(scalar_at(&taken, 1).unwrap().is_null());
+    }
+}
@@ -1,17 +1,104 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::sync::{LazyLock, RwLock};
+
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+use itertools::Itertools;
+use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexError, VortexResult};
+use vortex_flatbuffers::{FlatBufferRoot, WriteFlatBuffer};
+
 use crate::aliases::hash_map::HashMap;
 use crate::array::{
-    BoolEncoding, ChunkedEncoding, ConstantEncoding, ExtensionEncoding, ListEncoding, NullEncoding,
-    PrimitiveEncoding, SparseEncoding, StructEncoding, VarBinEncoding, VarBinViewEncoding,
+    BoolEncoding, ChunkedEncoding, ConstantEncoding, DecimalEncoding, ExtensionEncoding,
+    FixedSizeListEncoding, ListEncoding, NullEncoding, PrimitiveEncoding, SlicedEncoding,
+    SparseEncoding, StructEncoding, VarBinEncoding, VarBinViewEncoding,
 };
 use crate::encoding::EncodingRef;
+use crate::{flatbuffers as fb, ArrayData, MetadataFormat};
+
+/// Encodings from other crates, which every [`Context::default`] includes.
+static REGISTERED_ENCODINGS: LazyLock<RwLock<HashMap<u16, EncodingRef>>> =
+    LazyLock::new(Default::default);
+
+/// Register an encoding, e.g. a private one, so that readers with a default [`Context`] can read
+/// arrays of it.
+///
+/// Contexts created before the encoding is registered don't include it, so it should be
+/// registered at startup.
+///
+/// Registering the same encoding again does nothing, but registering an encoding with the code of
+/// another one fails.
+pub fn register_encoding(encoding: EncodingRef) -> VortexResult<()> {
+    let code = encoding.id().code();
+    let mut registered = REGISTERED_ENCODINGS
+        .write()
+        .unwrap_or_else(|poison| vortex_panic!("Failed to lock registered encodings: {poison}"));
+    if let Some(existing) = BUILTIN_ENCODINGS
+        .iter()
+        .copied()
+        .find(|e| e.id().code() == code)
+        .or_else(|| registered.get(&code).copied())
+    {
+        if existing.id().as_ref() == encoding.id().as_ref() {
+            return Ok(());
+        }
+        vortex_bail!(
+            "Cannot register encoding {}, its code is taken by {}",
+            encoding.id(),
+            existing.id()
+        );
+    }
+    registered.insert(code, encoding);
+    Ok(())
+}
+
+/// The encodings registered with [`register_encoding`].
+pub fn registered_encodings() -> Vec<EncodingRef> {
+    REGISTERED_ENCODINGS
+        .read()
+        .unwrap_or_else(|poison| vortex_panic!("Failed to lock registered encodings: {poison}"))
+        .values()
+        .copied()
+        .collect()
+}
+
+/// The canonical and structural encodings, which every context includes.
+const BUILTIN_ENCODINGS: [EncodingRef; 14] = [
+    &NullEncoding,
+    &BoolEncoding,
+    &PrimitiveEncoding,
+    &DecimalEncoding,
+    &StructEncoding,
+    &ListEncoding,
+    &FixedSizeListEncoding,
+    &VarBinEncoding,
+    &VarBinViewEncoding,
+    &ExtensionEncoding,
+    &SparseEncoding,
+    &ConstantEncoding,
+    &ChunkedEncoding,
+    &SlicedEncoding,
+];
 
 /// A mapping between an encoding's ID to an [`EncodingRef`], used to have a shared view of all available encoding schemes.
 #[derive(Debug, Clone)]
 pub struct Context {
     encodings: HashMap<u16, EncodingRef>,
+    metadata_format: MetadataFormat,
 }
 
 impl Context {
+    /// Read array metadata in the given format, e.g. [`MetadataFormat::Flexbuffers`] for files
+    /// written before the metadata of the in-tree encodings had fixed layouts.
+    pub fn with_metadata_format(mut self, metadata_format: MetadataFormat) -> Self {
+        self.metadata_format = metadata_format;
+        self
+    }
+
+    pub fn metadata_format(&self) -> MetadataFormat {
+        self.metadata_format
+    }
+
     pub fn with_encoding(mut self, encoding: EncodingRef) -> Self {
         self.encodings.insert(encoding.id().code(), encoding);
         self
@@ -30,27 +117,274 @@ impl Context {
     pub fn lookup_encoding(&self, encoding_code: u16) -> Option<EncodingRef> {
         self.encodings.get(&encoding_code).cloned()
     }
+
+    /// Check that the context can decode arrays of every encoding of the set, failing with the
+    /// names of those it lacks, or whose codes belong to other encodings in this context.
+    pub fn check_encodings(&self, encodings: &EncodingSet) -> VortexResult<()> {
+        let missing = self.missing_encodings(encodings)?;
+        if !missing.is_empty() {
+            vortex_bail!(
+                "Missing encodings {} of arrays written by Vortex {}",
+                missing.join(", "),
+                encodings.version()
+            );
+        }
+        Ok(())
+    }
+
+    /// The encodings of the set that this context lacks, named as `name(code)`.
+    ///
+    /// Unlike a missing encoding, which only fails to decode the arrays of it, a code that belongs
+    /// to another encoding in this context would decode them wrongly, so it fails here.
+    pub fn missing_encodings(&self, encodings: &EncodingSet) -> VortexResult<Vec<String>> {
+        let mut missing = Vec::new();
+        for (code, name) in encodings.encodings() {
+            match self.lookup_encoding(code) {
+                None => missing.push(format!("{name}({code:#04x})")),
+                Some(encoding) if encoding.id().as_ref() != name => {
+                    vortex_bail!(
+                        "Encoding code {code:#04x} is {name} in arrays written by Vortex {}, but {} in this context",
+                        encodings.version(),
+                        encoding.id()
+                    )
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(missing)
+    }
 }
 
 impl Default for Context {
     fn default() -> Self {
         Self {
-            encodings: [
-                &NullEncoding as EncodingRef,
-                &BoolEncoding,
-                &PrimitiveEncoding,
-                &StructEncoding,
-                &ListEncoding,
-                &VarBinEncoding,
-                &VarBinViewEncoding,
-                &ExtensionEncoding,
-                &SparseEncoding,
-                &ConstantEncoding,
-                &ChunkedEncoding,
-            ]
-            .into_iter()
-            .map(|e| (e.id().code(), e))
-            .collect(),
+            encodings: BUILTIN_ENCODINGS
+                .into_iter()
+                .chain(registered_encodings())
+                .map(|e| (e.id().code(), e))
+                .collect(),
+            metadata_format: MetadataFormat::default(),
         }
     }
 }
+
+/// The version of Vortex recorded in the [`EncodingSet`]s it writes.
+pub const VORTEX_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The encodings of some arrays, by code and name, and the version of Vortex that wrote them.
+///
+/// Writers serialize the set alongside the arrays, so that readers can [check] up front that their
+/// [`Context`] knows every encoding, and report the missing ones by name rather than failing on an
+/// unknown code partway through decoding.
+///
+/// [check]: Context::check_encodings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodingSet {
+    version: String,
+    encodings: BTreeMap<u16, String>,
+}
+
+impl Default for EncodingSet {
+    fn default() -> Self {
+        Self {
+            version: VORTEX_VERSION.to_string(),
+            encodings: BTreeMap::new(),
+        }
+    }
+}
+
+impl Display for EncodingSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}] (Vortex {})",
+            self.encodings
+                .iter()
+                .format_with(", ", |(code, name), f| f(&format_args!(
+                    "{name}({code:#04x})"
+                ))),
+            self.version
+        )
+    }
+}
+
+impl EncodingSet {
+    /// The version of Vortex that wrote the arrays.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// The code and name of each encoding, in order of code.
+    pub fn encodings(&self) -> impl Iterator<Item = (u16, &str)> + '_ {
+        self.encodings
+            .iter()
+            .map(|(code, name)| (*code, name.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.encodings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.encodings.is_empty()
+    }
+
+    pub fn contains(&self, code: u16) -> bool {
+        self.encodings.contains_key(&code)
+    }
+
+    pub fn insert(&mut self, encoding: EncodingRef) {
+        self.encodings
+            .insert(encoding.id().code(), encoding.id().as_ref().to_string());
+    }
+
+    /// Add the encodings of the array and of all of its descendants.
+    pub fn insert_array(&mut self, array: &ArrayData) {
+        for child in array.depth_first_traversal() {
+            self.insert(child.encoding());
+        }
+    }
+
+    /// Add the encodings of the other set.
+    pub fn extend(&mut self, other: &Self) {
+        self.encodings.extend(
+            other
+                .encodings
+                .iter()
+                .map(|(code, name)| (*code, name.clone())),
+        );
+    }
+
+    /// The encodings of this set which aren't in the other one, written by this set's version.
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            version: self.version.clone(),
+            encodings: self
+                .encodings
+                .iter()
+                .filter(|(code, _)| !other.contains(**code))
+                .map(|(code, name)| (*code, name.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl From<&Context> for EncodingSet {
+    fn from(ctx: &Context) -> Self {
+        let mut encodings = Self::default();
+        for encoding in ctx.encodings() {
+            encodings.insert(encoding);
+        }
+        encodings
+    }
+}
+
+impl FlatBufferRoot for EncodingSet {}
+
+impl WriteFlatBuffer for EncodingSet {
+    type Target<'a> = fb::EncodingSet<'a>;
+
+    fn write_flatbuffer<'fb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fb>,
+    ) -> WIPOffset<Self::Target<'fb>> {
+        let encodings = self
+            .encodings
+            .iter()
+            .map(|(code, name)| {
+                let name = fbb.create_string(name);
+                fb::EncodingSpec::create(
+                    fbb,
+                    &fb::EncodingSpecArgs {
+                        code: *code,
+                        name: Some(name),
+                    },
+                )
+            })
+            .collect_vec();
+        let encodings = fbb.create_vector(&encodings);
+        let version = fbb.create_string(&self.version);
+        fb::EncodingSet::create(
+            fbb,
+            &fb::EncodingSetArgs {
+                version: Some(version),
+                encodings: Some(encodings),
+            },
+        )
+    }
+}
+
+impl TryFrom<fb::EncodingSet<'_>> for EncodingSet {
+    type Error = VortexError;
+
+    fn try_from(fb: fb::EncodingSet<'_>) -> VortexResult<Self> {
+        let encodings = fb
+            .encodings()
+            .unwrap_or_default()
+            .iter()
+            .map(|encoding| {
+                encoding
+                    .name()
+                    .map(|name| (encoding.code(), name.to_string()))
+                    .ok_or_else(|| vortex_err!("Encoding {:#04x} has no name", encoding.code()))
+            })
+            .collect::<VortexResult<_>>()?;
+        Ok(Self {
+            version: fb.version().unwrap_or("unknown").to_string(),
+            encodings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use flatbuffers::root;
+    use vortex_flatbuffers::WriteFlatBufferExt;
+
+    use crate::array::{BoolEncoding, PrimitiveArray, PrimitiveEncoding};
+    use crate::encoding::opaque::OpaqueEncoding;
+    use crate::encoding::Encoding;
+    use crate::{
+        flatbuffers as fb, register_encoding, registered_encodings, Context, EncodingSet,
+        IntoArrayData,
+    };
+
+    #[test]
+    fn register_builtin_encoding() {
+        register_encoding(&BoolEncoding).unwrap();
+        let encoding = Context::default()
+            .lookup_encoding(BoolEncoding::ID.code())
+            .unwrap();
+        assert_eq!(encoding.id(), BoolEncoding::ID);
+        // Built-in encodings are always available, so registering them again is a no-op.
+        assert!(!registered_encodings()
+            .iter()
+            .any(|e| e.id().code() == BoolEncoding::ID.code()));
+    }
+
+    #[test]
+    fn check_encodings() {
+        let ctx = Context::default();
+        let mut encodings = EncodingSet::from(&ctx);
+        ctx.check_encodings(&encodings).unwrap();
+
+        encodings.insert(&OpaqueEncoding(0xfff0));
+        assert_eq!(
+            ctx.missing_encodings(&encodings).unwrap(),
+            ["vortex.opaque(0xfff0)"]
+        );
+        let err = ctx.check_encodings(&encodings).unwrap_err().to_string();
+        assert!(err.contains("vortex.opaque(0xfff0)"), "{err}");
+    }
+
+    #[test]
+    fn encoding_set_round_trip() {
+        let mut encodings = EncodingSet::default();
+        encodings.insert_array(&PrimitiveArray::from(vec![1i32, 2, 3]).into_array());
+        assert!(encodings.contains(PrimitiveEncoding::ID.code()));
+
+        let bytes = encodings.write_flatbuffer_bytes();
+        let read = EncodingSet::try_from(root::<fb::EncodingSet>(&bytes).unwrap()).unwrap();
+        assert_eq!(read, encodings);
+    }
+}
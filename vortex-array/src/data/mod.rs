@@ -5,15 +5,15 @@ use std::sync::{Arc, RwLock};
 
 use itertools::Itertools;
 use owned::OwnedArrayData;
-use viewed::ViewedArrayData;
+use viewed::{unresolved_children, ViewedArrayData};
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
-use vortex_error::{vortex_err, VortexExpect, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::array::{
-    BoolEncoding, ExtensionEncoding, NullEncoding, PrimitiveEncoding, StructEncoding,
-    VarBinEncoding, VarBinViewEncoding,
+    BoolEncoding, DecimalEncoding, ExtensionEncoding, FixedSizeListEncoding, NullEncoding,
+    PrimitiveEncoding, StructEncoding, VarBinEncoding, VarBinViewEncoding,
 };
 use crate::compute::scalar_at;
 use crate::encoding::{EncodingId, EncodingRef, EncodingVTable};
@@ -27,8 +27,11 @@ use crate::{
 };
 
 mod owned;
+mod validate;
 mod viewed;
 
+pub use validate::*;
+
 /// A central type for all Vortex arrays, which are known length sequences of typed and possibly compressed data.
 ///
 /// This is the main entrypoint for working with in-memory Vortex data, and dispatches work over the underlying encoding or memory representations.
@@ -65,7 +68,41 @@ impl ArrayData {
         children: Arc<[ArrayData]>,
         statistics: StatsSet,
     ) -> VortexResult<Self> {
-        Self::try_new(InnerArrayData::Owned(OwnedArrayData {
+        Self::try_new(Self::new_unchecked(
+            encoding, dtype, len, metadata, buffer, children, statistics,
+        ))
+    }
+
+    /// Create an owned array, validating the whole array tree to the given level.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new_checked(
+        encoding: EncodingRef,
+        dtype: DType,
+        len: usize,
+        metadata: Arc<dyn ArrayMetadata>,
+        buffer: Option<Buffer>,
+        children: Arc<[ArrayData]>,
+        statistics: StatsSet,
+        validation: ValidationLevel,
+    ) -> VortexResult<Self> {
+        let array =
+            Self::try_new_owned(encoding, dtype, len, metadata, buffer, children, statistics)?;
+        array.validate(validation)?;
+        Ok(array)
+    }
+
+    /// Create an owned array without any validation, for arrays assembled by code that already
+    /// guarantees their children and buffers match the encoding.
+    pub fn new_unchecked(
+        encoding: EncodingRef,
+        dtype: DType,
+        len: usize,
+        metadata: Arc<dyn ArrayMetadata>,
+        buffer: Option<Buffer>,
+        children: Arc<[ArrayData]>,
+        statistics: StatsSet,
+    ) -> Self {
+        ArrayData(InnerArrayData::Owned(OwnedArrayData {
             encoding,
             dtype,
             len,
@@ -78,6 +115,10 @@ impl ArrayData {
         }))
     }
 
+    /// Create a zero-copy view over a flatbuffer-encoded array, validated to the given level.
+    ///
+    /// Arrays read from trusted sources can use [`ValidationLevel::Metadata`], which only parses
+    /// the metadata of the root array and leaves its children to be resolved on access.
     pub fn try_new_viewed<F>(
         ctx: Arc<Context>,
         dtype: DType,
@@ -85,6 +126,7 @@ impl ArrayData {
         flatbuffer: Buffer,
         flatbuffer_init: F,
         buffers: Vec<Buffer>,
+        validation: ValidationLevel,
     ) -> VortexResult<Self>
     where
         F: FnOnce(&[u8]) -> VortexResult<crate::flatbuffers::Array>,
@@ -101,8 +143,10 @@ impl ArrayData {
         )?;
 
         // Parse the array metadata
-        let metadata = encoding.load_metadata(array.metadata().map(|v| v.bytes()))?;
+        let metadata =
+            encoding.load_metadata(array.metadata().map(|v| v.bytes()), ctx.metadata_format())?;
 
+        let children = unresolved_children(array.children().map(|c| c.len()).unwrap_or(0));
         let view = ViewedArrayData {
             encoding,
             dtype,
@@ -112,27 +156,30 @@ impl ArrayData {
             flatbuffer_loc,
             buffers: buffers.into(),
             ctx,
+            children,
             #[cfg(feature = "canonical_counter")]
             canonical_counter: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
         };
 
-        Self::try_new(InnerArrayData::Viewed(view))
+        let array = Self::try_new(ArrayData(InnerArrayData::Viewed(view)))?;
+        array.validate(validation)?;
+        Ok(array)
     }
 
     /// Shared constructor that performs common array validation.
-    fn try_new(inner: InnerArrayData) -> VortexResult<Self> {
-        let array = ArrayData(inner);
-
+    fn try_new(array: ArrayData) -> VortexResult<Self> {
         // Sanity check that the encoding implements the correct array trait
         debug_assert!(
             match array.dtype() {
                 DType::Null => array.as_null_array().is_some(),
                 DType::Bool(_) => array.as_bool_array().is_some(),
                 DType::Primitive(..) => array.as_primitive_array().is_some(),
+                // There is no variant trait for decimals
+                DType::Decimal(..) => true,
                 DType::Utf8(_) => array.as_utf8_array().is_some(),
                 DType::Binary(_) => array.as_binary_array().is_some(),
                 DType::Struct(..) => array.as_struct_array().is_some(),
-                DType::List(..) => array.as_list_array().is_some(),
+                DType::List(..) | DType::FixedSizeList(..) => array.as_list_array().is_some(),
                 DType::Extension(..) => array.as_extension_array().is_some(),
             },
             "Encoding {} does not implement the variant trait for {}",
@@ -140,9 +187,6 @@ impl ArrayData {
             array.dtype()
         );
 
-        // TODO(ngates): we should run something like encoding.validate(array) so the encoding
-        //  can check the number of children, number of buffers, and metadata values etc.
-
         Ok(array)
     }
 
@@ -173,7 +217,9 @@ impl ArrayData {
         self.is_encoding(NullEncoding.id())
             || self.is_encoding(BoolEncoding.id())
             || self.is_encoding(PrimitiveEncoding.id())
+            || self.is_encoding(DecimalEncoding.id())
             || self.is_encoding(StructEncoding.id())
+            || self.is_encoding(FixedSizeListEncoding.id())
             || self.is_encoding(VarBinViewEncoding.id())
             || self.is_encoding(ExtensionEncoding.id())
     }
@@ -184,6 +230,7 @@ impl ArrayData {
         self.is_encoding(NullEncoding.id())
             || self.is_encoding(BoolEncoding.id())
             || self.is_encoding(PrimitiveEncoding.id())
+            || self.is_encoding(DecimalEncoding.id())
             || self.is_encoding(VarBinEncoding.id())
             || self.is_encoding(VarBinViewEncoding.id())
     }
@@ -202,6 +249,12 @@ impl ArrayData {
             .then(|| scalar_at(self, 0).vortex_expect("expected a scalar value"))
     }
 
+    /// Return whether the array is known to be all nulls without decoding it, i.e. it has the
+    /// null dtype or is a constant null.
+    pub fn is_constant_null(&self) -> bool {
+        matches!(self.dtype(), DType::Null) || self.as_constant().is_some_and(|s| s.is_null())
+    }
+
     pub fn child<'a>(&'a self, idx: usize, dtype: &'a DType, len: usize) -> VortexResult<Self> {
         match &self.0 {
             InnerArrayData::Owned(d) => d.child(idx, dtype, len).cloned(),
@@ -219,6 +272,50 @@ impl ArrayData {
         }
     }
 
+    /// Rebuild the array with different children, keeping its encoding, metadata, buffer and
+    /// statistics.
+    ///
+    /// Each child must have the same dtype and length as the child it replaces, as the metadata
+    /// of the array may depend on them.
+    pub fn with_children(&self, children: Vec<ArrayData>) -> VortexResult<Self> {
+        let existing = self.children();
+        if existing.len() != children.len() {
+            vortex_bail!(
+                "{} array has {} children, cannot replace them with {}",
+                self.encoding().id(),
+                existing.len(),
+                children.len()
+            );
+        }
+        for (idx, (old, new)) in existing.iter().zip(&children).enumerate() {
+            if old.dtype() != new.dtype() || old.len() != new.len() {
+                vortex_bail!(
+                    "Cannot replace child {} of {} array, a {} of length {}, with a {} of length {}",
+                    idx,
+                    self.encoding().id(),
+                    old.dtype(),
+                    old.len(),
+                    new.dtype(),
+                    new.len()
+                );
+            }
+        }
+
+        let metadata = match &self.0 {
+            InnerArrayData::Owned(d) => d.metadata.clone(),
+            InnerArrayData::Viewed(v) => v.metadata.clone(),
+        };
+        Self::try_new_owned(
+            self.encoding(),
+            self.dtype().clone(),
+            self.len(),
+            metadata,
+            self.buffer().cloned(),
+            children.into(),
+            self.statistics().to_set(),
+        )
+    }
+
     /// Returns a Vec of Arrays with all the array's child arrays.
     pub fn named_children(&self) -> Vec<(String, ArrayData)> {
         let mut collector = NamedChildrenCollector::default();
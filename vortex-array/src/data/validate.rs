@@ -0,0 +1,158 @@
+use vortex_buffer::Buffer;
+use vortex_dtype::PType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use super::{ArrayData, InnerArrayData};
+use crate::array::{BoolEncoding, BoolMetadata, PrimitiveEncoding};
+use crate::encoding::Encoding;
+use crate::visitor::ArrayVisitor;
+use crate::ArrayDType;
+
+/// How thoroughly an [`ArrayData`] is validated when it is constructed.
+///
+/// Each level includes the checks of the levels before it. Arrays read from trusted sources, such
+/// as files written by Vortex, only need their metadata parsed, while tests and debugging tools can
+/// afford to check the whole array tree.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationLevel {
+    /// Only parse the metadata of the array itself.
+    #[default]
+    Metadata,
+    /// Also visit every array in the tree, parsing the metadata of each child and checking that
+    /// each encoding visits as many children as its array holds.
+    Children,
+    /// Also check that every array in the tree only refers to buffers that exist, and that the
+    /// buffers of primitive and boolean arrays hold all of their values.
+    Buffers,
+}
+
+impl ArrayData {
+    /// Validate the array tree to the given level, see [`ValidationLevel`].
+    ///
+    /// Arrays are always validated to [`ValidationLevel::Metadata`] when they're constructed, so
+    /// this only does work for the deeper levels.
+    pub fn validate(&self, level: ValidationLevel) -> VortexResult<()> {
+        if level == ValidationLevel::Metadata {
+            return Ok(());
+        }
+
+        let mut visitor = ValidationVisitor::default();
+        self.encoding().accept(self, &mut visitor)?;
+        if visitor.children.len() != self.nchildren() {
+            vortex_bail!(
+                "{} array has {} children, but its encoding visits {}",
+                self.encoding().id(),
+                self.nchildren(),
+                visitor.children.len()
+            );
+        }
+
+        if level >= ValidationLevel::Buffers {
+            self.validate_buffers()?;
+        }
+
+        for (idx, child) in visitor.children.iter().enumerate() {
+            child
+                .validate(level)
+                .map_err(|e| e.with_context(format!("child {idx} of {}", self.encoding().id())))?;
+        }
+        Ok(())
+    }
+
+    fn validate_buffers(&self) -> VortexResult<()> {
+        if let InnerArrayData::Viewed(v) = &self.0 {
+            v.validate_buffers()?;
+        }
+
+        let required = if self.is_encoding(PrimitiveEncoding::ID) {
+            PType::try_from(self.dtype())?.byte_width() * self.len()
+        } else if self.is_encoding(BoolEncoding::ID) {
+            let bit_offset = self.metadata::<BoolMetadata>()?.first_byte_bit_offset();
+            (bit_offset + self.len()).div_ceil(8)
+        } else {
+            return Ok(());
+        };
+        let available = self.buffer().map_or(0, Buffer::len);
+        if available < required {
+            vortex_bail!(
+                "{} array of {} values needs a buffer of {required} bytes, but has {available}",
+                self.encoding().id(),
+                self.len()
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct ValidationVisitor {
+    children: Vec<ArrayData>,
+}
+
+impl ArrayVisitor for ValidationVisitor {
+    fn visit_child(&mut self, _name: &str, array: &ArrayData) -> VortexResult<()> {
+        self.children.push(array.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_buffer::Buffer;
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use super::ValidationLevel;
+    use crate::array::{PrimitiveArray, PrimitiveEncoding};
+    use crate::stats::StatsSet;
+    use crate::{ArrayData, IntoArrayData, MetadataFormat, MetadataVTable};
+
+    #[test]
+    fn valid_array_passes_every_level() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3)]).into_array();
+        for level in [
+            ValidationLevel::Metadata,
+            ValidationLevel::Children,
+            ValidationLevel::Buffers,
+        ] {
+            array.validate(level).unwrap();
+        }
+    }
+
+    #[test]
+    fn short_buffer_fails_buffer_checks() {
+        let valid = PrimitiveArray::from(vec![1i32, 2]).into_array();
+        let metadata = PrimitiveEncoding
+            .load_metadata(
+                Some(&valid.metadata_bytes().unwrap()),
+                MetadataFormat::FixedLayout,
+            )
+            .unwrap();
+        let dtype = DType::Primitive(PType::I32, Nullability::NonNullable);
+        // Four i32 values need 16 bytes.
+        let buffer = Buffer::from(vec![0u8; 8]);
+
+        let array = ArrayData::new_unchecked(
+            &PrimitiveEncoding,
+            dtype.clone(),
+            4,
+            metadata.clone(),
+            Some(buffer.clone()),
+            [].into(),
+            StatsSet::default(),
+        );
+        array.validate(ValidationLevel::Children).unwrap();
+        assert!(array.validate(ValidationLevel::Buffers).is_err());
+
+        assert!(ArrayData::try_new_checked(
+            &PrimitiveEncoding,
+            dtype,
+            4,
+            metadata,
+            Some(buffer),
+            [].into(),
+            StatsSet::default(),
+            ValidationLevel::Buffers,
+        )
+        .is_err());
+    }
+}
@@ -1,15 +1,16 @@
 use std::fmt::{Debug, Formatter};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use enum_iterator::all;
 use itertools::Itertools;
 use vortex_buffer::Buffer;
 use vortex_dtype::{DType, Nullability, PType};
-use vortex_error::{vortex_err, VortexExpect as _, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexExpect as _, VortexResult};
 use vortex_scalar::{Scalar, ScalarValue};
 
 use crate::encoding::opaque::OpaqueEncoding;
 use crate::encoding::EncodingRef;
+use crate::stats::flatbuffers::STATS_LAYOUT_VERSION;
 use crate::stats::{Stat, Statistics, StatsSet};
 use crate::{flatbuffers as fb, ArrayData, ArrayMetadata, ChildrenCollector, Context};
 
@@ -24,6 +25,9 @@ pub(super) struct ViewedArrayData {
     pub(super) flatbuffer_loc: usize,
     pub(super) buffers: Arc<[Buffer]>,
     pub(super) ctx: Arc<Context>,
+    /// The views of the children, resolved on first access and shared by clones of the view, so
+    /// that walking a deep tree decodes each of its arrays once.
+    pub(super) children: Arc<[OnceLock<ViewedArrayData>]>,
     #[cfg(feature = "canonical_counter")]
     pub(super) canonical_counter: Arc<std::sync::atomic::AtomicUsize>,
 }
@@ -53,6 +57,33 @@ impl ViewedArrayData {
 
     // TODO(ngates): should we separate self and DType lifetimes? Should DType be cloned?
     pub fn child(&self, idx: usize, dtype: &DType, len: usize) -> VortexResult<Self> {
+        let cached = self
+            .children
+            .get(idx)
+            .ok_or_else(|| vortex_err!("ArrayView: array_child({idx}) not found"))?;
+        let child = match cached.get() {
+            Some(child) => child,
+            None => {
+                let child = self.resolve_child(idx, dtype, len)?;
+                // Another thread may have resolved the child in the meantime, in which case both
+                // views are the same.
+                cached.get_or_init(|| child)
+            }
+        };
+
+        if &child.dtype == dtype && child.len == len {
+            Ok(child.clone())
+        } else {
+            Ok(Self {
+                dtype: dtype.clone(),
+                len,
+                ..child.clone()
+            })
+        }
+    }
+
+    /// Parse the flatbuffer and metadata of a child, which is only done on its first access.
+    fn resolve_child(&self, idx: usize, dtype: &DType, len: usize) -> VortexResult<Self> {
         let child = self
             .array_child(idx)
             .ok_or_else(|| vortex_err!("ArrayView: array_child({idx}) not found"))?;
@@ -69,7 +100,10 @@ impl ViewedArrayData {
                 Box::leak(Box::new(OpaqueEncoding(child.encoding())))
             });
 
-        let metadata = encoding.load_metadata(child.metadata().map(|m| m.bytes()))?;
+        let metadata = encoding.load_metadata(
+            child.metadata().map(|m| m.bytes()),
+            self.ctx.metadata_format(),
+        )?;
 
         Ok(Self {
             encoding,
@@ -80,6 +114,7 @@ impl ViewedArrayData {
             flatbuffer_loc,
             buffers: self.buffers.clone(),
             ctx: self.ctx.clone(),
+            children: unresolved_children(child.children().map(|c| c.len()).unwrap_or(0)),
             #[cfg(feature = "canonical_counter")]
             canonical_counter: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
         })
@@ -91,7 +126,7 @@ impl ViewedArrayData {
     }
 
     pub fn nchildren(&self) -> usize {
-        self.flatbuffer().children().map(|c| c.len()).unwrap_or(0)
+        self.children.len()
     }
 
     pub fn children(&self) -> Vec<ArrayData> {
@@ -102,6 +137,30 @@ impl ViewedArrayData {
         collector.children()
     }
 
+    /// Check that the flatbuffer refers to at most one buffer, and only to buffers that exist.
+    pub(super) fn validate_buffers(&self) -> VortexResult<()> {
+        let Some(buffers) = self.flatbuffer().buffers() else {
+            return Ok(());
+        };
+        if buffers.len() > 1 {
+            vortex_bail!(
+                "{} array refers to {} buffers, expected at most one",
+                self.encoding.id(),
+                buffers.len()
+            );
+        }
+        for idx in buffers.iter() {
+            if usize::from(idx) >= self.buffers.len() {
+                vortex_bail!(
+                    "{} array refers to buffer {idx}, but only {} buffers were read",
+                    self.encoding.id(),
+                    self.buffers.len()
+                );
+            }
+        }
+        Ok(())
+    }
+
     pub fn buffer(&self) -> Option<&Buffer> {
         self.flatbuffer()
             .buffers()
@@ -111,6 +170,26 @@ impl ViewedArrayData {
             })
             .map(|idx| &self.buffers[idx])
     }
+
+    /// Look up a statistic without a dedicated field in the flatbuffer statistics.
+    fn extra_stat(&self, stat: Stat) -> Option<Scalar> {
+        let stats = self.flatbuffer().stats()?;
+        if stats.version() > STATS_LAYOUT_VERSION {
+            return None;
+        }
+        stats
+            .extra()?
+            .iter()
+            .find(|extra| extra.stat() == u8::from(stat))?
+            .value()
+            .and_then(|v| ScalarValue::try_from(v).ok())
+            .map(|v| Scalar::new(stat.dtype(&self.dtype), v))
+    }
+}
+
+/// The cache of the views of `nchildren` children, none of which are resolved yet.
+pub(super) fn unresolved_children(nchildren: usize) -> Arc<[OnceLock<ViewedArrayData>]> {
+    (0..nchildren).map(|_| OnceLock::new()).collect()
 }
 
 impl Statistics for ViewedArrayData {
@@ -156,6 +235,13 @@ impl Statistics for ViewedArrayData {
                 .stats()?
                 .uncompressed_size_in_bytes()
                 .map(u64::into),
+            Stat::ApproxDistinct => self
+                .flatbuffer()
+                .stats()?
+                .approx_distinct()
+                .map(|v| Scalar::from(v.bytes())),
+            Stat::HasNaN => self.flatbuffer().stats()?.has_nan().map(bool::into),
+            Stat::Histogram => self.extra_stat(stat),
         }
     }
 
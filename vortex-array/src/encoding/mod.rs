@@ -138,12 +138,12 @@ pub mod ids {
     pub const CONSTANT: u16 = 9;
     pub const CHUNKED: u16 = 10;
     pub const LIST: u16 = 11;
+    pub const SLICED: u16 = 12;
+    pub const FIXED_SIZE_LIST: u16 = 13;
+    pub const DECIMAL: u16 = 14;
 
     // currently unused, saved for future built-ins
-    // e.g., FixedList, Union, Tensor, etc.
-    pub(crate) const RESERVED_12: u16 = 12;
-    pub(crate) const RESERVED_13: u16 = 13;
-    pub(crate) const RESERVED_14: u16 = 14;
+    // e.g., Union, Tensor, etc.
     pub(crate) const RESERVED_15: u16 = 15;
     pub(crate) const RESERVED_16: u16 = 16;
 
@@ -185,9 +185,9 @@ mod tests {
             ids::CONSTANT,
             ids::CHUNKED,
             ids::LIST,
-            ids::RESERVED_12,
-            ids::RESERVED_13,
-            ids::RESERVED_14,
+            ids::SLICED,
+            ids::FIXED_SIZE_LIST,
+            ids::DECIMAL,
             ids::RESERVED_15,
             ids::RESERVED_16,
             ids::ALP,
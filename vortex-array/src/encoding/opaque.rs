@@ -5,6 +5,7 @@ use std::sync::Arc;
 use arrow_array::ArrayRef;
 use vortex_error::{vortex_bail, vortex_panic, VortexResult};
 
+use crate::builders::ArrayBuilder;
 use crate::compute::ComputeVTable;
 use crate::encoding::{EncodingId, EncodingVTable};
 use crate::stats::StatisticsVTable;
@@ -12,7 +13,7 @@ use crate::validity::{LogicalValidity, ValidityVTable};
 use crate::variants::VariantsVTable;
 use crate::visitor::{ArrayVisitor, VisitorVTable};
 use crate::{
-    ArrayData, ArrayMetadata, Canonical, IntoCanonicalVTable, MetadataVTable,
+    ArrayData, ArrayMetadata, Canonical, IntoCanonicalVTable, MetadataFormat, MetadataVTable,
     TrySerializeArrayMetadata,
 };
 
@@ -55,12 +56,27 @@ impl IntoCanonicalVTable for OpaqueEncoding {
             self.0
         )
     }
+
+    fn canonicalize_into(
+        &self,
+        _array: ArrayData,
+        _builder: &mut dyn ArrayBuilder,
+    ) -> VortexResult<()> {
+        vortex_bail!(
+            "OpaqueEncoding: canonicalize_into cannot be called for opaque array ({})",
+            self.0
+        )
+    }
 }
 
 impl ComputeVTable for OpaqueEncoding {}
 
 impl MetadataVTable for OpaqueEncoding {
-    fn load_metadata(&self, _metadata: Option<&[u8]>) -> VortexResult<Arc<dyn ArrayMetadata>> {
+    fn load_metadata(
+        &self,
+        _metadata: Option<&[u8]>,
+        _format: MetadataFormat,
+    ) -> VortexResult<Arc<dyn ArrayMetadata>> {
         Ok(Arc::new(OpaqueMetadata))
     }
 }
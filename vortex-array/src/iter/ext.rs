@@ -1,9 +1,11 @@
 use itertools::Itertools;
+use vortex_dtype::DType;
 use vortex_error::VortexResult;
 
 use crate::array::ChunkedArray;
-use crate::iter::ArrayIterator;
-use crate::stream::{ArrayStream, ArrayStreamAdapter};
+use crate::compute::{filter, FilterMask};
+use crate::iter::{ArrayIterator, ArrayIteratorAdapter, RechunkIterator};
+use crate::stream::{take_from_batch, validate_take_indices, ArrayStream, ArrayStreamAdapter};
 use crate::{ArrayData, IntoArrayData};
 
 pub trait ArrayIteratorExt: ArrayIterator {
@@ -29,6 +31,143 @@ pub trait ArrayIteratorExt: ArrayIterator {
             Ok(ChunkedArray::try_new(chunks, dtype)?.into_array())
         }
     }
+
+    /// Transform each chunk with `f`, which produces chunks of `dtype`.
+    fn map_chunks<F>(self, dtype: DType, mut f: F) -> impl ArrayIterator
+    where
+        Self: Sized,
+        F: FnMut(ArrayData) -> VortexResult<ArrayData>,
+    {
+        ArrayIteratorAdapter::new(dtype, self.map(move |chunk| chunk.and_then(&mut f)))
+    }
+
+    /// Keep the rows of each chunk for which `predicate` evaluates to true.
+    ///
+    /// The predicate returns a boolean array of the length of the chunk, e.g. by evaluating an
+    /// expression over it.
+    fn filter_rows<F>(self, mut predicate: F) -> impl ArrayIterator
+    where
+        Self: Sized,
+        F: FnMut(&ArrayData) -> VortexResult<ArrayData>,
+    {
+        let dtype = self.dtype().clone();
+        ArrayIteratorAdapter::new(
+            dtype,
+            self.map(move |chunk| {
+                let chunk = chunk?;
+                let mask = FilterMask::try_from(predicate(&chunk)?)?;
+                filter(&chunk, mask)
+            }),
+        )
+    }
+
+    /// Perform a row-wise selection on the iterator from an array of sorted indices.
+    ///
+    /// Chunks without selected rows are skipped.
+    fn take_rows(self, indices: ArrayData) -> VortexResult<impl ArrayIterator>
+    where
+        Self: Sized,
+    {
+        validate_take_indices(&indices)?;
+        let dtype = self.dtype().clone();
+        let mut row_offset = 0;
+        Ok(ArrayIteratorAdapter::new(
+            dtype,
+            self.filter_map(move |chunk| {
+                let chunk = match chunk {
+                    Ok(chunk) => chunk,
+                    Err(err) => return Some(Err(err)),
+                };
+                let chunk_offset = row_offset;
+                row_offset += chunk.len();
+                take_from_batch(&indices, chunk_offset, &chunk).transpose()
+            }),
+        ))
+    }
+
+    /// Regroup the chunks into chunks of `chunk_len` rows, of which only the last may be shorter.
+    fn rechunk(self, chunk_len: usize) -> VortexResult<impl ArrayIterator>
+    where
+        Self: Sized,
+    {
+        RechunkIterator::try_new(self, chunk_len)
+    }
 }
 
 impl<I: ArrayIterator> ArrayIteratorExt for I {}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_error::VortexResult;
+
+    use crate::array::{BoolArray, PrimitiveArray};
+    use crate::iter::{ArrayIterator, ArrayIteratorAdapter, ArrayIteratorExt};
+    use crate::{ArrayData, IntoArrayData, IntoArrayVariant};
+
+    fn chunks() -> impl ArrayIterator {
+        ArrayIteratorAdapter::new(
+            DType::Primitive(PType::I32, Nullability::NonNullable),
+            vec![
+                Ok(PrimitiveArray::from(vec![0i32, 1, 2]).into_array()),
+                Ok(PrimitiveArray::from(vec![3i32]).into_array()),
+                Ok(PrimitiveArray::from(vec![4i32, 5, 6, 7, 8]).into_array()),
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn values(chunks: impl ArrayIterator) -> Vec<Vec<i32>> {
+        chunks
+            .map(|chunk| {
+                chunk
+                    .and_then(|c| c.into_primitive())
+                    .map(|p| p.maybe_null_slice::<i32>().to_vec())
+            })
+            .collect::<VortexResult<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn rechunk() {
+        assert_eq!(
+            values(chunks().rechunk(2).unwrap()),
+            vec![vec![0, 1], vec![2, 3], vec![4, 5], vec![6, 7], vec![8]]
+        );
+        assert!(chunks().rechunk(0).is_err());
+    }
+
+    #[test]
+    fn filter_and_take_rows() {
+        let even = chunks().filter_rows(|chunk| {
+            let values = chunk.clone().into_primitive()?;
+            Ok(
+                BoolArray::from_iter(values.maybe_null_slice::<i32>().iter().map(|v| v % 2 == 0))
+                    .into_array(),
+            )
+        });
+        assert_eq!(values(even), vec![vec![0, 2], vec![], vec![4, 6, 8]]);
+
+        let taken = chunks()
+            .take_rows(PrimitiveArray::from(vec![1u64, 2, 7]).into_array())
+            .unwrap();
+        assert_eq!(values(taken), vec![vec![1, 2], vec![7]]);
+    }
+
+    #[test]
+    fn map_chunks() {
+        let dtype = DType::Primitive(PType::I64, Nullability::NonNullable);
+        let widened = chunks().map_chunks(dtype.clone(), |chunk| {
+            let values = chunk.into_primitive()?;
+            Ok(values
+                .maybe_null_slice::<i32>()
+                .iter()
+                .map(|&v| i64::from(v))
+                .collect::<Vec<_>>()
+                .into_array())
+        });
+        assert_eq!(widened.dtype(), &dtype);
+        let array: ArrayData = widened.into_array_data().unwrap();
+        assert_eq!(array.len(), 9);
+    }
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 pub use adapter::*;
 pub use ext::*;
+pub(crate) use rechunk::*;
 use vortex_dtype::{DType, NativePType};
 use vortex_error::{VortexExpect as _, VortexResult};
 
@@ -10,6 +11,7 @@ use crate::ArrayData;
 
 mod adapter;
 mod ext;
+mod rechunk;
 
 pub const ITER_BATCH_SIZE: usize = 1024;
 
@@ -0,0 +1,117 @@
+use std::collections::VecDeque;
+
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexExpect as _, VortexResult};
+
+use crate::array::concat_chunks;
+use crate::compute::slice;
+use crate::iter::ArrayIterator;
+use crate::ArrayData;
+
+/// Regroups chunks into chunks of a fixed number of rows, of which only the last may be shorter.
+pub(crate) struct Rechunker {
+    dtype: DType,
+    chunk_len: usize,
+    pending: VecDeque<ArrayData>,
+    pending_len: usize,
+}
+
+impl Rechunker {
+    pub(crate) fn try_new(dtype: DType, chunk_len: usize) -> VortexResult<Self> {
+        if chunk_len == 0 {
+            vortex_bail!("Cannot rechunk into chunks of 0 rows");
+        }
+        Ok(Self {
+            dtype,
+            chunk_len,
+            pending: VecDeque::new(),
+            pending_len: 0,
+        })
+    }
+
+    pub(crate) fn push(&mut self, chunk: ArrayData) {
+        if !chunk.is_empty() {
+            self.pending_len += chunk.len();
+            self.pending.push_back(chunk);
+        }
+    }
+
+    /// The next chunk of `chunk_len` rows, or when `flush` is set, of the rows that are left.
+    ///
+    /// A chunk that lies within a single pushed chunk is a slice of it, while one that spans
+    /// several pushed chunks is canonicalized.
+    pub(crate) fn pop(&mut self, flush: bool) -> VortexResult<Option<ArrayData>> {
+        if self.pending_len == 0 || (!flush && self.pending_len < self.chunk_len) {
+            return Ok(None);
+        }
+
+        let len = self.pending_len.min(self.chunk_len);
+        let mut parts = Vec::new();
+        let mut remaining = len;
+        while remaining > 0 {
+            let chunk = self
+                .pending
+                .pop_front()
+                .vortex_expect("Pending chunks must hold the pending rows");
+            if chunk.len() <= remaining {
+                remaining -= chunk.len();
+                parts.push(chunk);
+            } else {
+                parts.push(slice(&chunk, 0, remaining)?);
+                self.pending
+                    .push_front(slice(&chunk, remaining, chunk.len())?);
+                remaining = 0;
+            }
+        }
+        self.pending_len -= len;
+
+        if parts.len() == 1 {
+            return Ok(parts.pop());
+        }
+        concat_chunks(parts, &self.dtype).map(Some)
+    }
+}
+
+/// An [`ArrayIterator`] of the chunks of another regrouped by a [`Rechunker`].
+pub(crate) struct RechunkIterator<I> {
+    inner: I,
+    rechunker: Rechunker,
+    exhausted: bool,
+}
+
+impl<I: ArrayIterator> RechunkIterator<I> {
+    pub(crate) fn try_new(inner: I, chunk_len: usize) -> VortexResult<Self> {
+        let rechunker = Rechunker::try_new(inner.dtype().clone(), chunk_len)?;
+        Ok(Self {
+            inner,
+            rechunker,
+            exhausted: false,
+        })
+    }
+}
+
+impl<I: ArrayIterator> Iterator for RechunkIterator<I> {
+    type Item = VortexResult<ArrayData>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(chunk) = self.rechunker.pop(self.exhausted).transpose() {
+                return Some(chunk);
+            }
+            if self.exhausted {
+                return None;
+            }
+            match self.inner.next() {
+                Some(Ok(chunk)) => self.rechunker.push(chunk),
+                Some(Err(err)) => return Some(Err(err)),
+                None => self.exhausted = true,
+            }
+        }
+    }
+}
+
+impl<I: ArrayIterator> ArrayIterator for RechunkIterator<I> {
+    fn dtype(&self) -> &DType {
+        self.inner.dtype()
+    }
+}
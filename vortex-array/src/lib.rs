@@ -38,6 +38,7 @@ mod data;
 pub mod encoding;
 pub mod iter;
 mod macros;
+pub mod memory;
 mod metadata;
 pub mod nbytes;
 pub mod patches;
@@ -0,0 +1,236 @@
+//! Accounting for the memory held by arrays and builders, so that a query engine can bound the
+//! memory used by a scan.
+//!
+//! A [`MemoryPool`] tracks the number of bytes reserved from it, and a [`MemoryReservation`]
+//! holds some of those bytes until it is dropped.
+
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::ArrayData;
+
+/// Tracks the bytes reserved by arrays and builders.
+///
+/// Pools are usually implemented by the query engine, to share a single memory limit between
+/// Vortex and its own operators.
+pub trait MemoryPool: Debug + Send + Sync {
+    /// Reserve `additional` bytes, failing if the pool doesn't have room for them.
+    fn try_grow(&self, additional: usize) -> VortexResult<()>;
+
+    /// Reserve `additional` bytes that have already been allocated, even if the pool doesn't have
+    /// room for them.
+    fn grow(&self, additional: usize);
+
+    /// Release `bytes` previously reserved.
+    fn shrink(&self, bytes: usize);
+
+    /// The number of bytes currently reserved.
+    fn reserved(&self) -> usize;
+}
+
+/// A pool without a limit, which only counts the bytes reserved from it.
+#[derive(Debug, Default)]
+pub struct UnboundedMemoryPool {
+    reserved: AtomicUsize,
+}
+
+impl MemoryPool for UnboundedMemoryPool {
+    fn try_grow(&self, additional: usize) -> VortexResult<()> {
+        self.grow(additional);
+        Ok(())
+    }
+
+    fn grow(&self, additional: usize) {
+        self.reserved.fetch_add(additional, Ordering::Relaxed);
+    }
+
+    fn shrink(&self, bytes: usize) {
+        self.reserved.fetch_sub(bytes, Ordering::Relaxed);
+    }
+
+    fn reserved(&self) -> usize {
+        self.reserved.load(Ordering::Relaxed)
+    }
+}
+
+/// A pool that grants reservations until a fixed number of bytes is reserved.
+#[derive(Debug)]
+pub struct GreedyMemoryPool {
+    limit: usize,
+    reserved: AtomicUsize,
+}
+
+impl GreedyMemoryPool {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            reserved: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl MemoryPool for GreedyMemoryPool {
+    fn try_grow(&self, additional: usize) -> VortexResult<()> {
+        let result = self
+            .reserved
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |reserved| {
+                reserved
+                    .checked_add(additional)
+                    .filter(|&reserved| reserved <= self.limit)
+            });
+        match result {
+            Ok(_) => Ok(()),
+            Err(reserved) => vortex_bail!(
+                "Failed to reserve {} bytes, {} of {} bytes are already reserved",
+                additional,
+                reserved,
+                self.limit
+            ),
+        }
+    }
+
+    fn grow(&self, additional: usize) {
+        self.reserved.fetch_add(additional, Ordering::Relaxed);
+    }
+
+    fn shrink(&self, bytes: usize) {
+        self.reserved.fetch_sub(bytes, Ordering::Relaxed);
+    }
+
+    fn reserved(&self) -> usize {
+        self.reserved.load(Ordering::Relaxed)
+    }
+}
+
+/// Bytes reserved from a [`MemoryPool`], which are released when the reservation is dropped.
+#[derive(Debug)]
+pub struct MemoryReservation {
+    pool: Arc<dyn MemoryPool>,
+    size: usize,
+}
+
+impl MemoryReservation {
+    /// An empty reservation from the pool.
+    pub fn new(pool: Arc<dyn MemoryPool>) -> Self {
+        Self { pool, size: 0 }
+    }
+
+    /// Reserve the bytes an array holds on its own, i.e. the ones that would be freed by dropping
+    /// it. Buffers shared with other arrays are assumed to be accounted for by their other owners.
+    pub fn try_for_array(pool: Arc<dyn MemoryPool>, array: &ArrayData) -> VortexResult<Self> {
+        let mut reservation = Self::new(pool);
+        reservation.try_resize(array.allocated_bytes().owned())?;
+        Ok(reservation)
+    }
+
+    pub fn pool(&self) -> &Arc<dyn MemoryPool> {
+        &self.pool
+    }
+
+    /// The number of bytes reserved.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Grow or shrink the reservation to `size` bytes, failing if the pool doesn't have room.
+    pub fn try_resize(&mut self, size: usize) -> VortexResult<()> {
+        if size > self.size {
+            self.pool.try_grow(size - self.size)?;
+        } else {
+            self.pool.shrink(self.size - size);
+        }
+        self.size = size;
+        Ok(())
+    }
+
+    /// Grow or shrink the reservation to `size` bytes that have already been allocated, even if
+    /// the pool doesn't have room for them.
+    pub fn resize(&mut self, size: usize) {
+        if size > self.size {
+            self.pool.grow(size - self.size);
+        } else {
+            self.pool.shrink(self.size - size);
+        }
+        self.size = size;
+    }
+
+    /// Move `size` bytes of this reservation into a new reservation from the same pool.
+    pub fn split(&mut self, size: usize) -> VortexResult<Self> {
+        if size > self.size {
+            vortex_bail!(
+                "Cannot split {} bytes from a reservation of {} bytes",
+                size,
+                self.size
+            );
+        }
+        self.size -= size;
+        Ok(Self {
+            pool: self.pool.clone(),
+            size,
+        })
+    }
+
+    /// Release all the bytes of the reservation, returning how many there were.
+    pub fn free(&mut self) -> usize {
+        let size = self.size;
+        self.resize(0);
+        size
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::array::PrimitiveArray;
+    use crate::memory::{GreedyMemoryPool, MemoryPool, MemoryReservation};
+    use crate::IntoArrayData;
+
+    #[test]
+    fn greedy_pool_limit() {
+        let pool = Arc::new(GreedyMemoryPool::new(100));
+        let mut reservation = MemoryReservation::new(pool.clone());
+        reservation.try_resize(60).unwrap();
+        assert!(reservation.try_resize(120).is_err());
+        assert_eq!(reservation.size(), 60);
+
+        let mut other = MemoryReservation::new(pool.clone());
+        assert!(other.try_resize(50).is_err());
+        other.try_resize(40).unwrap();
+        assert_eq!(pool.reserved(), 100);
+
+        // Memory that is already allocated is reserved regardless of the limit.
+        other.resize(50);
+        assert_eq!(pool.reserved(), 110);
+
+        drop(reservation);
+        drop(other);
+        assert_eq!(pool.reserved(), 0);
+    }
+
+    #[test]
+    fn reserve_array() {
+        let pool = Arc::new(GreedyMemoryPool::new(1024));
+        let array = PrimitiveArray::from(vec![0u64; 100]).into_array();
+        let reservation = MemoryReservation::try_for_array(pool.clone(), &array).unwrap();
+        assert!(reservation.size() >= 800);
+        assert_eq!(pool.reserved(), reservation.size());
+
+        let larger = PrimitiveArray::from(vec![0u64; 200]).into_array();
+        assert!(MemoryReservation::try_for_array(pool.clone(), &larger).is_err());
+        assert_eq!(pool.reserved(), reservation.size());
+    }
+}
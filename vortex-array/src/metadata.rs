@@ -4,7 +4,9 @@ use std::sync::Arc;
 
 use flexbuffers::{FlexbufferSerializer, Reader};
 use serde::{Deserialize, Serialize};
-use vortex_error::{vortex_err, VortexResult};
+use vortex_dtype::{flatbuffers as fb, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::ScalarValue;
 
 use crate::encoding::Encoding;
 
@@ -30,9 +32,34 @@ pub trait TrySerializeArrayMetadata {
 
 pub trait TryDeserializeArrayMetadata<'m>: Sized {
     fn try_deserialize_metadata(metadata: Option<&'m [u8]>) -> VortexResult<Self>;
+
+    /// Deserialize metadata in [`MetadataFormat::Flexbuffers`], which differs from the current
+    /// format only for metadata that has since moved to a fixed layout.
+    fn try_deserialize_flexbuffers_metadata(metadata: Option<&'m [u8]>) -> VortexResult<Self> {
+        Self::try_deserialize_metadata(metadata)
+    }
+}
+
+/// How the metadata of the arrays in a file or stream was serialized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MetadataFormat {
+    /// Flexbuffers serde of every metadata struct, as written by version 1 of the file format.
+    Flexbuffers,
+    /// Fixed layouts for the metadata of every in-tree encoding, and flexbuffers serde for
+    /// out-of-tree encodings that don't define their own.
+    #[default]
+    FixedLayout,
 }
 
 /// Provide default implementation for metadata serialization based on flexbuffers serde.
+///
+/// The flexbuffers encoding follows the field names and shapes of the serde derive, so it changes
+/// whenever the metadata struct does. Encodings whose metadata must stay readable across releases
+/// implement the traits by hand with a fixed layout written by [`MetadataWriter`] instead.
+///
+/// Every in-tree encoding has a fixed layout, so this default only serializes the metadata of
+/// out-of-tree encodings, which must then only change their metadata in ways serde can read back,
+/// e.g. by adding optional fields.
 impl<M: Serialize> TrySerializeArrayMetadata for M {
     fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
         let mut ser = FlexbufferSerializer::new();
@@ -43,21 +70,378 @@ impl<M: Serialize> TrySerializeArrayMetadata for M {
 
 impl<'de, M: Deserialize<'de>> TryDeserializeArrayMetadata<'de> for M {
     fn try_deserialize_metadata(metadata: Option<&'de [u8]>) -> VortexResult<Self> {
+        Ok(M::deserialize(flexbuffers_root(metadata)?)?)
+    }
+}
+
+/// The root of metadata serialized with flexbuffers, for deserializing it with serde.
+///
+/// Metadata with a fixed layout derive `Deserialize` with `#[serde(remote = "Self")]` to keep
+/// reading the flexbuffers of [`MetadataFormat::Flexbuffers`], which generates an inherent
+/// `deserialize` function rather than an implementation of the trait.
+pub fn flexbuffers_root(metadata: Option<&[u8]>) -> VortexResult<Reader<&[u8]>> {
+    let bytes = metadata.ok_or_else(|| vortex_err!("Array requires metadata bytes"))?;
+    Ok(Reader::get_root(bytes)?)
+}
+
+/// Writes metadata as a fixed little-endian layout, led by a version byte.
+///
+/// A layout is documented next to the metadata it encodes. Once released, a layout only changes
+/// by appending fields under a new version, so that readers can keep decoding older metadata.
+#[derive(Debug)]
+pub struct MetadataWriter {
+    bytes: Vec<u8>,
+}
+
+impl MetadataWriter {
+    pub fn new(version: u8) -> Self {
+        Self {
+            bytes: vec![version],
+        }
+    }
+
+    pub fn put_u8(&mut self, value: u8) -> &mut Self {
+        self.bytes.push(value);
+        self
+    }
+
+    pub fn put_bool(&mut self, value: bool) -> &mut Self {
+        self.put_u8(u8::from(value))
+    }
+
+    pub fn put_u16(&mut self, value: u16) -> &mut Self {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    pub fn put_u32(&mut self, value: u32) -> &mut Self {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    pub fn put_u64(&mut self, value: u64) -> &mut Self {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    /// Write a length or offset as a u64, so that the layout doesn't depend on the platform.
+    pub fn put_usize(&mut self, value: usize) -> &mut Self {
+        self.put_u64(value as u64)
+    }
+
+    /// Write a ptype as its value in the flatbuffer schema of the dtypes.
+    pub fn put_ptype(&mut self, ptype: PType) -> &mut Self {
+        self.put_u8(fb::PType::from(ptype).0)
+    }
+
+    /// Write a scalar value as its length as a u64, followed by its flexbuffers serialization,
+    /// which is how scalar values are serialized everywhere else.
+    pub fn put_scalar_value(&mut self, value: &ScalarValue) -> VortexResult<&mut Self> {
+        let mut ser = FlexbufferSerializer::new();
+        value.serialize(&mut ser)?;
+        self.put_usize(ser.view().len());
+        self.bytes.extend_from_slice(ser.view());
+        Ok(self)
+    }
+
+    pub fn finish(self) -> Arc<[u8]> {
+        self.bytes.into()
+    }
+}
+
+/// Reads metadata written by a [`MetadataWriter`].
+#[derive(Debug)]
+pub struct MetadataReader<'m> {
+    version: u8,
+    bytes: &'m [u8],
+}
+
+impl<'m> MetadataReader<'m> {
+    /// Read the version of the metadata, failing if it is newer than `max_version`, i.e. written
+    /// by a later release with fields this one doesn't know about.
+    pub fn try_new(metadata: Option<&'m [u8]>, max_version: u8) -> VortexResult<Self> {
         let bytes = metadata.ok_or_else(|| vortex_err!("Array requires metadata bytes"))?;
-        Ok(M::deserialize(Reader::get_root(bytes)?)?)
+        let Some((&version, bytes)) = bytes.split_first() else {
+            vortex_bail!("Metadata is missing its version");
+        };
+        if version > max_version {
+            vortex_bail!(
+                "Metadata version {} is newer than the latest supported version {}",
+                version,
+                max_version
+            );
+        }
+        Ok(Self { version, bytes })
+    }
+
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn take<const N: usize>(&mut self) -> VortexResult<[u8; N]> {
+        if self.bytes.len() < N {
+            vortex_bail!(
+                "Metadata ended early, expected {} more bytes but found {}",
+                N,
+                self.bytes.len()
+            );
+        }
+        let (head, rest) = self.bytes.split_at(N);
+        self.bytes = rest;
+        let mut value = [0; N];
+        value.copy_from_slice(head);
+        Ok(value)
+    }
+
+    pub fn get_u8(&mut self) -> VortexResult<u8> {
+        Ok(u8::from_le_bytes(self.take()?))
+    }
+
+    pub fn get_bool(&mut self) -> VortexResult<bool> {
+        match self.get_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => vortex_bail!("Metadata value {} is not a bool", value),
+        }
+    }
+
+    pub fn get_u16(&mut self) -> VortexResult<u16> {
+        Ok(u16::from_le_bytes(self.take()?))
+    }
+
+    pub fn get_u32(&mut self) -> VortexResult<u32> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
+    pub fn get_u64(&mut self) -> VortexResult<u64> {
+        Ok(u64::from_le_bytes(self.take()?))
+    }
+
+    pub fn get_usize(&mut self) -> VortexResult<usize> {
+        let value = self.get_u64()?;
+        usize::try_from(value)
+            .map_err(|_| vortex_err!("Metadata value {} doesn't fit in a usize", value))
+    }
+
+    pub fn get_ptype(&mut self) -> VortexResult<PType> {
+        PType::try_from(fb::PType(self.get_u8()?))
+    }
+
+    pub fn get_scalar_value(&mut self) -> VortexResult<ScalarValue> {
+        let len = self.get_usize()?;
+        if self.bytes.len() < len {
+            vortex_bail!(
+                "Metadata ended early, expected a scalar value of {} bytes but found {}",
+                len,
+                self.bytes.len()
+            );
+        }
+        let (value, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(ScalarValue::deserialize(Reader::get_root(value)?)?)
+    }
+
+    /// Check that all the fields were read, since trailing bytes mean the layout was misread.
+    pub fn finish(self) -> VortexResult<()> {
+        if !self.bytes.is_empty() {
+            vortex_bail!(
+                "Metadata has {} trailing bytes for version {}",
+                self.bytes.len(),
+                self.version
+            );
+        }
+        Ok(())
     }
 }
 
 pub trait MetadataVTable {
-    fn load_metadata(&self, metadata: Option<&[u8]>) -> VortexResult<Arc<dyn ArrayMetadata>>;
+    fn load_metadata(
+        &self,
+        metadata: Option<&[u8]>,
+        format: MetadataFormat,
+    ) -> VortexResult<Arc<dyn ArrayMetadata>>;
 }
 
 impl<E: Encoding> MetadataVTable for E
 where
     E::Metadata: for<'m> TryDeserializeArrayMetadata<'m>,
 {
-    fn load_metadata(&self, metadata: Option<&[u8]>) -> VortexResult<Arc<dyn ArrayMetadata>> {
-        E::Metadata::try_deserialize_metadata(metadata)
-            .map(|m| Arc::new(m) as Arc<dyn ArrayMetadata>)
+    fn load_metadata(
+        &self,
+        metadata: Option<&[u8]>,
+        format: MetadataFormat,
+    ) -> VortexResult<Arc<dyn ArrayMetadata>> {
+        match format {
+            MetadataFormat::Flexbuffers => {
+                E::Metadata::try_deserialize_flexbuffers_metadata(metadata)
+            }
+            MetadataFormat::FixedLayout => E::Metadata::try_deserialize_metadata(metadata),
+        }
+        .map(|m| Arc::new(m) as Arc<dyn ArrayMetadata>)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use flexbuffers::FlexbufferSerializer;
+    use serde::Serialize;
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::{Scalar, ScalarValue};
+
+    use crate::array::{
+        BoolArray, ChunkedArray, ConstantArray, ConstantEncoding, PrimitiveArray,
+        PrimitiveEncoding, SparseArray, SparseEncoding, VarBinEncoding,
+    };
+    use crate::metadata::{MetadataFormat, MetadataReader, MetadataVTable, MetadataWriter};
+    use crate::stats::ArrayStatistics;
+    use crate::validity::Validity;
+    use crate::IntoArrayData;
+
+    #[test]
+    fn fixed_layout() {
+        let mut writer = MetadataWriter::new(1);
+        writer
+            .put_u8(7)
+            .put_u32(0x0102_0304)
+            .put_usize(5)
+            .put_ptype(PType::I32);
+        let bytes = writer.finish();
+        assert_eq!(
+            bytes.as_ref(),
+            &[1, 7, 4, 3, 2, 1, 5, 0, 0, 0, 0, 0, 0, 0, 6]
+        );
+
+        let mut reader = MetadataReader::try_new(Some(&bytes), 1).unwrap();
+        assert_eq!(reader.version(), 1);
+        assert_eq!(reader.get_u8().unwrap(), 7);
+        assert_eq!(reader.get_u32().unwrap(), 0x0102_0304);
+        assert_eq!(reader.get_usize().unwrap(), 5);
+        assert_eq!(reader.get_ptype().unwrap(), PType::I32);
+        reader.finish().unwrap();
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        assert!(MetadataReader::try_new(None, 0).is_err());
+        assert!(MetadataReader::try_new(Some(&[]), 0).is_err());
+        // Written by a later release.
+        assert!(MetadataReader::try_new(Some(&[2]), 1).is_err());
+
+        let mut reader = MetadataReader::try_new(Some(&[0, 1, 2]), 0).unwrap();
+        assert!(reader.get_u32().is_err());
+        let reader = MetadataReader::try_new(Some(&[0, 1]), 0).unwrap();
+        assert!(reader.finish().is_err());
+    }
+
+    // The layouts below are released, so these bytes must keep serializing and deserializing the
+    // same way. Change a layout by adding a version rather than by editing the expected bytes.
+
+    #[test]
+    fn serialized_layouts() {
+        let validity = BoolArray::from_iter([true, false]).into_array();
+        validity.statistics().compute_true_count();
+        let primitive =
+            PrimitiveArray::from_vec(vec![1i32, 0], Validity::Array(validity)).into_array();
+        assert_eq!(
+            primitive.metadata_bytes().unwrap().as_ref(),
+            &[0, 4, 1, 0, 0, 0, 0, 0, 0, 0]
+        );
+
+        let bools = BoolArray::from_iter([true, false]).into_array();
+        assert_eq!(bools.metadata_bytes().unwrap().as_ref(), &[0, 0, 0]);
+
+        let chunked = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from(vec![1u8]).into_array(),
+                PrimitiveArray::from(vec![2u8]).into_array(),
+            ],
+            DType::Primitive(PType::U8, Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(
+            chunked.metadata_bytes().unwrap().as_ref(),
+            &[0, 2, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn deserialized_layouts() {
+        let primitive = PrimitiveEncoding
+            .load_metadata(Some(&[0, 3]), MetadataFormat::FixedLayout)
+            .unwrap();
+        assert_eq!(
+            primitive.to_string(),
+            "PrimitiveMetadata { validity: Array { null_count: None } }"
+        );
+
+        let varbin = VarBinEncoding
+            .load_metadata(
+                Some(&[0, 1, 1, 5, 0, 0, 0, 0, 0, 0, 0]),
+                MetadataFormat::FixedLayout,
+            )
+            .unwrap();
+        assert_eq!(
+            varbin.to_string(),
+            "VarBinMetadata { validity: AllValid, offsets_ptype: U16, bytes_len: 5 }"
+        );
+
+        // Written by a later release, or cut short.
+        assert!(PrimitiveEncoding
+            .load_metadata(Some(&[1, 0]), MetadataFormat::FixedLayout)
+            .is_err());
+        assert!(VarBinEncoding
+            .load_metadata(Some(&[0, 1, 1]), MetadataFormat::FixedLayout)
+            .is_err());
+    }
+
+    #[test]
+    fn scalar_value_layouts() {
+        let constant = ConstantArray::new(5i32, 3).into_array();
+        let bytes = constant.metadata_bytes().unwrap();
+        assert_eq!(bytes.as_ref(), &[0, 3, 0, 0, 0, 0, 0, 0, 0, 5, 4, 1]);
+        assert_eq!(
+            ConstantEncoding
+                .load_metadata(Some(&bytes), MetadataFormat::FixedLayout)
+                .unwrap()
+                .to_string(),
+            "ConstantMetadata { scalar_value: 5_i8 }"
+        );
+
+        // Version 1 files serialized the same struct with flexbuffers.
+        #[derive(Serialize)]
+        struct FlexbuffersConstantMetadata {
+            scalar_value: ScalarValue,
+        }
+        let mut ser = FlexbufferSerializer::new();
+        FlexbuffersConstantMetadata {
+            scalar_value: Scalar::from(5i32).into_value(),
+        }
+        .serialize(&mut ser)
+        .unwrap();
+        assert_eq!(
+            ConstantEncoding
+                .load_metadata(Some(ser.view()), MetadataFormat::Flexbuffers)
+                .unwrap()
+                .to_string(),
+            "ConstantMetadata { scalar_value: 5_i8 }"
+        );
+
+        let sparse = SparseArray::try_new(
+            PrimitiveArray::from(vec![1u8]).into_array(),
+            PrimitiveArray::from(vec![7i32]).into_array(),
+            4,
+            Scalar::from(0i32),
+        )
+        .unwrap()
+        .into_array();
+        let bytes = sparse.metadata_bytes().unwrap();
+        assert_eq!(
+            &bytes[..18],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert!(SparseEncoding
+            .load_metadata(Some(&bytes), MetadataFormat::FixedLayout)
+            .is_ok());
     }
 }
@@ -1,8 +1,10 @@
 use vortex_buffer::Buffer;
+use vortex_dtype::DType;
 use vortex_error::{VortexExpect, VortexResult};
 
+use crate::stats::{ArrayStatistics, Stat};
 use crate::visitor::ArrayVisitor;
-use crate::ArrayData;
+use crate::{ArrayDType, ArrayData};
 
 impl ArrayData {
     /// Total size of the array in bytes, including all children and buffers.
@@ -13,11 +15,122 @@ impl ArrayData {
             .vortex_expect("Failed to get nbytes from Array");
         visitor.0 + size_of_val(self.array_metadata())
     }
+
+    /// The memory allocated for the buffers of the array and its children, split into the bytes
+    /// only this array holds and the bytes it shares with others.
+    ///
+    /// Slices share the memory of the array they were sliced from while that array is alive, see
+    /// [`Buffer::is_shared`]. Every other buffer counts as owned, including those read from a file
+    /// or an IPC message, so that memory limits err on the side of overcounting.
+    pub fn allocated_bytes(&self) -> AllocatedBytes {
+        let mut visitor = AllocatedBytesVisitor::default();
+        self.encoding()
+            .accept(self.as_ref(), &mut visitor)
+            .vortex_expect("Failed to get allocated bytes from Array");
+        visitor.0
+    }
+
+    /// The bytes of the buffers of the array and its children as they are encoded, i.e. what the
+    /// array takes up when written out.
+    pub fn compressed_size(&self) -> usize {
+        self.buffer_bytes(Buffer::len)
+    }
+
+    /// The bytes of the buffers of the array and its children that no other array references,
+    /// i.e. what this array alone adds to the data in memory.
+    ///
+    /// Buffers count as shared as described by [`Buffer::is_shared`], so arrays read from a file
+    /// or an IPC message count the bytes they were decoded from, while slices don't while the
+    /// array they were sliced from is alive.
+    pub fn unique_size(&self) -> usize {
+        self.buffer_bytes(|buffer| if buffer.is_shared() { 0 } else { buffer.len() })
+    }
+
+    /// An estimate of the bytes the array takes up once canonicalized, without decoding it.
+    ///
+    /// The uncompressed size statistic is used if it was computed. Otherwise the size of
+    /// fixed-width values follows from the dtype, while arrays holding variable-width values,
+    /// which can't be sized without decoding them, are estimated by their compressed size.
+    pub fn estimated_canonical_size(&self) -> usize {
+        if let Some(size) = self
+            .statistics()
+            .get_as::<usize>(Stat::UncompressedSizeInBytes)
+        {
+            return size;
+        }
+        fixed_width_size(self.dtype(), self.len()).unwrap_or_else(|| self.compressed_size())
+    }
+
+    fn buffer_bytes(&self, bytes_fn: fn(&Buffer) -> usize) -> usize {
+        let mut visitor = BufferBytesVisitor { bytes: 0, bytes_fn };
+        self.encoding()
+            .accept(self.as_ref(), &mut visitor)
+            .vortex_expect("Failed to get buffer bytes from Array");
+        visitor.bytes
+    }
+}
+
+/// The canonical size of `len` values of the dtype, or `None` if the values are variable-width.
+fn fixed_width_size(dtype: &DType, len: usize) -> Option<usize> {
+    let validity = if dtype.is_nullable() {
+        len.div_ceil(8)
+    } else {
+        0
+    };
+    let values = match dtype {
+        DType::Null => 0,
+        DType::Bool(_) => len.div_ceil(8),
+        DType::Primitive(ptype, _) => len * ptype.byte_width(),
+        DType::Decimal(decimal, _) => len * decimal.value_type().byte_width(),
+        DType::Struct(st, _) => st
+            .dtypes()
+            .iter()
+            .map(|field| fixed_width_size(field, len))
+            .sum::<Option<usize>>()?,
+        DType::Extension(ext) => return fixed_width_size(ext.storage_dtype(), len),
+        DType::FixedSizeList(elem, size, _) => fixed_width_size(elem, len * *size as usize)?,
+        DType::Utf8(_) | DType::Binary(_) | DType::List(..) => return None,
+    };
+    Some(values + validity)
+}
+
+/// The memory held by an array, as returned by [`ArrayData::allocated_bytes`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocatedBytes {
+    owned: usize,
+    shared: usize,
+}
+
+impl AllocatedBytes {
+    /// The bytes allocated for buffers only this array references, including their unused
+    /// capacity, which are freed when the array is dropped.
+    pub fn owned(&self) -> usize {
+        self.owned
+    }
+
+    /// The bytes of buffers that are also referenced elsewhere, which stay allocated when the
+    /// array is dropped. Only the part of each buffer the array views is counted.
+    pub fn shared(&self) -> usize {
+        self.shared
+    }
+
+    pub fn total(&self) -> usize {
+        self.owned + self.shared
+    }
 }
 
 pub trait ArrayNBytes {
     /// Total size of the array in bytes, including all children and buffers.
     fn nbytes(&self) -> usize;
+
+    /// See [`ArrayData::compressed_size`].
+    fn compressed_size(&self) -> usize;
+
+    /// See [`ArrayData::estimated_canonical_size`].
+    fn estimated_canonical_size(&self) -> usize;
+
+    /// See [`ArrayData::unique_size`].
+    fn unique_size(&self) -> usize;
 }
 
 // Implement ArrayNBytes for all concrete arrays.
@@ -26,6 +139,21 @@ impl<A: AsRef<ArrayData>> ArrayNBytes for A {
     fn nbytes(&self) -> usize {
         self.as_ref().nbytes()
     }
+
+    #[inline(always)]
+    fn compressed_size(&self) -> usize {
+        self.as_ref().compressed_size()
+    }
+
+    #[inline(always)]
+    fn estimated_canonical_size(&self) -> usize {
+        self.as_ref().estimated_canonical_size()
+    }
+
+    #[inline(always)]
+    fn unique_size(&self) -> usize {
+        self.as_ref().unique_size()
+    }
 }
 
 struct NBytesVisitor(usize);
@@ -41,3 +169,103 @@ impl ArrayVisitor for NBytesVisitor {
         Ok(())
     }
 }
+
+struct BufferBytesVisitor {
+    bytes: usize,
+    bytes_fn: fn(&Buffer) -> usize,
+}
+
+impl ArrayVisitor for BufferBytesVisitor {
+    fn visit_child(&mut self, _name: &str, array: &ArrayData) -> VortexResult<()> {
+        self.bytes += array.buffer_bytes(self.bytes_fn);
+        Ok(())
+    }
+
+    fn visit_buffer(&mut self, buffer: &Buffer) -> VortexResult<()> {
+        self.bytes += (self.bytes_fn)(buffer);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct AllocatedBytesVisitor(AllocatedBytes);
+
+impl ArrayVisitor for AllocatedBytesVisitor {
+    fn visit_child(&mut self, _name: &str, array: &ArrayData) -> VortexResult<()> {
+        let child = array.allocated_bytes();
+        self.0.owned += child.owned;
+        self.0.shared += child.shared;
+        Ok(())
+    }
+
+    fn visit_buffer(&mut self, buffer: &Buffer) -> VortexResult<()> {
+        if buffer.is_shared() {
+            self.0.shared += buffer.len();
+        } else {
+            self.0.owned += buffer.capacity();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_buffer::Buffer;
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::{ChunkedArray, ConstantArray, PrimitiveArray};
+    use crate::compute::slice;
+    use crate::validity::Validity;
+    use crate::IntoArrayData;
+
+    static STATIC_BYTES: [u8; 400] = [0; 400];
+
+    #[test]
+    fn allocated_bytes() {
+        let mut values = Vec::with_capacity(200);
+        values.extend(0u32..100);
+        let array = PrimitiveArray::from(values).into_array();
+        let allocated = array.allocated_bytes();
+        assert_eq!(allocated.owned(), 200 * size_of::<u32>());
+        assert_eq!(allocated.shared(), 0);
+
+        // The slice only views the allocation of the array it was sliced from.
+        let sliced = slice(&array, 10, 20).unwrap().allocated_bytes();
+        assert_eq!(sliced.owned(), 0);
+        assert_eq!(sliced.shared(), 10 * size_of::<u32>());
+
+        // Static memory is never freed by the array.
+        let shared = PrimitiveArray::new(
+            Buffer::from(&STATIC_BYTES[..]),
+            PType::U8,
+            Validity::NonNullable,
+        )
+        .into_array();
+        assert_eq!(shared.allocated_bytes().owned(), 0);
+        assert_eq!(shared.allocated_bytes().shared(), 400);
+    }
+
+    #[test]
+    fn size_notions() {
+        let constant = ConstantArray::new(5u64, 1000).into_array();
+        assert_eq!(constant.compressed_size(), 0);
+        assert_eq!(constant.estimated_canonical_size(), 8000);
+
+        let array = PrimitiveArray::from(vec![0u32; 100]).into_array();
+        assert_eq!(array.compressed_size(), 400);
+        assert_eq!(array.unique_size(), 400);
+
+        // Chunks sliced from the array share its values.
+        let chunked = ChunkedArray::try_new(
+            vec![
+                slice(&array, 0, 50).unwrap(),
+                slice(&array, 50, 100).unwrap(),
+            ],
+            DType::Primitive(PType::U32, Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(chunked.unique_size(), chunked.compressed_size() - 400);
+        assert_eq!(chunked.estimated_canonical_size(), 400);
+    }
+}
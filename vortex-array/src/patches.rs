@@ -13,6 +13,7 @@ use crate::compute::{
     scalar_at, search_sorted, search_sorted_usize, search_sorted_usize_many, slice, sub_scalar,
     take, FilterMask, SearchResult, SearchSortedSide,
 };
+use crate::metadata::{MetadataReader, MetadataWriter};
 use crate::stats::{ArrayStatistics, Stat};
 use crate::validity::Validity;
 use crate::variants::PrimitiveArrayTrait;
@@ -43,6 +44,35 @@ impl PatchesMetadata {
         );
         DType::Primitive(self.indices_ptype, NonNullable)
     }
+
+    /// Write the patches in the fixed layout of the metadata that holds them: the number of
+    /// patches as a u64 and the ptype of their indices.
+    pub fn write_to(&self, writer: &mut MetadataWriter) {
+        writer.put_usize(self.len).put_ptype(self.indices_ptype);
+    }
+
+    /// Read the patches written by [`PatchesMetadata::write_to`].
+    pub fn read_from(reader: &mut MetadataReader) -> VortexResult<Self> {
+        let len = reader.get_usize()?;
+        let indices_ptype = reader.get_ptype()?;
+        Ok(Self { len, indices_ptype })
+    }
+
+    /// Write optional patches as a bool of whether there are any, followed by the patches.
+    pub fn write_option_to(patches: Option<&Self>, writer: &mut MetadataWriter) {
+        writer.put_bool(patches.is_some());
+        if let Some(patches) = patches {
+            patches.write_to(writer);
+        }
+    }
+
+    /// Read the optional patches written by [`PatchesMetadata::write_option_to`].
+    pub fn read_option_from(reader: &mut MetadataReader) -> VortexResult<Option<Self>> {
+        reader
+            .get_bool()?
+            .then(|| Self::read_from(reader))
+            .transpose()
+    }
 }
 
 /// A helper for working with patched arrays.
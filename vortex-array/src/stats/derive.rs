@@ -0,0 +1,151 @@
+//! Statistics of the results of compute functions that follow from the statistics of their inputs,
+//! so they don't have to be recomputed from the values.
+
+use vortex_scalar::Scalar;
+
+use crate::stats::{ArrayStatistics, Stat, Statistics};
+use crate::ArrayData;
+
+/// Derive the statistics of `child`, which holds some of the values of `parent` in their original
+/// order, e.g. a slice or a filter of it.
+pub(crate) fn derive_subsequence_stats(parent: &ArrayData, child: &ArrayData) {
+    if child.is_empty() {
+        return;
+    }
+    for stat in [Stat::IsSorted, Stat::IsStrictSorted] {
+        if parent.statistics().get_as::<bool>(stat) == Some(true) {
+            set_if_absent(child.statistics(), stat, true.into());
+        }
+    }
+    derive_selection_stats(parent, child);
+}
+
+/// Derive the statistics of `taken`, which holds the values of `array` at the given `indices`.
+pub(crate) fn derive_take_stats(array: &ArrayData, indices: &ArrayData, taken: &ArrayData) {
+    if taken.is_empty() {
+        return;
+    }
+    // Indices that don't decrease pick values that don't decrease either.
+    for stat in [Stat::IsSorted, Stat::IsStrictSorted] {
+        if array.statistics().get_as::<bool>(stat) == Some(true)
+            && indices.statistics().get_as::<bool>(stat) == Some(true)
+        {
+            set_if_absent(taken.statistics(), stat, true.into());
+        }
+    }
+    derive_selection_stats(array, taken);
+}
+
+/// Derive the statistics of `casted`, which holds the values of `array` cast to another dtype.
+pub(crate) fn derive_cast_stats(array: &ArrayData, casted: &ArrayData) {
+    if let Some(null_count) = array.statistics().get(Stat::NullCount) {
+        set_if_absent(casted.statistics(), Stat::NullCount, null_count);
+    }
+    if array.statistics().get_as::<bool>(Stat::IsConstant) == Some(true) {
+        set_if_absent(casted.statistics(), Stat::IsConstant, true.into());
+    }
+}
+
+/// Derive the statistics that hold for any selection of the values of `parent`, in any order.
+fn derive_selection_stats(parent: &ArrayData, child: &ArrayData) {
+    let parent_stats = parent.statistics();
+    let stats = child.statistics();
+
+    if parent_stats.get_as::<bool>(Stat::IsConstant) == Some(true) {
+        set_if_absent(stats, Stat::IsConstant, true.into());
+        for stat in [Stat::Min, Stat::Max] {
+            if let Some(value) = parent_stats.get(stat) {
+                set_if_absent(stats, stat, value);
+            }
+        }
+    }
+
+    // Arrays without any or with only nulls remain so.
+    match parent_stats.get_as_cast::<u64>(Stat::NullCount) {
+        Some(0) => set_if_absent(stats, Stat::NullCount, 0u64.into()),
+        Some(n) if n == parent.len() as u64 => {
+            set_if_absent(stats, Stat::NullCount, (child.len() as u64).into())
+        }
+        _ => {}
+    }
+    if parent_stats.get_as_cast::<u64>(Stat::TrueCount) == Some(0) {
+        set_if_absent(stats, Stat::TrueCount, 0u64.into());
+    }
+    if parent_stats.get_as::<bool>(Stat::HasNaN) == Some(false) {
+        set_if_absent(stats, Stat::HasNaN, false.into());
+    }
+}
+
+fn set_if_absent(stats: &dyn Statistics, stat: Stat, value: Scalar) {
+    if stats.get(stat).is_none() {
+        stats.set(stat, value);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::PrimitiveArray;
+    use crate::compute::{filter, slice, take, try_cast, FilterMask};
+    use crate::stats::{ArrayStatistics, Stat};
+    use crate::IntoArrayData;
+
+    #[test]
+    fn slice_preserves_sortedness() {
+        let array = PrimitiveArray::from(vec![1i32, 2, 3, 4]).into_array();
+        array.statistics().compute(Stat::IsStrictSorted);
+        array.statistics().compute(Stat::NullCount);
+
+        let sliced = slice(&array, 1, 3).unwrap();
+        assert_eq!(
+            sliced.statistics().get_as::<bool>(Stat::IsStrictSorted),
+            Some(true)
+        );
+        assert_eq!(sliced.statistics().get_as::<u64>(Stat::NullCount), Some(0));
+        assert_eq!(sliced.statistics().get(Stat::Min), None);
+    }
+
+    #[test]
+    fn filter_preserves_constant() {
+        let array = PrimitiveArray::from(vec![7i32; 4]).into_array();
+        array.statistics().compute(Stat::IsConstant);
+        array.statistics().compute(Stat::Min);
+
+        let filtered = filter(&array, FilterMask::from_iter([true, false, true, false])).unwrap();
+        assert_eq!(
+            filtered.statistics().get_as::<bool>(Stat::IsConstant),
+            Some(true)
+        );
+        assert_eq!(filtered.statistics().get_as::<i32>(Stat::Min), Some(7));
+    }
+
+    #[test]
+    fn take_with_sorted_indices() {
+        let array = PrimitiveArray::from(vec![1i32, 2, 3, 4]).into_array();
+        array.statistics().compute(Stat::IsSorted);
+
+        let sorted = PrimitiveArray::from(vec![0u32, 0, 2]).into_array();
+        sorted.statistics().compute(Stat::IsSorted);
+        let taken = take(&array, &sorted).unwrap();
+        assert_eq!(
+            taken.statistics().get_as::<bool>(Stat::IsSorted),
+            Some(true)
+        );
+
+        let unsorted = PrimitiveArray::from(vec![2u32, 0]).into_array();
+        unsorted.statistics().compute(Stat::IsSorted);
+        let taken = take(&array, &unsorted).unwrap();
+        assert_eq!(taken.statistics().get(Stat::IsSorted), None);
+    }
+
+    #[test]
+    fn cast_preserves_null_count() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3)]).into_array();
+        array.statistics().compute(Stat::NullCount);
+
+        let casted =
+            try_cast(&array, &DType::Primitive(PType::I64, Nullability::Nullable)).unwrap();
+        assert_eq!(casted.statistics().get_as::<u64>(Stat::NullCount), Some(1));
+    }
+}
@@ -2,7 +2,62 @@ use flatbuffers::{FlatBufferBuilder, WIPOffset};
 use itertools::Itertools;
 use vortex_flatbuffers::WriteFlatBuffer;
 
-use crate::stats::{Stat, Statistics};
+use crate::stats::{ArrayStatistics, HyperLogLog, Stat, Statistics};
+use crate::ArrayData;
+
+/// The version of the layout of the flatbuffer statistics written by this library.
+///
+/// Version 1 stores the statistics without a dedicated field in `extra`, keyed by their [Stat]
+/// discriminant. Readers ignore the extra statistics of layouts newer than they know.
+pub const STATS_LAYOUT_VERSION: u16 = 1;
+
+/// The statistics with a dedicated field in the flatbuffer statistics.
+const DEDICATED_STATS: &[Stat] = &[
+    Stat::BitWidthFreq,
+    Stat::TrailingZeroFreq,
+    Stat::IsConstant,
+    Stat::IsSorted,
+    Stat::IsStrictSorted,
+    Stat::Max,
+    Stat::Min,
+    Stat::RunCount,
+    Stat::TrueCount,
+    Stat::NullCount,
+    Stat::UncompressedSizeInBytes,
+    Stat::ApproxDistinct,
+    Stat::HasNaN,
+];
+
+/// Arrays shorter than this leave the distinct count sketch out of their flatbuffer statistics, as
+/// its fixed 1 KiB would outweigh the values it describes.
+pub const MIN_SKETCHED_ARRAY_LEN: usize = 1024;
+
+/// The statistics of an array as written into its flatbuffer, which leave out the distinct count
+/// sketch of arrays shorter than [MIN_SKETCHED_ARRAY_LEN].
+pub struct ArrayStatsWriter<'a> {
+    stats: &'a dyn Statistics,
+    len: usize,
+}
+
+impl<'a> ArrayStatsWriter<'a> {
+    pub fn new(array: &'a ArrayData) -> Self {
+        Self {
+            stats: array.statistics(),
+            len: array.len(),
+        }
+    }
+}
+
+impl WriteFlatBuffer for ArrayStatsWriter<'_> {
+    type Target<'t> = crate::flatbuffers::ArrayStats<'t>;
+
+    fn write_flatbuffer<'fb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fb>,
+    ) -> WIPOffset<Self::Target<'fb>> {
+        write_stats(self.stats, self.len >= MIN_SKETCHED_ARRAY_LEN, fbb)
+    }
+}
 
 impl WriteFlatBuffer for &dyn Statistics {
     type Target<'t> = crate::flatbuffers::ArrayStats<'t>;
@@ -11,38 +66,72 @@ impl WriteFlatBuffer for &dyn Statistics {
         &self,
         fbb: &mut FlatBufferBuilder<'fb>,
     ) -> WIPOffset<Self::Target<'fb>> {
-        let trailing_zero_freq = self
-            .get_as::<Vec<u64>>(Stat::TrailingZeroFreq)
-            .map(|v| v.iter().copied().collect_vec())
-            .map(|v| fbb.create_vector(v.as_slice()));
-
-        let bit_width_freq = self
-            .get_as::<Vec<u64>>(Stat::BitWidthFreq)
-            .map(|v| v.iter().copied().collect_vec())
-            .map(|v| fbb.create_vector(v.as_slice()));
-
-        let min = self
-            .get(Stat::Min)
-            .map(|min| min.into_value().write_flatbuffer(fbb));
-
-        let max = self
-            .get(Stat::Max)
-            .map(|max| max.into_value().write_flatbuffer(fbb));
-
-        let stat_args = &crate::flatbuffers::ArrayStatsArgs {
-            min,
-            max,
-            is_sorted: self.get_as::<bool>(Stat::IsSorted),
-            is_strict_sorted: self.get_as::<bool>(Stat::IsStrictSorted),
-            is_constant: self.get_as::<bool>(Stat::IsConstant),
-            run_count: self.get_as_cast::<u64>(Stat::RunCount),
-            true_count: self.get_as_cast::<u64>(Stat::TrueCount),
-            null_count: self.get_as_cast::<u64>(Stat::NullCount),
-            bit_width_freq,
-            trailing_zero_freq,
-            uncompressed_size_in_bytes: self.get_as_cast::<u64>(Stat::UncompressedSizeInBytes),
-        };
-
-        crate::flatbuffers::ArrayStats::create(fbb, stat_args)
+        write_stats(*self, true, fbb)
     }
 }
+
+fn write_stats<'fb>(
+    stats: &dyn Statistics,
+    with_sketch: bool,
+    fbb: &mut FlatBufferBuilder<'fb>,
+) -> WIPOffset<crate::flatbuffers::ArrayStats<'fb>> {
+    let trailing_zero_freq = stats
+        .get_as::<Vec<u64>>(Stat::TrailingZeroFreq)
+        .map(|v| v.iter().copied().collect_vec())
+        .map(|v| fbb.create_vector(v.as_slice()));
+
+    let bit_width_freq = stats
+        .get_as::<Vec<u64>>(Stat::BitWidthFreq)
+        .map(|v| v.iter().copied().collect_vec())
+        .map(|v| fbb.create_vector(v.as_slice()));
+
+    let approx_distinct = with_sketch
+        .then(|| stats.get_as::<HyperLogLog>(Stat::ApproxDistinct))
+        .flatten()
+        .map(|hll| fbb.create_vector(hll.as_bytes()));
+
+    let min = stats
+        .get(Stat::Min)
+        .map(|min| min.into_value().write_flatbuffer(fbb));
+
+    let max = stats
+        .get(Stat::Max)
+        .map(|max| max.into_value().write_flatbuffer(fbb));
+
+    let extra = stats
+        .to_set()
+        .into_iter()
+        .filter(|(stat, _)| !DEDICATED_STATS.contains(stat))
+        .map(|(stat, value)| {
+            let value = value.into_value().write_flatbuffer(fbb);
+            crate::flatbuffers::ArrayStat::create(
+                fbb,
+                &crate::flatbuffers::ArrayStatArgs {
+                    stat: stat.into(),
+                    value: Some(value),
+                },
+            )
+        })
+        .collect_vec();
+    let extra = (!extra.is_empty()).then(|| fbb.create_vector(extra.as_slice()));
+
+    let stat_args = &crate::flatbuffers::ArrayStatsArgs {
+        min,
+        max,
+        is_sorted: stats.get_as::<bool>(Stat::IsSorted),
+        is_strict_sorted: stats.get_as::<bool>(Stat::IsStrictSorted),
+        is_constant: stats.get_as::<bool>(Stat::IsConstant),
+        run_count: stats.get_as_cast::<u64>(Stat::RunCount),
+        true_count: stats.get_as_cast::<u64>(Stat::TrueCount),
+        null_count: stats.get_as_cast::<u64>(Stat::NullCount),
+        bit_width_freq,
+        trailing_zero_freq,
+        uncompressed_size_in_bytes: stats.get_as_cast::<u64>(Stat::UncompressedSizeInBytes),
+        approx_distinct,
+        has_nan: stats.get_as::<bool>(Stat::HasNaN),
+        version: STATS_LAYOUT_VERSION,
+        extra,
+    };
+
+    crate::flatbuffers::ArrayStats::create(fbb, stat_args)
+}
@@ -0,0 +1,72 @@
+use vortex_scalar::Scalar;
+
+/// Number of buckets of the equi-depth histograms computed for
+/// [Stat::Histogram](super::Stat::Histogram).
+pub const HISTOGRAM_BUCKETS: usize = 32;
+
+/// The boundaries of an equi-depth histogram over `sorted` values, such that each of the
+/// `buckets` buckets holds roughly the same number of values.
+///
+/// Returns `buckets + 1` boundaries, starting with the minimum and ending with the maximum value,
+/// or nothing if there are no values.
+pub fn equi_depth_boundaries<T: Copy>(sorted: &[T], buckets: usize) -> Vec<T> {
+    if sorted.is_empty() || buckets == 0 {
+        return Vec::new();
+    }
+    (0..=buckets)
+        .map(|i| sorted[i * (sorted.len() - 1) / buckets])
+        .collect()
+}
+
+/// Estimate the fraction of the values summarized by an equi-depth histogram with the given
+/// `boundaries` that are strictly less than `value`.
+///
+/// Values within a bucket are assumed to sit at its midpoint, so the estimate is within half a
+/// bucket of the true fraction. Returns `None` if `value` cannot be compared to the boundaries.
+pub fn estimate_fraction_lt(boundaries: &[Scalar], value: &Scalar) -> Option<f64> {
+    let buckets = boundaries.len().checked_sub(1).filter(|&b| b > 0)?;
+    if value.partial_cmp(&boundaries[0])?.is_le() {
+        return Some(0.0);
+    }
+    if value.partial_cmp(&boundaries[buckets])?.is_gt() {
+        return Some(1.0);
+    }
+
+    // The number of boundaries below the value, which is at least one given the checks above.
+    let below = boundaries.partition_point(|b| b < value);
+    Some(((below as f64 - 0.5) / buckets as f64).clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::stats::{equi_depth_boundaries, estimate_fraction_lt};
+
+    #[test]
+    fn boundaries() {
+        let values = (0..101).collect::<Vec<i32>>();
+        assert_eq!(equi_depth_boundaries(&values, 4), vec![0, 25, 50, 75, 100]);
+        assert_eq!(equi_depth_boundaries(&[7], 2), vec![7, 7, 7]);
+        assert!(equi_depth_boundaries::<i32>(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn fraction_lt() {
+        let boundaries = [0, 25, 50, 75, 100].map(Scalar::from);
+        assert_eq!(
+            estimate_fraction_lt(&boundaries, &Scalar::from(-1)),
+            Some(0.0)
+        );
+        assert_eq!(
+            estimate_fraction_lt(&boundaries, &Scalar::from(101)),
+            Some(1.0)
+        );
+        assert_eq!(
+            estimate_fraction_lt(&boundaries, &Scalar::from(60)),
+            Some(0.625)
+        );
+        assert_eq!(estimate_fraction_lt(&[], &Scalar::from(60)), None);
+        assert_eq!(estimate_fraction_lt(&boundaries, &Scalar::from("a")), None);
+    }
+}
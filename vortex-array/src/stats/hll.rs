@@ -0,0 +1,162 @@
+use vortex_buffer::Buffer;
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+/// Number of bits of the hash used to pick a register.
+const PRECISION: u32 = 10;
+/// Number of registers in a sketch.
+const NUM_REGISTERS: usize = 1 << PRECISION;
+
+/// A HyperLogLog sketch estimating the number of distinct values inserted into it.
+///
+/// Values are hashed with a fixed, seedless hash function so that sketches computed separately,
+/// e.g. over the chunks of a file, can be merged into a sketch of their union.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HyperLogLog {
+    pub fn new() -> Self {
+        Self {
+            registers: vec![0; NUM_REGISTERS],
+        }
+    }
+
+    /// Add a value, given as its byte representation, to the sketch.
+    pub fn insert(&mut self, value: &[u8]) {
+        let hash = hash(value);
+        #[allow(clippy::cast_possible_truncation)]
+        let idx = (hash >> (u64::BITS - PRECISION)) as usize;
+        // The sentinel bit bounds the rank for hashes whose remaining bits are all zero.
+        #[allow(clippy::cast_possible_truncation)]
+        let rank = (((hash << PRECISION) | (1 << (PRECISION - 1))).leading_zeros() + 1) as u8;
+        self.registers[idx] = self.registers[idx].max(rank);
+    }
+
+    /// Merge `other` into this sketch, such that it estimates the union of both inputs.
+    pub fn merge(&mut self, other: &Self) {
+        self.registers
+            .iter_mut()
+            .zip(other.registers.iter())
+            .for_each(|(own, other)| *own = (*own).max(*other));
+    }
+
+    /// The estimated number of distinct values inserted into the sketch.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn estimate(&self) -> u64 {
+        let m = NUM_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|&r| 2f64.powi(-i32::from(r)))
+            .sum();
+        let raw = alpha * m * m / sum;
+
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        let estimate = if raw <= 2.5 * m && zeros > 0 {
+            // Linear counting is more accurate for small cardinalities.
+            m * (m / zeros as f64).ln()
+        } else {
+            raw
+        };
+
+        estimate.round() as u64
+    }
+
+    /// The serialized registers of the sketch.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.registers
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> VortexResult<Self> {
+        if bytes.len() != NUM_REGISTERS {
+            vortex_bail!(
+                "HyperLogLog sketch must have {} registers, found {}",
+                NUM_REGISTERS,
+                bytes.len()
+            );
+        }
+        Ok(Self {
+            registers: bytes.to_vec(),
+        })
+    }
+}
+
+impl From<HyperLogLog> for Scalar {
+    fn from(value: HyperLogLog) -> Self {
+        Scalar::from(Buffer::from(value.registers))
+    }
+}
+
+impl TryFrom<&Scalar> for HyperLogLog {
+    type Error = VortexError;
+
+    fn try_from(value: &Scalar) -> VortexResult<Self> {
+        Self::try_from_bytes(Buffer::try_from(value)?.as_slice())
+    }
+}
+
+/// FNV-1a over the bytes followed by the murmur3 finalizer to spread the bits of short inputs.
+fn hash(bytes: &[u8]) -> u64 {
+    let mut hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    });
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^= hash >> 33;
+    hash
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stats::HyperLogLog;
+
+    fn sketch(values: impl Iterator<Item = u64>) -> HyperLogLog {
+        let mut hll = HyperLogLog::new();
+        values.for_each(|v| hll.insert(&v.to_le_bytes()));
+        hll
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(HyperLogLog::new().estimate(), 0);
+    }
+
+    #[test]
+    fn small_cardinality() {
+        assert_eq!(sketch((0..10).cycle().take(1000)).estimate(), 10);
+    }
+
+    #[test]
+    fn estimate_within_error() {
+        let estimate = sketch(0..100_000).estimate();
+        assert!(
+            (90_000..=110_000).contains(&estimate),
+            "estimate {estimate} too far from 100000"
+        );
+    }
+
+    #[test]
+    fn merge_is_union() {
+        let mut first = sketch(0..6_000);
+        first.merge(&sketch(4_000..10_000));
+        assert_eq!(first, sketch(0..10_000));
+    }
+
+    #[test]
+    fn roundtrip_bytes() {
+        let hll = sketch(0..100);
+        assert_eq!(HyperLogLog::try_from_bytes(hll.as_bytes()).unwrap(), hll);
+        assert!(HyperLogLog::try_from_bytes(&[0; 3]).is_err());
+    }
+}
@@ -9,6 +9,11 @@ use enum_iterator::{cardinality, Sequence};
 use itertools::Itertools;
 use log::debug;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
+pub(crate) use derive::*;
+pub use histogram::*;
+pub use hll::*;
+pub use policy::*;
+pub use sampled::*;
 pub use statsset::*;
 use vortex_dtype::Nullability::NonNullable;
 use vortex_dtype::{DType, NativePType, PType};
@@ -18,11 +23,22 @@ use vortex_scalar::Scalar;
 use crate::encoding::Encoding;
 use crate::ArrayData;
 
+mod derive;
 pub mod flatbuffers;
+mod histogram;
+mod hll;
+mod policy;
+mod sampled;
 mod statsset;
 
 /// Statistics that are used for pruning files (i.e., we want to ensure they are computed when compressing/writing).
-pub const PRUNING_STATS: &[Stat] = &[Stat::Min, Stat::Max, Stat::TrueCount, Stat::NullCount];
+pub const PRUNING_STATS: &[Stat] = &[
+    Stat::Min,
+    Stat::Max,
+    Stat::TrueCount,
+    Stat::NullCount,
+    Stat::HasNaN,
+];
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
@@ -38,9 +54,9 @@ pub enum Stat {
     IsSorted,
     /// Whether the non-null values in the array are strictly sorted (i.e., sorted with no duplicates)
     IsStrictSorted,
-    /// The maximum value in the array (ignoring nulls, unless all values are null)
+    /// The maximum value in the array (ignoring nulls and NaNs, unless all values are null)
     Max,
-    /// The minimum value in the array (ignoring nulls, unless all values are null)
+    /// The minimum value in the array (ignoring nulls and NaNs, unless all values are null)
     Min,
     /// The number of runs in the array (ignoring nulls)
     RunCount,
@@ -50,6 +66,12 @@ pub enum Stat {
     NullCount,
     /// The uncompressed size of the array in bytes
     UncompressedSizeInBytes,
+    /// A [HyperLogLog] sketch of the distinct non-null values in the array
+    ApproxDistinct,
+    /// The boundaries of an equi-depth histogram of the non-null values in the array
+    Histogram,
+    /// Whether any of the values in the array is NaN (only computed for floating point arrays)
+    HasNaN,
 }
 
 impl Stat {
@@ -66,6 +88,17 @@ impl Stat {
                 | Stat::TrueCount
                 | Stat::NullCount
                 | Stat::UncompressedSizeInBytes
+                | Stat::ApproxDistinct
+                | Stat::HasNaN
+        )
+    }
+
+    /// Whether the statistic is expensive to compute compared to a scan over the values, e.g.
+    /// because it builds a frequency table or sketch, or sorts the values
+    pub fn is_expensive(&self) -> bool {
+        matches!(
+            self,
+            Stat::BitWidthFreq | Stat::TrailingZeroFreq | Stat::ApproxDistinct | Stat::Histogram
         )
     }
 
@@ -93,6 +126,9 @@ impl Stat {
             Stat::TrueCount => DType::Primitive(PType::U64, NonNullable),
             Stat::NullCount => DType::Primitive(PType::U64, NonNullable),
             Stat::UncompressedSizeInBytes => DType::Primitive(PType::U64, NonNullable),
+            Stat::ApproxDistinct => DType::Binary(NonNullable),
+            Stat::Histogram => DType::List(Arc::new(data_type.as_nonnullable()), NonNullable),
+            Stat::HasNaN => DType::Bool(NonNullable),
         }
     }
 
@@ -109,6 +145,9 @@ impl Stat {
             Self::TrueCount => "true_count",
             Self::NullCount => "null_count",
             Self::UncompressedSizeInBytes => "uncompressed_size_in_bytes",
+            Self::ApproxDistinct => "approx_distinct",
+            Self::Histogram => "histogram",
+            Self::HasNaN => "has_nan",
         }
     }
 }
@@ -330,6 +369,34 @@ impl dyn Statistics + '_ {
     pub fn compute_uncompressed_size_in_bytes(&self) -> Option<usize> {
         self.compute_as(Stat::UncompressedSizeInBytes)
     }
+
+    pub fn compute_has_nan(&self) -> Option<bool> {
+        self.compute_as(Stat::HasNaN)
+    }
+
+    /// Get or calculate the boundaries of an equi-depth histogram of the non-null values in the
+    /// array, returning as typed values.
+    ///
+    /// This function will panic if the conversion fails.
+    pub fn compute_histogram<U: for<'a> TryFrom<&'a Scalar, Error = VortexError>>(
+        &self,
+    ) -> Option<Vec<U>> {
+        self.compute_as(Stat::Histogram)
+    }
+
+    /// Estimate the fraction of non-null values in the array that are less than `value`, using
+    /// the histogram of the array.
+    pub fn estimate_fraction_lt(&self, value: &Scalar) -> Option<f64> {
+        let histogram = self.compute(Stat::Histogram)?;
+        let boundaries = histogram.as_list().elements().collect::<Vec<_>>();
+        estimate_fraction_lt(&boundaries, value)
+    }
+
+    /// Get or calculate the estimated number of distinct non-null values in the array.
+    pub fn compute_approx_distinct(&self) -> Option<u64> {
+        self.compute_as::<HyperLogLog>(Stat::ApproxDistinct)
+            .map(|hll| hll.estimate())
+    }
 }
 
 pub fn trailing_zeros(array: &ArrayData) -> u8 {
@@ -372,6 +439,7 @@ mod test {
         assert!(Stat::Max.is_commutative());
         assert!(Stat::TrueCount.is_commutative());
         assert!(Stat::NullCount.is_commutative());
+        assert!(Stat::ApproxDistinct.is_commutative());
 
         assert!(!Stat::IsStrictSorted.is_commutative());
         assert!(!Stat::IsSorted.is_commutative());
@@ -0,0 +1,139 @@
+use std::time::{Duration, Instant};
+
+use crate::stats::{ArrayStatistics, Stat, StatsSet};
+use crate::ArrayData;
+
+/// How eagerly statistics are computed ahead of time, e.g. before compressing or writing an array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatsMode {
+    /// Compute all the requested statistics up front.
+    #[default]
+    Eager,
+    /// Compute only the cheap statistics up front, leaving the [expensive](Stat::is_expensive)
+    /// ones to be computed when they're first requested.
+    Lazy,
+    /// Compute nothing up front, only using the statistics that are already present.
+    None,
+}
+
+/// Controls which statistics are computed ahead of time, and how much time may be spent on them.
+///
+/// The policy applies to the statistics computed implicitly, such as the pruning statistics
+/// computed before compression or the statistics tables written to a file. Statistics requested
+/// explicitly through [Statistics::compute](crate::stats::Statistics::compute) are still computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StatsPolicy {
+    mode: StatsMode,
+    budget: Option<Duration>,
+}
+
+impl StatsPolicy {
+    pub const fn eager() -> Self {
+        Self {
+            mode: StatsMode::Eager,
+            budget: None,
+        }
+    }
+
+    pub const fn lazy() -> Self {
+        Self {
+            mode: StatsMode::Lazy,
+            budget: None,
+        }
+    }
+
+    pub const fn none() -> Self {
+        Self {
+            mode: StatsMode::None,
+            budget: None,
+        }
+    }
+
+    /// Stop computing statistics ahead of time once `budget` has been spent in a single call to
+    /// [compute_all](Self::compute_all). A statistic that is being computed when the budget runs
+    /// out is still finished.
+    pub fn with_budget(mut self, budget: Duration) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    pub fn mode(&self) -> StatsMode {
+        self.mode
+    }
+
+    pub fn budget(&self) -> Option<Duration> {
+        self.budget
+    }
+
+    /// Whether `stat` may be computed ahead of time under this policy, ignoring the budget.
+    pub fn allows(&self, stat: Stat) -> bool {
+        match self.mode {
+            StatsMode::Eager => true,
+            StatsMode::Lazy => !stat.is_expensive(),
+            StatsMode::None => false,
+        }
+    }
+
+    /// Compute the requested statistics of `array` as far as this policy allows, returning all
+    /// of them that are available afterwards.
+    pub fn compute_all(&self, array: &ArrayData, stats: &[Stat]) -> StatsSet {
+        let start = Instant::now();
+        let mut stats_set = StatsSet::default();
+        for stat in stats {
+            let within_budget = self.budget.map_or(true, |budget| start.elapsed() < budget);
+            let value = if within_budget && self.allows(*stat) {
+                array.statistics().compute(*stat)
+            } else {
+                array.statistics().get(*stat)
+            };
+            if let Some(value) = value {
+                stats_set.set(*stat, value);
+            }
+        }
+        stats_set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::array::PrimitiveArray;
+    use crate::stats::{ArrayStatistics, Stat, StatsPolicy};
+    use crate::IntoArrayData;
+
+    #[test]
+    fn eager_computes_everything() {
+        let array = PrimitiveArray::from(vec![1u32, 2, 3]).into_array();
+        let stats = StatsPolicy::eager().compute_all(&array, &[Stat::Min, Stat::BitWidthFreq]);
+        assert!(stats.get(Stat::Min).is_some());
+        assert!(stats.get(Stat::BitWidthFreq).is_some());
+    }
+
+    #[test]
+    fn lazy_skips_expensive_stats() {
+        let array = PrimitiveArray::from(vec![1u32, 2, 3]).into_array();
+        let stats = StatsPolicy::lazy().compute_all(&array, &[Stat::Min, Stat::BitWidthFreq]);
+        assert!(stats.get(Stat::Min).is_some());
+        assert!(stats.get(Stat::BitWidthFreq).is_none());
+        assert!(array.statistics().get(Stat::BitWidthFreq).is_none());
+    }
+
+    #[test]
+    fn none_only_reads_present_stats() {
+        let array = PrimitiveArray::from(vec![1u32, 2, 3]).into_array();
+        array.statistics().compute(Stat::NullCount);
+        let stats = StatsPolicy::none().compute_all(&array, &[Stat::Min, Stat::NullCount]);
+        assert!(stats.get(Stat::Min).is_none());
+        assert!(stats.get(Stat::NullCount).is_some());
+    }
+
+    #[test]
+    fn exhausted_budget() {
+        let array = PrimitiveArray::from(vec![1u32, 2, 3]).into_array();
+        let stats = StatsPolicy::eager()
+            .with_budget(Duration::ZERO)
+            .compute_all(&array, &[Stat::Min]);
+        assert!(stats.get(Stat::Min).is_none());
+    }
+}
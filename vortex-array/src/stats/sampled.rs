@@ -0,0 +1,331 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::index::sample;
+use rand::SeedableRng;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::compute::slice;
+use crate::stats::{ArrayStatistics, Stat, StatsSet};
+use crate::ArrayData;
+
+/// Number of consecutive values in each block sampled by [sample_statistics].
+pub const SAMPLE_BLOCK_SIZE: usize = 1024;
+
+/// The statistics that [sample_statistics] can estimate.
+pub const SAMPLED_STATS: &[Stat] = &[
+    Stat::Min,
+    Stat::Max,
+    Stat::RunCount,
+    Stat::BitWidthFreq,
+    Stat::TrailingZeroFreq,
+];
+
+/// Quantile of the standard normal distribution for two-sided 95% confidence intervals.
+const Z_95: f64 = 1.96;
+
+/// How many blocks of an array to sample when estimating its statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SamplingOptions {
+    block_count: usize,
+    seed: u64,
+}
+
+impl Default for SamplingOptions {
+    fn default() -> Self {
+        Self {
+            block_count: 16,
+            seed: 0,
+        }
+    }
+}
+
+impl SamplingOptions {
+    pub fn new(block_count: usize, seed: u64) -> Self {
+        Self { block_count, seed }
+    }
+
+    pub fn block_count(&self) -> usize {
+        self.block_count
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+/// An estimate of a statistic, along with bounds on its true value where they're known.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatEstimate {
+    value: Scalar,
+    lower: Option<Scalar>,
+    upper: Option<Scalar>,
+}
+
+impl StatEstimate {
+    fn exact(value: Scalar) -> Self {
+        Self {
+            lower: Some(value.clone()),
+            upper: Some(value.clone()),
+            value,
+        }
+    }
+
+    pub fn value(&self) -> &Scalar {
+        &self.value
+    }
+
+    /// A lower bound on the true value. For counts this is a 95% confidence bound.
+    pub fn lower(&self) -> Option<&Scalar> {
+        self.lower.as_ref()
+    }
+
+    /// An upper bound on the true value. For counts this is a 95% confidence bound.
+    pub fn upper(&self) -> Option<&Scalar> {
+        self.upper.as_ref()
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.lower.as_ref() == Some(&self.value) && self.upper.as_ref() == Some(&self.value)
+    }
+}
+
+/// Statistics of an array estimated from a sample of its values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampledStats {
+    estimates: Vec<(Stat, StatEstimate)>,
+    sampled_len: usize,
+}
+
+impl SampledStats {
+    pub fn get(&self, stat: Stat) -> Option<&StatEstimate> {
+        self.estimates
+            .iter()
+            .find(|(s, _)| *s == stat)
+            .map(|(_, estimate)| estimate)
+    }
+
+    /// The number of values the estimates were computed from.
+    pub fn sampled_len(&self) -> usize {
+        self.sampled_len
+    }
+}
+
+/// Estimate the given statistics of `array` from a random sample of its blocks of
+/// [SAMPLE_BLOCK_SIZE] values, rather than computing them over all of its values.
+///
+/// The estimates are not stored as statistics of the array, since they aren't exact. Statistics
+/// other than [SAMPLED_STATS] are ignored, and arrays with no more blocks than are sampled have
+/// their statistics computed exactly.
+pub fn sample_statistics(
+    array: &ArrayData,
+    stats: &[Stat],
+    options: &SamplingOptions,
+) -> VortexResult<SampledStats> {
+    let stats = stats
+        .iter()
+        .copied()
+        .filter(|stat| SAMPLED_STATS.contains(stat))
+        .collect::<Vec<_>>();
+
+    let num_blocks = array.len().div_ceil(SAMPLE_BLOCK_SIZE);
+    if num_blocks <= options.block_count {
+        let estimates = stats
+            .iter()
+            .filter_map(|stat| {
+                array
+                    .statistics()
+                    .compute(*stat)
+                    .map(|value| (*stat, StatEstimate::exact(value)))
+            })
+            .collect();
+        return Ok(SampledStats {
+            estimates,
+            sampled_len: array.len(),
+        });
+    }
+
+    let mut rng = StdRng::seed_from_u64(options.seed);
+    let blocks = sample(&mut rng, num_blocks, options.block_count)
+        .into_iter()
+        .sorted_unstable()
+        .map(|block| {
+            let start = block * SAMPLE_BLOCK_SIZE;
+            let stop = (start + SAMPLE_BLOCK_SIZE).min(array.len());
+            let block = slice(array, start, stop)?;
+            Ok((stop - start, block.statistics().compute_all(&stats)?))
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+    let sampled_len = blocks.iter().map(|(len, _)| len).sum::<usize>();
+
+    let estimates = stats
+        .iter()
+        .filter_map(|stat| {
+            let estimate = match stat {
+                Stat::Min => estimate_bound(&blocks, Stat::Min, Ordering::Less),
+                Stat::Max => estimate_bound(&blocks, Stat::Max, Ordering::Greater),
+                Stat::RunCount => estimate_run_count(&blocks, array.len()),
+                Stat::BitWidthFreq | Stat::TrailingZeroFreq => {
+                    estimate_freq(&blocks, *stat, array.len(), sampled_len)
+                }
+                _ => None,
+            };
+            estimate.map(|estimate| (*stat, estimate))
+        })
+        .collect();
+
+    Ok(SampledStats {
+        estimates,
+        sampled_len,
+    })
+}
+
+/// The most extreme of the non-null bounds of the blocks in the given direction. The true bound is
+/// at least as extreme.
+fn estimate_bound(
+    blocks: &[(usize, StatsSet)],
+    stat: Stat,
+    direction: Ordering,
+) -> Option<StatEstimate> {
+    let bound = blocks
+        .iter()
+        .filter_map(|(_, stats)| stats.get(stat))
+        .filter(|bound| bound.is_valid())
+        .reduce(|acc, bound| {
+            if bound.partial_cmp(acc) == Some(direction) {
+                bound
+            } else {
+                acc
+            }
+        })?
+        .clone();
+    let (lower, upper) = match direction {
+        Ordering::Less => (None, Some(bound.clone())),
+        _ => (Some(bound.clone()), None),
+    };
+    Some(StatEstimate {
+        value: bound,
+        lower,
+        upper,
+    })
+}
+
+/// Scale the rate of runs per value in the blocks up to the whole array, with a confidence
+/// interval from the variance of the rate between the blocks.
+#[allow(clippy::cast_possible_truncation)]
+fn estimate_run_count(blocks: &[(usize, StatsSet)], len: usize) -> Option<StatEstimate> {
+    let runs = blocks
+        .iter()
+        .map(|(block_len, stats)| {
+            stats
+                .get_as::<usize>(Stat::RunCount)
+                .map(|runs| (runs as u64, runs as f64 / *block_len as f64))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if runs.is_empty() {
+        return None;
+    }
+
+    let n = runs.len() as f64;
+    let mean = runs.iter().map(|(_, rate)| rate).sum::<f64>() / n;
+    let variance = runs
+        .iter()
+        .map(|(_, rate)| (rate - mean).powi(2))
+        .sum::<f64>()
+        / (n - 1.0).max(1.0);
+    let margin = Z_95 * (variance / n).sqrt() * len as f64;
+    let estimate = mean * len as f64;
+
+    // The runs within each block are distinct runs of the array, except where a run spans blocks.
+    let sampled_runs = runs.iter().map(|(runs, _)| runs).sum::<u64>();
+    let min_runs = sampled_runs.saturating_sub(runs.len() as u64 - 1);
+    let lower = ((estimate - margin).max(0.0).round() as u64).max(min_runs);
+    let upper = ((estimate + margin).round() as u64).min(len as u64);
+
+    Some(StatEstimate {
+        value: (estimate.round() as u64).clamp(lower, upper).into(),
+        lower: Some(lower.into()),
+        upper: Some(upper.into()),
+    })
+}
+
+/// Sum the frequencies of the blocks and scale them up to the whole array.
+#[allow(clippy::cast_possible_truncation)]
+fn estimate_freq(
+    blocks: &[(usize, StatsSet)],
+    stat: Stat,
+    len: usize,
+    sampled_len: usize,
+) -> Option<StatEstimate> {
+    let freqs = blocks
+        .iter()
+        .map(|(_, stats)| stats.get_as::<Vec<u64>>(stat))
+        .collect::<Option<Vec<_>>>()?;
+    let scale = len as f64 / sampled_len as f64;
+    let freq = freqs
+        .into_iter()
+        .reduce(|acc, freq| acc.iter().zip_eq(freq.iter()).map(|(a, b)| a + b).collect())?
+        .into_iter()
+        .map(|count| (count as f64 * scale).round() as u64)
+        .collect::<Vec<_>>();
+    Some(StatEstimate {
+        value: freq.into(),
+        lower: None,
+        upper: None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::PrimitiveArray;
+    use crate::stats::{sample_statistics, SamplingOptions, Stat, SAMPLE_BLOCK_SIZE};
+    use crate::IntoArrayData;
+
+    #[test]
+    fn small_arrays_are_exact() {
+        let array = PrimitiveArray::from((0..100u32).collect::<Vec<_>>()).into_array();
+        let sampled =
+            sample_statistics(&array, &[Stat::Min, Stat::Max], &SamplingOptions::default())
+                .unwrap();
+        assert_eq!(sampled.sampled_len(), 100);
+        let min = sampled.get(Stat::Min).unwrap();
+        assert!(min.is_exact());
+        assert_eq!(min.value(), &0u32.into());
+    }
+
+    #[test]
+    fn estimates_within_bounds() {
+        // Runs of 8 equal values, with a minimum of 0 and a maximum of 127.
+        let array = PrimitiveArray::from((0..262_144u32).map(|i| i / 8 % 128).collect::<Vec<_>>())
+            .into_array();
+        let len = array.len();
+        assert_eq!(len, 256 * SAMPLE_BLOCK_SIZE);
+        let sampled = sample_statistics(
+            &array,
+            &[Stat::Min, Stat::Max, Stat::RunCount, Stat::BitWidthFreq],
+            &SamplingOptions::new(8, 42),
+        )
+        .unwrap();
+        assert_eq!(sampled.sampled_len(), 8 * SAMPLE_BLOCK_SIZE);
+
+        let min = sampled.get(Stat::Min).unwrap();
+        assert_eq!(min.value(), &0u32.into());
+        assert_eq!(min.upper(), Some(&0u32.into()));
+        assert_eq!(sampled.get(Stat::Max).unwrap().value(), &127u32.into());
+
+        let run_count = sampled.get(Stat::RunCount).unwrap();
+        let lower = u64::try_from(run_count.lower().unwrap()).unwrap();
+        let upper = u64::try_from(run_count.upper().unwrap()).unwrap();
+        let true_run_count = (len / 8) as u64;
+        assert!(
+            lower <= true_run_count && true_run_count <= upper,
+            "{true_run_count} not in [{lower}, {upper}]"
+        );
+
+        let bit_width_freq =
+            Vec::<u64>::try_from(sampled.get(Stat::BitWidthFreq).unwrap().value()).unwrap();
+        assert_eq!(bit_width_freq.iter().sum::<u64>(), len as u64);
+    }
+}
@@ -4,7 +4,7 @@ use vortex_dtype::DType;
 use vortex_error::{vortex_panic, VortexError};
 use vortex_scalar::Scalar;
 
-use crate::stats::Stat;
+use crate::stats::{HyperLogLog, Stat};
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct StatsSet {
@@ -44,6 +44,9 @@ impl StatsSet {
             }
             DType::Primitive(ptype, _) => {
                 ptype.byte_width();
+                if ptype.is_float() {
+                    stats.set(Stat::HasNaN, false);
+                }
                 stats.set(Stat::BitWidthFreq, vec![0u64; ptype.byte_width() * 8 + 1]);
                 stats.set(
                     Stat::TrailingZeroFreq,
@@ -78,8 +81,18 @@ impl StatsSet {
             stats.set(Stat::TrueCount, true_count);
         }
 
-        stats.set(Stat::Min, scalar.clone());
-        stats.set(Stat::Max, scalar.clone());
+        // NaNs are excluded from the bounds, so an array of only NaNs has none
+        let is_nan = scalar
+            .as_primitive_opt()
+            .filter(|p| p.ptype().is_float())
+            .map(|p| p.as_::<f64>().ok().flatten().is_some_and(f64::is_nan));
+        if let Some(is_nan) = is_nan {
+            stats.set(Stat::HasNaN, length > 0 && is_nan);
+        }
+        if !is_nan.unwrap_or(false) {
+            stats.set(Stat::Min, scalar.clone());
+            stats.set(Stat::Max, scalar.clone());
+        }
 
         stats
     }
@@ -215,6 +228,10 @@ impl StatsSet {
                 Stat::TrueCount => self.merge_true_count(other),
                 Stat::NullCount => self.merge_null_count(other),
                 Stat::UncompressedSizeInBytes => self.merge_uncompressed_size_in_bytes(other),
+                Stat::ApproxDistinct => self.merge_approx_distinct(other),
+                // Histograms of the parts don't tell us the boundaries of the whole
+                Stat::Histogram => self.clear(Stat::Histogram),
+                Stat::HasNaN => self.merge_has_nan(other),
             }
         }
 
@@ -239,6 +256,8 @@ impl StatsSet {
                 Stat::TrueCount => self.merge_true_count(other),
                 Stat::NullCount => self.merge_null_count(other),
                 Stat::UncompressedSizeInBytes => self.merge_uncompressed_size_in_bytes(other),
+                Stat::ApproxDistinct => self.merge_approx_distinct(other),
+                Stat::HasNaN => self.merge_has_nan(other),
                 _ => vortex_panic!("Unrecognized commutative stat {}", s),
             }
         }
@@ -249,9 +268,8 @@ impl StatsSet {
     fn merge_min(&mut self, other: &Self) {
         match (self.get(Stat::Min), other.get(Stat::Min)) {
             (Some(m1), Some(m2)) => {
-                if m2 < m1 {
-                    self.set(Stat::Min, m2.clone());
-                }
+                let min = merge_bound(m1, m2, &|m1: &Scalar, m2: &Scalar| m2 < m1);
+                self.set(Stat::Min, min);
             }
             _ => self.clear(Stat::Min),
         }
@@ -260,9 +278,8 @@ impl StatsSet {
     fn merge_max(&mut self, other: &Self) {
         match (self.get(Stat::Max), other.get(Stat::Max)) {
             (Some(m1), Some(m2)) => {
-                if m2 > m1 {
-                    self.set(Stat::Max, m2.clone());
-                }
+                let max = merge_bound(m1, m2, &|m1: &Scalar, m2: &Scalar| m2 > m1);
+                self.set(Stat::Max, max);
             }
             _ => self.clear(Stat::Max),
         }
@@ -361,6 +378,29 @@ impl StatsSet {
         }
     }
 
+    fn merge_approx_distinct(&mut self, other: &Self) {
+        match (
+            self.get_as::<HyperLogLog>(Stat::ApproxDistinct),
+            other.get_as::<HyperLogLog>(Stat::ApproxDistinct),
+        ) {
+            (Some(mut h1), Some(h2)) => {
+                h1.merge(&h2);
+                self.set(Stat::ApproxDistinct, h1);
+            }
+            _ => self.clear(Stat::ApproxDistinct),
+        }
+    }
+
+    fn merge_has_nan(&mut self, other: &Self) {
+        match (
+            self.get_as::<bool>(Stat::HasNaN),
+            other.get_as::<bool>(Stat::HasNaN),
+        ) {
+            (Some(n1), Some(n2)) => self.set(Stat::HasNaN, n1 || n2),
+            _ => self.clear(Stat::HasNaN),
+        }
+    }
+
     /// Merged run count is an upper bound where we assume run is interrupted at the boundary
     fn merge_run_count(&mut self, other: &Self) {
         match (
@@ -375,13 +415,44 @@ impl StatsSet {
     }
 }
 
+/// Merge two minimums or maximums, keeping `other` where `replace` holds.
+///
+/// The bounds of struct arrays hold the bound of every field, so they are merged field by field.
+fn merge_bound<F: Fn(&Scalar, &Scalar) -> bool>(
+    own: &Scalar,
+    other: &Scalar,
+    replace: &F,
+) -> Scalar {
+    let own_fields = own.as_struct_opt().and_then(|s| s.fields());
+    let other_fields = other.as_struct_opt().and_then(|s| s.fields());
+    match (own_fields, other_fields) {
+        (Some(own_fields), Some(other_fields)) if own_fields.len() == other_fields.len() => {
+            Scalar::struct_(
+                own.dtype().clone(),
+                own_fields
+                    .iter()
+                    .zip(other_fields.iter())
+                    .map(|(own, other)| merge_bound(own, other, replace))
+                    .collect(),
+            )
+        }
+        _ => {
+            if replace(own, other) {
+                other.clone()
+            } else {
+                own.clone()
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use enum_iterator::all;
     use itertools::Itertools;
 
     use crate::array::PrimitiveArray;
-    use crate::stats::{ArrayStatistics as _, Stat, StatsSet};
+    use crate::stats::{ArrayStatistics as _, HyperLogLog, Stat, StatsSet};
     use crate::IntoArrayData as _;
 
     #[test]
@@ -480,6 +551,29 @@ mod test {
         assert_eq!(first.get(Stat::BitWidthFreq).cloned(), Some(vec_out.into()));
     }
 
+    #[test]
+    fn merge_approx_distinct() {
+        let mut h1 = HyperLogLog::new();
+        h1.insert(b"a");
+        h1.insert(b"b");
+        let mut h2 = HyperLogLog::new();
+        h2.insert(b"b");
+        h2.insert(b"c");
+
+        let merged = StatsSet::of(Stat::ApproxDistinct, h1)
+            .merge_unordered(&StatsSet::of(Stat::ApproxDistinct, h2));
+        assert_eq!(
+            merged
+                .get_as::<HyperLogLog>(Stat::ApproxDistinct)
+                .map(|hll| hll.estimate()),
+            Some(3)
+        );
+
+        let missing = StatsSet::of(Stat::ApproxDistinct, HyperLogLog::new())
+            .merge_ordered(&StatsSet::default());
+        assert_eq!(missing.get(Stat::ApproxDistinct), None);
+    }
+
     #[test]
     fn merge_into_sortedness() {
         let first = StatsSet::of(Stat::IsStrictSorted, true).merge_ordered(&StatsSet::default());
@@ -549,7 +643,7 @@ mod test {
         ])
         .into_array();
         let all_stats = all::<Stat>()
-            .filter(|s| !matches!(s, Stat::TrueCount))
+            .filter(|s| !matches!(s, Stat::TrueCount | Stat::HasNaN))
             .collect_vec();
         array.statistics().compute_all(&all_stats).unwrap();
 
@@ -1,9 +1,12 @@
 use std::future::Future;
 
-use futures_util::TryStreamExt;
+use futures_util::{StreamExt, TryStreamExt};
+use vortex_dtype::DType;
 use vortex_error::VortexResult;
 
 use crate::array::ChunkedArray;
+use crate::compute::{filter, FilterMask};
+use crate::stream::rechunk::RechunkStream;
 use crate::stream::take_rows::TakeRows;
 use crate::stream::{ArrayStream, ArrayStreamAdapter};
 use crate::{ArrayData, IntoArrayData};
@@ -37,6 +40,88 @@ pub trait ArrayStreamExt: ArrayStream {
             TakeRows::try_new(self, indices)?,
         ))
     }
+
+    /// Transform each chunk with `f`, which produces chunks of `dtype`.
+    fn map_chunks<F>(self, dtype: DType, mut f: F) -> impl ArrayStream
+    where
+        Self: Sized,
+        F: FnMut(ArrayData) -> VortexResult<ArrayData>,
+    {
+        ArrayStreamAdapter::new(dtype, self.map(move |chunk| chunk.and_then(&mut f)))
+    }
+
+    /// Keep the rows of each chunk for which `predicate` evaluates to true.
+    ///
+    /// The predicate returns a boolean array of the length of the chunk, e.g. by evaluating an
+    /// expression over it.
+    fn filter_rows<F>(self, mut predicate: F) -> impl ArrayStream
+    where
+        Self: Sized,
+        F: FnMut(&ArrayData) -> VortexResult<ArrayData>,
+    {
+        let dtype = self.dtype().clone();
+        ArrayStreamAdapter::new(
+            dtype,
+            self.map(move |chunk| {
+                let chunk = chunk?;
+                let mask = FilterMask::try_from(predicate(&chunk)?)?;
+                filter(&chunk, mask)
+            }),
+        )
+    }
+
+    /// Regroup the chunks into chunks of `chunk_len` rows, of which only the last may be shorter.
+    fn rechunk(self, chunk_len: usize) -> VortexResult<impl ArrayStream>
+    where
+        Self: Sized,
+    {
+        RechunkStream::try_new(self, chunk_len)
+    }
 }
 
 impl<S: ArrayStream> ArrayStreamExt for S {}
+
+#[cfg(test)]
+mod test {
+    use futures_util::FutureExt as _;
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::{ChunkedArray, PrimitiveArray};
+    use crate::iter::{ArrayIteratorAdapter, ArrayIteratorExt};
+    use crate::stream::ArrayStreamExt;
+    use crate::{IntoArrayData, IntoArrayVariant};
+
+    #[test]
+    fn rechunk_stream() {
+        let stream = ArrayIteratorAdapter::new(
+            DType::Primitive(PType::I32, Nullability::NonNullable),
+            vec![
+                Ok(PrimitiveArray::from(vec![0i32, 1, 2]).into_array()),
+                Ok(PrimitiveArray::from(vec![3i32, 4]).into_array()),
+            ]
+            .into_iter(),
+        )
+        .into_stream();
+
+        let rechunked = stream
+            .rechunk(4)
+            .unwrap()
+            .into_array_data()
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+        let chunks = ChunkedArray::try_from(rechunked).unwrap();
+        assert_eq!(
+            chunks.chunks().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![4, 1]
+        );
+        assert_eq!(
+            chunks
+                .into_array()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            &[0, 1, 2, 3, 4]
+        );
+    }
+}
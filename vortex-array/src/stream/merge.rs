@@ -0,0 +1,265 @@
+use std::cmp::Ordering;
+
+use futures_util::stream::{select_all, try_unfold};
+use futures_util::StreamExt;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::compute::{scalar_at, search_sorted, slice, SearchSortedSide};
+use crate::stream::{ArrayStream, ArrayStreamAdapter};
+use crate::ArrayData;
+
+/// Combine streams of the same dtype into one that yields each of their chunks as soon as it is
+/// ready, in no particular order.
+pub fn interleave_streams<S>(streams: Vec<S>) -> VortexResult<impl ArrayStream>
+where
+    S: ArrayStream + Unpin,
+{
+    let dtype = common_dtype(&streams)?;
+    Ok(ArrayStreamAdapter::new(dtype, select_all(streams)))
+}
+
+/// Merge streams of the same dtype, each sorted ascending by the non-null array that `key`
+/// computes from its chunks, into one stream sorted by that key.
+///
+/// Each output chunk is the longest run of rows of a single input that sorts before the current
+/// chunks of all other inputs, found with [`search_sorted`]. Rows with equal keys are emitted in
+/// the order of their inputs. Inputs that overlap heavily therefore produce many small chunks,
+/// which can be regrouped with [`ArrayStreamExt::rechunk`](crate::stream::ArrayStreamExt::rechunk).
+pub fn merge_sorted_streams<S, F>(streams: Vec<S>, key: F) -> VortexResult<impl ArrayStream>
+where
+    S: ArrayStream + Unpin,
+    F: Fn(&ArrayData) -> VortexResult<ArrayData>,
+{
+    let dtype = common_dtype(&streams)?;
+    let inputs = streams
+        .into_iter()
+        .map(|stream| MergeInput {
+            stream,
+            head: None,
+            exhausted: false,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ArrayStreamAdapter::new(
+        dtype,
+        try_unfold((inputs, key), |(mut inputs, key)| async move {
+            for input in inputs.iter_mut() {
+                input.fill(&key).await?;
+            }
+            Ok::<_, VortexError>(next_run(&mut inputs)?.map(|run| (run, (inputs, key))))
+        }),
+    ))
+}
+
+fn common_dtype<S: ArrayStream>(streams: &[S]) -> VortexResult<DType> {
+    let Some(first) = streams.first() else {
+        vortex_bail!("Cannot combine an empty list of streams")
+    };
+    if let Some(other) = streams.iter().find(|s| s.dtype() != first.dtype()) {
+        vortex_bail!(MismatchedTypes: first.dtype(), other.dtype());
+    }
+    Ok(first.dtype().clone())
+}
+
+struct MergeInput<S> {
+    stream: S,
+    head: Option<MergeHead>,
+    exhausted: bool,
+}
+
+impl<S: ArrayStream + Unpin> MergeInput<S> {
+    /// Pull chunks until we hold a non-empty one or the stream is exhausted.
+    async fn fill<F>(&mut self, key: &F) -> VortexResult<()>
+    where
+        F: Fn(&ArrayData) -> VortexResult<ArrayData>,
+    {
+        while self.head.is_none() && !self.exhausted {
+            match self.stream.next().await {
+                Some(chunk) => {
+                    let chunk = chunk?;
+                    if !chunk.is_empty() {
+                        let key = key(&chunk)?;
+                        self.head = Some(MergeHead::try_new(chunk, key)?);
+                    }
+                }
+                None => self.exhausted = true,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The unconsumed rows of an input's current chunk along with their keys.
+struct MergeHead {
+    chunk: ArrayData,
+    key: ArrayData,
+    first: Scalar,
+}
+
+impl MergeHead {
+    fn try_new(chunk: ArrayData, key: ArrayData) -> VortexResult<Self> {
+        if key.len() != chunk.len() {
+            vortex_bail!(
+                "Merge key has {} rows but the chunk has {}",
+                key.len(),
+                chunk.len()
+            );
+        }
+        let first = scalar_at(&key, 0)?;
+        Ok(Self { chunk, key, first })
+    }
+
+    /// Split off the first `len` rows, returning them and the head of the remaining rows, if any.
+    fn split(self, len: usize) -> VortexResult<(ArrayData, Option<Self>)> {
+        let total = self.chunk.len();
+        if len == total {
+            return Ok((self.chunk, None));
+        }
+        let run = slice(&self.chunk, 0, len)?;
+        let rest = Self::try_new(
+            slice(&self.chunk, len, total)?,
+            slice(&self.key, len, total)?,
+        )?;
+        Ok((run, Some(rest)))
+    }
+}
+
+/// Take the next sorted run from the input whose current chunk starts with the smallest key.
+fn next_run<S>(inputs: &mut [MergeInput<S>]) -> VortexResult<Option<ArrayData>> {
+    let heads = inputs
+        .iter()
+        .map(|input| input.head.as_ref())
+        .collect::<Vec<_>>();
+    let Some((chosen, head)) = heads
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, head)| head.map(|head| (idx, head)))
+        .min_by(|(_, a), (_, b)| a.first.partial_cmp(&b.first).unwrap_or(Ordering::Equal))
+    else {
+        return Ok(None);
+    };
+
+    // Rows equal to the first key of an earlier input must wait for that input's rows.
+    let mut run_len = head.chunk.len();
+    for (idx, other) in heads.iter().enumerate() {
+        let Some(other) = other.filter(|_| idx != chosen) else {
+            continue;
+        };
+        let side = if idx < chosen {
+            SearchSortedSide::Left
+        } else {
+            SearchSortedSide::Right
+        };
+        run_len = run_len.min(search_sorted(&head.key, other.first.clone(), side)?.to_index());
+    }
+
+    inputs[chosen]
+        .head
+        .take()
+        .map(|head| {
+            let (run, rest) = head.split(run_len)?;
+            inputs[chosen].head = rest;
+            Ok(run)
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod test {
+    use futures_util::{FutureExt as _, TryStreamExt};
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::PrimitiveArray;
+    use crate::iter::{ArrayIteratorAdapter, ArrayIteratorExt};
+    use crate::stream::{interleave_streams, merge_sorted_streams, ArrayStream, ArrayStreamExt};
+    use crate::{ArrayData, IntoArrayData, IntoArrayVariant};
+
+    fn array_stream(dtype: DType, chunks: Vec<ArrayData>) -> impl ArrayStream + Unpin {
+        ArrayIteratorAdapter::new(dtype, chunks.into_iter().map(Ok)).into_stream()
+    }
+
+    fn i32_stream(chunks: Vec<Vec<i32>>) -> impl ArrayStream + Unpin {
+        array_stream(
+            DType::Primitive(PType::I32, Nullability::NonNullable),
+            chunks
+                .into_iter()
+                .map(|chunk| PrimitiveArray::from(chunk).into_array())
+                .collect(),
+        )
+    }
+
+    fn values(array: ArrayData) -> Vec<i32> {
+        array
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<i32>()
+            .to_vec()
+    }
+
+    #[test]
+    fn merge_sorted() {
+        let streams = vec![
+            i32_stream(vec![vec![1, 4, 7], vec![9, 12]]),
+            i32_stream(vec![vec![2, 3], vec![], vec![8, 10, 11]]),
+            i32_stream(vec![vec![5, 6]]),
+        ];
+        let merged = merge_sorted_streams(streams, |chunk| Ok(chunk.clone()))
+            .unwrap()
+            .into_array_data()
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+        assert_eq!(values(merged), (1..=12).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn merge_sorted_equal_keys_in_input_order() {
+        let streams = vec![
+            i32_stream(vec![vec![1, 1, 2]]),
+            i32_stream(vec![vec![1, 2]]),
+        ];
+        let runs = merge_sorted_streams(streams, |chunk| Ok(chunk.clone()))
+            .unwrap()
+            .map_ok(values)
+            .try_collect::<Vec<_>>()
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+        assert_eq!(runs, vec![vec![1, 1], vec![1], vec![2], vec![2]]);
+    }
+
+    #[test]
+    fn interleave() {
+        let streams = vec![
+            i32_stream(vec![vec![1, 2], vec![3]]),
+            i32_stream(vec![vec![4, 5]]),
+        ];
+        let mut rows = interleave_streams(streams)
+            .unwrap()
+            .map_ok(values)
+            .try_collect::<Vec<_>>()
+            .now_or_never()
+            .unwrap()
+            .unwrap()
+            .concat();
+        rows.sort_unstable();
+        assert_eq!(rows, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn mismatched_dtypes() {
+        let streams = vec![
+            array_stream(
+                DType::Primitive(PType::I32, Nullability::NonNullable),
+                vec![PrimitiveArray::from(vec![1i32]).into_array()],
+            ),
+            array_stream(
+                DType::Primitive(PType::I64, Nullability::NonNullable),
+                vec![PrimitiveArray::from(vec![1i64]).into_array()],
+            ),
+        ];
+        assert!(interleave_streams(streams).is_err());
+    }
+}
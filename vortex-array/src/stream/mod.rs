@@ -1,6 +1,7 @@
 pub use adapter::*;
 pub use ext::*;
 use futures_util::Stream;
+pub use merge::*;
 pub use take_rows::*;
 use vortex_dtype::DType;
 use vortex_error::VortexResult;
@@ -9,6 +10,8 @@ use crate::ArrayData;
 
 mod adapter;
 mod ext;
+mod merge;
+mod rechunk;
 mod take_rows;
 
 /// A stream of array chunks along with a DType.
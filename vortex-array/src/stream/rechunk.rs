@@ -0,0 +1,58 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::{ready, Stream};
+use pin_project::pin_project;
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+
+use crate::iter::Rechunker;
+use crate::stream::ArrayStream;
+use crate::ArrayData;
+
+/// An [`ArrayStream`] of the chunks of another regrouped into chunks of a fixed number of rows.
+#[pin_project]
+pub(crate) struct RechunkStream<S> {
+    #[pin]
+    inner: S,
+    rechunker: Rechunker,
+    exhausted: bool,
+}
+
+impl<S: ArrayStream> RechunkStream<S> {
+    pub(crate) fn try_new(inner: S, chunk_len: usize) -> VortexResult<Self> {
+        let rechunker = Rechunker::try_new(inner.dtype().clone(), chunk_len)?;
+        Ok(Self {
+            inner,
+            rechunker,
+            exhausted: false,
+        })
+    }
+}
+
+impl<S: ArrayStream> Stream for RechunkStream<S> {
+    type Item = VortexResult<ArrayData>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if let Some(chunk) = this.rechunker.pop(*this.exhausted).transpose() {
+                return Poll::Ready(Some(chunk));
+            }
+            if *this.exhausted {
+                return Poll::Ready(None);
+            }
+            match ready!(this.inner.as_mut().poll_next(cx)) {
+                Some(Ok(chunk)) => this.rechunker.push(chunk),
+                Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+                None => *this.exhausted = true,
+            }
+        }
+    }
+}
+
+impl<S: ArrayStream> ArrayStream for RechunkStream<S> {
+    fn dtype(&self) -> &DType {
+        self.inner.dtype()
+    }
+}
@@ -23,35 +23,7 @@ pub struct TakeRows<R: ArrayStream> {
 
 impl<R: ArrayStream> TakeRows<R> {
     pub fn try_new(reader: R, indices: ArrayData) -> VortexResult<Self> {
-        if !indices.is_empty() {
-            if !indices.statistics().compute_is_sorted().unwrap_or(false) {
-                vortex_bail!("Indices must be sorted to take from IPC stream")
-            }
-
-            if indices
-                .statistics()
-                .compute_null_count()
-                .map(|nc| nc > 0)
-                .unwrap_or(true)
-            {
-                vortex_bail!("Indices must not contain nulls")
-            }
-
-            if !indices.dtype().is_int() {
-                vortex_bail!("Indices must be integers")
-            }
-
-            if indices.dtype().is_signed_int()
-                && indices
-                    .statistics()
-                    .compute_as_cast::<i64>(Stat::Min)
-                    .map(|min| min < 0)
-                    .unwrap_or(true)
-            {
-                vortex_bail!("Indices must be positive")
-            }
-        }
-
+        validate_take_indices(&indices)?;
         Ok(Self {
             reader,
             indices,
@@ -60,6 +32,64 @@ impl<R: ArrayStream> TakeRows<R> {
     }
 }
 
+/// Check that the indices can be taken from a stream of chunks, which are only read once.
+pub(crate) fn validate_take_indices(indices: &ArrayData) -> VortexResult<()> {
+    if indices.is_empty() {
+        return Ok(());
+    }
+
+    if !indices.statistics().compute_is_sorted().unwrap_or(false) {
+        vortex_bail!("Indices must be sorted to take from IPC stream")
+    }
+
+    if indices
+        .statistics()
+        .compute_null_count()
+        .map(|nc| nc > 0)
+        .unwrap_or(true)
+    {
+        vortex_bail!("Indices must not contain nulls")
+    }
+
+    if !indices.dtype().is_int() {
+        vortex_bail!("Indices must be integers")
+    }
+
+    if indices.dtype().is_signed_int()
+        && indices
+            .statistics()
+            .compute_as_cast::<i64>(Stat::Min)
+            .map(|min| min < 0)
+            .unwrap_or(true)
+    {
+        vortex_bail!("Indices must be positive")
+    }
+
+    Ok(())
+}
+
+/// Take the rows of the batch, which starts at `row_offset` of the stream, selected by the
+/// sorted indices, or `None` if the indices select none of them.
+pub(crate) fn take_from_batch(
+    indices: &ArrayData,
+    row_offset: usize,
+    batch: &ArrayData,
+) -> VortexResult<Option<ArrayData>> {
+    let left = search_sorted_usize(indices, row_offset, SearchSortedSide::Left)?.to_index();
+    let right =
+        search_sorted_usize(indices, row_offset + batch.len(), SearchSortedSide::Left)?.to_index();
+
+    if left == right {
+        return Ok(None);
+    }
+
+    let indices_for_batch = slice(indices, left, right)?.into_primitive()?;
+    let shifted_arr = match_each_integer_ptype!(indices_for_batch.ptype(), |$T| {
+        sub_scalar(&indices_for_batch.into_array(), Scalar::from(row_offset as $T))?
+    });
+    take(batch, &shifted_arr).map(Some)
+}
+
 impl<R: ArrayStream> Stream for TakeRows<R> {
     type Item = VortexResult<ArrayData>;
 
@@ -72,28 +102,13 @@ impl<R: ArrayStream> Stream for TakeRows<R> {
 
         while let Some(batch) = ready!(this.reader.as_mut().poll_next(cx)?) {
             let curr_offset = *this.row_offset;
-            let left =
-                search_sorted_usize(this.indices, curr_offset, SearchSortedSide::Left)?.to_index();
-            let right = search_sorted_usize(
-                this.indices,
-                curr_offset + batch.len(),
-                SearchSortedSide::Left,
-            )?
-            .to_index();
-
             *this.row_offset += batch.len();
 
-            if left == right {
-                continue;
-            }
-
             // TODO(ngates): this is probably too heavy to run on the event loop. We should spawn
             //  onto a worker pool.
-            let indices_for_batch = slice(this.indices, left, right)?.into_primitive()?;
-            let shifted_arr = match_each_integer_ptype!(indices_for_batch.ptype(), |$T| {
-                sub_scalar(&indices_for_batch.into_array(), Scalar::from(curr_offset as $T))?
-            });
-            return Poll::Ready(take(&batch, &shifted_arr).map(Some).transpose());
+            if let Some(taken) = take_from_batch(this.indices, curr_offset, &batch).transpose() {
+                return Poll::Ready(Some(taken));
+            }
         }
 
         Poll::Ready(None)
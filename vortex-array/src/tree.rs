@@ -1,19 +1,183 @@
+use std::collections::BTreeMap;
 use std::fmt;
+use std::fmt::Write as _;
 
 use humansize::{format_size, DECIMAL};
 use serde::ser::Error;
+use serde::Serialize;
 use vortex_buffer::Buffer;
-use vortex_error::{VortexError, VortexResult};
+use vortex_error::{vortex_err, VortexError, VortexResult};
 
 use crate::array::ChunkedEncoding;
 use crate::encoding::EncodingVTable;
+use crate::stats::ArrayStatistics;
 use crate::visitor::ArrayVisitor;
-use crate::ArrayData;
+use crate::{ArrayDType, ArrayData};
 
 impl ArrayData {
     pub fn tree_display(&self) -> TreeDisplayWrapper {
         TreeDisplayWrapper(self)
     }
+
+    /// The encoding tree of the array, with the size and statistics of every array in it.
+    pub fn tree_node(&self) -> VortexResult<TreeNode> {
+        TreeNode::try_new("root", self)
+    }
+
+    /// Render the encoding tree of the array as JSON, see [TreeNode] for its layout.
+    pub fn tree_json(&self) -> VortexResult<String> {
+        serde_json::to_string_pretty(&self.tree_node()?)
+            .map_err(|e| vortex_err!("Failed to serialize array tree: {e}"))
+    }
+
+    /// Render the encoding tree of the array as a Graphviz DOT digraph.
+    pub fn tree_dot(&self) -> VortexResult<String> {
+        let mut dot = String::from("digraph vortex {\n  node [shape=box, fontname=monospace];\n");
+        self.tree_node()?.write_dot(&mut dot, &mut 0)?;
+        dot.push_str("}\n");
+        Ok(dot)
+    }
+
+    /// The layout of every array in the encoding tree, depth first from the root, as printed by
+    /// [ArrayData::tree_display].
+    pub fn layout_report(&self) -> VortexResult<Vec<LayoutNode>> {
+        let mut report = Vec::new();
+        LayoutNode::collect("root".to_string(), self, &mut report)?;
+        Ok(report)
+    }
+}
+
+/// The layout of a single array in the encoding tree, see [ArrayData::layout_report].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutNode {
+    /// The names of the array and its ancestors joined by `.`, e.g. `root.codes.validity`.
+    pub path: String,
+    pub encoding: String,
+    /// The length of the serialized metadata of the array.
+    pub metadata_bytes: usize,
+    /// The length of the buffer of the array itself, excluding its children.
+    pub buffer_bytes: usize,
+    pub nchildren: usize,
+    /// The alignment promised by the buffer of the array, if it has one.
+    pub alignment: Option<usize>,
+}
+
+impl LayoutNode {
+    fn collect(path: String, array: &ArrayData, report: &mut Vec<Self>) -> VortexResult<()> {
+        let children = array.named_children();
+        report.push(Self {
+            path: path.clone(),
+            encoding: array.encoding().id().to_string(),
+            metadata_bytes: array.metadata_bytes()?.len(),
+            buffer_bytes: array.buffer().map_or(0, Buffer::len),
+            nchildren: children.len(),
+            alignment: array.buffer().map(Buffer::alignment),
+        });
+        for (name, child) in children {
+            Self::collect(format!("{path}.{name}"), &child, report)?;
+        }
+        Ok(())
+    }
+}
+
+/// An array in the encoding tree of [ArrayData::tree_node], for tooling that renders it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TreeNode {
+    /// The name the parent gives the array, or `root`.
+    pub name: String,
+    /// The name of the encoding, e.g. `vortex.primitive`.
+    pub encoding: String,
+    /// The code of the encoding, see [EncodingId](crate::encoding::EncodingId).
+    pub encoding_id: u16,
+    pub dtype: String,
+    pub len: usize,
+    /// The size of the array including its children, as in [ArrayData::nbytes].
+    pub nbytes: usize,
+    pub metadata: String,
+    /// The statistics already computed for the array, by name.
+    pub stats: BTreeMap<String, String>,
+    /// The length in bytes of each buffer of the array.
+    pub buffers: Vec<usize>,
+    pub children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    fn try_new(name: &str, array: &ArrayData) -> VortexResult<Self> {
+        let mut collector = TreeNodeCollector::default();
+        array.encoding().accept(array, &mut collector)?;
+        Ok(Self {
+            name: name.to_string(),
+            encoding: array.encoding().id().as_ref().to_string(),
+            encoding_id: array.encoding().id().code(),
+            dtype: array.dtype().to_string(),
+            len: array.len(),
+            nbytes: array.nbytes(),
+            metadata: array.array_metadata().to_string(),
+            stats: array
+                .statistics()
+                .to_set()
+                .into_iter()
+                .map(|(stat, value)| (stat.to_string(), value.to_string()))
+                .collect(),
+            buffers: collector.buffers,
+            children: collector.children,
+        })
+    }
+
+    /// Write the node and its children as DOT statements, numbering the nodes from `next_id`.
+    fn write_dot(&self, dot: &mut String, next_id: &mut usize) -> VortexResult<usize> {
+        let id = *next_id;
+        *next_id += 1;
+
+        let mut label = format!(
+            "{}\nencoding_id={:#04x}\n{}\nlen={} nbytes={}",
+            self.encoding,
+            self.encoding_id,
+            self.dtype,
+            self.len,
+            format_size(self.nbytes, DECIMAL)
+        );
+        for (stat, value) in &self.stats {
+            write!(label, "\n{stat}={value}")?;
+        }
+        writeln!(dot, "  n{id} [label=\"{}\"];", escape_dot(&label))?;
+
+        for child in &self.children {
+            let child_id = child.write_dot(dot, next_id)?;
+            writeln!(
+                dot,
+                "  n{id} -> n{child_id} [label=\"{}\"];",
+                escape_dot(&child.name)
+            )?;
+        }
+        Ok(id)
+    }
+}
+
+/// Escape a string for a quoted DOT label, keeping the line breaks.
+fn escape_dot(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[derive(Default)]
+struct TreeNodeCollector {
+    buffers: Vec<usize>,
+    children: Vec<TreeNode>,
+}
+
+impl ArrayVisitor for TreeNodeCollector {
+    fn visit_child(&mut self, name: &str, array: &ArrayData) -> VortexResult<()> {
+        self.children.push(TreeNode::try_new(name, array)?);
+        Ok(())
+    }
+
+    fn visit_buffer(&mut self, buffer: &Buffer) -> VortexResult<()> {
+        self.buffers.push(buffer.len());
+        Ok(())
+    }
 }
 
 pub struct TreeDisplayWrapper<'a>(&'a ArrayData);
@@ -110,3 +274,81 @@ impl<'a, 'b: 'a> TreeFormatter<'a, 'b> {
         write!(self.fmt, "{}{}", self.indent, fmt)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::array::{PrimitiveArray, StructArray};
+    use crate::stats::ArrayStatistics;
+    use crate::validity::Validity;
+    use crate::IntoArrayData;
+
+    fn array() -> crate::ArrayData {
+        let values =
+            PrimitiveArray::from_vec(vec![1u32, 2, 3], Validity::from_iter([true, false, true]))
+                .into_array();
+        values.statistics().compute_max::<u32>();
+        StructArray::from_fields(&[("a\"b", values)])
+            .unwrap()
+            .into_array()
+    }
+
+    #[test]
+    fn tree_node() {
+        let node = array().tree_node().unwrap();
+        assert_eq!(node.name, "root");
+        assert_eq!(node.encoding, "vortex.struct");
+        assert_eq!(node.encoding_id, 0x04);
+        assert_eq!(node.len, 3);
+
+        let field = &node.children[0];
+        assert_eq!(field.name, "a\"b");
+        assert_eq!(field.encoding, "vortex.primitive");
+        assert_eq!(field.encoding_id, 0x03);
+        assert_eq!(field.buffers, vec![12]);
+        assert_eq!(field.stats.get("max").map(String::as_str), Some("3_u32"));
+        assert_eq!(field.children[0].name, "validity");
+    }
+
+    #[test]
+    fn tree_json() {
+        let json: serde_json::Value = serde_json::from_str(&array().tree_json().unwrap()).unwrap();
+        assert_eq!(json["encoding"], "vortex.struct");
+        assert_eq!(json["encoding_id"], 0x04);
+        assert_eq!(json["children"][0]["len"], 3);
+        assert_eq!(
+            json["children"][0]["children"][0]["encoding"],
+            "vortex.bool"
+        );
+    }
+
+    #[test]
+    fn layout_report() {
+        let report = array().layout_report().unwrap();
+        let paths = report.iter().map(|n| n.path.as_str()).collect::<Vec<_>>();
+        assert_eq!(paths, ["root", "root.a\"b", "root.a\"b.validity"]);
+
+        assert_eq!(report[0].nchildren, 1);
+        assert_eq!(report[0].buffer_bytes, 0);
+        assert_eq!(report[0].alignment, None);
+
+        let field = &report[1];
+        assert_eq!(field.encoding, "vortex.primitive(0x03)");
+        assert_eq!(field.buffer_bytes, 12);
+        assert_eq!(field.nchildren, 1);
+        assert!(field.alignment.is_some());
+        assert_eq!(
+            field.metadata_bytes,
+            array().children()[0].metadata_bytes().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn tree_dot() {
+        let dot = array().tree_dot().unwrap();
+        assert!(dot.starts_with("digraph vortex {"));
+        assert!(dot.contains("n0 [label=\"vortex.struct\\nencoding_id=0x04\\n"));
+        assert!(dot.contains("n0 -> n1 [label=\"a\\\"b\"];"));
+        assert!(dot.contains("n1 -> n2 [label=\"validity\"];"));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+}
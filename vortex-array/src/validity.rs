@@ -1,7 +1,6 @@
 //! Array validity and nullability behavior, used by arrays and compute functions.
 
 use std::fmt::{Debug, Display};
-use std::ops::BitAnd;
 
 use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder, NullBuffer};
 use serde::{Deserialize, Serialize};
@@ -11,8 +10,9 @@ use vortex_error::{
 };
 
 use crate::array::{BoolArray, ConstantArray};
-use crate::compute::{filter, scalar_at, slice, take, FilterMask};
+use crate::compute::{and, filter, invert, or, scalar_at, slice, take, FilterMask};
 use crate::encoding::Encoding;
+use crate::metadata::{MetadataReader, MetadataWriter};
 use crate::patches::Patches;
 use crate::stats::ArrayStatistics;
 use crate::{ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant};
@@ -57,11 +57,57 @@ pub trait ArrayValidity {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "SerdeValidityMetadata", into = "SerdeValidityMetadata")]
 pub enum ValidityMetadata {
+    NonNullable,
+    AllValid,
+    AllInvalid,
+    /// The validity is stored in a child array, with the number of nulls in it if that was known
+    /// when the array was constructed.
+    Array {
+        null_count: Option<usize>,
+    },
+}
+
+/// The serde representation of [`ValidityMetadata`].
+///
+/// `Array` was a unit variant before the null count was cached, so it stays one for metadata
+/// written without a null count, and a known null count is written as a variant of its own.
+#[derive(Serialize, Deserialize)]
+enum SerdeValidityMetadata {
     NonNullable,
     AllValid,
     AllInvalid,
     Array,
+    ArrayWithNullCount(usize),
+}
+
+impl From<SerdeValidityMetadata> for ValidityMetadata {
+    fn from(value: SerdeValidityMetadata) -> Self {
+        match value {
+            SerdeValidityMetadata::NonNullable => Self::NonNullable,
+            SerdeValidityMetadata::AllValid => Self::AllValid,
+            SerdeValidityMetadata::AllInvalid => Self::AllInvalid,
+            SerdeValidityMetadata::Array => Self::Array { null_count: None },
+            SerdeValidityMetadata::ArrayWithNullCount(null_count) => Self::Array {
+                null_count: Some(null_count),
+            },
+        }
+    }
+}
+
+impl From<ValidityMetadata> for SerdeValidityMetadata {
+    fn from(value: ValidityMetadata) -> Self {
+        match value {
+            ValidityMetadata::NonNullable => Self::NonNullable,
+            ValidityMetadata::AllValid => Self::AllValid,
+            ValidityMetadata::AllInvalid => Self::AllInvalid,
+            ValidityMetadata::Array { null_count: None } => Self::Array,
+            ValidityMetadata::Array {
+                null_count: Some(null_count),
+            } => Self::ArrayWithNullCount(null_count),
+        }
+    }
 }
 
 impl Display for ValidityMetadata {
@@ -79,9 +125,76 @@ impl ValidityMetadata {
             Self::NonNullable => Validity::NonNullable,
             Self::AllValid => Validity::AllValid,
             Self::AllInvalid => Validity::AllInvalid,
-            Self::Array => Validity::Array(array_fn()),
+            Self::Array { .. } => Validity::Array(array_fn()),
         }
     }
+
+    /// The number of nulls, if it is known without reading the validity array.
+    pub fn cached_null_count(&self, length: usize) -> Option<usize> {
+        match self {
+            Self::NonNullable | Self::AllValid => Some(0),
+            Self::AllInvalid => Some(length),
+            Self::Array { null_count } => *null_count,
+        }
+    }
+
+    /// The number of nulls, falling back to counting them in the validity returned by
+    /// `validity_fn` if it wasn't cached.
+    pub fn null_count<F>(&self, length: usize, validity_fn: F) -> VortexResult<usize>
+    where
+        F: FnOnce() -> Validity,
+    {
+        match self.cached_null_count(length) {
+            Some(null_count) => Ok(null_count),
+            None => validity_fn().null_count(length),
+        }
+    }
+
+    /// The [`LogicalValidity`] of an array of `length`, skipping the statistics of the validity
+    /// array returned by `validity_fn` if the null count was cached.
+    pub fn to_logical<F>(&self, length: usize, validity_fn: F) -> LogicalValidity
+    where
+        F: FnOnce() -> Validity,
+    {
+        match self.cached_null_count(length) {
+            Some(0) => LogicalValidity::AllValid(length),
+            Some(null_count) if null_count == length => LogicalValidity::AllInvalid(length),
+            Some(_) => match validity_fn() {
+                Validity::Array(a) => LogicalValidity::Array(a),
+                validity => validity.to_logical(length),
+            },
+            None => validity_fn().to_logical(length),
+        }
+    }
+
+    /// Write the validity in its fixed layout: a tag byte, 0 for non-nullable, 1 for all valid,
+    /// 2 for all invalid, 3 for an array without a known null count and 4 for an array followed
+    /// by its null count as a u64.
+    pub fn write_to(&self, writer: &mut MetadataWriter) {
+        match self {
+            Self::NonNullable => writer.put_u8(0),
+            Self::AllValid => writer.put_u8(1),
+            Self::AllInvalid => writer.put_u8(2),
+            Self::Array { null_count: None } => writer.put_u8(3),
+            Self::Array {
+                null_count: Some(null_count),
+            } => writer.put_u8(4).put_usize(*null_count),
+        };
+    }
+
+    /// Read the validity written by [`ValidityMetadata::write_to`].
+    pub fn read_from(reader: &mut MetadataReader) -> VortexResult<Self> {
+        Ok(match reader.get_u8()? {
+            0 => Self::NonNullable,
+            1 => Self::AllValid,
+            2 => Self::AllInvalid,
+            3 => Self::Array { null_count: None },
+            4 => Self::Array {
+                null_count: Some(reader.get_usize()?),
+            },
+            tag => vortex_bail!("Unknown validity metadata tag {}", tag),
+        })
+    }
 }
 
 /// Validity information for an array
@@ -116,7 +229,14 @@ impl Validity {
                         length
                     )
                 }
-                Ok(ValidityMetadata::Array)
+                // Metadata is built once, when the array is constructed, so this is where the null
+                // count is computed for readers to use without scanning the validity.
+                Ok(ValidityMetadata::Array {
+                    null_count: a
+                        .statistics()
+                        .compute_true_count()
+                        .map(|true_count| length - true_count),
+                })
             }
         }
     }
@@ -263,7 +383,10 @@ impl Validity {
         }
     }
 
-    /// Logically & two Validity values of the same length
+    /// Logically & two Validity values of the same length, i.e. intersect the valid positions.
+    ///
+    /// Validity arrays of any boolean encoding are combined with the [`and`] kernel, so compressed
+    /// validity stays compressed where the encoding supports it.
     pub fn and(self, rhs: Validity) -> VortexResult<Validity> {
         let validity = match (self, rhs) {
             // Should be pretty clear
@@ -280,20 +403,41 @@ impl Validity {
             | (Validity::AllValid, Validity::NonNullable)
             | (Validity::AllValid, Validity::AllValid) => Validity::AllValid,
             // Here we actually have to do some work
-            (Validity::Array(lhs), Validity::Array(rhs)) => {
-                let lhs = BoolArray::try_from(lhs)?;
-                let rhs = BoolArray::try_from(rhs)?;
+            (Validity::Array(lhs), Validity::Array(rhs)) => Validity::try_from(and(lhs, rhs)?)?,
+        };
 
-                let lhs = lhs.boolean_buffer();
-                let rhs = rhs.boolean_buffer();
+        Ok(validity)
+    }
 
-                Validity::from(lhs.bitand(&rhs))
-            }
+    /// Logically | two Validity values of the same length, i.e. union the valid positions.
+    ///
+    /// Like [`Validity::and`], validity arrays keep their encoding where it supports the [`or`]
+    /// kernel.
+    pub fn or(self, rhs: Validity) -> VortexResult<Validity> {
+        let validity = match (self, rhs) {
+            (Validity::NonNullable, Validity::NonNullable) => Validity::NonNullable,
+            // Any all valid side makes the output all valid
+            (Validity::NonNullable | Validity::AllValid, _)
+            | (_, Validity::NonNullable | Validity::AllValid) => Validity::AllValid,
+            // All invalid values on one side have no effect on the other
+            (Validity::AllInvalid, v) | (v, Validity::AllInvalid) => v,
+            (Validity::Array(lhs), Validity::Array(rhs)) => Validity::try_from(or(lhs, rhs)?)?,
         };
 
         Ok(validity)
     }
 
+    /// Negate the validity, so that valid positions become null and null positions valid.
+    ///
+    /// The result is always nullable, since a non-nullable validity becomes all invalid.
+    pub fn invert(self) -> VortexResult<Validity> {
+        Ok(match self {
+            Validity::NonNullable | Validity::AllValid => Validity::AllInvalid,
+            Validity::AllInvalid => Validity::AllValid,
+            Validity::Array(a) => Validity::Array(invert(&a)?),
+        })
+    }
+
     pub fn patch(self, len: usize, indices: &ArrayData, patches: Validity) -> VortexResult<Self> {
         match (&self, &patches) {
             (Validity::NonNullable, Validity::NonNullable) => return Ok(Validity::NonNullable),
@@ -504,6 +648,40 @@ impl LogicalValidity {
         }
     }
 
+    /// Logically & two validities of the same length, i.e. intersect the valid positions.
+    ///
+    /// Only two [`LogicalValidity::Array`] validities are combined with the [`and`] kernel, all
+    /// other cases are resolved without touching a buffer.
+    pub fn intersect(self, other: LogicalValidity) -> VortexResult<LogicalValidity> {
+        check_same_len(&self, &other)?;
+        Ok(match (self, other) {
+            (Self::AllInvalid(len), _) | (_, Self::AllInvalid(len)) => Self::AllInvalid(len),
+            (Self::AllValid(_), v) | (v, Self::AllValid(_)) => v,
+            (Self::Array(lhs), Self::Array(rhs)) => Self::Array(and(lhs, rhs)?),
+        })
+    }
+
+    /// Logically | two validities of the same length, i.e. union the valid positions.
+    ///
+    /// Like [`LogicalValidity::intersect`], only two array validities need the [`or`] kernel.
+    pub fn union(self, other: LogicalValidity) -> VortexResult<LogicalValidity> {
+        check_same_len(&self, &other)?;
+        Ok(match (self, other) {
+            (Self::AllValid(len), _) | (_, Self::AllValid(len)) => Self::AllValid(len),
+            (Self::AllInvalid(_), v) | (v, Self::AllInvalid(_)) => v,
+            (Self::Array(lhs), Self::Array(rhs)) => Self::Array(or(lhs, rhs)?),
+        })
+    }
+
+    /// Negate the validity, so that valid positions become null and null positions valid.
+    pub fn negate(self) -> VortexResult<LogicalValidity> {
+        Ok(match self {
+            Self::AllValid(len) => Self::AllInvalid(len),
+            Self::AllInvalid(len) => Self::AllValid(len),
+            Self::Array(a) => Self::Array(invert(&a)?),
+        })
+    }
+
     pub fn null_count(&self) -> VortexResult<usize> {
         match self {
             Self::AllValid(_) => Ok(0),
@@ -518,6 +696,17 @@ impl LogicalValidity {
     }
 }
 
+fn check_same_len(lhs: &LogicalValidity, rhs: &LogicalValidity) -> VortexResult<()> {
+    if lhs.len() != rhs.len() {
+        vortex_bail!(
+            "Cannot combine validities of different lengths {} and {}",
+            lhs.len(),
+            rhs.len()
+        );
+    }
+    Ok(())
+}
+
 impl TryFrom<ArrayData> for LogicalValidity {
     type Error = VortexError;
 
@@ -538,11 +727,18 @@ impl IntoArrayData for LogicalValidity {
 
 #[cfg(test)]
 mod tests {
+    use flexbuffers::FlexbufferSerializer;
     use rstest::rstest;
+    use serde::Serialize;
 
     use crate::array::{BoolArray, PrimitiveArray};
-    use crate::validity::Validity;
-    use crate::IntoArrayData;
+    use crate::metadata::{TryDeserializeArrayMetadata, TrySerializeArrayMetadata};
+    use crate::validity::{LogicalValidity, Validity};
+    use crate::{IntoArrayData, IntoArrayVariant};
+
+    fn array_validity(values: &[bool]) -> Validity {
+        Validity::Array(BoolArray::from_iter(values.iter().copied()).into_array())
+    }
 
     #[rstest]
     #[case(Validity::AllValid, 5, &[2, 4], Validity::AllValid, Validity::AllValid)]
@@ -584,4 +780,141 @@ mod tests {
             )
             .unwrap();
     }
+
+    #[rstest]
+    #[case(
+        Validity::NonNullable,
+        Validity::AllValid,
+        Validity::AllValid,
+        Validity::AllValid
+    )]
+    #[case(Validity::AllInvalid, array_validity(&[true, false]), Validity::AllInvalid, array_validity(&[true, false]))]
+    #[case(Validity::AllValid, array_validity(&[true, false]), array_validity(&[true, false]), Validity::AllValid)]
+    #[case(array_validity(&[true, false, true]), array_validity(&[true, true, false]), array_validity(&[true, false, false]), Validity::AllValid)]
+    fn and_or_validity(
+        #[case] lhs: Validity,
+        #[case] rhs: Validity,
+        #[case] intersection: Validity,
+        #[case] union: Validity,
+    ) {
+        assert_eq!(lhs.clone().and(rhs.clone()).unwrap(), intersection);
+        assert_eq!(lhs.or(rhs).unwrap(), union);
+    }
+
+    #[test]
+    fn invert_validity() {
+        assert_eq!(
+            Validity::NonNullable.invert().unwrap(),
+            Validity::AllInvalid
+        );
+        assert_eq!(Validity::AllInvalid.invert().unwrap(), Validity::AllValid);
+        assert_eq!(
+            array_validity(&[true, false]).invert().unwrap(),
+            array_validity(&[false, true])
+        );
+    }
+
+    fn array_logical(values: &[bool]) -> LogicalValidity {
+        LogicalValidity::Array(BoolArray::from_iter(values.iter().copied()).into_array())
+    }
+
+    fn logical_values(validity: LogicalValidity) -> Vec<bool> {
+        validity
+            .into_array()
+            .into_bool()
+            .unwrap()
+            .boolean_buffer()
+            .iter()
+            .collect()
+    }
+
+    #[test]
+    fn logical_fast_paths() {
+        let mixed = array_logical(&[true, false, true]);
+        assert!(mixed
+            .clone()
+            .intersect(LogicalValidity::AllInvalid(3))
+            .unwrap()
+            .all_invalid());
+        assert!(matches!(
+            mixed
+                .clone()
+                .intersect(LogicalValidity::AllValid(3))
+                .unwrap(),
+            LogicalValidity::Array(_)
+        ));
+        assert!(mixed
+            .clone()
+            .union(LogicalValidity::AllValid(3))
+            .unwrap()
+            .all_valid());
+        assert!(matches!(
+            LogicalValidity::AllInvalid(3).union(mixed).unwrap(),
+            LogicalValidity::Array(_)
+        ));
+        assert!(LogicalValidity::AllValid(3).negate().unwrap().all_invalid());
+        assert!(LogicalValidity::AllValid(3)
+            .intersect(LogicalValidity::AllValid(2))
+            .is_err());
+    }
+
+    #[test]
+    fn logical_arrays() {
+        let lhs = array_logical(&[true, false, true, false]);
+        let rhs = array_logical(&[true, true, false, false]);
+        assert_eq!(
+            logical_values(lhs.clone().intersect(rhs.clone()).unwrap()),
+            [true, false, false, false]
+        );
+        assert_eq!(
+            logical_values(lhs.clone().union(rhs).unwrap()),
+            [true, true, true, false]
+        );
+        assert_eq!(
+            logical_values(lhs.negate().unwrap()),
+            [false, true, false, true]
+        );
+    }
+
+    #[test]
+    fn metadata_null_count() {
+        let bools = BoolArray::from_iter([true, false, true, false]).into_array();
+        let validity = Validity::Array(bools);
+        let metadata = validity.to_metadata(4).unwrap();
+        assert_eq!(metadata.cached_null_count(4), Some(2));
+        assert_eq!(metadata.null_count(4, || unreachable!()).unwrap(), 2);
+        assert!(matches!(
+            metadata.to_logical(4, || validity.clone()),
+            LogicalValidity::Array(_)
+        ));
+
+        let bools = BoolArray::from_iter([true, true]).into_array();
+        let metadata = Validity::Array(bools).to_metadata(2).unwrap();
+        assert!(matches!(
+            metadata.to_logical(2, || unreachable!()),
+            LogicalValidity::AllValid(2)
+        ));
+    }
+
+    #[test]
+    fn metadata_reads_unit_array_variant() {
+        // The shape of the validity metadata before the null count was cached.
+        #[derive(Serialize)]
+        enum ValidityMetadata {
+            Array,
+        }
+
+        let mut ser = FlexbufferSerializer::new();
+        ValidityMetadata::Array.serialize(&mut ser).unwrap();
+        let bytes = ser.take_buffer();
+        let metadata = super::ValidityMetadata::try_deserialize_metadata(Some(&bytes)).unwrap();
+        assert_eq!(metadata.cached_null_count(3), None);
+
+        let metadata = super::ValidityMetadata::Array {
+            null_count: Some(1),
+        };
+        let bytes = metadata.try_serialize_metadata().unwrap();
+        let metadata = super::ValidityMetadata::try_deserialize_metadata(Some(&bytes)).unwrap();
+        assert_eq!(metadata.cached_null_count(3), Some(1));
+    }
 }
@@ -180,7 +180,7 @@ impl ArrayData {
     }
 
     pub fn as_list_array(&self) -> Option<&dyn ListArrayTrait> {
-        matches!(self.dtype(), DType::List(..))
+        matches!(self.dtype(), DType::List(..) | DType::FixedSizeList(..))
             .then(|| self.encoding().as_list_array(self))
             .flatten()
     }
@@ -194,7 +194,13 @@ impl ArrayData {
 
 pub trait NullArrayTrait: ArrayTrait {}
 
-pub trait BoolArrayTrait: ArrayTrait {}
+pub trait BoolArrayTrait: ArrayTrait {
+    /// The exclusive end of each run of the array and whether its values are set, for encodings
+    /// that hold their values as runs, or `None` for any other encoding.
+    fn run_ends(&self) -> VortexResult<Option<Vec<(usize, bool)>>> {
+        Ok(None)
+    }
+}
 
 pub trait PrimitiveArrayTrait: ArrayTrait {
     /// The logical primitive type of the array.
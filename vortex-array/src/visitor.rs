@@ -51,3 +51,103 @@ pub trait ArrayVisitor {
         Ok(())
     }
 }
+
+/// Rebuilds an array tree bottom-up, unlike an [`ArrayVisitor`] which only reads it.
+///
+/// Each array is offered to [`transform`](Self::transform) once its children have been
+/// transformed, so that e.g. every dictionary in a tree can be replaced by its canonical form, or
+/// every bit-packed array re-encoded.
+pub trait TreeTransformer {
+    /// Rewrite an array, or return `None` to keep it.
+    ///
+    /// The rewritten array must have the same dtype and length, since it replaces a child of an
+    /// array whose metadata may depend on them.
+    fn transform(&mut self, array: &ArrayData) -> VortexResult<Option<ArrayData>>;
+
+    /// Transform the array and all of its descendants, returning `None` if none of them changed.
+    fn transform_tree(&mut self, array: &ArrayData) -> VortexResult<Option<ArrayData>> {
+        let children = array.children();
+        let mut transformed = Vec::with_capacity(children.len());
+        let mut changed = false;
+        for child in children {
+            match self.transform_tree(&child)? {
+                Some(child) => {
+                    changed = true;
+                    transformed.push(child);
+                }
+                None => transformed.push(child),
+            }
+        }
+
+        let rebuilt = changed
+            .then(|| array.with_children(transformed))
+            .transpose()?;
+        let array = rebuilt.as_ref().unwrap_or(array);
+        Ok(self.transform(array)?.or(rebuilt))
+    }
+}
+
+/// Transform the tree of the array, returning it as is if nothing changed.
+pub fn transform_tree(
+    array: &ArrayData,
+    transformer: &mut dyn TreeTransformer,
+) -> VortexResult<ArrayData> {
+    Ok(transformer
+        .transform_tree(array)?
+        .unwrap_or_else(|| array.clone()))
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::Nullability;
+    use vortex_error::VortexResult;
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ConstantArray, ConstantEncoding, PrimitiveArray, StructArray};
+    use crate::encoding::Encoding;
+    use crate::visitor::{transform_tree, TreeTransformer};
+    use crate::{ArrayData, IntoArrayData, IntoCanonical};
+
+    /// Canonicalizes the constant arrays of a tree.
+    struct ExpandConstants;
+
+    impl TreeTransformer for ExpandConstants {
+        fn transform(&mut self, array: &ArrayData) -> VortexResult<Option<ArrayData>> {
+            if !array.is_encoding(ConstantEncoding::ID) {
+                return Ok(None);
+            }
+            Ok(Some(array.clone().into_canonical()?.into_array()))
+        }
+    }
+
+    #[test]
+    fn expand_nested_constants() {
+        let array = StructArray::from_fields(&[
+            (
+                "a",
+                ConstantArray::new(Scalar::primitive(7i32, Nullability::NonNullable), 3)
+                    .into_array(),
+            ),
+            ("b", PrimitiveArray::from(vec![1i64, 2, 3]).into_array()),
+        ])
+        .unwrap()
+        .into_array();
+
+        let transformed = transform_tree(&array, &mut ExpandConstants).unwrap();
+        let field = transformed.as_struct_array().unwrap().field(0).unwrap();
+        assert!(!field.is_encoding(ConstantEncoding::ID));
+        assert_eq!(
+            field
+                .into_canonical()
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            &[7, 7, 7]
+        );
+
+        // A tree without constants is left as it is.
+        let b = transformed.as_struct_array().unwrap().field(1).unwrap();
+        assert!(ExpandConstants.transform_tree(&b).unwrap().is_none());
+    }
+}
@@ -7,10 +7,18 @@
 //! # Alignment
 //! See: `<https://github.com/spiraldb/vortex/issues/115>`
 //!
-//! We do not currently enforce any alignment guarantees on the buffer.
+//! Buffers allocated by Vortex, e.g. with [`Buffer::from_len_zeroed`], start at an address aligned
+//! to [`DEFAULT_ALIGNMENT`] bytes. Buffers taking ownership of memory allocated elsewhere, e.g. a
+//! `Vec`, an Arrow buffer or [`bytes::Bytes`], never copy it and only promise the alignment their
+//! start happens to have. Code that needs a larger alignment calls [`Buffer::aligned`], which
+//! copies the buffer only if it isn't aligned already.
+//!
+//! Each buffer records the alignment it promises, see [`Buffer::alignment`], which slicing
+//! reduces only when the slice starts at an offset that isn't a multiple of it.
 
 use core::cmp::Ordering;
 use core::ops::{Deref, Range};
+use std::sync::{Arc, Weak};
 
 use arrow_buffer::{ArrowNativeType, Buffer as ArrowBuffer, MutableBuffer as ArrowMutableBuffer};
 pub use string::*;
@@ -18,11 +26,44 @@ pub use string::*;
 mod flexbuffers;
 mod string;
 
+/// The alignment, in bytes, of the buffers allocated by Vortex, which is enough for the widest
+/// SIMD registers and the cache lines of common CPUs.
+pub const DEFAULT_ALIGNMENT: usize = 64;
+
 /// Buffer is an owned, cheaply cloneable byte array.
 ///
 /// Buffers form the building blocks of all in-memory storage in Vortex.
 #[derive(Debug, Clone)]
-pub struct Buffer(Inner);
+pub struct Buffer {
+    inner: Inner,
+    alignment: usize,
+    memory: Memory,
+}
+
+/// What keeps the memory of a buffer allocated, which decides whether the buffer shares it.
+#[derive(Debug, Clone)]
+enum Memory {
+    /// Memory the buffer was created with, which its clones also hold.
+    Owned(Arc<()>),
+    /// A slice of the memory of another buffer, which is shared for as long as that buffer or
+    /// one of its clones is alive.
+    Slice(Weak<()>),
+    /// Memory that is never freed, e.g. static memory.
+    Static,
+}
+
+impl Memory {
+    fn owned() -> Self {
+        Self::Owned(Arc::new(()))
+    }
+
+    fn slice(&self) -> Self {
+        match self {
+            Self::Owned(owner) => Self::Slice(Arc::downgrade(owner)),
+            memory => memory.clone(),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 enum Inner {
@@ -38,23 +79,121 @@ unsafe impl Send for Buffer {}
 unsafe impl Sync for Buffer {}
 
 impl Buffer {
-    /// Create a new buffer of the provided length with all bytes set to `0u8`.
+    /// Wrap the memory, promising the alignment its start happens to have, up to the
+    /// [`DEFAULT_ALIGNMENT`].
+    fn new(inner: Inner, memory: Memory) -> Self {
+        let ptr = match &inner {
+            Inner::Arrow(b) => b.as_ptr(),
+            Inner::Bytes(b) => b.as_ptr(),
+        };
+        Self {
+            inner,
+            alignment: lowest_alignment(ptr as usize, DEFAULT_ALIGNMENT),
+            memory,
+        }
+    }
+
+    /// Create a new buffer of the provided length with all bytes set to `0u8`, aligned to the
+    /// [`DEFAULT_ALIGNMENT`].
     /// If len is 0, does not perform any allocations.
     pub fn from_len_zeroed(len: usize) -> Self {
-        Self::from(ArrowMutableBuffer::from_len_zeroed(len))
+        Self::from_len_zeroed_aligned(len, DEFAULT_ALIGNMENT)
+    }
+
+    /// Create a new buffer of the provided length with all bytes set to `0u8`, whose start is
+    /// aligned to `alignment` bytes.
+    ///
+    /// # Panics
+    /// Panics if `alignment` is not a power of two.
+    pub fn from_len_zeroed_aligned(len: usize, alignment: usize) -> Self {
+        let (buffer, offset) = zeroed_aligned(len, alignment);
+        Self::from_aligned(buffer, offset, len, alignment)
+    }
+
+    fn from_aligned(
+        buffer: ArrowMutableBuffer,
+        offset: usize,
+        len: usize,
+        alignment: usize,
+    ) -> Self {
+        Self {
+            inner: Inner::Arrow(ArrowBuffer::from(buffer).slice_with_length(offset, len)),
+            alignment,
+            memory: Memory::owned(),
+        }
+    }
+
+    /// The alignment in bytes that the start of the buffer is guaranteed to have, which is always a
+    /// power of two.
+    pub fn alignment(&self) -> usize {
+        self.alignment
+    }
+
+    /// Whether the start of the buffer is aligned to `n` bytes, which is false if `n` is not a
+    /// power of two.
+    ///
+    /// Unlike [`Buffer::alignment`], this checks the address of the buffer, which may happen to be
+    /// aligned beyond what the buffer guarantees.
+    pub fn is_aligned_to(&self, n: usize) -> bool {
+        n.is_power_of_two() && self.as_slice().as_ptr().align_offset(n) == 0
+    }
+
+    /// Return a buffer with the same bytes whose start is guaranteed to be aligned to `alignment`
+    /// bytes, copying them into a new allocation only if they are not already aligned.
+    ///
+    /// # Panics
+    /// Panics if `alignment` is not a power of two.
+    pub fn aligned(self, alignment: usize) -> Self {
+        check_alignment(alignment);
+        if self.alignment >= alignment || self.is_aligned_to(alignment) {
+            return Self {
+                alignment: self.alignment.max(alignment),
+                ..self
+            };
+        }
+
+        let len = self.len();
+        let (mut buffer, offset) = zeroed_aligned(len, alignment);
+        buffer.as_slice_mut()[offset..offset + len].copy_from_slice(self.as_slice());
+        Self::from_aligned(buffer, offset, len, alignment)
     }
 
     /// Length of the buffer in bytes
     pub fn len(&self) -> usize {
-        match &self.0 {
+        match &self.inner {
             Inner::Arrow(b) => b.len(),
             Inner::Bytes(b) => b.len(),
         }
     }
 
+    /// The number of bytes allocated for the buffer, which exceeds its length when the buffer has
+    /// unused capacity or is a slice of a larger allocation.
+    pub fn capacity(&self) -> usize {
+        match &self.inner {
+            // Arrow reports no capacity for memory it doesn't own, e.g. memory-mapped buffers.
+            Inner::Arrow(b) => b.capacity().max(b.len()),
+            Inner::Bytes(b) => b.len(),
+        }
+    }
+
+    /// Whether the memory backing the buffer is known to be referenced elsewhere, so that dropping
+    /// the buffer would not free it.
+    ///
+    /// That is the case for static memory, and for slices while the buffer they were sliced from,
+    /// or one of its clones, is still alive. Other buffers count as owning their memory, even if
+    /// they were cloned or wrap memory that is also referenced outside of Vortex, so that memory
+    /// accounting errs on the side of counting memory more than once rather than not at all.
+    pub fn is_shared(&self) -> bool {
+        match &self.memory {
+            Memory::Owned(_) => false,
+            Memory::Slice(owner) => owner.strong_count() > 0,
+            Memory::Static => true,
+        }
+    }
+
     /// Predicate for empty buffers
     pub fn is_empty(&self) -> bool {
-        match &self.0 {
+        match &self.inner {
             Inner::Arrow(b) => b.is_empty(),
             Inner::Bytes(b) => b.is_empty(),
         }
@@ -64,27 +203,37 @@ impl Buffer {
     /// Return a new view on the buffer, but limited to the given index range.
     /// TODO(ngates): implement std::ops::Index
     pub fn slice(&self, range: Range<usize>) -> Self {
-        match &self.0 {
-            Inner::Arrow(b) => Buffer(Inner::Arrow(
-                b.slice_with_length(range.start, range.end - range.start),
-            )),
+        // The slice keeps the alignment of the buffer if it starts at a multiple of it.
+        let alignment = lowest_alignment(range.start, self.alignment);
+        let inner = match &self.inner {
+            Inner::Arrow(b) => {
+                Inner::Arrow(b.slice_with_length(range.start, range.end - range.start))
+            }
             Inner::Bytes(b) => {
                 if range.is_empty() {
                     // bytes::Bytes::slice does not preserve alignment if the range is empty
                     let mut empty_b = b.clone();
                     empty_b.truncate(0);
-                    Buffer(Inner::Bytes(empty_b))
-                } else {
-                    Buffer(Inner::Bytes(b.slice(range)))
+                    return Self {
+                        inner: Inner::Bytes(empty_b),
+                        alignment: self.alignment,
+                        memory: self.memory.slice(),
+                    };
                 }
+                Inner::Bytes(b.slice(range))
             }
+        };
+        Self {
+            inner,
+            alignment,
+            memory: self.memory.slice(),
         }
     }
 
     #[allow(clippy::same_name_method)]
     /// Access the buffer as an immutable byte slice.
     pub fn as_slice(&self) -> &[u8] {
-        match &self.0 {
+        match &self.inner {
             Inner::Arrow(b) => b.as_ref(),
             Inner::Bytes(b) => b.as_ref(),
         }
@@ -101,8 +250,12 @@ impl Buffer {
     /// This method will also fail if we attempt to pass a `T` that is not aligned to the `T` that
     /// it was originally allocated with.
     pub fn into_vec<T: ArrowNativeType>(self) -> Result<Vec<T>, Self> {
-        match self.0 {
-            Inner::Arrow(buffer) => buffer.into_vec::<T>().map_err(|b| Buffer(Inner::Arrow(b))),
+        match self.inner {
+            Inner::Arrow(buffer) => buffer.into_vec::<T>().map_err(|b| Self {
+                inner: Inner::Arrow(b),
+                alignment: self.alignment,
+                memory: self.memory,
+            }),
             // Cannot convert bytes into a mutable vec
             Inner::Bytes(_) => Err(self),
         }
@@ -110,7 +263,7 @@ impl Buffer {
 
     /// Convert a Buffer into an ArrowBuffer with no copying.
     pub fn into_arrow(self) -> ArrowBuffer {
-        match self.0 {
+        match self.inner {
             Inner::Arrow(a) => a,
             // This is cheeky. But it uses From<bytes::Bytes> for arrow_buffer::Bytes, even though
             // arrow_buffer::Bytes is only pub(crate). Seems weird...
@@ -150,43 +303,157 @@ impl AsRef<[u8]> for Buffer {
 
 impl From<&'static [u8]> for Buffer {
     fn from(value: &'static [u8]) -> Self {
-        Buffer(Inner::Bytes(bytes::Bytes::from_static(value)))
+        Self::new(
+            Inner::Bytes(bytes::Bytes::from_static(value)),
+            Memory::Static,
+        )
     }
 }
 
 impl From<&'static str> for Buffer {
     fn from(slice: &'static str) -> Buffer {
-        Buffer(Inner::Bytes(bytes::Bytes::from_static(slice.as_bytes())))
+        Self::new(
+            Inner::Bytes(bytes::Bytes::from_static(slice.as_bytes())),
+            Memory::Static,
+        )
     }
 }
 
 impl<T: ArrowNativeType> From<Vec<T>> for Buffer {
     fn from(value: Vec<T>) -> Self {
         // We prefer Arrow since it retains mutability
-        Buffer(Inner::Arrow(ArrowBuffer::from_vec(value)))
+        Self::new(Inner::Arrow(ArrowBuffer::from_vec(value)), Memory::owned())
     }
 }
 
 impl From<bytes::Bytes> for Buffer {
     fn from(value: bytes::Bytes) -> Self {
-        Buffer(Inner::Bytes(value))
+        Self::new(Inner::Bytes(value), Memory::owned())
     }
 }
 
 impl From<ArrowBuffer> for Buffer {
     fn from(value: ArrowBuffer) -> Self {
-        Buffer(Inner::Arrow(value))
+        Self::new(Inner::Arrow(value), Memory::owned())
     }
 }
 
 impl From<ArrowMutableBuffer> for Buffer {
     fn from(value: ArrowMutableBuffer) -> Self {
-        Buffer(Inner::Arrow(ArrowBuffer::from(value)))
+        Self::new(Inner::Arrow(ArrowBuffer::from(value)), Memory::owned())
     }
 }
 
+/// The largest power of two, up to `max`, that divides `n`, given that `max` is a power of two.
+fn lowest_alignment(n: usize, max: usize) -> usize {
+    1 << (n | max).trailing_zeros()
+}
+
+fn check_alignment(alignment: usize) {
+    assert!(
+        alignment.is_power_of_two(),
+        "Alignment must be a power of two, got {alignment}"
+    );
+}
+
+/// Allocate a zeroed buffer holding `len` bytes from an offset aligned to `alignment` bytes.
+fn zeroed_aligned(len: usize, alignment: usize) -> (ArrowMutableBuffer, usize) {
+    check_alignment(alignment);
+    let buffer = ArrowMutableBuffer::from_len_zeroed(len);
+    if buffer.as_ptr().align_offset(alignment) == 0 {
+        return (buffer, 0);
+    }
+
+    // Over-allocate so that an aligned range of the given length fits within the allocation.
+    let buffer = ArrowMutableBuffer::from_len_zeroed(len + alignment - 1);
+    let offset = buffer.as_ptr().align_offset(alignment);
+    (buffer, offset)
+}
+
 impl FromIterator<u8> for Buffer {
     fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
-        Buffer(Inner::Arrow(ArrowBuffer::from_iter(iter)))
+        Self::new(Inner::Arrow(ArrowBuffer::from_iter(iter)), Memory::owned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Buffer, DEFAULT_ALIGNMENT};
+
+    #[test]
+    fn aligned_allocations() {
+        let buffer = Buffer::from_len_zeroed(100);
+        assert_eq!(buffer.alignment(), DEFAULT_ALIGNMENT);
+        assert!(buffer.is_aligned_to(DEFAULT_ALIGNMENT));
+        assert!(!buffer.is_aligned_to(3));
+
+        let buffer = Buffer::from_len_zeroed_aligned(100, 4096);
+        assert_eq!(buffer.len(), 100);
+        assert_eq!(buffer.alignment(), 4096);
+        assert!(buffer.is_aligned_to(4096));
+    }
+
+    #[test]
+    fn owned_allocations_are_not_copied() {
+        for len in [0u16, 1, 3, 17, 100, 4096] {
+            let values = (0..len).collect::<Vec<_>>();
+            let ptr = values.as_ptr();
+            let buffer = Buffer::from(values);
+            assert_eq!(buffer.as_ptr(), ptr.cast());
+            assert!(buffer.alignment() >= align_of::<u16>());
+            assert!(buffer.is_aligned_to(buffer.alignment()));
+            assert!(buffer
+                .chunks_exact(2)
+                .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+                .eq(0..len));
+
+            let aligned = buffer.clone().aligned(DEFAULT_ALIGNMENT);
+            assert!(aligned.is_aligned_to(DEFAULT_ALIGNMENT));
+            assert_eq!(aligned, buffer);
+
+            let collected = (0..len).flat_map(u16::to_le_bytes).collect::<Buffer>();
+            assert_eq!(collected, buffer);
+        }
+    }
+
+    #[test]
+    fn slice_preserves_alignment() {
+        let buffer = Buffer::from_len_zeroed(256);
+        assert_eq!(buffer.slice(128..256).alignment(), 64);
+        assert_eq!(buffer.slice(8..256).alignment(), 8);
+        assert_eq!(buffer.slice(8..256).slice(56..100).alignment(), 8);
+        assert!(buffer.slice(32..64).is_aligned_to(32));
+    }
+
+    #[test]
+    fn realign() {
+        let buffer = Buffer::from((0..100u8).collect::<Vec<_>>()).slice(1..100);
+        assert_eq!(buffer.alignment(), 1);
+
+        let aligned = buffer.clone().aligned(128);
+        assert_eq!(aligned.alignment(), 128);
+        assert!(aligned.is_aligned_to(128));
+        assert_eq!(aligned, buffer);
+    }
+
+    #[test]
+    fn shared_memory() {
+        let buffer = Buffer::from(vec![0u8; 100]);
+        let clone = buffer.clone();
+        assert!(!buffer.is_shared());
+        assert!(!clone.is_shared());
+
+        // A slice shares the memory of its buffer until the buffer is dropped.
+        let sliced = buffer.slice(10..20).slice(2..5);
+        assert!(sliced.is_shared());
+        drop(buffer);
+        assert!(sliced.is_shared());
+        drop(clone);
+        assert!(!sliced.is_shared());
+
+        assert!(Buffer::from(&b"static"[..]).is_shared());
+        let bytes = Buffer::from(bytes::Bytes::from(vec![0u8; 10]));
+        assert!(!bytes.is_shared());
+        assert!(bytes.slice(0..5).is_shared());
     }
 }
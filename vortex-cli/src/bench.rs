@@ -0,0 +1,50 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use futures::TryStreamExt;
+use humansize::{format_size, DECIMAL};
+use vortex::error::{vortex_bail, VortexResult};
+use vortex::{IntoArrayData, IntoCanonical};
+
+use crate::read_file;
+
+/// Read the file `iterations` times, canonicalizing every chunk, and report the time of each scan.
+pub async fn scan(
+    path: &Path,
+    iterations: usize,
+    columns: Option<Vec<String>>,
+) -> VortexResult<()> {
+    if iterations == 0 {
+        vortex_bail!("Must run at least one iteration");
+    }
+
+    let mut times = Vec::with_capacity(iterations);
+    for iteration in 0..iterations {
+        let start = Instant::now();
+        let mut stream = read_file(path, columns.clone()).await?;
+        let mut rows = 0;
+        let mut nbytes = 0;
+        while let Some(chunk) = stream.try_next().await? {
+            rows += chunk.len();
+            nbytes += chunk.into_canonical()?.into_array().nbytes();
+        }
+        let elapsed = start.elapsed();
+        times.push(elapsed);
+
+        println!(
+            "Scan {iteration}: {rows} rows ({} decompressed) in {:.3}s, {:.0} rows/s",
+            format_size(nbytes, DECIMAL),
+            elapsed.as_secs_f64(),
+            rows as f64 / elapsed.as_secs_f64()
+        );
+    }
+
+    times.sort();
+    println!(
+        "Min {:.3}s, median {:.3}s, mean {:.3}s",
+        times[0].as_secs_f64(),
+        times[times.len() / 2].as_secs_f64(),
+        (times.iter().sum::<Duration>() / u32::try_from(times.len())?).as_secs_f64()
+    );
+    Ok(())
+}
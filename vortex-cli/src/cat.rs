@@ -0,0 +1,41 @@
+use std::io::{stdout, BufWriter, Write};
+use std::path::Path;
+
+use arrow::ipc::writer::StreamWriter;
+use arrow::json::LineDelimitedWriter;
+use arrow::record_batch::RecordBatch;
+use futures::TryStreamExt;
+use vortex::arrow::infer_schema;
+use vortex::error::VortexResult;
+
+use crate::{read_file, CatFormat};
+
+pub async fn exec(
+    path: &Path,
+    format: CatFormat,
+    columns: Option<Vec<String>>,
+) -> VortexResult<()> {
+    let mut stream = read_file(path, columns).await?;
+    let out = BufWriter::new(stdout().lock());
+
+    match format {
+        CatFormat::Json => {
+            let mut writer = LineDelimitedWriter::new(out);
+            while let Some(chunk) = stream.try_next().await? {
+                writer.write(&RecordBatch::try_from(chunk)?)?;
+            }
+            writer.finish()?;
+            writer.into_inner().flush()?;
+        }
+        CatFormat::Arrow => {
+            let schema = infer_schema(stream.dtype())?;
+            let mut writer = StreamWriter::try_new(out, &schema)?;
+            while let Some(chunk) = stream.try_next().await? {
+                writer.write(&RecordBatch::try_from(chunk)?)?;
+            }
+            writer.finish()?;
+            writer.into_inner()?.flush()?;
+        }
+    }
+    Ok(())
+}
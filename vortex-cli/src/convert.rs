@@ -0,0 +1,44 @@
+use std::fs::File;
+use std::path::Path;
+
+use futures::stream;
+use humansize::{format_size, DECIMAL};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use vortex::arrow::FromArrowType;
+use vortex::compress::CompressionStrategy;
+use vortex::dtype::DType;
+use vortex::error::VortexResult;
+use vortex::file::VortexFileWriter;
+use vortex::sampling_compressor::SamplingCompressor;
+use vortex::stream::ArrayStreamAdapter;
+use vortex::ArrayData;
+
+pub async fn exec(input: &Path, output: &Path, batch_size: usize) -> VortexResult<()> {
+    let builder =
+        ParquetRecordBatchReaderBuilder::try_new(File::open(input)?)?.with_batch_size(batch_size);
+    let dtype = DType::from_arrow(builder.schema().clone());
+    let reader = builder.build()?;
+
+    // Compress each batch as it is read, rather than holding the whole file in memory.
+    let compressor = SamplingCompressor::default();
+    let chunks = reader.map(move |batch| {
+        let chunk = ArrayData::try_from(batch?)?;
+        CompressionStrategy::compress(&compressor, &chunk)
+    });
+
+    let file = tokio::fs::File::create(output).await?;
+    VortexFileWriter::new(file)
+        .write_array_columns_stream(ArrayStreamAdapter::new(dtype, stream::iter(chunks)))
+        .await?
+        .finalize()
+        .await?;
+
+    println!(
+        "Wrote {} ({}) from {} ({})",
+        output.display(),
+        format_size(tokio::fs::metadata(output).await?.len(), DECIMAL),
+        input.display(),
+        format_size(tokio::fs::metadata(input).await?.len(), DECIMAL),
+    );
+    Ok(())
+}
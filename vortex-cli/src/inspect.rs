@@ -0,0 +1,145 @@
+use std::io::{stdout, BufWriter, Write};
+use std::path::Path;
+
+use futures::TryStreamExt;
+use humansize::{format_size, DECIMAL};
+use vortex::error::VortexResult;
+use vortex::file::{
+    read_initial_bytes, verify_layout, LayoutId, CHUNKED_LAYOUT_ID, COLUMNAR_LAYOUT_ID,
+    FLAT_LAYOUT_ID,
+};
+use vortex::flatbuffers::footer;
+use vortex::io::{TokioFile, VortexReadAt};
+use vortex::variants::StructArrayTrait;
+use vortex::{ArrayLen, IntoArrayVariant};
+
+use crate::read_file;
+
+pub async fn exec(path: &Path) -> VortexResult<()> {
+    let mut out = BufWriter::new(stdout().lock());
+    inspect(path, &mut out).await?;
+    Ok(out.flush()?)
+}
+
+/// Write the footer and layout of the file, then the columns of each chunk as it's read.
+async fn inspect(path: &Path, out: &mut impl Write) -> VortexResult<()> {
+    let file = TokioFile::open(path)?;
+    let file_size = file.size().await?;
+    let initial_read = read_initial_bytes(&file, file_size).await?;
+    let postscript = initial_read.fb_postscript();
+
+    writeln!(out, "File: {}", path.display())?;
+    writeln!(out, "Size: {}", format_size(file_size, DECIMAL))?;
+    writeln!(out, "Version: {}", initial_read.version)?;
+    writeln!(
+        out,
+        "Footer: schema at byte {}, layout at byte {}",
+        postscript.schema_offset(),
+        postscript.layout_offset()
+    )?;
+    writeln!(out, "DType: {}", initial_read.lazy_dtype().value()?)?;
+
+    writeln!(out, "\nLayout:")?;
+    write_layout(out, initial_read.fb_layout(), 1)?;
+
+    writeln!(out, "\nChunks:")?;
+    let mut stream = read_file(path, None).await?;
+    let mut chunk_idx = 0;
+    while let Some(chunk) = stream.try_next().await? {
+        let chunk = chunk.into_struct()?;
+        writeln!(out, "chunk {chunk_idx} ({} rows):", chunk.len())?;
+        for (idx, name) in chunk.names().iter().enumerate() {
+            let Some(column) = chunk.field(idx) else {
+                continue;
+            };
+            writeln!(out, "  {name}:\n{}", column.tree_display())?;
+        }
+        chunk_idx += 1;
+    }
+
+    Ok(())
+}
+
+/// Write a verified layout and its children, verifying each child before reading it.
+fn write_layout(
+    out: &mut impl Write,
+    layout: footer::Layout<'_>,
+    depth: usize,
+) -> VortexResult<()> {
+    let encoding = match LayoutId(layout.encoding()) {
+        FLAT_LAYOUT_ID => "flat",
+        CHUNKED_LAYOUT_ID => "chunked",
+        COLUMNAR_LAYOUT_ID => "columnar",
+        _ => "unknown",
+    };
+    let (buffer_count, nbytes) = layout.buffers().map_or((0, 0), |buffers| {
+        (
+            buffers.len(),
+            buffers.iter().map(|b| b.end() - b.begin()).sum::<u64>(),
+        )
+    });
+    writeln!(
+        out,
+        "{:indent$}{encoding} ({} rows, {buffer_count} buffers, {})",
+        "",
+        layout.row_count(),
+        format_size(nbytes, DECIMAL),
+        indent = depth * 2
+    )?;
+    if let Some(children) = layout.children() {
+        for child in children.iter() {
+            verify_layout(&child)?;
+            write_layout(out, child, depth + 1)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{ChunkedArray, PrimitiveArray, StructArray};
+    use vortex::file::{VortexFileWriter, VERSION};
+    use vortex::IntoArrayData;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn inspect_streams_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.vortex");
+        let numbers = ChunkedArray::from_iter([
+            PrimitiveArray::from(vec![1u32, 2, 3]).into_array(),
+            PrimitiveArray::from(vec![4u32, 5]).into_array(),
+        ])
+        .into_array();
+        let array = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+        VortexFileWriter::new(tokio::fs::File::create(&path).await.unwrap())
+            .write_array_columns(array.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+
+        let mut out = Vec::new();
+        inspect(&path, &mut out).await.unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains(&format!("Version: {VERSION}\n")));
+        assert!(out.contains("\nLayout:\n  columnar (5 rows, 0 buffers, 0 B)\n"));
+        let chunks = out.split_once("\nChunks:\n").unwrap().1;
+        assert!(chunks.starts_with("chunk 0 (3 rows):\n  numbers:\n"));
+        assert!(chunks.contains("\nchunk 1 (2 rows):\n  numbers:\n"));
+        assert!(!chunks.contains("chunk 2"));
+    }
+
+    #[tokio::test]
+    async fn inspect_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut out = Vec::new();
+        assert!(inspect(&dir.path().join("missing.vortex"), &mut out)
+            .await
+            .is_err());
+        assert!(out.is_empty());
+    }
+}
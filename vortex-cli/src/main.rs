@@ -0,0 +1,129 @@
+//! The `vortex` command line tool, for inspecting, converting, printing and benchmarking Vortex
+//! files.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use vortex::dtype::field::Field;
+use vortex::error::VortexResult;
+use vortex::file::{
+    LayoutContext, LayoutDeserializer, Projection, VortexFileArrayStream, VortexReadBuilder,
+};
+use vortex::io::TokioFile;
+use vortex::sampling_compressor::ALL_ENCODINGS_CONTEXT;
+
+mod bench;
+mod cat;
+mod convert;
+mod inspect;
+
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the footer, the layout tree, and the encodings and statistics of each column of a
+    /// Vortex file.
+    Inspect { file: PathBuf },
+    /// Compress a Parquet file into a Vortex file.
+    Convert {
+        input: PathBuf,
+        output: PathBuf,
+        /// The number of rows in each chunk of the Vortex file.
+        #[arg(long, default_value_t = 65_536)]
+        batch_size: usize,
+    },
+    /// Write the rows of a Vortex file to stdout.
+    Cat {
+        file: PathBuf,
+        #[arg(short, long, value_enum, default_value_t = CatFormat::Json)]
+        format: CatFormat,
+        /// The columns to print, defaulting to all of them.
+        #[arg(short, long, value_delimiter = ',')]
+        columns: Option<Vec<String>>,
+    },
+    /// Benchmark reading a Vortex file.
+    Bench {
+        #[command(subcommand)]
+        bench: BenchCommand,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum CatFormat {
+    /// One JSON object per row.
+    Json,
+    /// An Arrow IPC stream.
+    Arrow,
+}
+
+#[derive(Subcommand)]
+enum BenchCommand {
+    /// Time full scans of a Vortex file.
+    Scan {
+        file: PathBuf,
+        #[arg(short, long, default_value_t = 5)]
+        iterations: usize,
+        /// The columns to read, defaulting to all of them.
+        #[arg(short, long, value_delimiter = ',')]
+        columns: Option<Vec<String>>,
+    },
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Inspect { file } => inspect::exec(&file).await,
+        Command::Convert {
+            input,
+            output,
+            batch_size,
+        } => convert::exec(&input, &output, batch_size).await,
+        Command::Cat {
+            file,
+            format,
+            columns,
+        } => cat::exec(&file, format, columns).await,
+        Command::Bench {
+            bench:
+                BenchCommand::Scan {
+                    file,
+                    iterations,
+                    columns,
+                },
+        } => bench::scan(&file, iterations, columns).await,
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Open a stream over the given columns of a Vortex file, or all of them if there are none.
+async fn read_file(
+    path: &Path,
+    columns: Option<Vec<String>>,
+) -> VortexResult<VortexFileArrayStream<TokioFile>> {
+    let projection = columns.map_or(Projection::All, |columns| {
+        Projection::Flat(columns.iter().map(|c| Field::from(c.as_str())).collect())
+    });
+    VortexReadBuilder::new(
+        TokioFile::open(path)?,
+        LayoutDeserializer::new(
+            ALL_ENCODINGS_CONTEXT.clone(),
+            LayoutContext::default().into(),
+        ),
+    )
+    .with_projection(projection)
+    .build()
+    .await
+}
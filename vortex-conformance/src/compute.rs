@@ -0,0 +1,211 @@
+//! Checks of the compute functions an encoding implements against the canonical results.
+
+use vortex_array::array::{ConstantArray, PrimitiveArray};
+use vortex_array::compute::{
+    binary_boolean, compare, filter, invert, scalar_at, slice, take, try_cast, BinaryOperator,
+    FilterMask, Operator,
+};
+use vortex_array::validity::ArrayValidity;
+use vortex_array::{ArrayDType, ArrayData, IntoArrayData};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::assert_same;
+
+const OPERATORS: [Operator; 6] = [
+    Operator::Eq,
+    Operator::NotEq,
+    Operator::Gt,
+    Operator::Gte,
+    Operator::Lt,
+    Operator::Lte,
+];
+
+const BOOLEAN_OPERATORS: [(&str, BinaryOperator); 5] = [
+    ("and", BinaryOperator::And),
+    ("and_kleene", BinaryOperator::AndKleene),
+    ("or", BinaryOperator::Or),
+    ("or_kleene", BinaryOperator::OrKleene),
+    ("xor", BinaryOperator::Xor),
+];
+
+/// Check each compute function implemented by the encoding of `encoded`.
+///
+/// Where the canonical array doesn't support a function for its dtype, e.g. comparing structs,
+/// there's nothing to check the encoding against and the function is skipped.
+pub(crate) fn check_all(encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+    check_scalar_at(encoded, canonical)?;
+    check_slice(encoded, canonical)?;
+    check_take(encoded, canonical)?;
+    check_filter(encoded, canonical)?;
+    check_compare(encoded, canonical)?;
+    check_cast(encoded, canonical)?;
+    if matches!(canonical.dtype(), DType::Bool(_)) {
+        check_invert(encoded, canonical)?;
+        check_binary_boolean(encoded, canonical)?;
+    }
+    Ok(())
+}
+
+fn check_scalar_at(encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+    let Some(f) = encoded.encoding().scalar_at_fn() else {
+        return Ok(());
+    };
+    // Kernels are only called for valid indices, nulls are resolved from the validity.
+    for idx in (0..canonical.len()).filter(|&idx| canonical.is_valid(idx)) {
+        let actual = f.scalar_at(encoded, idx)?;
+        let expected = scalar_at(canonical, idx)?;
+        if actual != expected {
+            vortex_bail!("scalar_at({idx}) is {actual} instead of {expected}");
+        }
+    }
+    Ok(())
+}
+
+fn check_slice(encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+    let Some(f) = encoded.encoding().slice_fn() else {
+        return Ok(());
+    };
+    let len = canonical.len();
+    let ranges = [
+        (0, 0),
+        (0, len),
+        (len, len),
+        (0, 1),
+        (len.saturating_sub(1), len),
+        (1, len.saturating_sub(1)),
+        (len / 3, 2 * len / 3),
+    ];
+    for (start, stop) in ranges {
+        if start > stop || stop > len {
+            continue;
+        }
+        let sliced = f.slice(encoded, start, stop)?;
+        let expected = slice(canonical, start, stop)?;
+        assert_same(&format!("slice({start}, {stop})"), &sliced, &expected)?;
+
+        // Slicing again exercises the offsets carried by the sliced array.
+        if stop - start >= 2 {
+            assert_same(
+                &format!("slice({start}, {stop}).slice(1, {})", stop - start - 1),
+                &slice(&sliced, 1, stop - start - 1)?,
+                &slice(&expected, 1, stop - start - 1)?,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn check_take(encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+    let Some(f) = encoded.encoding().take_fn() else {
+        return Ok(());
+    };
+    let len = canonical.len() as u64;
+    let index_sets = [
+        ("no indices", Vec::new()),
+        ("the first index", vec![0]),
+        ("reversed indices", (0..len).rev().collect()),
+        (
+            "repeated indices",
+            vec![len.saturating_sub(1), 0, len.saturating_sub(1), 0],
+        ),
+        ("every other index", (0..len).step_by(2).collect()),
+    ];
+    for (name, indices) in index_sets {
+        if indices.iter().any(|&idx| idx >= len) {
+            continue;
+        }
+        let indices = PrimitiveArray::from(indices).into_array();
+        assert_same(
+            &format!("take of {name}"),
+            &f.take(encoded, &indices)?,
+            &take(canonical, &indices)?,
+        )?;
+    }
+    Ok(())
+}
+
+/// Whether to keep the row at an index, given the length of the array.
+type MaskPredicate = fn(usize, usize) -> bool;
+
+fn check_filter(encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+    let Some(f) = encoded.encoding().filter_fn() else {
+        return Ok(());
+    };
+    let len = canonical.len();
+    let masks: [(&str, MaskPredicate); 4] = [
+        ("all true", |_, _| true),
+        ("all false", |_, _| false),
+        ("every third false", |idx, _| idx % 3 != 0),
+        ("first half", |idx, len| idx < len / 2),
+    ];
+    for (name, predicate) in masks {
+        let mask = FilterMask::from_iter((0..len).map(|idx| predicate(idx, len)));
+        assert_same(
+            &format!("filter with {name}"),
+            &f.filter(encoded, mask.clone())?,
+            &filter(canonical, mask)?,
+        )?;
+    }
+    Ok(())
+}
+
+fn check_compare(encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+    let Some(f) = encoded.encoding().compare_fn() else {
+        return Ok(());
+    };
+    if canonical.is_empty() {
+        return Ok(());
+    }
+    let rhs = ConstantArray::new(scalar_at(canonical, canonical.len() / 2)?, canonical.len())
+        .into_array();
+    for operator in OPERATORS {
+        let Ok(expected) = compare(canonical, &rhs, operator) else {
+            return Ok(());
+        };
+        if let Some(actual) = f.compare(encoded, &rhs, operator)? {
+            assert_same(&format!("compare {operator}"), &actual, &expected)?;
+        }
+    }
+    Ok(())
+}
+
+fn check_cast(encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+    let Some(f) = encoded.encoding().cast_fn() else {
+        return Ok(());
+    };
+    let dtype = canonical.dtype().as_nullable();
+    let Ok(expected) = try_cast(canonical, &dtype) else {
+        return Ok(());
+    };
+    assert_same(
+        &format!("cast to {dtype}"),
+        &f.cast(encoded, &dtype)?,
+        &expected,
+    )
+}
+
+fn check_invert(encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+    let Some(f) = encoded.encoding().invert_fn() else {
+        return Ok(());
+    };
+    assert_same("invert", &f.invert(encoded)?, &invert(canonical)?)
+}
+
+fn check_binary_boolean(encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+    let Some(f) = encoded.encoding().binary_boolean_fn() else {
+        return Ok(());
+    };
+    // Combine the array with itself reversed, so that every pairing of values and nulls occurs.
+    let len = canonical.len() as u64;
+    let other = take(
+        canonical,
+        PrimitiveArray::from((0..len).rev().collect::<Vec<_>>()).into_array(),
+    )?;
+    for (name, op) in BOOLEAN_OPERATORS {
+        if let Some(actual) = f.binary_boolean(encoded, &other, op)? {
+            assert_same(name, &actual, &binary_boolean(canonical, &other, op)?)?;
+        }
+    }
+    Ok(())
+}
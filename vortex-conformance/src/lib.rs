@@ -0,0 +1,257 @@
+//! A conformance suite for Vortex array encodings.
+//!
+//! Given an encoding and a function that encodes canonical arrays with it, a [`ConformanceSuite`]
+//! checks that the encoded arrays decode back to their input, survive an IPC round trip, report
+//! the same validity, and that every compute function the encoding implements agrees with the same
+//! function on the canonical array. Each input is also checked when sliced, and as the empty and
+//! all-null arrays of its dtype, so that in-tree and third-party encodings get the same coverage.
+//!
+//! The suite is meant to be run from a test of the crate defining the encoding:
+//!
+//! ```ignore
+//! #[test]
+//! fn conformance() {
+//!     ConformanceSuite::new(&MyEncoding, |array| Ok(MyArray::encode(array)?.into_array()))
+//!         .with_input(PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3)]))
+//!         .run()
+//!         .unwrap();
+//! }
+//! ```
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use vortex_array::array::ConstantArray;
+use vortex_array::compute::{array_compare_elementwise, slice};
+use vortex_array::encoding::EncodingRef;
+use vortex_array::iter::ArrayIteratorExt;
+use vortex_array::validity::ArrayValidity;
+use vortex_array::{ArrayDType, ArrayData, Context, IntoArrayData, IntoCanonical, ValidationLevel};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_ipc::iterator::{ArrayIteratorIPC, SyncIPCReader};
+use vortex_scalar::Scalar;
+
+mod compute;
+
+/// The maximum number of differing values reported when an array differs from its canonical
+/// counterpart.
+const MAX_DIFFERENCES: usize = 8;
+
+/// A function encoding a canonical array with the encoding under test.
+pub type EncodeFn = dyn Fn(&ArrayData) -> VortexResult<ArrayData>;
+
+/// The set of checks run against a single encoding.
+pub struct ConformanceSuite {
+    encoding: EncodingRef,
+    encode: Box<EncodeFn>,
+    inputs: Vec<ArrayData>,
+    ctx: Arc<Context>,
+}
+
+impl ConformanceSuite {
+    /// Create a suite for `encoding`, using `encode` to encode canonical inputs with it.
+    pub fn new<F>(encoding: EncodingRef, encode: F) -> Self
+    where
+        F: Fn(&ArrayData) -> VortexResult<ArrayData> + 'static,
+    {
+        Self {
+            encoding,
+            encode: Box::new(encode),
+            inputs: Vec::new(),
+            ctx: Arc::new(Context::default().with_encoding(encoding)),
+        }
+    }
+
+    /// Add an input to check. Inputs are canonicalized before being encoded, and must encode to
+    /// the encoding under test.
+    pub fn with_input(mut self, input: impl IntoArrayData) -> Self {
+        self.inputs.push(input.into_array());
+        self
+    }
+
+    /// Read arrays back from IPC with the given context, for encodings whose children use other
+    /// encodings than the default ones.
+    pub fn with_context(mut self, ctx: Arc<Context>) -> Self {
+        self.ctx = ctx;
+        self
+    }
+
+    /// Run every check against every input, failing with the first check that doesn't hold.
+    ///
+    /// The empty and all-null arrays derived from each input are only checked if the encode
+    /// function accepts them, since many encodings decline to encode trivial arrays.
+    pub fn run(&self) -> VortexResult<()> {
+        if self.inputs.is_empty() {
+            vortex_bail!("No inputs to check {} against", self.encoding.id());
+        }
+
+        for (idx, input) in self.inputs.iter().enumerate() {
+            let canonical = input.clone().into_canonical()?.into_array();
+            let encoded = (self.encode)(&canonical)
+                .map_err(|e| e.with_context(format!("Failed to encode input {idx}")))?;
+            if encoded.encoding().id() != self.encoding.id() {
+                vortex_bail!(
+                    "Input {idx} was encoded as {} instead of {}",
+                    encoded.encoding().id(),
+                    self.encoding.id()
+                );
+            }
+            self.check(&encoded, &canonical)
+                .map_err(|e| e.with_context(format!("{} input {idx}", self.encoding.id())))?;
+
+            for (name, derived) in derived_inputs(&canonical)? {
+                let Some(encoded) = (self.encode)(&derived)
+                    .ok()
+                    .filter(|encoded| encoded.encoding().id() == self.encoding.id())
+                else {
+                    continue;
+                };
+                self.check(&encoded, &derived).map_err(|e| {
+                    e.with_context(format!("{} {name} input {idx}", self.encoding.id()))
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check an encoded array, and the slice of it without its first and last elements if that
+    /// keeps the encoding, against its canonical counterpart.
+    fn check(&self, encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+        self.check_array(encoded, canonical)?;
+
+        let len = canonical.len();
+        if len >= 3 {
+            let sliced = slice(encoded, 1, len - 1)?;
+            if sliced.encoding().id() == self.encoding.id() {
+                self.check_array(&sliced, &slice(canonical, 1, len - 1)?)
+                    .map_err(|e| e.with_context(format!("slice 1..{}", len - 1)))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_array(&self, encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+        assert_same(
+            "into_canonical",
+            &encoded.clone().into_canonical()?.into_array(),
+            canonical,
+        )?;
+        check_validity(encoded, canonical)?;
+        self.check_ipc(encoded, canonical)?;
+        compute::check_all(encoded, canonical)
+    }
+
+    /// Write the array to an IPC stream and read it back, expecting a valid array tree of the same
+    /// encoding and values.
+    fn check_ipc(&self, encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+        let buffer = encoded
+            .clone()
+            .into_array_iterator()
+            .into_ipc()
+            .collect_to_buffer()?;
+        let read =
+            SyncIPCReader::try_new(Cursor::new(buffer), self.ctx.clone())?.into_array_data()?;
+        read.validate(ValidationLevel::Buffers)?;
+        if read.encoding().id() != encoded.encoding().id() {
+            vortex_bail!(
+                "IPC round trip read {} back as {}",
+                encoded.encoding().id(),
+                read.encoding().id()
+            );
+        }
+        assert_same("IPC round trip", &read, canonical)
+    }
+}
+
+/// The empty array, and if the dtype is nullable the all-null array, of the input's dtype and
+/// length.
+fn derived_inputs(canonical: &ArrayData) -> VortexResult<Vec<(&'static str, ArrayData)>> {
+    let mut derived = vec![("empty", slice(canonical, 0, 0)?)];
+    if canonical.dtype().is_nullable() {
+        // Not every dtype can be canonicalized from a constant, in which case there's no all-null
+        // input to check.
+        if let Ok(all_null) =
+            ConstantArray::new(Scalar::null(canonical.dtype().clone()), canonical.len())
+                .into_array()
+                .into_canonical()
+        {
+            derived.push(("all-null", all_null.into_array()));
+        }
+    }
+    Ok(derived)
+}
+
+fn check_validity(encoded: &ArrayData, canonical: &ArrayData) -> VortexResult<()> {
+    for idx in 0..canonical.len() {
+        if encoded.is_valid(idx) != canonical.is_valid(idx) {
+            vortex_bail!(
+                "is_valid({idx}) is {}, but {} in the canonical array",
+                encoded.is_valid(idx),
+                canonical.is_valid(idx)
+            );
+        }
+    }
+
+    let null_count = encoded.logical_validity().null_count()?;
+    let expected = canonical.logical_validity().null_count()?;
+    if null_count != expected {
+        vortex_bail!("Logical validity has {null_count} nulls instead of {expected}");
+    }
+    Ok(())
+}
+
+/// Fail if the result of the named check differs from the expected canonical result.
+fn assert_same(check: &str, actual: &ArrayData, expected: &ArrayData) -> VortexResult<()> {
+    let diff = array_compare_elementwise(actual, expected, MAX_DIFFERENCES)?;
+    if !diff.is_empty() {
+        vortex_bail!("{check} differs from the canonical result: {diff}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::{BoolArray, PrimitiveArray};
+    use vortex_array::IntoArrayVariant;
+    use vortex_fastlanes::{for_compress, FoREncoding};
+    use vortex_runend::{RunEndArray, RunEndEncoding};
+
+    use super::*;
+
+    #[test]
+    fn frame_of_reference() {
+        ConformanceSuite::new(&FoREncoding, |array| {
+            Ok(for_compress(&array.clone().into_primitive()?)?.into_array())
+        })
+        .with_input(PrimitiveArray::from((100i32..200).collect::<Vec<_>>()))
+        .with_input(PrimitiveArray::from_nullable_vec(
+            (0u64..50)
+                .map(|i| (i % 7 != 0).then_some(1000 + i * 3))
+                .collect(),
+        ))
+        .run()
+        .unwrap();
+    }
+
+    #[test]
+    fn run_end() {
+        ConformanceSuite::new(&RunEndEncoding, |array| {
+            Ok(RunEndArray::encode(array.clone())?.into_array())
+        })
+        .with_input(PrimitiveArray::from(
+            (0i64..100).map(|i| i / 10).collect::<Vec<_>>(),
+        ))
+        .run()
+        .unwrap();
+    }
+
+    #[test]
+    fn wrong_encoding_fails() {
+        let err = ConformanceSuite::new(&RunEndEncoding, |array| Ok(array.clone()))
+            .with_input(BoolArray::from_iter([true, false]))
+            .run()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("instead of"), "{err}");
+    }
+}
@@ -124,8 +124,11 @@ fn can_be_pushed_down(expr: &Expr, schema: &Schema) -> bool {
             Some((_, field)) => supported_data_types(field.data_type().clone()),
             _ => false,
         },
+        // Physical LIKE expressions, from which filters are converted, have no escape character.
         Expr::Like(like) => {
-            can_be_pushed_down(&like.expr, schema) && can_be_pushed_down(&like.pattern, schema)
+            like.escape_char.is_none()
+                && can_be_pushed_down(&like.expr, schema)
+                && can_be_pushed_down(&like.pattern, schema)
         }
         Expr::Literal(lit) => supported_data_types(lit.data_type()),
         _ => {
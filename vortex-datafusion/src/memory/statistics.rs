@@ -4,7 +4,7 @@ use itertools::Itertools;
 use vortex_array::array::ChunkedArray;
 use vortex_array::stats::{ArrayStatistics, Stat};
 use vortex_array::variants::StructArrayTrait;
-use vortex_array::ArrayLen;
+use vortex_array::{ArrayDType, ArrayLen};
 use vortex_error::{vortex_err, VortexExpect, VortexResult};
 
 pub fn chunked_array_df_stats(array: &ChunkedArray, projection: &[usize]) -> DFResult<Statistics> {
@@ -18,6 +18,8 @@ pub fn chunked_array_df_stats(array: &ChunkedArray, projection: &[usize]) -> DFR
         })
         .map_ok(|arr| {
             nbytes += arr.nbytes();
+            // The bounds of struct columns are per field, which DataFusion has no notion of
+            let has_bounds = !arr.dtype().is_struct();
             ColumnStatistics {
                 null_count: arr
                     .statistics()
@@ -28,6 +30,7 @@ pub fn chunked_array_df_stats(array: &ChunkedArray, projection: &[usize]) -> DFR
                 max_value: arr
                     .statistics()
                     .get(Stat::Max)
+                    .filter(|_| has_bounds)
                     .map(|n| {
                         ScalarValue::try_from(n).vortex_expect("cannot convert scalar to df scalar")
                     })
@@ -36,6 +39,7 @@ pub fn chunked_array_df_stats(array: &ChunkedArray, projection: &[usize]) -> DFR
                 min_value: arr
                     .statistics()
                     .get(Stat::Min)
+                    .filter(|_| has_bounds)
                     .map(|n| {
                         ScalarValue::try_from(n).vortex_expect("cannot convert scalar to df scalar")
                     })
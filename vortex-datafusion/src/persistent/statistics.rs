@@ -7,7 +7,7 @@ use datafusion_expr::Accumulator;
 use vortex_array::array::StructArray;
 use vortex_array::stats::Stat;
 use vortex_array::variants::StructArrayTrait as _;
-use vortex_array::IntoCanonical;
+use vortex_array::{ArrayDType, IntoCanonical};
 use vortex_error::VortexResult;
 
 pub fn array_to_col_statistics(array: &StructArray) -> VortexResult<ColumnStatistics> {
@@ -21,7 +21,11 @@ pub fn array_to_col_statistics(array: &StructArray) -> VortexResult<ColumnStatis
         stats.null_count = Precision::Exact(null_count as usize);
     }
 
-    if let Some(max_value_array) = array.field_by_name(Stat::Max.name()) {
+    // The bounds of struct columns are per field, which DataFusion has no notion of
+    if let Some(max_value_array) = array
+        .field_by_name(Stat::Max.name())
+        .filter(|a| !a.dtype().is_struct())
+    {
         let array = max_value_array.into_arrow()?;
         let mut acc = MaxAccumulator::try_new(array.data_type())?;
         acc.update_batch(&[array])?;
@@ -30,7 +34,10 @@ pub fn array_to_col_statistics(array: &StructArray) -> VortexResult<ColumnStatis
         stats.max_value = Precision::Exact(max_val)
     }
 
-    if let Some(min_value_array) = array.field_by_name(Stat::Min.name()) {
+    if let Some(min_value_array) = array
+        .field_by_name(Stat::Min.name())
+        .filter(|a| !a.dtype().is_struct())
+    {
         let array = min_value_array.into_arrow()?;
         let mut acc = MinAccumulator::try_new(array.data_type())?;
         acc.update_batch(&[array])?;
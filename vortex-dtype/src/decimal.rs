@@ -0,0 +1,160 @@
+use std::fmt::{Display, Formatter};
+
+use vortex_error::{vortex_bail, VortexExpect, VortexResult};
+
+/// The precision and scale of a fixed-point decimal type.
+///
+/// A decimal value is an integer number of units of `10^-scale`, with at most `precision`
+/// significant digits. Decimals with a precision of up to 38 digits are stored as 128-bit
+/// integers unless they are explicitly widened with [`DecimalDType::with_value_type`], larger ones
+/// as 256-bit integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecimalDType {
+    precision: u8,
+    scale: i8,
+    value_type: DecimalValueType,
+}
+
+impl DecimalDType {
+    /// The maximum precision of a decimal stored as a 128-bit integer
+    pub const MAX_DECIMAL128_PRECISION: u8 = 38;
+    /// The maximum precision of any decimal
+    pub const MAX_PRECISION: u8 = 76;
+
+    /// Creates a new `DecimalDType`, panicking if the precision or scale is invalid
+    pub fn new(precision: u8, scale: i8) -> Self {
+        Self::try_new(precision, scale).vortex_expect("Invalid decimal precision or scale")
+    }
+
+    /// Creates a new `DecimalDType`, checking that the precision is between 1 and
+    /// [`Self::MAX_PRECISION`] and that the scale is no larger than the precision.
+    pub fn try_new(precision: u8, scale: i8) -> VortexResult<Self> {
+        if precision == 0 || precision > Self::MAX_PRECISION {
+            vortex_bail!(
+                "Decimal precision {} must be between 1 and {}",
+                precision,
+                Self::MAX_PRECISION
+            );
+        }
+        if i16::from(scale) > i16::from(precision) {
+            vortex_bail!(
+                "Decimal scale {} is larger than its precision {}",
+                scale,
+                precision
+            );
+        }
+        let value_type = if precision <= Self::MAX_DECIMAL128_PRECISION {
+            DecimalValueType::I128
+        } else {
+            DecimalValueType::I256
+        };
+        Ok(Self {
+            precision,
+            scale,
+            value_type,
+        })
+    }
+
+    /// Store values as `value_type`, failing if it is too narrow for the precision.
+    ///
+    /// This keeps a decimal that arrived as 256-bit values, such as an Arrow `Decimal256`, at
+    /// that width even when its precision would fit in 128 bits.
+    pub fn with_value_type(self, value_type: DecimalValueType) -> VortexResult<Self> {
+        if value_type == DecimalValueType::I128 && self.precision > Self::MAX_DECIMAL128_PRECISION {
+            vortex_bail!(
+                "{} exceeds the maximum precision {} of a 128-bit decimal",
+                self,
+                Self::MAX_DECIMAL128_PRECISION
+            );
+        }
+        Ok(Self { value_type, ..self })
+    }
+
+    /// The maximum number of significant digits
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// The number of digits after the decimal point, which is negative if values are multiples of
+    /// a power of ten
+    pub fn scale(&self) -> i8 {
+        self.scale
+    }
+
+    /// The integer type that values of this decimal type are stored as
+    pub fn value_type(&self) -> DecimalValueType {
+        self.value_type
+    }
+}
+
+impl Display for DecimalDType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.value_type == DecimalValueType::I256
+            && self.precision <= Self::MAX_DECIMAL128_PRECISION
+        {
+            write!(f, "decimal256({}, {})", self.precision, self.scale)
+        } else {
+            write!(f, "decimal({}, {})", self.precision, self.scale)
+        }
+    }
+}
+
+/// The physical representation of decimal values, equivalent to Arrow's `Decimal128` and
+/// `Decimal256` types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DecimalValueType {
+    /// A 128-bit two's complement integer
+    I128,
+    /// A 256-bit two's complement integer
+    I256,
+}
+
+impl DecimalValueType {
+    /// The number of bytes in each value
+    pub const fn byte_width(&self) -> usize {
+        match self {
+            Self::I128 => 16,
+            Self::I256 => 32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DecimalDType, DecimalValueType};
+
+    #[test]
+    fn value_type() {
+        assert_eq!(
+            DecimalDType::new(38, 2).value_type(),
+            DecimalValueType::I128
+        );
+        assert_eq!(
+            DecimalDType::new(39, 2).value_type(),
+            DecimalValueType::I256
+        );
+    }
+
+    #[test]
+    fn widened() {
+        let wide = DecimalDType::new(10, 2)
+            .with_value_type(DecimalValueType::I256)
+            .unwrap();
+        assert_eq!(wide.value_type(), DecimalValueType::I256);
+        assert_ne!(wide, DecimalDType::new(10, 2));
+        assert_eq!(wide.to_string(), "decimal256(10, 2)");
+        assert!(DecimalDType::new(39, 2)
+            .with_value_type(DecimalValueType::I128)
+            .is_err());
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(DecimalDType::try_new(0, 0).is_err());
+        assert!(DecimalDType::try_new(77, 0).is_err());
+        assert!(DecimalDType::try_new(5, 6).is_err());
+        assert!(DecimalDType::try_new(5, -3).is_ok());
+    }
+}
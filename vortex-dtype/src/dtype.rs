@@ -8,7 +8,7 @@ use DType::*;
 
 use crate::field::Field;
 use crate::nullability::Nullability;
-use crate::{ExtDType, PType};
+use crate::{DecimalDType, ExtDType, PType};
 
 /// A name for a field in a struct
 pub type FieldName = Arc<str>;
@@ -37,6 +37,10 @@ pub enum DType {
     Struct(StructDType, Nullability),
     /// A variable-length list type, parameterized by a single element DType
     List(Arc<DType>, Nullability),
+    /// A list type whose lists all have the same number of elements of a single element DType
+    FixedSizeList(Arc<DType>, u32, Nullability),
+    /// Fixed-point decimal numbers with a given precision and scale
+    Decimal(DecimalDType, Nullability),
     /// User-defined extension types
     Extension(Arc<ExtDType>),
 }
@@ -62,6 +66,8 @@ impl DType {
             Binary(n) => matches!(n, Nullable),
             Struct(_, n) => matches!(n, Nullable),
             List(_, n) => matches!(n, Nullable),
+            FixedSizeList(_, _, n) => matches!(n, Nullable),
+            Decimal(_, n) => matches!(n, Nullable),
             Extension(ext_dtype) => ext_dtype.storage_dtype().is_nullable(),
         }
     }
@@ -86,6 +92,8 @@ impl DType {
             Binary(_) => Binary(nullability),
             Struct(st, _) => Struct(st.clone(), nullability),
             List(c, _) => List(c.clone(), nullability),
+            FixedSizeList(c, size, _) => FixedSizeList(c.clone(), *size, nullability),
+            Decimal(d, _) => Decimal(*d, nullability),
             Extension(ext) => Extension(Arc::new(ext.with_nullability(nullability))),
         }
     }
@@ -120,6 +128,11 @@ impl DType {
         PType::try_from(self).is_ok_and(PType::is_float)
     }
 
+    /// Check if `self` is a decimal
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, Decimal(..))
+    }
+
     /// Check if `self` is a boolean
     pub fn is_boolean(&self) -> bool {
         matches!(self, Bool(_))
@@ -140,6 +153,22 @@ impl DType {
             _ => None,
         }
     }
+
+    /// Get the `DecimalDType` if `self` is a `Decimal`, otherwise `None`
+    pub fn as_decimal(&self) -> Option<&DecimalDType> {
+        match self {
+            Decimal(d, _) => Some(d),
+            _ => None,
+        }
+    }
+
+    /// Get the inner dtype and list size if `self` is a `FixedSizeList`, otherwise `None`
+    pub fn as_fixed_size_list_element(&self) -> Option<(&DType, u32)> {
+        match self {
+            FixedSizeList(s, size, _) => Some((s.as_ref(), *size)),
+            _ => None,
+        }
+    }
 }
 
 impl Display for DType {
@@ -161,6 +190,8 @@ impl Display for DType {
                 n
             ),
             List(edt, n) => write!(f, "list({}){}", edt, n),
+            FixedSizeList(edt, size, n) => write!(f, "fixed_size_list({})[{}]{}", edt, size, n),
+            Decimal(decimal, n) => write!(f, "{}{}", decimal, n),
             Extension(ext) => write!(
                 f,
                 "ext({}, {}{}){}",
@@ -260,6 +291,87 @@ impl StructDType {
 
         Ok(StructDType::new(names.into(), dtypes))
     }
+
+    /// Rename a single field of the struct, keeping its position and dtype
+    /// Returns an error if the field is not found, or if another field already has the new name
+    pub fn rename(&self, field: &Field, new_name: FieldName) -> VortexResult<Self> {
+        let FieldInfo { index, .. } = self.field_info(field)?;
+        if let Some(existing) = self.find_name(&new_name) {
+            if existing != index {
+                vortex_bail!(
+                    "Cannot rename {} to {}: field already exists",
+                    field,
+                    new_name
+                );
+            }
+        }
+
+        let mut names = self.names.to_vec();
+        names[index] = new_name;
+        Ok(StructDType::new(names.into(), self.dtypes.to_vec()))
+    }
+
+    /// Reorder the fields of the struct
+    /// Returns an error if the new order does not reference every field exactly once
+    pub fn reorder(&self, order: &[Field]) -> VortexResult<Self> {
+        if order.len() != self.names.len() {
+            vortex_bail!(
+                "Reordering must reference all {} fields, got {}",
+                self.names.len(),
+                order.len()
+            );
+        }
+
+        let mut seen = vec![false; self.names.len()];
+        for field in order {
+            let FieldInfo { index, .. } = self.field_info(field)?;
+            if seen[index] {
+                vortex_bail!("Field {} referenced more than once in reordering", field);
+            }
+            seen[index] = true;
+        }
+
+        self.project(order)
+    }
+
+    /// Merge the fields of another struct into this one
+    ///
+    /// Fields of `self` keep their position, and fields only present in `other` are appended in
+    /// their order. Fields present in both must have compatible dtypes, i.e. equal ignoring
+    /// nullability, with nested structs merged recursively. The merged field is nullable if
+    /// either side is nullable.
+    pub fn merge(&self, other: &StructDType) -> VortexResult<Self> {
+        let mut names = self.names.to_vec();
+        let mut dtypes = self.dtypes.to_vec();
+
+        for (name, dtype) in other.names.iter().zip(other.dtypes.iter()) {
+            match self.find_name(name) {
+                Some(index) => dtypes[index] = merge_field_dtype(name, &dtypes[index], dtype)?,
+                None => {
+                    names.push(name.clone());
+                    dtypes.push(dtype.clone());
+                }
+            }
+        }
+
+        Ok(StructDType::new(names.into(), dtypes))
+    }
+}
+
+fn merge_field_dtype(name: &str, lhs: &DType, rhs: &DType) -> VortexResult<DType> {
+    let nullability = Nullability::from(lhs.is_nullable() || rhs.is_nullable());
+    match (lhs, rhs) {
+        (Struct(lhs_struct, _), Struct(rhs_struct, _)) => {
+            Ok(Struct(lhs_struct.merge(rhs_struct)?, nullability))
+        }
+        _ if lhs.eq_ignore_nullability(rhs) => Ok(lhs.with_nullability(nullability)),
+        _ => vortex_bail!(
+            "Incompatible dtypes for field {}: {} and {}",
+            name,
+            lhs,
+            rhs
+        ),
+    }
 }
 
 #[cfg(test)]
@@ -337,4 +449,73 @@ mod test {
         assert_eq!(sdt.find_name("B"), Some(1));
         assert_eq!(sdt.find_name("C"), None);
     }
+
+    fn field_names(sdt: &StructDType) -> Vec<&str> {
+        sdt.names().iter().map(|n| n.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_struct_rename_reorder() {
+        let sdt = StructDType::new(
+            vec!["A".into(), "B".into()].into(),
+            vec![
+                DType::Primitive(PType::I32, Nullability::Nullable),
+                DType::Bool(Nullability::NonNullable),
+            ],
+        );
+
+        let renamed = sdt.rename(&Field::Name("A".into()), "C".into()).unwrap();
+        assert_eq!(field_names(&renamed), vec!["C", "B"]);
+        assert_eq!(renamed.dtypes(), sdt.dtypes());
+        assert!(sdt.rename(&Field::Index(0), "B".into()).is_err());
+
+        let reordered = sdt
+            .reorder(&[Field::Name("B".into()), Field::Index(0)])
+            .unwrap();
+        assert_eq!(field_names(&reordered), vec!["B", "A"]);
+        assert!(sdt.reorder(&[Field::Index(1)]).is_err());
+        assert!(sdt.reorder(&[Field::Index(1), Field::Index(1)]).is_err());
+    }
+
+    #[test]
+    fn test_struct_merge() {
+        let inner = StructDType::new(
+            vec!["x".into()].into(),
+            vec![DType::Utf8(Nullability::NonNullable)],
+        );
+        let lhs = StructDType::new(
+            vec!["A".into(), "B".into()].into(),
+            vec![
+                DType::Primitive(PType::I32, Nullability::NonNullable),
+                DType::Struct(inner, Nullability::NonNullable),
+            ],
+        );
+        let rhs_inner = StructDType::new(
+            vec!["y".into()].into(),
+            vec![DType::Bool(Nullability::NonNullable)],
+        );
+        let rhs = StructDType::new(
+            vec!["C".into(), "B".into(), "A".into()].into(),
+            vec![
+                DType::Binary(Nullability::NonNullable),
+                DType::Struct(rhs_inner, Nullability::NonNullable),
+                DType::Primitive(PType::I32, Nullability::Nullable),
+            ],
+        );
+
+        let merged = lhs.merge(&rhs).unwrap();
+        assert_eq!(field_names(&merged), vec!["A", "B", "C"]);
+        assert_eq!(
+            merged.dtypes()[0],
+            DType::Primitive(PType::I32, Nullability::Nullable)
+        );
+        let merged_inner = merged.dtypes()[1].as_struct().unwrap();
+        assert_eq!(field_names(merged_inner), vec!["x", "y"]);
+
+        let incompatible = StructDType::new(
+            vec!["A".into()].into(),
+            vec![DType::Utf8(Nullability::NonNullable)],
+        );
+        assert!(lhs.merge(&incompatible).is_err());
+    }
 }
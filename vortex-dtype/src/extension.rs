@@ -57,6 +57,48 @@ impl From<&[u8]> for ExtMetadata {
     }
 }
 
+/// A compute function which an extension type can mark as transparent to its storage.
+///
+/// The function is transparent if applying it to the storage array and wrapping the result in
+/// the extension type gives the same answer as applying it to the extension array. Functions that
+/// only select rows, such as filter, slice and take, are transparent for every extension type.
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StorageTransparentOp {
+    /// Fill each null with the last valid value before it.
+    FillForward,
+    /// Fill each null with a given value.
+    FillNull,
+}
+
+impl StorageTransparentOp {
+    /// The code of the function in serialized dtypes.
+    pub fn code(self) -> u8 {
+        match self {
+            Self::FillForward => 0,
+            Self::FillNull => 1,
+        }
+    }
+
+    /// The function with the given code, if it is one known to this version.
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(Self::FillForward),
+            1 => Some(Self::FillNull),
+            _ => None,
+        }
+    }
+}
+
+impl Display for StorageTransparentOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FillForward => write!(f, "fill_forward"),
+            Self::FillNull => write!(f, "fill_null"),
+        }
+    }
+}
+
 /// A type descriptor for an extension type
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -64,6 +106,8 @@ pub struct ExtDType {
     id: ExtID,
     storage_dtype: Arc<DType>,
     metadata: Option<ExtMetadata>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    storage_transparent: Vec<StorageTransparentOp>,
 }
 
 impl ExtDType {
@@ -107,9 +151,34 @@ impl ExtDType {
             id,
             storage_dtype,
             metadata,
+            storage_transparent: Vec::new(),
         }
     }
 
+    /// Returns a new `ExtDType` which also marks the given compute functions as transparent to its
+    /// storage, so that they delegate to the storage of its arrays.
+    pub fn with_storage_transparent(
+        mut self,
+        ops: impl IntoIterator<Item = StorageTransparentOp>,
+    ) -> Self {
+        self.storage_transparent.extend(ops);
+        self.storage_transparent.sort_unstable();
+        self.storage_transparent.dedup();
+        self
+    }
+
+    /// Returns the compute functions, beyond row selection, that are transparent to the storage of
+    /// this extension type
+    #[inline]
+    pub fn storage_transparent(&self) -> &[StorageTransparentOp] {
+        &self.storage_transparent
+    }
+
+    /// Whether the given compute function is transparent to the storage of this extension type
+    pub fn is_storage_transparent(&self, op: StorageTransparentOp) -> bool {
+        self.storage_transparent.contains(&op)
+    }
+
     /// Returns the `ExtID` for this extension type
     #[inline]
     pub fn id(&self) -> &ExtID {
@@ -129,6 +198,7 @@ impl ExtDType {
             Arc::new(self.storage_dtype.with_nullability(nullability)),
             self.metadata.clone(),
         )
+        .with_storage_transparent(self.storage_transparent.iter().copied())
     }
 
     /// Returns the `ExtMetadata` for this extension type, if it exists
@@ -6,6 +6,7 @@
 //! This crate contains the core logical type system for Vortex, including the definition of data types,
 //! and (optionally) logic for their serialization and deserialization.
 
+pub use decimal::*;
 pub use dtype::*;
 pub use extension::*;
 pub use half;
@@ -14,6 +15,7 @@ pub use ptype::*;
 
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
+mod decimal;
 mod dtype;
 mod extension;
 pub mod field;
@@ -5,7 +5,10 @@ use itertools::Itertools;
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_flatbuffers::{FlatBufferRoot, WriteFlatBuffer};
 
-use crate::{flatbuffers as fb, DType, ExtDType, ExtID, ExtMetadata, PType, StructDType};
+use crate::{
+    flatbuffers as fb, DType, DecimalDType, DecimalValueType, ExtDType, ExtID, ExtMetadata, PType,
+    StorageTransparentOp, StructDType,
+};
 
 mod project;
 pub use project::*;
@@ -55,6 +58,30 @@ impl TryFrom<fb::DType<'_>> for DType {
                     fb_list.nullable().into(),
                 ))
             }
+            fb::Type::FixedSizeList => {
+                let fb_list = fb.type__as_fixed_size_list().ok_or_else(|| {
+                    vortex_err!("failed to parse fixed size list from flatbuffer")
+                })?;
+                let element_dtype = Self::try_from(fb_list.element_type().ok_or_else(|| {
+                    vortex_err!("failed to parse fixed size list element type from flatbuffer")
+                })?)?;
+                Ok(Self::FixedSizeList(
+                    Arc::new(element_dtype),
+                    fb_list.size(),
+                    fb_list.nullable().into(),
+                ))
+            }
+            fb::Type::Decimal => {
+                let fb_decimal = fb
+                    .type__as_decimal()
+                    .ok_or_else(|| vortex_err!("failed to parse decimal from flatbuffer"))?;
+                let mut decimal_dtype =
+                    DecimalDType::try_new(fb_decimal.precision(), fb_decimal.scale())?;
+                if fb_decimal.wide() {
+                    decimal_dtype = decimal_dtype.with_value_type(DecimalValueType::I256)?;
+                }
+                Ok(Self::Decimal(decimal_dtype, fb_decimal.nullable().into()))
+            }
             fb::Type::Struct_ => {
                 let fb_struct = fb
                     .type__as_struct_()
@@ -86,17 +113,28 @@ impl TryFrom<fb::DType<'_>> for DType {
                         vortex_err!("failed to parse extension id from flatbuffer")
                     })?);
                 let metadata = fb_ext.metadata().map(|m| ExtMetadata::from(m.bytes()));
-                Ok(Self::Extension(Arc::new(ExtDType::new(
-                    id,
-                    Arc::new(
-                        DType::try_from(fb_ext.storage_dtype().ok_or_else(|| {
-                            vortex_err!(
+                Ok(Self::Extension(Arc::new(
+                    ExtDType::new(
+                        id,
+                        Arc::new(
+                            DType::try_from(fb_ext.storage_dtype().ok_or_else(|| {
+                                vortex_err!(
                         InvalidSerde: "storage_dtype must be present on DType fbs message")
-                        })?)
-                        .map_err(|e| vortex_err!("failed to create DType from fbs message: {e}"))?,
+                            })?)
+                            .map_err(|e| {
+                                vortex_err!("failed to create DType from fbs message: {e}")
+                            })?,
+                        ),
+                        metadata,
+                    )
+                    .with_storage_transparent(
+                        fb_ext
+                            .storage_transparent()
+                            .into_iter()
+                            .flatten()
+                            .filter_map(StorageTransparentOp::from_code),
                     ),
-                    metadata,
-                ))))
+                )))
             }
             _ => Err(vortex_err!("Unknown DType variant")),
         }
@@ -178,16 +216,48 @@ impl WriteFlatBuffer for DType {
                 )
                 .as_union_value()
             }
+            Self::FixedSizeList(edt, size, n) => {
+                let element_type = Some(edt.as_ref().write_flatbuffer(fbb));
+                fb::FixedSizeList::create(
+                    fbb,
+                    &fb::FixedSizeListArgs {
+                        element_type,
+                        size: *size,
+                        nullable: (*n).into(),
+                    },
+                )
+                .as_union_value()
+            }
+            Self::Decimal(d, n) => fb::Decimal::create(
+                fbb,
+                &fb::DecimalArgs {
+                    precision: d.precision(),
+                    scale: d.scale(),
+                    nullable: (*n).into(),
+                    wide: d.value_type() == DecimalValueType::I256
+                        && d.precision() <= DecimalDType::MAX_DECIMAL128_PRECISION,
+                },
+            )
+            .as_union_value(),
             Self::Extension(ext) => {
                 let id = Some(fbb.create_string(ext.id().as_ref()));
                 let storage_dtype = Some(ext.storage_dtype().write_flatbuffer(fbb));
                 let metadata = ext.metadata().map(|m| fbb.create_vector(m.as_ref()));
+                let storage_transparent = (!ext.storage_transparent().is_empty()).then(|| {
+                    fbb.create_vector(
+                        &ext.storage_transparent()
+                            .iter()
+                            .map(|op| op.code())
+                            .collect::<Vec<_>>(),
+                    )
+                });
                 fb::Extension::create(
                     fbb,
                     &fb::ExtensionArgs {
                         id,
                         storage_dtype,
                         metadata,
+                        storage_transparent,
                     },
                 )
                 .as_union_value()
@@ -202,6 +272,8 @@ impl WriteFlatBuffer for DType {
             Self::Binary(_) => fb::Type::Binary,
             Self::Struct(..) => fb::Type::Struct_,
             Self::List(..) => fb::Type::List,
+            Self::FixedSizeList(..) => fb::Type::FixedSizeList,
+            Self::Decimal(..) => fb::Type::Decimal,
             Self::Extension { .. } => fb::Type::Extension,
         };
 
@@ -262,7 +334,10 @@ mod test {
     use vortex_flatbuffers::WriteFlatBufferExt;
 
     use crate::nullability::Nullability;
-    use crate::{flatbuffers as fb, DType, PType, StructDType};
+    use crate::{
+        flatbuffers as fb, DType, DecimalDType, DecimalValueType, ExtDType, ExtID, PType,
+        StorageTransparentOp, StructDType,
+    };
 
     fn roundtrip_dtype(dtype: DType) {
         let bytes = dtype.write_flatbuffer_bytes();
@@ -291,6 +366,21 @@ mod test {
             Arc::new(DType::Primitive(PType::F32, Nullability::Nullable)),
             Nullability::NonNullable,
         ));
+        roundtrip_dtype(DType::FixedSizeList(
+            Arc::new(DType::Primitive(PType::F32, Nullability::NonNullable)),
+            768,
+            Nullability::Nullable,
+        ));
+        roundtrip_dtype(DType::Decimal(
+            DecimalDType::new(76, -3),
+            Nullability::Nullable,
+        ));
+        roundtrip_dtype(DType::Decimal(
+            DecimalDType::new(10, 2)
+                .with_value_type(DecimalValueType::I256)
+                .unwrap(),
+            Nullability::NonNullable,
+        ));
         roundtrip_dtype(DType::Struct(
             StructDType::new(
                 ["strings".into(), "ints".into()].into(),
@@ -300,6 +390,14 @@ mod test {
                 ],
             ),
             Nullability::NonNullable,
-        ))
+        ));
+        roundtrip_dtype(DType::Extension(Arc::new(
+            ExtDType::new(
+                ExtID::from("test.ext"),
+                Arc::new(DType::Primitive(PType::I64, Nullability::Nullable)),
+                None,
+            )
+            .with_storage_transparent([StorageTransparentOp::FillNull]),
+        )));
     }
 }
@@ -6,7 +6,10 @@ use crate::field::{Field, FieldPath};
 use crate::proto::dtype as pb;
 use crate::proto::dtype::d_type::DtypeType;
 use crate::proto::dtype::field::FieldType;
-use crate::{DType, ExtDType, ExtID, ExtMetadata, PType, StructDType};
+use crate::{
+    DType, DecimalDType, DecimalValueType, ExtDType, ExtID, ExtMetadata, PType,
+    StorageTransparentOp, StructDType,
+};
 
 impl TryFrom<&pb::DType> for DType {
     type Error = VortexError;
@@ -20,7 +23,14 @@ impl TryFrom<&pb::DType> for DType {
             DtypeType::Null(_) => Ok(Self::Null),
             DtypeType::Bool(b) => Ok(Self::Bool(b.nullable.into())),
             DtypeType::Primitive(p) => Ok(Self::Primitive(p.r#type().into(), p.nullable.into())),
-            DtypeType::Decimal(_) => todo!("Not Implemented"),
+            DtypeType::Decimal(d) => {
+                let mut decimal_dtype =
+                    DecimalDType::try_new(u8::try_from(d.precision)?, i8::try_from(d.scale)?)?;
+                if d.wide {
+                    decimal_dtype = decimal_dtype.with_value_type(DecimalValueType::I256)?;
+                }
+                Ok(Self::Decimal(decimal_dtype, d.nullable.into()))
+            }
             DtypeType::Utf8(u) => Ok(Self::Utf8(u.nullable.into())),
             DtypeType::Binary(b) => Ok(Self::Binary(b.nullable.into())),
             DtypeType::Struct(s) => Ok(Self::Struct(
@@ -45,6 +55,21 @@ impl TryFrom<&pb::DType> for DType {
                     nullable,
                 ))
             }
+            DtypeType::FixedSizeList(l) => {
+                let nullable = l.nullable.into();
+                Ok(Self::FixedSizeList(
+                    l.element_type
+                        .as_ref()
+                        .ok_or_else(|| {
+                            vortex_err!(InvalidSerde: "Invalid fixed size list element type")
+                        })?
+                        .as_ref()
+                        .try_into()
+                        .map(Arc::new)?,
+                    l.size,
+                    nullable,
+                ))
+            }
             DtypeType::Extension(e) => Ok(Self::Extension(
                 Arc::new(ExtDType::new(
                     ExtID::from(e.id.as_str()),
@@ -54,6 +79,12 @@ impl TryFrom<&pb::DType> for DType {
                                                  .as_ref(),
                     ).map_err(|e| vortex_err!("failed converting DType from proto message: {}", e))?),
                     e.metadata.as_ref().map(|m| ExtMetadata::from(m.as_ref())),
+                )
+                .with_storage_transparent(
+                    e.storage_transparent
+                        .iter()
+                        .filter_map(|code| u8::try_from(*code).ok())
+                        .filter_map(StorageTransparentOp::from_code),
                 ),
             ))),
         }
@@ -72,6 +103,13 @@ impl From<&DType> for pb::DType {
                     r#type: pb::PType::from(*ptype).into(),
                     nullable: (*n).into(),
                 }),
+                DType::Decimal(d, n) => DtypeType::Decimal(pb::Decimal {
+                    precision: d.precision().into(),
+                    scale: d.scale().into(),
+                    nullable: (*n).into(),
+                    wide: d.value_type() == DecimalValueType::I256
+                        && d.precision() <= DecimalDType::MAX_DECIMAL128_PRECISION,
+                }),
                 DType::Utf8(n) => DtypeType::Utf8(pb::Utf8 {
                     nullable: (*n).into(),
                 }),
@@ -87,10 +125,22 @@ impl From<&DType> for pb::DType {
                     element_type: Some(Box::new(l.as_ref().into())),
                     nullable: (*n).into(),
                 })),
+                DType::FixedSizeList(l, size, n) => {
+                    DtypeType::FixedSizeList(Box::new(pb::FixedSizeList {
+                        element_type: Some(Box::new(l.as_ref().into())),
+                        size: *size,
+                        nullable: (*n).into(),
+                    }))
+                }
                 DType::Extension(e) => DtypeType::Extension(Box::new(pb::Extension {
                     id: e.id().as_ref().into(),
                     storage_dtype: Some(Box::new(e.storage_dtype().into())),
                     metadata: e.metadata().map(|m| m.as_ref().into()),
+                    storage_transparent: e
+                        .storage_transparent()
+                        .iter()
+                        .map(|op| u32::from(op.code()))
+                        .collect(),
                 })),
             }),
         }
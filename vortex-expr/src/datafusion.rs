@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use datafusion_expr::Operator as DFOperator;
 use datafusion_physical_expr::{expressions, PhysicalExpr};
+use vortex_array::compute::LikeOptions;
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_scalar::Scalar;
 
@@ -36,8 +37,12 @@ pub fn convert_expr_to_vortex(physical_expr: Arc<dyn PhysicalExpr>) -> VortexRes
         return Ok(Like::new_expr(
             child,
             pattern,
-            like.negated(),
-            like.case_insensitive(),
+            LikeOptions {
+                negated: like.negated(),
+                case_insensitive: like.case_insensitive(),
+                // Physical LIKE expressions only support the default escape character.
+                escape_char: None,
+            },
         ));
     }
 
@@ -45,7 +50,7 @@ pub fn convert_expr_to_vortex(physical_expr: Arc<dyn PhysicalExpr>) -> VortexRes
         .as_any()
         .downcast_ref::<expressions::Literal>()
     {
-        let value = Scalar::from(lit.value().clone());
+        let value = Scalar::try_from_datafusion(lit.value().clone())?;
         return Ok(Literal::new_expr(value));
     }
 
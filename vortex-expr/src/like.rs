@@ -14,22 +14,15 @@ use crate::{unbox_any, ExprRef, VortexExpr};
 pub struct Like {
     child: ExprRef,
     pattern: ExprRef,
-    negated: bool,
-    case_insensitive: bool,
+    options: LikeOptions,
 }
 
 impl Like {
-    pub fn new_expr(
-        child: ExprRef,
-        pattern: ExprRef,
-        negated: bool,
-        case_insensitive: bool,
-    ) -> ExprRef {
+    pub fn new_expr(child: ExprRef, pattern: ExprRef, options: LikeOptions) -> ExprRef {
         Arc::new(Self {
             child,
             pattern,
-            negated,
-            case_insensitive,
+            options,
         })
     }
 
@@ -41,18 +34,29 @@ impl Like {
         &self.pattern
     }
 
-    pub fn negated(&self) -> bool {
-        self.negated
-    }
-
-    pub fn case_insensitive(&self) -> bool {
-        self.case_insensitive
+    pub fn options(&self) -> LikeOptions {
+        self.options
     }
 }
 
 impl Display for Like {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} LIKE {}", self.child(), self.pattern())
+        write!(
+            f,
+            "{} {}{} {}",
+            self.child(),
+            if self.options.negated { "NOT " } else { "" },
+            if self.options.case_insensitive {
+                "ILIKE"
+            } else {
+                "LIKE"
+            },
+            self.pattern()
+        )?;
+        if let Some(escape) = self.options.escape_char {
+            write!(f, " ESCAPE '{escape}'")?;
+        }
+        Ok(())
     }
 }
 
@@ -64,14 +68,7 @@ impl VortexExpr for Like {
     fn evaluate(&self, batch: &ArrayData) -> VortexResult<ArrayData> {
         let child = self.child().evaluate(batch)?;
         let pattern = self.pattern().evaluate(batch)?;
-        like(
-            &child,
-            &pattern,
-            LikeOptions {
-                negated: self.negated,
-                case_insensitive: self.case_insensitive,
-            },
-        )
+        like(&child, &pattern, self.options)
     }
 
     fn collect_references<'a>(&'a self, references: &mut HashSet<&'a Field>) {
@@ -84,7 +81,9 @@ impl PartialEq<dyn Any> for Like {
     fn eq(&self, other: &dyn Any) -> bool {
         unbox_any(other)
             .downcast_ref::<Self>()
-            .map(|x| x.child.eq(&self.child) && x.pattern.eq(&self.pattern))
+            .map(|x| {
+                x.child.eq(&self.child) && x.pattern.eq(&self.pattern) && x.options == self.options
+            })
             .unwrap_or(false)
     }
 }
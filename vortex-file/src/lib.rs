@@ -75,7 +75,10 @@ mod pruning;
 mod tests;
 
 /// The current version of the Vortex file format
-pub const VERSION: u16 = 1;
+///
+/// Version 2 serializes the metadata of the in-tree encodings in fixed layouts rather than
+/// flexbuffers. Files of version 1 are still read, decoding their metadata with flexbuffers.
+pub const VERSION: u16 = 2;
 /// The size of the footer in bytes in Vortex version 1
 pub const V1_FOOTER_FBS_SIZE: usize = 32;
 
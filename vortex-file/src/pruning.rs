@@ -7,10 +7,12 @@ use std::hash::Hash;
 use itertools::Itertools;
 use vortex_array::aliases::hash_map::HashMap;
 use vortex_array::aliases::hash_set::HashSet;
+use vortex_array::array::{ConstantArray, StructArray};
 use vortex_array::stats::Stat;
-use vortex_array::ArrayData;
+use vortex_array::variants::StructArrayTrait;
+use vortex_array::{ArrayDType, ArrayData, ArrayLen, IntoArrayData};
 use vortex_dtype::field::Field;
-use vortex_dtype::Nullability;
+use vortex_dtype::{DType, FieldName, Nullability};
 use vortex_error::{VortexExpect as _, VortexResult};
 use vortex_expr::{BinaryExpr, Column, ExprRef, Identity, Literal, Not, Operator};
 use vortex_scalar::Scalar;
@@ -123,30 +125,68 @@ impl PruningPredicate {
     /// Returns Ok(None) if any of the required statistics are not present in metadata.
     /// If it returns Ok(Some(array)), the array is a boolean array with the same length as the
     /// metadata, and the values indicate whether the corresponding chunk can be pruned.
+    ///
+    /// Floating point columns without a [Stat::HasNaN] statistic, e.g. in files written before it
+    /// existed, may contain NaNs and so aren't pruned by their min and max. Other columns never
+    /// contain NaNs, so the statistic isn't written for them.
     pub fn evaluate(&self, metadata: &ArrayData) -> VortexResult<Option<ArrayData>> {
-        let known_stats = HashSet::from_iter(
-            metadata
-                .as_struct_array()
-                .vortex_expect("metadata must be struct array")
-                .names()
-                .iter()
-                .map(|x| x.to_string()),
-        );
-        let required_stats = self
+        let table = metadata
+            .as_struct_array()
+            .vortex_expect("metadata must be struct array");
+        let known_stats = HashSet::from_iter(table.names().iter().map(|x| x.to_string()));
+        let (missing_nan_stats, missing_stats): (Vec<_>, Vec<_>) = self
             .required_stats()
             .iter()
-            .flat_map(|(key, value)| value.iter().map(|stat| key.stat_column_name_string(*stat)))
-            .collect::<HashSet<_>>();
-        let missing_stats = required_stats.difference(&known_stats).collect::<Vec<_>>();
+            .flat_map(|(key, value)| value.iter().map(move |stat| (key, *stat)))
+            .filter(|(key, stat)| !known_stats.contains(&key.stat_column_name_string(*stat)))
+            .partition(|(_, stat)| *stat == Stat::HasNaN);
 
         if !missing_stats.is_empty() {
             return Ok(None);
         }
 
-        Ok(Some(self.expr.evaluate(metadata)?))
+        if missing_nan_stats.is_empty() {
+            return Ok(Some(self.expr.evaluate(metadata)?));
+        }
+
+        let has_nan_columns = missing_nan_stats
+            .into_iter()
+            .map(|(key, stat)| {
+                // HasNaN is only required alongside the min or max of the same column, whose
+                // dtype is that of the column.
+                let is_float = [Stat::Min, Stat::Max]
+                    .into_iter()
+                    .filter_map(|bound| table.field_by_name(&key.stat_column_name_string(bound)))
+                    .any(|bound| bound.dtype().is_float());
+                (key.stat_column_name_string(stat).into(), is_float)
+            })
+            .collect::<Vec<_>>();
+        let metadata = with_has_nan_columns(metadata, has_nan_columns)?;
+        Ok(Some(self.expr.evaluate(&metadata)?))
     }
 }
 
+/// Add a constant [Stat::HasNaN] column to the statistics table for each of the given names.
+fn with_has_nan_columns(
+    metadata: &ArrayData,
+    columns: Vec<(FieldName, bool)>,
+) -> VortexResult<ArrayData> {
+    let table = StructArray::try_from(metadata.clone())?;
+    let len = table.len();
+    let (names, fields): (Vec<_>, Vec<_>) = table
+        .names()
+        .iter()
+        .cloned()
+        .zip(table.children())
+        .chain(
+            columns
+                .into_iter()
+                .map(|(name, has_nan)| (name, ConstantArray::new(has_nan, len).into_array())),
+        )
+        .unzip();
+    StructArray::try_new(names.into(), fields, len, table.validity()).map(IntoArrayData::into_array)
+}
+
 fn not_prunable() -> PruningPredicateStats {
     (
         Literal::new_expr(Scalar::bool(false, Nullability::NonNullable)),
@@ -299,6 +339,39 @@ impl<'a> PruningPredicateRewriter<'a> {
             .unwrap_or_else(|| self.other_exp.clone())
     }
 
+    /// A condition that holds if the column has no NaNs, or `None` if it can't have any because
+    /// it's compared to a literal that isn't a float.
+    ///
+    /// NaNs are excluded from the min and max, but order above all other values when compared.
+    fn column_has_no_nans(&mut self) -> Option<ExprRef> {
+        if let Some(literal) = self.other_exp.as_any().downcast_ref::<Literal>() {
+            if !matches!(literal.value().dtype(), DType::Primitive(ptype, _) if ptype.is_float()) {
+                return None;
+            }
+        }
+        let has_nan = Column::new_expr(self.add_stat_reference(Stat::HasNaN));
+        Some(Not::new_expr(has_nan))
+    }
+
+    /// A condition that holds if none of the columns referenced by the other side of the
+    /// comparison have NaNs, or `None` if it doesn't reference any.
+    fn other_has_no_nans(&mut self) -> Option<ExprRef> {
+        let fields = self
+            .other_exp
+            .references()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        fields
+            .into_iter()
+            .map(|field| {
+                self.stats_to_fetch
+                    .insert(FieldOrIdentity::Field(field.clone()), Stat::HasNaN);
+                Not::new_expr(Column::new_expr(stat_column_field(&field, Stat::HasNaN)))
+            })
+            .reduce(|lhs, rhs| BinaryExpr::new_expr(lhs, Operator::And, rhs))
+    }
+
     fn rewrite(mut self) -> Option<PruningPredicateStats> {
         let expr: Option<ExprRef> = match self.operator {
             Operator::Eq => {
@@ -306,11 +379,19 @@ impl<'a> PruningPredicateRewriter<'a> {
                 let max_col = Column::new_expr(self.add_stat_reference(Stat::Max));
                 let replaced_max = self.rewrite_other_exp(Stat::Max);
                 let replaced_min = self.rewrite_other_exp(Stat::Min);
+                let other_has_no_nans = self.other_has_no_nans();
+                let column_has_no_nans = self.column_has_no_nans();
 
                 Some(BinaryExpr::new_expr(
-                    BinaryExpr::new_expr(min_col, Operator::Gt, replaced_max),
+                    and_if(
+                        BinaryExpr::new_expr(min_col, Operator::Gt, replaced_max),
+                        other_has_no_nans,
+                    ),
                     Operator::Or,
-                    BinaryExpr::new_expr(replaced_min, Operator::Gt, max_col),
+                    and_if(
+                        BinaryExpr::new_expr(replaced_min, Operator::Gt, max_col),
+                        column_has_no_nans,
+                    ),
                 ))
             }
             Operator::NotEq => {
@@ -327,27 +408,44 @@ impl<'a> PruningPredicateRewriter<'a> {
                     BinaryExpr::new_expr(replaced_min.clone(), Operator::Eq, replaced_max.clone());
                 let other_value = replaced_min;
 
-                Some(BinaryExpr::new_expr(
-                    BinaryExpr::new_expr(
-                        column_value_is_single_known_value,
-                        Operator::And,
-                        other_value_is_single_known_value,
+                let column_has_no_nans = self.column_has_no_nans();
+                let other_has_no_nans = self.other_has_no_nans();
+
+                Some(and_if(
+                    and_if(
+                        BinaryExpr::new_expr(
+                            BinaryExpr::new_expr(
+                                column_value_is_single_known_value,
+                                Operator::And,
+                                other_value_is_single_known_value,
+                            ),
+                            Operator::And,
+                            BinaryExpr::new_expr(column_value, Operator::Eq, other_value),
+                        ),
+                        column_has_no_nans,
                     ),
-                    Operator::And,
-                    BinaryExpr::new_expr(column_value, Operator::Eq, other_value),
+                    other_has_no_nans,
                 ))
             }
             Operator::Gt | Operator::Gte => {
                 let max_col = Column::new_expr(self.add_stat_reference(Stat::Max));
                 let replaced_min = self.rewrite_other_exp(Stat::Min);
+                let column_has_no_nans = self.column_has_no_nans();
 
-                Some(BinaryExpr::new_expr(max_col, Operator::Lte, replaced_min))
+                Some(and_if(
+                    BinaryExpr::new_expr(max_col, Operator::Lte, replaced_min),
+                    column_has_no_nans,
+                ))
             }
             Operator::Lt | Operator::Lte => {
                 let min_col = Column::new_expr(self.add_stat_reference(Stat::Min));
                 let replaced_max = self.rewrite_other_exp(Stat::Max);
+                let other_has_no_nans = self.other_has_no_nans();
 
-                Some(BinaryExpr::new_expr(min_col, Operator::Gte, replaced_max))
+                Some(and_if(
+                    BinaryExpr::new_expr(min_col, Operator::Gte, replaced_max),
+                    other_has_no_nans,
+                ))
             }
             _ => None,
         };
@@ -355,6 +453,14 @@ impl<'a> PruningPredicateRewriter<'a> {
     }
 }
 
+/// Combine `expr` with the given condition, if any.
+fn and_if(expr: ExprRef, condition: Option<ExprRef>) -> ExprRef {
+    match condition {
+        Some(condition) => BinaryExpr::new_expr(expr, Operator::And, condition),
+        None => expr,
+    }
+}
+
 fn replace_column_with_stat(
     expr: &ExprRef,
     stat: Stat,
@@ -439,7 +545,9 @@ where
 mod tests {
     use vortex_array::aliases::hash_map::HashMap;
     use vortex_array::aliases::hash_set::HashSet;
+    use vortex_array::array::{BoolArray, PrimitiveArray, StructArray};
     use vortex_array::stats::Stat;
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
     use vortex_dtype::field::Field;
     use vortex_expr::{BinaryExpr, Column, Identity, Literal, Not, Operator};
 
@@ -496,25 +604,36 @@ mod tests {
             HashMap::from_iter([
                 (
                     FieldOrIdentity::Field(column.clone()),
-                    HashSet::from_iter([Stat::Min, Stat::Max])
+                    HashSet::from_iter([Stat::Min, Stat::Max, Stat::HasNaN])
                 ),
                 (
                     FieldOrIdentity::Field(other_col.clone()),
-                    HashSet::from_iter([Stat::Max, Stat::Min])
+                    HashSet::from_iter([Stat::Max, Stat::Min, Stat::HasNaN])
                 )
             ])
         );
         let expected_expr = BinaryExpr::new_expr(
             BinaryExpr::new_expr(
-                Column::new_expr(stat_column_field(&column, Stat::Min)),
-                Operator::Gt,
-                Column::new_expr(stat_column_field(&other_col, Stat::Max)),
+                BinaryExpr::new_expr(
+                    Column::new_expr(stat_column_field(&column, Stat::Min)),
+                    Operator::Gt,
+                    Column::new_expr(stat_column_field(&other_col, Stat::Max)),
+                ),
+                Operator::And,
+                Not::new_expr(Column::new_expr(stat_column_field(
+                    &other_col,
+                    Stat::HasNaN,
+                ))),
             ),
             Operator::Or,
             BinaryExpr::new_expr(
-                Column::new_expr(stat_column_field(&other_col, Stat::Min)),
-                Operator::Gt,
-                Column::new_expr(stat_column_field(&column, Stat::Max)),
+                BinaryExpr::new_expr(
+                    Column::new_expr(stat_column_field(&other_col, Stat::Min)),
+                    Operator::Gt,
+                    Column::new_expr(stat_column_field(&column, Stat::Max)),
+                ),
+                Operator::And,
+                Not::new_expr(Column::new_expr(stat_column_field(&column, Stat::HasNaN))),
             ),
         );
         assert_eq!(*converted, *expected_expr.as_any());
@@ -536,34 +655,45 @@ mod tests {
             HashMap::from_iter([
                 (
                     FieldOrIdentity::Field(column.clone()),
-                    HashSet::from_iter([Stat::Min, Stat::Max])
+                    HashSet::from_iter([Stat::Min, Stat::Max, Stat::HasNaN])
                 ),
                 (
                     FieldOrIdentity::Field(other_col.clone()),
-                    HashSet::from_iter([Stat::Max, Stat::Min])
+                    HashSet::from_iter([Stat::Max, Stat::Min, Stat::HasNaN])
                 )
             ])
         );
         let expected_expr = BinaryExpr::new_expr(
             BinaryExpr::new_expr(
                 BinaryExpr::new_expr(
-                    Column::new_expr(stat_column_field(&column, Stat::Min)),
-                    Operator::Eq,
-                    Column::new_expr(stat_column_field(&column, Stat::Max)),
+                    BinaryExpr::new_expr(
+                        BinaryExpr::new_expr(
+                            Column::new_expr(stat_column_field(&column, Stat::Min)),
+                            Operator::Eq,
+                            Column::new_expr(stat_column_field(&column, Stat::Max)),
+                        ),
+                        Operator::And,
+                        BinaryExpr::new_expr(
+                            Column::new_expr(stat_column_field(&other_col, Stat::Min)),
+                            Operator::Eq,
+                            Column::new_expr(stat_column_field(&other_col, Stat::Max)),
+                        ),
+                    ),
+                    Operator::And,
+                    BinaryExpr::new_expr(
+                        Column::new_expr(stat_column_field(&column, Stat::Min)),
+                        Operator::Eq,
+                        Column::new_expr(stat_column_field(&other_col, Stat::Min)),
+                    ),
                 ),
                 Operator::And,
-                BinaryExpr::new_expr(
-                    Column::new_expr(stat_column_field(&other_col, Stat::Min)),
-                    Operator::Eq,
-                    Column::new_expr(stat_column_field(&other_col, Stat::Max)),
-                ),
+                Not::new_expr(Column::new_expr(stat_column_field(&column, Stat::HasNaN))),
             ),
             Operator::And,
-            BinaryExpr::new_expr(
-                Column::new_expr(stat_column_field(&column, Stat::Min)),
-                Operator::Eq,
-                Column::new_expr(stat_column_field(&other_col, Stat::Min)),
-            ),
+            Not::new_expr(Column::new_expr(stat_column_field(
+                &other_col,
+                Stat::HasNaN,
+            ))),
         );
 
         assert_eq!(*converted, *expected_expr.as_any());
@@ -586,7 +716,7 @@ mod tests {
             HashMap::from_iter([
                 (
                     FieldOrIdentity::Field(column.clone()),
-                    HashSet::from_iter([Stat::Max])
+                    HashSet::from_iter([Stat::Max, Stat::HasNaN])
                 ),
                 (
                     FieldOrIdentity::Field(other_col.clone()),
@@ -595,9 +725,13 @@ mod tests {
             ])
         );
         let expected_expr = BinaryExpr::new_expr(
-            Column::new_expr(stat_column_field(&column, Stat::Max)),
-            Operator::Lte,
-            Column::new_expr(stat_column_field(&other_col, Stat::Min)),
+            BinaryExpr::new_expr(
+                Column::new_expr(stat_column_field(&column, Stat::Max)),
+                Operator::Lte,
+                Column::new_expr(stat_column_field(&other_col, Stat::Min)),
+            ),
+            Operator::And,
+            Not::new_expr(Column::new_expr(stat_column_field(&column, Stat::HasNaN))),
         );
         assert_eq!(*converted, *expected_expr.as_any());
     }
@@ -628,6 +762,102 @@ mod tests {
         assert_eq!(*converted, *expected_expr.as_any());
     }
 
+    #[test]
+    pub fn pruning_gt_float_value() {
+        let column = Field::from("a");
+        let other_col = Literal::new_expr(42.0.into());
+        let gt_expr = BinaryExpr::new_expr(
+            Column::new_expr(column.clone()),
+            Operator::Gt,
+            other_col.clone(),
+        );
+
+        let (converted, refs) = convert_to_pruning_expression(&gt_expr);
+        assert_eq!(
+            refs.into_map(),
+            HashMap::from_iter([(
+                FieldOrIdentity::Field(column.clone()),
+                HashSet::from_iter([Stat::Max, Stat::HasNaN])
+            ),])
+        );
+        let expected_expr = BinaryExpr::new_expr(
+            BinaryExpr::new_expr(
+                Column::new_expr(stat_column_field(&column, Stat::Max)),
+                Operator::Lte,
+                other_col,
+            ),
+            Operator::And,
+            Not::new_expr(Column::new_expr(stat_column_field(&column, Stat::HasNaN))),
+        );
+        assert_eq!(*converted, *expected_expr.as_any());
+    }
+
+    #[test]
+    fn evaluate_consults_has_nan() {
+        let expr = BinaryExpr::new_expr(
+            Column::new_expr(Field::from("a")),
+            Operator::Gt,
+            Literal::new_expr(5.0.into()),
+        );
+        let predicate = PruningPredicate::try_new(&expr).unwrap();
+        let max = PrimitiveArray::from(vec![1.0f64, 1.0]).into_array();
+
+        let with_nans = StructArray::from_fields(&[
+            ("a_max", max.clone()),
+            (
+                "a_has_nan",
+                BoolArray::from_iter([false, true]).into_array(),
+            ),
+        ])
+        .unwrap();
+        let pruned = predicate
+            .evaluate(with_nans.as_ref())
+            .unwrap()
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            pruned.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false]
+        );
+
+        // Floating point columns without the statistic may contain NaNs
+        let without_nans = StructArray::from_fields(&[("a_max", max)]).unwrap();
+        let pruned = predicate
+            .evaluate(without_nans.as_ref())
+            .unwrap()
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            pruned.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, false]
+        );
+
+        // Other columns never contain NaNs
+        let expr = BinaryExpr::new_expr(
+            Column::new_expr(Field::from("a")),
+            Operator::Gt,
+            Literal::new_expr(5i64.into()),
+        );
+        let ints = StructArray::from_fields(&[(
+            "a_max",
+            PrimitiveArray::from(vec![1i64, 1]).into_array(),
+        )])
+        .unwrap();
+        let pruned = PruningPredicate::try_new(&expr)
+            .unwrap()
+            .evaluate(ints.as_ref())
+            .unwrap()
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            pruned.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, true]
+        );
+    }
+
     #[test]
     pub fn pruning_lt_column() {
         let column = Field::from("a");
@@ -649,14 +879,21 @@ mod tests {
                 ),
                 (
                     FieldOrIdentity::Field(other_col.clone()),
-                    HashSet::from_iter([Stat::Max])
+                    HashSet::from_iter([Stat::Max, Stat::HasNaN])
                 )
             ])
         );
         let expected_expr = BinaryExpr::new_expr(
-            Column::new_expr(stat_column_field(&column, Stat::Min)),
-            Operator::Gte,
-            Column::new_expr(stat_column_field(&other_col, Stat::Max)),
+            BinaryExpr::new_expr(
+                Column::new_expr(stat_column_field(&column, Stat::Min)),
+                Operator::Gte,
+                Column::new_expr(stat_column_field(&other_col, Stat::Max)),
+            ),
+            Operator::And,
+            Not::new_expr(Column::new_expr(stat_column_field(
+                &other_col,
+                Stat::HasNaN,
+            ))),
         );
         assert_eq!(*converted, *expected_expr.as_any());
     }
@@ -1,12 +1,12 @@
 use std::collections::VecDeque;
-use std::io;
 use std::io::ErrorKind;
 use std::pin::Pin;
 use std::sync::{Arc, RwLock};
 use std::task::{Context, Poll, Waker};
+use std::{io, mem};
 
 use futures::Stream;
-use futures_util::future::BoxFuture;
+use futures_util::future::{ready, BoxFuture};
 use futures_util::{FutureExt, StreamExt};
 use vortex_array::ArrayData;
 use vortex_error::{vortex_err, vortex_panic, VortexExpect, VortexResult};
@@ -45,18 +45,27 @@ impl ReadMasked for ReadArray {
 }
 
 enum RowMaskState<V> {
-    Pending(RowMask),
+    /// The mask needs messages that are fetched by the read with the given number, after which
+    /// it's read again.
+    Pending(RowMask, usize),
+    /// The mask is being read, possibly on the compute dispatcher.
+    Decoding(BoxFuture<'static, VortexResult<(RowMask, Option<PollRead<V>>)>>),
     Ready(V),
     Empty,
 }
 
 pub struct BufferedLayoutReader<R, S, V, RM> {
     values: S,
-    row_mask_reader: RM,
+    row_mask_reader: Arc<RM>,
     in_flight: Option<BoxFuture<'static, io::Result<Vec<Message>>>>,
+    /// The number of reads dispatched so far, which is also the number of the next one.
+    reads: usize,
+    /// Messages that masks asked for while a read was in flight, to be fetched by the next read.
+    to_read: Vec<MessageLocator>,
     queued: VecDeque<RowMaskState<V>>,
     io_read: VortexReadRanges<R>,
     dispatcher: Arc<IoDispatcher>,
+    compute_dispatcher: Option<Arc<IoDispatcher>>,
     cache: Arc<RwLock<LayoutMessageCache>>,
 }
 
@@ -64,22 +73,32 @@ impl<R, S, V, RM> BufferedLayoutReader<R, S, V, RM>
 where
     R: VortexReadAt,
     S: Stream<Item = VortexResult<RowMask>> + Unpin,
-    RM: ReadMasked<Value = V>,
+    RM: ReadMasked<Value = V> + Send + Sync + 'static,
+    V: Send + 'static,
 {
+    /// Read the masks from `values` with `row_mask_reader`.
+    ///
+    /// Without a `compute_dispatcher` the masks are read, i.e. their arrays decoded, inline on the
+    /// task polling the stream. With one they're read on its threads instead, so that decoding
+    /// doesn't hold up dispatching the reads of further masks.
     pub fn new(
         read: R,
         dispatcher: Arc<IoDispatcher>,
+        compute_dispatcher: Option<Arc<IoDispatcher>>,
         values: S,
         row_mask_reader: RM,
         cache: Arc<RwLock<LayoutMessageCache>>,
     ) -> Self {
         Self {
             values,
-            row_mask_reader,
+            row_mask_reader: Arc::new(row_mask_reader),
             in_flight: None,
+            reads: 0,
+            to_read: Vec::new(),
             queued: VecDeque::new(),
             io_read: VortexReadRanges::new(read, dispatcher.clone(), 1 << 20),
             dispatcher,
+            compute_dispatcher,
             cache,
         }
     }
@@ -98,48 +117,38 @@ where
         &mut self,
         cx: &mut Context<'_>,
     ) -> VortexResult<(Vec<MessageLocator>, bool)> {
-        let mut to_read = Vec::with_capacity(NUM_TO_COALESCE);
-        let mut read_more_count = 0;
+        let mut to_read = mem::take(&mut self.to_read);
+        let next_read = self.reads;
+        let mut in_progress = 0;
 
-        // Poll all queued pending masks to see if we can make progress
+        // Read the masks again whose messages have arrived, and poll those being decoded
         for queued_res in self.queued.iter_mut() {
-            match queued_res {
-                RowMaskState::Pending(pending_mask) => {
-                    if let Some(pending_read) = self.row_mask_reader.read_masked(pending_mask)? {
-                        match pending_read {
-                            PollRead::ReadMore(m) => {
-                                to_read.extend(m);
-                                read_more_count += 1;
-                            }
-                            PollRead::Value(v) => {
-                                *queued_res = RowMaskState::Ready(v);
-                            }
-                        }
-                    } else {
-                        *queued_res = RowMaskState::Empty;
-                    }
-                }
-                RowMaskState::Ready(_) => {}
-                RowMaskState::Empty => {}
+            *queued_res = match mem::replace(queued_res, RowMaskState::Empty) {
+                RowMaskState::Pending(pending_mask, read) if read < next_read => start_read(
+                    &self.row_mask_reader,
+                    self.compute_dispatcher.as_deref(),
+                    pending_mask,
+                )?,
+                state => state,
+            };
+            if poll_decoding(queued_res, cx, &mut to_read, next_read)? {
+                in_progress += 1;
             }
         }
 
         let mut exhausted = false;
-        while read_more_count < NUM_TO_COALESCE {
+        while in_progress < NUM_TO_COALESCE {
             match self.values.poll_next_unpin(cx) {
                 Poll::Ready(Some(Ok(next_mask))) => {
-                    if let Some(read_result) = self.row_mask_reader.read_masked(&next_mask)? {
-                        match read_result {
-                            PollRead::ReadMore(m) => {
-                                self.queued.push_back(RowMaskState::Pending(next_mask));
-                                to_read.extend(m);
-                                read_more_count += 1;
-                            }
-                            PollRead::Value(v) => {
-                                self.queued.push_back(RowMaskState::Ready(v));
-                            }
-                        }
+                    let mut state = start_read(
+                        &self.row_mask_reader,
+                        self.compute_dispatcher.as_deref(),
+                        next_mask,
+                    )?;
+                    if poll_decoding(&mut state, cx, &mut to_read, next_read)? {
+                        in_progress += 1;
                     }
+                    self.queued.push_back(state);
                 }
                 Poll::Ready(Some(Err(e))) => {
                     return Err(e);
@@ -156,13 +165,22 @@ where
         Ok((to_read, exhausted))
     }
 
+    /// Read the messages, whose bytes are first reserved in the cache they're stored into.
     fn dispatch_messages(
-        &self,
+        &mut self,
         messages: Vec<MessageLocator>,
         waker: Waker,
-    ) -> BoxFuture<'static, io::Result<Vec<Message>>> {
+    ) -> VortexResult<BoxFuture<'static, io::Result<Vec<Message>>>> {
+        let bytes = messages.iter().map(|msg| msg.1.len() as usize).sum();
+        self.cache
+            .write()
+            .unwrap_or_else(|poison| vortex_panic!("Failed to write to message cache: {poison}"))
+            .try_reserve(bytes)?;
+
+        self.reads += 1;
         let reader = self.io_read.clone();
-        self.dispatcher
+        Ok(self
+            .dispatcher
             .dispatch(move || async move {
                 let read_messages = reader
                     .read_byte_ranges(messages.iter().map(|msg| msg.1.as_range()).collect())
@@ -180,16 +198,74 @@ where
             })
             .vortex_expect("Async task dispatch")
             .map(|res| res.unwrap_or_else(|e| Err(io::Error::new(ErrorKind::Other, e))))
-            .boxed()
+            .boxed())
     }
 }
 
+/// Start reading the mask, on the compute dispatcher if there is one.
+fn start_read<RM, V>(
+    reader: &Arc<RM>,
+    compute_dispatcher: Option<&IoDispatcher>,
+    mask: RowMask,
+) -> VortexResult<RowMaskState<V>>
+where
+    RM: ReadMasked<Value = V> + Send + Sync + 'static,
+    V: Send + 'static,
+{
+    let Some(compute_dispatcher) = compute_dispatcher else {
+        let read = reader.read_masked(&mask);
+        return Ok(RowMaskState::Decoding(
+            ready(read.map(|read| (mask, read))).boxed(),
+        ));
+    };
+
+    let reader = reader.clone();
+    let read = compute_dispatcher
+        .dispatch(move || async move {
+            let read = reader.read_masked(&mask);
+            (mask, read)
+        })?
+        .map(|res| res.and_then(|(mask, read)| Ok((mask, read?))))
+        .boxed();
+    Ok(RowMaskState::Decoding(read))
+}
+
+/// Advance a mask that is being read, adding the messages it still needs to `to_read`, which
+/// are fetched by the read numbered `next_read`.
+///
+/// Returns whether the mask is still in progress, i.e. being decoded or waiting for messages.
+fn poll_decoding<V>(
+    state: &mut RowMaskState<V>,
+    cx: &mut Context<'_>,
+    to_read: &mut Vec<MessageLocator>,
+    next_read: usize,
+) -> VortexResult<bool> {
+    let RowMaskState::Decoding(read) = state else {
+        return Ok(matches!(state, RowMaskState::Pending(..)));
+    };
+    let Poll::Ready(read) = read.poll_unpin(cx) else {
+        return Ok(true);
+    };
+
+    let (mask, read) = read?;
+    let (next_state, in_progress) = match read {
+        Some(PollRead::ReadMore(messages)) => {
+            to_read.extend(messages);
+            (RowMaskState::Pending(mask, next_read), true)
+        }
+        Some(PollRead::Value(value)) => (RowMaskState::Ready(value), false),
+        None => (RowMaskState::Empty, false),
+    };
+    *state = next_state;
+    Ok(in_progress)
+}
+
 impl<R, S, V, RM> Stream for BufferedLayoutReader<R, S, V, RM>
 where
     R: VortexReadAt + Unpin,
     S: Stream<Item = VortexResult<RowMask>> + Unpin,
-    RM: ReadMasked<Value = V> + Unpin,
-    V: Unpin,
+    RM: ReadMasked<Value = V> + Send + Sync + Unpin + 'static,
+    V: Send + Unpin + 'static,
 {
     type Item = VortexResult<V>;
 
@@ -201,28 +277,35 @@ where
                         msgs.map_err(|e| vortex_err!("Cancelled in flight read {e}"))?,
                     );
                     let (messages, exhausted) = self.gather_read_messages(cx)?;
-                    if !messages.is_empty() {
-                        self.in_flight = Some(self.dispatch_messages(messages, cx.waker().clone()));
-                    } else {
-                        self.in_flight = None;
-                    }
+                    self.in_flight = (!messages.is_empty())
+                        .then(|| self.dispatch_messages(messages, cx.waker().clone()))
+                        .transpose()?;
                     exhausted
                 }
                 // If read is pending see if we have any available results
-                Poll::Pending => false,
+                Poll::Pending => {
+                    // Masks that turn out to need more messages wait for the next read, which
+                    // fetches the messages they ask for now.
+                    let this = &mut *self;
+                    for queued_res in this.queued.iter_mut() {
+                        poll_decoding(queued_res, cx, &mut this.to_read, this.reads)?;
+                    }
+                    false
+                }
             }
         } else {
             let (messages, exhausted) = self.gather_read_messages(cx)?;
             if !messages.is_empty() {
-                self.in_flight = Some(self.dispatch_messages(messages, cx.waker().clone()));
+                let read = self.dispatch_messages(messages, cx.waker().clone())?;
+                self.in_flight = Some(read);
             }
             exhausted
         };
 
         while let Some(next_mask) = self.queued.pop_front() {
             match next_mask {
-                RowMaskState::Pending(m) => {
-                    self.queued.push_front(RowMaskState::Pending(m));
+                pending @ (RowMaskState::Pending(..) | RowMaskState::Decoding(_)) => {
+                    self.queued.push_front(pending);
                     return Poll::Pending;
                 }
                 RowMaskState::Ready(next_ready) => return Poll::Ready(Some(Ok(next_ready))),
@@ -237,3 +320,68 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, RwLock};
+
+    use futures::{stream, TryStreamExt};
+    use vortex_buffer::Buffer;
+    use vortex_error::{vortex_panic, VortexResult};
+    use vortex_io::IoDispatcher;
+
+    use super::{BufferedLayoutReader, ReadMasked};
+    use crate::byte_range::ByteRange;
+    use crate::{LayoutMessageCache, LayoutPartId, MessageLocator, PollRead, RowMask};
+
+    /// Reads the begin of each mask once the byte at that offset is in the cache.
+    struct CountingReader {
+        cache: Arc<RwLock<LayoutMessageCache>>,
+        reads: Arc<AtomicUsize>,
+    }
+
+    impl ReadMasked for CountingReader {
+        type Value = usize;
+
+        fn read_masked(&self, mask: &RowMask) -> VortexResult<Option<PollRead<usize>>> {
+            self.reads.fetch_add(1, Ordering::Relaxed);
+            let id = vec![LayoutPartId::try_from(mask.begin())?];
+            let cached = self
+                .cache
+                .read()
+                .unwrap_or_else(|poison| vortex_panic!("Failed to read cache: {poison}"))
+                .get(&id)
+                .is_some();
+            Ok(Some(if cached {
+                PollRead::Value(mask.begin())
+            } else {
+                let range = ByteRange::new(mask.begin() as u64, mask.end() as u64);
+                PollRead::ReadMore(vec![MessageLocator(id, range)])
+            }))
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn masks_are_read_again_only_once_their_messages_arrive() {
+        let n = 100;
+        let cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
+        let reads = Arc::new(AtomicUsize::new(0));
+        let reader = BufferedLayoutReader::new(
+            Buffer::from(vec![0u8; n]),
+            Arc::new(IoDispatcher::default()),
+            Some(Arc::new(IoDispatcher::new_tokio(2))),
+            stream::iter((0..n).map(|begin| Ok(RowMask::new_valid_between(begin, begin + 1)))),
+            CountingReader {
+                cache: cache.clone(),
+                reads: reads.clone(),
+            },
+            cache,
+        );
+
+        let values = reader.try_collect::<Vec<_>>().await.unwrap();
+        assert_eq!(values, (0..n).collect::<Vec<_>>());
+        // Each mask asks for its message once, and is decoded again once it arrives.
+        assert_eq!(reads.load(Ordering::Relaxed), 2 * n);
+    }
+}
@@ -1,11 +1,13 @@
 use core::ops::Range;
 
 use flatbuffers::{root, root_unchecked};
+use vortex_array::{EncodingSet, MetadataFormat};
 use vortex_buffer::Buffer;
 use vortex_error::{vortex_bail, vortex_err, VortexResult, VortexUnwrap};
 use vortex_flatbuffers::{dtype as fbd, footer};
 use vortex_io::VortexReadAt;
 
+use crate::read::context::verify_root_layout;
 use crate::{LazyDType, EOF_SIZE, INITIAL_READ_SIZE, MAGIC_BYTES, VERSION};
 
 #[derive(Debug, Clone)]
@@ -17,6 +19,8 @@ pub struct InitialRead {
     pub initial_read_offset: u64,
     /// The byte range within `buf` representing the Postscript flatbuffer.
     pub fb_postscript_byte_range: Range<usize>,
+    /// The version of the file format the file was written in.
+    pub version: u16,
 }
 
 impl InitialRead {
@@ -34,7 +38,10 @@ impl InitialRead {
         layout_start..layout_end
     }
 
-    /// The `Layout` flatbuffer.
+    /// The `Layout` flatbuffer, of which only the root layout has been verified.
+    ///
+    /// Its children must be checked with [verify_layout](crate::verify_layout) before reading
+    /// from them.
     pub fn fb_layout(&self) -> footer::Layout {
         unsafe { root_unchecked::<footer::Layout>(&self.buf[self.fb_layout_byte_range()]) }
     }
@@ -47,6 +54,24 @@ impl InitialRead {
         schema_start..schema_end
     }
 
+    /// The format of the array metadata, which moved to fixed layouts for the in-tree encodings in
+    /// version 2 of the file format.
+    pub fn metadata_format(&self) -> MetadataFormat {
+        if self.version < 2 {
+            MetadataFormat::Flexbuffers
+        } else {
+            MetadataFormat::FixedLayout
+        }
+    }
+
+    /// The encodings of the arrays in the file, if the writer recorded them.
+    pub fn encodings(&self) -> VortexResult<Option<EncodingSet>> {
+        self.fb_postscript()
+            .encodings()
+            .map(EncodingSet::try_from)
+            .transpose()
+    }
+
     pub fn lazy_dtype(&self) -> LazyDType {
         // we validated the schema bytes at construction time
         unsafe { LazyDType::from_schema_bytes(self.buf.slice(self.fb_schema_byte_range())) }
@@ -84,7 +109,7 @@ pub async fn read_initial_bytes<R: VortexReadAt>(
             .try_into()
             .map_err(|e| vortex_err!("Version was not a u16 {e}"))?,
     );
-    if version != VERSION {
+    if !(1..=VERSION).contains(&version) {
         vortex_bail!("Malformed file, unsupported version {version}")
     }
 
@@ -133,16 +158,17 @@ pub async fn read_initial_bytes<R: VortexReadAt>(
         )
     }
 
-    // validate the schema and layout
+    // validate the schema and the root layout, whose children are validated as they are read
     let schema_loc = (schema_offset - initial_read_offset) as usize;
     let layout_loc = (layout_offset - initial_read_offset) as usize;
     root::<fbd::DType>(&buf[schema_loc..layout_loc])?;
-    root::<footer::Layout>(&buf[layout_loc..ps_loc])?;
+    verify_root_layout(&buf[layout_loc..ps_loc])?;
 
     Ok(InitialRead {
         buf,
         initial_read_offset,
         fb_postscript_byte_range,
+        version,
     })
 }
 
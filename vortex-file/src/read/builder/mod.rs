@@ -1,13 +1,19 @@
 use std::sync::{Arc, RwLock};
 
 use initial_read::read_initial_bytes;
+use log::warn;
+use vortex_array::compute::scalar_at;
+use vortex_array::memory::MemoryPool;
+use vortex_array::stats::ArrayStatistics;
 use vortex_array::{ArrayDType, ArrayData};
-use vortex_error::VortexResult;
+use vortex_dtype::Nullability::NonNullable;
+use vortex_dtype::{DType, PType};
+use vortex_error::{vortex_bail, VortexResult};
 use vortex_expr::Select;
 use vortex_io::{IoDispatcher, VortexReadAt};
 
 use super::InitialRead;
-use crate::read::cache::{LayoutMessageCache, RelativeLayoutCache};
+use crate::read::cache::{ChunkCache, LayoutMessageCache, RelativeLayoutCache};
 use crate::read::context::LayoutDeserializer;
 use crate::read::filtering::RowFilter;
 use crate::read::projection::Projection;
@@ -28,7 +34,7 @@ pub(crate) mod initial_read;
 ///     b. The Layout, which is a valid footer::Layout flatbuffer, and describes the physical byte ranges & relationships amongst
 ///        the those byte ranges that we wrote in part 1.
 ///     c. The Postscript, which is a valid footer::Postscript flatbuffer, containing the absolute start offsets of the Schema & Layout
-///        flatbuffers within the file.
+///        flatbuffers within the file, and the set of encodings of the arrays in the file.
 ///     d. The End-of-File marker, which is 8 bytes, and contains the u16 version, u16 postscript length, and 4 magic bytes.
 ///
 ///
@@ -54,7 +60,8 @@ pub(crate) mod initial_read;
 /// ├────────────────────────────┤
 /// │                            │
 /// │    Postscript Flatbuffer   │
-/// │  (Schema & Layout Offsets) │
+/// │  (Schema & Layout Offsets, │
+/// │        Encodings)          │
 /// │                            │
 /// ├────────────────────────────┤
 /// │     8-byte End of File     │
@@ -70,7 +77,10 @@ pub struct VortexReadBuilder<R> {
     row_mask: Option<ArrayData>,
     row_filter: Option<RowFilter>,
     io_dispatcher: Option<Arc<IoDispatcher>>,
+    compute_dispatcher: Option<Arc<IoDispatcher>>,
     initial_read: Option<InitialRead>,
+    chunk_cache: Option<ChunkCache>,
+    memory_pool: Option<Arc<dyn MemoryPool>>,
 }
 
 impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
@@ -83,7 +93,10 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
             row_mask: None,
             row_filter: None,
             io_dispatcher: None,
+            compute_dispatcher: None,
             initial_read: None,
+            chunk_cache: None,
+            memory_pool: None,
         }
     }
 
@@ -92,17 +105,22 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
         self
     }
 
+    /// Read only the projected columns.
+    ///
+    /// The layouts and data of the other columns are never read, so a file can be read without
+    /// the columns this reader can't decode, as long as they aren't projected or filtered on.
     pub fn with_projection(mut self, projection: Projection) -> Self {
         self.projection = projection;
         self
     }
 
+    /// Read only the rows selected by the given array, much like a Parquet row selection.
+    ///
+    /// The array is either a non-nullable boolean mask over all rows of the file, or non-nullable
+    /// absolute row indices, which must be strictly increasing and less than the row count of the
+    /// file. Otherwise building the stream fails. Only the chunks containing some of the rows are
+    /// read, and the stream returns just those rows.
     pub fn with_indices(mut self, array: ArrayData) -> Self {
-        assert!(
-            !array.dtype().is_nullable() && (array.dtype().is_int() || array.dtype().is_boolean()),
-            "Mask arrays have to be non-nullable integer or boolean arrays"
-        );
-
         self.row_mask = Some(array);
         self
     }
@@ -117,11 +135,42 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
         self
     }
 
+    /// Decode arrays and evaluate the row filter on the threads of the given dispatcher, e.g. one
+    /// made with [`IoDispatcher::new_tokio`], rather than on the task polling the stream, so that
+    /// heavy decompression doesn't hold up IO.
+    ///
+    /// By default arrays are decoded inline.
+    pub fn with_compute_dispatcher(mut self, dispatcher: Arc<IoDispatcher>) -> Self {
+        self.compute_dispatcher = Some(dispatcher);
+        self
+    }
+
     pub fn with_initial_read(mut self, initial_read: InitialRead) -> Self {
         self.initial_read = Some(initial_read);
         self
     }
 
+    /// Keep canonicalized chunks in the given cache and reuse them instead of decoding the same
+    /// chunk again, e.g. for point lookups that repeatedly hit the same hot chunks.
+    ///
+    /// Readers of other files sharing the cache must be given a handle made for their file by
+    /// [`ChunkCache::for_file`].
+    pub fn with_chunk_cache(mut self, chunk_cache: ChunkCache) -> Self {
+        self.chunk_cache = Some(chunk_cache);
+        self
+    }
+
+    /// Reserve the bytes the stream reads from the pool, failing the stream rather than reading
+    /// more than the pool has room for.
+    ///
+    /// The bytes of each read are reserved before it's dispatched and released when the stream is
+    /// dropped. They include the arrays the stream returns, which view the bytes without copying
+    /// them, but not the chunks decoded into a chunk cache.
+    pub fn with_memory_pool(mut self, pool: Arc<dyn MemoryPool>) -> Self {
+        self.memory_pool = Some(pool);
+        self
+    }
+
     pub async fn build(self) -> VortexResult<VortexFileArrayStream<R>> {
         // we do a large enough initial read to get footer, layout, and schema
         let initial_read = match self.initial_read {
@@ -129,6 +178,23 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
             None => read_initial_bytes(&self.read_at, self.file_size().await?).await?,
         };
 
+        let layout_serde = self
+            .layout_serde
+            .with_metadata_format(initial_read.metadata_format());
+
+        // Name the encodings we can't read up front, rather than only failing with their codes on
+        // the first array of them. Columns that don't use them remain readable, so this only warns.
+        if let Some(encodings) = initial_read.encodings()? {
+            let missing = layout_serde.ctx().missing_encodings(&encodings)?;
+            if !missing.is_empty() {
+                warn!(
+                    "Missing encodings {} of the file written by Vortex {}, columns that use them can't be read",
+                    missing.join(", "),
+                    encodings.version()
+                );
+            }
+        }
+
         let layout = initial_read.fb_layout();
 
         let row_count = layout.row_count();
@@ -139,23 +205,29 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
             Projection::Flat(ref fields) => lazy_dtype.project(fields)?,
         };
 
-        let message_cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
-        let layout_reader = self.layout_serde.read_layout(
+        let mut message_cache = LayoutMessageCache::default();
+        if let Some(pool) = self.memory_pool {
+            message_cache = message_cache.with_memory_pool(pool);
+        }
+        let message_cache = Arc::new(RwLock::new(message_cache));
+        let layout_reader = layout_serde.read_layout(
             initial_read.fb_layout(),
             match self.projection {
                 Projection::All => Scan::empty(),
                 Projection::Flat(p) => Scan::new(Arc::new(Select::include(p))),
             },
-            RelativeLayoutCache::new(message_cache.clone(), lazy_dtype.clone()),
+            RelativeLayoutCache::new(message_cache.clone(), lazy_dtype.clone())
+                .with_chunk_cache(self.chunk_cache.clone()),
         )?;
 
         let filter_reader = self
             .row_filter
             .map(|row_filter| {
-                self.layout_serde.read_layout(
+                layout_serde.read_layout(
                     initial_read.fb_layout(),
                     Scan::new(Arc::new(row_filter)),
-                    RelativeLayoutCache::new(message_cache.clone(), lazy_dtype),
+                    RelativeLayoutCache::new(message_cache.clone(), lazy_dtype)
+                        .with_chunk_cache(self.chunk_cache.clone()),
                 )
             })
             .transpose()?;
@@ -163,12 +235,14 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
         let row_mask = self
             .row_mask
             .as_ref()
-            .map(|row_mask| {
-                if row_mask.dtype().is_int() {
-                    RowMask::from_index_array(row_mask, 0, row_count as usize)
-                } else {
+            .map(|row_mask| match row_mask.dtype() {
+                DType::Bool(NonNullable) => {
                     RowMask::from_mask_array(row_mask, 0, row_count as usize)
                 }
+                dtype if dtype.is_int() => row_indices_mask(row_mask, row_count as usize),
+                dtype => vortex_bail!(
+                    "Rows must be selected by a non-nullable boolean mask or integer indices, found {dtype}"
+                ),
             })
             .transpose()?;
 
@@ -184,6 +258,7 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
             row_count,
             row_mask,
             io_dispatcher,
+            self.compute_dispatcher,
         )
     }
 
@@ -194,3 +269,33 @@ impl<R: VortexReadAt + Unpin> VortexReadBuilder<R> {
         })
     }
 }
+
+/// The mask of the rows at the indices, which must be strictly increasing and within the file.
+fn row_indices_mask(indices: &ArrayData, row_count: usize) -> VortexResult<RowMask> {
+    if indices.dtype().is_nullable() {
+        vortex_bail!(
+            "Row indices must be non-nullable integers, found {}",
+            indices.dtype()
+        );
+    }
+    if indices.is_empty() {
+        return Ok(RowMask::new_invalid_between(0, row_count));
+    }
+    if !indices
+        .statistics()
+        .compute_is_strict_sorted()
+        .unwrap_or(false)
+    {
+        vortex_bail!("Row indices must be strictly increasing");
+    }
+    // Casting fails for negative indices, and the first and last bound the others.
+    let index_at = |idx: usize| -> VortexResult<u64> {
+        u64::try_from(&scalar_at(indices, idx)?.cast(&DType::Primitive(PType::U64, NonNullable))?)
+    };
+    index_at(0)?;
+    let last = index_at(indices.len() - 1)?;
+    if last >= row_count as u64 {
+        vortex_bail!("Row index {last} is out of bounds for {row_count} rows");
+    }
+    RowMask::from_index_array(indices, 0, row_count)
+}
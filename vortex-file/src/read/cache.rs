@@ -2,8 +2,12 @@ use std::fmt::Debug;
 use std::sync::{Arc, RwLock};
 
 use flatbuffers::root_unchecked;
+use moka::policy::EvictionPolicy;
+use moka::sync::Cache;
 use once_cell::sync::OnceCell;
 use vortex_array::aliases::hash_map::HashMap;
+use vortex_array::memory::{MemoryPool, MemoryReservation};
+use vortex_array::ArrayData;
 use vortex_buffer::Buffer;
 use vortex_dtype::field::Field;
 use vortex_dtype::flatbuffers::{extract_field, project_and_deserialize, resolve_field};
@@ -17,12 +21,28 @@ use crate::read::{LayoutPartId, MessageId};
 #[derive(Default, Debug)]
 pub struct LayoutMessageCache {
     cache: HashMap<MessageId, Buffer>,
+    /// The bytes of the cached messages and of the messages being read, if they count against a
+    /// memory pool.
+    reservation: Option<MemoryReservation>,
 }
 
 impl LayoutMessageCache {
     pub fn new() -> Self {
-        Self {
-            cache: HashMap::new(),
+        Self::default()
+    }
+
+    /// Reserve the bytes of the messages from the pool, see [`LayoutMessageCache::try_reserve`].
+    pub fn with_memory_pool(mut self, pool: Arc<dyn MemoryPool>) -> Self {
+        self.reservation = Some(MemoryReservation::new(pool));
+        self
+    }
+
+    /// Reserve the bytes of messages about to be read into the cache, failing if the memory pool
+    /// doesn't have room for them. The bytes are released as the messages are removed.
+    pub fn try_reserve(&mut self, bytes: usize) -> VortexResult<()> {
+        match &mut self.reservation {
+            Some(reservation) => reservation.try_resize(reservation.size() + bytes),
+            None => Ok(()),
         }
     }
 
@@ -31,11 +51,84 @@ impl LayoutMessageCache {
     }
 
     pub fn remove(&mut self, path: &[LayoutPartId]) -> Option<Buffer> {
-        self.cache.remove(path)
+        let removed = self.cache.remove(path);
+        if let Some(buf) = &removed {
+            self.release(buf.len());
+        }
+        removed
     }
 
     pub fn set(&mut self, path: MessageId, value: Buffer) {
-        self.cache.insert(path, value);
+        if let Some(replaced) = self.cache.insert(path, value) {
+            self.release(replaced.len());
+        }
+    }
+
+    fn release(&mut self, bytes: usize) {
+        if let Some(reservation) = &mut self.reservation {
+            reservation.resize(reservation.size().saturating_sub(bytes));
+        }
+    }
+}
+
+/// A least recently used cache of canonicalized arrays, bounded by their size in bytes and keyed
+/// by the file and the id of the layout message they were decoded from.
+///
+/// Sharing one cache between the streams of a file, see
+/// [`VortexReadBuilder::with_chunk_cache`](crate::VortexReadBuilder::with_chunk_cache), lets
+/// repeated reads of the same chunks skip decompression. Message ids are only unique within a
+/// single file, so the streams of other files must use handles made by
+/// [`for_file`](Self::for_file) with their own identity.
+#[derive(Debug, Clone)]
+pub struct ChunkCache {
+    arrays: Cache<(Arc<str>, MessageId), ArrayData>,
+    file: Arc<str>,
+}
+
+impl ChunkCache {
+    /// Create a cache holding decoded chunks of at most `capacity` bytes in total.
+    pub fn new(capacity: u64) -> Self {
+        Self {
+            arrays: Cache::builder()
+                .eviction_policy(EvictionPolicy::lru())
+                .weigher(|_, array: &ArrayData| u32::try_from(array.nbytes()).unwrap_or(u32::MAX))
+                .max_capacity(capacity)
+                .build(),
+            file: Arc::from(""),
+        }
+    }
+
+    /// A handle to the same cache for the chunks of the file with the given identity, e.g. its
+    /// path or URL, which must differ from that of every other file sharing the cache.
+    pub fn for_file(&self, file: impl Into<Arc<str>>) -> Self {
+        Self {
+            arrays: self.arrays.clone(),
+            file: file.into(),
+        }
+    }
+
+    pub fn get(&self, id: &[LayoutPartId]) -> Option<ArrayData> {
+        self.arrays.get(&(self.file.clone(), id.to_vec()))
+    }
+
+    pub fn set(&self, id: MessageId, array: ArrayData) {
+        self.arrays.insert((self.file.clone(), id), array);
+    }
+
+    /// The number of chunks in the cache, of every file.
+    pub fn len(&self) -> usize {
+        self.arrays.run_pending_tasks();
+        usize::try_from(self.arrays.entry_count()).unwrap_or(usize::MAX)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The total size of the chunks in the cache in bytes, of every file.
+    pub fn nbytes(&self) -> u64 {
+        self.arrays.run_pending_tasks();
+        self.arrays.weighted_size()
     }
 }
 
@@ -262,6 +355,7 @@ pub struct RelativeLayoutCache {
     root: Arc<RwLock<LayoutMessageCache>>,
     dtype: Arc<LazyDType>,
     path: MessageId,
+    chunks: Option<ChunkCache>,
 }
 
 impl RelativeLayoutCache {
@@ -270,9 +364,16 @@ impl RelativeLayoutCache {
             root,
             dtype,
             path: Vec::new(),
+            chunks: None,
         }
     }
 
+    /// Keep canonicalized arrays decoded through this cache, and its children, in `chunks`.
+    pub fn with_chunk_cache(mut self, chunks: Option<ChunkCache>) -> Self {
+        self.chunks = chunks;
+        self
+    }
+
     pub fn relative(&self, id: LayoutPartId, dtype: Arc<LazyDType>) -> Self {
         let mut new_path = Vec::with_capacity(self.path.len() + 1);
         new_path.clone_from(&self.path);
@@ -281,6 +382,7 @@ impl RelativeLayoutCache {
             root: self.root.clone(),
             path: new_path,
             dtype,
+            chunks: self.chunks.clone(),
         }
     }
 
@@ -314,6 +416,16 @@ impl RelativeLayoutCache {
             .remove(&self.absolute_id(path))
     }
 
+    pub fn chunk_cache(&self) -> Option<&ChunkCache> {
+        self.chunks.as_ref()
+    }
+
+    pub fn get_decoded(&self, path: &[LayoutPartId]) -> Option<ArrayData> {
+        self.chunks
+            .as_ref()
+            .and_then(|chunks| chunks.get(&self.absolute_id(path)))
+    }
+
     pub fn dtype(&self) -> &Arc<LazyDType> {
         &self.dtype
     }
@@ -325,3 +437,52 @@ impl RelativeLayoutCache {
         lookup_key
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex_array::array::PrimitiveArray;
+    use vortex_array::{IntoArrayData, IntoArrayVariant};
+
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used_bytes() {
+        let array = PrimitiveArray::from(vec![0i32; 100]).into_array();
+        let cache = ChunkCache::new(2 * array.nbytes() as u64);
+
+        cache.set(vec![0], array.clone());
+        cache.set(vec![1], array.clone());
+        // Insertions and accesses are recorded in batches, which counting the chunks applies
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&[0]).is_some());
+        cache.set(vec![2], array.clone());
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.nbytes(), 2 * array.nbytes() as u64);
+        assert!(cache.get(&[1]).is_none());
+        assert!(cache.get(&[0]).is_some());
+        assert!(cache.get(&[2]).is_some());
+    }
+
+    #[test]
+    fn files_are_cached_apart() {
+        let cache = ChunkCache::new(1 << 20);
+        let other = cache.for_file("other");
+
+        cache.set(vec![0], PrimitiveArray::from(vec![1i32]).into_array());
+        assert!(other.get(&[0]).is_none());
+        other.set(vec![0], PrimitiveArray::from(vec![2i32]).into_array());
+
+        assert_eq!(cache.len(), 2);
+        let value = |cache: &ChunkCache| {
+            cache
+                .get(&[0])
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>()[0]
+        };
+        assert_eq!(value(&cache), 1);
+        assert_eq!(value(&other), 2);
+    }
+}
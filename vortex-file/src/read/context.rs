@@ -1,8 +1,11 @@
 use std::fmt::{Debug, Display, Formatter};
 use std::sync::Arc;
 
+use flatbuffers::{
+    ForwardsUOffset, InvalidFlatbuffer, Vector, Verifiable, Verifier, VerifierOptions,
+};
 use vortex_array::aliases::hash_map::HashMap;
-use vortex_array::Context;
+use vortex_array::{Context, MetadataFormat};
 use vortex_error::{vortex_err, VortexResult};
 use vortex_flatbuffers::footer as fb;
 
@@ -69,12 +72,15 @@ impl LayoutDeserializer {
         Self { ctx, layout_ctx }
     }
 
+    /// Create a reader for the layout, which is verified here rather than along with the rest of
+    /// the layout tree, so only the layouts of the projected columns are ever parsed.
     pub fn read_layout(
         &self,
         layout: fb::Layout,
         scan: Scan,
         message_cache: RelativeLayoutCache,
     ) -> VortexResult<Box<dyn LayoutReader>> {
+        verify_layout(&layout)?;
         let layout_id = LayoutId(layout.encoding());
         self.layout_ctx
             .lookup_layout(&layout_id)
@@ -85,4 +91,71 @@ impl LayoutDeserializer {
     pub(crate) fn ctx(&self) -> Arc<Context> {
         self.ctx.clone()
     }
+
+    /// Read array metadata in the given format, which depends on the version of the file.
+    pub(crate) fn with_metadata_format(self, metadata_format: MetadataFormat) -> Self {
+        if self.ctx.metadata_format() == metadata_format {
+            return self;
+        }
+        Self {
+            ctx: Arc::new(
+                self.ctx
+                    .as_ref()
+                    .clone()
+                    .with_metadata_format(metadata_format),
+            ),
+            layout_ctx: self.layout_ctx,
+        }
+    }
+}
+
+/// A `Layout` table whose verification covers its own fields and the offsets of its children, but
+/// not the child layouts themselves.
+///
+/// Verifying the whole layout tree of a file with tens of thousands of columns up front is slow,
+/// so each child layout is verified on demand, before its fields are first read.
+struct ShallowLayout;
+
+impl Verifiable for ShallowLayout {
+    fn run_verifier(v: &mut Verifier, pos: usize) -> Result<(), InvalidFlatbuffer> {
+        v.visit_table(pos)?
+            .visit_field::<u16>("encoding", fb::Layout::VT_ENCODING, false)?
+            .visit_field::<ForwardsUOffset<Vector<'_, fb::Buffer>>>(
+                "buffers",
+                fb::Layout::VT_BUFFERS,
+                false,
+            )?
+            .visit_field::<ForwardsUOffset<Vector<'_, u32>>>(
+                "children",
+                fb::Layout::VT_CHILDREN,
+                false,
+            )?
+            .visit_field::<u64>("row_count", fb::Layout::VT_ROW_COUNT, false)?
+            .visit_field::<ForwardsUOffset<Vector<'_, u8>>>(
+                "metadata",
+                fb::Layout::VT_METADATA,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+
+/// Verify the root `Layout` flatbuffer of a file, but none of the layouts below it.
+pub(crate) fn verify_root_layout(bytes: &[u8]) -> VortexResult<()> {
+    let opts = VerifierOptions::default();
+    let mut verifier = Verifier::new(&opts, bytes);
+    <ForwardsUOffset<ShallowLayout>>::run_verifier(&mut verifier, 0)?;
+    Ok(())
+}
+
+/// Verify a layout reached from a verified parent, before reading any of its fields.
+///
+/// Only the root of the layout flatbuffer is verified when a file is opened, so every child must
+/// be verified this way before it's read.
+pub fn verify_layout(layout: &fb::Layout) -> VortexResult<()> {
+    let opts = VerifierOptions::default();
+    let mut verifier = Verifier::new(&opts, layout._tab.buf());
+    ShallowLayout::run_verifier(&mut verifier, layout._tab.loc())?;
+    Ok(())
 }
@@ -18,6 +18,7 @@ use vortex_flatbuffers::footer as fb;
 use crate::layouts::RangedLayoutReader;
 use crate::pruning::PruningPredicate;
 use crate::read::cache::RelativeLayoutCache;
+use crate::read::context::verify_layout;
 use crate::read::mask::RowMask;
 use crate::{
     Layout, LayoutDeserializer, LayoutId, LayoutPartId, LayoutReader, LazyDType, MessageLocator,
@@ -108,23 +109,25 @@ impl ChunkedLayoutBuilder<'_> {
             .enumerate()
             .skip(if stats_layout.is_some() { 1 } else { 0 })
             .scan(0usize, |total_rows, (child_idx, next_chunk)| {
-                // Calculate the start/end range of the chunk in the global row offset range.
-                let chunk_start = *total_rows;
-                *total_rows += usize::try_from(next_chunk.row_count()).vortex_expect("row_count");
-                let chunk_end = *total_rows;
-
-                // Relative layout cache for the `child_idx`-th child.
-                let child_cache = self.message_cache.relative(
-                    child_idx.try_into().vortex_unwrap(),
-                    self.message_cache.dtype().clone(),
-                );
-
-                // Construct the ranged layout.
-                Some(
+                // The chunk's row count is read before its reader is created, which verifies it.
+                Some(verify_layout(&next_chunk).and_then(|()| {
+                    // Calculate the start/end range of the chunk in the global row offset range.
+                    let chunk_start = *total_rows;
+                    *total_rows +=
+                        usize::try_from(next_chunk.row_count()).vortex_expect("row_count");
+                    let chunk_end = *total_rows;
+
+                    // Relative layout cache for the `child_idx`-th child.
+                    let child_cache = self.message_cache.relative(
+                        child_idx.try_into().vortex_unwrap(),
+                        self.message_cache.dtype().clone(),
+                    );
+
+                    // Construct the ranged layout.
                     self.layout_builder
                         .read_layout(next_chunk, self.scan.clone(), child_cache)
-                        .map(|layout| RangedLayoutReader((chunk_start, chunk_end), layout)),
-                )
+                        .map(|layout| RangedLayoutReader((chunk_start, chunk_end), layout))
+                }))
             })
             .try_collect()?;
 
@@ -215,7 +218,18 @@ impl ChunkedLayoutReader {
             .filter(|(_, cr)| !cr.finished())
         {
             let layout_selection = mask.slice(*begin, *end)?.shift(*begin)?;
-            if let Some(rr) = layout.poll_read(&layout_selection)? {
+            // Chunks without any selected rows aren't read at all.
+            if layout_selection.is_all_false() {
+                *array_slot = ChildRead::Finished(None);
+                continue;
+            }
+            // Chunks whose overlapping rows are all selected are sliced rather than filtered.
+            let read = if layout_selection.true_count() == layout_selection.len() {
+                layout.poll_slice(layout_selection.begin(), layout_selection.end())?
+            } else {
+                layout.poll_read(&layout_selection)?
+            };
+            if let Some(rr) = read {
                 match rr {
                     PollRead::ReadMore(m) => {
                         messages_to_fetch.extend(m);
@@ -415,7 +429,10 @@ mod tests {
     use crate::read::cache::{LazyDType, RelativeLayoutCache};
     use crate::read::layouts::test_read::{filter_read_layout, read_layout, read_layout_data};
     use crate::read::mask::RowMask;
-    use crate::{write, LayoutDeserializer, LayoutMessageCache, RowFilter, Scan};
+    use crate::{
+        write, LayoutDeserializer, LayoutMessageCache, LayoutReader, MessageLocator, PollRead,
+        RowFilter, Scan,
+    };
 
     async fn layout_and_bytes(
         cache: Arc<RwLock<LayoutMessageCache>>,
@@ -563,6 +580,30 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn read_slice() {
+        let cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
+        let (_, projection_layout, buf, _) = layout_and_bytes(cache.clone(), Scan::empty()).await;
+
+        let arr = loop {
+            match projection_layout.poll_slice(150, 320).unwrap().unwrap() {
+                PollRead::ReadMore(m) => {
+                    let mut write_cache_guard = cache.write().unwrap();
+                    for MessageLocator(id, range) in m {
+                        write_cache_guard.set(id, buf.slice(range.as_range()));
+                    }
+                }
+                PollRead::Value(a) => break a,
+            }
+        };
+
+        assert_eq!(
+            arr.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            (50..100).chain(0..100).chain(0..20).collect::<Vec<_>>()
+        );
+    }
+
     #[tokio::test]
     #[cfg_attr(miri, ignore)]
     async fn read_multiple_selectors() {
@@ -2,7 +2,8 @@ use std::collections::BTreeSet;
 use std::io::Cursor;
 use std::sync::Arc;
 
-use vortex_array::{ArrayData, Context};
+use vortex_array::compute::slice;
+use vortex_array::{ArrayData, Context, IntoArrayData, IntoCanonical};
 use vortex_buffer::Buffer;
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_flatbuffers::footer;
@@ -84,6 +85,37 @@ impl FlatLayoutReader {
             None => vortex_bail!("Expected Array message, got EOF"),
         }
     }
+
+    /// Decode our message, reusing and populating the chunk cache if one is configured.
+    fn decoded_array(&self) -> VortexResult<Option<ArrayData>> {
+        if let Some(array) = self.message_cache.get_decoded(&[]) {
+            return Ok(Some(array));
+        }
+        let Some(buf) = self.message_cache.get(&[]) else {
+            return Ok(None);
+        };
+        self.cache_decoded(self.array_from_bytes(buf)?).map(Some)
+    }
+
+    fn cache_decoded(&self, array: ArrayData) -> VortexResult<ArrayData> {
+        match self.message_cache.chunk_cache() {
+            Some(chunks) => {
+                let canonical = array.into_canonical()?.into_array();
+                chunks.set(self.message_cache.absolute_id(&[]), canonical.clone());
+                Ok(canonical)
+            }
+            None => Ok(array),
+        }
+    }
+
+    fn evaluate(&self, array: ArrayData) -> VortexResult<ArrayData> {
+        self.scan
+            .expr
+            .as_ref()
+            .map(|e| e.evaluate(&array))
+            .transpose()
+            .map(|evaluated| evaluated.unwrap_or(array))
+    }
 }
 
 impl LayoutReader for FlatLayoutReader {
@@ -93,32 +125,49 @@ impl LayoutReader for FlatLayoutReader {
     }
 
     fn poll_read(&self, selection: &RowMask) -> VortexResult<Option<PollRead<ArrayData>>> {
-        if let Some(buf) = self.message_cache.get(&[]) {
-            let array = self.array_from_bytes(buf)?;
+        if let Some(array) = self.decoded_array()? {
             selection
                 .filter_array(array)?
-                .map(|s| {
-                    Ok(PollRead::Value(
-                        self.scan
-                            .expr
-                            .as_ref()
-                            .map(|e| e.evaluate(&s))
-                            .transpose()?
-                            .unwrap_or(s),
-                    ))
-                })
+                .map(|s| self.evaluate(s).map(PollRead::Value))
                 .transpose()
         } else {
             Ok(Some(PollRead::ReadMore(vec![self.own_message()])))
         }
     }
+
+    fn poll_slice(&self, begin: usize, end: usize) -> VortexResult<Option<PollRead<ArrayData>>> {
+        if begin == end {
+            return Ok(None);
+        }
+        if let Some(array) = self.message_cache.get_decoded(&[]) {
+            return self
+                .evaluate(slice(array, begin, end)?)
+                .map(|a| Some(PollRead::Value(a)));
+        }
+        let Some(buf) = self.message_cache.get(&[]) else {
+            return Ok(Some(PollRead::ReadMore(vec![self.own_message()])));
+        };
+        let array = self.array_from_bytes(buf)?;
+        let array = if begin == 0 && end == array.len() {
+            self.cache_decoded(array)?
+        } else {
+            // Decode only the sliced rows, which are not worth caching on their own
+            let sliced = slice(array, begin, end)?;
+            if self.message_cache.chunk_cache().is_some() {
+                sliced.into_canonical()?.into_array()
+            } else {
+                sliced
+            }
+        };
+        self.evaluate(array).map(|a| Some(PollRead::Value(a)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::sync::{Arc, RwLock};
 
-    use vortex_array::array::PrimitiveArray;
+    use vortex_array::array::{ConstantArray, PrimitiveArray};
     use vortex_array::{Context, IntoArrayData, IntoArrayVariant, ToArrayData};
     use vortex_buffer::Buffer;
     use vortex_dtype::PType;
@@ -129,7 +178,9 @@ mod tests {
     use crate::layouts::flat::FlatLayoutReader;
     use crate::read::cache::{LazyDType, RelativeLayoutCache};
     use crate::read::layouts::test_read::{filter_read_layout, read_layout};
-    use crate::{LayoutMessageCache, RowFilter, Scan};
+    use crate::{
+        ChunkCache, LayoutMessageCache, LayoutReader, MessageLocator, PollRead, RowFilter, Scan,
+    };
 
     async fn read_only_layout(
         cache: Arc<RwLock<LayoutMessageCache>>,
@@ -245,4 +296,47 @@ mod tests {
 
         assert!(arr.is_none());
     }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn slice_decodes_only_sliced_rows() {
+        let array = ConstantArray::new(7i32, 100).into_array();
+        let mut written = vec![];
+        SyncMessageWriter::new(&mut written)
+            .write_message(EncoderMessage::Array(&array))
+            .unwrap();
+        let chunks = ChunkCache::new(1 << 10);
+        let cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
+        let layout = FlatLayoutReader::new(
+            ByteRange::new(0, written.len() as u64),
+            Scan::empty(),
+            Arc::new(Context::default()),
+            RelativeLayoutCache::new(
+                cache.clone(),
+                Arc::new(LazyDType::from_dtype(PType::I32.into())),
+            )
+            .with_chunk_cache(Some(chunks.clone())),
+        );
+
+        let written = Buffer::from(written);
+        let read_slice = |begin, end| loop {
+            match layout.poll_slice(begin, end).unwrap().unwrap() {
+                PollRead::ReadMore(m) => {
+                    let mut write_cache_guard = cache.write().unwrap();
+                    for MessageLocator(id, range) in m {
+                        write_cache_guard.set(id, written.slice(range.as_range()));
+                    }
+                }
+                PollRead::Value(a) => break a,
+            }
+        };
+
+        let sliced = read_slice(10, 20).into_primitive().unwrap();
+        assert_eq!(sliced.maybe_null_slice::<i32>(), &[7; 10]);
+        assert_eq!(sliced.buffer().len(), 10 * size_of::<i32>());
+        assert!(chunks.is_empty());
+
+        assert_eq!(read_slice(0, 100).len(), 100);
+        assert_eq!(chunks.len(), 1);
+    }
 }
@@ -9,7 +9,10 @@ use vortex_array::validity::{ArrayValidity, LogicalValidity, Validity};
 use vortex_array::{ArrayData, IntoArrayData, IntoArrayVariant};
 use vortex_dtype::Nullability::NonNullable;
 use vortex_dtype::{DType, PType};
-use vortex_error::{vortex_bail, VortexResult, VortexUnwrap};
+use vortex_error::{vortex_bail, vortex_err, VortexResult, VortexUnwrap};
+
+/// The version of the serialized form of [RowMask], written as its first byte.
+const ROW_MASK_FORMAT_VERSION: u8 = 1;
 
 /// A RowMask captures a set of selected rows offset by a range.
 ///
@@ -126,6 +129,11 @@ impl RowMask {
         }
     }
 
+    /// The selected rows, relative to the begin row of the mask.
+    pub fn filter_mask(&self) -> &FilterMask {
+        &self.mask
+    }
+
     pub fn is_all_false(&self) -> bool {
         self.mask.true_count() == 0
     }
@@ -154,11 +162,8 @@ impl RowMask {
             if range_begin == self.begin && range_end == self.end {
                 self.mask.clone()
             } else {
-                FilterMask::from(
-                    self.mask
-                        .to_boolean_buffer()?
-                        .slice(range_begin - self.begin, range_end - range_begin),
-                )
+                self.mask
+                    .slice(range_begin - self.begin, range_end - range_begin)?
             },
             range_begin,
             range_end,
@@ -190,10 +195,9 @@ impl RowMask {
         filter(sliced, self.mask.clone()).map(Some)
     }
 
-    #[allow(deprecated)]
     fn to_indices_array(&self) -> VortexResult<ArrayData> {
         Ok(PrimitiveArray::from_vec(
-            self.mask.iter_indices()?.map(|i| i as u64).collect_vec(),
+            self.mask.indices()?.iter().map(|&i| i as u64).collect_vec(),
             Validity::NonNullable,
         )
         .into_array())
@@ -214,6 +218,127 @@ impl RowMask {
     pub fn true_count(&self) -> usize {
         self.mask.true_count()
     }
+
+    /// Serialize the mask, so that the result of a filtering pass can be persisted or sent to
+    /// other workers and reused, see [RowMask::from_bytes].
+    ///
+    /// The mask is written as a version byte, then little-endian u64s: the begin and end rows, the
+    /// number of runs of selected rows, and the begin and end of each run relative to the begin
+    /// row. Its size thus depends on how clustered the selected rows are rather than on the length
+    /// of the range.
+    pub fn to_bytes(&self) -> VortexResult<Vec<u8>> {
+        let slices = self.mask.slices()?;
+        let mut bytes = Vec::with_capacity(1 + 8 * (3 + 2 * slices.len()));
+        self.write_to(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Deserialize a mask written by [RowMask::to_bytes].
+    pub fn from_bytes(bytes: &[u8]) -> VortexResult<Self> {
+        let mut reader = MaskReader::new(bytes);
+        let mask = Self::read_from(&mut reader)?;
+        reader.finish()?;
+        Ok(mask)
+    }
+
+    /// Serialize the masks of a filtering pass, such as those of each split of a file, as their
+    /// count followed by each of them in the format of [RowMask::to_bytes].
+    pub fn to_bytes_all(masks: &[RowMask]) -> VortexResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(masks.len() as u64).to_le_bytes());
+        for mask in masks {
+            mask.write_to(&mut bytes)?;
+        }
+        Ok(bytes)
+    }
+
+    /// Deserialize masks written by [RowMask::to_bytes_all].
+    pub fn from_bytes_all(bytes: &[u8]) -> VortexResult<Vec<Self>> {
+        let mut reader = MaskReader::new(bytes);
+        let count = reader.read_usize()?;
+        let masks = (0..count)
+            .map(|_| Self::read_from(&mut reader))
+            .collect::<VortexResult<Vec<_>>>()?;
+        reader.finish()?;
+        Ok(masks)
+    }
+
+    fn write_to(&self, bytes: &mut Vec<u8>) -> VortexResult<()> {
+        let slices = self.mask.slices()?;
+        bytes.push(ROW_MASK_FORMAT_VERSION);
+        for value in [self.begin, self.end, slices.len()] {
+            bytes.extend_from_slice(&(value as u64).to_le_bytes());
+        }
+        for &(start, end) in slices {
+            bytes.extend_from_slice(&(start as u64).to_le_bytes());
+            bytes.extend_from_slice(&(end as u64).to_le_bytes());
+        }
+        Ok(())
+    }
+
+    fn read_from(reader: &mut MaskReader) -> VortexResult<Self> {
+        let version = reader.read_u8()?;
+        if version != ROW_MASK_FORMAT_VERSION {
+            vortex_bail!("Unsupported RowMask format version {version}");
+        }
+        let begin = reader.read_usize()?;
+        let end = reader.read_usize()?;
+        if begin > end {
+            vortex_bail!("Invalid RowMask range {}..{}", begin, end);
+        }
+        let len = end - begin;
+
+        let nslices = reader.read_usize()?;
+        let mut slices = Vec::with_capacity(nslices.min(reader.remaining() / 16));
+        for _ in 0..nslices {
+            let start = reader.read_usize()?;
+            let end = reader.read_usize()?;
+            if start >= end || end > len || slices.last().is_some_and(|&(_, prev)| start < prev) {
+                vortex_bail!("Invalid RowMask slice {start}..{end} in a mask of length {len}");
+            }
+            slices.push((start, end));
+        }
+        RowMask::try_new(FilterMask::from_slices(len, slices), begin, end)
+    }
+}
+
+/// Reads the fields of serialized [RowMask]s, failing on truncated input.
+struct MaskReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> MaskReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn take<const N: usize>(&mut self) -> VortexResult<[u8; N]> {
+        let (head, tail) = self
+            .bytes
+            .split_first_chunk::<N>()
+            .ok_or_else(|| vortex_err!("Truncated RowMask, expected {N} more bytes"))?;
+        self.bytes = tail;
+        Ok(*head)
+    }
+
+    fn read_u8(&mut self) -> VortexResult<u8> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn read_usize(&mut self) -> VortexResult<usize> {
+        Ok(usize::try_from(u64::from_le_bytes(self.take()?))?)
+    }
+
+    fn finish(self) -> VortexResult<()> {
+        if !self.bytes.is_empty() {
+            vortex_bail!("{} trailing bytes after RowMask", self.bytes.len());
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -282,6 +407,33 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn bytes_round_trip() {
+        let masks = vec![
+            RowMask::try_new(
+                FilterMask::from_iter([false, true, true, false, true, false]),
+                10,
+                16,
+            )
+            .unwrap(),
+            RowMask::new_valid_between(16, 20),
+            RowMask::new_invalid_between(20, 25),
+        ];
+        for mask in &masks {
+            let bytes = mask.to_bytes().unwrap();
+            assert_eq!(&RowMask::from_bytes(&bytes).unwrap(), mask);
+            assert!(RowMask::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        }
+
+        let bytes = RowMask::to_bytes_all(&masks).unwrap();
+        assert_eq!(RowMask::from_bytes_all(&bytes).unwrap(), masks);
+
+        let mut trailing = masks[1].to_bytes().unwrap();
+        trailing.push(0);
+        assert!(RowMask::from_bytes(&trailing).is_err());
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn filter_array() {
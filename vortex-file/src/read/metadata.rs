@@ -40,6 +40,7 @@ pub async fn fetch_metadata<R: VortexReadAt + Unpin>(
     let mut metadata_reader = BufferedLayoutReader::new(
         input,
         dispatcher,
+        None,
         stream::iter(iter::once(Ok(RowMask::new_valid_between(0, 1)))),
         MetadataMaskReader::new(root_layout),
         layout_cache,
@@ -16,6 +16,7 @@ pub mod metadata;
 pub mod projection;
 mod reader;
 mod recordbatchreader;
+mod roaring_mask;
 mod splits;
 mod stream;
 
@@ -32,6 +33,7 @@ use vortex_expr::ExprRef;
 
 use crate::byte_range::ByteRange;
 pub use crate::read::mask::RowMask;
+pub use crate::read::roaring_mask::RoaringRowMask;
 
 // Recommended read-size according to the AWS performance guide
 // FIXME(ngates): this is dumb
@@ -105,7 +107,7 @@ pub enum Prune {
 /// Layout readers are **synchronous** and **stateful**. A request to read a given row range may
 /// trigger a request for more messages, which will be handled by the caller, placing the messages
 /// back into the message cache for this layout as a result.
-pub trait LayoutReader: Debug + Send {
+pub trait LayoutReader: Debug + Send + Sync {
     /// Register all horizontal row boundaries of this layout.
     ///
     /// Layout should register all indivisible absolute row boundaries of the data stored in itself and its children.
@@ -122,6 +124,15 @@ pub trait LayoutReader: Debug + Send {
     /// The layout is finished producing data for selection when it returns None
     fn poll_read(&self, selector: &RowMask) -> VortexResult<Option<PollRead<ArrayData>>>;
 
+    /// Reads all the rows in the [begin, end) range, like [poll_read](Self::poll_read) with a
+    /// selection of every row in the range.
+    ///
+    /// Flat layouts slice their array before decoding it, so chunked layouts, which forward the
+    /// overlapping part of the range to each of their chunks, only decode the rows in the range.
+    fn poll_slice(&self, begin: usize, end: usize) -> VortexResult<Option<PollRead<ArrayData>>> {
+        self.poll_read(&RowMask::new_valid_between(begin, end))
+    }
+
     /// Reads the metadata of the layout, if it exists.
     ///
     /// `LayoutReader`s can override the default behavior, which is to return no metadata.
@@ -0,0 +1,300 @@
+use std::cmp::{max, min};
+use std::fmt::{Display, Formatter};
+
+use croaring::Bitmap;
+use vortex_array::compute::FilterMask;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::read::mask::RowMask;
+
+/// A set of selected rows within a range, like a [RowMask], held as a roaring bitmap.
+///
+/// Row zero of the bitmap represents the begin row of the mask. Unlike the dense bitmap of a
+/// [RowMask], the size of the bitmap depends on the number and clustering of the selected rows
+/// rather than the length of the range, which keeps masks over billions of rows small, and sets of
+/// them can be intersected and combined without being densified.
+///
+/// The range of a mask can hold at most `u32::MAX` rows.
+#[derive(Debug, Clone)]
+pub struct RoaringRowMask {
+    bitmap: Bitmap,
+    begin: usize,
+    end: usize,
+}
+
+impl Display for RoaringRowMask {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RoaringRowMask [{}..{}]", self.begin, self.end)
+    }
+}
+
+impl RoaringRowMask {
+    pub fn try_new(bitmap: Bitmap, begin: usize, end: usize) -> VortexResult<Self> {
+        let len = range_len(begin, end)?;
+        if let Some(max) = bitmap.maximum() {
+            if max >= len {
+                vortex_bail!(
+                    "Bitmap selects row {} beyond the given range {}..{}",
+                    max,
+                    begin,
+                    end
+                );
+            }
+        }
+        Ok(Self { bitmap, begin, end })
+    }
+
+    /// Construct a mask which selects every row in the given range.
+    pub fn new_valid_between(begin: usize, end: usize) -> VortexResult<Self> {
+        let len = range_len(begin, end)?;
+        Ok(Self {
+            bitmap: Bitmap::from_range(0..len),
+            begin,
+            end,
+        })
+    }
+
+    /// Construct a mask which selects no rows in the given range.
+    pub fn new_invalid_between(begin: usize, end: usize) -> VortexResult<Self> {
+        range_len(begin, end)?;
+        Ok(Self {
+            bitmap: Bitmap::new(),
+            begin,
+            end,
+        })
+    }
+
+    /// Convert a dense [RowMask] over the same range.
+    pub fn from_row_mask(mask: &RowMask) -> VortexResult<Self> {
+        range_len(mask.begin(), mask.end())?;
+        let mut bitmap = Bitmap::new();
+        for &(start, end) in mask.filter_mask().slices()? {
+            bitmap.add_range(u32::try_from(start)?..u32::try_from(end)?);
+        }
+        bitmap.run_optimize();
+        Ok(Self {
+            bitmap,
+            begin: mask.begin(),
+            end: mask.end(),
+        })
+    }
+
+    /// Convert into a dense [RowMask] over the same range.
+    pub fn to_row_mask(&self) -> VortexResult<RowMask> {
+        RowMask::try_new(self.to_filter_mask(), self.begin, self.end)
+    }
+
+    /// The selected rows as a [FilterMask] over the range.
+    ///
+    /// The mask is built from the runs of selected rows, which it caches, so that filtering with it
+    /// doesn't need to find them again.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_filter_mask(&self) -> FilterMask {
+        // The range holds at most u32::MAX rows.
+        let len = self.len() as u32;
+        // Each run of selected rows ends at the first unselected row after its start, so the
+        // cursors jump from run to run rather than visiting every row.
+        let unselected = self.bitmap.flip(0..len);
+        let mut selected_cursor = self.bitmap.cursor();
+        let mut unselected_cursor = unselected.cursor();
+
+        let mut slices = Vec::new();
+        while let Some(start) = selected_cursor.current() {
+            unselected_cursor.reset_at_or_after(start);
+            let end = unselected_cursor.current().unwrap_or(len);
+            slices.push((start as usize, end as usize));
+            selected_cursor.reset_at_or_after(end);
+        }
+        FilterMask::from_slices(self.len(), slices)
+    }
+
+    /// The selected rows, relative to the begin row of the mask.
+    pub fn bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    pub fn begin(&self) -> usize {
+        self.begin
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.begin
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.begin == self.end
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn true_count(&self) -> usize {
+        // The bitmap holds at most u32::MAX rows.
+        self.bitmap.cardinality() as usize
+    }
+
+    pub fn is_all_false(&self) -> bool {
+        self.bitmap.is_empty()
+    }
+
+    /// The rows selected by both masks, within the intersection of their ranges.
+    ///
+    /// Masks over disjoint ranges intersect in an empty range at the later begin row.
+    pub fn and(&self, other: &Self) -> VortexResult<Self> {
+        let begin = max(self.begin, other.begin);
+        let end = max(begin, min(self.end, other.end));
+        let len = range_len(begin, end)?;
+
+        let mut bitmap = self.bitmap_from(begin)?.and(&other.bitmap_from(begin)?);
+        bitmap.remove_range(len..);
+        Ok(Self { bitmap, begin, end })
+    }
+
+    /// The rows selected by either mask, within the smallest range covering both of theirs.
+    ///
+    /// The rows between disjoint ranges are not selected.
+    pub fn or(&self, other: &Self) -> VortexResult<Self> {
+        let begin = min(self.begin, other.begin);
+        let end = max(self.end, other.end);
+        range_len(begin, end)?;
+
+        let bitmap = self.bitmap_from(begin)?.or(&other.bitmap_from(begin)?);
+        Ok(Self { bitmap, begin, end })
+    }
+
+    /// Limit the mask to the [begin..end) range.
+    pub fn slice(&self, begin: usize, end: usize) -> VortexResult<Self> {
+        let range_begin = max(self.begin, begin);
+        let range_end = max(range_begin, min(self.end, end));
+        if range_begin == self.begin && range_end == self.end {
+            return Ok(self.clone());
+        }
+
+        let mut bitmap = self.bitmap_from(range_begin)?;
+        bitmap.remove_range(range_len(range_begin, range_end)?..);
+        Ok(Self {
+            bitmap,
+            begin: range_begin,
+            end: range_end,
+        })
+    }
+
+    /// Move the range of the mask down by `offset` rows, without changing its bitmap.
+    pub fn shift(self, offset: usize) -> VortexResult<Self> {
+        if self.begin < offset {
+            vortex_bail!(
+                "Can shift RoaringRowMask by at most {}, tried to shift by {offset}",
+                self.begin
+            )
+        }
+        Ok(Self {
+            bitmap: self.bitmap,
+            begin: self.begin - offset,
+            end: self.end - offset,
+        })
+    }
+
+    /// The bitmap relative to the given begin row instead of the begin row of the mask, dropping
+    /// the rows before it.
+    fn bitmap_from(&self, begin: usize) -> VortexResult<Bitmap> {
+        if begin == self.begin {
+            return Ok(self.bitmap.clone());
+        }
+        let offset = i64::try_from(self.begin)? - i64::try_from(begin)?;
+        Ok(self.bitmap.add_offset(offset))
+    }
+}
+
+/// The length of the range, which must fit a bitmap.
+fn range_len(begin: usize, end: usize) -> VortexResult<u32> {
+    if begin > end {
+        vortex_bail!("Invalid range {}..{}", begin, end);
+    }
+    u32::try_from(end - begin)
+        .map_err(|_| vortex_err!("Range {}..{} is too long for a RoaringRowMask", begin, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use croaring::Bitmap;
+    use vortex_array::compute::FilterMask;
+
+    use crate::read::roaring_mask::RoaringRowMask;
+    use crate::RowMask;
+
+    fn mask(rows: &[u32], begin: usize, end: usize) -> RoaringRowMask {
+        RoaringRowMask::try_new(Bitmap::of(rows), begin, end).unwrap()
+    }
+
+    #[test]
+    fn new_checks_range() {
+        assert!(RoaringRowMask::try_new(Bitmap::of(&[5]), 10, 15).is_err());
+        assert!(RoaringRowMask::try_new(Bitmap::of(&[4]), 10, 15).is_ok());
+        assert!(RoaringRowMask::new_valid_between(0, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn and_or() {
+        // Rows 12, 13, 17 and rows 13, 17, 18, 25.
+        let lhs = mask(&[2, 3, 7], 10, 20);
+        let rhs = mask(&[0, 4, 5, 12], 13, 26);
+
+        let and = lhs.and(&rhs).unwrap();
+        assert_eq!((and.begin(), and.end()), (13, 20));
+        assert_eq!(and.bitmap().to_vec(), vec![0, 4]);
+
+        let or = lhs.or(&rhs).unwrap();
+        assert_eq!((or.begin(), or.end()), (10, 26));
+        assert_eq!(or.bitmap().to_vec(), vec![2, 3, 7, 8, 15]);
+
+        let disjoint = lhs.and(&mask(&[0], 30, 40)).unwrap();
+        assert_eq!((disjoint.begin(), disjoint.end()), (30, 30));
+        assert!(disjoint.is_all_false());
+    }
+
+    #[test]
+    fn slice_and_shift() {
+        let sliced = mask(&[0, 1, 5, 8], 10, 20).slice(12, 30).unwrap();
+        assert_eq!((sliced.begin(), sliced.end()), (12, 20));
+        assert_eq!(sliced.bitmap().to_vec(), vec![3, 6]);
+
+        let shifted = sliced.shift(12).unwrap();
+        assert_eq!((shifted.begin(), shifted.end()), (0, 8));
+        assert_eq!(shifted.bitmap().to_vec(), vec![3, 6]);
+        assert!(shifted.shift(1).is_err());
+    }
+
+    #[test]
+    fn filter_mask_from_runs() {
+        let filter_mask = mask(&[0, 1, 2, 5, 8, 9], 10, 20).to_filter_mask();
+        assert_eq!(filter_mask.slices().unwrap(), &[(0, 3), (5, 6), (8, 10)]);
+
+        let all = RoaringRowMask::new_valid_between(3, 7)
+            .unwrap()
+            .to_filter_mask();
+        assert_eq!(all.slices().unwrap(), &[(0, 4)]);
+        let none = RoaringRowMask::new_invalid_between(3, 7)
+            .unwrap()
+            .to_filter_mask();
+        assert_eq!(none.true_count(), 0);
+    }
+
+    #[test]
+    fn row_mask_round_trip() {
+        let row_mask = RowMask::try_new(
+            FilterMask::from_iter([false, true, true, false, true]),
+            5,
+            10,
+        )
+        .unwrap();
+        let roaring = RoaringRowMask::from_row_mask(&row_mask).unwrap();
+        assert_eq!(roaring.bitmap().to_vec(), vec![1, 2, 4]);
+        assert_eq!(roaring.true_count(), 3);
+
+        let filter_mask = roaring.to_filter_mask();
+        assert_eq!(filter_mask.slices().unwrap(), &[(1, 3), (4, 5)]);
+        assert_eq!(roaring.to_row_mask().unwrap(), row_mask);
+    }
+}
@@ -5,7 +5,7 @@ use vortex_array::stats::ArrayStatistics;
 use vortex_error::{VortexResult, VortexUnwrap};
 
 use crate::read::buffered::ReadMasked;
-use crate::{LayoutReader, PollRead, Prune, RowMask};
+use crate::{LayoutReader, PollRead, Prune, RoaringRowMask, RowMask};
 
 /// Reads an array out of a [`LayoutReader`] as a [`RowMask`].
 ///
@@ -60,19 +60,52 @@ impl ReadMasked for ReadRowMask {
 
 pub struct SplitsAccumulator {
     splits: BTreeSet<usize>,
-    row_mask: Option<RowMask>,
+    row_mask: Option<SplitsMask>,
 }
 
 pub struct SplitsIntoIter {
     ranges: Box<dyn Iterator<Item = (usize, usize)> + Send>,
-    row_mask: Option<RowMask>,
+    row_mask: Option<SplitsMask>,
+}
+
+/// The rows selected over the whole file, held as a roaring bitmap while the splits are read so
+/// that only the mask of each split is dense.
+///
+/// Masks over more rows than a roaring bitmap holds stay dense.
+enum SplitsMask {
+    Roaring(RoaringRowMask),
+    Dense(RowMask),
+}
+
+impl SplitsMask {
+    fn new(row_mask: RowMask) -> Self {
+        RoaringRowMask::from_row_mask(&row_mask).map_or(Self::Dense(row_mask), Self::Roaring)
+    }
+
+    /// The mask of the rows in [begin..end), or `None` if none of them are selected.
+    fn slice(&self, begin: usize, end: usize) -> VortexResult<Option<RowMask>> {
+        let sliced = match self {
+            Self::Roaring(mask) => {
+                let sliced = mask.slice(begin, end)?;
+                if sliced.is_all_false() {
+                    return Ok(None);
+                }
+                sliced.to_row_mask()?
+            }
+            Self::Dense(mask) => mask.slice(begin, end)?,
+        };
+        Ok((!sliced.is_all_false()).then_some(sliced))
+    }
 }
 
 impl SplitsAccumulator {
     pub fn new(row_count: u64, row_mask: Option<RowMask>) -> Self {
         let mut splits = BTreeSet::new();
         splits.insert(row_count.try_into().vortex_unwrap());
-        Self { splits, row_mask }
+        Self {
+            splits,
+            row_mask: row_mask.map(SplitsMask::new),
+        }
     }
 
     pub fn append_splits(&mut self, other: &mut BTreeSet<usize>) {
@@ -101,15 +134,11 @@ impl Iterator for SplitsIntoIter {
         // Find next range that's not filtered out by supplied row_mask
         for (begin, end) in self.ranges.as_mut() {
             return if let Some(ref row_mask) = self.row_mask {
-                let sliced = match row_mask.slice(begin, end) {
-                    Ok(s) => s,
-                    Err(e) => return Some(Err(e)),
-                };
-
-                if sliced.is_all_false() {
-                    continue;
+                match row_mask.slice(begin, end) {
+                    Ok(Some(sliced)) => Some(Ok(sliced)),
+                    Ok(None) => continue,
+                    Err(e) => Some(Err(e)),
                 }
-                Some(Ok(sliced))
             } else {
                 Some(Ok(RowMask::new_valid_between(begin, end)))
             };
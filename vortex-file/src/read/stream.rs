@@ -47,6 +47,7 @@ impl<R: VortexReadAt + Unpin> VortexFileArrayStream<R> {
         row_count: u64,
         row_mask: Option<RowMask>,
         dispatcher: Arc<IoDispatcher>,
+        compute_dispatcher: Option<Arc<IoDispatcher>>,
     ) -> VortexResult<Self> {
         let mut reader_splits = BTreeSet::new();
         layout_reader.add_splits(0, &mut reader_splits)?;
@@ -63,6 +64,7 @@ impl<R: VortexReadAt + Unpin> VortexFileArrayStream<R> {
             Box::new(BufferedLayoutReader::new(
                 input.clone(),
                 dispatcher.clone(),
+                compute_dispatcher.clone(),
                 splits_stream,
                 ReadRowMask::new(fr),
                 messages_cache.clone(),
@@ -76,6 +78,7 @@ impl<R: VortexReadAt + Unpin> VortexFileArrayStream<R> {
         let array_reader = BufferedLayoutReader::new(
             input,
             dispatcher,
+            compute_dispatcher,
             mask_iterator,
             ReadArray::new(layout_reader),
             messages_cache,
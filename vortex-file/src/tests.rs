@@ -1,36 +1,47 @@
 #![allow(clippy::cast_possible_truncation)]
 use std::collections::BTreeSet;
-use std::iter;
-use std::sync::{Arc, RwLock};
+use std::future::Future;
+use std::ops::Range;
+use std::sync::{Arc, Mutex, RwLock};
+use std::{io, iter, mem};
 
-use futures::StreamExt;
+use futures::{stream, StreamExt};
 use futures_util::TryStreamExt;
 use itertools::Itertools;
 use vortex_array::accessor::ArrayAccessor;
-use vortex_array::array::{ChunkedArray, PrimitiveArray, StructArray, VarBinArray};
+use vortex_array::array::{
+    ChunkedArray, PrimitiveArray, PrimitiveEncoding, StructArray, VarBinArray, VarBinEncoding,
+};
 use vortex_array::compute::scalar_at;
+use vortex_array::encoding::Encoding;
+use vortex_array::memory::{GreedyMemoryPool, MemoryPool, UnboundedMemoryPool};
+use vortex_array::stream::ArrayStreamAdapter;
 use vortex_array::validity::Validity;
 use vortex_array::variants::{PrimitiveArrayTrait, StructArrayTrait};
-use vortex_array::{ArrayDType, ArrayData, ArrayLen, IntoArrayData, IntoArrayVariant, ToArrayData};
+use vortex_array::{
+    register_encoding, ArrayDType, ArrayData, ArrayLen, Context, IntoArrayData, IntoArrayVariant,
+    ToArrayData,
+};
 use vortex_buffer::Buffer;
+use vortex_dict::DictArray;
 use vortex_dtype::field::Field;
 use vortex_dtype::{DType, Nullability, PType, StructDType};
 use vortex_error::{vortex_panic, VortexResult};
 use vortex_expr::{BinaryExpr, Column, Literal, Operator};
-use vortex_io::VortexReadAt;
+use vortex_io::{IoDispatcher, VortexReadAt};
 
 use crate::builder::initial_read::read_initial_bytes;
 use crate::write::VortexFileWriter;
 use crate::{
-    LayoutDeserializer, LayoutMessageCache, Projection, RelativeLayoutCache, RowFilter, Scan,
-    VortexReadBuilder, V1_FOOTER_FBS_SIZE, VERSION,
+    ChunkCache, LayoutDeserializer, LayoutMessageCache, Projection, RelativeLayoutCache, RowFilter,
+    Scan, VortexReadBuilder, V1_FOOTER_FBS_SIZE, VERSION,
 };
 
 #[test]
 fn test_eof_values() {
     // this test exists as a reminder to think about whether we should increment the version
     // when we change the footer
-    assert_eq!(VERSION, 1);
+    assert_eq!(VERSION, 2);
     assert_eq!(V1_FOOTER_FBS_SIZE, 32);
 }
 
@@ -72,6 +83,324 @@ async fn test_read_simple() {
     assert_eq!(row_count, 8);
 }
 
+/// A file written by version 1 of the file format, which serialized all array metadata with
+/// flexbuffers, holding chunked strings, chunked nullable i64s and bools.
+const V1_FILE: &[u8] = include_bytes!("../testdata/v1.vortex");
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_read_version_1() {
+    let initial_read = read_initial_bytes(&Buffer::from(V1_FILE), V1_FILE.len() as u64)
+        .await
+        .unwrap();
+    assert_eq!(initial_read.version, 1);
+
+    let array = VortexReadBuilder::new(Buffer::from(V1_FILE), LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+    assert_eq!(array.len(), 8);
+
+    let strings = array
+        .field_by_name("strings")
+        .unwrap()
+        .into_varbinview()
+        .unwrap()
+        .with_iterator(|iter| {
+            iter.map(|s| String::from_utf8(s.unwrap().to_vec()).unwrap())
+                .collect::<Vec<_>>()
+        })
+        .unwrap();
+    assert_eq!(
+        strings,
+        ["ab", "foo", "bar", "baz", "ab", "foo", "bar", "baz"]
+    );
+
+    let numbers = array.field_by_name("numbers").unwrap();
+    assert_eq!(
+        (0..8)
+            .map(|i| scalar_at(&numbers, i)
+                .unwrap()
+                .as_primitive()
+                .typed_value::<i64>())
+            .collect::<Vec<_>>(),
+        [
+            Some(1),
+            None,
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(6),
+            None,
+            Some(8)
+        ]
+    );
+
+    let bools = array.field_by_name("bools").unwrap().into_bool().unwrap();
+    assert_eq!(
+        bools.boolean_buffer().iter().collect::<Vec<_>>(),
+        [true, false, true, true, false, false, true, false]
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_footer_records_encodings() {
+    let st = StructArray::from_fields(&[
+        (
+            "strings",
+            VarBinArray::from(vec!["ab", "foo", "bar", "baz"]).into_array(),
+        ),
+        (
+            "numbers",
+            PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        ),
+    ])
+    .unwrap();
+    let writer = VortexFileWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap();
+    let written = Buffer::from(writer.finalize().await.unwrap());
+
+    let initial_read = read_initial_bytes(&written, written.len() as u64)
+        .await
+        .unwrap();
+    let encodings = initial_read.encodings().unwrap().unwrap();
+    assert!(encodings.contains(VarBinEncoding::ID.code()));
+    assert!(encodings.contains(PrimitiveEncoding::ID.code()));
+    Context::default().check_encodings(&encodings).unwrap();
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_read_with_memory_pool() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from((0u64..1000).collect::<Vec<_>>()).into_array(),
+        PrimitiveArray::from((1000u64..2000).collect::<Vec<_>>()).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let writer = VortexFileWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap();
+    let written = Buffer::from(writer.finalize().await.unwrap());
+
+    let pool = Arc::new(UnboundedMemoryPool::default());
+    let mut stream = VortexReadBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_memory_pool(pool.clone())
+        .build()
+        .await
+        .unwrap();
+    let mut rows = 0;
+    while let Some(array) = stream.next().await {
+        rows += array.unwrap().len();
+    }
+    assert_eq!(rows, 2000);
+    // The stream holds the bytes of both chunks, which the arrays it returned view.
+    assert!(pool.reserved() >= 2000 * size_of::<u64>());
+    drop(stream);
+    assert_eq!(pool.reserved(), 0);
+
+    let pool = Arc::new(GreedyMemoryPool::new(1000));
+    assert!(
+        VortexReadBuilder::new(written, LayoutDeserializer::default())
+            .with_memory_pool(pool.clone())
+            .build()
+            .await
+            .unwrap()
+            .read_all()
+            .await
+            .is_err()
+    );
+    assert_eq!(pool.reserved(), 0);
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_read_columns_without_missing_encodings() {
+    let st = StructArray::from_fields(&[
+        (
+            "numbers",
+            PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        ),
+        (
+            "dict",
+            DictArray::try_new(
+                PrimitiveArray::from(vec![0u8, 1, 0, 1]).into_array(),
+                PrimitiveArray::from(vec![10i32, 20]).into_array(),
+            )
+            .unwrap()
+            .into_array(),
+        ),
+    ])
+    .unwrap();
+    let writer = VortexFileWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap();
+    let written = Buffer::from(writer.finalize().await.unwrap());
+
+    // The default context lacks the dict encoding, which only matters for the column using it.
+    let numbers = VortexReadBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_projection(Projection::new([0]))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+    assert_eq!(numbers.len(), 4);
+
+    assert!(
+        VortexReadBuilder::new(written, LayoutDeserializer::default())
+            .with_projection(Projection::new([1]))
+            .build()
+            .await
+            .unwrap()
+            .read_all()
+            .await
+            .is_err()
+    );
+}
+
+/// An encoding from outside of the Vortex crates, which wraps an array without changing it.
+mod passthrough {
+    use std::fmt::{Display, Formatter};
+
+    use serde::{Deserialize, Serialize};
+    use vortex_array::compute::ComputeVTable;
+    use vortex_array::stats::{StatisticsVTable, StatsSet};
+    use vortex_array::validity::{ArrayValidity, LogicalValidity, ValidityVTable};
+    use vortex_array::variants::{PrimitiveArrayTrait, VariantsVTable};
+    use vortex_array::visitor::{ArrayVisitor, VisitorVTable};
+    use vortex_array::{
+        impl_encoding, ArrayDType, ArrayData, ArrayLen, ArrayTrait, Canonical, IntoCanonical,
+    };
+    use vortex_error::{VortexExpect as _, VortexResult};
+
+    impl_encoding!("test.passthrough", 0x7e57, Passthrough);
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct PassthroughMetadata;
+
+    impl Display for PassthroughMetadata {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "PassthroughMetadata")
+        }
+    }
+
+    impl PassthroughArray {
+        pub fn try_new(inner: ArrayData) -> VortexResult<Self> {
+            Self::try_from_parts(
+                inner.dtype().clone(),
+                inner.len(),
+                PassthroughMetadata,
+                [inner].into(),
+                StatsSet::default(),
+            )
+        }
+
+        pub fn inner(&self) -> ArrayData {
+            self.as_ref()
+                .child(0, self.dtype(), self.len())
+                .vortex_expect("PassthroughArray is missing its inner array")
+        }
+    }
+
+    impl ArrayTrait for PassthroughArray {}
+
+    // The test only wraps primitive arrays.
+    impl VariantsVTable<PassthroughArray> for PassthroughEncoding {
+        fn as_primitive_array<'a>(
+            &self,
+            array: &'a PassthroughArray,
+        ) -> Option<&'a dyn PrimitiveArrayTrait> {
+            Some(array)
+        }
+    }
+
+    impl PrimitiveArrayTrait for PassthroughArray {}
+
+    impl ValidityVTable<PassthroughArray> for PassthroughEncoding {
+        fn is_valid(&self, array: &PassthroughArray, index: usize) -> bool {
+            array.inner().is_valid(index)
+        }
+
+        fn logical_validity(&self, array: &PassthroughArray) -> LogicalValidity {
+            array.inner().logical_validity()
+        }
+    }
+
+    impl VisitorVTable<PassthroughArray> for PassthroughEncoding {
+        fn accept(
+            &self,
+            array: &PassthroughArray,
+            visitor: &mut dyn ArrayVisitor,
+        ) -> VortexResult<()> {
+            visitor.visit_child("inner", &array.inner())
+        }
+    }
+
+    impl StatisticsVTable<PassthroughArray> for PassthroughEncoding {}
+
+    impl ComputeVTable for PassthroughEncoding {}
+
+    impl IntoCanonical for PassthroughArray {
+        fn into_canonical(self) -> VortexResult<Canonical> {
+            self.inner().into_canonical()
+        }
+    }
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_read_registered_encoding() {
+    use passthrough::{PassthroughArray, PassthroughEncoding};
+
+    let numbers =
+        PassthroughArray::try_new(PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array()).unwrap();
+    let st = StructArray::from_fields(&[("numbers", numbers.into_array())]).unwrap();
+    let writer = VortexFileWriter::new(Vec::new())
+        .write_array_columns(st.into_array())
+        .await
+        .unwrap();
+    let written = Buffer::from(writer.finalize().await.unwrap());
+
+    // Files are read with the default context, which only includes the encoding once it's
+    // registered.
+    assert!(
+        VortexReadBuilder::new(written.clone(), LayoutDeserializer::default())
+            .build()
+            .await
+            .unwrap()
+            .read_all()
+            .await
+            .is_err()
+    );
+    register_encoding(&PassthroughEncoding).unwrap();
+    let read = VortexReadBuilder::new(written, LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+
+    let numbers = StructArray::try_from(read).unwrap().field(0).unwrap();
+    assert_eq!(numbers.encoding().id(), PassthroughEncoding::ID);
+    assert_eq!(
+        numbers.into_primitive().unwrap().maybe_null_slice::<u32>(),
+        &[1, 2, 3, 4]
+    );
+}
+
 #[tokio::test]
 #[cfg_attr(miri, ignore)]
 async fn test_read_simple_with_spawn() {
@@ -289,6 +618,86 @@ async fn test_read_projection() {
     assert_eq!(actual, numbers_expected);
 }
 
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_read_projection_wide() {
+    let fields = (0..2000u32)
+        .map(|i| {
+            (
+                format!("col_{i}"),
+                PrimitiveArray::from(vec![i, i + 1, i + 2]).into_array(),
+            )
+        })
+        .collect_vec();
+    let st = StructArray::from_fields(&fields).unwrap();
+    let mut writer = VortexFileWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = Buffer::from(writer.finalize().await.unwrap());
+
+    let array = VortexReadBuilder::new(written, LayoutDeserializer::default())
+        .with_projection(Projection::Flat(vec![
+            Field::from("col_1999"),
+            Field::from("col_7"),
+        ]))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap();
+
+    assert_eq!(array.names().len(), 2);
+    let last = array.field(0).unwrap().into_primitive().unwrap();
+    assert_eq!(last.maybe_null_slice::<u32>(), [1999, 2000, 2001]);
+    let seventh = array.field(1).unwrap().into_primitive().unwrap();
+    assert_eq!(seventh.maybe_null_slice::<u32>(), [7, 8, 9]);
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn write_stream_row_groups() {
+    let batches = (0..4u32).map(|i| {
+        let numbers = PrimitiveArray::from(vec![i * 3, i * 3 + 1, i * 3 + 2]).into_array();
+        StructArray::from_fields(&[("numbers", numbers)]).map(IntoArrayData::into_array)
+    });
+    let dtype = DType::Struct(
+        StructDType::new(
+            vec!["numbers".into()].into(),
+            vec![DType::Primitive(PType::U32, Nullability::NonNullable)],
+        ),
+        Nullability::NonNullable,
+    );
+
+    let written = VortexFileWriter::new(Vec::new())
+        .write_array_columns_stream(ArrayStreamAdapter::new(dtype, stream::iter(batches)))
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+
+    let array = VortexReadBuilder::new(Buffer::from(written), LayoutDeserializer::default())
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap();
+    let numbers = array
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(
+        numbers.maybe_null_slice::<u32>(),
+        (0..12u32).collect_vec().as_slice()
+    );
+}
+
 #[tokio::test]
 #[cfg_attr(miri, ignore)]
 async fn unequal_batches() {
@@ -641,6 +1050,179 @@ async fn test_with_indices_simple() {
     assert_eq!(expected_numbers, actual_numbers);
 }
 
+/// Records the byte ranges read from the buffer.
+#[derive(Clone)]
+struct RecordingRead {
+    buffer: Buffer,
+    ranges: Arc<Mutex<Vec<Range<u64>>>>,
+}
+
+impl RecordingRead {
+    fn new(buffer: Buffer) -> Self {
+        Self {
+            buffer,
+            ranges: Arc::default(),
+        }
+    }
+
+    fn take_ranges(&self) -> Vec<Range<u64>> {
+        mem::take(
+            &mut self
+                .ranges
+                .lock()
+                .unwrap_or_else(|poison| vortex_panic!("Failed to lock ranges: {poison}")),
+        )
+    }
+}
+
+impl VortexReadAt for RecordingRead {
+    fn read_byte_range(
+        &self,
+        pos: u64,
+        len: u64,
+    ) -> impl Future<Output = io::Result<Buffer>> + 'static {
+        self.ranges
+            .lock()
+            .unwrap_or_else(|poison| vortex_panic!("Failed to lock ranges: {poison}"))
+            .push(pos..pos + len);
+        self.buffer.read_byte_range(pos, len)
+    }
+
+    fn size(&self) -> impl Future<Output = io::Result<u64>> + 'static {
+        self.buffer.size()
+    }
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_with_indices_skips_chunks() {
+    let expected_array = StructArray::from_fields(&[(
+        "numbers",
+        ChunkedArray::from_iter(
+            (0..5).map(|i| ArrayData::from((i * 100_000..(i + 1) * 100_000).collect_vec())),
+        )
+        .into_array(),
+    )])
+    .unwrap();
+
+    let writer = VortexFileWriter::new(Vec::new())
+        .write_array_columns(expected_array.into_array())
+        .await
+        .unwrap();
+    let read = RecordingRead::new(Buffer::from(writer.finalize().await.unwrap()));
+    let initial_read = read_initial_bytes(&read, read.size().await.unwrap())
+        .await
+        .unwrap();
+
+    let read_rows = |indices: Vec<u32>| {
+        let read = read.clone();
+        let initial_read = initial_read.clone();
+        async move {
+            read.take_ranges();
+            let rows = VortexReadBuilder::new(read.clone(), LayoutDeserializer::default())
+                .with_initial_read(initial_read)
+                .with_indices(ArrayData::from(indices))
+                .build()
+                .await
+                .unwrap()
+                .read_all()
+                .await
+                .unwrap()
+                .into_struct()
+                .unwrap()
+                .field(0)
+                .unwrap()
+                .into_primitive()
+                .unwrap();
+            (rows, read.take_ranges())
+        }
+    };
+
+    let (_, all_ranges) = read_rows((0..500_000).collect_vec()).await;
+    // The rows are in the first and last chunks only
+    let indices = vec![2u32, 50_000, 99_999, 400_000, 499_998];
+    let (actual, ranges) = read_rows(indices.clone()).await;
+    assert_eq!(
+        actual.maybe_null_slice::<i32>(),
+        indices.iter().map(|&i| i as i32).collect_vec()
+    );
+    // Reads less than a MiB apart are merged, which the three chunks in between are not, so
+    // only the first and last chunks are read.
+    let bytes_read = |ranges: &[Range<u64>]| ranges.iter().map(|r| r.end - r.start).sum::<u64>();
+    assert_eq!(ranges.len(), 2);
+    assert!(bytes_read(&ranges) * 2 < bytes_read(&all_ranges));
+
+    for invalid in [vec![3u32, 1], vec![1, 1], vec![10, 500_000]] {
+        assert!(
+            VortexReadBuilder::new(read.clone(), LayoutDeserializer::default())
+                .with_indices(ArrayData::from(invalid))
+                .build()
+                .await
+                .is_err()
+        );
+    }
+    assert!(
+        VortexReadBuilder::new(read.clone(), LayoutDeserializer::default())
+            .with_indices(PrimitiveArray::from_nullable_vec(vec![Some(1u32), None]).into_array())
+            .build()
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_projection_skips_unprojected_column() {
+    // A column that the reader can't decode, such as an encrypted one read without its keys,
+    // must not be needed to read the others.
+    let secret = VarBinArray::from_vec(
+        (0..1000).map(|i| format!("{i:04096}")).collect_vec(),
+        DType::Utf8(Nullability::NonNullable),
+    )
+    .into_array();
+    let struct_array = StructArray::from_fields(&[
+        ("ids", ArrayData::from((0..1000u32).collect_vec())),
+        ("secret", secret),
+    ])
+    .unwrap();
+
+    let writer = VortexFileWriter::new(Vec::new())
+        .write_array_columns(struct_array.into_array())
+        .await
+        .unwrap();
+    let read = RecordingRead::new(Buffer::from(writer.finalize().await.unwrap()));
+    // The initial read speculatively fetches the tail of the file, which here is all of it.
+    let initial_read = read_initial_bytes(&read, read.size().await.unwrap())
+        .await
+        .unwrap();
+    read.take_ranges();
+
+    let ids = VortexReadBuilder::new(read.clone(), LayoutDeserializer::default())
+        .with_initial_read(initial_read)
+        .with_projection(Projection::new([0]))
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .into_struct()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(ids.maybe_null_slice::<u32>(), (0..1000).collect_vec());
+
+    // The secret column holds 4 MB of the file, none of which is read.
+    let bytes_read = read
+        .take_ranges()
+        .iter()
+        .map(|r| r.end - r.start)
+        .sum::<u64>();
+    assert!(bytes_read < 4_000_000);
+}
+
 #[tokio::test]
 #[cfg_attr(miri, ignore)]
 async fn test_with_indices_on_two_columns() {
@@ -879,6 +1461,136 @@ async fn filter_string_chunked() {
     );
 }
 
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn filter_with_compute_dispatcher() {
+    let chunks = (0..4)
+        .map(|chunk| {
+            StructArray::from_fields(&[(
+                "id",
+                PrimitiveArray::from((chunk * 100..(chunk + 1) * 100).collect::<Vec<i32>>())
+                    .into_array(),
+            )])
+            .unwrap()
+            .into_array()
+        })
+        .collect::<Vec<_>>();
+    let dtype = chunks[0].dtype().clone();
+    let array = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+
+    let written_bytes = VortexFileWriter::new(Vec::new())
+        .write_array_columns(array)
+        .await
+        .unwrap()
+        .finalize()
+        .await
+        .unwrap();
+    let actual_array =
+        VortexReadBuilder::new(Buffer::from(written_bytes), LayoutDeserializer::default())
+            .with_row_filter(RowFilter::new(BinaryExpr::new_expr(
+                Column::new_expr(Field::from("id")),
+                Operator::Gte,
+                Literal::new_expr(250.into()),
+            )))
+            .with_compute_dispatcher(Arc::new(IoDispatcher::new_tokio(2)))
+            .build()
+            .await
+            .unwrap()
+            .read_all()
+            .await
+            .unwrap();
+
+    let ids = actual_array
+        .as_struct_array()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(
+        ids.maybe_null_slice::<i32>(),
+        (250..400).collect::<Vec<_>>()
+    );
+}
+
+async fn write_id_chunks(first_id: i32) -> Buffer {
+    let chunks = (0..4)
+        .map(|chunk| {
+            let ids = first_id + chunk * 100..first_id + (chunk + 1) * 100;
+            StructArray::from_fields(&[(
+                "id",
+                PrimitiveArray::from(ids.collect::<Vec<i32>>()).into_array(),
+            )])
+            .unwrap()
+            .into_array()
+        })
+        .collect::<Vec<_>>();
+    let dtype = chunks[0].dtype().clone();
+    let array = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+
+    Buffer::from(
+        VortexFileWriter::new(Vec::new())
+            .write_array_columns(array)
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap(),
+    )
+}
+
+async fn read_ids_from(written: Buffer, chunk_cache: ChunkCache, from: i32) -> Vec<i32> {
+    VortexReadBuilder::new(written, LayoutDeserializer::default())
+        .with_row_filter(RowFilter::new(BinaryExpr::new_expr(
+            Column::new_expr(Field::from("id")),
+            Operator::Gte,
+            Literal::new_expr(from.into()),
+        )))
+        .with_chunk_cache(chunk_cache)
+        .build()
+        .await
+        .unwrap()
+        .read_all()
+        .await
+        .unwrap()
+        .as_struct_array()
+        .unwrap()
+        .field(0)
+        .unwrap()
+        .into_primitive()
+        .unwrap()
+        .maybe_null_slice::<i32>()
+        .to_vec()
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn read_with_chunk_cache() {
+    let written = write_id_chunks(0).await;
+
+    // Room for two chunks of the column but not for all four
+    let capacity = 3 * 100 * size_of::<i32>() as u64;
+    let chunk_cache = ChunkCache::new(capacity);
+    for _ in 0..2 {
+        assert_eq!(
+            read_ids_from(written.clone(), chunk_cache.clone(), 250).await,
+            (250..400).collect::<Vec<_>>()
+        );
+        assert!(chunk_cache.nbytes() <= capacity);
+    }
+
+    // The same messages of another file are cached apart from those of the first one.
+    let other = write_id_chunks(1000).await;
+    assert_eq!(
+        read_ids_from(other, chunk_cache.for_file("other"), 1250).await,
+        (1250..1400).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        read_ids_from(written, chunk_cache, 250).await,
+        (250..400).collect::<Vec<_>>()
+    );
+}
+
 #[tokio::test]
 #[cfg_attr(miri, ignore)]
 async fn test_pruning_with_or() {
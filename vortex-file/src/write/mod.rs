@@ -1,7 +1,9 @@
 pub use layout::LayoutSpec;
-pub use writer::VortexFileWriter;
+pub use partitioned::{PartitionedWriter, NULL_PARTITION_VALUE};
+pub use writer::{ColumnStats, VortexFileWriter};
 
 mod layout;
+mod partitioned;
 mod postscript;
 mod stats_accumulator;
 mod writer;
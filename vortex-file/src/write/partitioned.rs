@@ -0,0 +1,444 @@
+//! Writing a dataset as a directory of Vortex files, partitioned by the values of some columns.
+
+use std::future::Future;
+
+use vortex_array::aliases::hash_map::HashMap;
+use vortex_array::array::StructArray;
+use vortex_array::compute::{filter, group_ids, scalar_at, FilterMask};
+use vortex_array::validity::Validity;
+use vortex_array::variants::StructArrayTrait;
+use vortex_array::{ArrayDType, ArrayData, ArrayLen, IntoArrayData};
+use vortex_dtype::field::Field;
+use vortex_dtype::{match_each_native_ptype, DType, FieldName};
+use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult};
+use vortex_io::VortexWrite;
+use vortex_scalar::Scalar;
+
+use crate::write::VortexFileWriter;
+
+/// The directory name used for null partition values, as written by Hive and understood by
+/// DataFusion's listing tables.
+pub const NULL_PARTITION_VALUE: &str = "__HIVE_DEFAULT_PARTITION__";
+
+const DEFAULT_MAX_OPEN_FILES: usize = 64;
+const DEFAULT_MAX_FILE_SIZE: u64 = 512 << 20;
+
+/// Writes batches of rows to one Vortex file per partition, where a partition is a distinct
+/// combination of values of the partition columns.
+///
+/// Files are laid out Hive-style, e.g. `year=2024/month=10/part-00003.vortex`, so that the
+/// directory can be read back as a DataFusion listing table with the partition columns declared
+/// as `table_partition_cols`. As in that layout, the partition columns are only recorded in the
+/// paths and are dropped from the files themselves.
+///
+/// Files are created through the `create` function, which is given the path of each new file
+/// relative to the root of the dataset. At most `max_open_files` files are written at once: when
+/// a batch needs another one, the least recently written file is finalized, and later rows of its
+/// partition go to a new file. Files are also finalized once they reach `max_file_size` bytes.
+pub struct PartitionedWriter<W, F> {
+    partition_by: Vec<FieldName>,
+    create: F,
+    max_open_files: usize,
+    max_file_size: u64,
+    dtype: Option<DType>,
+    open: HashMap<String, OpenFile<W>>,
+    finished: Vec<(String, W)>,
+    next_file: usize,
+    tick: u64,
+}
+
+struct OpenFile<W> {
+    path: String,
+    writer: VortexFileWriter<W>,
+    last_write: u64,
+}
+
+impl<W, F, Fut> PartitionedWriter<W, F>
+where
+    W: VortexWrite,
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = VortexResult<W>>,
+{
+    /// Create a writer partitioning batches by the named columns, in the order of the directory
+    /// levels they map to.
+    pub fn new<I, N>(partition_by: I, create: F) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<FieldName>,
+    {
+        Self {
+            partition_by: partition_by.into_iter().map(Into::into).collect(),
+            create,
+            max_open_files: DEFAULT_MAX_OPEN_FILES,
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            dtype: None,
+            open: HashMap::new(),
+            finished: Vec::new(),
+            next_file: 0,
+            tick: 0,
+        }
+    }
+
+    /// Set the maximum number of files written at once, which must be at least one.
+    pub fn with_max_open_files(mut self, max_open_files: usize) -> Self {
+        self.max_open_files = max_open_files.max(1);
+        self
+    }
+
+    /// Set the size in bytes after which a file is finalized and its partition rolls over to a
+    /// new file. Files can exceed the size by up to one batch of their partition and the footer.
+    pub fn with_max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
+
+    /// Route the rows of a struct array to the files of their partitions.
+    pub async fn write(&mut self, batch: ArrayData) -> VortexResult<()> {
+        match &self.dtype {
+            None => self.dtype = Some(batch.dtype().clone()),
+            Some(dtype) => {
+                if dtype != batch.dtype() {
+                    vortex_bail!(
+                        "Expected all batches to have the same dtype {}, found {}",
+                        dtype,
+                        batch.dtype()
+                    )
+                }
+            }
+        }
+
+        let batch = StructArray::try_from(batch)?;
+        let partition_columns = self
+            .partition_by
+            .iter()
+            .map(|name| {
+                batch
+                    .field_by_name(name)
+                    .ok_or_else(|| vortex_err!("Unknown partition column {name}"))
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+        let data_fields = batch
+            .names()
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| !self.partition_by.contains(name))
+            .map(|(idx, _)| Field::Index(idx))
+            .collect::<Vec<_>>();
+        let data = batch.project(&data_fields)?.into_array();
+
+        for (dir, rows) in group_rows(&self.partition_by, &partition_columns, batch.len())? {
+            let rows = if rows.len() == data.len() {
+                data.clone()
+            } else {
+                filter(&data, FilterMask::from_indices(data.len(), rows))?
+            };
+            self.write_partition(dir, rows).await?;
+        }
+        Ok(())
+    }
+
+    /// Finalize every open file, returning the path and writer of each file written, in the order
+    /// they were finalized.
+    pub async fn finish(mut self) -> VortexResult<Vec<(String, W)>> {
+        let mut open = self.open.drain().map(|(_, file)| file).collect::<Vec<_>>();
+        open.sort_by_key(|file| file.last_write);
+        for file in open {
+            self.finished
+                .push((file.path, file.writer.finalize().await?));
+        }
+        Ok(self.finished)
+    }
+
+    async fn write_partition(&mut self, dir: String, rows: ArrayData) -> VortexResult<()> {
+        if !self.open.contains_key(&dir) {
+            if self.open.len() >= self.max_open_files {
+                self.finalize_least_recent().await?;
+            }
+            let path = format!("{dir}part-{:05}.vortex", self.next_file);
+            self.next_file += 1;
+            let file = OpenFile {
+                writer: VortexFileWriter::new((self.create)(path.clone()).await?),
+                path,
+                last_write: 0,
+            };
+            self.open.insert(dir.clone(), file);
+        }
+
+        self.tick += 1;
+        // The file stays open if the write fails, so that it's still finalized by finish.
+        let file = self
+            .open
+            .get_mut(&dir)
+            .vortex_expect("The file of the partition was just opened");
+        file.writer.append_array_columns(rows).await?;
+        file.last_write = self.tick;
+
+        if file.writer.bytes_written() >= self.max_file_size {
+            if let Some(file) = self.open.remove(&dir) {
+                self.finished
+                    .push((file.path, file.writer.finalize().await?));
+            }
+        }
+        Ok(())
+    }
+
+    async fn finalize_least_recent(&mut self) -> VortexResult<()> {
+        let Some(dir) = self
+            .open
+            .iter()
+            .min_by_key(|(_, file)| file.last_write)
+            .map(|(dir, _)| dir.clone())
+        else {
+            return Ok(());
+        };
+        if let Some(file) = self.open.remove(&dir) {
+            self.finished
+                .push((file.path, file.writer.finalize().await?));
+        }
+        Ok(())
+    }
+}
+
+/// Group the rows of a batch by the directory of their partition, in the order each partition
+/// first occurs.
+fn group_rows(
+    names: &[FieldName],
+    columns: &[ArrayData],
+    len: usize,
+) -> VortexResult<Vec<(String, Vec<usize>)>> {
+    let keys = StructArray::try_new(
+        names.to_vec().into(),
+        columns.to_vec(),
+        len,
+        Validity::NonNullable,
+    )?;
+    let group_ids = group_ids(&keys)?;
+
+    // The directory of each group is rendered once, from its first row. Distinct values can still
+    // share a directory, e.g. NaNs with different payloads, so groups are merged by directory.
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    let mut group_positions = Vec::with_capacity(group_ids.ngroups());
+    for &row in group_ids.first_rows() {
+        let row = usize::try_from(row)?;
+        let mut dir = String::new();
+        for (name, column) in names.iter().zip(columns) {
+            dir.push_str(&escape_path_segment(name));
+            dir.push('=');
+            dir.push_str(&partition_value(&scalar_at(column, row)?)?);
+            dir.push('/');
+        }
+        let position = match positions.get(&dir) {
+            Some(&position) => position,
+            None => {
+                positions.insert(dir.clone(), groups.len());
+                groups.push((dir, Vec::new()));
+                groups.len() - 1
+            }
+        };
+        group_positions.push(position);
+    }
+    for (row, &id) in group_ids.ids().iter().enumerate() {
+        groups[group_positions[id]].1.push(row);
+    }
+    Ok(groups)
+}
+
+/// The directory name of a partition value.
+fn partition_value(scalar: &Scalar) -> VortexResult<String> {
+    if scalar.is_null() {
+        return Ok(NULL_PARTITION_VALUE.to_string());
+    }
+    match scalar.dtype() {
+        DType::Bool(_) => Ok(scalar.to_string()),
+        // The Display of primitive scalars is suffixed with their type, e.g. `2024_i32`.
+        DType::Primitive(ptype, _) => Ok(match_each_native_ptype!(ptype, |$T| {
+            scalar
+                .as_primitive()
+                .typed_value::<$T>()
+                .map(|value| escape_path_segment(&value.to_string()))
+                .unwrap_or_else(|| NULL_PARTITION_VALUE.to_string())
+        })),
+        DType::Utf8(_) => Ok(scalar
+            .as_utf8()
+            .value()
+            .map(|value| escape_path_segment(value.as_str()))
+            .unwrap_or_else(|| NULL_PARTITION_VALUE.to_string())),
+        dtype => vortex_bail!("Cannot partition by a column of type {dtype}"),
+    }
+}
+
+/// Percent-encode the characters that can't appear in a path segment of the directory layout.
+fn escape_path_segment(segment: &str) -> String {
+    let mut escaped = String::with_capacity(segment.len());
+    for c in segment.chars() {
+        if c.is_ascii_control() || matches!(c, '/' | '\\' | '=' | '%' | ':' | '*' | '?' | '"') {
+            escaped.push_str(&format!("%{:02X}", u32::from(c)));
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use std::io;
+
+    use vortex_array::array::{PrimitiveArray, VarBinArray};
+    use vortex_array::IntoArrayVariant;
+    use vortex_buffer::Buffer;
+    use vortex_io::IoBuf;
+
+    use super::*;
+    use crate::{LayoutDeserializer, VortexReadBuilder};
+
+    fn batch(years: &[i32], cities: &[Option<&str>], values: &[u64]) -> ArrayData {
+        StructArray::from_fields(&[
+            ("year", PrimitiveArray::from(years.to_vec()).into_array()),
+            (
+                "city",
+                VarBinArray::from_iter(cities.iter().copied(), DType::Utf8(true.into()))
+                    .into_array(),
+            ),
+            ("value", PrimitiveArray::from(values.to_vec()).into_array()),
+        ])
+        .unwrap()
+        .into_array()
+    }
+
+    async fn read_values(written: Vec<u8>) -> Vec<u64> {
+        let array = VortexReadBuilder::new(Buffer::from(written), LayoutDeserializer::default())
+            .build()
+            .await
+            .unwrap()
+            .read_all()
+            .await
+            .unwrap();
+        let st = array.into_struct().unwrap();
+        assert_eq!(st.names().len(), 1);
+        st.field(0)
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u64>()
+            .to_vec()
+    }
+
+    async fn create(_path: String) -> VortexResult<Vec<u8>> {
+        Ok(Vec::new())
+    }
+
+    #[tokio::test]
+    async fn routes_rows_to_partitions() {
+        let mut writer = PartitionedWriter::new(["year", "city"], create);
+        writer
+            .write(batch(
+                &[2023, 2024, 2023, 2024],
+                &[Some("a/b"), Some("c"), Some("a/b"), None],
+                &[1, 2, 3, 4],
+            ))
+            .await
+            .unwrap();
+        writer
+            .write(batch(&[2024], &[Some("c")], &[5]))
+            .await
+            .unwrap();
+
+        let mut files = writer.finish().await.unwrap();
+        files.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let paths = files
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "year=2023/city=a%2Fb/part-00000.vortex",
+                "year=2024/city=__HIVE_DEFAULT_PARTITION__/part-00002.vortex",
+                "year=2024/city=c/part-00001.vortex",
+            ]
+        );
+
+        let mut values = Vec::new();
+        for (_, written) in files {
+            values.push(read_values(written).await);
+        }
+        assert_eq!(values, [vec![1, 3], vec![4], vec![2, 5]]);
+    }
+
+    #[tokio::test]
+    async fn limits_open_files_and_size() {
+        let mut writer = PartitionedWriter::new(["year"], create).with_max_open_files(1);
+        for year in [2023, 2024, 2023] {
+            writer
+                .write(batch(&[year], &[None], &[u64::try_from(year).unwrap()]))
+                .await
+                .unwrap();
+        }
+        let files = writer.finish().await.unwrap();
+        assert_eq!(files.len(), 3);
+        assert_eq!(files[0].0, "year=2023/part-00000.vortex");
+        assert_eq!(files[2].0, "year=2023/part-00002.vortex");
+
+        let mut writer = PartitionedWriter::new(["year"], create).with_max_file_size(1);
+        for value in 0..3 {
+            writer
+                .write(batch(&[2023], &[None], &[value]))
+                .await
+                .unwrap();
+        }
+        let files = writer.finish().await.unwrap();
+        assert_eq!(files.len(), 3);
+        assert!(files.iter().all(|(_, written)| !written.is_empty()));
+    }
+
+    #[tokio::test]
+    async fn unknown_partition_column() {
+        let mut writer = PartitionedWriter::new(["month"], create);
+        assert!(writer.write(batch(&[2023], &[None], &[1])).await.is_err());
+    }
+
+    /// A writer whose first write fails.
+    #[derive(Default)]
+    struct FailFirstWrite {
+        failed: bool,
+        written: Vec<u8>,
+    }
+
+    impl VortexWrite for FailFirstWrite {
+        async fn write_all<B: IoBuf>(&mut self, buffer: B) -> io::Result<B> {
+            if !self.failed {
+                self.failed = true;
+                return Err(io::Error::other("first write fails"));
+            }
+            self.written.write_all(buffer).await
+        }
+
+        async fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_write_keeps_file_open() {
+        let mut writer = PartitionedWriter::new(["year", "city"], |_| async {
+            Ok(FailFirstWrite::default())
+        });
+        assert!(writer.write(batch(&[2023], &[None], &[1])).await.is_err());
+        writer.write(batch(&[2023], &[None], &[2])).await.unwrap();
+
+        let mut files = writer.finish().await.unwrap();
+        assert_eq!(files.len(), 1);
+        let (path, written) = files.remove(0);
+        assert_eq!(
+            path,
+            "year=2023/city=__HIVE_DEFAULT_PARTITION__/part-00000.vortex"
+        );
+        assert_eq!(read_values(written.written).await, [2]);
+    }
+}
@@ -1,4 +1,5 @@
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
+use vortex_array::EncodingSet;
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_flatbuffers::{footer as fb, FlatBufferRoot, WriteFlatBuffer};
 
@@ -6,6 +7,7 @@ use vortex_flatbuffers::{footer as fb, FlatBufferRoot, WriteFlatBuffer};
 pub struct Postscript {
     schema_offset: u64,
     layout_offset: u64,
+    encodings: Option<EncodingSet>,
 }
 
 impl Postscript {
@@ -20,8 +22,15 @@ impl Postscript {
         Ok(Self {
             schema_offset,
             layout_offset,
+            encodings: None,
         })
     }
+
+    /// Record the encodings of the arrays in the file.
+    pub fn with_encodings(mut self, encodings: EncodingSet) -> Self {
+        self.encodings = Some(encodings);
+        self
+    }
 }
 
 impl FlatBufferRoot for Postscript {}
@@ -33,11 +42,16 @@ impl WriteFlatBuffer for Postscript {
         &self,
         fbb: &mut FlatBufferBuilder<'fb>,
     ) -> WIPOffset<Self::Target<'fb>> {
+        let encodings = self
+            .encodings
+            .as_ref()
+            .map(|encodings| encodings.write_flatbuffer(fbb));
         fb::Postscript::create(
             fbb,
             &fb::PostscriptArgs {
                 schema_offset: self.schema_offset,
                 layout_offset: self.layout_offset,
+                encodings,
             },
         )
     }
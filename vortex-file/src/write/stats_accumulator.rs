@@ -2,21 +2,23 @@
 
 use itertools::Itertools;
 use vortex_array::array::StructArray;
-use vortex_array::builders::{builder_with_capacity, ArrayBuilder, ArrayBuilderExt};
-use vortex_array::stats::{ArrayStatistics as _, Stat};
+use vortex_array::builders::{builder_with_capacity, ArrayBuilder};
+use vortex_array::stats::{Stat, StatsPolicy};
 use vortex_array::validity::{ArrayValidity, Validity};
 use vortex_array::{ArrayData, IntoArrayData};
 use vortex_dtype::DType;
 use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
 
 pub struct StatsAccumulator {
     stats: Vec<Stat>,
+    policy: StatsPolicy,
     builders: Vec<Box<dyn ArrayBuilder>>,
     length: usize,
 }
 
 impl StatsAccumulator {
-    pub fn new(dtype: &DType, mut stats: Vec<Stat>) -> Self {
+    pub fn new(dtype: &DType, mut stats: Vec<Stat>, policy: StatsPolicy) -> Self {
         // Sort stats by their ordinal so we can recreate their dtype from bitset
         stats.sort_by_key(|s| u8::from(*s));
         let builders = stats
@@ -25,17 +27,39 @@ impl StatsAccumulator {
             .collect();
         Self {
             stats,
+            policy,
             builders,
             length: 0,
         }
     }
 
+    #[cfg(test)]
     pub fn push_chunk(&mut self, array: &ArrayData) -> VortexResult<()> {
-        for (s, builder) in self.stats.iter().zip_eq(self.builders.iter_mut()) {
-            if let Some(v) = array.statistics().compute(*s) {
-                builder.append_scalar(&v.cast(builder.dtype())?)?;
-            } else {
-                builder.append_null();
+        let stats = self.chunk_stats(array)?;
+        self.push_chunk_stats(stats)
+    }
+
+    /// Compute the statistics of a chunk, cast to the types of the statistics table, to be pushed
+    /// later with [push_chunk_stats](Self::push_chunk_stats).
+    pub fn chunk_stats(&self, array: &ArrayData) -> VortexResult<Vec<Option<Scalar>>> {
+        let computed = self.policy.compute_all(array, &self.stats);
+        self.stats
+            .iter()
+            .zip_eq(self.builders.iter())
+            .map(|(s, builder)| {
+                computed
+                    .get(*s)
+                    .map(|v| v.cast(builder.dtype()))
+                    .transpose()
+            })
+            .collect()
+    }
+
+    pub fn push_chunk_stats(&mut self, stats: Vec<Option<Scalar>>) -> VortexResult<()> {
+        for (value, builder) in stats.into_iter().zip_eq(self.builders.iter_mut()) {
+            match value {
+                Some(value) => builder.append_scalar(&value)?,
+                None => builder.append_null(),
             }
         }
         self.length += 1;
@@ -5,16 +5,18 @@ use std::{io, iter, mem};
 use futures::TryStreamExt;
 use futures_util::io::Cursor;
 use itertools::Itertools;
+use vortex_array::aliases::hash_map::HashMap;
 use vortex_array::array::{ChunkedArray, StructArray};
-use vortex_array::stats::{as_stat_bitset_bytes, ArrayStatistics, Stat};
+use vortex_array::stats::{as_stat_bitset_bytes, ArrayStatistics, Stat, StatsPolicy};
 use vortex_array::stream::ArrayStream;
-use vortex_array::{ArrayData, ArrayLen};
+use vortex_array::{ArrayData, ArrayLen, EncodingSet};
 use vortex_buffer::Buffer;
-use vortex_dtype::DType;
+use vortex_dtype::{DType, FieldName};
 use vortex_error::{vortex_bail, vortex_err, VortexExpect as _, VortexResult};
 use vortex_flatbuffers::{FlatBufferRoot, WriteFlatBuffer, WriteFlatBufferExt};
 use vortex_io::VortexWrite;
 use vortex_ipc::messages::{EncoderMessage, MessageEncoder};
+use vortex_scalar::Scalar;
 
 use crate::byte_range::ByteRange;
 use crate::write::postscript::Postscript;
@@ -31,13 +33,43 @@ const STATS_TO_WRITE: &[Stat] = &[
     Stat::IsSorted,
     Stat::IsStrictSorted,
     Stat::UncompressedSizeInBytes,
+    Stat::HasNaN,
 ];
 
+const SKETCH_STATS: &[Stat] = &[
+    Stat::BitWidthFreq,
+    Stat::TrailingZeroFreq,
+    Stat::ApproxDistinct,
+    Stat::Histogram,
+];
+
+/// Which statistics are computed for each chunk of a column and persisted in its statistics table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColumnStats {
+    /// Write no statistics table for the column.
+    None,
+    /// Only the bounds and null count of each chunk, plus whether it contains NaNs for float
+    /// columns, which are enough to prune chunks by simple comparisons.
+    MinMax,
+    /// The statistics written by default, including histograms if they're
+    /// [enabled](VortexFileWriter::with_histograms).
+    #[default]
+    Full,
+    /// The full statistics, plus the bit width and trailing zero frequencies, distinct count
+    /// sketch and histogram of each chunk.
+    Sketches,
+}
+
 pub struct VortexFileWriter<W> {
     write: Cursor<W>,
     row_count: u64,
     dtype: Option<DType>,
     column_writers: Vec<ColumnWriter>,
+    stats_to_write: Vec<Stat>,
+    stats_policy: StatsPolicy,
+    default_column_stats: ColumnStats,
+    column_stats: HashMap<FieldName, ColumnStats>,
+    encodings: EncodingSet,
 }
 
 impl<W: VortexWrite> VortexFileWriter<W> {
@@ -47,60 +79,146 @@ impl<W: VortexWrite> VortexFileWriter<W> {
             dtype: None,
             column_writers: Vec::new(),
             row_count: 0,
+            stats_to_write: STATS_TO_WRITE.to_vec(),
+            stats_policy: StatsPolicy::default(),
+            default_column_stats: ColumnStats::default(),
+            column_stats: HashMap::new(),
+            encodings: EncodingSet::default(),
         }
     }
 
-    pub async fn write_array_columns(self, array: ArrayData) -> VortexResult<Self> {
+    /// Use `stats_policy` to decide which statistics of each chunk to compute for the statistics
+    /// tables, instead of computing all of them. Statistics that aren't computed are written as
+    /// nulls, or left out of the table entirely if no chunk of the column has them.
+    pub fn with_stats_policy(mut self, stats_policy: StatsPolicy) -> Self {
+        self.stats_policy = stats_policy;
+        self
+    }
+
+    /// Also compute an equi-depth histogram of each chunk, stored in the statistics table of its
+    /// column for selectivity estimation.
+    pub fn with_histograms(mut self) -> Self {
+        self.stats_to_write.push(Stat::Histogram);
+        self
+    }
+
+    /// Write `stats` for each column that isn't configured with
+    /// [with_column_stats](Self::with_column_stats), instead of the [full](ColumnStats::Full)
+    /// statistics.
+    pub fn with_default_column_stats(mut self, stats: ColumnStats) -> Self {
+        self.default_column_stats = stats;
+        self
+    }
+
+    /// Write `stats` for the top-level column with the given name.
+    pub fn with_column_stats(mut self, column: impl Into<FieldName>, stats: ColumnStats) -> Self {
+        self.column_stats.insert(column.into(), stats);
+        self
+    }
+
+    /// The number of bytes written to the file so far, excluding the metadata and footer written
+    /// when it's finalized.
+    pub fn bytes_written(&self) -> u64 {
+        self.write.position()
+    }
+
+    pub async fn write_array_columns(mut self, array: ArrayData) -> VortexResult<Self> {
+        self.append_array_columns(array).await?;
+        Ok(self)
+    }
+
+    /// Write the columns of a struct array like [write_array_columns](Self::write_array_columns),
+    /// without taking the writer, which stays usable if writing fails.
+    pub(crate) async fn append_array_columns(&mut self, array: ArrayData) -> VortexResult<()> {
         if let Ok(chunked) = ChunkedArray::try_from(array.clone()) {
-            self.write_array_columns_stream(chunked.array_stream())
+            self.append_array_columns_stream(chunked.array_stream())
                 .await
         } else {
-            self.write_array_columns_stream(array.into_array_stream())
+            self.append_array_columns_stream(array.into_array_stream())
                 .await
         }
     }
 
+    /// Write a stream of struct arrays as row groups, flushing the underlying writer after each
+    /// one.
+    ///
+    /// Each array is written as soon as it arrives, and only the byte and row offsets of its
+    /// chunks are kept until the file is finalized, so tables much larger than memory can be
+    /// written while holding just one array of the stream at a time.
     pub async fn write_array_columns_stream<S: ArrayStream + Unpin>(
         mut self,
-        mut array_stream: S,
+        array_stream: S,
     ) -> VortexResult<Self> {
+        self.append_array_columns_stream(array_stream).await?;
+        Ok(self)
+    }
+
+    async fn append_array_columns_stream<S: ArrayStream + Unpin>(
+        &mut self,
+        mut array_stream: S,
+    ) -> VortexResult<()> {
+        self.check_dtype(array_stream.dtype())?;
+        while let Some(columns) = array_stream.try_next().await? {
+            self.write_columns(columns).await?;
+            self.write.flush().await?;
+        }
+        Ok(())
+    }
+
+    fn check_dtype(&mut self, dtype: &DType) -> VortexResult<()> {
         match self.dtype {
-            None => self.dtype = Some(array_stream.dtype().clone()),
+            None => self.dtype = Some(dtype.clone()),
             Some(ref sd) => {
-                if sd != array_stream.dtype() {
+                if sd != dtype {
                     vortex_bail!(
                         "Expected all arrays in the stream to have the same dtype {}, found {}",
                         sd,
-                        array_stream.dtype()
+                        dtype
                     )
                 }
             }
         }
+        Ok(())
+    }
 
-        while let Some(columns) = array_stream.try_next().await? {
-            let st = StructArray::try_from(columns)?;
-            self.row_count += st.len() as u64;
-            for (i, field) in st.children().enumerate() {
-                if let Ok(chunked_array) = ChunkedArray::try_from(field.clone()) {
-                    self.write_column_chunks(chunked_array.array_stream(), i)
-                        .await?
-                } else {
-                    self.write_column_chunks(field.into_array_stream(), i)
-                        .await?
-                }
-            }
+    async fn write_columns(&mut self, columns: ArrayData) -> VortexResult<()> {
+        let st = StructArray::try_from(columns)?;
+        // Every column is written before any of them records its chunks, so that a failed write
+        // leaves the batch out of the file, which can still be finalized.
+        let mut written = Vec::new();
+        for (i, field) in st.children().enumerate() {
+            let chunks = if let Ok(chunked_array) = ChunkedArray::try_from(field.clone()) {
+                self.write_column_chunks(chunked_array.array_stream(), i)
+                    .await?
+            } else {
+                self.write_column_chunks(field.into_array_stream(), i)
+                    .await?
+            };
+            written.push(chunks);
         }
-
-        Ok(self)
+        for (column_writer, chunks) in self.column_writers.iter_mut().zip_eq(written) {
+            column_writer.record_chunks(chunks)?;
+        }
+        self.row_count += st.len() as u64;
+        Ok(())
     }
 
-    async fn write_column_chunks<S>(&mut self, stream: S, column_idx: usize) -> VortexResult<()>
+    async fn write_column_chunks<S>(
+        &mut self,
+        stream: S,
+        column_idx: usize,
+    ) -> VortexResult<WrittenChunks>
     where
         S: ArrayStream + Unpin,
     {
         let column_writer = match self.column_writers.get_mut(column_idx) {
             None => {
-                self.column_writers.push(ColumnWriter::new(stream.dtype()));
+                let stats = self.stats_for_column(column_idx);
+                self.column_writers.push(ColumnWriter::new(
+                    stream.dtype(),
+                    &stats,
+                    self.stats_policy,
+                ));
 
                 assert_eq!(
                     self.column_writers.len(),
@@ -117,7 +235,44 @@ impl<W: VortexWrite> VortexFileWriter<W> {
             Some(x) => x,
         };
 
-        column_writer.write_chunks(stream, &mut self.write).await
+        column_writer
+            .write_chunks(stream, &mut self.write, &mut self.encodings)
+            .await
+    }
+
+    /// The statistics to compute for the top-level column with the given index.
+    fn stats_for_column(&self, column_idx: usize) -> Vec<Stat> {
+        let struct_dtype = self.dtype.as_ref().and_then(DType::as_struct);
+        let column_stats = struct_dtype
+            .and_then(|st| st.names().get(column_idx))
+            .and_then(|name| self.column_stats.get(name))
+            .copied()
+            .unwrap_or(self.default_column_stats);
+
+        match column_stats {
+            ColumnStats::None => Vec::new(),
+            ColumnStats::MinMax => {
+                let mut stats = vec![Stat::Min, Stat::Max, Stat::NullCount];
+                // Bounds of a float column can only prune chunks known not to contain NaNs
+                if struct_dtype
+                    .and_then(|st| st.dtypes().get(column_idx))
+                    .is_some_and(
+                        |dtype| matches!(dtype, DType::Primitive(ptype, _) if ptype.is_float()),
+                    )
+                {
+                    stats.push(Stat::HasNaN);
+                }
+                stats
+            }
+            ColumnStats::Full => self.stats_to_write.clone(),
+            ColumnStats::Sketches => self
+                .stats_to_write
+                .iter()
+                .chain(SKETCH_STATS)
+                .copied()
+                .unique()
+                .collect(),
+        }
     }
 
     async fn write_metadata_arrays(&mut self) -> VortexResult<LayoutSpec> {
@@ -125,7 +280,7 @@ impl<W: VortexWrite> VortexFileWriter<W> {
         for column_writer in mem::take(&mut self.column_writers) {
             column_layouts.push(
                 column_writer
-                    .write_metadata(self.row_count, &mut self.write)
+                    .write_metadata(self.row_count, &mut self.write, &mut self.encodings)
                     .await?,
             );
         }
@@ -153,7 +308,8 @@ impl<W: VortexWrite> VortexFileWriter<W> {
         // write the layout
         write_fb_raw(&mut self.write, top_level_layout).await?;
 
-        let footer = Postscript::try_new(dtype_offset, layout_offset)?;
+        let footer = Postscript::try_new(dtype_offset, layout_offset)?
+            .with_encodings(mem::take(&mut self.encodings));
         let footer_len = write_fb_raw(&mut self.write, footer).await?;
         if footer_len > MAX_FOOTER_SIZE as u64 {
             vortex_bail!(
@@ -185,6 +341,14 @@ async fn write_fb_raw<W: VortexWrite, F: WriteFlatBuffer + FlatBufferRoot>(
     Ok(buffer_len as u64)
 }
 
+/// The chunks of one batch of a column, written to the file but not yet recorded by its
+/// [ColumnWriter].
+struct WrittenChunks {
+    byte_offsets: Vec<u64>,
+    row_offsets: Vec<u64>,
+    stats: Vec<Vec<Option<Scalar>>>,
+}
+
 struct ColumnWriter {
     metadata: StatsAccumulator,
     batch_byte_offsets: Vec<Vec<u64>>,
@@ -192,9 +356,9 @@ struct ColumnWriter {
 }
 
 impl ColumnWriter {
-    fn new(dtype: &DType) -> Self {
+    fn new(dtype: &DType, stats: &[Stat], policy: StatsPolicy) -> Self {
         Self {
-            metadata: StatsAccumulator::new(dtype, STATS_TO_WRITE.to_vec()),
+            metadata: StatsAccumulator::new(dtype, stats.to_vec(), policy),
             batch_byte_offsets: Vec::new(),
             batch_row_offsets: Vec::new(),
         }
@@ -204,9 +368,10 @@ impl ColumnWriter {
         &mut self,
         mut stream: S,
         write: &mut Cursor<W>,
-    ) -> VortexResult<()> {
-        let mut offsets = Vec::with_capacity(stream.size_hint().0 + 1);
-        offsets.push(write.position());
+        encodings: &mut EncodingSet,
+    ) -> VortexResult<WrittenChunks> {
+        let mut byte_offsets = Vec::with_capacity(stream.size_hint().0 + 1);
+        byte_offsets.push(write.position());
         let mut row_offsets = Vec::with_capacity(stream.size_hint().0 + 1);
         row_offsets.push(
             self.batch_row_offsets
@@ -215,30 +380,44 @@ impl ColumnWriter {
                 .copied()
                 .unwrap_or(0),
         );
+        let mut stats = Vec::with_capacity(stream.size_hint().0);
 
         let mut rows_written = row_offsets[0];
 
         while let Some(chunk) = stream.try_next().await? {
             rows_written += chunk.len() as u64;
 
-            // accumulate the stats for the stats table
-            self.metadata.push_chunk(&chunk)?;
+            // compute the stats for the stats table
+            stats.push(self.metadata.chunk_stats(&chunk)?);
 
             // clear the stats that we don't want to serialize into the file
             retain_only_stats(&chunk, STATS_TO_WRITE);
 
+            encodings.insert_array(&chunk);
             let mut encoder = MessageEncoder::default();
             for buffer in encoder.encode(EncoderMessage::Array(&chunk)) {
                 write.write_all(buffer).await?;
             }
 
-            offsets.push(write.position());
+            byte_offsets.push(write.position());
             row_offsets.push(rows_written);
         }
 
-        self.batch_byte_offsets.push(offsets);
-        self.batch_row_offsets.push(row_offsets);
+        Ok(WrittenChunks {
+            byte_offsets,
+            row_offsets,
+            stats,
+        })
+    }
 
+    /// Record chunks written by [write_chunks](Self::write_chunks) in the layout and statistics
+    /// table of the column.
+    fn record_chunks(&mut self, chunks: WrittenChunks) -> VortexResult<()> {
+        for stats in chunks.stats {
+            self.metadata.push_chunk_stats(stats)?;
+        }
+        self.batch_byte_offsets.push(chunks.byte_offsets);
+        self.batch_row_offsets.push(chunks.row_offsets);
         Ok(())
     }
 
@@ -246,6 +425,7 @@ impl ColumnWriter {
         self,
         row_count: u64,
         write: &mut Cursor<W>,
+        encodings: &mut EncodingSet,
     ) -> VortexResult<LayoutSpec> {
         let data_chunks = self
             .batch_byte_offsets
@@ -270,6 +450,7 @@ impl ColumnWriter {
 
             let stat_bitset = as_stat_bitset_bytes(&present_stats);
 
+            encodings.insert_array(&metadata_array);
             let metadata_array_begin = write.position();
             let mut encoder = MessageEncoder::default();
             for buffer in encoder.encode(EncoderMessage::Array(&metadata_array)) {
@@ -313,17 +494,29 @@ fn retain_only_stats(array: &ArrayData, stats: &[Stat]) {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::{Arc, RwLock};
+
     use flatbuffers::FlatBufferBuilder;
     use futures_executor::block_on;
+    use itertools::Itertools;
     use vortex_array::array::{PrimitiveArray, StructArray, VarBinArray};
-    use vortex_array::stats::PRUNING_STATS;
+    use vortex_array::compute::scalar_at;
+    use vortex_array::stats::{Stat, StatsPolicy, PRUNING_STATS};
     use vortex_array::validity::Validity;
-    use vortex_array::IntoArrayData;
+    use vortex_array::variants::StructArrayTrait;
+    use vortex_array::{ArrayDType, IntoArrayData};
+    use vortex_buffer::Buffer;
     use vortex_flatbuffers::WriteFlatBuffer;
+    use vortex_io::IoDispatcher;
 
+    use crate::metadata::fetch_metadata;
     use crate::write::postscript::Postscript;
-    use crate::write::writer::STATS_TO_WRITE;
-    use crate::{VortexFileWriter, V1_FOOTER_FBS_SIZE};
+    use crate::write::stats_accumulator::StatArray;
+    use crate::write::writer::{ColumnWriter, STATS_TO_WRITE};
+    use crate::{
+        read_initial_bytes, ColumnStats, LayoutDeserializer, LayoutMessageCache,
+        RelativeLayoutCache, Scan, VortexFileWriter, V1_FOOTER_FBS_SIZE,
+    };
 
     #[test]
     fn write_columns() {
@@ -343,6 +536,199 @@ mod tests {
         assert!(!written.is_empty());
     }
 
+    #[test]
+    fn write_columns_with_histograms() {
+        let numbers = PrimitiveArray::from_nullable_vec(vec![Some(1u32), None, Some(3), Some(4)]);
+        let st = StructArray::try_new(
+            ["numbers".into()].into(),
+            vec![numbers.into_array()],
+            4,
+            Validity::NonNullable,
+        )
+        .unwrap();
+        let writer = VortexFileWriter::new(Vec::new()).with_histograms();
+        let field = st.field(0).unwrap();
+        let mut column_writer =
+            ColumnWriter::new(field.dtype(), &writer.stats_to_write, writer.stats_policy);
+        column_writer.metadata.push_chunk(&field).unwrap();
+        let StatArray(stats_array, stats) = column_writer.metadata.into_array().unwrap().unwrap();
+        assert!(stats.contains(&Stat::Histogram));
+        let histogram = StructArray::try_from(stats_array)
+            .unwrap()
+            .field_by_name("histogram")
+            .unwrap();
+        let boundaries = scalar_at(histogram, 0)
+            .unwrap()
+            .as_list()
+            .elements()
+            .map(|b| u32::try_from(&b).unwrap())
+            .collect_vec();
+        // The three non-null values split into 32 buckets, so each boundary is repeated
+        assert_eq!(boundaries, [vec![1; 16], vec![3; 16], vec![4]].concat());
+
+        let written = block_on(async {
+            writer
+                .write_array_columns(st.into_array())
+                .await
+                .unwrap()
+                .finalize()
+                .await
+        })
+        .unwrap();
+        assert!(!written.is_empty());
+    }
+
+    #[test]
+    fn lazy_stats_policy_skips_histograms() {
+        let numbers = PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array();
+        let writer = VortexFileWriter::new(Vec::new())
+            .with_histograms()
+            .with_stats_policy(StatsPolicy::lazy());
+        let mut column_writer =
+            ColumnWriter::new(numbers.dtype(), &writer.stats_to_write, writer.stats_policy);
+        column_writer.metadata.push_chunk(&numbers).unwrap();
+        let StatArray(_, stats) = column_writer.metadata.into_array().unwrap().unwrap();
+        assert!(stats.contains(&Stat::Min));
+        assert!(!stats.contains(&Stat::Histogram));
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn per_column_stats() {
+        let st = StructArray::from_fields(&[
+            ("strings", VarBinArray::from(vec!["ab", "foo"]).into_array()),
+            ("numbers", PrimitiveArray::from(vec![1u32, 2]).into_array()),
+            (
+                "floats",
+                PrimitiveArray::from(vec![1.5f64, 2.5]).into_array(),
+            ),
+            (
+                "bounded_floats",
+                PrimitiveArray::from(vec![0.5f32, f32::NAN]).into_array(),
+            ),
+        ])
+        .unwrap();
+        let mut writer = VortexFileWriter::new(Vec::new())
+            .with_default_column_stats(ColumnStats::None)
+            .with_column_stats("numbers", ColumnStats::MinMax)
+            .with_column_stats("floats", ColumnStats::Sketches)
+            .with_column_stats("bounded_floats", ColumnStats::MinMax);
+        writer.dtype = Some(st.dtype().clone());
+
+        assert!(writer.stats_for_column(0).is_empty());
+        assert_eq!(
+            writer.stats_for_column(1),
+            vec![Stat::Min, Stat::Max, Stat::NullCount]
+        );
+        let sketches = writer.stats_for_column(2);
+        assert!(sketches.contains(&Stat::Min) && sketches.contains(&Stat::BitWidthFreq));
+        assert_eq!(
+            writer.stats_for_column(3),
+            vec![Stat::Min, Stat::Max, Stat::NullCount, Stat::HasNaN]
+        );
+
+        let written = Buffer::from(
+            writer
+                .write_array_columns(st.into_array())
+                .await
+                .unwrap()
+                .finalize()
+                .await
+                .unwrap(),
+        );
+        let initial_read = read_initial_bytes(&written, written.len() as u64)
+            .await
+            .unwrap();
+        let layout_message_cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
+        let layout_reader = LayoutDeserializer::default()
+            .read_layout(
+                initial_read.fb_layout(),
+                Scan::empty(),
+                RelativeLayoutCache::new(
+                    layout_message_cache.clone(),
+                    Arc::new(initial_read.lazy_dtype()),
+                ),
+            )
+            .unwrap();
+        let stats_tables = fetch_metadata(
+            written,
+            IoDispatcher::default().into(),
+            layout_reader,
+            layout_message_cache,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        let stat_names = stats_tables
+            .iter()
+            .map(|table| {
+                table.as_ref().map(|table| {
+                    table
+                        .as_struct_array()
+                        .unwrap()
+                        .names()
+                        .iter()
+                        .map(|name| name.to_string())
+                        .sorted()
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(stat_names[0], None);
+        assert_eq!(
+            stat_names[1].as_deref(),
+            Some(["max", "min", "null_count"].map(String::from).as_slice())
+        );
+        let sketches = stat_names[2].as_ref().unwrap();
+        assert!(sketches.iter().any(|name| name == "bit_width_frequency"));
+        assert!(sketches.iter().any(|name| name == "has_nan"));
+        assert_eq!(
+            stat_names[3].as_deref(),
+            Some(
+                ["has_nan", "max", "min", "null_count"]
+                    .map(String::from)
+                    .as_slice()
+            )
+        );
+    }
+
+    #[test]
+    fn struct_column_field_bounds() {
+        let nested = StructArray::try_new(
+            ["numbers".into()].into(),
+            vec![PrimitiveArray::from(vec![3u32, 1, 2]).into_array()],
+            3,
+            Validity::NonNullable,
+        )
+        .unwrap();
+        let mut column_writer =
+            ColumnWriter::new(nested.dtype(), STATS_TO_WRITE, StatsPolicy::default());
+        column_writer
+            .metadata
+            .push_chunk(&nested.into_array())
+            .unwrap();
+        let StatArray(table, stats) = column_writer.metadata.into_array().unwrap().unwrap();
+        assert!(stats.contains(&Stat::Min) && stats.contains(&Stat::Max));
+
+        let min = scalar_at(
+            StructArray::try_from(table)
+                .unwrap()
+                .field_by_name(Stat::Min.name())
+                .unwrap(),
+            0,
+        )
+        .unwrap();
+        assert_eq!(
+            min.as_struct()
+                .field("numbers")
+                .unwrap()
+                .as_primitive()
+                .typed_value::<u32>(),
+            Some(1)
+        );
+    }
+
     #[test]
     fn footer_size() {
         let footer = Postscript::try_new(1000000u64, 1100000u64).unwrap();
@@ -312,6 +312,122 @@ impl core::fmt::Debug for Array<'_> {
       ds.finish()
   }
 }
+pub enum ArrayStatOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A statistic without a dedicated field in ArrayStats.
+pub struct ArrayStat<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ArrayStat<'a> {
+  type Inner = ArrayStat<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ArrayStat<'a> {
+  pub const VT_STAT: flatbuffers::VOffsetT = 4;
+  pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ArrayStat { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ArrayStatArgs<'args>
+  ) -> flatbuffers::WIPOffset<ArrayStat<'bldr>> {
+    let mut builder = ArrayStatBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    builder.add_stat(args.stat);
+    builder.finish()
+  }
+
+
+  /// The discriminant of the statistic.
+  #[inline]
+  pub fn stat(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(ArrayStat::VT_STAT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn value(&self) -> Option<ScalarValue<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<ScalarValue>>(ArrayStat::VT_VALUE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ArrayStat<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u8>("stat", Self::VT_STAT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<ScalarValue>>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ArrayStatArgs<'a> {
+    pub stat: u8,
+    pub value: Option<flatbuffers::WIPOffset<ScalarValue<'a>>>,
+}
+impl<'a> Default for ArrayStatArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ArrayStatArgs {
+      stat: 0,
+      value: None,
+    }
+  }
+}
+
+pub struct ArrayStatBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ArrayStatBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_stat(&mut self, stat: u8) {
+    self.fbb_.push_slot::<u8>(ArrayStat::VT_STAT, stat, 0);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: flatbuffers::WIPOffset<ScalarValue<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<ScalarValue>>(ArrayStat::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ArrayStatBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ArrayStatBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ArrayStat<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ArrayStat<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ArrayStat");
+      ds.field("stat", &self.stat());
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
 pub enum ArrayStatsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -339,6 +455,10 @@ impl<'a> ArrayStats<'a> {
   pub const VT_BIT_WIDTH_FREQ: flatbuffers::VOffsetT = 20;
   pub const VT_TRAILING_ZERO_FREQ: flatbuffers::VOffsetT = 22;
   pub const VT_UNCOMPRESSED_SIZE_IN_BYTES: flatbuffers::VOffsetT = 24;
+  pub const VT_APPROX_DISTINCT: flatbuffers::VOffsetT = 26;
+  pub const VT_HAS_NAN: flatbuffers::VOffsetT = 28;
+  pub const VT_VERSION: flatbuffers::VOffsetT = 30;
+  pub const VT_EXTRA: flatbuffers::VOffsetT = 32;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -354,10 +474,14 @@ impl<'a> ArrayStats<'a> {
     if let Some(x) = args.null_count { builder.add_null_count(x); }
     if let Some(x) = args.true_count { builder.add_true_count(x); }
     if let Some(x) = args.run_count { builder.add_run_count(x); }
+    if let Some(x) = args.extra { builder.add_extra(x); }
+    if let Some(x) = args.approx_distinct { builder.add_approx_distinct(x); }
     if let Some(x) = args.trailing_zero_freq { builder.add_trailing_zero_freq(x); }
     if let Some(x) = args.bit_width_freq { builder.add_bit_width_freq(x); }
     if let Some(x) = args.max { builder.add_max(x); }
     if let Some(x) = args.min { builder.add_min(x); }
+    builder.add_version(args.version);
+    if let Some(x) = args.has_nan { builder.add_has_nan(x); }
     if let Some(x) = args.is_constant { builder.add_is_constant(x); }
     if let Some(x) = args.is_strict_sorted { builder.add_is_strict_sorted(x); }
     if let Some(x) = args.is_sorted { builder.add_is_sorted(x); }
@@ -442,6 +566,36 @@ impl<'a> ArrayStats<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<u64>(ArrayStats::VT_UNCOMPRESSED_SIZE_IN_BYTES, None)}
   }
+  #[inline]
+  pub fn approx_distinct(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ArrayStats::VT_APPROX_DISTINCT, None)}
+  }
+  #[inline]
+  pub fn has_nan(&self) -> Option<bool> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ArrayStats::VT_HAS_NAN, None)}
+  }
+  /// The version of the layout of these statistics. Version 1 adds the extra statistics.
+  #[inline]
+  pub fn version(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(ArrayStats::VT_VERSION, Some(0)).unwrap()}
+  }
+  /// Statistics without a dedicated field above, keyed by their discriminant.
+  #[inline]
+  pub fn extra(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ArrayStat<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ArrayStat>>>>(ArrayStats::VT_EXTRA, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for ArrayStats<'_> {
@@ -462,6 +616,10 @@ impl flatbuffers::Verifiable for ArrayStats<'_> {
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u64>>>("bit_width_freq", Self::VT_BIT_WIDTH_FREQ, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u64>>>("trailing_zero_freq", Self::VT_TRAILING_ZERO_FREQ, false)?
      .visit_field::<u64>("uncompressed_size_in_bytes", Self::VT_UNCOMPRESSED_SIZE_IN_BYTES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("approx_distinct", Self::VT_APPROX_DISTINCT, false)?
+     .visit_field::<bool>("has_nan", Self::VT_HAS_NAN, false)?
+     .visit_field::<u16>("version", Self::VT_VERSION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ArrayStat>>>>("extra", Self::VT_EXTRA, false)?
      .finish();
     Ok(())
   }
@@ -478,6 +636,10 @@ pub struct ArrayStatsArgs<'a> {
     pub bit_width_freq: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u64>>>,
     pub trailing_zero_freq: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u64>>>,
     pub uncompressed_size_in_bytes: Option<u64>,
+    pub approx_distinct: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub has_nan: Option<bool>,
+    pub version: u16,
+    pub extra: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ArrayStat<'a>>>>>,
 }
 impl<'a> Default for ArrayStatsArgs<'a> {
   #[inline]
@@ -494,6 +656,10 @@ impl<'a> Default for ArrayStatsArgs<'a> {
       bit_width_freq: None,
       trailing_zero_freq: None,
       uncompressed_size_in_bytes: None,
+      approx_distinct: None,
+      has_nan: None,
+      version: 0,
+      extra: None,
     }
   }
 }
@@ -548,6 +714,22 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ArrayStatsBuilder<'a, 'b, A> {
     self.fbb_.push_slot_always::<u64>(ArrayStats::VT_UNCOMPRESSED_SIZE_IN_BYTES, uncompressed_size_in_bytes);
   }
   #[inline]
+  pub fn add_approx_distinct(&mut self, approx_distinct: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ArrayStats::VT_APPROX_DISTINCT, approx_distinct);
+  }
+  #[inline]
+  pub fn add_has_nan(&mut self, has_nan: bool) {
+    self.fbb_.push_slot_always::<bool>(ArrayStats::VT_HAS_NAN, has_nan);
+  }
+  #[inline]
+  pub fn add_version(&mut self, version: u16) {
+    self.fbb_.push_slot::<u16>(ArrayStats::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_extra(&mut self, extra: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ArrayStat<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ArrayStats::VT_EXTRA, extra);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ArrayStatsBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     ArrayStatsBuilder {
@@ -576,6 +758,10 @@ impl core::fmt::Debug for ArrayStats<'_> {
       ds.field("bit_width_freq", &self.bit_width_freq());
       ds.field("trailing_zero_freq", &self.trailing_zero_freq());
       ds.field("uncompressed_size_in_bytes", &self.uncompressed_size_in_bytes());
+      ds.field("approx_distinct", &self.approx_distinct());
+      ds.field("has_nan", &self.has_nan());
+      ds.field("version", &self.version());
+      ds.field("extra", &self.extra());
       ds.finish()
   }
 }
@@ -695,6 +881,238 @@ impl core::fmt::Debug for Buffer<'_> {
       ds.finish()
   }
 }
+pub enum EncodingSpecOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// An encoding, identified by the code its arrays are written with and by its name.
+pub struct EncodingSpec<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for EncodingSpec<'a> {
+  type Inner = EncodingSpec<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> EncodingSpec<'a> {
+  pub const VT_CODE: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    EncodingSpec { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args EncodingSpecArgs<'args>
+  ) -> flatbuffers::WIPOffset<EncodingSpec<'bldr>> {
+    let mut builder = EncodingSpecBuilder::new(_fbb);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_code(args.code);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn code(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(EncodingSpec::VT_CODE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(EncodingSpec::VT_NAME, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for EncodingSpec<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("code", Self::VT_CODE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EncodingSpecArgs<'a> {
+    pub code: u16,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for EncodingSpecArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    EncodingSpecArgs {
+      code: 0,
+      name: None,
+    }
+  }
+}
+
+pub struct EncodingSpecBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EncodingSpecBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_code(&mut self, code: u16) {
+    self.fbb_.push_slot::<u16>(EncodingSpec::VT_CODE, code, 0);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(EncodingSpec::VT_NAME, name);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EncodingSpecBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    EncodingSpecBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<EncodingSpec<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for EncodingSpec<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("EncodingSpec");
+      ds.field("code", &self.code());
+      ds.field("name", &self.name());
+      ds.finish()
+  }
+}
+pub enum EncodingSetOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// The encodings of the arrays in a file or stream, and the version of Vortex that wrote them, so
+/// that readers can check they know every encoding before decoding any array.
+pub struct EncodingSet<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for EncodingSet<'a> {
+  type Inner = EncodingSet<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> EncodingSet<'a> {
+  pub const VT_VERSION: flatbuffers::VOffsetT = 4;
+  pub const VT_ENCODINGS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    EncodingSet { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args EncodingSetArgs<'args>
+  ) -> flatbuffers::WIPOffset<EncodingSet<'bldr>> {
+    let mut builder = EncodingSetBuilder::new(_fbb);
+    if let Some(x) = args.encodings { builder.add_encodings(x); }
+    if let Some(x) = args.version { builder.add_version(x); }
+    builder.finish()
+  }
+
+
+  /// The version of Vortex that wrote the arrays.
+  #[inline]
+  pub fn version(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(EncodingSet::VT_VERSION, None)}
+  }
+  #[inline]
+  pub fn encodings(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EncodingSpec<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EncodingSpec>>>>(EncodingSet::VT_ENCODINGS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for EncodingSet<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("version", Self::VT_VERSION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<EncodingSpec>>>>("encodings", Self::VT_ENCODINGS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EncodingSetArgs<'a> {
+    pub version: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub encodings: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EncodingSpec<'a>>>>>,
+}
+impl<'a> Default for EncodingSetArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    EncodingSetArgs {
+      version: None,
+      encodings: None,
+    }
+  }
+}
+
+pub struct EncodingSetBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EncodingSetBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_version(&mut self, version: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(EncodingSet::VT_VERSION, version);
+  }
+  #[inline]
+  pub fn add_encodings(&mut self, encodings: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<EncodingSpec<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(EncodingSet::VT_ENCODINGS, encodings);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EncodingSetBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    EncodingSetBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<EncodingSet<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for EncodingSet<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("EncodingSet");
+      ds.field("version", &self.version());
+      ds.field("encodings", &self.encodings());
+      ds.finish()
+  }
+}
 #[inline]
 /// Verifies that a buffer of bytes contains a `ArrayData`
 /// and returns it.
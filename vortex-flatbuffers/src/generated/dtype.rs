@@ -133,10 +133,10 @@ impl flatbuffers::SimpleToVerifyInSlice for PType {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_TYPE: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_TYPE: u8 = 9;
+pub const ENUM_MAX_TYPE: u8 = 10;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_TYPE: [Type; 10] = [
+pub const ENUM_VALUES_TYPE: [Type; 11] = [
   Type::NONE,
   Type::Null,
   Type::Bool,
@@ -147,6 +147,7 @@ pub const ENUM_VALUES_TYPE: [Type; 10] = [
   Type::Struct_,
   Type::List,
   Type::Extension,
+  Type::FixedSizeList,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -164,9 +165,10 @@ impl Type {
   pub const Struct_: Self = Self(7);
   pub const List: Self = Self(8);
   pub const Extension: Self = Self(9);
+  pub const FixedSizeList: Self = Self(10);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 9;
+  pub const ENUM_MAX: u8 = 10;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::NONE,
     Self::Null,
@@ -178,6 +180,7 @@ impl Type {
     Self::Struct_,
     Self::List,
     Self::Extension,
+    Self::FixedSizeList,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -192,6 +195,7 @@ impl Type {
       Self::Struct_ => Some("Struct_"),
       Self::List => Some("List"),
       Self::Extension => Some("Extension"),
+      Self::FixedSizeList => Some("FixedSizeList"),
       _ => None,
     }
   }
@@ -558,6 +562,7 @@ impl<'a> Decimal<'a> {
   pub const VT_PRECISION: flatbuffers::VOffsetT = 4;
   pub const VT_SCALE: flatbuffers::VOffsetT = 6;
   pub const VT_NULLABLE: flatbuffers::VOffsetT = 8;
+  pub const VT_WIDE: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -569,6 +574,7 @@ impl<'a> Decimal<'a> {
     args: &'args DecimalArgs
   ) -> flatbuffers::WIPOffset<Decimal<'bldr>> {
     let mut builder = DecimalBuilder::new(_fbb);
+    builder.add_wide(args.wide);
     builder.add_nullable(args.nullable);
     builder.add_scale(args.scale);
     builder.add_precision(args.precision);
@@ -584,13 +590,13 @@ impl<'a> Decimal<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<u8>(Decimal::VT_PRECISION, Some(0)).unwrap()}
   }
-  /// Number of digits after the decimal point "."
+  /// Number of digits after the decimal point ".", negative for multiples of powers of ten
   #[inline]
-  pub fn scale(&self) -> u8 {
+  pub fn scale(&self) -> i8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(Decimal::VT_SCALE, Some(0)).unwrap()}
+    unsafe { self._tab.get::<i8>(Decimal::VT_SCALE, Some(0)).unwrap()}
   }
   #[inline]
   pub fn nullable(&self) -> bool {
@@ -599,6 +605,14 @@ impl<'a> Decimal<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<bool>(Decimal::VT_NULLABLE, Some(false)).unwrap()}
   }
+  /// Values are stored as 256-bit integers even though the precision fits in 128 bits
+  #[inline]
+  pub fn wide(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Decimal::VT_WIDE, Some(false)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for Decimal<'_> {
@@ -609,16 +623,18 @@ impl flatbuffers::Verifiable for Decimal<'_> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
      .visit_field::<u8>("precision", Self::VT_PRECISION, false)?
-     .visit_field::<u8>("scale", Self::VT_SCALE, false)?
+     .visit_field::<i8>("scale", Self::VT_SCALE, false)?
      .visit_field::<bool>("nullable", Self::VT_NULLABLE, false)?
+     .visit_field::<bool>("wide", Self::VT_WIDE, false)?
      .finish();
     Ok(())
   }
 }
 pub struct DecimalArgs {
     pub precision: u8,
-    pub scale: u8,
+    pub scale: i8,
     pub nullable: bool,
+    pub wide: bool,
 }
 impl<'a> Default for DecimalArgs {
   #[inline]
@@ -627,6 +643,7 @@ impl<'a> Default for DecimalArgs {
       precision: 0,
       scale: 0,
       nullable: false,
+      wide: false,
     }
   }
 }
@@ -641,14 +658,18 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DecimalBuilder<'a, 'b, A> {
     self.fbb_.push_slot::<u8>(Decimal::VT_PRECISION, precision, 0);
   }
   #[inline]
-  pub fn add_scale(&mut self, scale: u8) {
-    self.fbb_.push_slot::<u8>(Decimal::VT_SCALE, scale, 0);
+  pub fn add_scale(&mut self, scale: i8) {
+    self.fbb_.push_slot::<i8>(Decimal::VT_SCALE, scale, 0);
   }
   #[inline]
   pub fn add_nullable(&mut self, nullable: bool) {
     self.fbb_.push_slot::<bool>(Decimal::VT_NULLABLE, nullable, false);
   }
   #[inline]
+  pub fn add_wide(&mut self, wide: bool) {
+    self.fbb_.push_slot::<bool>(Decimal::VT_WIDE, wide, false);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DecimalBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     DecimalBuilder {
@@ -669,6 +690,7 @@ impl core::fmt::Debug for Decimal<'_> {
       ds.field("precision", &self.precision());
       ds.field("scale", &self.scale());
       ds.field("nullable", &self.nullable());
+      ds.field("wide", &self.wide());
       ds.finish()
   }
 }
@@ -1130,6 +1152,7 @@ impl<'a> Extension<'a> {
   pub const VT_ID: flatbuffers::VOffsetT = 4;
   pub const VT_STORAGE_DTYPE: flatbuffers::VOffsetT = 6;
   pub const VT_METADATA: flatbuffers::VOffsetT = 8;
+  pub const VT_STORAGE_TRANSPARENT: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -1141,6 +1164,7 @@ impl<'a> Extension<'a> {
     args: &'args ExtensionArgs<'args>
   ) -> flatbuffers::WIPOffset<Extension<'bldr>> {
     let mut builder = ExtensionBuilder::new(_fbb);
+    if let Some(x) = args.storage_transparent { builder.add_storage_transparent(x); }
     if let Some(x) = args.metadata { builder.add_metadata(x); }
     if let Some(x) = args.storage_dtype { builder.add_storage_dtype(x); }
     if let Some(x) = args.id { builder.add_id(x); }
@@ -1169,6 +1193,13 @@ impl<'a> Extension<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Extension::VT_METADATA, None)}
   }
+  #[inline]
+  pub fn storage_transparent(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Extension::VT_STORAGE_TRANSPARENT, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for Extension<'_> {
@@ -1181,6 +1212,7 @@ impl flatbuffers::Verifiable for Extension<'_> {
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<DType>>("storage_dtype", Self::VT_STORAGE_DTYPE, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("metadata", Self::VT_METADATA, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("storage_transparent", Self::VT_STORAGE_TRANSPARENT, false)?
      .finish();
     Ok(())
   }
@@ -1189,6 +1221,7 @@ pub struct ExtensionArgs<'a> {
     pub id: Option<flatbuffers::WIPOffset<&'a str>>,
     pub storage_dtype: Option<flatbuffers::WIPOffset<DType<'a>>>,
     pub metadata: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub storage_transparent: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
 }
 impl<'a> Default for ExtensionArgs<'a> {
   #[inline]
@@ -1197,6 +1230,7 @@ impl<'a> Default for ExtensionArgs<'a> {
       id: None,
       storage_dtype: None,
       metadata: None,
+      storage_transparent: None,
     }
   }
 }
@@ -1219,6 +1253,10 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ExtensionBuilder<'a, 'b, A> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Extension::VT_METADATA, metadata);
   }
   #[inline]
+  pub fn add_storage_transparent(&mut self, storage_transparent: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Extension::VT_STORAGE_TRANSPARENT, storage_transparent);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ExtensionBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     ExtensionBuilder {
@@ -1239,6 +1277,138 @@ impl core::fmt::Debug for Extension<'_> {
       ds.field("id", &self.id());
       ds.field("storage_dtype", &self.storage_dtype());
       ds.field("metadata", &self.metadata());
+      ds.field("storage_transparent", &self.storage_transparent());
+      ds.finish()
+  }
+}
+pub enum FixedSizeListOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FixedSizeList<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FixedSizeList<'a> {
+  type Inner = FixedSizeList<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FixedSizeList<'a> {
+  pub const VT_ELEMENT_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_SIZE: flatbuffers::VOffsetT = 6;
+  pub const VT_NULLABLE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FixedSizeList { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args FixedSizeListArgs<'args>
+  ) -> flatbuffers::WIPOffset<FixedSizeList<'bldr>> {
+    let mut builder = FixedSizeListBuilder::new(_fbb);
+    builder.add_size(args.size);
+    if let Some(x) = args.element_type { builder.add_element_type(x); }
+    builder.add_nullable(args.nullable);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn element_type(&self) -> Option<DType<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<DType>>(FixedSizeList::VT_ELEMENT_TYPE, None)}
+  }
+  #[inline]
+  pub fn size(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FixedSizeList::VT_SIZE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn nullable(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(FixedSizeList::VT_NULLABLE, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FixedSizeList<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<DType>>("element_type", Self::VT_ELEMENT_TYPE, false)?
+     .visit_field::<u32>("size", Self::VT_SIZE, false)?
+     .visit_field::<bool>("nullable", Self::VT_NULLABLE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FixedSizeListArgs<'a> {
+    pub element_type: Option<flatbuffers::WIPOffset<DType<'a>>>,
+    pub size: u32,
+    pub nullable: bool,
+}
+impl<'a> Default for FixedSizeListArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FixedSizeListArgs {
+      element_type: None,
+      size: 0,
+      nullable: false,
+    }
+  }
+}
+
+pub struct FixedSizeListBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FixedSizeListBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_element_type(&mut self, element_type: flatbuffers::WIPOffset<DType<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<DType>>(FixedSizeList::VT_ELEMENT_TYPE, element_type);
+  }
+  #[inline]
+  pub fn add_size(&mut self, size: u32) {
+    self.fbb_.push_slot::<u32>(FixedSizeList::VT_SIZE, size, 0);
+  }
+  #[inline]
+  pub fn add_nullable(&mut self, nullable: bool) {
+    self.fbb_.push_slot::<bool>(FixedSizeList::VT_NULLABLE, nullable, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FixedSizeListBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    FixedSizeListBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FixedSizeList<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FixedSizeList<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FixedSizeList");
+      ds.field("element_type", &self.element_type());
+      ds.field("size", &self.size());
+      ds.field("nullable", &self.nullable());
       ds.finish()
   }
 }
@@ -1426,6 +1596,21 @@ impl<'a> DType<'a> {
     }
   }
 
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn type__as_fixed_size_list(&self) -> Option<FixedSizeList<'a>> {
+    if self.type_type() == Type::FixedSizeList {
+      self.type_().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { FixedSizeList::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
 }
 
 impl flatbuffers::Verifiable for DType<'_> {
@@ -1446,6 +1631,7 @@ impl flatbuffers::Verifiable for DType<'_> {
           Type::Struct_ => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Struct_>>("Type::Struct_", pos),
           Type::List => v.verify_union_variant::<flatbuffers::ForwardsUOffset<List>>("Type::List", pos),
           Type::Extension => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Extension>>("Type::Extension", pos),
+          Type::FixedSizeList => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FixedSizeList>>("Type::FixedSizeList", pos),
           _ => Ok(()),
         }
      })?
@@ -1563,6 +1749,13 @@ impl core::fmt::Debug for DType<'_> {
             ds.field("type_", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
+        Type::FixedSizeList => {
+          if let Some(x) = self.type__as_fixed_size_list() {
+            ds.field("type_", &x)
+          } else {
+            ds.field("type_", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
         _ => {
           let x: Option<()> = None;
           ds.field("type_", &x)
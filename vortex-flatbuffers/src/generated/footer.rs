@@ -3,6 +3,9 @@
 
 // @generated
 
+use crate::dtype::*;
+use crate::scalar::*;
+use crate::array::*;
 use core::mem;
 use core::cmp::Ordering;
 
@@ -344,6 +347,7 @@ impl<'a> flatbuffers::Follow<'a> for Postscript<'a> {
 impl<'a> Postscript<'a> {
   pub const VT_SCHEMA_OFFSET: flatbuffers::VOffsetT = 4;
   pub const VT_LAYOUT_OFFSET: flatbuffers::VOffsetT = 6;
+  pub const VT_ENCODINGS: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -352,11 +356,12 @@ impl<'a> Postscript<'a> {
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args PostscriptArgs
+    args: &'args PostscriptArgs<'args>
   ) -> flatbuffers::WIPOffset<Postscript<'bldr>> {
     let mut builder = PostscriptBuilder::new(_fbb);
     builder.add_layout_offset(args.layout_offset);
     builder.add_schema_offset(args.schema_offset);
+    if let Some(x) = args.encodings { builder.add_encodings(x); }
     builder.finish()
   }
 
@@ -375,6 +380,14 @@ impl<'a> Postscript<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<u64>(Postscript::VT_LAYOUT_OFFSET, Some(0)).unwrap()}
   }
+  /// The encodings of the arrays in the file, absent from files written before they were recorded.
+  #[inline]
+  pub fn encodings(&self) -> Option<EncodingSet<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<EncodingSet>>(Postscript::VT_ENCODINGS, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for Postscript<'_> {
@@ -386,20 +399,23 @@ impl flatbuffers::Verifiable for Postscript<'_> {
     v.visit_table(pos)?
      .visit_field::<u64>("schema_offset", Self::VT_SCHEMA_OFFSET, false)?
      .visit_field::<u64>("layout_offset", Self::VT_LAYOUT_OFFSET, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<EncodingSet>>("encodings", Self::VT_ENCODINGS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct PostscriptArgs {
+pub struct PostscriptArgs<'a> {
     pub schema_offset: u64,
     pub layout_offset: u64,
+    pub encodings: Option<flatbuffers::WIPOffset<EncodingSet<'a>>>,
 }
-impl<'a> Default for PostscriptArgs {
+impl<'a> Default for PostscriptArgs<'a> {
   #[inline]
   fn default() -> Self {
     PostscriptArgs {
       schema_offset: 0,
       layout_offset: 0,
+      encodings: None,
     }
   }
 }
@@ -418,6 +434,10 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PostscriptBuilder<'a, 'b, A> {
     self.fbb_.push_slot::<u64>(Postscript::VT_LAYOUT_OFFSET, layout_offset, 0);
   }
   #[inline]
+  pub fn add_encodings(&mut self, encodings: flatbuffers::WIPOffset<EncodingSet<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<EncodingSet>>(Postscript::VT_ENCODINGS, encodings);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PostscriptBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     PostscriptBuilder {
@@ -437,6 +457,7 @@ impl core::fmt::Debug for Postscript<'_> {
     let mut ds = f.debug_struct("Postscript");
       ds.field("schema_offset", &self.schema_offset());
       ds.field("layout_offset", &self.layout_offset());
+      ds.field("encodings", &self.encodings());
       ds.finish()
   }
 }
@@ -15,11 +15,12 @@ use self::flatbuffers::{EndianScalar, Follow};
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_MESSAGE_VERSION: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_MESSAGE_VERSION: u8 = 0;
+pub const ENUM_MAX_MESSAGE_VERSION: u8 = 1;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_MESSAGE_VERSION: [MessageVersion; 1] = [
+pub const ENUM_VALUES_MESSAGE_VERSION: [MessageVersion; 2] = [
   MessageVersion::V0,
+  MessageVersion::V1,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -28,16 +29,19 @@ pub struct MessageVersion(pub u8);
 #[allow(non_upper_case_globals)]
 impl MessageVersion {
   pub const V0: Self = Self(0);
+  pub const V1: Self = Self(1);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 0;
+  pub const ENUM_MAX: u8 = 1;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::V0,
+    Self::V1,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
     match self {
       Self::V0 => Some("V0"),
+      Self::V1 => Some("V1"),
       _ => None,
     }
   }
@@ -177,14 +181,15 @@ impl flatbuffers::SimpleToVerifyInSlice for Compression {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_MESSAGE_HEADER: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_MESSAGE_HEADER: u8 = 3;
+pub const ENUM_MAX_MESSAGE_HEADER: u8 = 4;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_MESSAGE_HEADER: [MessageHeader; 4] = [
+pub const ENUM_VALUES_MESSAGE_HEADER: [MessageHeader; 5] = [
   MessageHeader::NONE,
   MessageHeader::ArrayData,
   MessageHeader::Buffer,
   MessageHeader::DType,
+  MessageHeader::EncodingSet,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -196,14 +201,16 @@ impl MessageHeader {
   pub const ArrayData: Self = Self(1);
   pub const Buffer: Self = Self(2);
   pub const DType: Self = Self(3);
+  pub const EncodingSet: Self = Self(4);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 3;
+  pub const ENUM_MAX: u8 = 4;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::NONE,
     Self::ArrayData,
     Self::Buffer,
     Self::DType,
+    Self::EncodingSet,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -212,6 +219,7 @@ impl MessageHeader {
       Self::ArrayData => Some("ArrayData"),
       Self::Buffer => Some("Buffer"),
       Self::DType => Some("DType"),
+      Self::EncodingSet => Some("EncodingSet"),
       _ => None,
     }
   }
@@ -372,6 +380,21 @@ impl<'a> Message<'a> {
     }
   }
 
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn header_as_encoding_set(&self) -> Option<EncodingSet<'a>> {
+    if self.header_type() == MessageHeader::EncodingSet {
+      self.header().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { EncodingSet::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
 }
 
 impl flatbuffers::Verifiable for Message<'_> {
@@ -387,6 +410,7 @@ impl flatbuffers::Verifiable for Message<'_> {
           MessageHeader::ArrayData => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ArrayData>>("MessageHeader::ArrayData", pos),
           MessageHeader::Buffer => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Buffer>>("MessageHeader::Buffer", pos),
           MessageHeader::DType => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DType>>("MessageHeader::DType", pos),
+          MessageHeader::EncodingSet => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EncodingSet>>("MessageHeader::EncodingSet", pos),
           _ => Ok(()),
         }
      })?
@@ -469,6 +493,13 @@ impl core::fmt::Debug for Message<'_> {
             ds.field("header", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
+        MessageHeader::EncodingSet => {
+          if let Some(x) = self.header_as_encoding_set() {
+            ds.field("header", &x)
+          } else {
+            ds.field("header", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
         _ => {
           let x: Option<()> = None;
           ds.field("header", &x)
@@ -108,6 +108,52 @@ impl VortexReadAt for ObjectStoreReadAt {
         })
     }
 
+    /// Read all the ranges with [`ObjectStore::get_ranges`], which lets the store coalesce nearby
+    /// ranges into fewer requests and issue them concurrently.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    fn read_byte_ranges(
+        &self,
+        ranges: Vec<Range<u64>>,
+    ) -> impl Future<Output = io::Result<Vec<Buffer>>> + 'static {
+        let object_store = self.object_store.clone();
+        let location = self.location.clone();
+        Box::pin(async move {
+            let ranges = ranges
+                .into_iter()
+                .map(|range| {
+                    let start: usize = range.start.try_into().map_err(io::Error::other)?;
+                    let end: usize = range.end.try_into().map_err(io::Error::other)?;
+                    Ok(start..end.max(start))
+                })
+                .collect::<io::Result<Vec<_>>>()?;
+
+            // Stores reject empty ranges, so only request the non-empty ones
+            let non_empty = ranges
+                .iter()
+                .filter(|range| !range.is_empty())
+                .cloned()
+                .collect::<Vec<_>>();
+            let mut fetched = object_store
+                .get_ranges(&location, &non_empty)
+                .await?
+                .into_iter();
+
+            ranges
+                .iter()
+                .map(|range| {
+                    if range.is_empty() {
+                        Ok(Buffer::from(Vec::<u8>::new()))
+                    } else {
+                        fetched
+                            .next()
+                            .map(Buffer::from)
+                            .ok_or_else(|| io::Error::other("object store returned too few ranges"))
+                    }
+                })
+                .collect()
+        })
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     fn size(&self) -> impl Future<Output = io::Result<u64>> + 'static {
         let object_store = self.object_store.clone();
@@ -161,3 +207,45 @@ impl VortexWrite for ObjectStoreWriter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use object_store::memory::InMemory;
+    use object_store::path::Path;
+    use object_store::ObjectStore;
+    use vortex_buffer::Buffer;
+
+    use crate::{ObjectStoreReadAt, VortexReadAt};
+
+    #[tokio::test]
+    async fn read_byte_ranges_from_store() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let location = Path::from("bytes");
+        store
+            .put(&location, "readthesebytesinbatches".into())
+            .await
+            .unwrap();
+
+        let reader = ObjectStoreReadAt::new(store, location);
+        let read = reader
+            .read_byte_ranges(vec![9..14, 0..4, 4..4, 16..23])
+            .await
+            .unwrap();
+        assert_eq!(
+            read,
+            vec![
+                Buffer::from("bytes"),
+                Buffer::from("read"),
+                Buffer::from(""),
+                Buffer::from("batches"),
+            ]
+        );
+        assert!(reader
+            .read_byte_ranges(Vec::new())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -1,5 +1,6 @@
 use std::future::Future;
 use std::io;
+use std::ops::Range;
 
 use futures::FutureExt;
 use vortex_buffer::Buffer;
@@ -39,6 +40,19 @@ impl<R: VortexReadAt> VortexReadAt for OffsetReadAt<R> {
         self.read.read_byte_range(pos + self.offset, len)
     }
 
+    fn read_byte_ranges(
+        &self,
+        ranges: Vec<Range<u64>>,
+    ) -> impl Future<Output = io::Result<Vec<Buffer>>> + 'static {
+        let offset = self.offset;
+        self.read.read_byte_ranges(
+            ranges
+                .into_iter()
+                .map(|range| range.start + offset..range.end + offset)
+                .collect(),
+        )
+    }
+
     fn performance_hint(&self) -> usize {
         self.read.performance_hint()
     }
@@ -1,10 +1,15 @@
 use std::future::{self, Future};
 use std::io;
+use std::ops::Range;
 use std::sync::Arc;
 
+use futures_util::{stream, StreamExt, TryStreamExt};
 use vortex_buffer::Buffer;
 use vortex_error::{vortex_err, VortexUnwrap};
 
+/// The number of reads that the default [`VortexReadAt::read_byte_ranges`] keeps in flight.
+pub const MAX_CONCURRENT_READS: usize = 10;
+
 /// A trait for types that support asynchronous reads.
 ///
 /// References to the type must be safe to [share across threads][Send], but spawned
@@ -27,6 +32,25 @@ pub trait VortexReadAt: Send + Sync + Clone + 'static {
         len: u64,
     ) -> impl Future<Output = io::Result<Buffer>> + 'static;
 
+    /// Request a batch of asynchronous positional reads, returning a [`Buffer`] for each of the
+    /// `ranges`, in the same order.
+    ///
+    /// Implementations can serve the whole batch at once, e.g. with fewer, larger requests to
+    /// remote storage. By default, the ranges are read with
+    /// [`read_byte_range`][Self::read_byte_range], up to [`MAX_CONCURRENT_READS`] at a time.
+    fn read_byte_ranges(
+        &self,
+        ranges: Vec<Range<u64>>,
+    ) -> impl Future<Output = io::Result<Vec<Buffer>>> + 'static {
+        let reads = ranges
+            .iter()
+            .map(|range| self.read_byte_range(range.start, range_len(range)))
+            .collect::<Vec<_>>();
+        stream::iter(reads)
+            .buffered(MAX_CONCURRENT_READS)
+            .try_collect()
+    }
+
     // TODO(ngates): the read implementation should be able to hint at its latency/throughput
     //  allowing the caller to make better decisions about how to coalesce reads.
     fn performance_hint(&self) -> usize {
@@ -49,6 +73,13 @@ impl<T: VortexReadAt> VortexReadAt for Arc<T> {
         T::read_byte_range(self, pos, len)
     }
 
+    fn read_byte_ranges(
+        &self,
+        ranges: Vec<Range<u64>>,
+    ) -> impl Future<Output = io::Result<Vec<Buffer>>> + 'static {
+        T::read_byte_ranges(self, ranges)
+    }
+
     fn performance_hint(&self) -> usize {
         T::performance_hint(self)
     }
@@ -79,3 +110,37 @@ impl VortexReadAt for Buffer {
         future::ready(Ok(self.len() as u64))
     }
 }
+
+/// The length of a range, or zero if it ends before it starts.
+pub(crate) fn range_len(range: &Range<u64>) -> u64 {
+    range.end.saturating_sub(range.start)
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex_buffer::Buffer;
+
+    use crate::offset::OffsetReadAt;
+    use crate::VortexReadAt;
+
+    #[tokio::test]
+    async fn read_byte_ranges_in_order() {
+        let bytes = Buffer::from("readthesebytesinbatches");
+        let read = bytes
+            .read_byte_ranges(vec![9..14, 0..4, 4..4])
+            .await
+            .unwrap();
+        assert_eq!(
+            read,
+            vec![
+                Buffer::from("bytes"),
+                Buffer::from("read"),
+                Buffer::from("")
+            ]
+        );
+
+        let offset = OffsetReadAt::new(bytes, 4);
+        let read = offset.read_byte_ranges(vec![0..5, 12..19]).await.unwrap();
+        assert_eq!(read, vec![Buffer::from("these"), Buffer::from("batches")]);
+    }
+}
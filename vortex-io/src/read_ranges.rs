@@ -5,14 +5,11 @@ use std::io::ErrorKind;
 use std::ops::Range;
 use std::sync::Arc;
 
-use futures_util::{stream, FutureExt, StreamExt, TryStreamExt};
 use vortex_buffer::Buffer;
 use vortex_error::VortexExpect;
 
 use crate::{Dispatch, IoDispatcher, VortexReadAt};
 
-const MAX_BUFFERED_READS: usize = 10;
-
 #[derive(Debug, Clone)]
 pub struct VortexReadRanges<R> {
     read: R,
@@ -40,27 +37,17 @@ impl<R: VortexReadAt> VortexReadRanges<R> {
         let max_gap = self.max_gap;
         async move {
             let merged_ranges = merge_ranges(ranges.clone(), max_gap);
-            let read_ranges = stream::iter(merged_ranges.iter().cloned())
-                .map(|r| {
-                    dispatcher
-                        .dispatch({
-                            let reader = reader.clone();
-                            move || async move {
-                                reader
-                                    .read_byte_range(r.start as u64, (r.end - r.start) as u64)
-                                    .await
-                            }
-                        })
-                        .vortex_expect("dispatch async")
-                        .map(|bytes| {
-                            bytes
-                                .map_err(|e| io::Error::new(ErrorKind::Other, e))
-                                .and_then(|b| b)
-                        })
+            let read_ranges = dispatcher
+                .dispatch({
+                    let merged_ranges = merged_ranges
+                        .iter()
+                        .map(|r| r.start as u64..r.end as u64)
+                        .collect::<Vec<_>>();
+                    move || async move { reader.read_byte_ranges(merged_ranges).await }
                 })
-                .buffered(MAX_BUFFERED_READS)
-                .try_collect::<Vec<_>>()
-                .await?;
+                .vortex_expect("dispatch async")
+                .await
+                .map_err(|e| io::Error::new(ErrorKind::Other, e))??;
 
             let mut result_bytes = Vec::with_capacity(ranges.len());
             for range in ranges {
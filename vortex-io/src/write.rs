@@ -42,9 +42,12 @@ where
 }
 
 impl<W: VortexWrite> VortexWrite for futures::io::Cursor<W> {
-    fn write_all<B: IoBuf>(&mut self, buffer: B) -> impl Future<Output = io::Result<B>> {
-        self.set_position(self.position() + buffer.as_slice().len() as u64);
-        VortexWrite::write_all(self.get_mut(), buffer)
+    async fn write_all<B: IoBuf>(&mut self, buffer: B) -> io::Result<B> {
+        let len = buffer.as_slice().len() as u64;
+        let buffer = VortexWrite::write_all(self.get_mut(), buffer).await?;
+        // Only count the bytes once they're written, so failed writes don't move the position.
+        self.set_position(self.position() + len);
+        Ok(buffer)
     }
 
     fn flush(&mut self) -> impl Future<Output = io::Result<()>> {
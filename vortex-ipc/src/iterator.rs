@@ -5,12 +5,14 @@ use aligned_buffer::UniqueAlignedBuffer;
 use bytes::Bytes;
 use itertools::Itertools;
 use vortex_array::iter::ArrayIterator;
-use vortex_array::{ArrayDType, ArrayData, Context};
+use vortex_array::{ArrayDType, ArrayData, Context, EncodingSet};
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
-use crate::messages::{DecoderMessage, EncoderMessage, MessageEncoder, SyncMessageReader};
+use crate::messages::{
+    unannounced_encodings, DecoderMessage, EncoderMessage, MessageEncoder, SyncMessageReader,
+};
 use crate::ALIGNMENT;
 
 /// An [`ArrayIterator`] for reading messages off an IPC stream.
@@ -47,6 +49,13 @@ impl<R: Read> Iterator for SyncIPCReader<R> {
     fn next(&mut self) -> Option<Self::Item> {
         match self.reader.next()? {
             Ok(msg) => match msg {
+                // Fail on the announcement of encodings we lack, rather than on the array of them.
+                DecoderMessage::EncodingSet(encodings) => {
+                    match self.ctx.check_encodings(&encodings) {
+                        Ok(()) => self.next(),
+                        Err(e) => Some(Err(e)),
+                    }
+                }
                 DecoderMessage::Array(array_parts) => Some(
                     array_parts
                         .into_array_data(self.ctx.clone(), self.dtype.clone())
@@ -91,6 +100,7 @@ impl<I: ArrayIterator + 'static> ArrayIteratorIPC for I {
             inner: Box::new(self),
             encoder,
             buffers,
+            announced: EncodingSet::default(),
         }
     }
 
@@ -110,6 +120,7 @@ pub struct ArrayIteratorIPCBytes {
     inner: Box<dyn ArrayIterator + 'static>,
     encoder: MessageEncoder,
     buffers: Vec<Buffer>,
+    announced: EncodingSet,
 }
 
 impl ArrayIteratorIPCBytes {
@@ -138,6 +149,10 @@ impl Iterator for ArrayIteratorIPCBytes {
         // Or else try to serialize the next array
         match self.inner.next()? {
             Ok(chunk) => {
+                if let Some(encodings) = unannounced_encodings(&mut self.announced, &chunk) {
+                    self.buffers
+                        .extend(self.encoder.encode(EncoderMessage::EncodingSet(&encodings)));
+                }
                 self.buffers
                     .extend(self.encoder.encode(EncoderMessage::Array(&chunk)));
             }
@@ -160,9 +175,10 @@ mod test {
     use std::sync::Arc;
 
     use vortex_array::array::PrimitiveArray;
+    use vortex_array::encoding::opaque::OpaqueEncoding;
     use vortex_array::iter::{ArrayIterator, ArrayIteratorExt};
     use vortex_array::validity::Validity;
-    use vortex_array::{ArrayDType, Context, IntoArrayVariant, ToArrayData};
+    use vortex_array::{ArrayDType, Context, IntoArrayData, IntoArrayVariant, ToArrayData};
 
     use super::*;
 
@@ -186,4 +202,47 @@ mod test {
             result.maybe_null_slice::<i32>()
         );
     }
+
+    #[test]
+    fn read_arrays_own_their_buffers() {
+        let array = PrimitiveArray::from(vec![1u64; 100]).into_array();
+        let ipc_buffer = array
+            .clone()
+            .into_array_iterator()
+            .into_ipc()
+            .collect_to_buffer()
+            .unwrap();
+
+        let read = SyncIPCReader::try_new(Cursor::new(ipc_buffer), Arc::new(Context::default()))
+            .unwrap()
+            .into_array_data()
+            .unwrap();
+        assert_eq!(read.unique_size(), array.unique_size());
+        assert_eq!(read.unique_size(), 100 * size_of::<u64>());
+        assert!(read.allocated_bytes().owned() >= 100 * size_of::<u64>());
+    }
+
+    #[test]
+    fn missing_encoding_fails_before_array() {
+        let array = PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        let mut encodings = EncodingSet::default();
+        encodings.insert(&OpaqueEncoding(0xfff0));
+
+        let mut encoder = MessageEncoder::default();
+        let mut ipc_bytes = Vec::new();
+        for message in [
+            EncoderMessage::DType(array.dtype()),
+            EncoderMessage::EncodingSet(&encodings),
+            EncoderMessage::Array(&array),
+        ] {
+            for buffer in encoder.encode(message) {
+                ipc_bytes.extend_from_slice(buffer.as_slice());
+            }
+        }
+
+        let mut reader =
+            SyncIPCReader::try_new(Cursor::new(ipc_bytes), Arc::new(Context::default())).unwrap();
+        let err = reader.next().unwrap().unwrap_err().to_string();
+        assert!(err.contains("vortex.opaque(0xfff0)"), "{err}");
+    }
 }
@@ -6,7 +6,8 @@
 //!
 //! This crate provides both in-memory message representations for holding IPC messages
 //! before/after serialization, and streaming readers and writers that sit on top
-//! of any type implementing `VortexRead` or `VortexWrite` respectively.
+//! of any type implementing `AsyncRead` or `AsyncWrite` respectively, such as the
+//! [`AsyncMessageWriter`][messages::AsyncMessageWriter].
 pub mod iterator;
 pub mod messages;
 pub mod stream;
@@ -4,7 +4,9 @@ use std::sync::Arc;
 use bytes::{Buf, BytesMut};
 use flatbuffers::{root, root_unchecked, Follow};
 use itertools::Itertools;
-use vortex_array::{flatbuffers as fba, ArrayData, Context};
+use vortex_array::{
+    flatbuffers as fba, ArrayData, Context, EncodingSet, MetadataFormat, ValidationLevel,
+};
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult};
@@ -23,6 +25,7 @@ pub enum DecoderMessage {
     Array(ArrayParts),
     Buffer(Buffer),
     DType(DType),
+    EncodingSet(EncodingSet),
 }
 
 /// ArrayParts represents a partially decoded Vortex array.
@@ -33,6 +36,8 @@ pub struct ArrayParts {
     array_flatbuffer: Buffer,
     array_flatbuffer_loc: usize,
     buffers: Vec<Buffer>,
+    validation: ValidationLevel,
+    metadata_format: MetadataFormat,
 }
 
 impl Debug for ArrayParts {
@@ -41,12 +46,30 @@ impl Debug for ArrayParts {
             .field("row_count", &self.row_count)
             .field("array_flatbuffer", &self.array_flatbuffer.len())
             .field("buffers", &self.buffers.len())
+            .field("validation", &self.validation)
+            .field("metadata_format", &self.metadata_format)
             .finish()
     }
 }
 
 impl ArrayParts {
+    /// Validate the array to the given level when it's decoded, rather than only parsing the
+    /// metadata of its root.
+    pub fn with_validation(mut self, validation: ValidationLevel) -> Self {
+        self.validation = validation;
+        self
+    }
+
     pub fn into_array_data(self, ctx: Arc<Context>, dtype: DType) -> VortexResult<ArrayData> {
+        let ctx = if ctx.metadata_format() == self.metadata_format {
+            ctx
+        } else {
+            Arc::new(
+                ctx.as_ref()
+                    .clone()
+                    .with_metadata_format(self.metadata_format),
+            )
+        };
         ArrayData::try_new_viewed(
             ctx,
             dtype,
@@ -55,6 +78,7 @@ impl ArrayParts {
             // SAFETY: ArrayComponents guarantees the buffers are valid.
             |buf| unsafe { Ok(fba::Array::follow(buf, self.array_flatbuffer_loc)) },
             self.buffers,
+            self.validation,
         )
     }
 }
@@ -138,9 +162,7 @@ impl MessageDecoder {
 
                     let mut msg_bytes = bytes.split_to_aligned(*msg_length, FB_ALIGNMENT);
                     let msg = root::<fb::Message>(msg_bytes.as_ref())?;
-                    if msg.version() != MessageVersion::V0 {
-                        vortex_bail!("Unsupported message version {:?}", msg.version());
-                    }
+                    metadata_format(msg.version())?;
 
                     match msg.header_type() {
                         MessageHeader::ArrayData => {
@@ -182,6 +204,16 @@ impl MessageDecoder {
                             self.state = Default::default();
                             return Ok(PollRead::Some(DecoderMessage::DType(dtype)));
                         }
+                        MessageHeader::EncodingSet => {
+                            let encodings = msg
+                                .header_as_encoding_set()
+                                .vortex_expect("encoding set header");
+                            let encodings = EncodingSet::try_from(encodings)?;
+
+                            // Nothing else to read, so we reset the state to Length
+                            self.state = Default::default();
+                            return Ok(PollRead::Some(DecoderMessage::EncodingSet(encodings)));
+                        }
                         _ => {
                             vortex_bail!("Unsupported message header type {:?}", msg.header_type());
                         }
@@ -237,12 +269,15 @@ impl MessageDecoder {
 
                     let row_count = usize::try_from(array_data_msg.row_count())
                         .map_err(|_| vortex_err!("row count is too large for usize"))?;
+                    let metadata_format = metadata_format(msg.version())?;
 
                     let msg = DecoderMessage::Array(ArrayParts {
                         row_count,
                         array_flatbuffer: header.clone(),
                         array_flatbuffer_loc: array_msg._tab.loc(),
                         buffers,
+                        validation: ValidationLevel::default(),
+                        metadata_format,
                     });
 
                     self.state = Default::default();
@@ -253,6 +288,15 @@ impl MessageDecoder {
     }
 }
 
+/// The format of the array metadata in messages of the given version.
+fn metadata_format(version: MessageVersion) -> VortexResult<MetadataFormat> {
+    match version {
+        MessageVersion::V0 => Ok(MetadataFormat::Flexbuffers),
+        MessageVersion::V1 => Ok(MetadataFormat::FixedLayout),
+        _ => vortex_bail!("Unsupported message version {:?}", version),
+    }
+}
+
 trait BytesMutAlignedSplit {
     /// If the buffer is empty, advances the cursor to the next aligned position and ensures there
     /// is sufficient capacity for the requested length.
@@ -309,14 +353,18 @@ impl BytesMutAlignedSplit for BytesMut {
 
 #[cfg(test)]
 mod test {
-    use vortex_array::array::{ConstantArray, PrimitiveArray};
-    use vortex_array::{ArrayDType, IntoArrayData};
+    use vortex_array::array::{ConstantArray, PrimitiveArray, SlicedArray, StructArray};
+    use vortex_array::stats::{ArrayStatistics, Stat};
+    use vortex_array::validity::Validity;
+    use vortex_array::variants::StructArrayTrait;
+    use vortex_array::{ArrayDType, IntoArrayData, IntoArrayVariant};
+    use vortex_dtype::Nullability;
     use vortex_error::vortex_panic;
 
     use super::*;
     use crate::messages::{EncoderMessage, MessageEncoder};
 
-    fn write_and_read(expected: ArrayData) {
+    fn write_and_read(expected: ArrayData) -> ArrayData {
         let mut ipc_bytes = BytesMut::new();
         let mut encoder = MessageEncoder::default();
         for buf in encoder.encode(EncoderMessage::Array(&expected)) {
@@ -332,13 +380,38 @@ mod test {
             otherwise => vortex_panic!("Expected an array, got {:?}", otherwise),
         };
 
-        // Decode the array parts with the context
+        // Decode the array parts with the context, checking the whole array tree
         let actual = array_parts
+            .with_validation(ValidationLevel::Buffers)
             .into_array_data(Arc::new(Context::default()), expected.dtype().clone())
             .unwrap();
 
         assert_eq!(expected.len(), actual.len());
         assert_eq!(expected.encoding(), actual.encoding());
+        actual
+    }
+
+    #[test]
+    fn message_versions() {
+        let mut encoder = MessageEncoder::default();
+        let mut ipc_bytes = BytesMut::new();
+        for buf in encoder.encode(EncoderMessage::DType(&DType::Bool(
+            Nullability::NonNullable,
+        ))) {
+            ipc_bytes.extend_from_slice(buf.as_ref());
+        }
+        let msg = root::<fb::Message>(&ipc_bytes[4..]).unwrap();
+        assert_eq!(msg.version(), MessageVersion::V1);
+
+        assert_eq!(
+            metadata_format(MessageVersion::V0).unwrap(),
+            MetadataFormat::Flexbuffers
+        );
+        assert_eq!(
+            metadata_format(MessageVersion::V1).unwrap(),
+            MetadataFormat::FixedLayout
+        );
+        assert!(metadata_format(MessageVersion(2)).is_err());
     }
 
     #[test]
@@ -353,4 +426,90 @@ mod test {
         assert!(array.buffer().is_none(), "Array should have no buffers");
         write_and_read(array);
     }
+
+    #[test]
+    fn array_stats_ipc() {
+        let array = PrimitiveArray::from((0..2000i32).collect::<Vec<_>>()).into_array();
+        let stats = [
+            Stat::Min,
+            Stat::NullCount,
+            Stat::ApproxDistinct,
+            Stat::Histogram,
+        ];
+        let expected = array.statistics().compute_all(&stats).unwrap();
+
+        let actual = write_and_read(array);
+        for stat in stats {
+            assert!(expected.get(stat).is_some(), "{stat} was not computed");
+            assert_eq!(
+                actual.statistics().get(stat).as_ref(),
+                expected.get(stat),
+                "{stat} did not round trip"
+            );
+        }
+    }
+
+    #[test]
+    fn small_array_leaves_out_sketch() {
+        let array = PrimitiveArray::from((0..100i32).collect::<Vec<_>>()).into_array();
+        array
+            .statistics()
+            .compute_all(&[Stat::Min, Stat::ApproxDistinct])
+            .unwrap();
+
+        let actual = write_and_read(array);
+        assert!(actual.statistics().get(Stat::Min).is_some());
+        assert!(actual.statistics().get(Stat::ApproxDistinct).is_none());
+    }
+
+    #[test]
+    fn nested_array_children_resolved_once() {
+        let values =
+            PrimitiveArray::from_vec(vec![1i32, 2, 3], Validity::from_iter([true, false, true]))
+                .into_array();
+        let inner = StructArray::from_fields(&[("values", values)])
+            .unwrap()
+            .into_array();
+        let outer = StructArray::from_fields(&[("inner", inner)])
+            .unwrap()
+            .into_array();
+
+        let actual = write_and_read(outer).into_struct().unwrap();
+        let field = |array: &StructArray| array.field(0).unwrap().into_struct().unwrap();
+        let first = field(&actual);
+        let second = field(&actual);
+        // The second access reuses the view of the child, including its parsed metadata.
+        assert!(std::ptr::addr_eq(
+            first.as_ref().array_metadata(),
+            second.as_ref().array_metadata()
+        ));
+
+        let values = first.field(0).unwrap().into_primitive().unwrap();
+        assert_eq!(values.maybe_null_slice::<i32>(), &[1, 2, 3]);
+        assert!(!values.validity().is_valid(1));
+    }
+
+    #[test]
+    fn small_slice_written_alone() {
+        let values = PrimitiveArray::from((0..10_000).collect::<Vec<i32>>()).into_array();
+        let sliced = SlicedArray::try_new(values, 100, 110).unwrap().into_array();
+        let expected = StructArray::from_fields(&[("values", sliced)])
+            .unwrap()
+            .into_array();
+
+        let mut encoder = MessageEncoder::default();
+        let written = encoder
+            .encode(EncoderMessage::Array(&expected))
+            .iter()
+            .map(Buffer::len)
+            .sum::<usize>();
+        assert!(written < 10_000);
+
+        let actual = write_and_read(expected).into_struct().unwrap();
+        let values = actual.field(0).unwrap().into_primitive().unwrap();
+        assert_eq!(
+            values.maybe_null_slice::<i32>(),
+            (100..110).collect::<Vec<_>>()
+        );
+    }
 }
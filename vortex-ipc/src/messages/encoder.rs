@@ -1,7 +1,8 @@
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
 use itertools::Itertools;
-use vortex_array::stats::ArrayStatistics;
-use vortex_array::{flatbuffers as fba, ArrayData};
+use vortex_array::array::materialize_small_slices;
+use vortex_array::stats::flatbuffers::ArrayStatsWriter;
+use vortex_array::{flatbuffers as fba, ArrayData, EncodingSet};
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_error::{vortex_panic, VortexExpect};
@@ -14,6 +15,8 @@ pub enum EncoderMessage<'a> {
     Array(&'a ArrayData),
     Buffer(&'a Buffer),
     DType(&'a DType),
+    /// Encodings of the arrays that follow, which the reader must know to decode them.
+    EncodingSet(&'a EncodingSet),
 }
 
 pub struct MessageEncoder {
@@ -76,6 +79,9 @@ impl MessageEncoder {
 
         let header = match message {
             EncoderMessage::Array(array) => {
+                // Small slices are written as copies rather than with the whole array they slice.
+                let array = &materialize_small_slices(array)
+                    .vortex_expect("Materialized slices keep the dtype and length of the slice");
                 let row_count = array.len();
                 let array_def = ArrayWriter {
                     array,
@@ -133,14 +139,18 @@ impl MessageEncoder {
                 .as_union_value()
             }
             EncoderMessage::DType(dtype) => dtype.write_flatbuffer(&mut fbb).as_union_value(),
+            EncoderMessage::EncodingSet(encodings) => {
+                encodings.write_flatbuffer(&mut fbb).as_union_value()
+            }
         };
 
         let mut msg = fb::MessageBuilder::new(&mut fbb);
-        msg.add_version(Default::default());
+        msg.add_version(fb::MessageVersion::V1);
         msg.add_header_type(match message {
             EncoderMessage::Array(_) => fb::MessageHeader::ArrayData,
             EncoderMessage::Buffer(_) => fb::MessageHeader::Buffer,
             EncoderMessage::DType(_) => fb::MessageHeader::DType,
+            EncoderMessage::EncodingSet(_) => fb::MessageHeader::EncodingSet,
         });
         msg.add_header(header);
         let msg = msg.finish();
@@ -169,6 +179,24 @@ impl MessageEncoder {
     }
 }
 
+/// The encodings of the array that haven't been announced yet, which are then marked as announced.
+///
+/// IPC writers send these in an [`EncoderMessage::EncodingSet`] ahead of the array, so that readers
+/// can name any encoding they lack instead of failing on its code.
+pub(crate) fn unannounced_encodings(
+    announced: &mut EncodingSet,
+    array: &ArrayData,
+) -> Option<EncodingSet> {
+    let mut encodings = EncodingSet::default();
+    encodings.insert_array(array);
+    let unannounced = encodings.difference(announced);
+    if unannounced.is_empty() {
+        return None;
+    }
+    announced.extend(&unannounced);
+    Some(unannounced)
+}
+
 struct ArrayWriter<'a> {
     array: &'a ArrayData,
     buffer_idx: u16,
@@ -223,7 +251,7 @@ impl WriteFlatBuffer for ArrayWriter<'_> {
             .then_some(self.buffer_idx)
             .map(|buffer_idx| fbb.create_vector_from_iter(std::iter::once(buffer_idx)));
 
-        let stats = Some(self.array.statistics().write_flatbuffer(fbb));
+        let stats = Some(ArrayStatsWriter::new(self.array).write_flatbuffer(fbb));
 
         fba::Array::create(
             fbb,
@@ -1,11 +1,23 @@
-use futures_util::{AsyncWrite, AsyncWriteExt};
+use futures_util::{AsyncWrite, AsyncWriteExt, Stream, StreamExt};
+use vortex_array::{ArrayData, EncodingSet};
+use vortex_buffer::Buffer;
+use vortex_dtype::DType;
 use vortex_error::VortexResult;
 
-use crate::messages::{EncoderMessage, MessageEncoder};
+use crate::messages::{unannounced_encodings, EncoderMessage, MessageEncoder};
 
+/// Writes IPC messages to any [`AsyncWrite`], e.g. a tokio TCP socket behind a compat adapter.
+///
+/// DType, array and buffer messages may be interleaved in any order. Each write completes once
+/// the sink has accepted all of the message's buffers, so a slow sink holds up the writer rather
+/// than letting encoded messages pile up in memory.
+///
+/// Arrays written with [`write_array`][Self::write_array] are preceded by the encodings that they
+/// use and which this writer hasn't announced yet, so that readers can name any encoding they lack.
 pub struct AsyncMessageWriter<W> {
     write: W,
     encoder: MessageEncoder,
+    announced: EncodingSet,
 }
 
 impl<W: AsyncWrite + Unpin> AsyncMessageWriter<W> {
@@ -13,9 +25,11 @@ impl<W: AsyncWrite + Unpin> AsyncMessageWriter<W> {
         Self {
             write,
             encoder: MessageEncoder::default(),
+            announced: EncodingSet::default(),
         }
     }
 
+    /// Write the message as is, without announcing the encodings of arrays.
     pub async fn write_message(&mut self, message: EncoderMessage<'_>) -> VortexResult<()> {
         for buffer in self.encoder.encode(message) {
             self.write.write_all(&buffer).await?;
@@ -23,6 +37,46 @@ impl<W: AsyncWrite + Unpin> AsyncMessageWriter<W> {
         Ok(())
     }
 
+    pub async fn write_dtype(&mut self, dtype: &DType) -> VortexResult<()> {
+        self.write_message(EncoderMessage::DType(dtype)).await
+    }
+
+    pub async fn write_array(&mut self, array: &ArrayData) -> VortexResult<()> {
+        if let Some(encodings) = unannounced_encodings(&mut self.announced, array) {
+            self.write_message(EncoderMessage::EncodingSet(&encodings))
+                .await?;
+        }
+        self.write_message(EncoderMessage::Array(array)).await
+    }
+
+    pub async fn write_buffer(&mut self, buffer: &Buffer) -> VortexResult<()> {
+        self.write_message(EncoderMessage::Buffer(buffer)).await
+    }
+
+    /// Write the dtype followed by every array of the stream, which is only polled for the next
+    /// array once the previous one has been written.
+    pub async fn write_array_stream<S>(&mut self, dtype: &DType, arrays: S) -> VortexResult<()>
+    where
+        S: Stream<Item = VortexResult<ArrayData>>,
+    {
+        self.write_dtype(dtype).await?;
+        let mut arrays = Box::pin(arrays);
+        while let Some(array) = arrays.next().await {
+            self.write_array(&array?).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn flush(&mut self) -> VortexResult<()> {
+        Ok(self.write.flush().await?)
+    }
+
+    /// Flush and close the sink, returning it.
+    pub async fn close(mut self) -> VortexResult<W> {
+        self.write.close().await?;
+        Ok(self.write)
+    }
+
     pub fn inner(&self) -> &W {
         &self.write
     }
@@ -31,3 +85,120 @@ impl<W: AsyncWrite + Unpin> AsyncMessageWriter<W> {
         self.write
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::io;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context as TaskContext, Poll};
+
+    use futures_util::io::Cursor;
+    use futures_util::{stream, TryStreamExt};
+    use tokio::io::{AsyncReadExt, DuplexStream};
+    use vortex_array::array::PrimitiveArray;
+    use vortex_array::{ArrayDType, Context, IntoArrayData, IntoArrayVariant};
+    use vortex_buffer::Buffer;
+
+    use super::*;
+    use crate::messages::{AsyncMessageReader, DecoderMessage};
+
+    /// The writing half of a tokio pipe as a futures [`AsyncWrite`].
+    struct PipeWriter(DuplexStream);
+
+    impl AsyncWrite for PipeWriter {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            cx: &mut TaskContext<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            tokio::io::AsyncWrite::poll_write(Pin::new(&mut self.0), cx, buf)
+        }
+
+        fn poll_flush(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<io::Result<()>> {
+            tokio::io::AsyncWrite::poll_flush(Pin::new(&mut self.0), cx)
+        }
+
+        fn poll_close(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<io::Result<()>> {
+            tokio::io::AsyncWrite::poll_shutdown(Pin::new(&mut self.0), cx)
+        }
+    }
+
+    #[tokio::test]
+    async fn interleaved_messages() {
+        let array = PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        let buffer = Buffer::from(vec![7u8; 10]);
+
+        let mut writer = AsyncMessageWriter::new(Cursor::new(Vec::new()));
+        writer.write_dtype(array.dtype()).await.unwrap();
+        writer.write_array(&array).await.unwrap();
+        writer.write_buffer(&buffer).await.unwrap();
+        writer.write_array(&array).await.unwrap();
+        let written = writer.close().await.unwrap().into_inner();
+
+        let messages = AsyncMessageReader::new(Cursor::new(written))
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        let kinds = messages
+            .iter()
+            .map(|msg| match msg {
+                DecoderMessage::Array(_) => "array",
+                DecoderMessage::Buffer(_) => "buffer",
+                DecoderMessage::DType(_) => "dtype",
+                DecoderMessage::EncodingSet(_) => "encodings",
+            })
+            .collect::<Vec<_>>();
+        // The encodings are only announced ahead of the first array
+        assert_eq!(
+            kinds,
+            vec!["dtype", "encodings", "array", "buffer", "array"]
+        );
+        let DecoderMessage::Buffer(read) = &messages[3] else {
+            unreachable!()
+        };
+        assert_eq!(read.as_slice(), buffer.as_slice());
+    }
+
+    #[tokio::test]
+    async fn backpressure() {
+        let arrays = (0..10)
+            .map(|i| PrimitiveArray::from(vec![i; 1000]).into_array())
+            .collect::<Vec<_>>();
+        let dtype = arrays[0].dtype().clone();
+
+        // The pipe holds far less than the arrays, so the writer must wait for the reader.
+        let (write, mut read) = tokio::io::duplex(256);
+        let writing = async {
+            let mut writer = AsyncMessageWriter::new(PipeWriter(write));
+            writer
+                .write_array_stream(&dtype, stream::iter(arrays.into_iter().map(Ok)))
+                .await
+                .unwrap();
+            writer.close().await.unwrap();
+        };
+        let mut bytes = Vec::new();
+        let (_, read_result) = tokio::join!(writing, read.read_to_end(&mut bytes));
+        read_result.unwrap();
+
+        let mut arrays = AsyncMessageReader::new(Cursor::new(bytes))
+            .try_filter_map(|msg| async move {
+                Ok(match msg {
+                    DecoderMessage::Array(parts) => Some(parts),
+                    _ => None,
+                })
+            })
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(arrays.len(), 10);
+        let last = arrays
+            .pop()
+            .unwrap()
+            .into_array_data(Arc::new(Context::default()), dtype)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(last.maybe_null_slice::<i32>(), &[9; 1000]);
+    }
+}
@@ -5,15 +5,18 @@ use std::task::{ready, Poll};
 
 use aligned_buffer::UniqueAlignedBuffer;
 use bytes::Bytes;
-use futures_util::{AsyncRead, AsyncWrite, AsyncWriteExt, Stream, StreamExt, TryStreamExt};
+use futures_util::{AsyncRead, AsyncWrite, Stream, StreamExt, TryStreamExt};
 use pin_project_lite::pin_project;
 use vortex_array::stream::ArrayStream;
-use vortex_array::{ArrayDType, ArrayData, Context};
+use vortex_array::{ArrayDType, ArrayData, Context, EncodingSet};
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
-use crate::messages::{AsyncMessageReader, DecoderMessage, EncoderMessage, MessageEncoder};
+use crate::messages::{
+    unannounced_encodings, AsyncMessageReader, AsyncMessageWriter, DecoderMessage, EncoderMessage,
+    MessageEncoder,
+};
 use crate::ALIGNMENT;
 
 pin_project! {
@@ -57,9 +60,21 @@ impl<R: AsyncRead> Stream for AsyncIPCReader<R> {
         self: Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> Poll<Option<Self::Item>> {
-        let this = self.project();
+        let mut this = self.project();
+
+        let msg = loop {
+            match ready!(this.reader.as_mut().poll_next(cx)) {
+                // Fail on the announcement of encodings we lack, rather than on the array of them.
+                Some(Ok(DecoderMessage::EncodingSet(encodings))) => {
+                    if let Err(e) = this.ctx.check_encodings(&encodings) {
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+                msg => break msg,
+            }
+        };
 
-        match ready!(this.reader.poll_next(cx)) {
+        match msg {
             None => Poll::Ready(None),
             Some(msg) => match msg {
                 Ok(DecoderMessage::Array(array_parts)) => Poll::Ready(Some(
@@ -108,18 +123,18 @@ impl<S: ArrayStream + 'static> ArrayStreamIPC for S {
             encoder: MessageEncoder::default(),
             buffers: vec![],
             written_dtype: false,
+            announced: EncodingSet::default(),
         }
     }
 
-    async fn write_ipc<W: AsyncWrite + Unpin>(self, mut write: W) -> VortexResult<W>
+    async fn write_ipc<W: AsyncWrite + Unpin>(self, write: W) -> VortexResult<W>
     where
         Self: Sized,
     {
-        let mut stream = self.into_ipc();
-        while let Some(chunk) = stream.next().await {
-            write.write_all(chunk?.as_slice()).await?;
-        }
-        Ok(write)
+        let dtype = self.dtype().clone();
+        let mut writer = AsyncMessageWriter::new(write);
+        writer.write_array_stream(&dtype, self).await?;
+        Ok(writer.into_inner())
     }
 }
 
@@ -128,6 +143,7 @@ pub struct ArrayStreamIPCBytes {
     encoder: MessageEncoder,
     buffers: Vec<Buffer>,
     written_dtype: bool,
+    announced: EncodingSet,
 }
 
 impl ArrayStreamIPCBytes {
@@ -172,6 +188,10 @@ impl Stream for ArrayStreamIPCBytes {
             None => return Poll::Ready(None),
             Some(chunk) => match chunk {
                 Ok(chunk) => {
+                    if let Some(encodings) = unannounced_encodings(&mut this.announced, &chunk) {
+                        this.buffers
+                            .extend(this.encoder.encode(EncoderMessage::EncodingSet(&encodings)));
+                    }
                     this.buffers
                         .extend(this.encoder.encode(EncoderMessage::Array(&chunk)));
                 }
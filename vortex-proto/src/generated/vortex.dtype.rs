@@ -17,10 +17,13 @@ pub struct Primitive {
 pub struct Decimal {
     #[prost(uint32, tag = "1")]
     pub precision: u32,
-    #[prost(uint32, tag = "2")]
-    pub scale: u32,
+    #[prost(int32, tag = "2")]
+    pub scale: i32,
     #[prost(bool, tag = "3")]
     pub nullable: bool,
+    /// Values are stored as 256-bit integers even though the precision fits in 128 bits
+    #[prost(bool, tag = "4")]
+    pub wide: bool,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Utf8 {
@@ -56,10 +59,21 @@ pub struct Extension {
     pub storage_dtype: ::core::option::Option<::prost::alloc::boxed::Box<DType>>,
     #[prost(bytes = "vec", optional, tag = "3")]
     pub metadata: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, repeated, tag = "4")]
+    pub storage_transparent: ::prost::alloc::vec::Vec<u32>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FixedSizeList {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub element_type: ::core::option::Option<::prost::alloc::boxed::Box<DType>>,
+    #[prost(uint32, tag = "2")]
+    pub size: u32,
+    #[prost(bool, tag = "3")]
+    pub nullable: bool,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DType {
-    #[prost(oneof = "d_type::DtypeType", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9")]
+    #[prost(oneof = "d_type::DtypeType", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10")]
     pub dtype_type: ::core::option::Option<d_type::DtypeType>,
 }
 /// Nested message and enum types in `DType`.
@@ -84,6 +98,8 @@ pub mod d_type {
         List(::prost::alloc::boxed::Box<super::List>),
         #[prost(message, tag = "9")]
         Extension(::prost::alloc::boxed::Box<super::Extension>),
+        #[prost(message, tag = "10")]
+        FixedSizeList(::prost::alloc::boxed::Box<super::FixedSizeList>),
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
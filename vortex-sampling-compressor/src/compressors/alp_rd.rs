@@ -6,7 +6,10 @@ use vortex_array::aliases::hash_set::HashSet;
 use vortex_array::array::PrimitiveArray;
 use vortex_array::encoding::{Encoding, EncodingRef};
 use vortex_array::variants::PrimitiveArrayTrait;
-use vortex_array::{ArrayData, IntoArrayData, IntoArrayVariant};
+use vortex_array::{
+    ArrayData, IntoArrayData, IntoArrayVariant, TryDeserializeArrayMetadata,
+    TrySerializeArrayMetadata,
+};
 use vortex_dtype::PType;
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_fastlanes::BitPackedEncoding;
@@ -21,6 +24,10 @@ impl EncoderMetadata for ALPRDEncoder {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn try_serialize(&self) -> VortexResult<Option<Arc<[u8]>>> {
+        self.try_serialize_metadata().map(Some)
+    }
 }
 
 impl EncodingCompressor for ALPRDCompressor {
@@ -74,6 +81,12 @@ impl EncodingCompressor for ALPRDCompressor {
     fn used_encodings(&self) -> HashSet<EncodingRef> {
         HashSet::from([&ALPRDEncoding as EncodingRef, &BitPackedEncoding])
     }
+
+    fn deserialize_metadata(&self, metadata: &[u8]) -> VortexResult<Arc<dyn EncoderMetadata>> {
+        Ok(Arc::new(ALPRDEncoder::try_deserialize_metadata(Some(
+            metadata,
+        ))?))
+    }
 }
 
 /// Create a new `ALPRDEncoder` from the given array of samples.
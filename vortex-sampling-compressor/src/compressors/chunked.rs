@@ -1,11 +1,14 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use serde::{Deserialize, Serialize};
 use vortex_array::aliases::hash_set::HashSet;
 use vortex_array::array::{ChunkedArray, ChunkedEncoding};
 use vortex_array::compress::compute_precompression_stats;
 use vortex_array::encoding::{Encoding, EncodingRef};
-use vortex_array::{ArrayDType, ArrayData, IntoArrayData};
+use vortex_array::{
+    ArrayDType, ArrayData, IntoArrayData, TryDeserializeArrayMetadata, TrySerializeArrayMetadata,
+};
 use vortex_error::{vortex_bail, VortexExpect, VortexResult};
 
 use super::EncoderMetadata;
@@ -21,12 +24,17 @@ pub const DEFAULT_CHUNKED_COMPRESSOR: ChunkedCompressor = ChunkedCompressor {
     relatively_good_ratio: 1.2,
 };
 
+#[derive(Serialize, Deserialize)]
 pub struct ChunkedCompressorMetadata(Option<f32>);
 
 impl EncoderMetadata for ChunkedCompressorMetadata {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn try_serialize(&self) -> VortexResult<Option<Arc<[u8]>>> {
+        self.try_serialize_metadata().map(Some)
+    }
 }
 
 impl EncodingCompressor for ChunkedCompressor {
@@ -55,6 +63,12 @@ impl EncodingCompressor for ChunkedCompressor {
     fn used_encodings(&self) -> HashSet<EncodingRef> {
         HashSet::from([])
     }
+
+    fn deserialize_metadata(&self, metadata: &[u8]) -> VortexResult<Arc<dyn EncoderMetadata>> {
+        Ok(Arc::new(
+            ChunkedCompressorMetadata::try_deserialize_metadata(Some(metadata))?,
+        ))
+    }
 }
 
 impl ChunkedCompressor {
@@ -67,8 +81,12 @@ impl ChunkedCompressor {
     /// ```text
     /// new_ratio <= old_ratio * self.relatively_good_ratio
     /// ```
-    fn relatively_good_ratio(&self) -> f32 {
-        self.relatively_good_ratio
+    ///
+    /// The ctx's options can override it with `CompressConfig::with_chunk_ratio_tolerance`.
+    fn relatively_good_ratio(&self, ctx: &SamplingCompressor) -> f32 {
+        ctx.options()
+            .chunk_ratio_tolerance()
+            .unwrap_or(self.relatively_good_ratio)
     }
 
     fn compress_chunked<'a>(
@@ -81,44 +99,35 @@ impl ChunkedCompressor {
             ctx.options().target_block_bytesize,
             ctx.options().target_block_size,
         )?;
-
-        let mut previous = like_into_parts(like)?;
-        let mut compressed_chunks = Vec::with_capacity(less_chunked.nchunks());
-        let mut compressed_trees = Vec::with_capacity(less_chunked.nchunks() + 1);
+        let chunks = less_chunked.chunks().collect::<Vec<_>>();
+        let like = like_into_parts(like)?;
+
+        // Each group of chunks is compressed on its own thread, starting over from the given tree
+        // and reusing the trees found for its own earlier chunks.
+        #[cfg(feature = "rayon")]
+        let groups = {
+            use rayon::prelude::*;
+
+            let group_size = chunks.len().div_ceil(rayon::current_num_threads()).max(1);
+            chunks
+                .par_chunks(group_size)
+                .enumerate()
+                .map(|(group, chunks)| {
+                    self.compress_chunks(chunks, group * group_size, like.clone(), &ctx)
+                })
+                .collect::<VortexResult<Vec<_>>>()?
+        };
+        #[cfg(not(feature = "rayon"))]
+        let groups = vec![self.compress_chunks(&chunks, 0, like, &ctx)?];
+
+        let mut compressed_chunks = Vec::with_capacity(chunks.len());
+        let mut compressed_trees = Vec::with_capacity(chunks.len() + 1);
         compressed_trees.push(None); // for the chunk offsets
-
-        for (index, chunk) in less_chunked.chunks().enumerate() {
-            // these are extremely valuable when reading/writing, but are potentially much more expensive
-            // to compute post-compression. That's because not all encodings implement stats, so we would
-            // potentially have to canonicalize during writes just to get stats, which would be silly.
-            // Also, we only really require them for column chunks, not for every array.
-            compute_precompression_stats(&chunk)?;
-
-            let like = previous.as_ref().map(|(like, _)| like);
-            let (compressed_chunk, tree) = ctx
-                .named(&format!("chunk-{}", index))
-                .compress(&chunk, like)?
-                .into_parts();
-
-            let ratio = (compressed_chunk.nbytes() as f32) / (chunk.nbytes() as f32);
-            let exceeded_target_ratio = previous
-                .as_ref()
-                .map(|(_, target_ratio)| ratio > target_ratio * self.relatively_good_ratio())
-                .unwrap_or(false);
-
-            if ratio > 1.0 || exceeded_target_ratio {
-                log::debug!("unsatisfactory ratio {}, previous: {:?}", ratio, previous);
-                let (compressed_chunk, tree) = ctx.compress_array(&chunk)?.into_parts();
-                let new_ratio = (compressed_chunk.nbytes() as f32) / (chunk.nbytes() as f32);
-
-                compressed_chunks.push(compressed_chunk);
-                compressed_trees.push(tree.clone());
-                previous = tree.map(|tree| (tree, new_ratio));
-            } else {
-                compressed_chunks.push(compressed_chunk);
-                compressed_trees.push(tree.clone());
-                previous = previous.or_else(|| tree.map(|tree| (tree, ratio)));
-            }
+        let mut previous = None;
+        for group in groups {
+            compressed_chunks.extend(group.chunks);
+            compressed_trees.extend(group.trees);
+            previous = group.previous.or(previous);
         }
 
         let ratio = previous.map(|(_, ratio)| ratio);
@@ -132,6 +141,83 @@ impl ChunkedCompressor {
             array,
         ))
     }
+
+    /// Compress consecutive chunks, the first of which is chunk `offset` of the array, reusing the
+    /// tree of an earlier chunk as long as it compresses well.
+    fn compress_chunks<'a>(
+        &'a self,
+        chunks: &[ArrayData],
+        offset: usize,
+        mut previous: Option<(CompressionTree<'a>, f32)>,
+        ctx: &SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedChunks<'a>> {
+        let mut compressed_chunks = Vec::with_capacity(chunks.len());
+        let mut compressed_trees = Vec::with_capacity(chunks.len());
+
+        for (index, chunk) in chunks.iter().enumerate() {
+            let (compressed_chunk, tree) = self
+                .compress_chunk(
+                    chunk,
+                    &mut previous,
+                    &ctx.named(&format!("chunk-{}", offset + index)),
+                )?
+                .into_parts();
+            compressed_chunks.push(compressed_chunk);
+            compressed_trees.push(tree);
+        }
+
+        Ok(CompressedChunks {
+            chunks: compressed_chunks,
+            trees: compressed_trees,
+            previous,
+        })
+    }
+
+    /// Compress a chunk like the chunks before it, as long as that compresses it well enough, and
+    /// update `previous` to the tree and ratio to compress later chunks like.
+    pub(crate) fn compress_chunk<'a>(
+        &'a self,
+        chunk: &ArrayData,
+        previous: &mut Option<(CompressionTree<'a>, f32)>,
+        ctx: &SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        // these are extremely valuable when reading/writing, but are potentially much more expensive
+        // to compute post-compression. That's because not all encodings implement stats, so we would
+        // potentially have to canonicalize during writes just to get stats, which would be silly.
+        // Also, we only really require them for column chunks, not for every array.
+        compute_precompression_stats(chunk, ctx.options().stats_policy())?;
+
+        let like = previous.as_ref().map(|(like, _)| like);
+        let compressed = ctx.compress(chunk, like)?;
+
+        let ratio = (compressed.nbytes() as f32) / (chunk.nbytes() as f32);
+        // Only a reused tree is worth re-sampling for, since a chunk compressed without one has
+        // just been sampled.
+        let regressed = previous.as_ref().is_some_and(|(_, target_ratio)| {
+            ratio > 1.0 || ratio > target_ratio * self.relatively_good_ratio(ctx)
+        });
+
+        if regressed {
+            log::debug!("unsatisfactory ratio {}, previous: {:?}", ratio, previous);
+            let resampled = ctx.compress_array(chunk)?;
+            let new_ratio = (resampled.nbytes() as f32) / (chunk.nbytes() as f32);
+            if new_ratio < ratio {
+                *previous = resampled.path().clone().map(|tree| (tree, new_ratio));
+                return Ok(resampled);
+            }
+            // The data changed for the worse, but the tree we have is still the best.
+        } else if previous.is_none() {
+            *previous = compressed.path().clone().map(|tree| (tree, ratio));
+        }
+        Ok(compressed)
+    }
+}
+
+/// The compressed chunks of one group, along with the tree to compress later chunks like.
+struct CompressedChunks<'a> {
+    chunks: Vec<ArrayData>,
+    trees: Vec<Option<CompressionTree<'a>>>,
+    previous: Option<(CompressionTree<'a>, f32)>,
 }
 
 fn like_into_parts(
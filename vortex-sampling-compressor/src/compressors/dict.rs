@@ -7,7 +7,8 @@ use vortex_array::encoding::{Encoding, EncodingRef};
 use vortex_array::stats::ArrayStatistics;
 use vortex_array::{ArrayData, IntoArrayData};
 use vortex_dict::{
-    dict_encode_primitive, dict_encode_varbin, dict_encode_varbinview, DictArray, DictEncoding,
+    dict_encode_primitive, dict_encode_varbin, dict_encode_varbinview, dict_top_k, DictArray,
+    DictEncoding,
 };
 use vortex_error::VortexResult;
 
@@ -54,18 +55,8 @@ impl EncodingCompressor for DictCompressor {
         like: Option<CompressionTree<'a>>,
         ctx: SamplingCompressor<'a>,
     ) -> VortexResult<CompressedArray<'a>> {
-        let (codes, values) = if let Some(p) = PrimitiveArray::maybe_from(array) {
-            let (codes, values) = dict_encode_primitive(&p);
-            (codes.into_array(), values.into_array())
-        } else if let Some(vb) = VarBinArray::maybe_from(array) {
-            let (codes, values) = dict_encode_varbin(&vb);
-            (codes.into_array(), values.into_array())
-        } else if let Some(vb) = VarBinViewArray::maybe_from(array) {
-            let (codes, values) = dict_encode_varbinview(&vb);
-            (codes.into_array(), values.into_array())
-        } else {
-            unreachable!("This array kind should have been filtered out");
-        };
+        let (codes, values) = dict_encode(array);
+        let codes = codes.into_array();
 
         let (codes, values) = (
             ctx.auxiliary("codes").excluding(self).compress(
@@ -88,3 +79,80 @@ impl EncodingCompressor for DictCompressor {
         HashSet::from([&DictEncoding as EncodingRef])
     }
 }
+
+pub const DEFAULT_PARTIAL_DICT_COMPRESSOR: PartialDictCompressor = PartialDictCompressor {
+    max_values: u8::MAX as usize,
+};
+
+/// Dictionary encodes only the most frequent values and patches in the others, for columns with
+/// a few common values among too many distinct ones for a full dictionary to pay off.
+#[derive(Debug)]
+pub struct PartialDictCompressor {
+    max_values: usize,
+}
+
+impl EncodingCompressor for PartialDictCompressor {
+    fn id(&self) -> &str {
+        "vortex.dict_partial"
+    }
+
+    fn cost(&self) -> u8 {
+        constants::DICT_COST
+    }
+
+    fn can_compress(&self, array: &ArrayData) -> Option<&dyn EncodingCompressor> {
+        DictCompressor
+            .can_compress(array)
+            .map(|_| self as &dyn EncodingCompressor)
+    }
+
+    fn compress<'a>(
+        &'a self,
+        array: &ArrayData,
+        like: Option<CompressionTree<'a>>,
+        ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let (codes, values) = dict_encode(array);
+        let dict = dict_top_k(&codes, values, self.max_values)?;
+
+        let (codes, values) = (
+            ctx.auxiliary("codes").excluding(self).compress(
+                &downscale_integer_array(dict.codes())?,
+                like.as_ref().and_then(|l| l.child(0)),
+            )?,
+            ctx.named("values")
+                .excluding(self)
+                .compress(&dict.values(), like.as_ref().and_then(|l| l.child(1)))?,
+        );
+        let patches = dict
+            .patches()
+            .map(|p| ctx.auxiliary("patches").excluding(self).compress_patches(p))
+            .transpose()?;
+
+        Ok(CompressedArray::compressed(
+            DictArray::try_new_with_patches(codes.array, values.array, patches)?.into_array(),
+            Some(CompressionTree::new(self, vec![codes.path, values.path])),
+            array,
+        ))
+    }
+
+    fn used_encodings(&self) -> HashSet<EncodingRef> {
+        HashSet::from([&DictEncoding as EncodingRef])
+    }
+}
+
+/// Dictionary encode all the values of an array the [DictCompressor] can compress.
+fn dict_encode(array: &ArrayData) -> (PrimitiveArray, ArrayData) {
+    if let Some(p) = PrimitiveArray::maybe_from(array) {
+        let (codes, values) = dict_encode_primitive(&p);
+        (codes, values.into_array())
+    } else if let Some(vb) = VarBinArray::maybe_from(array) {
+        let (codes, values) = dict_encode_varbin(&vb);
+        (codes, values.into_array())
+    } else if let Some(vb) = VarBinViewArray::maybe_from(array) {
+        let (codes, values) = dict_encode_varbinview(&vb);
+        (codes, values.into_array())
+    } else {
+        unreachable!("This array kind should have been filtered out");
+    }
+}
@@ -2,11 +2,14 @@ use std::any::Any;
 use std::fmt::Debug;
 use std::sync::Arc;
 
-use fsst::Compressor;
+use fsst::{Compressor, CompressorBuilder, Symbol};
+use serde::{Deserialize, Serialize};
 use vortex_array::aliases::hash_set::HashSet;
 use vortex_array::array::{VarBinEncoding, VarBinViewEncoding};
 use vortex_array::encoding::{Encoding, EncodingRef};
-use vortex_array::{ArrayDType, IntoArrayData};
+use vortex_array::{
+    ArrayDType, IntoArrayData, TryDeserializeArrayMetadata, TrySerializeArrayMetadata,
+};
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_fsst::{fsst_compress, fsst_train_compressor, FSSTArray, FSSTEncoding};
@@ -25,10 +28,29 @@ pub struct FSSTCompressor;
 /// Maximum size in bytes of the FSST symbol table
 const FSST_SYMTAB_MAX_SIZE: usize = 8 * 255 + 255;
 
+/// The serializable form of a trained FSST [Compressor].
+#[derive(Serialize, Deserialize)]
+struct SymbolTable {
+    symbols: Vec<u64>,
+    symbol_lengths: Vec<u8>,
+}
+
 impl EncoderMetadata for Compressor {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn try_serialize(&self) -> VortexResult<Option<Arc<[u8]>>> {
+        let symbols_vec: Vec<Symbol> = self.symbol_table().to_vec();
+        // SAFETY: Symbol and u64 are same size
+        let symbols: Vec<u64> = unsafe { std::mem::transmute(symbols_vec) };
+        SymbolTable {
+            symbols,
+            symbol_lengths: self.symbol_lengths().to_vec(),
+        }
+        .try_serialize_metadata()
+        .map(Some)
+    }
 }
 
 impl EncodingCompressor for FSSTCompressor {
@@ -134,4 +156,13 @@ impl EncodingCompressor for FSSTCompressor {
     fn used_encodings(&self) -> HashSet<EncodingRef> {
         HashSet::from([&FSSTEncoding as EncodingRef])
     }
+
+    fn deserialize_metadata(&self, metadata: &[u8]) -> VortexResult<Arc<dyn EncoderMetadata>> {
+        let table = SymbolTable::try_deserialize_metadata(Some(metadata))?;
+        let mut builder = CompressorBuilder::new();
+        for (symbol, symbol_len) in table.symbols.iter().zip(table.symbol_lengths.iter()) {
+            builder.insert(Symbol::from_slice(&symbol.to_le_bytes()), *symbol_len as _);
+        }
+        Ok(Arc::new(builder.build()))
+    }
 }
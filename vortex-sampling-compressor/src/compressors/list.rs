@@ -3,7 +3,10 @@ use vortex_array::array::{ListArray, ListEncoding};
 use vortex_array::encoding::{Encoding, EncodingRef};
 use vortex_array::{ArrayData, IntoArrayData};
 use vortex_error::VortexResult;
+use vortex_fastlanes::{BitPackedEncoding, DeltaEncoding};
 
+use crate::compressors::bitpacked::BITPACK_WITH_PATCHES;
+use crate::compressors::delta::DeltaCompressor;
 use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
 use crate::downscale::downscale_integer_array;
 use crate::{constants, SamplingCompressor};
@@ -31,19 +34,26 @@ impl EncodingCompressor for ListCompressor {
         ctx: SamplingCompressor<'a>,
     ) -> VortexResult<CompressedArray<'a>> {
         let list_array = ListArray::try_from(array.clone())?;
+        let compressed_validity = ctx.compress_validity(list_array.validity())?;
         let compressed_elements = ctx.named("elements").compress(
             &list_array.elements(),
             like.as_ref().and_then(|l| l.child(0)),
         )?;
-        let compressed_offsets = ctx.auxiliary("offsets").compress(
-            &downscale_integer_array(list_array.offsets())?,
-            like.as_ref().and_then(|l| l.child(1)),
-        )?;
+        // The offsets only ever increase, by the lengths of the lists, so they're best compressed as
+        // deltas whether or not delta encoding is enabled for other arrays.
+        let compressed_offsets = ctx
+            .auxiliary("offsets")
+            .including(&DeltaCompressor)
+            .including(&BITPACK_WITH_PATCHES)
+            .compress(
+                &downscale_integer_array(list_array.offsets())?,
+                like.as_ref().and_then(|l| l.child(1)),
+            )?;
         Ok(CompressedArray::compressed(
             ListArray::try_new(
                 compressed_elements.array,
                 compressed_offsets.array,
-                list_array.validity(),
+                compressed_validity,
             )?
             .into_array(),
             Some(CompressionTree::new(
@@ -55,6 +65,10 @@ impl EncodingCompressor for ListCompressor {
     }
 
     fn used_encodings(&self) -> HashSet<EncodingRef> {
-        HashSet::from([&ListEncoding as EncodingRef])
+        HashSet::from([
+            &ListEncoding as EncodingRef,
+            &DeltaEncoding,
+            &BitPackedEncoding,
+        ])
     }
 }
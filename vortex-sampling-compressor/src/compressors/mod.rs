@@ -4,12 +4,15 @@ use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use itertools::{EitherOrBoth, Itertools};
+use serde::{Deserialize, Serialize};
 use vortex_array::aliases::hash_set::HashSet;
 use vortex_array::encoding::EncodingRef;
 use vortex_array::stats::ArrayStatistics;
 use vortex_array::tree::TreeFormatter;
-use vortex_array::{ArrayDType, ArrayData};
-use vortex_error::{vortex_panic, VortexExpect, VortexResult};
+use vortex_array::{ArrayDType, ArrayData, TryDeserializeArrayMetadata, TrySerializeArrayMetadata};
+use vortex_error::{
+    vortex_bail, vortex_err, vortex_panic, VortexError, VortexExpect, VortexResult,
+};
 
 use crate::SamplingCompressor;
 
@@ -50,6 +53,12 @@ pub trait EncodingCompressor: Sync + Send + Debug {
     ) -> VortexResult<CompressedArray<'a>>;
 
     fn used_encodings(&self) -> HashSet<EncodingRef>;
+
+    /// Load the metadata of a tree built by this compressor, as serialized by
+    /// [EncoderMetadata::try_serialize].
+    fn deserialize_metadata(&self, _metadata: &[u8]) -> VortexResult<Arc<dyn EncoderMetadata>> {
+        vortex_bail!("{} does not have serializable metadata", self.id())
+    }
 }
 
 pub type CompressorRef<'a> = &'a dyn EncodingCompressor;
@@ -83,8 +92,14 @@ impl Debug for CompressionTree<'_> {
 ///
 /// This enables codecs to cache trained parameters from the sampling runs to reuse for
 /// the large run.
-pub trait EncoderMetadata {
+pub trait EncoderMetadata: Send + Sync {
     fn as_any(&self) -> &dyn Any;
+
+    /// Serialize the metadata along with its tree, or return `None` if it can't be, in which case
+    /// the compressor learns it again when the tree is replayed.
+    fn try_serialize(&self) -> VortexResult<Option<Arc<[u8]>>> {
+        Ok(None)
+    }
 }
 
 impl Display for CompressionTree<'_> {
@@ -148,6 +163,10 @@ impl<'a> CompressionTree<'a> {
         self.children[idx].as_ref()
     }
 
+    pub fn children(&self) -> impl Iterator<Item = Option<&CompressionTree<'a>>> {
+        self.children.iter().map(Option::as_ref)
+    }
+
     /// Compresses array with our compressor without verifying that the compressor can compress this array
     pub fn compress_unchecked(
         &self,
@@ -195,6 +214,69 @@ impl<'a> CompressionTree<'a> {
     ) {
         (self.compressor, self.children, self.metadata)
     }
+
+    /// Serialize the tree, so that it can be persisted and later loaded with
+    /// [SamplingCompressor::deserialize_tree] to compress similar arrays without sampling them.
+    pub fn serialize(&self) -> VortexResult<Arc<[u8]>> {
+        SerializedCompressionTree::try_from(self)?.try_serialize_metadata()
+    }
+}
+
+/// The serializable form of a [CompressionTree], which refers to its compressors by id.
+#[derive(Debug, Serialize, Deserialize)]
+struct SerializedCompressionTree {
+    id: String,
+    children: Vec<Option<SerializedCompressionTree>>,
+    metadata: Option<Vec<u8>>,
+}
+
+impl TryFrom<&CompressionTree<'_>> for SerializedCompressionTree {
+    type Error = VortexError;
+
+    fn try_from(tree: &CompressionTree<'_>) -> VortexResult<Self> {
+        Ok(Self {
+            id: tree.compressor.id().to_string(),
+            children: tree
+                .children()
+                .map(|child| child.map(Self::try_from).transpose())
+                .collect::<VortexResult<_>>()?,
+            metadata: tree
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.try_serialize())
+                .transpose()?
+                .flatten()
+                .map(|metadata| metadata.to_vec()),
+        })
+    }
+}
+
+impl SerializedCompressionTree {
+    fn load<'a>(self, ctx: &SamplingCompressor<'a>) -> VortexResult<CompressionTree<'a>> {
+        let compressor = ctx
+            .compressor_by_id(&self.id)
+            .ok_or_else(|| vortex_err!("Unknown compressor {} in compression tree", self.id))?;
+        Ok(CompressionTree {
+            compressor,
+            children: self
+                .children
+                .into_iter()
+                .map(|child| child.map(|child| child.load(ctx)).transpose())
+                .collect::<VortexResult<_>>()?,
+            metadata: self
+                .metadata
+                .map(|metadata| compressor.deserialize_metadata(&metadata))
+                .transpose()?,
+        })
+    }
+}
+
+/// Load a tree serialized with [CompressionTree::serialize], resolving its compressors with `ctx`.
+pub(crate) fn deserialize_tree<'a>(
+    bytes: &[u8],
+    ctx: &SamplingCompressor<'a>,
+) -> VortexResult<CompressionTree<'a>> {
+    SerializedCompressionTree::try_deserialize_metadata(Some(bytes))?.load(ctx)
 }
 
 #[derive(Debug, Clone)]
@@ -282,7 +364,7 @@ impl<'a> CompressedArray<'a> {
     }
 
     #[inline]
-    pub fn path(&self) -> &Option<CompressionTree> {
+    pub fn path(&self) -> &Option<CompressionTree<'a>> {
         &self.path
     }
 
@@ -5,7 +5,7 @@ use vortex_array::compress::compute_precompression_stats;
 use vortex_array::encoding::{Encoding, EncodingRef};
 use vortex_array::variants::StructArrayTrait;
 use vortex_array::{ArrayDType, ArrayData, ArrayLen, IntoArrayData};
-use vortex_dtype::DType;
+use vortex_dtype::{DType, FieldName};
 use vortex_error::VortexResult;
 
 use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
@@ -43,17 +43,43 @@ impl EncodingCompressor for StructCompressor {
             None => vec![None; array.nfields()],
         };
 
-        let (arrays, trees) = array
-            .children()
-            .zip_eq(children_trees)
-            .map(|(array, like)| {
+        let compress_child =
+            |((name, array), like): ((FieldName, ArrayData), Option<CompressionTree<'a>>)| {
                 // these are extremely valuable when reading/writing, but are potentially much more expensive
                 // to compute post-compression. That's because not all encodings implement stats, so we would
                 // potentially have to canonicalize during writes just to get stats, which would be silly.
                 // Also, we only really require them for column chunks, not for every array.
-                compute_precompression_stats(&array)?;
-                ctx.compress(&array, like.as_ref())
-            })
+                compute_precompression_stats(&array, ctx.options().stats_policy())?;
+                ctx.for_field(&name).compress_column(&array, like.as_ref())
+            };
+
+        // Fields are independent of each other, so they can be compressed on separate threads.
+        #[cfg(feature = "rayon")]
+        let (arrays, trees): (Vec<_>, Vec<_>) = {
+            use rayon::prelude::*;
+
+            array
+                .names()
+                .iter()
+                .cloned()
+                .zip_eq(array.children())
+                .collect::<Vec<_>>()
+                .into_par_iter()
+                .zip_eq(children_trees)
+                .map(compress_child)
+                .collect::<VortexResult<Vec<_>>>()?
+                .into_iter()
+                .map(|x| (x.array, x.path))
+                .unzip()
+        };
+        #[cfg(not(feature = "rayon"))]
+        let (arrays, trees) = array
+            .names()
+            .iter()
+            .cloned()
+            .zip_eq(array.children())
+            .zip_eq(children_trees)
+            .map(compress_child)
             .process_results(|iter| iter.map(|x| (x.array, x.path)).unzip())?;
 
         Ok(CompressedArray::compressed(
@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 
 pub const DEFAULT_MAX_COST: u8 = 3;
+pub const FAST_MAX_COST: u8 = 2;
+pub const BEST_MAX_COST: u8 = 4;
 
 // structural pass-throughs have no cost
 pub const CHUNKED_COST: u8 = 0;
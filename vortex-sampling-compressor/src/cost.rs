@@ -0,0 +1,127 @@
+use std::fmt::Debug;
+
+use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
+
+/// Decides how expensive each encoding is to decode and how to score candidate compressions of a
+/// sample against each other.
+///
+/// Implement this to bias the compressor toward the trees that suit a workload, e.g. smaller files
+/// for archival data or cheaper-to-decode trees for read-heavy tables.
+pub trait CostModel: Debug + Send + Sync {
+    /// The cost of decoding one layer of the given compressor's encoding. The costs along any path
+    /// of a compression tree add up to at most the configured maximum cost.
+    fn cost(&self, compressor: &dyn EncodingCompressor) -> u8 {
+        compressor.cost()
+    }
+
+    /// Score the compression of an array of `uncompressed_nbytes` bytes, where lower is better.
+    ///
+    /// A compression is only chosen over leaving the array uncompressed if it scores lower.
+    fn evaluate(&self, compressed: &CompressedArray, uncompressed_nbytes: usize) -> f64;
+
+    /// The total cost of decoding along the most expensive path of the given tree.
+    fn tree_cost(&self, tree: Option<&CompressionTree>) -> u32 {
+        tree.map(|tree| {
+            u32::from(self.cost(tree.compressor()))
+                + tree
+                    .children()
+                    .map(|child| self.tree_cost(child))
+                    .max()
+                    .unwrap_or(0)
+        })
+        .unwrap_or(0)
+    }
+}
+
+/// Prefer whichever compression is smallest, no matter how expensive it is to decode.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RatioCostModel;
+
+impl CostModel for RatioCostModel {
+    fn evaluate(&self, compressed: &CompressedArray, uncompressed_nbytes: usize) -> f64 {
+        (compressed.nbytes() as f64) / (uncompressed_nbytes as f64)
+    }
+}
+
+/// Trade size for decoding speed, by inflating the compression ratio of a tree by `weight` for
+/// every unit of cost along its most expensive path.
+#[derive(Debug, Clone, Copy)]
+pub struct DecompressionCostModel {
+    weight: f64,
+}
+
+impl DecompressionCostModel {
+    pub fn new(weight: f64) -> Self {
+        Self { weight }
+    }
+
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+}
+
+impl Default for DecompressionCostModel {
+    fn default() -> Self {
+        Self::new(0.1)
+    }
+}
+
+impl CostModel for DecompressionCostModel {
+    fn evaluate(&self, compressed: &CompressedArray, uncompressed_nbytes: usize) -> f64 {
+        let ratio = (compressed.nbytes() as f64) / (uncompressed_nbytes as f64);
+        ratio * (1.0 + self.weight * f64::from(self.tree_cost(compressed.path().as_ref())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_alp::ALPEncoding;
+    use vortex_array::array::{PrimitiveArray, PrimitiveEncoding};
+    use vortex_array::encoding::Encoding;
+    use vortex_array::{ArrayData, IntoArrayData};
+
+    use crate::cost::{CostModel, DecompressionCostModel, RatioCostModel};
+    #[allow(deprecated)]
+    use crate::Objective;
+    use crate::{CompressConfig, SamplingCompressor, DEFAULT_COMPRESSORS};
+
+    fn floats() -> ArrayData {
+        PrimitiveArray::from(
+            (0..4096u32)
+                .map(|i| f64::from(i) / 100.0)
+                .collect::<Vec<_>>(),
+        )
+        .into_array()
+    }
+
+    #[test]
+    fn decompression_cost_avoids_expensive_encodings() {
+        let array = floats();
+
+        let compressed = SamplingCompressor::default()
+            .compress(&array, None)
+            .unwrap()
+            .into_array();
+        assert_eq!(compressed.encoding().id(), ALPEncoding::ID);
+
+        let compressor = SamplingCompressor::new_with_options(
+            DEFAULT_COMPRESSORS.into_iter().collect(),
+            CompressConfig::default().with_cost_model(DecompressionCostModel::new(100.0)),
+        );
+        let compressed = compressor.compress(&array, None).unwrap().into_array();
+        assert_eq!(compressed.encoding().id(), PrimitiveEncoding::ID);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn objective_evaluates_with_the_cost_model() {
+        let array = floats();
+        let compressed = SamplingCompressor::default()
+            .compress(&array, None)
+            .unwrap();
+        assert_eq!(
+            Objective::evaluate(&compressed, array.nbytes(), &CompressConfig::default()),
+            RatioCostModel.evaluate(&compressed, array.nbytes())
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! Choose among the float encodings from a profile of the values, rather than by sampling each of
+//! them.
+//!
+//! The float encodings are treated as a family: if any of them is a candidate for an array, the
+//! profile picks the one that suits the values, whether or not it was among the candidates, and
+//! only samples all of them when the profile is inconclusive.
+
+use std::mem::size_of;
+
+use num_traits::ToPrimitive;
+use vortex_alp::{match_each_alp_float_ptype, ALPEncoding, ALPFloat, ALPRDEncoding, Exponents};
+use vortex_array::aliases::hash_set::HashSet;
+use vortex_array::array::PrimitiveArray;
+use vortex_array::encoding::{Encoding as _, EncodingId};
+use vortex_array::variants::PrimitiveArrayTrait;
+use vortex_array::ArrayData;
+use vortex_dtype::PType;
+
+use crate::compressors::alp::ALPCompressor;
+use crate::compressors::alp_rd::ALPRDCompressor;
+use crate::compressors::{CompressorRef, EncodingCompressor};
+
+/// ALP encodes decimals, so it is the float encoding to use when nearly all the values are decimals.
+const MIN_ALP_DECIMAL_FRACTION: f64 = 0.9;
+/// ALP-RD splits off the bits that differ from value to value, which it only needs to do when
+/// most values are unique.
+const MIN_ALP_RD_DISTINCT_MANTISSA_FRACTION: f64 = 0.5;
+/// When at least half of the values aren't decimals, ALP would store most of them as exceptions.
+const MAX_ALP_RD_DECIMAL_FRACTION: f64 = 0.5;
+/// ALP-RD keeps a dictionary of up to 8 distinct leading bits, which hold the exponent, so values
+/// with more exponents than that become exceptions.
+const MAX_ALP_RD_EXPONENT_SPREAD: u16 = 7;
+
+/// The float encodings chosen among by their profile, as opposed to by sampling.
+pub(crate) const FLOAT_COMPRESSORS: [CompressorRef<'static>; 2] =
+    [&ALPCompressor as CompressorRef, &ALPRDCompressor];
+
+/// Statistics of an array of floats that tell which float encoding suits it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FloatProfile {
+    /// The difference between the largest and smallest binary exponents of the finite, non-zero
+    /// values.
+    exponent_spread: u16,
+    /// The number of distinct mantissas relative to the number of finite, non-zero values.
+    distinct_mantissa_fraction: f64,
+    /// The fraction of the values that are decimals ALP encodes without exceptions.
+    decimal_fraction: f64,
+}
+
+impl FloatProfile {
+    /// Profile an array of f32 or f64 values.
+    pub(crate) fn new(array: &PrimitiveArray) -> Self {
+        match_each_alp_float_ptype!(array.ptype(), |$T| {
+            Self::from_values(array.maybe_null_slice::<$T>())
+        })
+    }
+
+    fn from_values<T: ALPFloat>(values: &[T]) -> Self {
+        let mut min_exponent = i16::MAX;
+        let mut max_exponent = i16::MIN;
+        let mut mantissas = HashSet::with_capacity(values.len());
+        let mut nonzero = 0usize;
+        let mut decimals = 0usize;
+
+        for &value in values {
+            if is_decimal(value) {
+                decimals += 1;
+            }
+            if !value.is_finite() || value.is_zero() {
+                continue;
+            }
+            let (mantissa, exponent, _) = value.integer_decode();
+            min_exponent = min_exponent.min(exponent);
+            max_exponent = max_exponent.max(exponent);
+            mantissas.insert(mantissa);
+            nonzero += 1;
+        }
+
+        Self {
+            exponent_spread: if nonzero == 0 {
+                0
+            } else {
+                max_exponent.abs_diff(min_exponent)
+            },
+            distinct_mantissa_fraction: fraction(mantissas.len(), nonzero),
+            decimal_fraction: fraction(decimals, values.len()),
+        }
+    }
+
+    /// The id of the float encoding that suits the values, or `None` if the profile can't tell and
+    /// the encodings must be sampled, i.e. when the values are partly decimals and partly repeated,
+    /// or their exponents are too spread out for ALP-RD.
+    pub(crate) fn preferred_encoding(&self) -> Option<EncodingId> {
+        if self.decimal_fraction >= MIN_ALP_DECIMAL_FRACTION {
+            Some(ALPEncoding::ID)
+        } else if self.exponent_spread <= MAX_ALP_RD_EXPONENT_SPREAD
+            && (self.decimal_fraction <= MAX_ALP_RD_DECIMAL_FRACTION
+                || self.distinct_mantissa_fraction >= MIN_ALP_RD_DISTINCT_MANTISSA_FRACTION)
+        {
+            Some(ALPRDEncoding::ID)
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether ALP can encode the value with some exponent into an integer of at most half the bits
+/// of the float, which is what makes the encoding worthwhile.
+fn is_decimal<T: ALPFloat>(value: T) -> bool {
+    let max_encoded = 1u64 << (size_of::<T>() * 4);
+    value.is_finite()
+        && (0..T::MAX_EXPONENT).any(|e| {
+            T::encode_single(value, Exponents { e, f: 0 }).is_ok_and(|encoded| {
+                encoded
+                    .to_i64()
+                    .is_some_and(|encoded| encoded.unsigned_abs() < max_encoded)
+            })
+        })
+}
+
+fn fraction(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+/// Replace the float encodings among the candidates with the one that the profile of the array
+/// prefers, or with all the `eligible` float encodings if it has no preference.
+pub(crate) fn choose_float_candidates(
+    candidates: &mut Vec<&dyn EncodingCompressor>,
+    array: &ArrayData,
+    eligible: impl Fn(&dyn EncodingCompressor) -> bool,
+) {
+    if !candidates
+        .iter()
+        .any(|candidate| is_float_encoding(candidate.id()))
+    {
+        return;
+    }
+    let Some(parray) = PrimitiveArray::maybe_from(array) else {
+        return;
+    };
+    if !matches!(parray.ptype(), PType::F32 | PType::F64) {
+        return;
+    }
+
+    let profile = FloatProfile::new(&parray);
+    let preferred = profile.preferred_encoding();
+    let eligible = FLOAT_COMPRESSORS
+        .into_iter()
+        .filter(|&compressor| eligible(compressor))
+        .collect::<Vec<_>>();
+    let chosen = eligible
+        .iter()
+        .copied()
+        .filter(|compressor| {
+            preferred.map_or(true, |preferred| compressor.id() == preferred.as_ref())
+        })
+        .collect::<Vec<_>>();
+    let chosen = if chosen.is_empty() { eligible } else { chosen };
+    log::debug!(
+        "choosing {:?} for floats with {:?}",
+        chosen.iter().map(|c| c.id()).collect::<Vec<_>>(),
+        profile
+    );
+
+    candidates.retain(|candidate| !is_float_encoding(candidate.id()));
+    candidates.extend(chosen);
+}
+
+pub(crate) fn is_float_encoding(id: &str) -> bool {
+    FLOAT_COMPRESSORS
+        .iter()
+        .any(|compressor| compressor.id() == id)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_alp::{ALPEncoding, ALPRDEncoding};
+    use vortex_array::encoding::Encoding;
+
+    use crate::float_profile::FloatProfile;
+
+    #[test]
+    fn decimals_prefer_alp() {
+        let values = (0..1024).map(|i| f64::from(i) / 100.0).collect::<Vec<_>>();
+        let profile = FloatProfile::from_values(&values);
+        assert_eq!(profile.decimal_fraction, 1.0);
+        assert_eq!(profile.preferred_encoding(), Some(ALPEncoding::ID));
+    }
+
+    #[test]
+    fn real_doubles_prefer_alp_rd() {
+        let values = (1..1024)
+            .map(|i| 1.0 + f64::from(i).sqrt() / 100.0)
+            .collect::<Vec<_>>();
+        let profile = FloatProfile::from_values(&values);
+        assert!(profile.exponent_spread <= 1);
+        assert_eq!(profile.distinct_mantissa_fraction, 1.0);
+        assert_eq!(profile.preferred_encoding(), Some(ALPRDEncoding::ID));
+    }
+
+    #[test]
+    fn wide_doubles_are_sampled() {
+        let values = (1..1024)
+            .map(|i| f64::from(i).sqrt() * 2f64.powi(i % 64))
+            .collect::<Vec<_>>();
+        let profile = FloatProfile::from_values(&values);
+        assert!(profile.exponent_spread > 7);
+        assert_eq!(profile.preferred_encoding(), None);
+    }
+}
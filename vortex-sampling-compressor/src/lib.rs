@@ -1,6 +1,7 @@
-use std::sync::{Arc, LazyLock};
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::Duration;
 
-use compressors::bitpacked::BITPACK_WITH_PATCHES;
+use compressors::bitpacked::{BITPACK_NO_PATCHES, BITPACK_WITH_PATCHES};
 use compressors::chunked::DEFAULT_CHUNKED_COMPRESSOR;
 use compressors::constant::ConstantCompressor;
 use compressors::delta::DeltaCompressor;
@@ -12,16 +13,20 @@ use compressors::roaring_int::RoaringIntCompressor;
 use compressors::struct_::StructCompressor;
 use compressors::varbin::VarBinCompressor;
 use compressors::{CompressedArray, CompressorRef};
+use cost::{CostModel, RatioCostModel};
+use rules::CompressionRule;
 use vortex_alp::{ALPEncoding, ALPRDEncoding};
 use vortex_array::array::{
     ListEncoding, PrimitiveEncoding, SparseEncoding, StructEncoding, VarBinEncoding,
     VarBinViewEncoding,
 };
 use vortex_array::encoding::EncodingRef;
+use vortex_array::stats::StatsPolicy;
 use vortex_array::Context;
 use vortex_bytebool::ByteBoolEncoding;
 use vortex_datetime_parts::DateTimePartsEncoding;
 use vortex_dict::DictEncoding;
+use vortex_error::{vortex_bail, vortex_panic, VortexResult};
 use vortex_fastlanes::{BitPackedEncoding, DeltaEncoding, FoREncoding};
 use vortex_fsst::FSSTEncoding;
 #[cfg(not(target_arch = "wasm32"))]
@@ -31,8 +36,9 @@ use vortex_runend_bool::RunEndBoolEncoding;
 use vortex_zigzag::ZigZagEncoding;
 
 use crate::compressors::alp::ALPCompressor;
+use crate::compressors::alp_rd::ALPRDCompressor;
 use crate::compressors::date_time_parts::DateTimePartsCompressor;
-use crate::compressors::dict::DictCompressor;
+use crate::compressors::dict::{DictCompressor, DEFAULT_PARTIAL_DICT_COMPRESSOR};
 use crate::compressors::list::ListCompressor;
 use crate::compressors::r#for::FoRCompressor;
 use crate::compressors::runend::DEFAULT_RUN_END_COMPRESSOR;
@@ -44,9 +50,14 @@ use crate::compressors::zigzag::ZigZagCompressor;
 pub mod arbitrary;
 pub mod compressors;
 mod constants;
+pub mod cost;
 mod downscale;
+mod float_profile;
+pub mod report;
+pub mod rules;
 mod sampling;
 mod sampling_compressor;
+pub mod stream;
 
 pub use sampling_compressor::*;
 
@@ -71,15 +82,33 @@ pub const DEFAULT_COMPRESSORS: [CompressorRef; 15] = [
     &ZigZagCompressor,
 ];
 
+/// The encodings that are cheapest to search over and to decompress, used by
+/// [CompressionLevel::Fast].
+pub const FAST_COMPRESSORS: [CompressorRef; 11] = [
+    &ALPCompressor as CompressorRef,
+    &BITPACK_WITH_PATCHES,
+    &DEFAULT_CHUNKED_COMPRESSOR,
+    &ConstantCompressor,
+    &DictCompressor,
+    &FoRCompressor,
+    &SparseCompressor,
+    &StructCompressor,
+    &ListCompressor,
+    &VarBinCompressor,
+    &ZigZagCompressor,
+];
+
 #[cfg(not(target_arch = "wasm32"))]
-pub const ALL_COMPRESSORS: [CompressorRef; 18] = [
+pub const ALL_COMPRESSORS: [CompressorRef; 20] = [
     &ALPCompressor as CompressorRef,
+    &ALPRDCompressor,
     &BITPACK_WITH_PATCHES,
     &DEFAULT_CHUNKED_COMPRESSOR,
     &ConstantCompressor,
     &DateTimePartsCompressor,
     &DeltaCompressor,
     &DictCompressor,
+    &DEFAULT_PARTIAL_DICT_COMPRESSOR,
     &FoRCompressor,
     &FSSTCompressor,
     &RoaringBoolCompressor,
@@ -94,14 +123,16 @@ pub const ALL_COMPRESSORS: [CompressorRef; 18] = [
 ];
 
 #[cfg(target_arch = "wasm32")]
-pub const ALL_COMPRESSORS: [CompressorRef; 16] = [
+pub const ALL_COMPRESSORS: [CompressorRef; 18] = [
     &ALPCompressor as CompressorRef,
+    &ALPRDCompressor,
     &BITPACK_WITH_PATCHES,
     &DEFAULT_CHUNKED_COMPRESSOR,
     &ConstantCompressor,
     &DateTimePartsCompressor,
     &DeltaCompressor,
     &DictCompressor,
+    &DEFAULT_PARTIAL_DICT_COMPRESSOR,
     &FoRCompressor,
     &FSSTCompressor,
     // vortex-roaring depends on croaring which does not build for wasm32
@@ -144,24 +175,88 @@ pub static ALL_ENCODINGS_CONTEXT: LazyLock<Arc<Context>> = LazyLock::new(|| {
     ]))
 });
 
+/// Compressors from other crates, which the default and preset compressors try along with their own.
+static REGISTERED_COMPRESSORS: LazyLock<RwLock<Vec<CompressorRef<'static>>>> =
+    LazyLock::new(Default::default);
+
+/// Register a compressor, e.g. for a private encoding.
+///
+/// [SamplingCompressor::default] and [SamplingCompressor::with_level] then try it, and trees built
+/// with it can be deserialized. The encodings it uses should be registered with
+/// [vortex_array::register_encoding] for readers to read them.
+///
+/// Registering a compressor with the id of a built-in one fails.
+pub fn register_compressor(compressor: CompressorRef<'static>) -> VortexResult<()> {
+    if ALL_COMPRESSORS
+        .iter()
+        .copied()
+        .chain([&BITPACK_NO_PATCHES as CompressorRef])
+        .any(|c| c.id() == compressor.id())
+    {
+        vortex_bail!(
+            "Cannot register compressor {}, a built-in compressor has its id",
+            compressor.id()
+        );
+    }
+
+    let mut registered = REGISTERED_COMPRESSORS
+        .write()
+        .unwrap_or_else(|poison| vortex_panic!("Failed to lock registered compressors: {poison}"));
+    if !registered.iter().any(|c| c.id() == compressor.id()) {
+        registered.push(compressor);
+    }
+    Ok(())
+}
+
+/// The compressors registered with [register_compressor].
+pub fn registered_compressors() -> Vec<CompressorRef<'static>> {
+    REGISTERED_COMPRESSORS
+        .read()
+        .unwrap_or_else(|poison| vortex_panic!("Failed to lock registered compressors: {poison}"))
+        .clone()
+}
+
+/// Presets that trade write throughput for compression ratio.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CompressionLevel {
+    /// Take fewer samples, build shallower trees and only try the cheapest encodings.
+    Fast,
+    #[default]
+    Default,
+    /// Take more samples, build deeper trees and try every encoding.
+    Best,
+}
+
+impl CompressionLevel {
+    /// The encodings to try at this level.
+    pub fn compressors(&self) -> &'static [CompressorRef<'static>] {
+        match self {
+            Self::Fast => &FAST_COMPRESSORS,
+            Self::Default => &DEFAULT_COMPRESSORS,
+            Self::Best => &ALL_COMPRESSORS,
+        }
+    }
+}
+
+#[deprecated(note = "Move to using CompressConfig::with_cost_model instead")]
 #[derive(Debug, Clone)]
 pub enum Objective {
     MinSize,
 }
 
+#[allow(deprecated)]
 impl Objective {
     pub fn starting_value(&self) -> f64 {
         1.0
     }
 
+    /// Score the compression with the config's cost model.
     pub fn evaluate(
         array: &CompressedArray,
         base_size_bytes: usize,
         config: &CompressConfig,
     ) -> f64 {
-        match &config.objective {
-            Objective::MinSize => (array.nbytes() as f64) / (base_size_bytes as f64),
-        }
+        config.cost_model().evaluate(array, base_size_bytes)
     }
 }
 
@@ -177,12 +272,29 @@ pub struct CompressConfig {
     // Maximum depth of compression tree
     max_cost: u8,
     // Are we minimizing size or maximizing performance?
-    objective: Objective,
+    cost_model: Arc<dyn CostModel>,
 
     // Target chunk size in bytes
     target_block_bytesize: usize,
     // Target chunk size in row count
     target_block_size: usize,
+
+    // Which statistics to compute ahead of compressing an array
+    stats_policy: StatsPolicy,
+
+    // Time allowed for a single call to compress, as it runs out fewer encodings are tried
+    time_budget: Option<Duration>,
+    // Memory allowed for compressing an array, as it runs out fewer encodings are tried
+    memory_budget: Option<usize>,
+
+    // Constraints on the encodings of particular columns
+    rules: Vec<CompressionRule>,
+
+    // How far the compression ratio of a chunk may regress before re-sampling it
+    chunk_ratio_tolerance: Option<f32>,
+
+    // Whether to choose among the float encodings from the profile of the values, or sample them all
+    profile_floats: bool,
 }
 
 impl Default for CompressConfig {
@@ -194,10 +306,130 @@ impl Default for CompressConfig {
             sample_size: 64,
             sample_count: 16,
             max_cost: constants::DEFAULT_MAX_COST,
-            objective: Objective::MinSize,
+            cost_model: Arc::new(RatioCostModel),
             target_block_bytesize: 16 * mib,
             target_block_size: 64 * kib,
             rng_seed: 0,
+            stats_policy: StatsPolicy::default(),
+            time_budget: None,
+            memory_budget: None,
+            rules: Vec::new(),
+            chunk_ratio_tolerance: None,
+            profile_floats: true,
         }
     }
 }
+
+impl CompressConfig {
+    /// The sample size and maximum tree depth of the given level.
+    pub fn for_level(level: CompressionLevel) -> Self {
+        let default = Self::default();
+        match level {
+            CompressionLevel::Fast => Self {
+                sample_count: 4,
+                max_cost: constants::FAST_MAX_COST,
+                // Only ALP is fast enough to decompress, so there's no float encoding to choose.
+                profile_floats: false,
+                ..default
+            },
+            CompressionLevel::Default => default,
+            CompressionLevel::Best => Self {
+                sample_size: 128,
+                sample_count: 32,
+                max_cost: constants::BEST_MAX_COST,
+                profile_floats: false,
+                ..default
+            },
+        }
+    }
+
+    pub fn with_cost_model(mut self, cost_model: impl CostModel + 'static) -> Self {
+        self.cost_model = Arc::new(cost_model);
+        self
+    }
+
+    pub fn cost_model(&self) -> &dyn CostModel {
+        self.cost_model.as_ref()
+    }
+
+    pub fn with_stats_policy(mut self, stats_policy: StatsPolicy) -> Self {
+        self.stats_policy = stats_policy;
+        self
+    }
+
+    pub fn stats_policy(&self) -> &StatsPolicy {
+        &self.stats_policy
+    }
+
+    /// Bound the wall-clock time of compressing an array. Once half of it is used, the remaining
+    /// arrays are compressed with progressively shallower trees and fewer samples, until only the
+    /// encodings that don't deepen the tree are tried.
+    pub fn with_time_budget(mut self, time_budget: Duration) -> Self {
+        self.time_budget = Some(time_budget);
+        self
+    }
+
+    pub fn time_budget(&self) -> Option<Duration> {
+        self.time_budget
+    }
+
+    /// Bound the memory used for compressing an array, counting the arrays, samples and compressed
+    /// candidates held at any one time. Like the time budget, trees get shallower and samples fewer
+    /// as it runs out.
+    pub fn with_memory_budget(mut self, memory_budget: usize) -> Self {
+        self.memory_budget = Some(memory_budget);
+        self
+    }
+
+    pub fn memory_budget(&self) -> Option<usize> {
+        self.memory_budget
+    }
+
+    /// Add a rule constraining how the arrays it matches are compressed. All the rules that match
+    /// an array apply to it.
+    pub fn with_rule(mut self, rule: CompressionRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn rules(&self) -> &[CompressionRule] {
+        &self.rules
+    }
+
+    /// Rechunk chunked arrays into chunks of at most this many values before compressing them.
+    pub fn with_target_block_size(mut self, target_block_size: usize) -> Self {
+        self.target_block_size = target_block_size;
+        self
+    }
+
+    /// Rechunk chunked arrays into chunks of at most this many bytes before compressing them.
+    pub fn with_target_block_bytesize(mut self, target_block_bytesize: usize) -> Self {
+        self.target_block_bytesize = target_block_bytesize;
+        self
+    }
+
+    /// Compress each chunk of a chunked array like the chunks before it, without sampling it, as
+    /// long as its compression ratio is within `tolerance` times theirs.
+    pub fn with_chunk_ratio_tolerance(mut self, tolerance: f32) -> Self {
+        self.chunk_ratio_tolerance = Some(tolerance);
+        self
+    }
+
+    pub fn chunk_ratio_tolerance(&self) -> Option<f32> {
+        self.chunk_ratio_tolerance
+    }
+
+    /// Whether to pick among the float encodings, e.g. ALP and ALP-RD, from statistics of the
+    /// values such as their exponents and mantissas, rather than by sampling each of them. The
+    /// statistics are taken over rows sampled at random with [`vortex_array::compute::sample`]. Once
+    /// any float encoding is enabled, the one the statistics pick is used even if it isn't enabled
+    /// itself, and the encodings are only all sampled when the statistics are inconclusive.
+    pub fn with_float_profiling(mut self, profile_floats: bool) -> Self {
+        self.profile_floats = profile_floats;
+        self
+    }
+
+    pub fn float_profiling(&self) -> bool {
+        self.profile_floats
+    }
+}
@@ -0,0 +1,196 @@
+//! A report of the choices the sampling compressor made, to understand and tune how large the
+//! compressed arrays are.
+
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use vortex_array::aliases::hash_map::HashMap;
+use vortex_array::{ArrayDType, ArrayData};
+use vortex_dtype::DType;
+use vortex_error::vortex_panic;
+
+use crate::compressors::CompressedArray;
+
+/// How a candidate encoding fared on a sample of a column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateReport {
+    compressor: String,
+    ratio: f64,
+    objective: f64,
+}
+
+impl CandidateReport {
+    pub fn compressor(&self) -> &str {
+        &self.compressor
+    }
+
+    /// The size of the compressed sample relative to the uncompressed sample.
+    pub fn ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    /// The score the cost model gave the compressed sample, where lower is better.
+    pub fn objective(&self) -> f64 {
+        self.objective
+    }
+}
+
+/// How a column, i.e. a field of a struct or the whole array, was compressed.
+#[derive(Debug, Clone)]
+pub struct ColumnReport {
+    path: String,
+    dtype: DType,
+    uncompressed_nbytes: usize,
+    compressed_nbytes: usize,
+    tree: Option<String>,
+    candidates: Vec<CandidateReport>,
+    duration: Duration,
+}
+
+impl ColumnReport {
+    /// The names of the fields leading to the column separated by dots, which is empty for the
+    /// whole array.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    /// The estimated size of the column once canonicalized.
+    pub fn uncompressed_nbytes(&self) -> usize {
+        self.uncompressed_nbytes
+    }
+
+    /// The size of the buffers of the compressed column.
+    pub fn compressed_nbytes(&self) -> usize {
+        self.compressed_nbytes
+    }
+
+    /// The compression tree chosen for the column, or `None` if it was left uncompressed.
+    pub fn tree(&self) -> Option<&str> {
+        self.tree.as_deref()
+    }
+
+    /// Every candidate tried on a sample of the column or of the arrays compressed within it,
+    /// including the ones that were chosen.
+    pub fn candidates(&self) -> &[CandidateReport] {
+        &self.candidates
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// How each column of an array was compressed.
+#[derive(Debug, Clone, Default)]
+pub struct CompressionReport {
+    columns: Vec<ColumnReport>,
+}
+
+impl CompressionReport {
+    pub fn columns(&self) -> &[ColumnReport] {
+        &self.columns
+    }
+
+    pub fn column(&self, path: &str) -> Option<&ColumnReport> {
+        self.columns.iter().find(|column| column.path == path)
+    }
+}
+
+impl Display for CompressionReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for column in &self.columns {
+            writeln!(
+                f,
+                "{}: {} {} -> {} bytes in {:.3}s",
+                column.path,
+                column.dtype,
+                column.uncompressed_nbytes,
+                column.compressed_nbytes,
+                column.duration.as_secs_f64()
+            )?;
+            for candidate in &column.candidates {
+                writeln!(
+                    f,
+                    "  {}: ratio {:.3}, objective {:.3}",
+                    candidate.compressor, candidate.ratio, candidate.objective
+                )?;
+            }
+            match &column.tree {
+                Some(tree) => write!(f, "{tree}")?,
+                None => writeln!(f, "  uncompressed")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Collects the report of a call to compress, shared by all the contexts within it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ReportCollector {
+    inner: Arc<Mutex<Collected>>,
+}
+
+#[derive(Debug, Default)]
+struct Collected {
+    columns: Vec<ColumnReport>,
+    candidates: HashMap<String, Vec<CandidateReport>>,
+}
+
+impl ReportCollector {
+    fn with<R>(&self, f: impl FnOnce(&mut Collected) -> R) -> R {
+        let mut collected = self
+            .inner
+            .lock()
+            .unwrap_or_else(|poison| vortex_panic!("Failed to lock compression report: {poison}"));
+        f(&mut collected)
+    }
+
+    pub(crate) fn add_candidate(&self, column: &str, compressor: &str, ratio: f64, objective: f64) {
+        self.with(|collected| {
+            collected
+                .candidates
+                .entry(column.to_string())
+                .or_default()
+                .push(CandidateReport {
+                    compressor: compressor.to_string(),
+                    ratio,
+                    objective,
+                })
+        })
+    }
+
+    pub(crate) fn add_column(
+        &self,
+        path: String,
+        uncompressed: &ArrayData,
+        compressed: &CompressedArray,
+        duration: Duration,
+    ) {
+        self.with(|collected| {
+            let candidates = collected.candidates.remove(&path).unwrap_or_default();
+            collected.columns.push(ColumnReport {
+                path,
+                dtype: uncompressed.dtype().clone(),
+                uncompressed_nbytes: uncompressed.estimated_canonical_size(),
+                compressed_nbytes: compressed.array().compressed_size(),
+                tree: compressed.path().as_ref().map(ToString::to_string),
+                candidates,
+                duration,
+            })
+        })
+    }
+
+    /// The report of the columns compressed so far, sorted by their paths.
+    pub(crate) fn finish(&self) -> CompressionReport {
+        self.with(|collected| {
+            let mut columns = collected.columns.clone();
+            columns.sort_by(|a, b| a.path.cmp(&b.path));
+            CompressionReport { columns }
+        })
+    }
+}
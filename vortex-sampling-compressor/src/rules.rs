@@ -0,0 +1,191 @@
+//! Rules that encode domain knowledge about which encodings suit which columns, applied before
+//! sampling the arrays they match.
+
+use vortex_array::aliases::hash_set::HashSet;
+use vortex_dtype::DType;
+
+/// The dtypes of the arrays a [CompressionRule] applies to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DTypePattern {
+    #[default]
+    Any,
+    /// Exactly the given dtype, ignoring nullability.
+    Exact(DType),
+    Bool,
+    Int,
+    Float,
+    Utf8,
+    Binary,
+    Struct,
+    List,
+    Extension,
+}
+
+impl DTypePattern {
+    pub fn matches(&self, dtype: &DType) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Exact(expected) => expected.eq_ignore_nullability(dtype),
+            Self::Bool => dtype.is_boolean(),
+            Self::Int => dtype.is_int(),
+            Self::Float => dtype.is_float(),
+            Self::Utf8 => matches!(dtype, DType::Utf8(_)),
+            Self::Binary => matches!(dtype, DType::Binary(_)),
+            Self::Struct => dtype.is_struct(),
+            Self::List => matches!(dtype, DType::List(..)),
+            Self::Extension => matches!(dtype, DType::Extension(_)),
+        }
+    }
+}
+
+/// Constrains how the arrays of some columns are compressed.
+///
+/// A rule applies to every array of a matching dtype within a struct field of a matching name,
+/// including the arrays an encoding produces while compressing the column, e.g. the values of a
+/// dictionary. Compressors are referred to by their ids.
+#[derive(Debug, Clone, Default)]
+pub struct CompressionRule {
+    dtype: DTypePattern,
+    field_name: Option<String>,
+    required: Option<HashSet<String>>,
+    forbidden: HashSet<String>,
+    max_cost: Option<u8>,
+    sample_size: Option<u16>,
+    sample_count: Option<u16>,
+}
+
+impl CompressionRule {
+    /// A rule for the arrays of the given dtypes, in any column.
+    pub fn new(dtype: DTypePattern) -> Self {
+        Self {
+            dtype,
+            ..Default::default()
+        }
+    }
+
+    /// Only apply the rule within struct fields whose name matches the pattern, in which `*`
+    /// matches any run of characters, e.g. `*_id`.
+    pub fn for_field(mut self, pattern: &str) -> Self {
+        self.field_name = Some(pattern.to_string());
+        self
+    }
+
+    /// Only try the given compressors.
+    pub fn require(mut self, compressor_ids: &[&str]) -> Self {
+        self.required
+            .get_or_insert_with(HashSet::new)
+            .extend(compressor_ids.iter().map(|id| id.to_string()));
+        self
+    }
+
+    /// Never try the given compressors.
+    pub fn forbid(mut self, compressor_ids: &[&str]) -> Self {
+        self.forbidden
+            .extend(compressor_ids.iter().map(|id| id.to_string()));
+        self
+    }
+
+    pub fn with_max_cost(mut self, max_cost: u8) -> Self {
+        self.max_cost = Some(max_cost);
+        self
+    }
+
+    pub fn with_sample_size(mut self, sample_size: u16) -> Self {
+        self.sample_size = Some(sample_size);
+        self
+    }
+
+    pub fn with_sample_count(mut self, sample_count: u16) -> Self {
+        self.sample_count = Some(sample_count);
+        self
+    }
+
+    pub fn max_cost(&self) -> Option<u8> {
+        self.max_cost
+    }
+
+    pub fn sample_size(&self) -> Option<u16> {
+        self.sample_size
+    }
+
+    pub fn sample_count(&self) -> Option<u16> {
+        self.sample_count
+    }
+
+    /// Whether the rule applies to an array of the given dtype in the given struct field.
+    pub fn matches(&self, dtype: &DType, field_name: Option<&str>) -> bool {
+        self.dtype.matches(dtype)
+            && self.field_name.as_deref().map_or(true, |pattern| {
+                field_name.is_some_and(|name| glob_matches(pattern, name))
+            })
+    }
+
+    /// Whether the rule lets the compressor with the given id be tried.
+    pub fn allows(&self, compressor_id: &str) -> bool {
+        !self.forbidden.contains(compressor_id)
+            && self
+                .required
+                .as_ref()
+                .map_or(true, |required| required.contains(compressor_id))
+    }
+}
+
+/// Match `name` against `pattern`, in which `*` matches any run of characters.
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts = parts.collect::<Vec<_>>();
+    let Some(last) = parts.pop() else {
+        // No wildcards, so the whole name must match.
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::rules::{glob_matches, CompressionRule, DTypePattern};
+
+    #[test]
+    fn globs() {
+        assert!(glob_matches("*_id", "user_id"));
+        assert!(glob_matches("*_id", "_id"));
+        assert!(!glob_matches("*_id", "user_ids"));
+        assert!(glob_matches("user_*", "user_id"));
+        assert!(glob_matches("*ser*", "user_id"));
+        assert!(glob_matches("a*b*c", "abc"));
+        assert!(!glob_matches("a*b*c", "acb"));
+        assert!(glob_matches("name", "name"));
+        assert!(!glob_matches("name", "names"));
+        assert!(glob_matches("*", ""));
+    }
+
+    #[test]
+    fn rule_matches() {
+        let rule = CompressionRule::new(DTypePattern::Int)
+            .for_field("*_id")
+            .forbid(&["vortex.dict"]);
+        let int = DType::Primitive(PType::I64, Nullability::Nullable);
+        assert!(rule.matches(&int, Some("user_id")));
+        assert!(!rule.matches(&int, Some("user")));
+        assert!(!rule.matches(&int, None));
+        assert!(!rule.matches(&DType::Utf8(Nullability::Nullable), Some("user_id")));
+        assert!(!rule.allows("vortex.dict"));
+        assert!(rule.allows("fastlanes.bitpacked"));
+
+        let rule = CompressionRule::new(DTypePattern::Utf8).require(&["vortex.fsst"]);
+        assert!(rule.allows("vortex.fsst"));
+        assert!(!rule.allows("vortex.dict"));
+    }
+}
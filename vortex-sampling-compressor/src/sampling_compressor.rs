@@ -1,5 +1,7 @@
 use core::fmt::Formatter;
 use std::fmt::Display;
+use std::sync::Arc;
+use std::time::Instant;
 
 use rand::rngs::StdRng;
 use rand::SeedableRng as _;
@@ -9,19 +11,29 @@ use vortex_array::compress::{
     check_dtype_unchanged, check_statistics_unchanged, check_validity_unchanged,
     CompressionStrategy,
 };
-use vortex_array::compute::slice;
+use vortex_array::compute::{sample, slice, SampleSize};
 use vortex_array::encoding::{Encoding, EncodingRef};
+use vortex_array::memory::{MemoryPool, MemoryReservation, UnboundedMemoryPool};
 use vortex_array::patches::Patches;
 use vortex_array::validity::Validity;
 use vortex_array::{ArrayDType, ArrayData, IntoCanonical};
+use vortex_dtype::FieldName;
 use vortex_error::{VortexExpect as _, VortexResult};
 
 use super::compressors::chunked::DEFAULT_CHUNKED_COMPRESSOR;
 use super::compressors::struct_::StructCompressor;
-use super::{CompressConfig, Objective, DEFAULT_COMPRESSORS};
+use super::{
+    registered_compressors, CompressConfig, CompressionLevel, ALL_COMPRESSORS, DEFAULT_COMPRESSORS,
+};
+use crate::compressors::bitpacked::BITPACK_NO_PATCHES;
 use crate::compressors::constant::ConstantCompressor;
-use crate::compressors::{CompressedArray, CompressionTree, CompressorRef, EncodingCompressor};
+use crate::compressors::list::ListCompressor;
+use crate::compressors::{
+    deserialize_tree, CompressedArray, CompressionTree, CompressorRef, EncodingCompressor,
+};
 use crate::downscale::downscale_integer_array;
+use crate::float_profile::{choose_float_candidates, is_float_encoding, FLOAT_COMPRESSORS};
+use crate::report::{CompressionReport, ReportCollector};
 use crate::sampling::stratified_slices;
 
 #[derive(Debug, Clone)]
@@ -33,6 +45,16 @@ pub struct SamplingCompressor<'a> {
     depth: u8,
     /// A set of encodings disabled for this ctx.
     disabled_compressors: HashSet<CompressorRef<'a>>,
+    /// When the current call to compress started, against which its time budget is measured.
+    started: Option<Instant>,
+    /// The bytes held by the arrays, samples and compressed candidates of the current call to
+    /// compress, against which its memory budget is measured.
+    memory: Option<Arc<dyn MemoryPool>>,
+    /// The struct field being compressed, which selects the rules that apply.
+    field: Option<FieldName>,
+    /// The path of the column being compressed, and where to report on it.
+    column: Option<String>,
+    report: Option<ReportCollector>,
 }
 
 impl Display for SamplingCompressor<'_> {
@@ -48,8 +70,16 @@ impl CompressionStrategy for SamplingCompressor<'_> {
     }
 
     fn used_encodings(&self) -> HashSet<EncodingRef> {
+        // Profiling floats may choose any of the float encodings once one of them is enabled.
+        let floats = (self.options.float_profiling()
+            && self.compressors.iter().any(|c| is_float_encoding(c.id())))
+        .then_some(FLOAT_COMPRESSORS)
+        .into_iter()
+        .flatten();
         self.compressors
             .iter()
+            .copied()
+            .chain(floats)
             .flat_map(|c| c.used_encodings())
             .collect()
     }
@@ -57,7 +87,11 @@ impl CompressionStrategy for SamplingCompressor<'_> {
 
 impl Default for SamplingCompressor<'_> {
     fn default() -> Self {
-        Self::new(HashSet::from_iter(DEFAULT_COMPRESSORS))
+        Self::new(HashSet::from_iter(
+            DEFAULT_COMPRESSORS
+                .into_iter()
+                .chain(registered_compressors()),
+        ))
     }
 }
 
@@ -76,15 +110,43 @@ impl<'a> SamplingCompressor<'a> {
             path: Vec::new(),
             depth: 0,
             disabled_compressors: HashSet::new(),
+            started: None,
+            memory: None,
+            field: None,
+            column: None,
+            report: None,
         }
     }
 
+    /// A compressor with the encodings and options of the given preset.
+    pub fn with_level(level: CompressionLevel) -> Self {
+        Self::new_with_options(
+            HashSet::from_iter(
+                level
+                    .compressors()
+                    .iter()
+                    .copied()
+                    .chain(registered_compressors()),
+            ),
+            CompressConfig::for_level(level),
+        )
+    }
+
     pub fn named(&self, name: &str) -> Self {
         let mut cloned = self.clone();
         cloned.path.push(name.into());
         cloned
     }
 
+    /// Returns a new ctx for compressing the given field of a struct, to which the rules for fields
+    /// of that name apply.
+    pub fn for_field(&self, name: &FieldName) -> Self {
+        let mut cloned = self.named(name);
+        cloned.field = Some(name.clone());
+        cloned.column = Some(cloned.path.join("."));
+        cloned
+    }
+
     // Returns a new ctx used for compressing an auxiliary array.
     // In practice, this means resetting any disabled encodings back to the original config.
     pub fn auxiliary(&self, name: &str) -> Self {
@@ -96,7 +158,7 @@ impl<'a> SamplingCompressor<'a> {
 
     pub fn for_compressor(&self, compression: &dyn EncodingCompressor) -> Self {
         let mut cloned = self.clone();
-        cloned.depth += compression.cost();
+        cloned.depth += self.options.cost_model().cost(compression);
         cloned
     }
 
@@ -128,6 +190,28 @@ impl<'a> SamplingCompressor<'a> {
         self.compressors.contains(compressor) && !self.disabled_compressors.contains(compressor)
     }
 
+    /// Load a tree serialized with [CompressionTree::serialize], to pass as the `like` tree of
+    /// [compress][Self::compress] and skip sampling arrays similar to the one it was learned from.
+    pub fn deserialize_tree(&self, bytes: &[u8]) -> VortexResult<CompressionTree<'a>> {
+        deserialize_tree(bytes, self)
+    }
+
+    /// Find the compressor with the given id, whether or not it's enabled, since trees can be built
+    /// with compressors that are only enabled for some of their children.
+    pub(crate) fn compressor_by_id(&self, id: &str) -> Option<CompressorRef<'a>> {
+        self.compressors
+            .iter()
+            .copied()
+            .chain(ALL_COMPRESSORS.iter().map(|&c| c as CompressorRef<'a>))
+            .chain([&BITPACK_NO_PATCHES as CompressorRef])
+            .chain(
+                registered_compressors()
+                    .into_iter()
+                    .map(|c| c as CompressorRef<'a>),
+            )
+            .find(|c| c.id() == id)
+    }
+
     #[allow(clippy::same_name_method)]
     pub fn compress(
         &self,
@@ -138,6 +222,16 @@ impl<'a> SamplingCompressor<'a> {
             return Ok(CompressedArray::uncompressed(arr.clone()));
         }
 
+        // Start measuring the budgets, which all the arrays compressed within this call share.
+        if self.started.is_none() {
+            let mut budgeted = self.clone();
+            budgeted.started = Some(Instant::now());
+            if self.options.memory_budget().is_some() {
+                budgeted.memory = Some(Arc::new(UnboundedMemoryPool::default()));
+            }
+            return budgeted.compress(arr, like);
+        }
+
         // Attempt to compress using the "like" array, otherwise fall back to sampled compression
         if let Some(l) = like {
             if let Some(compressed) = l.compress(arr, self) {
@@ -161,6 +255,40 @@ impl<'a> SamplingCompressor<'a> {
         Ok(compressed)
     }
 
+    /// Compress the array like [compress][Self::compress], along with a report of how the whole
+    /// array and each of its struct fields were compressed.
+    pub fn compress_with_report(
+        &self,
+        arr: &ArrayData,
+        like: Option<&CompressionTree<'a>>,
+    ) -> VortexResult<(CompressedArray<'a>, CompressionReport)> {
+        let report = ReportCollector::default();
+        let mut reporting = self.clone();
+        reporting.report = Some(report.clone());
+        let compressed = reporting.compress_column(arr, like)?;
+        Ok((compressed, report.finish()))
+    }
+
+    /// Compress a column, reporting on it if a report is being collected.
+    pub(crate) fn compress_column(
+        &self,
+        arr: &ArrayData,
+        like: Option<&CompressionTree<'a>>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let Some(report) = &self.report else {
+            return self.compress(arr, like);
+        };
+        let start = Instant::now();
+        let compressed = self.compress(arr, like)?;
+        report.add_column(
+            self.column.clone().unwrap_or_default(),
+            arr,
+            &compressed,
+            start.elapsed(),
+        );
+        Ok(compressed)
+    }
+
     pub fn compress_validity(&self, validity: Validity) -> VortexResult<Validity> {
         match validity {
             Validity::Array(a) => Ok(Validity::Array(self.compress(&a, None)?.into_array())),
@@ -177,6 +305,46 @@ impl<'a> SamplingCompressor<'a> {
         ))
     }
 
+    /// Count `bytes` of arrays held while compressing towards the memory budget, until the returned
+    /// reservation is dropped.
+    fn hold(&self, bytes: usize) -> Option<MemoryReservation> {
+        self.memory.as_ref().map(|pool| {
+            let mut reservation = MemoryReservation::new(pool.clone());
+            reservation.resize(bytes);
+            reservation
+        })
+    }
+
+    /// How much of the time or memory budget has been used, from 0 when nothing has been used to 1
+    /// or more once a budget is exhausted.
+    fn budget_used(&self) -> f64 {
+        let time = self
+            .options
+            .time_budget()
+            .zip(self.started)
+            .map(|(budget, started)| {
+                if budget.is_zero() {
+                    1.0
+                } else {
+                    started.elapsed().as_secs_f64() / budget.as_secs_f64()
+                }
+            })
+            .unwrap_or_default();
+        let memory = self
+            .options
+            .memory_budget()
+            .zip(self.memory.as_ref())
+            .map(|(budget, pool)| pool.reserved() as f64 / budget.max(1) as f64)
+            .unwrap_or_default();
+        time.max(memory)
+    }
+
+    /// The fraction of the configured tree depth and samples to use, which shrinks linearly from
+    /// 1 once half of a budget has been used to 0 once it is exhausted.
+    fn budget_headroom(&self) -> f64 {
+        (2.0 * (1.0 - self.budget_used())).clamp(0.0, 1.0)
+    }
+
     pub(crate) fn compress_array(&self, array: &ArrayData) -> VortexResult<CompressedArray<'a>> {
         let mut rng = StdRng::seed_from_u64(self.options.rng_seed);
 
@@ -193,20 +361,77 @@ impl<'a> SamplingCompressor<'a> {
             return cc.compress(array, None, self.clone());
         }
 
+        // The rules for arrays of this dtype in this field constrain which encodings we try.
+        let rules = self
+            .options
+            .rules()
+            .iter()
+            .filter(|rule| rule.matches(array.dtype(), self.field.as_deref()))
+            .collect::<Vec<_>>();
+        let allowed =
+            |encoding: &dyn EncodingCompressor| rules.iter().all(|rule| rule.allows(encoding.id()));
+
         // short-circuit because seriously nothing beats constant
-        if self.is_enabled(&ConstantCompressor) && ConstantCompressor.can_compress(array).is_some()
+        if self.is_enabled(&ConstantCompressor)
+            && allowed(&ConstantCompressor)
+            && ConstantCompressor.can_compress(array).is_some()
         {
             return ConstantCompressor.compress(array, None, self.clone());
         }
 
+        // Like structs, lists only need their children compressed, unless the list compressor is
+        // disabled or ruled out for them.
+        if self.is_enabled(&ListCompressor)
+            && allowed(&ListCompressor)
+            && ListCompressor.can_compress(array).is_some()
+        {
+            return ListCompressor.compress(array, None, self.clone());
+        }
+
+        let _array_held = self.hold(array.nbytes());
+
+        // As the budgets run out, try shallower trees and take fewer samples, until only the
+        // encodings that don't deepen the tree are left.
+        let headroom = self.budget_headroom();
+        if headroom < 1.0 {
+            log::debug!("{} with {:.2} of the budget left", self, headroom);
+        }
+        let max_cost = rules
+            .iter()
+            .filter_map(|rule| rule.max_cost())
+            .fold(self.options.max_cost, u8::min);
+        // The headroom is between 0 and 1, so neither the cost nor the count can grow.
+        #[allow(clippy::cast_possible_truncation)]
+        let max_cost =
+            self.depth + (f64::from(max_cost.saturating_sub(self.depth)) * headroom).floor() as u8;
+        let sample_size = rules
+            .iter()
+            .rev()
+            .find_map(|rule| rule.sample_size())
+            .unwrap_or(self.options.sample_size);
+        let sample_count = rules
+            .iter()
+            .rev()
+            .find_map(|rule| rule.sample_count())
+            .unwrap_or(self.options.sample_count);
+        #[allow(clippy::cast_possible_truncation)]
+        let sample_count = ((f64::from(sample_count) * headroom).ceil() as u16).max(1);
+
+        let usable = |encoding: &dyn EncodingCompressor| {
+            !self.disabled_compressors.contains(encoding)
+                && allowed(encoding)
+                && encoding.can_compress(array).is_some()
+        };
+        let shallow_enough = |encoding: &dyn EncodingCompressor| {
+            self.depth + self.options.cost_model().cost(encoding) <= max_cost
+        };
+
         let (mut candidates, too_deep) = self
             .compressors
             .iter()
-            .filter(|&encoding| !self.disabled_compressors.contains(encoding))
-            .filter(|&encoding| encoding.can_compress(array).is_some())
-            .partition::<Vec<&dyn EncodingCompressor>, _>(|&encoding| {
-                self.depth + encoding.cost() <= self.options.max_cost
-            });
+            .copied()
+            .filter(|&encoding| usable(encoding))
+            .partition::<Vec<&dyn EncodingCompressor>, _>(|&encoding| shallow_enough(encoding));
 
         if !too_deep.is_empty() {
             log::debug!(
@@ -243,26 +468,39 @@ impl<'a> SamplingCompressor<'a> {
             candidates.retain(|&compression| compression.id() != array.encoding().id().as_ref());
         }
 
-        if array.len() <= (self.options.sample_size as usize * self.options.sample_count as usize) {
+        if self.options.float_profiling()
+            && candidates
+                .iter()
+                .any(|candidate| is_float_encoding(candidate.id()))
+        {
+            // Profile rows drawn from the whole array rather than the contiguous slices that the
+            // encodings are sampled on, which tell more about how the values are distributed.
+            let rows = sample(
+                array,
+                SampleSize::Count(sample_size as usize * sample_count as usize),
+                self.options.rng_seed,
+            )?;
+            choose_float_candidates(&mut candidates, &rows, |encoding| {
+                usable(encoding) && shallow_enough(encoding)
+            });
+        }
+
+        if array.len() <= (sample_size as usize * sample_count as usize) {
             // We're either already within a sample, or we're operating over a sufficiently small array.
             return find_best_compression(candidates, array, self);
         }
 
         // Take a sample of the array, then ask codecs for their best compression estimate.
-        let sample = ChunkedArray::try_new(
-            stratified_slices(
-                array.len(),
-                self.options.sample_size,
-                self.options.sample_count,
-                &mut rng,
-            )
-            .into_iter()
-            .map(|(start, stop)| slice(array, start, stop))
-            .collect::<VortexResult<Vec<ArrayData>>>()?,
+        let sample: ArrayData = ChunkedArray::try_new(
+            stratified_slices(array.len(), sample_size, sample_count, &mut rng)
+                .into_iter()
+                .map(|(start, stop)| slice(array, start, stop))
+                .collect::<VortexResult<Vec<ArrayData>>>()?,
             array.dtype().clone(),
         )?
         .into_canonical()?
         .into();
+        let _sample_held = self.hold(sample.nbytes());
 
         let best = find_best_compression(candidates, &sample, self)?
             .into_path()
@@ -287,7 +525,12 @@ pub(crate) fn find_best_compression<'a>(
     ctx: &SamplingCompressor<'a>,
 ) -> VortexResult<CompressedArray<'a>> {
     let mut best = None;
-    let mut best_objective = ctx.options().objective.starting_value();
+    let mut best_held = ctx.hold(0);
+    let cost_model = ctx.options().cost_model();
+    let mut best_objective = cost_model.evaluate(
+        &CompressedArray::uncompressed(sample.clone()),
+        sample.nbytes(),
+    );
     let mut best_objective_ratio = 1.0;
     // for logging
     let mut best_compression_ratio = 1.0;
@@ -307,7 +550,15 @@ pub(crate) fn find_best_compression<'a>(
             compression.compress(sample, None, ctx.for_compressor(compression))?;
 
         let ratio = (compressed_sample.nbytes() as f64) / (sample.nbytes() as f64);
-        let objective = Objective::evaluate(&compressed_sample, sample.nbytes(), ctx.options());
+        let objective = cost_model.evaluate(&compressed_sample, sample.nbytes());
+        if let Some(report) = &ctx.report {
+            report.add_candidate(
+                ctx.column.as_deref().unwrap_or_default(),
+                compression.id(),
+                ratio,
+                objective,
+            );
+        }
 
         // track the compression ratio, just for logging
         if ratio < best_compression_ratio {
@@ -324,6 +575,9 @@ pub(crate) fn find_best_compression<'a>(
         if objective < best_objective && ratio < 1.0 {
             best_objective = objective;
             best_objective_ratio = ratio;
+            if let Some(held) = &mut best_held {
+                held.resize(compressed_sample.nbytes());
+            }
             best = Some(compressed_sample);
         }
 
@@ -0,0 +1,137 @@
+//! Compress the chunks of an array as they arrive, rather than collecting them into a chunked
+//! array first.
+
+#[cfg(feature = "rayon")]
+use futures::channel::oneshot;
+#[cfg(feature = "rayon")]
+use futures_util::stream;
+use futures_util::{future, StreamExt as _};
+use vortex_array::stream::{ArrayStream, ArrayStreamAdapter};
+use vortex_array::ArrayData;
+#[cfg(feature = "rayon")]
+use vortex_error::vortex_err;
+use vortex_error::VortexResult;
+
+use crate::compressors::chunked::DEFAULT_CHUNKED_COMPRESSOR;
+use crate::compressors::{CompressedArray, CompressionTree};
+use crate::SamplingCompressor;
+
+/// Compresses a sequence of chunks one at a time, the way the chunks of a chunked array are
+/// compressed.
+///
+/// Each chunk reuses the tree of the chunks before it for as long as that tree compresses it
+/// well, and is only sampled again when it does not.
+///
+/// Only the chunk being compressed is held in memory. With the `rayon` feature, chunks can be
+/// compressed on the rayon thread pool with `compress_stream_on_pool`.
+#[derive(Debug, Clone)]
+pub struct StreamingCompressor<'a> {
+    ctx: SamplingCompressor<'a>,
+    previous: Option<(CompressionTree<'a>, f32)>,
+    nchunks: usize,
+}
+
+impl<'a> StreamingCompressor<'a> {
+    pub fn new(ctx: SamplingCompressor<'a>) -> Self {
+        Self {
+            ctx,
+            previous: None,
+            nchunks: 0,
+        }
+    }
+
+    /// Start from the tree of an earlier compression, e.g. of the previous file, rather than
+    /// sampling the first chunk.
+    pub fn with_tree(mut self, tree: CompressionTree<'a>, ratio: f32) -> Self {
+        self.previous = Some((tree, ratio));
+        self
+    }
+
+    /// The tree later chunks are compressed like, if any chunk has been compressed.
+    pub fn tree(&self) -> Option<&CompressionTree<'a>> {
+        self.previous.as_ref().map(|(tree, _)| tree)
+    }
+
+    /// The number of chunks compressed so far.
+    pub fn nchunks(&self) -> usize {
+        self.nchunks
+    }
+
+    /// Compress the next chunk of the sequence.
+    pub fn compress_chunk(&mut self, chunk: &ArrayData) -> VortexResult<CompressedArray<'a>> {
+        let ctx = self.ctx.named(&format!("chunk-{}", self.nchunks));
+        let compressed =
+            DEFAULT_CHUNKED_COMPRESSOR.compress_chunk(chunk, &mut self.previous, &ctx)?;
+        self.nchunks += 1;
+        Ok(compressed)
+    }
+
+    /// Compress the chunks of the stream as they are polled, on the thread polling the stream.
+    ///
+    /// An error, whether from the stream or from compressing a chunk, is yielded in place of the
+    /// chunk it came from.
+    pub fn compress_stream<S>(self, stream: S) -> impl ArrayStream + 'a
+    where
+        S: ArrayStream + 'a,
+    {
+        let dtype = stream.dtype().clone();
+        ArrayStreamAdapter::new(
+            dtype,
+            stream.scan(self, |compressor, chunk| {
+                future::ready(Some(chunk.and_then(|chunk| {
+                    compressor
+                        .compress_chunk(&chunk)
+                        .map(CompressedArray::into_array)
+                })))
+            }),
+        )
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl StreamingCompressor<'static> {
+    /// Compress the chunks of the stream on the rayon thread pool, so that polling the compressed
+    /// stream doesn't block the executor.
+    ///
+    /// Chunks are still compressed one at a time, since each may reuse the tree of the one before
+    /// it, but the next chunk is read from the stream while the current one is compressed. At most
+    /// those two chunks are held in memory.
+    pub fn compress_stream_on_pool<S>(self, stream: S) -> impl ArrayStream + 'static
+    where
+        S: ArrayStream + Send + 'static,
+    {
+        let dtype = stream.dtype().clone();
+        let chunks = stream::unfold(
+            (Some(self), Box::pin(stream).fuse(), None),
+            |(compressor, mut chunks, next)| async move {
+                let compressor = compressor?;
+                let chunk = match next {
+                    Some(chunk) => chunk,
+                    None => chunks.next().await?,
+                };
+                let ((compressor, compressed), next) =
+                    future::join(compressor.compress_on_pool(chunk), chunks.next()).await;
+                Some((compressed, (compressor, chunks, next)))
+            },
+        );
+        ArrayStreamAdapter::new(dtype, chunks)
+    }
+
+    /// Compress a chunk on the rayon thread pool, handing the compressor back along with it.
+    async fn compress_on_pool(
+        mut self,
+        chunk: VortexResult<ArrayData>,
+    ) -> (Option<Self>, VortexResult<ArrayData>) {
+        let (sender, receiver) = oneshot::channel();
+        rayon::spawn(move || {
+            let compressed = chunk
+                .and_then(|chunk| self.compress_chunk(&chunk).map(CompressedArray::into_array));
+            // The receiver is only gone if the compressed stream was dropped.
+            let _ = sender.send((self, compressed));
+        });
+        match receiver.await {
+            Ok((compressor, compressed)) => (Some(compressor), compressed),
+            Err(_) => (None, Err(vortex_err!("Compressing a chunk panicked"))),
+        }
+    }
+}
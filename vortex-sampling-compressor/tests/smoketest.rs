@@ -1,4 +1,5 @@
 use std::ops::Add;
+use std::time::Duration;
 
 use chrono::TimeDelta;
 use vortex_array::aliases::hash_set::HashSet;
@@ -7,20 +8,41 @@ use vortex_array::array::{BoolArray, PrimitiveArray, StructArray, TemporalArray}
 use vortex_array::validity::Validity;
 use vortex_array::{ArrayDType, ArrayData, IntoArrayData};
 use vortex_dtype::{DType, FieldName, FieldNames, Nullability};
-use vortex_sampling_compressor::{CompressConfig, SamplingCompressor};
+use vortex_sampling_compressor::{CompressConfig, CompressionLevel, SamplingCompressor};
 
 #[cfg(test)]
 mod tests {
-    use vortex_array::array::{BoolEncoding, BooleanBuffer, ChunkedArray, VarBinEncoding};
-    use vortex_array::encoding::Encoding;
+    use futures_executor::block_on;
+    use futures_util::{stream, StreamExt as _};
+    use vortex_alp::{ALPEncoding, ALPRDEncoding};
+    use vortex_array::accessor::ArrayAccessor;
+    use vortex_array::array::{
+        BoolEncoding, BoolMetadata, BooleanBuffer, ChunkedArray, ListArray, ListEncoding,
+        PrimitiveEncoding, PrimitiveMetadata,
+    };
+    use vortex_array::compute::scalar_at;
+    use vortex_array::encoding::{Encoding, EncodingId, EncodingRef};
     use vortex_array::stats::{ArrayStatistics, Stat};
+    use vortex_array::stream::{ArrayStream, ArrayStreamAdapter};
     use vortex_array::variants::StructArrayTrait;
+    use vortex_array::IntoArrayVariant;
     use vortex_datetime_dtype::TimeUnit;
     use vortex_datetime_parts::DateTimePartsEncoding;
-    use vortex_dict::DictEncoding;
+    use vortex_dict::{DictArray, DictEncoding};
+    use vortex_error::VortexResult;
     use vortex_fastlanes::BitPackedEncoding;
     use vortex_fsst::FSSTEncoding;
-    use vortex_sampling_compressor::ALL_COMPRESSORS;
+    use vortex_sampling_compressor::compressors::bitpacked::BITPACK_WITH_PATCHES;
+    use vortex_sampling_compressor::compressors::dict::DEFAULT_PARTIAL_DICT_COMPRESSOR;
+    use vortex_sampling_compressor::compressors::list::ListCompressor;
+    use vortex_sampling_compressor::compressors::{
+        CompressedArray, CompressionTree, CompressorRef, EncodingCompressor,
+    };
+    use vortex_sampling_compressor::rules::{CompressionRule, DTypePattern};
+    use vortex_sampling_compressor::stream::StreamingCompressor;
+    use vortex_sampling_compressor::{
+        register_compressor, registered_compressors, ALL_COMPRESSORS, DEFAULT_COMPRESSORS,
+    };
     use vortex_scalar::Scalar;
 
     use super::*;
@@ -63,6 +85,455 @@ mod tests {
         assert_eq!(compressed.dtype(), to_compress.dtype());
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn smoketest_compression_levels() {
+        let to_compress = StructArray::try_new(
+            vec!["prim_col".into(), "varbin_col".into()].into(),
+            vec![make_primitive_column(65536), make_string_column(65536)],
+            65536,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        for (name, level) in [
+            ("fast", CompressionLevel::Fast),
+            ("default", CompressionLevel::Default),
+            ("best", CompressionLevel::Best),
+        ] {
+            let compressed = SamplingCompressor::with_level(level)
+                .compress(&to_compress, None)
+                .unwrap()
+                .into_array();
+            assert_eq!(compressed.dtype(), to_compress.dtype());
+            assert_eq!(compressed.len(), to_compress.len());
+            assert!(
+                compressed.nbytes() < to_compress.nbytes(),
+                "{name} level didn't compress: {}",
+                compressed.tree_display()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn smoketest_compression_budgets() {
+        let floats = PrimitiveArray::from(
+            (0..65536u32)
+                .map(|i| f64::from(i) / 100.0)
+                .collect::<Vec<_>>(),
+        )
+        .into_array();
+
+        let unbounded = SamplingCompressor::default()
+            .compress(&floats, None)
+            .unwrap()
+            .into_array();
+        assert_eq!(unbounded.encoding().id(), ALPEncoding::ID);
+
+        for options in [
+            CompressConfig::default().with_time_budget(Duration::ZERO),
+            CompressConfig::default().with_memory_budget(1 << 10),
+        ] {
+            let compressed = SamplingCompressor::new_with_options(
+                HashSet::from_iter(DEFAULT_COMPRESSORS),
+                options,
+            )
+            .compress(&floats, None)
+            .unwrap()
+            .into_array();
+            assert_eq!(compressed.dtype(), floats.dtype());
+            assert_ne!(compressed.encoding().id(), ALPEncoding::ID);
+        }
+
+        // With part of the budget left, trees are shallower but still deep enough for ALP.
+        let compressed = SamplingCompressor::new_with_options(
+            HashSet::from_iter(DEFAULT_COMPRESSORS),
+            CompressConfig::default().with_memory_budget(floats.nbytes() * 3 / 2),
+        )
+        .compress(&floats, None)
+        .unwrap()
+        .into_array();
+        assert_eq!(compressed.encoding().id(), ALPEncoding::ID);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn smoketest_float_profiling() {
+        let decimals = PrimitiveArray::from(
+            (0..65536u32)
+                .map(|i| f64::from(i) / 100.0)
+                .collect::<Vec<_>>(),
+        )
+        .into_array();
+        let doubles = PrimitiveArray::from(
+            (1..65536u32)
+                .map(|i| 1.0 + f64::from(i).sqrt() / 100.0)
+                .collect::<Vec<_>>(),
+        )
+        .into_array();
+
+        // The encoding chosen for the floats, and the float encodings that were sampled.
+        let compress = |floats: &ArrayData, options: CompressConfig| {
+            let (compressed, report) = SamplingCompressor::new_with_options(
+                HashSet::from_iter(DEFAULT_COMPRESSORS),
+                options,
+            )
+            .compress_with_report(floats, None)
+            .unwrap();
+            let sampled = report
+                .column("")
+                .unwrap()
+                .candidates()
+                .iter()
+                .map(|candidate| candidate.compressor().to_string())
+                .filter(|id| {
+                    [ALPEncoding::ID.as_ref(), ALPRDEncoding::ID.as_ref()].contains(&id.as_str())
+                })
+                .collect::<HashSet<_>>();
+            (compressed.into_array().encoding().id(), sampled)
+        };
+        let only = |id: EncodingId| HashSet::from_iter([id.as_ref().to_string()]);
+
+        // The decimals only need ALP sampled.
+        assert_eq!(
+            compress(&decimals, CompressConfig::default()),
+            (ALPEncoding::ID, only(ALPEncoding::ID))
+        );
+        // ALP-RD isn't a default compressor, but the profile picks it for the doubles.
+        assert_eq!(
+            compress(&doubles, CompressConfig::default()),
+            (ALPRDEncoding::ID, only(ALPRDEncoding::ID))
+        );
+        // Without the profile, only the enabled ALP is tried, though it may use ALP-RD for its
+        // exceptions.
+        assert_ne!(
+            compress(
+                &doubles,
+                CompressConfig::default().with_float_profiling(false)
+            )
+            .0,
+            ALPRDEncoding::ID
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn smoketest_partial_dictionary() {
+        // Most values are one of a few common strings, the rest are unique.
+        let strings = (0..1 << 14)
+            .map(|i| {
+                if i % 4 == 0 {
+                    format!("rare-{i}")
+                } else {
+                    format!("common-{}", i % 8)
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut builder = VarBinBuilder::<i64>::with_capacity(strings.len());
+        for string in &strings {
+            builder.push_value(string.as_bytes());
+        }
+        let array = builder
+            .finish(DType::Utf8(Nullability::NonNullable))
+            .into_array();
+
+        let compressor = SamplingCompressor::new(HashSet::from_iter([
+            &DEFAULT_PARTIAL_DICT_COMPRESSOR as CompressorRef,
+            &BITPACK_WITH_PATCHES,
+        ]));
+        let compressed = compressor.compress(&array, None).unwrap().into_array();
+        assert_eq!(compressed.encoding().id(), DictEncoding::ID);
+        assert!(DictArray::try_from(compressed.clone())
+            .unwrap()
+            .patches()
+            .is_some());
+        assert!(compressed.nbytes() < array.nbytes());
+
+        compressed
+            .into_varbinview()
+            .unwrap()
+            .with_iterator(|iter| {
+                assert!(iter
+                    .map(|value| value.unwrap())
+                    .eq(strings.iter().map(String::as_bytes)));
+            })
+            .unwrap();
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn smoketest_list_compression() {
+        let nlists = 1u32 << 12;
+        let elements = PrimitiveArray::from((0..nlists * 3).map(|i| i % 100).collect::<Vec<_>>());
+        let offsets =
+            PrimitiveArray::from((0..=nlists).map(|i| u64::from(i * 3)).collect::<Vec<_>>());
+        let lists = ListArray::try_new(
+            elements.into_array(),
+            offsets.into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let compressed = SamplingCompressor::default()
+            .compress(&lists, None)
+            .unwrap()
+            .into_array();
+        assert_eq!(compressed.encoding().id(), ListEncoding::ID);
+        assert!(compressed.nbytes() < lists.nbytes());
+
+        let compressed_lists = ListArray::try_from(compressed.clone()).unwrap();
+        assert_ne!(
+            compressed_lists.offsets().encoding().id(),
+            PrimitiveEncoding::ID
+        );
+        assert_ne!(
+            compressed_lists.elements().encoding().id(),
+            PrimitiveEncoding::ID
+        );
+        for index in [0, 1, 1000, nlists as usize - 1] {
+            assert_eq!(
+                scalar_at(&compressed, index).unwrap(),
+                scalar_at(&lists, index).unwrap()
+            );
+        }
+
+        // Lists are left as they are when the list compressor is disabled.
+        let uncompressed = SamplingCompressor::default()
+            .excluding(&ListCompressor)
+            .compress(&lists, None)
+            .unwrap()
+            .into_array();
+        assert_eq!(uncompressed.nbytes(), lists.nbytes());
+    }
+
+    #[derive(Debug)]
+    struct PassThroughCompressor;
+
+    impl EncodingCompressor for PassThroughCompressor {
+        fn id(&self) -> &str {
+            "test.pass_through"
+        }
+
+        fn cost(&self) -> u8 {
+            0
+        }
+
+        fn can_compress(&self, _array: &ArrayData) -> Option<&dyn EncodingCompressor> {
+            None
+        }
+
+        fn compress<'a>(
+            &'a self,
+            array: &ArrayData,
+            _like: Option<CompressionTree<'a>>,
+            _ctx: SamplingCompressor<'a>,
+        ) -> VortexResult<CompressedArray<'a>> {
+            Ok(CompressedArray::uncompressed(array.clone()))
+        }
+
+        fn used_encodings(&self) -> HashSet<EncodingRef> {
+            HashSet::new()
+        }
+    }
+
+    #[test]
+    pub fn smoketest_registered_compressors() {
+        register_compressor(&PassThroughCompressor).unwrap();
+        assert!(SamplingCompressor::default().is_enabled(&PassThroughCompressor));
+        assert!(SamplingCompressor::with_level(CompressionLevel::Fast)
+            .is_enabled(&PassThroughCompressor));
+        assert!(registered_compressors()
+            .iter()
+            .any(|c| c.id() == PassThroughCompressor.id()));
+
+        // Built-in compressors can't be replaced.
+        assert!(register_compressor(&DEFAULT_PARTIAL_DICT_COMPRESSOR).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn smoketest_compression_rules() {
+        let floats = || {
+            PrimitiveArray::from(
+                (0..65536u32)
+                    .map(|i| f64::from(i) / 100.0)
+                    .collect::<Vec<_>>(),
+            )
+            .into_array()
+        };
+        let to_compress = StructArray::try_new(
+            vec!["price".into(), "tax_ratio".into()].into(),
+            vec![floats(), floats()],
+            65536,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let compressor = SamplingCompressor::new_with_options(
+            HashSet::from_iter(DEFAULT_COMPRESSORS),
+            CompressConfig::default().with_rule(
+                CompressionRule::new(DTypePattern::Float)
+                    .for_field("*_ratio")
+                    .forbid(&[ALPEncoding::ID.as_ref()]),
+            ),
+        );
+        let compressed: StructArray = compressor
+            .compress(&to_compress, None)
+            .unwrap()
+            .into_array()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            compressed.field_by_name("price").unwrap().encoding().id(),
+            ALPEncoding::ID
+        );
+        assert_ne!(
+            compressed
+                .field_by_name("tax_ratio")
+                .unwrap()
+                .encoding()
+                .id(),
+            ALPEncoding::ID
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn smoketest_compression_report() {
+        let to_compress = StructArray::try_new(
+            vec!["prim_col".into(), "varbin_col".into()].into(),
+            vec![make_primitive_column(65536), make_string_column(65536)],
+            65536,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let (compressed, report) = SamplingCompressor::default()
+            .compress_with_report(&to_compress, None)
+            .unwrap();
+        let display = report.to_string();
+        for column in ["prim_col", "varbin_col"] {
+            assert!(
+                display
+                    .lines()
+                    .any(|line| line.starts_with(&format!("{column}: "))),
+                "{column} missing from report:\n{display}"
+            );
+        }
+        assert!(
+            display.contains("ratio"),
+            "no candidates in report:\n{display}"
+        );
+
+        let paths = report
+            .columns()
+            .iter()
+            .map(|column| column.path())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec!["", "prim_col", "varbin_col"]);
+
+        let root = report.column("").unwrap();
+        assert_eq!(
+            root.compressed_nbytes(),
+            compressed.array().compressed_size()
+        );
+        assert_eq!(
+            root.uncompressed_nbytes(),
+            to_compress.estimated_canonical_size()
+        );
+
+        let prim_col = report.column("prim_col").unwrap();
+        assert_eq!(prim_col.dtype(), make_primitive_column(1).dtype());
+        assert!(!prim_col.candidates().is_empty());
+        assert!(prim_col.tree().is_some());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    #[cfg(not(feature = "rayon"))] // With rayon, each thread samples its first chunk
+    pub fn smoketest_homogeneous_chunks_are_sampled_once() {
+        let chunk_size = 1 << 14;
+        let chunked = |nchunks: usize| {
+            let chunks: Vec<ArrayData> = (0..nchunks)
+                .map(|_| make_primitive_column(chunk_size))
+                .collect();
+            let dtype = chunks[0].dtype().clone();
+            ChunkedArray::try_new(chunks, dtype).unwrap().into_array()
+        };
+        // Keep the chunks apart, rather than rechunking them into one.
+        let compressor = SamplingCompressor::new_with_options(
+            HashSet::from_iter(DEFAULT_COMPRESSORS),
+            CompressConfig::default().with_target_block_size(chunk_size),
+        );
+
+        let candidates = |nchunks: usize| {
+            let (_, report) = compressor
+                .compress_with_report(&chunked(nchunks), None)
+                .unwrap();
+            report.column("").unwrap().candidates().len()
+        };
+        let one_chunk = candidates(1);
+        assert!(one_chunk > 0);
+        assert!(candidates(4) < 4 * one_chunk);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn smoketest_streaming_compressor() {
+        let chunks: Vec<ArrayData> = (0..4).map(|_| make_primitive_column(1 << 14)).collect();
+        let dtype = chunks[0].dtype().clone();
+        let stream = ArrayStreamAdapter::new(
+            dtype.clone(),
+            stream::iter(chunks.clone().into_iter().map(Ok)),
+        );
+
+        let compressor = StreamingCompressor::new(SamplingCompressor::default());
+        let compressed = compressor.compress_stream(stream);
+        assert_eq!(compressed.dtype(), &dtype);
+        let compressed: Vec<ArrayData> = block_on(compressed.collect::<Vec<_>>())
+            .into_iter()
+            .collect::<VortexResult<_>>()
+            .unwrap();
+
+        assert_eq!(compressed.len(), chunks.len());
+        for (chunk, compressed) in chunks.iter().zip(compressed.iter()) {
+            assert_eq!(compressed.dtype(), chunk.dtype());
+            assert_eq!(compressed.len(), chunk.len());
+            assert!(compressed.nbytes() < chunk.nbytes());
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn smoketest_streaming_compressor_on_pool() {
+        let chunks: Vec<ArrayData> = (0..4).map(|_| make_primitive_column(1 << 14)).collect();
+        let dtype = chunks[0].dtype().clone();
+        let stream = ArrayStreamAdapter::new(
+            dtype.clone(),
+            stream::iter(chunks.clone().into_iter().map(Ok)),
+        );
+
+        let compressor = StreamingCompressor::new(SamplingCompressor::default());
+        let compressed = compressor.compress_stream_on_pool(stream);
+        assert_eq!(compressed.dtype(), &dtype);
+        let compressed: Vec<ArrayData> = block_on(compressed.collect::<Vec<_>>())
+            .into_iter()
+            .collect::<VortexResult<_>>()
+            .unwrap();
+
+        assert_eq!(compressed.len(), chunks.len());
+        for (chunk, compressed) in chunks.iter().zip(compressed.iter()) {
+            assert_eq!(compressed.len(), chunk.len());
+            assert!(compressed.nbytes() < chunk.nbytes());
+        }
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // roaring bit maps uses an unsupported FFI
     pub fn smoketest_compressor_on_chunked_array() {
@@ -125,7 +596,9 @@ mod tests {
             assert_eq!(chunk.encoding().id(), BitPackedEncoding::ID);
             assert_eq!(
                 chunk.statistics().get(Stat::UncompressedSizeInBytes),
-                Some(Scalar::from((chunk.len() * 8) as u64 + 1))
+                Some(Scalar::from(
+                    (chunk.len() * 8 + size_of::<PrimitiveMetadata>()) as u64
+                ))
             );
         }
 
@@ -138,7 +611,9 @@ mod tests {
             assert_eq!(chunk.encoding().id(), BoolEncoding::ID);
             assert_eq!(
                 chunk.statistics().get(Stat::UncompressedSizeInBytes),
-                Some(Scalar::from(chunk.len().div_ceil(8) as u64 + 2))
+                Some(Scalar::from(
+                    (chunk.len().div_ceil(8) + size_of::<BoolMetadata>()) as u64
+                ))
             );
         }
 
@@ -152,9 +627,12 @@ mod tests {
                 chunk.encoding().id() == DictEncoding::ID
                     || chunk.encoding().id() == FSSTEncoding::ID
             );
+            // Every value is short enough to be inlined, so the rechunked array is 65536 views of
+            // 16 bytes plus its metadata. Rechunking no longer carries along the source byte
+            // buffers (4 * 86016 bytes) that none of those views reference.
             assert_eq!(
                 chunk.statistics().get(Stat::UncompressedSizeInBytes),
-                Some(Scalar::from(1392677u64))
+                Some(Scalar::from(1048632u64))
             );
         }
 
@@ -163,13 +641,17 @@ mod tests {
             .unwrap()
             .try_into()
             .unwrap();
+        // The chunks are too big to compress whole, so they are sliced into pieces, each of which
+        // holds only the bytes of its own values.
+        let mut total_size = 0;
         for chunk in binary_col.chunks() {
-            assert_eq!(chunk.encoding().id(), VarBinEncoding::ID);
-            assert_eq!(
-                chunk.statistics().get(Stat::UncompressedSizeInBytes),
-                Some(Scalar::from(134357018u64))
-            );
+            total_size += chunk
+                .statistics()
+                .get_as::<u64>(Stat::UncompressedSizeInBytes)
+                .unwrap();
         }
+        assert_eq!(binary_col.nchunks(), 36);
+        assert_eq!(total_size, 537431328u64);
 
         let timestamp_col: ChunkedArray = struct_array
             .field_by_name("timestamp_col")
@@ -180,11 +662,141 @@ mod tests {
             assert_eq!(chunk.encoding().id(), DateTimePartsEncoding::ID);
             assert_eq!(
                 chunk.statistics().get(Stat::UncompressedSizeInBytes),
-                Some((chunk.len() * 8 + 4).into())
+                // The storage and the days, seconds and subseconds of the parts it is split into.
+                Some((chunk.len() * 8 + 4 * size_of::<PrimitiveMetadata>()).into())
             )
         }
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn smoketest_replay_serialized_tree() {
+        let chunk_size = 1 << 14;
+        let ints: Vec<ArrayData> = (0..4).map(|_| make_primitive_column(chunk_size)).collect();
+        let dtype = ints[0].dtype().clone();
+        let to_compress = StructArray::try_new(
+            vec!["prim_col".into(), "varbin_col".into()].into(),
+            vec![
+                ChunkedArray::try_new(ints, dtype).unwrap().into_array(),
+                make_string_column(chunk_size * 4),
+            ],
+            chunk_size * 4,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let compressor = SamplingCompressor::default();
+        let compressed = compressor.compress(&to_compress, None).unwrap();
+        let tree = compressed.path().as_ref().unwrap();
+
+        let replayed = compressor
+            .deserialize_tree(&tree.serialize().unwrap())
+            .unwrap();
+        assert_eq!(replayed.to_string(), tree.to_string());
+
+        let recompressed = compressor.compress(&to_compress, Some(&replayed)).unwrap();
+        assert_eq!(recompressed.array().dtype(), to_compress.dtype());
+
+        let struct_array = StructArray::try_from(recompressed.into_array()).unwrap();
+        let prim_col = struct_array
+            .field_by_name("prim_col")
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert!(prim_col
+            .maybe_null_slice::<i64>()
+            .iter()
+            .copied()
+            .eq((0..4).flat_map(|_| 0..chunk_size as i64)));
+        let expected = make_string_column(chunk_size * 4)
+            .into_varbinview()
+            .unwrap()
+            .with_iterator(|iter| {
+                iter.map(|value| value.unwrap().to_vec())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        struct_array
+            .field_by_name("varbin_col")
+            .unwrap()
+            .into_varbinview()
+            .unwrap()
+            .with_iterator(|iter| {
+                assert!(iter
+                    .map(|value| value.unwrap())
+                    .eq(expected.iter().map(Vec::as_slice)));
+            })
+            .unwrap();
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn smoketest_wide_struct() {
+        // With the rayon feature, the fields are compressed on separate threads.
+        let len = 4096usize;
+        let names: FieldNames = (0..16)
+            .map(|i| FieldName::from(format!("col_{i}")))
+            .collect::<Vec<_>>()
+            .into();
+        let fields = (0..16)
+            .map(|i| {
+                PrimitiveArray::from((0..len).map(|x| (x * i) as i64).collect::<Vec<_>>())
+                    .into_array()
+            })
+            .collect::<Vec<_>>();
+        let to_compress = StructArray::try_new(names.clone(), fields, len, Validity::NonNullable)
+            .unwrap()
+            .into_array();
+
+        let compressed = SamplingCompressor::default()
+            .compress(&to_compress, None)
+            .unwrap();
+        assert_eq!(compressed.path().as_ref().unwrap().children().count(), 16);
+        let struct_array = StructArray::try_from(compressed.into_array()).unwrap();
+        assert_eq!(struct_array.names(), &names);
+        for i in 0..16 {
+            let field = struct_array.field(i).unwrap().into_primitive().unwrap();
+            assert_eq!(
+                field.maybe_null_slice::<i64>(),
+                (0..len).map(|x| (x * i) as i64).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // This test is too slow on miri
+    pub fn smoketest_many_chunks() {
+        // With the rayon feature, groups of chunks are compressed on separate threads.
+        let chunk_size = 1024usize;
+        let chunks = (0..16)
+            .map(|i| {
+                PrimitiveArray::from(
+                    (0..chunk_size)
+                        .map(|x| (x + i * chunk_size) as i64)
+                        .collect::<Vec<_>>(),
+                )
+                .into_array()
+            })
+            .collect::<Vec<_>>();
+        let dtype = chunks[0].dtype().clone();
+        let to_compress = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+
+        let compressor = SamplingCompressor::new_with_options(
+            HashSet::from_iter(DEFAULT_COMPRESSORS),
+            CompressConfig::default().with_target_block_size(chunk_size),
+        );
+        let compressed = compressor.compress(&to_compress, None).unwrap();
+        // The first tree is for the chunk offsets.
+        assert_eq!(compressed.path().as_ref().unwrap().children().count(), 17);
+        let chunked = ChunkedArray::try_from(compressed.into_array()).unwrap();
+        assert_eq!(chunked.nchunks(), 16);
+        assert_eq!(
+            chunked.into_primitive().unwrap().maybe_null_slice::<i64>(),
+            (0..16 * chunk_size).map(|x| x as i64).collect::<Vec<_>>()
+        );
+    }
+
     fn make_primitive_column(count: usize) -> ArrayData {
         PrimitiveArray::from_vec(
             (0..count).map(|i| i as i64).collect::<Vec<i64>>(),
@@ -1,11 +1,12 @@
 use std::iter;
 
 use arbitrary::{Result, Unstructured};
+use arrow_buffer::i256;
 use vortex_buffer::{Buffer, BufferString};
 use vortex_dtype::half::f16;
-use vortex_dtype::{DType, PType};
+use vortex_dtype::{DType, DecimalValueType, PType};
 
-use crate::{InnerScalarValue, PValue, Scalar, ScalarValue};
+use crate::{DecimalValue, InnerScalarValue, PValue, Scalar, ScalarValue};
 
 pub fn random_scalar(u: &mut Unstructured, dtype: &DType) -> Result<Scalar> {
     Ok(Scalar::new(dtype.clone(), random_scalar_value(u, dtype)?))
@@ -24,6 +25,15 @@ fn random_scalar_value(u: &mut Unstructured, dtype: &DType) -> Result<ScalarValu
         DType::Binary(_) => Ok(ScalarValue(InnerScalarValue::Buffer(Buffer::from(
             u.arbitrary::<Vec<u8>>()?,
         )))),
+        DType::Decimal(decimal_dtype, _) => Ok(ScalarValue(InnerScalarValue::Decimal(
+            // Random values ignore the precision, which bounds the number of digits
+            match decimal_dtype.value_type() {
+                DecimalValueType::I128 => DecimalValue::I128(u.arbitrary()?),
+                DecimalValueType::I256 => {
+                    DecimalValue::I256(i256::from_le_bytes(u.arbitrary::<[u8; 32]>()?))
+                }
+            },
+        ))),
         DType::Struct(sdt, _) => Ok(ScalarValue(InnerScalarValue::List(
             sdt.dtypes()
                 .iter()
@@ -40,6 +50,12 @@ fn random_scalar_value(u: &mut Unstructured, dtype: &DType) -> Result<ScalarValu
             .collect::<Result<Vec<_>>>()?
             .into(),
         ))),
+        DType::FixedSizeList(edt, size, _) => Ok(ScalarValue(InnerScalarValue::List(
+            (0..*size)
+                .map(|_| random_scalar_value(u, edt))
+                .collect::<Result<Vec<_>>>()?
+                .into(),
+        ))),
         DType::Extension(..) => {
             unreachable!("Can't yet generate arbitrary scalars for ext dtype")
         }
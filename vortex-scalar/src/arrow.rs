@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use arrow_array::*;
 use vortex_datetime_dtype::{is_temporal_ext_type, TemporalMetadata, TimeUnit};
-use vortex_dtype::{DType, PType};
+use vortex_dtype::{DType, DecimalValueType, PType};
 use vortex_error::{vortex_bail, vortex_err, VortexError};
 
 use crate::Scalar;
@@ -78,12 +78,29 @@ impl TryFrom<&Scalar> for Arc<dyn Datum> {
             DType::Binary(_) => {
                 value_to_arrow_scalar!(value.as_binary().value(), BinaryViewArray)
             }
+            DType::Decimal(decimal_dtype, _) => {
+                let value = value.as_decimal().value();
+                let (precision, scale) = (decimal_dtype.precision(), decimal_dtype.scale());
+                Ok(match decimal_dtype.value_type() {
+                    DecimalValueType::I128 => Arc::new(arrow_array::Scalar::new(
+                        Decimal128Array::from_iter([value.and_then(|v| v.as_i128())])
+                            .with_precision_and_scale(precision, scale)?,
+                    )) as Arc<dyn Datum>,
+                    DecimalValueType::I256 => Arc::new(arrow_array::Scalar::new(
+                        Decimal256Array::from_iter([value.map(|v| v.as_i256())])
+                            .with_precision_and_scale(precision, scale)?,
+                    )),
+                })
+            }
             DType::Struct(..) => {
                 todo!("struct scalar conversion")
             }
             DType::List(..) => {
                 todo!("list scalar conversion")
             }
+            DType::FixedSizeList(..) => {
+                vortex_bail!("Cannot convert fixed size list scalar {value}")
+            }
             DType::Extension(ext) => {
                 if is_temporal_ext_type(ext.id()) {
                     let metadata = TemporalMetadata::try_from(ext.as_ref())?;
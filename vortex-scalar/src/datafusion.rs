@@ -6,10 +6,10 @@ use vortex_buffer::Buffer;
 use vortex_datetime_dtype::arrow::make_temporal_ext_dtype;
 use vortex_datetime_dtype::{is_temporal_ext_type, TemporalMetadata, TimeUnit};
 use vortex_dtype::half::f16;
-use vortex_dtype::{DType, Nullability, PType};
-use vortex_error::VortexError;
+use vortex_dtype::{DType, DecimalDType, DecimalValueType, Nullability, PType};
+use vortex_error::{vortex_bail, VortexError, VortexExpect, VortexResult};
 
-use crate::{InnerScalarValue, PValue, Scalar};
+use crate::{DecimalValue, InnerScalarValue, PValue, Scalar};
 
 impl TryFrom<Scalar> for ScalarValue {
     type Error = VortexError;
@@ -43,12 +43,27 @@ impl TryFrom<Scalar> for ScalarValue {
                     .value()
                     .map(|b| b.into_vec().unwrap_or_else(|buf| buf.as_slice().to_vec())),
             ),
+            DType::Decimal(decimal_dtype, _) => {
+                let value = scalar.as_decimal().value();
+                let (precision, scale) = (decimal_dtype.precision(), decimal_dtype.scale());
+                match decimal_dtype.value_type() {
+                    DecimalValueType::I128 => {
+                        ScalarValue::Decimal128(value.and_then(|v| v.as_i128()), precision, scale)
+                    }
+                    DecimalValueType::I256 => {
+                        ScalarValue::Decimal256(value.map(|v| v.as_i256()), precision, scale)
+                    }
+                }
+            }
             DType::Struct(..) => {
                 todo!("struct scalar conversion")
             }
             DType::List(..) => {
                 todo!("list scalar conversion")
             }
+            DType::FixedSizeList(..) => {
+                vortex_bail!("Cannot convert fixed size list scalar {scalar}")
+            }
             DType::Extension(ext) => {
                 let storage_scalar = scalar.as_extension().storage();
 
@@ -103,9 +118,19 @@ impl TryFrom<Scalar> for ScalarValue {
     }
 }
 
+/// Deprecated: panics on values that have no Vortex equivalent, use
+/// [`Scalar::try_from_datafusion`] instead.
 impl From<ScalarValue> for Scalar {
     fn from(value: ScalarValue) -> Scalar {
-        match value {
+        Scalar::try_from_datafusion(value)
+            .vortex_expect("Failed to convert DataFusion scalar to a Vortex scalar")
+    }
+}
+
+impl Scalar {
+    /// Convert a DataFusion scalar, failing if it has no Vortex equivalent.
+    pub fn try_from_datafusion(value: ScalarValue) -> VortexResult<Scalar> {
+        Ok(match value {
             ScalarValue::Null => Some(Scalar::null(DType::Null)),
             ScalarValue::Boolean(b) => b.map(Scalar::from),
             ScalarValue::Float16(f) => f.map(Scalar::from),
@@ -128,6 +153,13 @@ impl From<ScalarValue> for Scalar {
             | ScalarValue::FixedSizeBinary(_, b) => b
                 .as_ref()
                 .map(|b| Scalar::binary(Buffer::from(b.clone()), Nullability::Nullable)),
+            ScalarValue::Decimal128(v, precision, scale) => {
+                Some(decimal_scalar(v, DecimalDType::try_new(precision, scale)?))
+            }
+            ScalarValue::Decimal256(v, precision, scale) => Some(decimal_scalar(
+                v,
+                DecimalDType::try_new(precision, scale)?.with_value_type(DecimalValueType::I256)?,
+            )),
             ScalarValue::Date32(v)
             | ScalarValue::Time32Second(v)
             | ScalarValue::Time32Millisecond(v) => v.map(|i| {
@@ -151,8 +183,47 @@ impl From<ScalarValue> for Scalar {
                     crate::ScalarValue(InnerScalarValue::Primitive(PValue::I64(i))),
                 )
             }),
-            _ => unimplemented!("Can't convert {value:?} value to a Vortex scalar"),
+            _ => vortex_bail!("Can't convert {value:?} value to a Vortex scalar"),
         }
-        .unwrap_or_else(|| Scalar::null(DType::Null))
+        .unwrap_or_else(|| Scalar::null(DType::Null)))
+    }
+}
+
+fn decimal_scalar<T: Into<DecimalValue>>(value: Option<T>, decimal_dtype: DecimalDType) -> Scalar {
+    match value {
+        Some(v) => Scalar::decimal(v, decimal_dtype, Nullability::Nullable),
+        None => Scalar::null(DType::Decimal(decimal_dtype, Nullability::Nullable)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion_common::ScalarValue;
+    use vortex_dtype::{DType, DecimalDType, DecimalValueType, Nullability};
+
+    use crate::Scalar;
+
+    #[test]
+    fn decimal_scalar_value() {
+        let scalar =
+            Scalar::try_from_datafusion(ScalarValue::Decimal128(Some(12345), 10, 2)).unwrap();
+        assert_eq!(
+            scalar.dtype(),
+            &DType::Decimal(DecimalDType::new(10, 2), Nullability::Nullable)
+        );
+        assert!(Scalar::try_from_datafusion(ScalarValue::Decimal128(Some(1), 0, 0)).is_err());
+
+        let value = ScalarValue::Decimal256(Some(12345.into()), 10, 2);
+        let scalar = Scalar::try_from_datafusion(value.clone()).unwrap();
+        assert_eq!(
+            scalar.dtype(),
+            &DType::Decimal(
+                DecimalDType::new(10, 2)
+                    .with_value_type(DecimalValueType::I256)
+                    .unwrap(),
+                Nullability::Nullable
+            )
+        );
+        assert_eq!(ScalarValue::try_from(scalar).unwrap(), value);
     }
 }
@@ -0,0 +1,260 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use arrow_buffer::i256;
+use vortex_dtype::{DType, DecimalDType, DecimalValueType, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexExpect, VortexResult};
+
+use crate::value::{InnerScalarValue, ScalarValue};
+use crate::Scalar;
+
+/// The unscaled value of a decimal, i.e. an integer number of units of `10^-scale`.
+///
+/// Values of either width compare equal if they hold the same integer.
+#[derive(Debug, Clone, Copy)]
+pub enum DecimalValue {
+    I128(i128),
+    I256(i256),
+}
+
+impl DecimalValue {
+    pub fn value_type(&self) -> DecimalValueType {
+        match self {
+            Self::I128(_) => DecimalValueType::I128,
+            Self::I256(_) => DecimalValueType::I256,
+        }
+    }
+
+    /// The value as a 256-bit integer, which can hold values of either width.
+    pub fn as_i256(&self) -> i256 {
+        match self {
+            Self::I128(v) => i256::from_i128(*v),
+            Self::I256(v) => *v,
+        }
+    }
+
+    /// The value as a 128-bit integer, or `None` if it doesn't fit.
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Self::I128(v) => Some(*v),
+            Self::I256(v) => v.to_i128(),
+        }
+    }
+
+    /// Convert the value to the given width, failing if it doesn't fit.
+    pub fn cast(&self, value_type: DecimalValueType) -> VortexResult<Self> {
+        Ok(match value_type {
+            DecimalValueType::I128 => Self::I128(
+                self.as_i128()
+                    .ok_or_else(|| vortex_err!("Decimal value {} does not fit in i128", self))?,
+            ),
+            DecimalValueType::I256 => Self::I256(self.as_i256()),
+        })
+    }
+
+    /// The little-endian two's complement bytes of the value.
+    pub(crate) fn to_le_bytes(self) -> Vec<u8> {
+        match self {
+            Self::I128(v) => v.to_le_bytes().to_vec(),
+            Self::I256(v) => v.to_le_bytes().to_vec(),
+        }
+    }
+
+    /// Read a value from its little-endian bytes, whose length decides its width.
+    pub(crate) fn from_le_bytes(bytes: &[u8]) -> VortexResult<Self> {
+        if let Ok(bytes) = <[u8; 16]>::try_from(bytes) {
+            Ok(Self::I128(i128::from_le_bytes(bytes)))
+        } else if let Ok(bytes) = <[u8; 32]>::try_from(bytes) {
+            Ok(Self::I256(i256::from_le_bytes(bytes)))
+        } else {
+            vortex_bail!(
+                "Expected 16 or 32 bytes of decimal value, found {}",
+                bytes.len()
+            )
+        }
+    }
+}
+
+impl PartialEq for DecimalValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_i256() == other.as_i256()
+    }
+}
+
+impl PartialOrd for DecimalValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.as_i256().cmp(&other.as_i256()))
+    }
+}
+
+impl Display for DecimalValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::I128(v) => write!(f, "{}", v),
+            Self::I256(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl From<i128> for DecimalValue {
+    fn from(value: i128) -> Self {
+        Self::I128(value)
+    }
+}
+
+impl From<i256> for DecimalValue {
+    fn from(value: i256) -> Self {
+        Self::I256(value)
+    }
+}
+
+/// Format an unscaled decimal value with its decimal point, e.g. `12345` with scale 2 as `123.45`.
+pub(crate) fn format_decimal(value: DecimalValue, scale: i8) -> String {
+    let digits = value.to_string();
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", digits.as_str()),
+    };
+    if scale <= 0 {
+        return format!(
+            "{sign}{digits}{}",
+            "0".repeat(usize::from(scale.unsigned_abs()))
+        );
+    }
+
+    let scale = usize::from(scale.unsigned_abs());
+    let digits = format!("{digits:0>width$}", width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{sign}{integer}.{fraction}")
+}
+
+pub struct DecimalScalar<'a> {
+    dtype: &'a DType,
+    decimal_dtype: DecimalDType,
+    value: Option<DecimalValue>,
+}
+
+impl<'a> DecimalScalar<'a> {
+    #[inline]
+    pub fn dtype(&self) -> &'a DType {
+        self.dtype
+    }
+
+    #[inline]
+    pub fn decimal_dtype(&self) -> DecimalDType {
+        self.decimal_dtype
+    }
+
+    pub fn value(&self) -> Option<DecimalValue> {
+        self.value
+    }
+
+    /// Cast to a decimal dtype of the same scale, changing only how many digits it may hold.
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        let DType::Decimal(decimal_dtype, nullability) = dtype else {
+            vortex_bail!("Can't cast {} scalar to {}", self.dtype, dtype)
+        };
+        if decimal_dtype.scale() != self.decimal_dtype.scale() {
+            vortex_bail!(
+                "Can't cast {} scalar to {} with a different scale",
+                self.dtype,
+                dtype
+            )
+        }
+
+        Ok(match self.value {
+            None => Scalar::null(dtype.clone()),
+            Some(value) => Scalar::new(
+                DType::Decimal(*decimal_dtype, *nullability),
+                ScalarValue(InnerScalarValue::Decimal(
+                    value.cast(decimal_dtype.value_type())?,
+                )),
+            ),
+        })
+    }
+}
+
+impl Scalar {
+    /// Create a decimal scalar, converting the value to the width of the decimal dtype.
+    ///
+    /// Panics if the value doesn't fit in that width.
+    pub fn decimal(
+        value: impl Into<DecimalValue>,
+        decimal_dtype: DecimalDType,
+        nullability: Nullability,
+    ) -> Self {
+        let value = value
+            .into()
+            .cast(decimal_dtype.value_type())
+            .vortex_expect("Decimal value does not fit its dtype");
+        Self {
+            dtype: DType::Decimal(decimal_dtype, nullability),
+            value: ScalarValue(InnerScalarValue::Decimal(value)),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Scalar> for DecimalScalar<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
+        let DType::Decimal(decimal_dtype, _) = value.dtype() else {
+            vortex_bail!("Expected decimal scalar, found {}", value.dtype())
+        };
+        Ok(Self {
+            dtype: value.dtype(),
+            decimal_dtype: *decimal_dtype,
+            // The serialized form may come back as bytes, or as a value of the other width.
+            value: value
+                .value
+                .as_decimal()?
+                .map(|v| v.cast(decimal_dtype.value_type()))
+                .transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_buffer::i256;
+    use vortex_dtype::{DType, DecimalDType, Nullability};
+
+    use crate::{DecimalValue, Scalar};
+
+    #[test]
+    fn value_width() {
+        let small = Scalar::decimal(
+            12345i128,
+            DecimalDType::new(10, 2),
+            Nullability::NonNullable,
+        );
+        assert_eq!(small.as_decimal().value(), Some(DecimalValue::I128(12345)));
+
+        let large = Scalar::decimal(
+            12345i128,
+            DecimalDType::new(50, 2),
+            Nullability::NonNullable,
+        );
+        assert!(matches!(
+            large.as_decimal().value(),
+            Some(DecimalValue::I256(v)) if v == i256::from_i128(12345)
+        ));
+    }
+
+    #[test]
+    fn cast_precision() {
+        let scalar = Scalar::decimal(
+            12345i128,
+            DecimalDType::new(10, 2),
+            Nullability::NonNullable,
+        );
+        let wider = DType::Decimal(DecimalDType::new(40, 2), Nullability::Nullable);
+        assert_eq!(scalar.cast(&wider).unwrap().dtype(), &wider);
+        assert!(scalar
+            .cast(&DType::Decimal(
+                DecimalDType::new(10, 3),
+                Nullability::NonNullable
+            ))
+            .is_err());
+    }
+}
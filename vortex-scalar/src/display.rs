@@ -6,7 +6,9 @@ use vortex_dtype::DType;
 use vortex_error::vortex_panic;
 
 use crate::binary::BinaryScalar;
+use crate::decimal::{format_decimal, DecimalScalar};
 use crate::extension::ExtScalar;
+use crate::list::ListScalar;
 use crate::struct_::StructScalar;
 use crate::utf8::Utf8Scalar;
 use crate::Scalar;
@@ -39,6 +41,15 @@ impl Display for Scalar {
                     }
                 }
             }
+            DType::Decimal(decimal_dtype, _) => {
+                match DecimalScalar::try_from(self)
+                    .map_err(|_| std::fmt::Error)?
+                    .value()
+                {
+                    None => write!(f, "null"),
+                    Some(value) => write!(f, "{}", format_decimal(value, decimal_dtype.scale())),
+                }
+            }
             DType::Struct(dtype, _) => {
                 let v = StructScalar::try_from(self).map_err(|_| std::fmt::Error)?;
 
@@ -59,7 +70,15 @@ impl Display for Scalar {
                     write!(f, "}}")
                 }
             }
-            DType::List(..) => todo!(),
+            DType::List(..) | DType::FixedSizeList(..) => {
+                let v = ListScalar::try_from(self).map_err(|_| std::fmt::Error)?;
+
+                if v.is_null() {
+                    write!(f, "null")
+                } else {
+                    write!(f, "[{}]", v.elements().format(","))
+                }
+            }
             // Specialized handling for date/time/timestamp builtin extension types.
             DType::Extension(dtype) if is_temporal_ext_type(dtype.id()) => {
                 let metadata =
@@ -107,7 +126,7 @@ mod tests {
     use vortex_buffer::Buffer;
     use vortex_datetime_dtype::{TemporalMetadata, TimeUnit, DATE_ID, TIMESTAMP_ID, TIME_ID};
     use vortex_dtype::Nullability::{NonNullable, Nullable};
-    use vortex_dtype::{DType, ExtDType, ExtMetadata, PType, StructDType};
+    use vortex_dtype::{DType, DecimalDType, ExtDType, ExtMetadata, PType, StructDType};
 
     use crate::{InnerScalarValue, PValue, Scalar, ScalarValue};
 
@@ -163,6 +182,27 @@ mod tests {
         assert_eq!(format!("{}", Scalar::null(DType::Binary(Nullable))), "null");
     }
 
+    #[test]
+    fn display_decimal() {
+        let decimal = |value: i128, scale: i8| {
+            format!(
+                "{}",
+                Scalar::decimal(value, DecimalDType::new(10, scale), NonNullable)
+            )
+        };
+        assert_eq!(decimal(12345, 2), "123.45");
+        assert_eq!(decimal(-5, 3), "-0.005");
+        assert_eq!(decimal(12, -2), "1200");
+        assert_eq!(decimal(7, 0), "7");
+        assert_eq!(
+            format!(
+                "{}",
+                Scalar::null(DType::Decimal(DecimalDType::new(10, 2), Nullable))
+            ),
+            "null"
+        );
+    }
+
     #[test]
     fn display_empty_struct() {
         fn dtype() -> DType {
@@ -238,6 +278,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_list() {
+        let element_dtype = Arc::new(DType::Primitive(PType::I32, NonNullable));
+        let elements = vec![Scalar::from(1i32), Scalar::from(2i32), Scalar::from(3i32)];
+        assert_eq!(
+            format!(
+                "{}",
+                Scalar::list(element_dtype.clone(), elements.clone(), Nullable)
+            ),
+            "[1_i32,2_i32,3_i32]"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Scalar::fixed_size_list(element_dtype.clone(), elements, NonNullable)
+            ),
+            "[1_i32,2_i32,3_i32]"
+        );
+        assert_eq!(
+            format!("{}", Scalar::null(DType::List(element_dtype, Nullable))),
+            "null"
+        );
+    }
+
     #[test]
     fn display_time() {
         fn dtype() -> DType {
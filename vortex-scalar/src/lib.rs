@@ -12,6 +12,7 @@ mod arrow;
 mod binary;
 mod bool;
 mod datafusion;
+mod decimal;
 mod display;
 mod extension;
 mod list;
@@ -27,6 +28,7 @@ mod value;
 
 pub use binary::*;
 pub use bool::*;
+pub use decimal::*;
 pub use extension::*;
 pub use list::*;
 pub use primitive::*;
@@ -118,7 +120,10 @@ impl Scalar {
             DType::Utf8(_) => Utf8Scalar::try_from(self).and_then(|s| s.cast(dtype)),
             DType::Binary(_) => BinaryScalar::try_from(self).and_then(|s| s.cast(dtype)),
             DType::Struct(..) => StructScalar::try_from(self).and_then(|s| s.cast(dtype)),
-            DType::List(..) => ListScalar::try_from(self).and_then(|s| s.cast(dtype)),
+            DType::Decimal(..) => DecimalScalar::try_from(self).and_then(|s| s.cast(dtype)),
+            DType::List(..) | DType::FixedSizeList(..) => {
+                ListScalar::try_from(self).and_then(|s| s.cast(dtype))
+            }
             DType::Extension(ext_dtype) => {
                 if !self.value().is_instance_of(ext_dtype.storage_dtype()) {
                     vortex_bail!(
@@ -176,6 +181,14 @@ impl Scalar {
         matches!(self.dtype, DType::Binary(..)).then(|| self.as_binary())
     }
 
+    pub fn as_decimal(&self) -> DecimalScalar {
+        DecimalScalar::try_from(self).vortex_expect("Failed to convert scalar to decimal")
+    }
+
+    pub fn as_decimal_opt(&self) -> Option<DecimalScalar> {
+        matches!(self.dtype, DType::Decimal(..)).then(|| self.as_decimal())
+    }
+
     pub fn as_struct(&self) -> StructScalar {
         StructScalar::try_from(self).vortex_expect("Failed to convert scalar to struct")
     }
@@ -189,7 +202,7 @@ impl Scalar {
     }
 
     pub fn as_list_opt(&self) -> Option<ListScalar> {
-        matches!(self.dtype, DType::List(..)).then(|| self.as_list())
+        matches!(self.dtype, DType::List(..) | DType::FixedSizeList(..)).then(|| self.as_list())
     }
 
     pub fn as_extension(&self) -> ExtScalar {
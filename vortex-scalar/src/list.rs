@@ -37,7 +37,8 @@ impl<'a> ListScalar<'a> {
     }
 
     pub fn element_dtype(&self) -> DType {
-        let DType::List(element_type, _) = self.dtype() else {
+        let (DType::List(element_type, _) | DType::FixedSizeList(element_type, ..)) = self.dtype()
+        else {
             unreachable!();
         };
         (*element_type).deref().clone()
@@ -93,6 +94,21 @@ impl Scalar {
         }
     }
 
+    /// Create a scalar of a fixed size list whose size is the number of `children`.
+    pub fn fixed_size_list(
+        element_dtype: Arc<DType>,
+        children: Vec<Scalar>,
+        nullability: Nullability,
+    ) -> Self {
+        let size = u32::try_from(children.len())
+            .unwrap_or_else(|_| vortex_panic!("fixed size list of {} elements", children.len()));
+        let Self { value, .. } = Self::list(element_dtype.clone(), children, nullability);
+        Self {
+            dtype: DType::FixedSizeList(element_dtype, size, nullability),
+            value,
+        }
+    }
+
     pub fn list_empty(element_dtype: Arc<DType>, nullability: Nullability) -> Self {
         Self {
             dtype: DType::List(element_dtype, nullability),
@@ -105,7 +121,7 @@ impl<'a> TryFrom<&'a Scalar> for ListScalar<'a> {
     type Error = VortexError;
 
     fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
-        if !matches!(value.dtype(), DType::List(..)) {
+        if !matches!(value.dtype(), DType::List(..) | DType::FixedSizeList(..)) {
             vortex_bail!("Expected list scalar, found {}", value.dtype())
         }
 
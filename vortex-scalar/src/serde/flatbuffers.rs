@@ -4,7 +4,7 @@ use vortex_dtype::DType;
 use vortex_error::{VortexError, VortexExpect as _};
 use vortex_flatbuffers::{scalar as fb, WriteFlatBuffer};
 
-use crate::{Scalar, ScalarValue};
+use crate::{InnerScalarValue, Scalar, ScalarValue};
 
 impl TryFrom<fb::Scalar<'_>> for Scalar {
     type Error = VortexError;
@@ -14,7 +14,14 @@ impl TryFrom<fb::Scalar<'_>> for Scalar {
         let dtype = DType::try_from(dtype)?;
 
         let reader = flexbuffers::Reader::get_root(value.value().flex().bytes())?;
-        let value = ScalarValue::deserialize(reader)?;
+        let mut value = ScalarValue::deserialize(reader)?;
+
+        // Decimals are serialized as bytes, which only the dtype tells apart from binary values.
+        if dtype.is_decimal() {
+            if let Some(decimal) = value.as_decimal()? {
+                value = ScalarValue(InnerScalarValue::Decimal(decimal));
+            }
+        }
 
         Ok(Self { dtype, value })
     }
@@ -1,13 +1,13 @@
 use vortex_buffer::{Buffer, BufferString};
 use vortex_dtype::half::f16;
-use vortex_dtype::{DType, PType};
+use vortex_dtype::{match_each_native_ptype, DType, PType};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_proto::scalar as pb;
 use vortex_proto::scalar::scalar_value::Kind;
 use vortex_proto::scalar::ListValue;
 
 use crate::pvalue::PValue;
-use crate::{InnerScalarValue, Scalar, ScalarValue};
+use crate::{DecimalValue, InnerScalarValue, Scalar, ScalarValue};
 
 impl From<&Scalar> for pb::Scalar {
     fn from(value: &Scalar) -> Self {
@@ -34,6 +34,9 @@ impl From<&ScalarValue> for pb::ScalarValue {
             ScalarValue(InnerScalarValue::BufferString(v)) => pb::ScalarValue {
                 kind: Some(Kind::StringValue(v.as_str().to_string())),
             },
+            ScalarValue(InnerScalarValue::Decimal(v)) => pb::ScalarValue {
+                kind: Some(Kind::BytesValue(v.to_le_bytes())),
+            },
             ScalarValue(InnerScalarValue::List(v)) => {
                 let mut values = Vec::with_capacity(v.len());
                 for elem in v.iter() {
@@ -110,17 +113,27 @@ impl TryFrom<&pb::Scalar> for Scalar {
     }
 }
 
+/// Deserialize a value of the given dtype.
+///
+/// The serialized value doesn't record its exact type, e.g. 8-bit integers are serialized as 32-bit
+/// ones, so the dtype decides the type of the value, recursively for the elements of lists and the
+/// fields of structs.
 fn deserialize_scalar_value(dtype: &DType, value: &pb::ScalarValue) -> VortexResult<ScalarValue> {
     let kind = value
         .kind
         .as_ref()
         .ok_or_else(|| vortex_err!(InvalidSerde: "ScalarValue missing kind"))?;
 
+    // Extension scalars hold a value of their storage dtype.
+    if let DType::Extension(ext_dtype) = dtype {
+        return deserialize_scalar_value(ext_dtype.storage_dtype(), value);
+    }
+
     match kind {
         Kind::NullValue(_) => Ok(ScalarValue(InnerScalarValue::Null)),
         Kind::BoolValue(v) => Ok(ScalarValue(InnerScalarValue::Bool(*v))),
-        Kind::Int32Value(v) => Ok(ScalarValue(InnerScalarValue::Primitive(PValue::I32(*v)))),
-        Kind::Int64Value(v) => Ok(ScalarValue(InnerScalarValue::Primitive(PValue::I64(*v)))),
+        Kind::Int32Value(v) => primitive_value(dtype, PValue::I32(*v)),
+        Kind::Int64Value(v) => primitive_value(dtype, PValue::I64(*v)),
         Kind::Uint32Value(v) => match dtype {
             DType::Primitive(PType::F16, _) => {
                 let f16_value = f16::from_bits(u16::try_from(*v).map_err(|_| {
@@ -134,40 +147,61 @@ fn deserialize_scalar_value(dtype: &DType, value: &pb::ScalarValue) -> VortexRes
                     f16_value,
                 ))))
             }
-            DType::Primitive(PType::U32, _) => {
-                Ok(ScalarValue(InnerScalarValue::Primitive(PValue::U32(*v))))
-            }
-            _ => vortex_bail!("invalid dtype for f32 value {}", dtype),
+            _ => primitive_value(dtype, PValue::U32(*v)),
         },
-        Kind::Uint64Value(v) => Ok(ScalarValue(InnerScalarValue::Primitive(PValue::U64(*v)))),
-        Kind::FloatValue(v) => Ok(ScalarValue(InnerScalarValue::Primitive(PValue::F32(*v)))),
-        Kind::DoubleValue(v) => Ok(ScalarValue(InnerScalarValue::Primitive(PValue::F64(*v)))),
+        Kind::Uint64Value(v) => primitive_value(dtype, PValue::U64(*v)),
+        Kind::FloatValue(v) => primitive_value(dtype, PValue::F32(*v)),
+        Kind::DoubleValue(v) => primitive_value(dtype, PValue::F64(*v)),
         Kind::StringValue(v) => Ok(ScalarValue(InnerScalarValue::BufferString(
             BufferString::from(v.clone()),
         ))),
-        Kind::BytesValue(v) => Ok(ScalarValue(InnerScalarValue::Buffer(Buffer::from(
-            v.clone(),
-        )))),
+        Kind::BytesValue(v) => match dtype {
+            DType::Decimal(..) => Ok(ScalarValue(InnerScalarValue::Decimal(
+                DecimalValue::from_le_bytes(v)?,
+            ))),
+            _ => Ok(ScalarValue(InnerScalarValue::Buffer(Buffer::from(
+                v.clone(),
+            )))),
+        },
         Kind::ListValue(v) => {
-            let mut values = Vec::with_capacity(v.values.len());
-            match dtype {
+            let values = match dtype {
                 DType::Struct(structdt, _) => {
-                    for (elem, dtype) in v.values.iter().zip(structdt.dtypes().iter()) {
-                        values.push(deserialize_scalar_value(dtype, elem)?);
-                    }
-                }
-                DType::List(elementdt, _) => {
-                    for elem in v.values.iter() {
-                        values.push(deserialize_scalar_value(elementdt, elem)?);
+                    if v.values.len() != structdt.dtypes().len() {
+                        vortex_bail!(
+                            InvalidSerde: "Struct value has {} fields, expected {} for {}",
+                            v.values.len(),
+                            structdt.dtypes().len(),
+                            dtype
+                        );
                     }
+                    v.values
+                        .iter()
+                        .zip(structdt.dtypes().iter())
+                        .map(|(elem, dtype)| deserialize_scalar_value(dtype, elem))
+                        .collect::<VortexResult<Vec<_>>>()?
                 }
+                DType::List(elementdt, _) | DType::FixedSizeList(elementdt, ..) => v
+                    .values
+                    .iter()
+                    .map(|elem| deserialize_scalar_value(elementdt, elem))
+                    .collect::<VortexResult<Vec<_>>>()?,
                 _ => vortex_bail!("invalid dtype for list value {}", dtype),
-            }
+            };
             Ok(ScalarValue(InnerScalarValue::List(values.into())))
         }
     }
 }
 
+/// The primitive value as the primitive type of the dtype, which may be narrower than the type it
+/// was serialized as.
+fn primitive_value(dtype: &DType, value: PValue) -> VortexResult<ScalarValue> {
+    let DType::Primitive(ptype, _) = dtype else {
+        vortex_bail!("invalid dtype for primitive value {}", dtype);
+    };
+    let value = match_each_native_ptype!(ptype, |$T| PValue::from(value.as_primitive::<$T>()?));
+    Ok(ScalarValue(InnerScalarValue::Primitive(value)))
+}
+
 #[cfg(test)]
 mod test {
     use std::sync::Arc;
@@ -175,16 +209,15 @@ mod test {
     use vortex_buffer::BufferString;
     use vortex_dtype::half::f16;
     use vortex_dtype::PType::{self, I32};
-    use vortex_dtype::{DType, Nullability};
+    use vortex_dtype::{DType, DecimalDType, ExtDType, ExtID, Nullability, StructDType};
     use vortex_proto::scalar as pb;
 
     use crate::{InnerScalarValue, PValue, Scalar, ScalarValue};
 
-    fn round_trip(scalar: Scalar) {
-        assert_eq!(
-            scalar,
-            Scalar::try_from(&pb::Scalar::from(&scalar)).unwrap(),
-        );
+    fn round_trip(scalar: Scalar) -> Scalar {
+        let read = Scalar::try_from(&pb::Scalar::from(&scalar)).unwrap();
+        assert_eq!(scalar, read);
+        read
     }
 
     #[test]
@@ -226,6 +259,15 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_fixed_size_list() {
+        round_trip(Scalar::fixed_size_list(
+            Arc::new(DType::Primitive(I32, Nullability::NonNullable)),
+            vec![42i32.into(), 43i32.into()],
+            Nullability::Nullable,
+        ));
+    }
+
     #[test]
     fn test_list() {
         round_trip(Scalar::new(
@@ -243,6 +285,24 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_decimal() {
+        round_trip(Scalar::decimal(
+            -12345i128,
+            DecimalDType::new(10, 2),
+            Nullability::Nullable,
+        ));
+        round_trip(Scalar::decimal(
+            i128::MAX,
+            DecimalDType::new(76, 0),
+            Nullability::NonNullable,
+        ));
+        round_trip(Scalar::null(DType::Decimal(
+            DecimalDType::new(10, 2),
+            Nullability::Nullable,
+        )));
+    }
+
     #[test]
     fn test_f16() {
         round_trip(Scalar::new(
@@ -252,4 +312,99 @@ mod test {
             )))),
         ));
     }
+
+    #[test]
+    fn test_narrow_primitives() {
+        for scalar in [
+            Scalar::from(7u8),
+            Scalar::from(-7i8),
+            Scalar::from(700u16),
+            Scalar::from(-700i16),
+        ] {
+            let ptype = scalar.as_primitive().ptype();
+            let read = round_trip(scalar);
+            assert!(
+                matches!(read.value().0, InnerScalarValue::Primitive(v) if v.ptype() == ptype),
+                "{ptype} was read as {read}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_extension() {
+        let dtype = DType::Extension(Arc::new(ExtDType::new(
+            ExtID::new("test.ext".into()),
+            Arc::new(DType::Primitive(PType::U16, Nullability::Nullable)),
+            None,
+        )));
+        round_trip(Scalar::new(
+            dtype.clone(),
+            ScalarValue(InnerScalarValue::Primitive(PValue::U16(42))),
+        ));
+        round_trip(Scalar::null(dtype));
+    }
+
+    #[test]
+    fn test_nested() {
+        let f16s = Scalar::list(
+            Arc::new(DType::Primitive(PType::F16, Nullability::NonNullable)),
+            vec![
+                Scalar::from(f16::from_f32(0.5)),
+                Scalar::from(f16::from_f32(1.5)),
+            ],
+            Nullability::NonNullable,
+        );
+        let inner_dtype = DType::Struct(
+            StructDType::new(
+                ["name".into()].into(),
+                vec![DType::Utf8(Nullability::Nullable)],
+            ),
+            Nullability::Nullable,
+        );
+        let inner = Scalar::struct_(
+            inner_dtype.clone(),
+            vec![Scalar::null(DType::Utf8(Nullability::Nullable))],
+        );
+        let outer_dtype = DType::Struct(
+            StructDType::new(
+                ["byte".into(), "halves".into(), "inner".into()].into(),
+                vec![
+                    DType::Primitive(PType::U8, Nullability::NonNullable),
+                    f16s.dtype().clone(),
+                    inner_dtype,
+                ],
+            ),
+            Nullability::NonNullable,
+        );
+        let outer = Scalar::struct_(outer_dtype, vec![Scalar::from(3u8), f16s, inner]);
+        round_trip(Scalar::list(
+            Arc::new(outer.dtype().clone()),
+            vec![outer.clone(), outer],
+            Nullability::Nullable,
+        ));
+    }
+
+    #[test]
+    fn test_struct_field_count_mismatch() {
+        let dtype = DType::Struct(
+            StructDType::new(
+                ["a".into(), "b".into()].into(),
+                vec![
+                    DType::Bool(Nullability::NonNullable),
+                    DType::Bool(Nullability::NonNullable),
+                ],
+            ),
+            Nullability::NonNullable,
+        );
+        let mut proto = pb::Scalar::from(&Scalar::struct_(
+            dtype,
+            vec![Scalar::from(true), Scalar::from(false)],
+        ));
+        if let Some(pb::scalar_value::Kind::ListValue(list)) =
+            proto.value.as_mut().and_then(|v| v.kind.as_mut())
+        {
+            list.values.pop();
+        }
+        assert!(Scalar::try_from(&proto).is_err());
+    }
 }
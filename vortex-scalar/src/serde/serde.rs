@@ -28,6 +28,8 @@ impl Serialize for InnerScalarValue {
             Self::Buffer(buffer) => buffer.as_ref().serialize(serializer),
             Self::BufferString(buffer) => buffer.as_str().serialize(serializer),
             Self::List(l) => l.serialize(serializer),
+            // Read back as a buffer, which the decimal scalar knows how to interpret.
+            Self::Decimal(d) => serializer.serialize_bytes(&d.to_le_bytes()),
         }
     }
 }
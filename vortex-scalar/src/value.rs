@@ -5,6 +5,7 @@ use vortex_buffer::{Buffer, BufferString};
 use vortex_dtype::DType;
 use vortex_error::{vortex_err, VortexResult};
 
+use crate::decimal::DecimalValue;
 use crate::pvalue::PValue;
 
 /// Represents the internal data of a scalar value. Must be interpreted by wrapping
@@ -23,6 +24,7 @@ pub(crate) enum InnerScalarValue {
     Buffer(Buffer),
     BufferString(BufferString),
     List(Arc<[ScalarValue]>),
+    Decimal(DecimalValue),
     // It's significant that Null is last in this list. As a result generated PartialOrd sorts Scalar
     // values such that Nulls are last (greatest)
     Null,
@@ -72,6 +74,7 @@ impl Display for InnerScalarValue {
                 }
             }
             Self::List(_) => todo!(),
+            Self::Decimal(d) => write!(f, "{}", d),
             Self::Null => write!(f, "null"),
         }
     }
@@ -112,6 +115,10 @@ impl ScalarValue {
     pub(crate) fn as_list(&self) -> VortexResult<Option<&Arc<[ScalarValue]>>> {
         self.0.as_list()
     }
+
+    pub(crate) fn as_decimal(&self) -> VortexResult<Option<DecimalValue>> {
+        self.0.as_decimal()
+    }
 }
 
 impl InnerScalarValue {
@@ -127,9 +134,15 @@ impl InnerScalarValue {
             }
             (InnerScalarValue::Buffer(_), DType::Binary(_)) => true,
             (InnerScalarValue::BufferString(_), DType::Utf8(_)) => true,
+            (InnerScalarValue::Decimal(value), DType::Decimal(decimal_dtype, _)) => {
+                value.value_type() == decimal_dtype.value_type()
+            }
             (InnerScalarValue::List(values), DType::List(dtype, _)) => {
                 values.iter().all(|v| v.is_instance_of(dtype))
             }
+            (InnerScalarValue::List(values), DType::FixedSizeList(dtype, size, _)) => {
+                values.len() == *size as usize && values.iter().all(|v| v.is_instance_of(dtype))
+            }
             (InnerScalarValue::List(values), DType::Struct(structdt, _)) => values
                 .iter()
                 .zip(structdt.dtypes().to_vec())
@@ -190,6 +203,15 @@ impl InnerScalarValue {
             _ => Err(vortex_err!("Expected a list scalar, found {:?}", self)),
         }
     }
+
+    pub(crate) fn as_decimal(&self) -> VortexResult<Option<DecimalValue>> {
+        match &self {
+            InnerScalarValue::Null => Ok(None),
+            InnerScalarValue::Decimal(d) => Ok(Some(*d)),
+            InnerScalarValue::Buffer(b) => DecimalValue::from_le_bytes(b.as_slice()).map(Some),
+            _ => Err(vortex_err!("Expected a decimal scalar, found {:?}", self)),
+        }
+    }
 }
 
 #[cfg(test)]